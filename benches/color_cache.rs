@@ -0,0 +1,54 @@
+//! Frame time of a dense widget gallery with and without the per-frame color cache
+//!
+//! ```bash
+//! cargo bench --bench color_cache
+//! ```
+//!
+//! `with_begin_frame` resolves the theme colors once per frame with
+//! [`begin_frame`]; `without_begin_frame` marks the cache stale before every
+//! frame, so each color lookup locks the global theme and parses hex strings.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use egui_material3::theme::{begin_frame, invalidate_theme_cache};
+use egui_material3::{assist_chip, MaterialButton, MaterialCheckbox, MaterialSwitch};
+
+/// Rows of the gallery; each row shows four widgets
+const ROWS: usize = 100;
+
+fn render_gallery(ctx: &egui::Context) {
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            for row in 0..ROWS {
+                ui.horizontal(|ui| {
+                    let mut checked = row % 2 == 0;
+                    let mut on = row % 3 == 0;
+                    ui.add(MaterialButton::filled("Button"));
+                    ui.add(MaterialCheckbox::new(&mut checked, "Checkbox"));
+                    ui.add(MaterialSwitch::new(&mut on));
+                    ui.add(assist_chip("Chip"));
+                });
+            }
+        });
+    });
+}
+
+fn dense_gallery(c: &mut Criterion) {
+    let ctx = egui::Context::default();
+    let mut group = c.benchmark_group("dense_gallery");
+    group.bench_function("with_begin_frame", |b| {
+        b.iter(|| {
+            begin_frame();
+            render_gallery(&ctx);
+        })
+    });
+    group.bench_function("without_begin_frame", |b| {
+        b.iter(|| {
+            invalidate_theme_cache();
+            render_gallery(&ctx);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, dense_gallery);
+criterion_main!(benches);
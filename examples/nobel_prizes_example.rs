@@ -126,12 +126,10 @@ impl NobelPrizesApp {
     fn load_custom_theme(&mut self, file_path: &str) {
         match self.load_theme_from_file(file_path) {
             Ok(theme_file) => {
-                let theme_context = MaterialThemeContext {
-                    theme_mode: ThemeMode::Light,
-                    contrast_level: ContrastLevel::Normal,
-                    material_theme: Some(theme_file),
-                    selected_colors: std::collections::HashMap::new(),
-                };
+                let mut theme_context = MaterialThemeContext::default();
+                theme_context.theme_mode = ThemeMode::Light;
+                theme_context.contrast_level = ContrastLevel::Normal;
+                theme_context.material_theme = Some(theme_file);
                 update_global_theme(theme_context);
                 self.theme_loaded = true;
                 println!("Custom theme loaded successfully!");
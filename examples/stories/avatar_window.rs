@@ -0,0 +1,101 @@
+#![doc(hidden)]
+
+use crate::{list, list_item, AvatarShape, AvatarSize, MaterialAvatar, MaterialCheckbox};
+use eframe::egui::{self, Color32, Ui, Window};
+
+#[doc(hidden)]
+pub struct AvatarWindow {
+    pub open: bool,
+    rounded: bool,
+    show_status: bool,
+    name: String,
+}
+
+impl Default for AvatarWindow {
+    fn default() -> Self {
+        Self {
+            open: false,
+            rounded: false,
+            show_status: true,
+            name: "Ada Lovelace".to_string(),
+        }
+    }
+}
+
+impl AvatarWindow {
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let mut open = self.open;
+        Window::new("Avatar Stories")
+            .open(&mut open)
+            .default_size([600.0, 500.0])
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    self.render_controls(ui);
+                    ui.add_space(20.0);
+                    self.render_sizes(ui);
+                    ui.add_space(20.0);
+                    self.render_list(ui);
+                });
+            });
+        self.open = open;
+    }
+
+    fn render_controls(&mut self, ui: &mut Ui) {
+        ui.heading("Avatar Controls");
+        ui.horizontal(|ui| {
+            ui.add(MaterialCheckbox::new(&mut self.rounded, "Rounded square"));
+            ui.add(MaterialCheckbox::new(&mut self.show_status, "Status dot"));
+        });
+        ui.horizontal(|ui| {
+            ui.label("Name:");
+            ui.text_edit_singleline(&mut self.name);
+        });
+    }
+
+    fn styled(&self, avatar: MaterialAvatar) -> MaterialAvatar {
+        let avatar = if self.rounded {
+            avatar.shape(AvatarShape::RoundedSquare)
+        } else {
+            avatar
+        };
+        if self.show_status {
+            avatar.status(Color32::from_rgb(0x2E, 0x7D, 0x32))
+        } else {
+            avatar
+        }
+    }
+
+    fn render_sizes(&self, ui: &mut Ui) {
+        ui.heading("Sizes");
+        for size in [AvatarSize::Small, AvatarSize::Medium, AvatarSize::Large] {
+            ui.horizontal(|ui| {
+                ui.add(self.styled(MaterialAvatar::initials(&self.name).size(size)));
+                ui.add(self.styled(MaterialAvatar::icon("person").size(size)));
+                ui.add(
+                    self.styled(
+                        MaterialAvatar::image("https://picsum.photos/id/64/112").size(size),
+                    ),
+                );
+            });
+        }
+    }
+
+    fn render_list(&self, ui: &mut Ui) {
+        ui.heading("In a List");
+        let contacts = [
+            "Ada Lovelace",
+            "Alan Turing",
+            "Grace Hopper",
+            "Edsger Dijkstra",
+        ];
+        let mut contacts_list = list().id("avatar_contacts_list");
+        for name in contacts {
+            contacts_list = contacts_list.item(
+                list_item(name)
+                    .secondary_text("Same name, same color")
+                    .leading_avatar(self.styled(MaterialAvatar::initials(name))),
+            );
+        }
+        ui.add(contacts_list);
+    }
+}
@@ -1,6 +1,6 @@
 #![doc(hidden)]
 
-use crate::{badge, badge_dot, BadgeColor, BadgePosition, BadgeSize, MaterialButton, MaterialIconButton, MaterialFab, FabSize, noto_emoji};
+use crate::{badge, badge_dot, with_badge, BadgeColor, BadgePosition, BadgeSize, MaterialBadge, MaterialButton, MaterialIconButton, MaterialFab, FabSize, noto_emoji};
 use eframe::egui::{self, Window};
 
 #[doc(hidden)]
@@ -15,6 +15,9 @@ pub struct BadgeWindow {
     inbox_count: i32,
     calendar_count: i32,
     upload_count: i32,
+    // Count badge overlay demo
+    overlay_count: u32,
+    overlay_rtl: bool,
 }
 
 impl Default for BadgeWindow {
@@ -28,6 +31,8 @@ impl Default for BadgeWindow {
             inbox_count: 5,
             calendar_count: 7,
             upload_count: 1,
+            overlay_count: 120,
+            overlay_rtl: false,
         }
     }
 }
@@ -148,6 +153,36 @@ impl BadgeWindow {
 
         ui.add_space(20.0);
 
+        // Count badges overlaid with `with_badge`
+        ui.heading("Count Badges (with_badge)");
+        ui.horizontal(|ui| {
+            ui.label("Count:");
+            ui.add(egui::Slider::new(&mut self.overlay_count, 0..=200));
+            ui.checkbox(&mut self.overlay_rtl, "Right-to-left position");
+        });
+        ui.horizontal_wrapped(|ui| {
+            let position = BadgePosition::trailing_top(self.overlay_rtl);
+            with_badge(
+                ui,
+                MaterialIconButton::standard(noto_emoji::ENVELOPE).size(48.0),
+                MaterialBadge::count(self.overlay_count).position(position),
+            );
+            with_badge(
+                ui,
+                MaterialIconButton::filled(noto_emoji::BELL).size(48.0),
+                MaterialBadge::count(self.overlay_count)
+                    .max_count(9)
+                    .position(position),
+            );
+            with_badge(
+                ui,
+                MaterialIconButton::filled_tonal(noto_emoji::CALENDAR).size(48.0),
+                MaterialBadge::dot().position(position),
+            );
+        });
+
+        ui.add_space(20.0);
+
         // Badges on FABs
         ui.heading("Badges on FABs");
         ui.horizontal_wrapped(|ui| {
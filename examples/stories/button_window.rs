@@ -1,6 +1,6 @@
 #![doc(hidden)]
 
-use crate::MaterialButton;
+use crate::{MaterialBadge, MaterialButton, MaterialButtonVariant};
 #[cfg(feature = "svg_emoji")]
 use crate::svg_emoji::SOLAR_ICONS;
 use eframe::egui::{self, epaint::Stroke, Ui, Vec2, Window};
@@ -11,6 +11,7 @@ pub struct ButtonWindow {
     label: String,
     disabled: bool,
     soft_disabled: bool,
+    loading: bool,
 }
 
 impl Default for ButtonWindow {
@@ -20,6 +21,7 @@ impl Default for ButtonWindow {
             label: String::new(),
             disabled: false,
             soft_disabled: false,
+            loading: false,
         }
     }
 }
@@ -42,6 +44,8 @@ impl ButtonWindow {
                     ui.add_space(20.0);
                     self.render_buttons_with_both_icons(ui);
                     ui.add_space(20.0);
+                    self.render_icon_only_buttons(ui);
+                    ui.add_space(20.0);
                     self.render_small_buttons(ui);
                     ui.add_space(20.0);
                     self.render_small_buttons_with_svg_icons(ui);
@@ -53,6 +57,8 @@ impl ButtonWindow {
                     self.render_custom_size_buttons(ui);
                     ui.add_space(20.0);
                     self.render_custom_corner_radius_buttons(ui);
+                    ui.add_space(20.0);
+                    self.render_custom_content_buttons(ui);
                 });
             });
         self.open = open;
@@ -74,6 +80,7 @@ impl ButtonWindow {
 
         ui.checkbox(&mut self.disabled, "Disabled");
         ui.checkbox(&mut self.soft_disabled, "Soft Disabled");
+        ui.checkbox(&mut self.loading, "Loading");
     }
 
     fn is_disabled(&self) -> bool {
@@ -92,10 +99,12 @@ impl ButtonWindow {
         ui.heading("Button Variants");
 
         let disabled = self.is_disabled();
+        let loading = self.loading;
 
         ui.horizontal(|ui| {
             for (label, button) in self.all_variants() {
                 let button = if disabled { button.enabled(false) } else { button };
+                let button = button.loading(loading);
                 if ui.add(button).clicked() && !disabled {
                     println!("{label} button clicked!");
                 }
@@ -153,6 +162,29 @@ impl ButtonWindow {
         });
     }
 
+    fn render_icon_only_buttons(&mut self, ui: &mut Ui) {
+        ui.heading("Icon-only Buttons");
+
+        let disabled = self.is_disabled();
+        let variants = [
+            ("Filled", MaterialButtonVariant::Filled),
+            ("Outlined", MaterialButtonVariant::Outlined),
+            ("Elevated", MaterialButtonVariant::Elevated),
+            ("Tonal", MaterialButtonVariant::FilledTonal),
+            ("Text", MaterialButtonVariant::Text),
+        ];
+
+        ui.horizontal(|ui| {
+            for (label, variant) in variants {
+                let button = MaterialButton::icon("add").variant(variant);
+                let button = if disabled { button.enabled(false) } else { button };
+                if ui.add(button).clicked() && !disabled {
+                    println!("{label} icon-only button clicked!");
+                }
+            }
+        });
+    }
+
     fn render_small_buttons(&mut self, ui: &mut Ui) {
         ui.heading("Small Buttons");
 
@@ -336,6 +368,24 @@ impl ButtonWindow {
         });
     }
 
+    fn render_custom_content_buttons(&mut self, ui: &mut Ui) {
+        ui.heading("Rich and Custom Content Buttons");
+
+        ui.horizontal(|ui| {
+            let _ = ui.add(MaterialButton::filled(
+                egui::RichText::new(self.label_or("Strong")).strong(),
+            ));
+            let _ = ui.add(
+                MaterialButton::custom(|ui| {
+                    ui.label("Inbox");
+                    ui.add(MaterialBadge::new("3"));
+                })
+                .variant(MaterialButtonVariant::FilledTonal)
+                .enabled(!self.disabled),
+            );
+        });
+    }
+
     fn render_custom_corner_radius_buttons(&mut self, ui: &mut Ui) {
         ui.heading("Custom Corner Radius Buttons");
 
@@ -27,6 +27,9 @@ pub struct Card2Window {
     next_card_id: usize,
     edit_dialog_open: bool,
     editing_card: Option<CustomCard>,
+    // Interactive cards
+    board: [Vec<String>; 2],
+    last_clicked: String,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -87,6 +90,11 @@ impl Default for Card2Window {
             next_card_id: 1,
             edit_dialog_open: false,
             editing_card: None,
+            board: [
+                vec!["Write release notes".to_string(), "Review PR".to_string()],
+                vec!["Update icons".to_string()],
+            ],
+            last_clicked: String::new(),
         }
     }
 }
@@ -104,6 +112,8 @@ impl Card2Window {
                     ui.add_space(20.0);
                     self.render_elevation_demo(ui);
                     ui.add_space(20.0);
+                    self.render_interactive_demo(ui);
+                    ui.add_space(20.0);
                     self.render_card2_examples(ui);
                 });
             });
@@ -272,6 +282,52 @@ impl Card2Window {
                 }
             });
     }
+    fn render_interactive_demo(&mut self, ui: &mut Ui) {
+        ui.heading("Interactive Cards");
+        ui.label("Click a card, or drag it to the other column.");
+
+        let mut column_rects = [egui::Rect::NOTHING; 2];
+        let mut dropped = None;
+        ui.horizontal_top(|ui| {
+            for (column, title) in ["To do", "Done"].into_iter().enumerate() {
+                let column_response = ui.vertical(|ui| {
+                    ui.set_width(260.0);
+                    ui.strong(title);
+                    for (index, task) in self.board[column].iter().enumerate() {
+                        let card = outlined_card2()
+                            .header(task, None::<String>)
+                            .min_size(egui::vec2(240.0, 124.0))
+                            .clickable(true)
+                            .draggable(true);
+                        let response = ui
+                            .allocate_ui(egui::vec2(250.0, 132.0), |ui| ui.add(card))
+                            .inner;
+                        if response.clicked() {
+                            self.last_clicked = task.clone();
+                        }
+                        if response.drag_stopped() {
+                            let pos = ui.input(|i| i.pointer.latest_pos());
+                            dropped = pos.map(|pos| (column, index, pos));
+                        }
+                    }
+                });
+                column_rects[column] = column_response.response.rect;
+            }
+        });
+
+        if let Some((from, index, pos)) = dropped {
+            if let Some(to) = column_rects.iter().position(|rect| rect.contains(pos)) {
+                if to != from {
+                    let task = self.board[from].remove(index);
+                    self.board[to].push(task);
+                }
+            }
+        }
+        if !self.last_clicked.is_empty() {
+            ui.label(format!("Last clicked: {}", self.last_clicked));
+        }
+    }
+
     fn render_card2_examples(&mut self, ui: &mut Ui) {
         ui.heading("Enhanced Card Variants");
 
@@ -305,7 +361,7 @@ impl Card2Window {
             }
 
             if self.show_media {
-                elevated_card = elevated_card.media_area(|ui| {
+                elevated_card = elevated_card.media(|ui| {
                     ui.centered_and_justified(|ui| {
                         ui.label("📷 Media Content");
                         ui.label("Image or video would go here");
@@ -1,6 +1,6 @@
 #![doc(hidden)]
 
-use crate::{checkbox, MaterialButton, MaterialCheckbox};
+use crate::{checkbox, CheckState, MaterialButton, MaterialCheckbox};
 use eframe::egui::{self, Window};
 
 #[doc(hidden)]
@@ -29,6 +29,10 @@ pub struct CheckboxWindow {
     error_normal: bool,
     error_error: bool,
     error_error_checked: bool,
+    // For the select-all example
+    notify_email: bool,
+    notify_push: bool,
+    notify_sms: bool,
 }
 
 impl Default for CheckboxWindow {
@@ -56,6 +60,9 @@ impl Default for CheckboxWindow {
             error_normal: false,
             error_error: false,
             error_error_checked: true,
+            notify_email: true,
+            notify_push: false,
+            notify_sms: false,
         }
     }
 }
@@ -92,6 +99,11 @@ impl CheckboxWindow {
                     ui.add_space(20.0);
                     
                     self.render_interactive_example(ui);
+                    ui.add_space(20.0);
+                    ui.separator();
+                    ui.add_space(20.0);
+
+                    self.render_select_all(ui);
                 });
             });
         self.open = open;
@@ -245,4 +257,28 @@ impl CheckboxWindow {
             }
         });
     }
+
+    fn render_select_all(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Select All");
+        ui.label("A tri-state parent is indeterminate while only some children are checked.");
+        ui.add_space(10.0);
+
+        let mut all =
+            CheckState::from_children([self.notify_email, self.notify_push, self.notify_sms]);
+        if ui
+            .add(MaterialCheckbox::tristate(&mut all, "All notifications"))
+            .changed()
+        {
+            let checked = all == CheckState::Checked;
+            self.notify_email = checked;
+            self.notify_push = checked;
+            self.notify_sms = checked;
+        }
+
+        ui.indent("select_all_children", |ui| {
+            ui.add(checkbox(&mut self.notify_email, "Email"));
+            ui.add(checkbox(&mut self.notify_push, "Push"));
+            ui.add(checkbox(&mut self.notify_sms, "SMS"));
+        });
+    }
 }
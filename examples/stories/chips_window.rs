@@ -1,6 +1,7 @@
 #![doc(hidden)]
 
-use crate::{assist_chip, filter_chip, image_utils, input_chip, suggestion_chip, MaterialButton, MaterialCheckbox};
+use crate::theme::{get_reduced_motion, set_reduced_motion};
+use crate::{assist_chip, filter_chip, image_utils, input_chip, suggestion_chip, MaterialButton, MaterialCheckbox, MaterialChipSet};
 #[cfg(feature = "svg_emoji")]
 use crate::svg_emoji::SOLAR_ICONS;
 use eframe::egui::{self, Window};
@@ -17,6 +18,9 @@ pub struct ChipsWindow {
     filter_icon_selected: bool,
     filter_removable_selected: bool,
     filter_elevated_selected: bool,
+    // Removable tags demo
+    tags: Vec<String>,
+    tag_max_rows: usize,
 }
 
 impl Default for ChipsWindow {
@@ -31,6 +35,8 @@ impl Default for ChipsWindow {
             filter_icon_selected: false,
             filter_removable_selected: true,
             filter_elevated_selected: false,
+            tags: default_tags(),
+            tag_max_rows: 2,
         }
     }
 }
@@ -444,6 +450,44 @@ impl ChipsWindow {
             // Soft-disabled
             ui.add(input_chip(l("Soft-disabled")).soft_disabled(true));
         });
+
+        ui.add_space(8.0);
+        ui.label("Removable tags in a chip set (shrink and fade out before being deleted):");
+        ui.horizontal(|ui| {
+            ui.label("Max rows:");
+            ui.add(egui::Slider::new(&mut self.tag_max_rows, 1..=4));
+        });
+        let chips = self
+            .tags
+            .iter()
+            .map(|tag| {
+                input_chip(tag.as_str())
+                    .id_salt(format!("tag_{}", tag))
+                    .removable(true)
+            })
+            .collect();
+        let response = MaterialChipSet::new(chips)
+            .id_salt("tag_chip_set")
+            .max_rows(self.tag_max_rows)
+            .show(ui);
+        if let Some(index) = response.clicked {
+            println!("Tag clicked: {}", self.tags[index]);
+        }
+        if let Some(index) = response.removed {
+            self.tags.remove(index);
+        }
+        ui.horizontal(|ui| {
+            if ui.add(MaterialButton::outlined("Reset tags").small()).clicked() {
+                self.tags = default_tags();
+            }
+            let mut reduced_motion = get_reduced_motion();
+            if ui
+                .add(MaterialCheckbox::new(&mut reduced_motion, "Reduced Motion"))
+                .changed()
+            {
+                set_reduced_motion(reduced_motion);
+            }
+        });
     }
 
     fn render_suggestion_chips(&mut self, ui: &mut egui::Ui) {
@@ -486,3 +530,22 @@ impl ChipsWindow {
         });
     }
 }
+
+const DEFAULT_TAGS: &[&str] = &[
+    "rust",
+    "egui",
+    "material",
+    "design",
+    "widgets",
+    "chips",
+    "layout",
+    "wrap",
+    "overflow",
+    "tags",
+    "filters",
+    "animation",
+];
+
+fn default_tags() -> Vec<String> {
+    DEFAULT_TAGS.iter().map(|tag| tag.to_string()).collect()
+}
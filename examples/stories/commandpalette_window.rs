@@ -0,0 +1,90 @@
+#![doc(hidden)]
+
+use crate::theme::Shortcut;
+use crate::{Command, MaterialButton, MaterialCommandPalette};
+use eframe::egui::{self, Key, Modifiers, Window};
+
+#[doc(hidden)]
+pub struct CommandPaletteWindow {
+    pub open: bool,
+    palette_open: bool,
+    last_command: String,
+}
+
+impl Default for CommandPaletteWindow {
+    fn default() -> Self {
+        Self {
+            open: false,
+            palette_open: false,
+            last_command: "None".to_string(),
+        }
+    }
+}
+
+impl CommandPaletteWindow {
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let mut open = self.open;
+        Window::new("Command Palette Stories")
+            .open(&mut open)
+            .default_size([600.0, 300.0])
+            .show(ctx, |ui| {
+                ui.heading("Command Palette");
+                ui.label(format!(
+                    "Press {} or the button below, then type to filter. \
+                     Up/Down move the highlight and Enter runs the command.",
+                    Shortcut::command(Key::K)
+                        .format_for(ctx.os() == egui::os::OperatingSystem::Mac)
+                ));
+                ui.add_space(8.0);
+                if ui.add(MaterialButton::filled("Open Palette")).clicked() {
+                    self.palette_open = true;
+                }
+                ui.add_space(8.0);
+                ui.label(format!("Last command: {}", self.last_command));
+            });
+        self.open = open;
+
+        if !self.open {
+            return;
+        }
+        let chosen = MaterialCommandPalette::new(&mut self.palette_open)
+            .id_salt("stories")
+            .open_shortcut(Shortcut::command(Key::K))
+            .commands(commands())
+            .show(ctx);
+        if let Some(id) = chosen {
+            self.last_command = id;
+        }
+    }
+}
+
+fn commands() -> Vec<Command> {
+    let shift_command = Modifiers::COMMAND | Modifiers::SHIFT;
+    vec![
+        Command::new("new_file", "New File")
+            .icon("note_add")
+            .shortcut(Shortcut::command(Key::N)),
+        Command::new("open_file", "Open File…")
+            .icon("folder_open")
+            .shortcut(Shortcut::command(Key::O)),
+        Command::new("save", "Save")
+            .icon("save")
+            .shortcut(Shortcut::command(Key::S)),
+        Command::new("save_as", "Save As…")
+            .icon("save_as")
+            .shortcut(Shortcut::new(shift_command, Key::S)),
+        Command::new("new_window", "New Window").icon("open_in_new"),
+        Command::new("close_window", "Close Window")
+            .icon("close")
+            .shortcut(Shortcut::command(Key::W)),
+        Command::new("find", "Find in Files")
+            .icon("search")
+            .shortcut(Shortcut::new(shift_command, Key::F)),
+        Command::new("toggle_theme", "Toggle Dark Theme").icon("dark_mode"),
+        Command::new("format", "Format Document").icon("format_align_left"),
+        Command::new("settings", "Open Settings")
+            .icon("settings")
+            .shortcut(Shortcut::command(Key::Comma)),
+        Command::new("shortcuts", "Keyboard Shortcuts").icon("keyboard"),
+    ]
+}
@@ -1,7 +1,12 @@
 #![doc(hidden)]
 
-use crate::datatable::{RowAction, SortDirection as DataTableSortDirection};
-use crate::{data_table, DataTableCell, DataTableTheme, MaterialButton, MaterialCheckbox};
+use crate::datatable::{
+    DataTableState, EditKind, RowAction, SortDirection as DataTableSortDirection,
+};
+use crate::{
+    assist_chip, data_table, linear_progress, DataTableCell, DataTableTheme, MaterialButton,
+    MaterialCheckbox,
+};
 use eframe::egui::{self, Color32, Id, Ui, Window};
 use std::collections::{HashMap, HashSet};
 
@@ -48,6 +53,8 @@ pub struct DataTableWindow {
     sort_direction: SortDirection,
     // Track selection state for drawer table
     drawer_selection: Vec<bool>,
+    // Only one expanded row at a time in the drawer table
+    accordion: bool,
 }
 
 impl Default for DataTableWindow {
@@ -104,6 +111,7 @@ impl Default for DataTableWindow {
             sort_column: None,
             sort_direction: SortDirection::Ascending,
             drawer_selection: vec![false; 4], // 4 rows in the drawer table
+            accordion: false,
         }
     }
 }
@@ -232,9 +240,12 @@ impl DataTableWindow {
             ui.add_space(20.0);
             
             ui.heading("Data Table with Sortable Columns");
+            ui.label("Drag the right edge of a column header to resize it.");
+            ui.label("Shift-click more headers to add secondary sort keys.");
             
             let sortable_table = data_table()
                 .id(Id::new("sortable_data_table"))
+                .resizable_columns(true)
                 .sortable_column("Signal name", 200.0, false)
                 .sortable_column("Status", 100.0, false)
                 .sortable_column("Severity", 100.0, false)
@@ -322,7 +333,9 @@ impl DataTableWindow {
             ui.add_space(20.0);
         
         ui.heading("Interactive Data Table Demo");
+        ui.label("Click a row to select it, ctrl/cmd-click to toggle, shift-click to select a range.");
         
+        let mut export_requested = false;
         ui.horizontal(|ui| {
             if ui.add(MaterialButton::filled("Add Row")).clicked() {
                 // Add a new row with sample data
@@ -363,19 +376,7 @@ impl DataTableWindow {
                 }
             }
             
-            if ui.add(MaterialButton::text("Export")).clicked() {
-                // Export data (in real implementation, this could save to CSV, etc.)
-                let export_data: Vec<String> = self.interactive_rows
-                    .iter()
-                    .map(|row| format!("{},{},{},{}", row.product, row.category, row.price, row.stock))
-                    .collect();
-                
-                println!("Exported data:");
-                println!("Product,Category,Price,Stock");
-                for line in export_data {
-                    println!("{}", line);
-                }
-            }
+            export_requested = ui.add(MaterialButton::text("Export")).clicked();
         });
 
         ui.add_space(10.0);
@@ -388,8 +389,14 @@ impl DataTableWindow {
             .id(Id::new("interactive_data_table"))
             .column("Product", 180.0, false)  // All columns are sortable by default now
             .column("Category", 120.0, false)
+            .column_edit_kind(EditKind::Dropdown(vec![
+                "Electronics".to_string(),
+                "Accessories".to_string(),
+                "Furniture".to_string(),
+            ]))
             .column("Price", 100.0, true)
             .column("Stock", 80.0, true)
+            .column_edit_kind(EditKind::Number)
             .column("Actions", 140.0, false)  // Add Actions column
             .allow_selection(true);
 
@@ -434,6 +441,16 @@ impl DataTableWindow {
                 (self.editing_rows.clone(), self.edit_data.clone()));
         });
         
+        if export_requested {
+            // Only the checked rows are exported when any are selected
+            let state = DataTableState::load(ui.ctx(), Id::new("interactive_data_table"));
+            interactive_table = interactive_table
+                .export_selected_only(!state.selected_rows.is_empty())
+                .export_state(&state);
+            println!("Exported data:");
+            print!("{}", interactive_table.to_csv());
+        }
+
         // Show the table and get the selection state back
         let table_response = interactive_table.show(ui);
         
@@ -445,6 +462,10 @@ impl DataTableWindow {
             self.edit_data = updated_edit_data;
         }
         
+        for edit in &table_response.cell_edits {
+            println!("Row {} column {} changed to {:?}", edit.row, edit.column, edit.value);
+        }
+
         // Process row actions from the data table
         for action in &table_response.row_actions {
             match action {
@@ -540,6 +561,13 @@ impl DataTableWindow {
             self.sort_column = None;
         }
         
+        if table_response.selection_changed {
+            println!(
+                "Selected {:?}, deselected {:?}",
+                table_response.newly_selected, table_response.newly_deselected
+            );
+        }
+
         // Sync the selection state back to our window state
         if table_response.selected_rows.len() == self.interactive_selection.len() {
             self.interactive_selection = table_response.selected_rows;
@@ -690,6 +718,35 @@ impl DataTableWindow {
             });
         
         ui.add(placeholder_table);
+
+        ui.add_space(20.0);
+        ui.separator();
+        ui.heading("Widget Cells");
+        ui.label("Cells with chips and progress bars; the progress column sorts by its value");
+
+        let widget_table = data_table()
+            .id(Id::new("widget_cell_table"))
+            .column("Project", 160.0, false)
+            .column("Owner", 140.0, false)
+            .column("Progress", 160.0, true)
+            .row(|row| {
+                row.cell("Website redesign")
+                    .widget_cell(|ui| ui.add(assist_chip("Design")))
+                    .custom_cell(
+                        DataTableCell::widget(|ui| ui.add(linear_progress(0.8).width(120.0)))
+                            .value("0.8"),
+                    )
+            })
+            .row(|row| {
+                row.cell("Mobile app")
+                    .widget_cell(|ui| ui.add(assist_chip("Engineering")))
+                    .custom_cell(
+                        DataTableCell::widget(|ui| ui.add(linear_progress(0.35).width(120.0)))
+                            .value("0.35"),
+                    )
+            });
+
+        ui.add(widget_table);
         
         ui.add_space(20.0);
         ui.separator();
@@ -862,8 +919,9 @@ impl DataTableWindow {
 
         ui.add_space(20.0);
         ui.separator();
-        ui.heading("Data Table with Drawer");
-        ui.label("Click the > arrow on a row to expand its drawer panel. Click v to collapse.");
+        ui.heading("Data Table with Expandable Rows");
+        ui.label("Click the chevron on a row to expand its detail panel. Click it again to collapse.");
+        ui.checkbox(&mut self.accordion, "Accordion (one expanded row at a time)");
 
         // Ensure drawer_selection length matches row count
         self.drawer_selection.resize(4, false);
@@ -871,7 +929,7 @@ impl DataTableWindow {
         let drawer_table = data_table()
             .id(Id::new("drawer_table"))
             .allow_selection(self.allow_selection)
-            .allow_drawer(true)
+            .accordion(self.accordion)
             .column("Product", 180.0, false)
             .column("Category", 120.0, false)
             .column("Price", 100.0, true)
@@ -881,8 +939,9 @@ impl DataTableWindow {
                    .cell("Electronics")
                    .cell("$1299")
                    .cell("15")
+                   .id("laptop-pro")
                    .selected(self.drawer_selection[0])
-                   .drawer(|ui| {
+                   .expandable(|ui| {
                        ui.add_space(8.0);
                        ui.label("High-performance laptop with 16 GB RAM, 512 GB SSD, 14\" display.");
                        ui.label("SKU: LAP-001 · Weight: 1.8 kg · Color: Silver");
@@ -894,15 +953,16 @@ impl DataTableWindow {
                    .cell("Accessories")
                    .cell("$39")
                    .cell("200")
+                   .id("wireless-mouse")
                    .selected(self.drawer_selection[1])
-                   .drawer(|ui| {
+                   .expandable(|ui| {
                        ui.add_space(8.0);
                        ui.label("Ergonomic wireless mouse with 3-year battery life.");
                        ui.label("SKU: MOU-007 · DPI: 400–4000 · Color: Black");
                    })
             })
             .row(|row| {
-                // Row without a drawer — no arrow shown
+                // Row without detail — no chevron shown
                 row.cell("HDMI Cable")
                    .cell("Accessories")
                    .cell("$12")
@@ -914,8 +974,9 @@ impl DataTableWindow {
                    .cell("Accessories")
                    .cell("$129")
                    .cell("45")
+                   .id("mechanical-keyboard")
                    .selected(self.drawer_selection[3])
-                   .drawer(|ui| {
+                   .expandable(|ui| {
                        ui.add_space(8.0);
                        ui.label("Tenkeyless layout, Cherry MX Brown switches, per-key RGB.");
                        ui.label("SKU: KEY-042 · Connection: USB-C + Bluetooth · Color: Space Gray");
@@ -930,7 +991,7 @@ impl DataTableWindow {
         }
 
         ui.add_space(10.0);
-        ui.label("Note: rows without a .drawer() call show no arrow and never expand.");
+        ui.label("Note: rows without an .expandable() call show no chevron and never expand.");
         });
     }
 }
@@ -0,0 +1,80 @@
+#![doc(hidden)]
+
+use crate::{MaterialButton, MaterialDatePicker};
+use eframe::egui::{self, Ui, Window};
+
+#[doc(hidden)]
+pub struct DatePickerWindow {
+    pub open: bool,
+    dialog_open: bool,
+    limit_years: bool,
+    modal_date: Option<(i32, u32, u32)>,
+    docked_date: Option<(i32, u32, u32)>,
+}
+
+impl Default for DatePickerWindow {
+    fn default() -> Self {
+        Self {
+            open: false,
+            dialog_open: false,
+            limit_years: false,
+            modal_date: None,
+            docked_date: Some((2024, 2, 29)),
+        }
+    }
+}
+
+impl DatePickerWindow {
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let mut open = self.open;
+        Window::new("Date Picker Stories")
+            .open(&mut open)
+            .default_size([500.0, 500.0])
+            .show(ctx, |ui| {
+                self.render_controls(ui);
+                ui.add_space(20.0);
+                self.render_pickers(ui);
+            });
+        self.open = open;
+
+        let mut picker = MaterialDatePicker::new("stories_date_picker", &mut self.modal_date);
+        if self.limit_years {
+            picker = picker.year_range(2020..=2030);
+        }
+        picker.show(ctx, &mut self.dialog_open);
+    }
+
+    fn render_controls(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.heading("Date Picker Controls");
+
+            if ui.add(MaterialButton::filled("Target").small()).clicked() {
+                let _ = webbrowser::open("https://m3.material.io/components/date-pickers/overview");
+            }
+        });
+
+        ui.checkbox(&mut self.limit_years, "Limit years to 2020–2030");
+    }
+
+    fn render_pickers(&mut self, ui: &mut Ui) {
+        ui.heading("Modal");
+        ui.horizontal(|ui| {
+            if ui.add(MaterialButton::filled("Pick a date")).clicked() {
+                self.dialog_open = true;
+            }
+            ui.label(match self.modal_date {
+                Some((year, month, day)) => format!("Selected: {year}-{month:02}-{day:02}"),
+                None => "Nothing selected".to_string(),
+            });
+        });
+        ui.add_space(20.0);
+
+        ui.heading("Docked");
+        let mut picker = MaterialDatePicker::new("stories_docked_date", &mut self.docked_date)
+            .label("Departure");
+        if self.limit_years {
+            picker = picker.year_range(2020..=2030);
+        }
+        picker.show_docked(ui);
+    }
+}
@@ -1,6 +1,6 @@
 #![doc(hidden)]
 
-use crate::{dialog, MaterialButton};
+use crate::{dialog, MaterialButton, MaterialDialog};
 use eframe::egui::{self, Window};
 
 #[doc(hidden)]
@@ -24,6 +24,11 @@ pub struct DialogWindow {
     long_content_dialog_open: bool,
     custom_padding_dialog_open: bool,
     multi_action_dialog_open: bool,
+    full_screen_dialog_open: bool,
+    draggable_dialog_open: bool,
+    nested_edit_dialog_open: bool,
+    discard_confirm_open: bool,
+    last_dialog_result: String,
     // Form data
     pet_choice: Option<usize>,
     first_name: String,
@@ -64,6 +69,11 @@ impl Default for DialogWindow {
             long_content_dialog_open: false,
             custom_padding_dialog_open: false,
             multi_action_dialog_open: false,
+            full_screen_dialog_open: false,
+            draggable_dialog_open: false,
+            nested_edit_dialog_open: false,
+            discard_confirm_open: false,
+            last_dialog_result: String::new(),
             pet_choice: Some(0),
             first_name: String::new(),
             last_name: String::new(),
@@ -239,7 +249,23 @@ impl DialogWindow {
             if ui.add(MaterialButton::filled_tonal("Scrollable Settings")).clicked() {
                 self.settings_dialog_open = true;
             }
+
+            if ui.add(MaterialButton::filled_tonal("Full-screen Dialog")).clicked() {
+                self.full_screen_dialog_open = true;
+            }
+
+            if ui.add(MaterialButton::filled_tonal("Draggable Dialog")).clicked() {
+                self.draggable_dialog_open = true;
+            }
+
+            if ui.add(MaterialButton::filled_tonal("Nested Dialogs")).clicked() {
+                self.nested_edit_dialog_open = true;
+            }
         });
+
+        if !self.last_dialog_result.is_empty() {
+            ui.label(format!("Last result: {}", self.last_dialog_result));
+        }
     }
 
     fn show_dialogs(&mut self, ctx: &egui::Context) {
@@ -504,6 +530,98 @@ impl DialogWindow {
                 .show(ctx);
         }
 
+        // Full-screen Dialog
+        if self.full_screen_dialog_open {
+            let result = MaterialDialog::full_screen(
+                "full_screen_dialog",
+                "New event",
+                &mut self.full_screen_dialog_open,
+            )
+            .no_focus_trap(self.no_focus_trap)
+            .content(|ui| {
+                ui.label("Event name");
+                ui.text_edit_singleline(&mut self.first_name);
+                ui.add_space(8.0);
+                ui.label("Location");
+                ui.text_edit_singleline(&mut self.company);
+                ui.add_space(8.0);
+                for i in 0..30 {
+                    ui.label(format!("Guest {}", i + 1));
+                }
+            })
+            .confirm_action("Save", || {
+                println!("Full-screen dialog Save clicked!");
+            })
+            .show(ctx);
+            if result.confirmed {
+                self.last_dialog_result = "full-screen dialog confirmed".to_string();
+            } else if result.dismissed {
+                self.last_dialog_result = "full-screen dialog dismissed".to_string();
+            }
+        }
+
+        // Draggable, non-dismissible Dialog
+        if self.draggable_dialog_open {
+            let result = dialog(
+                "draggable_dialog",
+                "Drag me by the title",
+                &mut self.draggable_dialog_open,
+            )
+            .draggable(true)
+            .dismissible(false)
+            .no_focus_trap(self.no_focus_trap)
+            .content(|ui| {
+                ui.label("This dialog can be moved by dragging its title.");
+                ui.label("Escape and clicking outside are ignored; choose an action.");
+            })
+            .action("Cancel", || {})
+            .confirm_action("OK", || {})
+            .show(ctx);
+            if result.confirmed {
+                self.last_dialog_result = "draggable dialog confirmed".to_string();
+            } else if result.dismissed {
+                self.last_dialog_result = "draggable dialog dismissed".to_string();
+            }
+        }
+
+        // Nested dialogs: a "Discard changes?" confirmation over an edit dialog.
+        // Only the top dialog draws a scrim and reacts to Escape.
+        if self.nested_edit_dialog_open {
+            dialog(
+                "nested_edit_dialog",
+                "Edit profile",
+                &mut self.nested_edit_dialog_open,
+            )
+            .dismissible(false)
+            .content(|ui| {
+                ui.label("First name");
+                ui.text_edit_singleline(&mut self.first_name);
+                ui.add_space(8.0);
+                if ui.add(MaterialButton::text("Discard changes…")).clicked() {
+                    self.discard_confirm_open = true;
+                }
+            })
+            .confirm_action("Save", || {})
+            .show(ctx);
+        }
+        if self.discard_confirm_open {
+            let result = dialog(
+                "discard_confirm_dialog",
+                "Discard changes?",
+                &mut self.discard_confirm_open,
+            )
+            .max_width(320.0)
+            .content(|ui| {
+                ui.label("Your edits to the profile will be lost.");
+            })
+            .action("Keep editing", || {})
+            .confirm_action("Discard", || {})
+            .show(ctx);
+            if result.confirmed {
+                self.nested_edit_dialog_open = false;
+            }
+        }
+
         // Scrollable Settings Dialog
         if self.settings_dialog_open {
             dialog(
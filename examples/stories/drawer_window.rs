@@ -1,7 +1,9 @@
 #![doc(hidden)]
 
-use crate::theme::get_global_color;
-use crate::{MaterialButton, MaterialCheckbox, DrawerItem};
+use crate::theme::{dismiss_on_escape, get_global_color};
+use crate::{
+    DrawerItem, DrawerVariant, MaterialBadge, MaterialButton, MaterialCheckbox, MaterialDrawer,
+};
 use eframe::egui::{self, Ui, Window};
 
 #[doc(hidden)]
@@ -23,6 +25,7 @@ pub struct DrawerWindow {
     // State for different drawer demos
     is_dismissible_sidebar_open: bool,
     is_modal_sidebar_open: bool,
+    viewport_modal_open: bool,
     selected_menu_item: String,
     dismissible_selected_menu_item: String,
     modal_selected_menu_item: String,
@@ -54,6 +57,7 @@ impl Default for DrawerWindow {
             use_material3: true,
             is_dismissible_sidebar_open: false,
             is_modal_sidebar_open: false,
+            viewport_modal_open: false,
             selected_menu_item: "Inbox".to_string(),
             dismissible_selected_menu_item: "Dashboard".to_string(),
             modal_selected_menu_item: "Home".to_string(),
@@ -63,15 +67,6 @@ impl Default for DrawerWindow {
 
 impl DrawerWindow {
     pub fn show(&mut self, ctx: &egui::Context) {
-        // Handle ESC key to close all drawers and the window
-        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
-            self.modal_drawer_open = false;
-            self.dismissible_drawer_open = false;
-            self.is_dismissible_sidebar_open = false;
-            self.is_modal_sidebar_open = false;
-            // Don't close the main window on ESC, only the drawers
-        }
-
         // Main drawer stories window
         let mut open = self.open;
         Window::new("Drawer Stories")
@@ -88,6 +83,43 @@ impl DrawerWindow {
 
         // Only show drawer demos when specifically requested and in demo mode
         self.show_active_drawer_demo(ctx);
+
+        // Shown every frame so it can slide out after closing
+        self.show_viewport_modal_drawer(ctx);
+
+        // Escape not taken by a drawer itself closes the demo drawers, but
+        // not the main window
+        let any_drawer_open = self.modal_drawer_open
+            || self.dismissible_drawer_open
+            || self.is_dismissible_sidebar_open
+            || self.is_modal_sidebar_open;
+        if any_drawer_open && dismiss_on_escape(ctx) {
+            self.modal_drawer_open = false;
+            self.dismissible_drawer_open = false;
+            self.is_dismissible_sidebar_open = false;
+            self.is_modal_sidebar_open = false;
+        }
+    }
+
+    fn show_viewport_modal_drawer(&mut self, ctx: &egui::Context) {
+        let items = self.create_mail_items();
+        let names: Vec<String> = items.iter().map(|item| item.text.clone()).collect();
+        let mut drawer = MaterialDrawer::new_with_id(
+            DrawerVariant::Modal,
+            &mut self.viewport_modal_open,
+            egui::Id::new("viewport_modal_drawer"),
+        )
+        .width(self.drawer_width)
+        .elevation(self.drawer_elevation)
+        .corner_radius(self.corner_radius.min(28.0) as u8)
+        .scrim_opacity(self.scrim_opacity)
+        .items(items);
+        if self.show_header {
+            drawer = drawer.header("Mail", Some("email@material.io"));
+        }
+        if let Some(index) = drawer.show(ctx).clicked {
+            self.modal_selected_menu_item = names[index].clone();
+        }
     }
 
     fn show_active_drawer_demo(&mut self, ctx: &egui::Context) {
@@ -134,56 +166,37 @@ impl DrawerWindow {
                         |ui| {
                             ui.set_width(drawer_width);
                             
-                            egui::ScrollArea::vertical()
-                                .id_salt("permanent_drawer_scroll")
-                                .show(ui, |ui| {
-                                // Header
-                                if self.show_header {
-                                    ui.vertical_centered(|ui| {
-                                        ui.add_space(16.0);
-                                        ui.heading("Mail");
-                                        ui.colored_label(get_global_color("onSurfaceVariant"), "email@material.io");
-                                        ui.add_space(16.0);
-                                    });
-                                }
-                                
-                                // Multi-section navigation demonstration
-                                if self.show_sections {
-                                    // Mail section
-                                    ui.add_space(8.0);
-                                    ui.horizontal(|ui| {
-                                        ui.add_space(28.0);
-                                        ui.colored_label(
-                                            get_global_color("onSurfaceVariant"),
-                                            "Mail"
-                                        );
-                                    });
-                                    ui.add_space(8.0);
-
-                                    self.render_navigation_items(ui, &self.create_mail_items());
-
-                                    // Divider
-                                    ui.add_space(8.0);
-                                    ui.separator();
-                                    ui.add_space(8.0);
-
-                                    // Labels section
-                                    ui.horizontal(|ui| {
-                                        ui.add_space(28.0);
-                                        ui.colored_label(
-                                            get_global_color("onSurfaceVariant"),
-                                            "Labels"
-                                        );
-                                    });
-                                    ui.add_space(8.0);
+                            let mail_items = self.create_mail_items();
+                            let label_items = self.create_label_items();
+                            let mut always_open = true;
+                            let mut drawer = MaterialDrawer::new_with_id(
+                                DrawerVariant::Permanent,
+                                &mut always_open,
+                                egui::Id::new("permanent_drawer_demo"),
+                            )
+                            .width(drawer_width)
+                            .corner_radius(self.corner_radius.min(28.0) as u8);
+
+                            if self.show_header {
+                                drawer = drawer.header("Mail", Some("email@material.io"));
+                            }
 
-                                    self.render_navigation_items(ui, &self.create_label_items());
-                                } else {
-                                    // Simple flat list
-                                    ui.add_space(16.0);
-                                    self.render_navigation_items(ui, &self.create_mail_items());
-                                }
-                            });
+                            // Multi-section navigation demonstration
+                            let names: Vec<String> = if self.show_sections {
+                                let names = mail_items.iter().chain(&label_items).map(|item| item.text.clone()).collect();
+                                drawer = drawer
+                                    .section(Some("Mail"), mail_items)
+                                    .section(Some("Labels"), label_items);
+                                names
+                            } else {
+                                let names = mail_items.iter().map(|item| item.text.clone()).collect();
+                                drawer = drawer.items(mail_items);
+                                names
+                            };
+
+                            if let Some(index) = drawer.show_inside(ui).clicked {
+                                self.selected_menu_item = names[index].clone();
+                            }
                         },
                     );
                     
@@ -228,99 +241,6 @@ impl DrawerWindow {
         self.permanent_drawer_open = demo_open;
     }
 
-    /// Renders navigation items with Material 3 styling
-    fn render_navigation_items(&mut self, ui: &mut Ui, items: &[DrawerItem]) {
-        for item in items {
-            let is_selected = item.active;
-            let item_height = 56.0;
-            let horizontal_padding = 12.0;
-
-            let (rect, response) = ui.allocate_exact_size(
-                egui::vec2(ui.available_width(), item_height),
-                egui::Sense::click(),
-            );
-
-            // Background for active/hover states
-            if is_selected {
-                let container_rect = rect.shrink2(egui::vec2(horizontal_padding, 12.0));
-                ui.painter().rect_filled(
-                    container_rect,
-                    self.corner_radius.min(28.0),
-                    get_global_color("secondaryContainer"),
-                );
-            } else if response.hovered() {
-                let container_rect = rect.shrink2(egui::vec2(horizontal_padding, 12.0));
-                ui.painter().rect_filled(
-                    container_rect,
-                    self.corner_radius.min(28.0),
-                    get_global_color("onSurface").linear_multiply(0.08),
-                );
-            }
-
-            // Content layout
-            let mut cursor_x = rect.min.x + horizontal_padding + 16.0;
-
-            // Icon
-            if let Some(icon) = &item.icon {
-                let icon_center = egui::pos2(cursor_x + 12.0, rect.center().y);
-                let text_color = if is_selected {
-                    get_global_color("onSecondaryContainer")
-                } else {
-                    get_global_color("onSurfaceVariant")
-                };
-
-                ui.painter().text(
-                    icon_center,
-                    egui::Align2::CENTER_CENTER,
-                    icon,
-                    egui::FontId::proportional(20.0),
-                    text_color,
-                );
-                cursor_x += 40.0;
-            }
-
-            // Label
-            let text_color = if is_selected {
-                get_global_color("onSecondaryContainer")
-            } else {
-                get_global_color("onSurfaceVariant")
-            };
-
-            ui.painter().text(
-                egui::pos2(cursor_x, rect.center().y),
-                egui::Align2::LEFT_CENTER,
-                &item.text,
-                egui::FontId::proportional(14.0),
-                text_color,
-            );
-
-            // Badge
-            if let Some(badge) = &item.badge {
-                let badge_x = rect.max.x - horizontal_padding - 30.0;
-                let badge_center = egui::pos2(badge_x, rect.center().y);
-                
-                ui.painter().circle_filled(
-                    badge_center,
-                    12.0,
-                    get_global_color("error"),
-                );
-                
-                ui.painter().text(
-                    badge_center,
-                    egui::Align2::CENTER_CENTER,
-                    badge,
-                    egui::FontId::proportional(11.0),
-                    get_global_color("onError"),
-                );
-            }
-
-            // Handle click
-            if response.clicked() {
-                self.selected_menu_item = item.text.clone();
-            }
-        }
-    }
-
     /// Renders content for a selection
     fn render_content_for_selection(&self, selection: &str, ui: &mut Ui) {
         match selection {
@@ -1026,8 +946,15 @@ impl DrawerWindow {
         });
     }
 
-    fn render_drawer_examples(&mut self, _ui: &mut Ui) {
-        
+    fn render_drawer_examples(&mut self, ui: &mut Ui) {
+        ui.heading("Animated Modal Drawer");
+        ui.label("Slides over the whole window using the width, elevation, corner radius and scrim opacity above.");
+        ui.horizontal(|ui| {
+            if ui.add(MaterialButton::filled("Open Modal Drawer")).clicked() {
+                self.viewport_modal_open = true;
+            }
+            ui.label(format!("Selected: {}", self.modal_selected_menu_item));
+        });
     }
 
     /// Helper to create navigation items based on current settings
@@ -1048,7 +975,7 @@ impl DrawerWindow {
             }
 
             if self.show_badges && *badge_count > 0 {
-                item = item.badge(badge_count.to_string());
+                item = item.icon_badge(MaterialBadge::count(*badge_count));
             }
 
             item
@@ -1072,7 +999,7 @@ impl DrawerWindow {
             }
 
             if self.show_badges && *badge_count > 0 {
-                item = item.badge(badge_count.to_string());
+                item = item.icon_badge(MaterialBadge::count(*badge_count));
             }
 
             item
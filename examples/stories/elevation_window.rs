@@ -0,0 +1,78 @@
+#![doc(hidden)]
+
+use crate::theme::{elevation_shadow, get_global_color, surface_tint_overlay};
+use crate::MaterialButton;
+use eframe::egui::{self, Align2, FontId, Sense, Ui, Vec2, Window};
+
+#[doc(hidden)]
+pub struct ElevationWindow {
+    pub open: bool,
+    show_shadow: bool,
+    show_tint: bool,
+}
+
+impl Default for ElevationWindow {
+    fn default() -> Self {
+        Self {
+            open: false,
+            show_shadow: true,
+            show_tint: true,
+        }
+    }
+}
+
+impl ElevationWindow {
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let mut open = self.open;
+        Window::new("Elevation Stories")
+            .open(&mut open)
+            .default_size([700.0, 300.0])
+            .show(ctx, |ui| {
+                self.render_controls(ui);
+                ui.add_space(20.0);
+                self.render_levels(ui);
+            });
+        self.open = open;
+    }
+
+    fn render_controls(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.heading("Elevation Controls");
+
+            if ui.add(MaterialButton::filled("Target").small()).clicked() {
+                let _ = webbrowser::open("https://m3.material.io/styles/elevation/tokens");
+            }
+        });
+
+        ui.checkbox(&mut self.show_shadow, "Shadow");
+        ui.checkbox(&mut self.show_tint, "Surface tint");
+    }
+
+    fn render_levels(&mut self, ui: &mut Ui) {
+        let dp = ["0dp", "1dp", "3dp", "6dp", "8dp", "12dp"];
+        ui.horizontal(|ui| {
+            for level in 0..=5u8 {
+                let (rect, _) = ui.allocate_exact_size(Vec2::new(96.0, 96.0), Sense::hover());
+                let surface = rect.shrink(12.0);
+                if self.show_shadow {
+                    ui.painter()
+                        .add(elevation_shadow(level).as_shape(surface, 12.0));
+                }
+                ui.painter()
+                    .rect_filled(surface, 12.0, get_global_color("surface"));
+                if self.show_tint {
+                    ui.painter()
+                        .rect_filled(surface, 12.0, surface_tint_overlay(level));
+                }
+                ui.painter().text(
+                    surface.center(),
+                    Align2::CENTER_CENTER,
+                    format!("Level {}\n{}", level, dp[level as usize]),
+                    FontId::proportional(12.0),
+                    get_global_color("onSurface"),
+                );
+                ui.add_space(8.0);
+            }
+        });
+    }
+}
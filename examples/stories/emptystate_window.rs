@@ -0,0 +1,88 @@
+#![doc(hidden)]
+
+use crate::{image_list, MaterialCheckbox, MaterialDataTable, MaterialEmptyState, MaterialSelect};
+use eframe::egui::{self, Ui, Window};
+
+#[doc(hidden)]
+pub struct EmptyStateWindow {
+    pub open: bool,
+    compact: bool,
+    with_action: bool,
+    fruit: Option<usize>,
+    refreshes: usize,
+}
+
+impl Default for EmptyStateWindow {
+    fn default() -> Self {
+        Self {
+            open: false,
+            compact: false,
+            with_action: true,
+            fruit: None,
+            refreshes: 0,
+        }
+    }
+}
+
+impl EmptyStateWindow {
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let mut open = self.open;
+        Window::new("Empty State Stories")
+            .open(&mut open)
+            .default_size([600.0, 600.0])
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    self.render_standalone(ui);
+                    ui.add_space(20.0);
+                    self.render_components(ui);
+                });
+            });
+        self.open = open;
+    }
+
+    fn render_standalone(&mut self, ui: &mut Ui) {
+        ui.heading("Empty State");
+        ui.horizontal(|ui| {
+            ui.add(MaterialCheckbox::new(&mut self.compact, "Compact"));
+            ui.add(MaterialCheckbox::new(&mut self.with_action, "Action"));
+        });
+
+        let mut empty_state = MaterialEmptyState::new("No messages")
+            .icon("inbox")
+            .body("Messages you receive will show up here")
+            .compact(self.compact);
+        if self.with_action {
+            empty_state = empty_state.action("Refresh");
+        }
+        if empty_state.show(ui).action_clicked {
+            self.refreshes += 1;
+        }
+        ui.label(format!("Refreshed {} times", self.refreshes));
+    }
+
+    fn render_components(&mut self, ui: &mut Ui) {
+        ui.heading("In Components");
+
+        ui.label("Searchable select (search for something that isn't there):");
+        ui.add(
+            MaterialSelect::new(&mut self.fruit)
+                .searchable(true)
+                .option(0, "Apple")
+                .option(1, "Banana")
+                .option(2, "Cherry"),
+        );
+        ui.add_space(10.0);
+
+        ui.label("Data table without rows:");
+        ui.add(
+            MaterialDataTable::new()
+                .id(egui::Id::new("empty_state_table"))
+                .column("Name", 200.0, false)
+                .column("Role", 160.0, false),
+        );
+        ui.add_space(10.0);
+
+        ui.label("Image list without images:");
+        ui.add(image_list().id_salt("empty_state_images"));
+    }
+}
@@ -1,10 +1,10 @@
 #![doc(hidden)]
 
 use crate::{
-    fab_branded, fab_primary, fab_secondary, fab_surface, fab_tertiary, google_branded_icon,
-    FabSize, MaterialButton,
+    extended_fab_primary, fab_branded, fab_primary, fab_secondary, fab_surface, fab_tertiary,
+    google_branded_icon, FabMenu, FabSize, MaterialButton, SvgIcon, SvgPath,
 };
-use eframe::egui::{self, Window};
+use eframe::egui::{self, Color32, Window};
 
 #[doc(hidden)]
 pub struct FabWindow {
@@ -13,6 +13,7 @@ pub struct FabWindow {
     label: String,
     lowered: bool,
     size: FabSize,
+    collapsed: bool,
 }
 
 impl Default for FabWindow {
@@ -23,6 +24,7 @@ impl Default for FabWindow {
             label: String::new(),
             lowered: false,
             size: FabSize::Regular,
+            collapsed: false,
         }
     }
 }
@@ -175,6 +177,21 @@ impl FabWindow {
 
                 ui.add(fab.on_click(|| println!("Branded FAB clicked!")));
             });
+
+            ui.vertical(|ui| {
+                ui.label("Custom SVG");
+                let mut fab = fab_surface().svg_icon(two_tone_logo()).size(self.size);
+
+                if !self.label.is_empty() {
+                    fab = fab.text(&self.label);
+                }
+
+                if self.lowered {
+                    fab = fab.lowered(true);
+                }
+
+                ui.add(fab.on_click(|| println!("Custom SVG FAB clicked!")));
+            });
         });
 
         ui.add_space(20.0);
@@ -225,5 +242,39 @@ impl FabWindow {
                 ui.add(fab.on_click(|| println!("Large Extended FAB clicked!")));
             });
         });
+
+        ui.add_space(20.0);
+
+        ui.heading("Collapsible Extended FAB");
+        ui.checkbox(&mut self.collapsed, "Collapsed");
+        ui.add(
+            extended_fab_primary("edit", "Compose")
+                .collapsed(self.collapsed)
+                .on_click(|| println!("Compose FAB clicked!")),
+        );
+
+        ui.add_space(20.0);
+
+        ui.heading("FAB Menu");
+        let menu_response = FabMenu::new("fab_menu_demo", fab_primary().icon("add"))
+            .labeled_action("description", "Document")
+            .labeled_action("image", "Image")
+            .labeled_action("folder", "Folder")
+            .show(ui);
+        if let Some(index) = menu_response.selected {
+            println!("FAB menu action {} chosen", index);
+        }
     }
 }
+
+/// A two-color brand mark built from path data: a teal ring around an orange dot
+fn two_tone_logo() -> SvgIcon {
+    SvgIcon::from_paths(vec![
+        // The inner circle cuts a hole into the outer one with the even-odd rule
+        SvgPath::new("M24 4a20 20 0 1 0 0.01 0zM24 12a12 12 0 1 1-0.01 0z")
+            .fill(Color32::from_rgb(0x00, 0x96, 0x88))
+            .even_odd(true),
+        SvgPath::new("M24 17a7 7 0 1 0 0.01 0z").fill(Color32::from_rgb(0xFF, 0x70, 0x43)),
+    ])
+    .viewbox(48.0, 48.0)
+}
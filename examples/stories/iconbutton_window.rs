@@ -2,7 +2,8 @@
 
 use crate::{
     icon_button_filled, icon_button_filled_tonal, icon_button_outlined, icon_button_standard,
-    icon_button_toggle, noto_emoji, MaterialButton, MaterialCheckbox,
+    icon_button_toggle, noto_emoji, IconButtonVariant, MaterialButton, MaterialCheckbox,
+    MaterialIconButton,
 };
 use eframe::egui::{self, Ui, Window};
 
@@ -329,64 +330,51 @@ impl IconButtonWindow {
 
         ui.heading("Toggle Icon Buttons");
 
-        ui.label(format!(
-            "Toggle buttons maintain selected/unselected state (using '{}'):",
-            self.selected_icon
-        ));
+        ui.label("Toggle buttons animate between states and swap icons when selected:");
+        let toggles = [
+            (IconButtonVariant::Standard, "Standard"),
+            (IconButtonVariant::Filled, "Filled"),
+            (IconButtonVariant::FilledTonal, "Filled tonal"),
+            (IconButtonVariant::Outlined, "Outlined"),
+        ];
+        let states = [
+            &mut self.icon_toggle_1,
+            &mut self.icon_toggle_2,
+            &mut self.icon_toggle_3,
+            &mut self.icon_toggle_4,
+        ];
         ui.horizontal(|ui| {
-            ui.add(
-                icon_button_toggle(&self.selected_icon, &mut self.icon_toggle_1)
-                    .size(self.size)
-                    .enabled(self.enabled)
-                    .container(self.container),
-            );
-            ui.label(if self.icon_toggle_1 {
-                "✓ Selected"
-            } else {
-                "○ Unselected"
-            });
-
-            ui.separator();
-
-            ui.add(
-                icon_button_toggle(&self.selected_icon, &mut self.icon_toggle_2)
-                    .size(self.size)
-                    .enabled(self.enabled)
-                    .container(self.container),
-            );
-            ui.label(if self.icon_toggle_2 {
-                "✓ Selected"
-            } else {
-                "○ Unselected"
-            });
+            for ((variant, name), selected) in toggles.into_iter().zip(states) {
+                let response = ui.add(
+                    icon_button_toggle(selected, "bookmark_border", "bookmark")
+                        .variant(variant)
+                        .size(self.size)
+                        .enabled(self.enabled)
+                        .container(self.container)
+                        .tooltip(name),
+                );
+                if response.changed() {
+                    println!("{} toggle changed", name);
+                }
+            }
         });
 
         ui.horizontal(|ui| {
             ui.add(
-                icon_button_toggle(&self.selected_icon, &mut self.icon_toggle_3)
+                MaterialIconButton::toggle(&self.selected_icon, &mut self.icon_toggle_1)
                     .size(self.size)
                     .enabled(self.enabled)
                     .container(self.container),
             );
-            ui.label(if self.icon_toggle_3 {
-                "✓ Selected"
-            } else {
-                "○ Unselected"
-            });
-
-            ui.separator();
-
-            ui.add(
-                icon_button_toggle(&self.selected_icon, &mut self.icon_toggle_4)
-                    .size(self.size)
-                    .enabled(self.enabled)
-                    .container(self.container),
-            );
-            ui.label(if self.icon_toggle_4 {
-                "✓ Selected"
-            } else {
-                "○ Unselected"
-            });
+            ui.label(format!(
+                "Single icon toggle using '{}': {}",
+                self.selected_icon,
+                if self.icon_toggle_1 {
+                    "✓ Selected"
+                } else {
+                    "○ Unselected"
+                }
+            ));
         });
 
         ui.add_space(20.0);
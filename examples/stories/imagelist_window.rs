@@ -1,6 +1,9 @@
 #![doc(hidden)]
 
-use crate::{image_list, masonry_image_list, woven_image_list, MaterialButton, MaterialCheckbox};
+use crate::{
+    clear_image_cache, image_cache_config, image_list, masonry_image_list, woven_image_list,
+    ImageFit, ImageListItem, MaterialButton, MaterialCheckbox,
+};
 use eframe::egui::{self, Ui, Window};
 
 #[doc(hidden)]
@@ -10,9 +13,13 @@ pub struct ImageListWindow {
     text_protected: bool,
     show_supporting_text: bool,
     item_spacing: f32,
+    image_fit: ImageFit,
     // Dynamic image list for interactive demo
     dynamic_images: Vec<DynamicImageItem>,
     next_image_id: usize,
+    // Photo picker demo
+    selected_photos: Vec<usize>,
+    last_clicked_photo: Option<usize>,
 }
 
 #[derive(Clone)]
@@ -40,8 +47,11 @@ impl Default for ImageListWindow {
             text_protected: false,
             show_supporting_text: true,
             item_spacing: 8.0,
+            image_fit: ImageFit::default(),
             dynamic_images,
             next_image_id: 9,
+            selected_photos: Vec::new(),
+            last_clicked_photo: None,
         }
     }
 }
@@ -99,6 +109,26 @@ impl ImageListWindow {
                 ui.add(egui::Slider::new(&mut self.item_spacing, 0.0..=20.0).suffix("px"));
             });
 
+            ui.horizontal(|ui| {
+                ui.label("Image Fit:");
+                ui.selectable_value(&mut self.image_fit, ImageFit::Fill, "Fill");
+                ui.selectable_value(&mut self.image_fit, ImageFit::Cover, "Cover");
+                ui.selectable_value(&mut self.image_fit, ImageFit::Contain, "Contain");
+            });
+
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "Image cache: {}",
+                    image_cache_config().dir.display()
+                ));
+                if ui
+                    .add(MaterialButton::outlined("Clear Cache").small())
+                    .clicked()
+                {
+                    clear_image_cache();
+                }
+            });
+
             ui.horizontal(|ui| {
                 ui.add(MaterialCheckbox::new(
                     &mut self.text_protected,
@@ -212,6 +242,85 @@ impl ImageListWindow {
 
         ui.add_space(30.0);
 
+        ui.heading("Lazy Gallery");
+        ui.label("500 online images; only the cells near the visible area are loaded.");
+
+        egui::ScrollArea::vertical()
+            .id_salt("lazy_imagelist_scroll")
+            .max_height(320.0)
+            .show(ui, |ui| {
+                ui.add(
+                    woven_image_list()
+                        .id_salt("lazy_imagelist")
+                        .columns(self.columns)
+                        .item_spacing(self.item_spacing)
+                        .lazy(true)
+                        .prefetch_margin(200.0)
+                        .items_from_urls(
+                            (0..500)
+                                .map(|i| format!("https://picsum.photos/seed/m3_{}/320/240", i)),
+                        ),
+                );
+            });
+
+        ui.add_space(30.0);
+
+        ui.heading("Captioned Image List");
+        ui.label("Captions drawn over a gradient scrim so they stay legible on any image.");
+
+        ui.add(
+            image_list()
+                .id_salt("captioned_imagelist")
+                .columns(self.columns)
+                .item_spacing(self.item_spacing)
+                .image_fit(self.image_fit)
+                .add_item(
+                    ImageListItem::new("Sunset", "resources/320x240.png")
+                        .caption("Sunset")
+                        .secondary_text("Photo by Jane"),
+                )
+                .add_item(
+                    ImageListItem::new("Harbor", "resources/imgur_image.png").caption("Harbor"),
+                )
+                .add_item(
+                    ImageListItem::new("Meadow", "resources/320x240.png")
+                        .caption("Meadow")
+                        .secondary_text("12 photos"),
+                )
+                .add_item(
+                    ImageListItem::new("Missing", "resources/missing.png")
+                        .caption("Missing")
+                        .secondary_text("Fails to load; try Retry"),
+                )
+                .on_retry(|index| println!("Retrying image {}", index)),
+        );
+
+        ui.add_space(30.0);
+
+        ui.heading("Photo Picker");
+        ui.label("Multi-select mode: click photos to toggle them.");
+
+        let mut picker = image_list()
+            .id_salt("picker_imagelist")
+            .columns(4)
+            .item_spacing(self.item_spacing);
+        for i in 1..=8 {
+            picker = picker.item(format!("Pick {}", i), "resources/320x240.png");
+        }
+        let picker_response = picker.multi_select(&mut self.selected_photos).show(ui);
+        if picker_response.clicked.is_some() {
+            self.last_clicked_photo = picker_response.clicked;
+        }
+
+        let mut selected = self.selected_photos.clone();
+        selected.sort_unstable();
+        ui.label(format!(
+            "Selected: {:?}, last clicked: {:?}, hovered: {:?}",
+            selected, self.last_clicked_photo, picker_response.hovered
+        ));
+
+        ui.add_space(30.0);
+
         ui.heading("Interactive Demo");
 
         ui.horizontal(|ui| {
@@ -1,9 +1,16 @@
 #![doc(hidden)]
 
 use crate::{list, list_item, MaterialButton};
-use egui_material3::VisualDensity;
+use egui_material3::{checkbox, get_global_color, switch, SwipeAction, VisualDensity};
 use eframe::egui::{self, Window};
 
+const INBOX: [&str; 4] = [
+    "Team standup notes",
+    "Invoice #1042",
+    "Weekend plans",
+    "Flight itinerary",
+];
+
 #[doc(hidden)]
 pub struct ListWindow {
     pub open: bool,
@@ -16,6 +23,11 @@ pub struct ListWindow {
     trailing_icon: bool,
     visual_density_mode: usize, // 0=Standard, 1=Comfortable, 2=Compact
     selected_items: Vec<bool>, // For selection demo
+    playlist: Vec<String>,     // For reorder demo
+    inbox: Vec<String>,        // For swipe demo
+    swipe_log: String,         // Last swipe action
+    dark_mode: bool,           // For trailing widget demo
+    sync: bool,
 }
 
 impl Default for ListWindow {
@@ -31,6 +43,13 @@ impl Default for ListWindow {
             trailing_icon: false,
             visual_density_mode: 0,
             selected_items: vec![false; 5],
+            playlist: ["Intro", "Morning Light", "Undertow", "Glass Harbor", "Outro"]
+                .map(String::from)
+                .to_vec(),
+            inbox: INBOX.map(String::from).to_vec(),
+            swipe_log: String::new(),
+            dark_mode: false,
+            sync: true,
         }
     }
 }
@@ -55,6 +74,10 @@ impl ListWindow {
                     ui.add_space(20.0);
                     self.render_selection_demo(ui);
                     ui.add_space(20.0);
+                    self.render_reorder_demo(ui);
+                    ui.add_space(20.0);
+                    self.render_swipe_demo(ui);
+                    ui.add_space(20.0);
                     self.render_visual_density_comparison(ui);
                     ui.add_space(20.0);
                     self.render_real_world_examples(ui);
@@ -585,6 +608,62 @@ impl ListWindow {
         ui.add(selection_list);
     }
 
+    fn render_reorder_demo(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Reorderable List");
+        ui.label("Drag the handle, or long-press an item, to move it.");
+
+        let mut playlist = list().id("reorder_list").reorderable(true);
+        for (i, track) in self.playlist.iter().enumerate() {
+            playlist = playlist.item(
+                list_item(track)
+                    .leading_icon("music_note".to_string())
+                    .trailing_text(format!("{}:{:02}", 3 + i % 2, (i * 17) % 60)),
+            );
+        }
+
+        if let Some((from, to)) = playlist.show(ui).moved {
+            let track = self.playlist.remove(from);
+            self.playlist.insert(to, track);
+        }
+    }
+
+    fn render_swipe_demo(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Swipe Actions");
+        ui.label("Swipe right to archive or flag, left to delete.");
+        ui.label("Swipe all the way across to act at once.");
+
+        let mut inbox = list().id("swipe_list");
+        for message in &self.inbox {
+            inbox = inbox.item(
+                list_item(message)
+                    .leading_icon("mail".to_string())
+                    .swipe_actions(
+                        vec![
+                            SwipeAction::new("archive", "archive", get_global_color("tertiary")),
+                            SwipeAction::new("flag", "flag", get_global_color("secondary")),
+                        ],
+                        vec![
+                            SwipeAction::new("delete", "delete", get_global_color("error"))
+                                .icon_color(get_global_color("onError")),
+                        ],
+                    ),
+            );
+        }
+
+        if let Some((index, action)) = inbox.show(ui).swiped {
+            self.swipe_log = format!("{} \"{}\"", action, self.inbox[index]);
+            if action != "flag" {
+                self.inbox.remove(index);
+            }
+        }
+        if !self.swipe_log.is_empty() {
+            ui.label(format!("Last action: {}", self.swipe_log));
+        }
+        if self.inbox.is_empty() && ui.button("Refill inbox").clicked() {
+            self.inbox = INBOX.map(String::from).to_vec();
+        }
+    }
+
     fn render_visual_density_comparison(&mut self, ui: &mut egui::Ui) {
         ui.heading("Visual Density Comparison");
 
@@ -673,6 +752,53 @@ impl ListWindow {
 
         ui.add_space(10.0);
 
+        ui.label("Settings with Controls:");
+        let (dark_mode, sync) = (&mut self.dark_mode, &mut self.sync);
+        let controls_list = list()
+            .id("controls_list")
+            .item(
+                list_item("Dark theme")
+                    .supporting_text("Use a dark background throughout the app")
+                    .leading_icon("dark_mode".to_string())
+                    .trailing(|ui| {
+                        ui.add(switch(dark_mode));
+                    }),
+            )
+            .item(
+                list_item("Sync over mobile data")
+                    .leading_icon("sync".to_string())
+                    .trailing(|ui| {
+                        ui.add(checkbox(sync, ""));
+                    }),
+            );
+        ui.add(controls_list);
+
+        ui.add_space(10.0);
+
+        ui.label("Contacts (three-line):");
+        let contacts_list = list()
+            .id("contacts_list")
+            .item(
+                list_item("Ada Lovelace")
+                    .supporting_text(
+                        "Sent the notes on the Analytical Engine, with a long appendix \
+                         describing how it could compute Bernoulli numbers",
+                    )
+                    .is_three_line(true)
+                    .leading_avatar("AL")
+                    .trailing_text("10 min"),
+            )
+            .item(
+                list_item("Grace Hopper")
+                    .overline("Compilers")
+                    .supporting_text("Shared a nanosecond of wire")
+                    .leading_avatar("GH")
+                    .trailing_icon("star".to_string()),
+            );
+        ui.add(contacts_list);
+
+        ui.add_space(10.0);
+
         ui.label("Inbox List:");
         let inbox_list = list()
             .id("inbox_list")
@@ -1,10 +1,13 @@
 #![doc(hidden)]
 
-use eframe::egui::{self, Color32};
+use eframe::egui;
 use egui_file_dialog::FileDialog;
 use egui_material3::theme::{
-    load_fonts, load_themes, setup_google_fonts,
-    setup_local_fonts_from_bytes, setup_local_theme,
+    activate_theme, apply_if_changed, available_theme_names, dismiss_on_escape, get_rtl,
+    invalidate_theme_cache, load_fonts, load_themes, set_rtl, set_theme_animation_enabled,
+    setup_google_fonts, setup_google_fonts_with_priority, setup_local_fonts_from_bytes,
+    setup_local_fonts_from_bytes_with_priority, setup_local_theme, theme_animation_enabled,
+    FontPriority,
 };
 use egui_material3::*;
 use std::collections::HashMap;
@@ -12,6 +15,7 @@ use std::path::PathBuf;
 
 // Import window modules - reorganized from src/ to examples/stories/ directory structure
 mod actionsheet_window;
+mod avatar_window;
 mod badge_window;
 mod breadcrumbs_window;
 mod button_window;
@@ -19,34 +23,46 @@ mod card2_window;
 mod carousel_window;
 mod checkbox_window;
 mod chips_window;
+mod commandpalette_window;
 mod dashcounter_window;
 mod datatable_window;
+mod datepicker_window;
 mod dialog_window;
 mod drawer_window;
+mod emptystate_window;
+mod elevation_window;
 mod fab_window;
 mod iconbutton_window;
 mod imagelist_window;
 mod layoutgrid_window;
 mod list_window;
 mod menu_window;
+mod navigationbar_window;
+mod navigationrail_window;
 mod notification_window;
 mod progress_window;
 mod radio_window;
+mod ripple_window;
+mod segmentedbutton_window;
 mod select_window;
 mod slider_window;
 mod snackbar_window;
 mod spreadsheet_window;
+mod stepper_window;
 mod switch_window;
 mod svgemoji_window;
 mod symbol_window;
 mod tabs_window;
+mod textfield_window;
 mod timeline_window;
+mod timepicker_window;
 mod toolbar_window;
 mod tooltip_window;
 mod topappbar_window;
 mod treeview_window;
 
 use actionsheet_window::ActionSheetWindow;
+use avatar_window::AvatarWindow;
 use badge_window::BadgeWindow;
 use breadcrumbs_window::BreadcrumbsWindow;
 use button_window::ButtonWindow;
@@ -54,28 +70,39 @@ use card2_window::Card2Window;
 use carousel_window::CarouselWindow;
 use checkbox_window::CheckboxWindow;
 use chips_window::ChipsWindow;
+use commandpalette_window::CommandPaletteWindow;
 use dashcounter_window::DashCounterWindow;
 use datatable_window::DataTableWindow;
+use datepicker_window::DatePickerWindow;
 use dialog_window::DialogWindow;
 use drawer_window::DrawerWindow;
+use emptystate_window::EmptyStateWindow;
+use elevation_window::ElevationWindow;
 use fab_window::FabWindow;
 use iconbutton_window::IconButtonWindow;
 use imagelist_window::ImageListWindow;
 use layoutgrid_window::LayoutGridWindow;
 use list_window::ListWindow;
 use menu_window::MenuWindow;
+use navigationbar_window::NavigationBarWindow;
+use navigationrail_window::NavigationRailWindow;
 use notification_window::NotificationWindow;
 use progress_window::ProgressWindow;
 use radio_window::RadioWindow;
+use ripple_window::RippleWindow;
+use segmentedbutton_window::SegmentedButtonSetWindow;
 use select_window::SelectWindow;
 use slider_window::SliderWindow;
 use snackbar_window::SnackbarWindow;
 use spreadsheet_window::SpreadsheetWindow;
+use stepper_window::StepperWindow;
 use switch_window::SwitchWindow;
 use svgemoji_window::SvgEmojiWindow;
 use symbol_window::SymbolWindow;
 use tabs_window::TabsWindow;
+use textfield_window::TextFieldWindow;
 use timeline_window::TimelineWindow;
+use timepicker_window::TimePickerWindow;
 use toolbar_window::ToolbarWindow;
 use tooltip_window::TooltipWindow;
 use treeview_window::TreeViewWindow;
@@ -100,15 +127,24 @@ fn main() -> Result<(), eframe::Error> {
                 "MaterialSymbolsOutlined",
                 include_bytes!("../../resources/MaterialSymbolsOutlined[FILL,GRAD,opsz,wght].ttf"),
             );
-            setup_local_fonts_from_bytes(
+            // Nanum Gothic only fills in the Korean glyphs Google Sans Code lacks
+            setup_local_fonts_from_bytes_with_priority(
                 "Nanum Gothic",
                 include_bytes!("../../resources/nanum-gothic.ttf"),
+                FontPriority::Fallback,
             );
             // Prepare Google Sans Code font for Material Design (default)
             setup_google_fonts(Some("Google Sans Code"));
-            setup_google_fonts(Some("Nanum Gothic"));
-            // Prepare themes from build-time constants
+            setup_google_fonts_with_priority(Some("Nanum Gothic"), FontPriority::Fallback);
+            // Prepare themes from build-time constants, plus a few to switch to
             setup_local_theme(None);
+            for path in [
+                "resources/material-theme2.json",
+                "resources/material-theme5.json",
+                "resources/material-theme6.json",
+            ] {
+                setup_local_theme(Some(path));
+            }
             // Install image loaders
             egui_extras::install_image_loaders(&cc.egui_ctx);
             // Load all prepared fonts and themes
@@ -123,29 +159,41 @@ struct MaterialApp {
     // Theme changer controls
     file_dialog: FileDialog,
     selected_file_path: Option<PathBuf>,
+    active_theme: String,
     color_pickers_open: HashMap<String, bool>,
     // Demo windows
     actionsheet_window: ActionSheetWindow,
+    avatar_window: AvatarWindow,
     badge_window: BadgeWindow,
     breadcrumbs_window: BreadcrumbsWindow,
     button_window: ButtonWindow,
     checkbox_window: CheckboxWindow,
     chips_window: ChipsWindow,
+    commandpalette_window: CommandPaletteWindow,
     dashcounter_window: DashCounterWindow,
     dialog_window: DialogWindow,
     fab_window: FabWindow,
     iconbutton_window: IconButtonWindow,
     list_window: ListWindow,
     menu_window: MenuWindow,
+    navigationbar_window: NavigationBarWindow,
+    navigationrail_window: NavigationRailWindow,
     notification_window: NotificationWindow,
     progress_window: ProgressWindow,
     radio_window: RadioWindow,
+    ripple_window: RippleWindow,
+    segmentedbutton_window: SegmentedButtonSetWindow,
     select_window: SelectWindow,
     slider_window: SliderWindow,
+    stepper_window: StepperWindow,
     switch_window: SwitchWindow,
     tabs_window: TabsWindow,
+    textfield_window: TextFieldWindow,
     datatable_window: DataTableWindow,
+    datepicker_window: DatePickerWindow,
     drawer_window: DrawerWindow,
+    emptystate_window: EmptyStateWindow,
+    elevation_window: ElevationWindow,
     imagelist_window: ImageListWindow,
     layoutgrid_window: LayoutGridWindow,
     snackbar_window: SnackbarWindow,
@@ -155,6 +203,7 @@ struct MaterialApp {
     carousel_window: CarouselWindow,
     symbol_window: SymbolWindow,
     timeline_window: TimelineWindow,
+    timepicker_window: TimePickerWindow,
     toolbar_window: ToolbarWindow,
     tooltip_window: TooltipWindow,
     treeview_window: TreeViewWindow,
@@ -166,28 +215,40 @@ impl Default for MaterialApp {
         Self {
             file_dialog: FileDialog::new(),
             selected_file_path: None,
+            active_theme: "default".to_string(),
             color_pickers_open: HashMap::new(),
             actionsheet_window: ActionSheetWindow::default(),
+            avatar_window: AvatarWindow::default(),
             badge_window: BadgeWindow::default(),
             breadcrumbs_window: BreadcrumbsWindow::default(),
             button_window: ButtonWindow::default(),
             checkbox_window: CheckboxWindow::default(),
             chips_window: ChipsWindow::default(),
+            commandpalette_window: CommandPaletteWindow::default(),
             dashcounter_window: DashCounterWindow::default(),
             dialog_window: DialogWindow::default(),
             fab_window: FabWindow::default(),
             iconbutton_window: IconButtonWindow::default(),
             list_window: ListWindow::default(),
             menu_window: MenuWindow::default(),
+            navigationbar_window: NavigationBarWindow::default(),
+            navigationrail_window: NavigationRailWindow::default(),
             notification_window: NotificationWindow::default(),
             progress_window: ProgressWindow::default(),
             radio_window: RadioWindow::default(),
+            ripple_window: RippleWindow::default(),
+            segmentedbutton_window: SegmentedButtonSetWindow::default(),
             select_window: SelectWindow::default(),
             slider_window: SliderWindow::default(),
+            stepper_window: StepperWindow::default(),
             switch_window: SwitchWindow::default(),
             tabs_window: TabsWindow::default(),
+            textfield_window: TextFieldWindow::default(),
             datatable_window: DataTableWindow::default(),
+            datepicker_window: DatePickerWindow::default(),
             drawer_window: DrawerWindow::default(),
+            emptystate_window: EmptyStateWindow::default(),
+            elevation_window: ElevationWindow::default(),
             imagelist_window: ImageListWindow::default(),
             layoutgrid_window: LayoutGridWindow::default(),
             snackbar_window: SnackbarWindow::default(),
@@ -197,6 +258,7 @@ impl Default for MaterialApp {
             carousel_window: CarouselWindow::default(),
             symbol_window: SymbolWindow::default(),
             timeline_window: TimelineWindow::default(),
+            timepicker_window: TimePickerWindow::default(),
             toolbar_window: ToolbarWindow::default(),
             tooltip_window: TooltipWindow::default(),
             treeview_window: TreeViewWindow::default(),
@@ -221,6 +283,7 @@ impl MaterialApp {
         if let Ok(mut theme) = get_global_theme().lock() {
             update_fn(&mut *theme);
         }
+        invalidate_theme_cache();
     }
 
     fn load_theme_from_file(
@@ -237,90 +300,39 @@ impl MaterialApp {
         self.file_dialog.pick_file();
     }
 
-    fn apply_theme(&self, ctx: &egui::Context) {
-        let theme = self.get_theme();
-
-        let mut visuals = match theme.theme_mode {
-            ThemeMode::Light => egui::Visuals::light(),
-            ThemeMode::Dark => egui::Visuals::dark(),
-            ThemeMode::Auto => {
-                // Use system preference or default to light
-                if ctx.style().visuals.dark_mode {
-                    egui::Visuals::dark()
-                } else {
-                    egui::Visuals::light()
-                }
-            }
-        };
-
-        // Apply Material Design 3 colors if theme is loaded
-        let primary_color = theme.get_primary_color();
-        let on_primary = theme.get_on_primary_color();
-        let surface = theme.get_surface_color(visuals.dark_mode);
-        let _on_surface = theme.get_color_by_name("onSurface");
-        let _background = theme.get_color_by_name("background");
-        let _on_background = theme.get_color_by_name("onBackground");
-
-        // Apply colors to visuals
-        visuals.selection.bg_fill = primary_color;
-        visuals.selection.stroke.color = primary_color;
-        visuals.hyperlink_color = primary_color;
-
-        // Button and widget colors
-        visuals.widgets.noninteractive.bg_fill = surface;
-
-        visuals.widgets.inactive.bg_fill = Color32::from_rgba_unmultiplied(
-            primary_color.r(),
-            primary_color.g(),
-            primary_color.b(),
-            20,
-        );
-
-        visuals.widgets.hovered.bg_fill = Color32::from_rgba_unmultiplied(
-            primary_color.r(),
-            primary_color.g(),
-            primary_color.b(),
-            40,
-        );
-
-        visuals.widgets.active.bg_fill = primary_color;
-        visuals.widgets.active.fg_stroke.color = on_primary;
-
-        // Window background
-        visuals.window_fill = surface;
-        visuals.panel_fill = theme.get_color_by_name("surfaceContainer");
-
-        // Text colors
-        // visuals.override_text_color = Some(background);
-
-        // Apply surface colors
-        visuals.extreme_bg_color = theme.get_color_by_name("surfaceContainerLowest");
-
-        ctx.set_visuals(visuals);
-    }
-
     /// Close all open demo windows
     fn close_all_windows(&mut self) {
         self.actionsheet_window.open = false;
+        self.avatar_window.open = false;
         self.badge_window.open = false;
         self.breadcrumbs_window.open = false;
         self.button_window.open = false;
         self.checkbox_window.open = false;
         self.chips_window.open = false;
+        self.commandpalette_window.open = false;
         self.dashcounter_window.open = false;
         self.dialog_window.open = false;
         self.fab_window.open = false;
         self.iconbutton_window.open = false;
         self.list_window.open = false;
         self.menu_window.open = false;
+        self.navigationbar_window.open = false;
+        self.navigationrail_window.open = false;
         self.progress_window.open = false;
         self.radio_window.open = false;
+        self.ripple_window.open = false;
+        self.segmentedbutton_window.open = false;
         self.select_window.open = false;
         self.slider_window.open = false;
+        self.stepper_window.open = false;
         self.switch_window.open = false;
         self.tabs_window.open = false;
+        self.textfield_window.open = false;
         self.datatable_window.open = false;
+        self.datepicker_window.open = false;
         self.drawer_window.open = false;
+        self.emptystate_window.open = false;
+        self.elevation_window.open = false;
         self.imagelist_window.open = false;
         self.layoutgrid_window.open = false;
         self.snackbar_window.open = false;
@@ -329,6 +341,7 @@ impl MaterialApp {
         self.card2_window.open = false;
         self.carousel_window.open = false;
         self.timeline_window.open = false;
+        self.timepicker_window.open = false;
         self.toolbar_window.open = false;
         self.svgemoji_window.open = false;
         self.symbol_window.open = false;
@@ -338,13 +351,8 @@ impl MaterialApp {
 
 impl eframe::App for MaterialApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Apply theme based on settings
-        self.apply_theme(ctx);
-
-        // Global ESC key handler to close all sub windows
-        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
-            self.close_all_windows();
-        }
+        // Apply theme based on settings, only when they changed
+        apply_if_changed(ctx);
 
         // Update the file dialog
         self.file_dialog.update(ctx);
@@ -450,6 +458,26 @@ impl eframe::App for MaterialApp {
 
                 ui.add_space(8.0);
 
+                // Prepared Theme Selection
+                ui.horizontal(|ui| {
+                    ui.label("Theme:");
+                    egui::ComboBox::from_id_salt("prepared_theme")
+                        .selected_text(self.active_theme.clone())
+                        .show_ui(ui, |ui| {
+                            for name in available_theme_names() {
+                                let selected = name == self.active_theme;
+                                if ui.selectable_label(selected, &name).clicked() {
+                                    match activate_theme(&name) {
+                                        Ok(()) => self.active_theme = name,
+                                        Err(e) => eprintln!("Failed to switch theme: {}", e),
+                                    }
+                                }
+                            }
+                        });
+                });
+
+                ui.add_space(8.0);
+
                 // Contrast Level Selection
                 ui.horizontal(|ui| {
                     ui.label("Contrast:");
@@ -484,10 +512,27 @@ impl eframe::App for MaterialApp {
 
                 ui.add_space(8.0);
 
+                let mut animate = theme_animation_enabled();
+                if ui.checkbox(&mut animate, "Animate theme changes").changed() {
+                    set_theme_animation_enabled(animate);
+                }
+
+                let mut rtl = get_rtl();
+                if ui.checkbox(&mut rtl, "Right-to-left layout").changed() {
+                    set_rtl(rtl);
+                }
+
+                ui.add_space(8.0);
+
                 // 49 Color Selectors - always show since we have default theme
                 {
                     ui.group(|ui| {
-                        ui.label("Material Color Tokens:");
+                        ui.horizontal(|ui| {
+                            ui.label("Material Color Tokens:");
+                            if ui.button("Copy Theme Builder JSON").clicked() {
+                                ui.ctx().copy_text(theme.export_material_theme_json());
+                            }
+                        });
                         ui.add_space(4.0);
 
                         let color_names = [
@@ -573,6 +618,10 @@ impl eframe::App for MaterialApp {
                     self.actionsheet_window.open = true;
                 }
 
+                if ui.add(MaterialButton::filled("Avatar Stories")).clicked() {
+                    self.avatar_window.open = true;
+                }
+
                 if ui.add(MaterialButton::filled("Badge Stories")).clicked() {
                     self.badge_window.open = true;
                 }
@@ -593,6 +642,10 @@ impl eframe::App for MaterialApp {
                     self.chips_window.open = true;
                 }
 
+                if ui.add(MaterialButton::filled("Command Palette Stories")).clicked() {
+                    self.commandpalette_window.open = true;
+                }
+
                 if ui
                     .add(MaterialButton::filled("Data Table Stories"))
                     .clicked()
@@ -600,6 +653,13 @@ impl eframe::App for MaterialApp {
                     self.datatable_window.open = true;
                 }
 
+                if ui
+                    .add(MaterialButton::filled("Date Picker Stories"))
+                    .clicked()
+                {
+                    self.datepicker_window.open = true;
+                }
+
                 if ui.add(MaterialButton::filled("Dialog Stories")).clicked() {
                     self.dialog_window.open = true;
                 }
@@ -608,6 +668,20 @@ impl eframe::App for MaterialApp {
                     self.drawer_window.open = true;
                 }
 
+                if ui
+                    .add(MaterialButton::filled("Empty State Stories"))
+                    .clicked()
+                {
+                    self.emptystate_window.open = true;
+                }
+
+                if ui
+                    .add(MaterialButton::filled("Elevation Stories"))
+                    .clicked()
+                {
+                    self.elevation_window.open = true;
+                }
+
                 if ui
                     .add(MaterialButton::filled("Enhanced Card (Card2) Stories"))
                     .clicked()
@@ -656,6 +730,20 @@ impl eframe::App for MaterialApp {
                     self.menu_window.open = true;
                 }
 
+                if ui
+                    .add(MaterialButton::filled("Navigation Bar Stories"))
+                    .clicked()
+                {
+                    self.navigationbar_window.open = true;
+                }
+
+                if ui
+                    .add(MaterialButton::filled("Navigation Rail Stories"))
+                    .clicked()
+                {
+                    self.navigationrail_window.open = true;
+                }
+
                 if ui.add(MaterialButton::filled("Notification Stories")).clicked() {
                     self.notification_window.open = true;
                 }
@@ -668,6 +756,17 @@ impl eframe::App for MaterialApp {
                     self.radio_window.open = true;
                 }
 
+                if ui.add(MaterialButton::filled("Ripple Stories")).clicked() {
+                    self.ripple_window.open = true;
+                }
+
+                if ui
+                    .add(MaterialButton::filled("Segmented Button Stories"))
+                    .clicked()
+                {
+                    self.segmentedbutton_window.open = true;
+                }
+
                 if ui.add(MaterialButton::filled("Select Stories")).clicked() {
                     self.select_window.open = true;
                 }
@@ -684,6 +783,10 @@ impl eframe::App for MaterialApp {
                     self.spreadsheet_window.open = true;
                 }
 
+                if ui.add(MaterialButton::filled("Stepper Stories")).clicked() {
+                    self.stepper_window.open = true;
+                }
+
                 if ui.add(MaterialButton::filled("Switch Stories")).clicked() {
                     self.switch_window.open = true;
                 }
@@ -700,10 +803,24 @@ impl eframe::App for MaterialApp {
                     self.tabs_window.open = true;
                 }
 
+                if ui
+                    .add(MaterialButton::filled("Text Field Stories"))
+                    .clicked()
+                {
+                    self.textfield_window.open = true;
+                }
+
                 if ui.add(MaterialButton::filled("Timeline Stories")).clicked() {
                     self.timeline_window.open = true;
                 }
 
+                if ui
+                    .add(MaterialButton::filled("Time Picker Stories"))
+                    .clicked()
+                {
+                    self.timepicker_window.open = true;
+                }
+
                 if ui.add(MaterialButton::filled("Toolbar Stories")).clicked() {
                     self.toolbar_window.open = true;
                 }
@@ -729,25 +846,36 @@ impl eframe::App for MaterialApp {
 
         // Show demo windows
         self.actionsheet_window.show(ctx);
+        self.avatar_window.show(ctx);
         self.badge_window.show(ctx);
         self.breadcrumbs_window.show(ctx);
         self.button_window.show(ctx);
         self.checkbox_window.show(ctx);
         self.chips_window.show(ctx);
+        self.commandpalette_window.show(ctx);
         self.dialog_window.show(ctx);
         self.fab_window.show(ctx);
         self.iconbutton_window.show(ctx);
         self.list_window.show(ctx);
         self.menu_window.show(ctx);
+        self.navigationbar_window.show(ctx);
+        self.navigationrail_window.show(ctx);
         self.notification_window.show(ctx);
         self.progress_window.show(ctx);
         self.radio_window.show(ctx);
+        self.ripple_window.show(ctx);
+        self.segmentedbutton_window.show(ctx);
         self.select_window.show(ctx);
         self.slider_window.show(ctx);
+        self.stepper_window.show(ctx);
         self.switch_window.show(ctx);
         self.tabs_window.show(ctx);
+        self.textfield_window.show(ctx);
         self.datatable_window.show(ctx);
+        self.datepicker_window.show(ctx);
         self.drawer_window.show(ctx);
+        self.emptystate_window.show(ctx);
+        self.elevation_window.show(ctx);
         self.imagelist_window.show(ctx);
         self.layoutgrid_window.show(ctx);
         self.snackbar_window.show(ctx);
@@ -757,12 +885,19 @@ impl eframe::App for MaterialApp {
         self.carousel_window.show(ctx);
         self.dashcounter_window.show(ctx);
         self.timeline_window.show(ctx);
+        self.timepicker_window.show(ctx);
         self.toolbar_window.show(ctx);
         self.tooltip_window.show(ctx);
         self.treeview_window.show(ctx);
         self.svgemoji_window.show(ctx);
         self.symbol_window.show(ctx);
 
+        // Escape closes all sub windows, unless a dialog, menu or drawer
+        // shown above took it to close itself
+        if dismiss_on_escape(ctx) {
+            self.close_all_windows();
+        }
+
         // let image_bytes = include_bytes!("../../resources/imgur_image.png");
         // egui::Window::new("Test egui::Image")
         //     .open(&mut true)
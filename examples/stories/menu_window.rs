@@ -1,7 +1,7 @@
 #![doc(hidden)]
 
 use crate::menu::{Corner, FocusState, MenuButtonThemeData, MenuStyle, Positioning};
-use crate::{menu, menu_item, MaterialButton, MaterialCheckbox};
+use crate::{material_context_menu, menu, menu_item, MaterialButton, MaterialCheckbox, MenuItem};
 use eframe::egui::{self, Color32, Rect, Window};
 
 #[doc(hidden)]
@@ -54,6 +54,11 @@ pub struct MenuWindow {
     custom_item_height: f32,
     custom_icon_size: f32,
     custom_padding_horizontal: f32,
+    // Last item picked from the right-click context menu
+    last_context_action: Option<String>,
+    // View options toggled from the context menu
+    show_grid: bool,
+    sort_by: &'static str,
 }
 
 impl Default for MenuWindow {
@@ -107,6 +112,9 @@ impl Default for MenuWindow {
             custom_item_height: 48.0,
             custom_icon_size: 24.0,
             custom_padding_horizontal: 12.0,
+            last_context_action: None,
+            show_grid: true,
+            sort_by: "name",
         }
     }
 }
@@ -131,17 +139,7 @@ impl MenuWindow {
             });
         self.open = open;
 
-        // Close menus on Escape key
-        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
-            self.standard_menu_open = false;
-            self.link_menu_open = false;
-            self.submenu_open = false;
-            self.context_menu_open = false;
-            self.button_anchor_open = false;
-            self.icon_anchor_open = false;
-        }
-
-        // Show menus
+        // Show menus; each closes itself on Escape
         self.show_menus(ctx);
         self.show_component_menus(ctx);
     }
@@ -184,68 +182,60 @@ impl MenuWindow {
         let custom_style = self.build_custom_style();
         let custom_button_theme = self.build_custom_button_theme();
 
-        // ButtonAnchorExample menu
-        if self.button_anchor_open {
-            let mut builder = menu("button_anchor_menu", &mut self.button_anchor_open)
-                .item(
-                    menu_item("Item 1")
-                        .leading_icon("people")
-                        .on_click(|| println!("Item 1 clicked!")),
-                )
-                .item(
-                    menu_item("Item 2")
-                        .leading_icon("eye")
-                        .on_click(|| println!("Item 2 clicked!")),
-                )
-                .item(
-                    menu_item("Item 3")
-                        .leading_icon("refresh")
-                        .on_click(|| println!("Item 3 clicked!")),
-                );
-
-            if let Some(rect) = self.button_anchor_rect {
-                builder = builder.anchor_rect(rect);
-            }
-            if let Some(style) = &custom_style {
-                builder = builder.style(style.clone());
-            }
-            if let Some(theme) = &custom_button_theme {
-                builder = builder.button_theme(theme.clone());
-            }
-
-            builder.show(ctx);
+        // ButtonAnchorExample menu, shown while closed too so it can animate out
+        let mut builder = menu("button_anchor_menu", &mut self.button_anchor_open)
+            .item(
+                menu_item("Item 1")
+                    .leading_icon("people")
+                    .on_click(|| println!("Item 1 clicked!")),
+            )
+            .item(
+                menu_item("Item 2")
+                    .leading_icon("eye")
+                    .on_click(|| println!("Item 2 clicked!")),
+            )
+            .item(
+                menu_item("Item 3")
+                    .leading_icon("refresh")
+                    .on_click(|| println!("Item 3 clicked!")),
+            );
+
+        if let Some(rect) = self.button_anchor_rect {
+            builder = builder.anchor_rect(rect);
+        }
+        if let Some(style) = &custom_style {
+            builder = builder.style(style.clone());
+        }
+        if let Some(theme) = &custom_button_theme {
+            builder = builder.button_theme(theme.clone());
         }
 
-        // IconButtonAnchorExample menu
-        if self.icon_anchor_open {
-            let mut builder = menu("icon_anchor_menu", &mut self.icon_anchor_open)
-                .item(menu_item("Menu 1").on_click(|| println!("Menu 1 clicked!")))
-                .item(menu_item("Menu 2").on_click(|| println!("Menu 2 clicked!")))
-                .item(
-                    menu_item("Menu 3.1")
-                        .on_click(|| println!("Menu 3.1 clicked!")),
-                )
-                .item(
-                    menu_item("Menu 3.2")
-                        .on_click(|| println!("Menu 3.2 clicked!")),
-                )
-                .item(
-                    menu_item("Menu 3.3")
-                        .on_click(|| println!("Menu 3.3 clicked!")),
-                );
-
-            if let Some(rect) = self.icon_anchor_rect {
-                builder = builder.anchor_rect(rect);
-            }
-            if let Some(style) = &custom_style {
-                builder = builder.style(style.clone());
-            }
-            if let Some(theme) = &custom_button_theme {
-                builder = builder.button_theme(theme.clone());
-            }
-
-            builder.show(ctx);
+        builder.show(ctx);
+
+        // IconButtonAnchorExample menu, shown while closed too so it can animate out
+        let mut builder = menu("icon_anchor_menu", &mut self.icon_anchor_open)
+            .item(menu_item("Menu 1").on_click(|| println!("Menu 1 clicked!")))
+            .item(menu_item("Menu 2").on_click(|| println!("Menu 2 clicked!")))
+            .item(MenuItem::submenu(
+                "Menu 3",
+                vec![
+                    menu_item("Menu 3.1").on_click(|| println!("Menu 3.1 clicked!")),
+                    menu_item("Menu 3.2").on_click(|| println!("Menu 3.2 clicked!")),
+                    menu_item("Menu 3.3").on_click(|| println!("Menu 3.3 clicked!")),
+                ],
+            ));
+
+        if let Some(rect) = self.icon_anchor_rect {
+            builder = builder.anchor_rect(rect);
         }
+        if let Some(style) = &custom_style {
+            builder = builder.style(style.clone());
+        }
+        if let Some(theme) = &custom_button_theme {
+            builder = builder.button_theme(theme.clone());
+        }
+
+        builder.show(ctx);
     }
 
     /// Render MenuStyle and MenuButtonThemeData controls.
@@ -566,6 +556,47 @@ impl MenuWindow {
                 }
             }
         });
+
+        ui.add_space(8.0);
+        let target = ui.add(
+            egui::Label::new("Right-click here for a Material context menu")
+                .sense(egui::Sense::click()),
+        );
+        let selected = material_context_menu(&target, |menu| {
+            menu.item(
+                MenuItem::new("Cut")
+                    .id("cut")
+                    .leading_icon("content_cut")
+                    .shortcut("Ctrl+X"),
+            )
+            .item(
+                MenuItem::new("Copy")
+                    .id("copy")
+                    .leading_icon("content_copy")
+                    .shortcut("Ctrl+C"),
+            )
+            .item(
+                MenuItem::new("Paste")
+                    .id("paste")
+                    .leading_icon("content_paste")
+                    .shortcut("Ctrl+V"),
+            )
+            .divider()
+            .item(MenuItem::checkable("Show grid", &mut self.show_grid))
+            .divider()
+            .items(MenuItem::radio_group(
+                &mut self.sort_by,
+                [("Sort by name", "name"), ("Sort by date", "date")],
+            ))
+            .divider()
+            .item(MenuItem::new("Delete").id("delete").leading_icon("delete"));
+        });
+        if selected.is_some() {
+            self.last_context_action = selected;
+        }
+        if let Some(action) = &self.last_context_action {
+            ui.label(format!("Last context action: {}", action));
+        }
     }
 
     fn show_menus(&mut self, ctx: &egui::Context) {
@@ -691,19 +722,36 @@ impl MenuWindow {
             };
 
             let mut menu_builder = menu("submenu", &mut self.submenu_open)
-                .item(
-                    menu_item("Fruits")
-                        .leading_icon("expand_more")
-                        .on_click(|| println!("Fruits submenu clicked!")),
-                )
-                .item(
-                    menu_item("Vegetables")
-                        .leading_icon("expand_more")
-                        .on_click(|| println!("Vegetables submenu clicked!")),
-                )
-                .item(apple_sub)
-                .item(avocado_sub)
-                .item(orange_sub)
+                .item(MenuItem::submenu(
+                    "Fruits",
+                    vec![
+                        apple_sub,
+                        avocado_sub,
+                        MenuItem::submenu(
+                            "Citrus",
+                            vec![
+                                orange_sub,
+                                menu_item("Lemon").on_click(|| println!("Lemon clicked!")),
+                                MenuItem::submenu(
+                                    "Limes",
+                                    vec![
+                                        menu_item("Key Lime")
+                                            .on_click(|| println!("Key Lime clicked!")),
+                                        menu_item("Kaffir Lime")
+                                            .on_click(|| println!("Kaffir Lime clicked!")),
+                                    ],
+                                ),
+                            ],
+                        ),
+                    ],
+                ))
+                .item(MenuItem::submenu(
+                    "Vegetables",
+                    vec![
+                        menu_item("Carrot").on_click(|| println!("Carrot clicked!")),
+                        menu_item("Spinach").on_click(|| println!("Spinach clicked!")),
+                    ],
+                ))
                 .anchor_corner(submenu_anchor)
                 .menu_corner(submenu_menu_corner)
                 .default_focus(self.default_focus)
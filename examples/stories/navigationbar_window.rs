@@ -0,0 +1,102 @@
+#![doc(hidden)]
+
+use crate::{BarDestination, LabelBehavior, MaterialBadge, MaterialButton, MaterialNavigationBar};
+use eframe::egui::{self, Ui, Vec2, Window};
+
+#[doc(hidden)]
+pub struct NavigationBarWindow {
+    pub open: bool,
+    selected: usize,
+    label_behavior: LabelBehavior,
+    destination_count: usize,
+    bar_width: f32,
+    show_badges: bool,
+}
+
+impl Default for NavigationBarWindow {
+    fn default() -> Self {
+        Self {
+            open: false,
+            selected: 0,
+            label_behavior: LabelBehavior::Always,
+            destination_count: 4,
+            bar_width: 400.0,
+            show_badges: true,
+        }
+    }
+}
+
+impl NavigationBarWindow {
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let mut open = self.open;
+        Window::new("Navigation Bar Stories")
+            .open(&mut open)
+            .default_size([600.0, 400.0])
+            .show(ctx, |ui| {
+                self.render_controls(ui);
+                ui.add_space(20.0);
+                self.render_bar(ui);
+            });
+        self.open = open;
+    }
+
+    fn render_controls(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.heading("Navigation Bar Controls");
+
+            if ui.add(MaterialButton::filled("Target").small()).clicked() {
+                let _ =
+                    webbrowser::open("https://m3.material.io/components/navigation-bar/overview");
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Labels:");
+            ui.selectable_value(&mut self.label_behavior, LabelBehavior::Always, "Always");
+            ui.selectable_value(
+                &mut self.label_behavior,
+                LabelBehavior::Selected,
+                "Selected",
+            );
+            ui.selectable_value(&mut self.label_behavior, LabelBehavior::Never, "Never");
+        });
+
+        ui.add(egui::Slider::new(&mut self.destination_count, 3..=5).text("Destinations"));
+        ui.add(egui::Slider::new(&mut self.bar_width, 160.0..=560.0).text("Bar width"));
+        ui.checkbox(&mut self.show_badges, "Badges");
+    }
+
+    fn render_bar(&mut self, ui: &mut Ui) {
+        let all = [
+            ("home", "Home"),
+            ("search", "Search"),
+            ("notifications", "Alerts"),
+            ("mail", "Mail"),
+            ("person", "Profile"),
+        ];
+        let destinations: Vec<BarDestination> = all
+            .iter()
+            .take(self.destination_count)
+            .enumerate()
+            .map(|(index, (icon, label))| {
+                let destination = BarDestination::new(*icon).label(*label);
+                match index {
+                    2 if self.show_badges => destination.badge(MaterialBadge::count(12)),
+                    3 if self.show_badges => destination.badge(MaterialBadge::dot()),
+                    _ => destination,
+                }
+            })
+            .collect();
+        self.selected = self.selected.min(self.destination_count - 1);
+
+        ui.label(format!("Selected: {}", all[self.selected].1));
+        ui.add_space(8.0);
+
+        ui.allocate_ui(Vec2::new(self.bar_width, 80.0), |ui| {
+            MaterialNavigationBar::new(&mut self.selected)
+                .destinations(destinations)
+                .label_behavior(self.label_behavior)
+                .show(ui);
+        });
+    }
+}
@@ -0,0 +1,106 @@
+#![doc(hidden)]
+
+use crate::{
+    fab_primary, MaterialBadge, MaterialButton, MaterialNavigationRail, RailAlignment,
+    RailDestination,
+};
+use eframe::egui::{self, Ui, Vec2, Window};
+
+#[doc(hidden)]
+pub struct NavigationRailWindow {
+    pub open: bool,
+    selected: usize,
+    alignment: RailAlignment,
+    show_menu: bool,
+    show_fab: bool,
+    show_badges: bool,
+    last_leading_click: Option<String>,
+}
+
+impl Default for NavigationRailWindow {
+    fn default() -> Self {
+        Self {
+            open: false,
+            selected: 0,
+            alignment: RailAlignment::Top,
+            show_menu: true,
+            show_fab: true,
+            show_badges: true,
+            last_leading_click: None,
+        }
+    }
+}
+
+impl NavigationRailWindow {
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let mut open = self.open;
+        Window::new("Navigation Rail Stories")
+            .open(&mut open)
+            .default_size([600.0, 600.0])
+            .show(ctx, |ui| {
+                self.render_controls(ui);
+                ui.add_space(20.0);
+                self.render_rail(ui);
+            });
+        self.open = open;
+    }
+
+    fn render_controls(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.heading("Navigation Rail Controls");
+
+            if ui.add(MaterialButton::filled("Target").small()).clicked() {
+                let _ =
+                    webbrowser::open("https://m3.material.io/components/navigation-rail/overview");
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Alignment:");
+            ui.selectable_value(&mut self.alignment, RailAlignment::Top, "Top");
+            ui.selectable_value(&mut self.alignment, RailAlignment::Center, "Center");
+            ui.selectable_value(&mut self.alignment, RailAlignment::Bottom, "Bottom");
+        });
+
+        ui.checkbox(&mut self.show_menu, "Menu button");
+        ui.checkbox(&mut self.show_fab, "FAB");
+        ui.checkbox(&mut self.show_badges, "Badges");
+    }
+
+    fn render_rail(&mut self, ui: &mut Ui) {
+        let labels = ["Inbox", "Articles", "Chat", "Video"];
+        let mut destinations = vec![
+            RailDestination::new("inbox", labels[0]),
+            RailDestination::new("article", labels[1]),
+            RailDestination::new("chat_bubble", labels[2]),
+            RailDestination::new("videocam", labels[3]),
+        ];
+        if self.show_badges {
+            destinations[0] = destinations[0].clone().badge(MaterialBadge::count(3));
+            destinations[2] = destinations[2].clone().badge(MaterialBadge::dot());
+        }
+
+        let size = Vec2::new(ui.available_width(), 420.0);
+        ui.allocate_ui(size, |ui| {
+            ui.horizontal_top(|ui| {
+                let mut rail = MaterialNavigationRail::new(&mut self.selected)
+                    .destinations(destinations)
+                    .alignment(self.alignment)
+                    .menu_button(self.show_menu);
+                if self.show_fab {
+                    rail = rail.fab(fab_primary().icon("edit"));
+                }
+                if rail.show(ui).leading_clicked {
+                    self.last_leading_click = Some("Leading item clicked".to_string());
+                }
+
+                ui.vertical(|ui| {
+                    ui.heading(labels[self.selected.min(labels.len() - 1)]);
+                    if let Some(message) = &self.last_leading_click {
+                        ui.label(message);
+                    }
+                });
+            });
+        });
+    }
+}
@@ -1,7 +1,9 @@
 #![doc(hidden)]
 
 use egui_material3::material_symbol::{ICON_PAUSE, ICON_PLAY_ARROW};
-use crate::{circular_progress, icon_button_standard, linear_progress, MaterialButton};
+use crate::{
+    circular_progress, icon_button_standard, linear_progress, MaterialButton, MaterialProgress,
+};
 use eframe::egui::{self, Color32, Vec2, Window};
 
 #[doc(hidden)]
@@ -133,8 +135,7 @@ impl ProgressWindow {
 
         // Determinate with all features
         ui.label("Determinate:");
-        let progress = linear_progress()
-            .value(self.value)
+        let progress = linear_progress(self.value)
             .max(self.max)
             .buffer(self.buffer)
             .width(400.0)
@@ -148,8 +149,7 @@ impl ProgressWindow {
 
         // Indeterminate
         ui.label("Indeterminate:");
-        let mut indet_progress = linear_progress()
-            .indeterminate(true)
+        let mut indet_progress = MaterialProgress::linear_indeterminate()
             .width(400.0)
             .height(self.track_height)
             .track_gap(self.track_gap)
@@ -163,8 +163,7 @@ impl ProgressWindow {
 
         // Interactive (controlled by sliders)
         ui.label("Interactive (controlled by settings):");
-        let mut interactive = linear_progress()
-            .value(if self.indeterminate { 0.0 } else { self.value })
+        let mut interactive = linear_progress(if self.indeterminate { 0.0 } else { self.value })
             .max(self.max)
             .width(400.0)
             .height(self.track_height)
@@ -191,8 +190,7 @@ impl ProgressWindow {
         // Custom themed linear progress
         if self.custom_theme {
             ui.label("Custom Themed:");
-            let custom = linear_progress()
-                .value(if self.indeterminate { 0.0 } else { self.value })
+            let custom = linear_progress(if self.indeterminate { 0.0 } else { self.value })
                 .indeterminate(self.indeterminate)
                 .width(400.0)
                 .height(self.track_height)
@@ -216,8 +214,7 @@ impl ProgressWindow {
             ui.vertical(|ui| {
                 ui.label("Determinate:");
                 ui.add(
-                    circular_progress()
-                        .value(self.value)
+                    circular_progress(self.value)
                         .max(self.max)
                         .stroke_width(self.stroke_width)
                         .track_gap(self.track_gap),
@@ -229,8 +226,7 @@ impl ProgressWindow {
             // Indeterminate
             ui.vertical(|ui| {
                 ui.label("Indeterminate:");
-                let mut indet = circular_progress()
-                    .indeterminate(true)
+                let mut indet = circular_progress(None)
                     .stroke_width(self.stroke_width)
                     .track_gap(self.track_gap);
                 if self.four_color {
@@ -244,14 +240,13 @@ impl ProgressWindow {
             // Interactive
             ui.vertical(|ui| {
                 ui.label("Interactive:");
-                let mut interactive = circular_progress()
-                    .value(if self.indeterminate { 0.0 } else { self.value })
-                    .max(self.max)
-                    .stroke_width(self.stroke_width)
-                    .track_gap(self.track_gap);
-                if self.indeterminate {
-                    interactive = interactive.indeterminate(true);
-                }
+                // The same indicator switches between a labelled value and a spinner
+                let mut interactive =
+                    circular_progress((!self.indeterminate).then_some(self.value))
+                        .max(self.max)
+                        .stroke_width(self.stroke_width)
+                        .track_gap(self.track_gap)
+                        .with_label();
                 if self.four_color {
                     interactive = interactive.four_color_enabled(true);
                 }
@@ -263,9 +258,7 @@ impl ProgressWindow {
                 ui.vertical(|ui| {
                     ui.label("Custom Themed:");
                     ui.add(
-                        circular_progress()
-                            .value(if self.indeterminate { 0.0 } else { self.value })
-                            .indeterminate(self.indeterminate)
+                        circular_progress((!self.indeterminate).then_some(self.value))
                             .stroke_width(self.stroke_width)
                             .track_gap(self.track_gap)
                             .active_color(Color32::from_rgb(50, 180, 50))
@@ -293,7 +286,11 @@ impl ProgressWindow {
         if self.content_loading {
             // Show loading state with centered spinner (mimics about.dart lines 812, 1077)
             ui.horizontal(|ui| {
-                ui.add(circular_progress().indeterminate(true).size(Vec2::splat(36.0)).stroke_width(3.0));
+                ui.add(
+                    circular_progress(None)
+                        .size(Vec2::splat(36.0))
+                        .stroke_width(3.0),
+                );
                 ui.add_space(12.0);
                 ui.label("Loading content...");
             });
@@ -328,8 +325,7 @@ impl ProgressWindow {
             ui.vertical(|ui| {
                 ui.label("Player Progress:");
                 ui.add(
-                    circular_progress()
-                        .value(self.player_progress)
+                    circular_progress(self.player_progress)
                         .stroke_width(self.stroke_width)
                         .track_gap(self.track_gap),
                 );
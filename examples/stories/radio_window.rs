@@ -28,6 +28,7 @@ pub struct RadioWindow {
     labeled_radio_selected: Option<usize>,
     animals_selected: Option<Animal>,
     music_selected: Option<MusicGenre>,
+    quality_selected: Option<usize>,
     // New examples
     toggleable_selected: Option<usize>,
     themed_selected: Option<usize>,
@@ -46,6 +47,7 @@ impl Default for RadioWindow {
             labeled_radio_selected: Some(1),
             animals_selected: Some(Animal::Dogs),
             music_selected: None,
+            quality_selected: Some(1),
             toggleable_selected: Some(1),
             themed_selected: Some(0),
             string_selected: Some("option_a".to_string()),
@@ -72,6 +74,8 @@ impl RadioWindow {
                     ui.add_space(20.0);
                     self.render_radio_groups(ui);
                     ui.add_space(20.0);
+                    self.render_horizontal_radio_group(ui);
+                    ui.add_space(20.0);
                     self.render_toggleable_radios(ui);
                     ui.add_space(20.0);
                     self.render_themed_radios(ui);
@@ -205,6 +209,19 @@ impl RadioWindow {
             ui.label(format!("Music: {:?}", self.music_selected));
         });
     }
+    fn render_horizontal_radio_group(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Horizontal Radio Group with Helper Text");
+        ui.label("Options wrap onto the next line when the window is narrow:");
+
+        let quality_group = radio_group(&mut self.quality_selected)
+            .option_with_helper_text(0, "Data saver", "Lower quality, less data")
+            .option_with_helper_text(1, "Standard", "Balanced quality")
+            .option_with_helper_text(2, "High", "Best on Wi-Fi")
+            .horizontal(true)
+            .enabled(!self.disabled);
+        ui.add(quality_group);
+    }
+
     fn render_toggleable_radios(&mut self, ui: &mut egui::Ui) {
         ui.heading("Toggleable Radios");
         ui.label("Click a selected radio to deselect it:");
@@ -0,0 +1,83 @@
+#![doc(hidden)]
+
+use crate::theme::get_global_color;
+use crate::{MaterialButton, Ripple};
+use eframe::egui::{self, Align2, FontId, Sense, Ui, Vec2, Window};
+
+#[doc(hidden)]
+pub struct RippleWindow {
+    pub open: bool,
+    corner_radius: f32,
+    state_layer: bool,
+    clicks: usize,
+}
+
+impl Default for RippleWindow {
+    fn default() -> Self {
+        Self {
+            open: false,
+            corner_radius: 12.0,
+            state_layer: true,
+            clicks: 0,
+        }
+    }
+}
+
+impl RippleWindow {
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let mut open = self.open;
+        Window::new("Ripple Stories")
+            .open(&mut open)
+            .default_size([500.0, 400.0])
+            .show(ctx, |ui| {
+                self.render_controls(ui);
+                ui.add_space(20.0);
+                self.render_surfaces(ui);
+            });
+        self.open = open;
+    }
+
+    fn render_controls(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.heading("Ripple Controls");
+
+            if ui.add(MaterialButton::filled("Target").small()).clicked() {
+                let _ = webbrowser::open("https://m3.material.io/foundations/interaction/states");
+            }
+        });
+
+        ui.add(egui::Slider::new(&mut self.corner_radius, 0.0..=60.0).text("Corner radius"));
+        ui.checkbox(&mut self.state_layer, "Hover/focus state layer");
+        ui.label(format!("Clicks: {}", self.clicks));
+    }
+
+    fn render_surfaces(&mut self, ui: &mut Ui) {
+        ui.label("Press anywhere on a surface; the ripple starts at the pointer:");
+        ui.horizontal(|ui| {
+            for (container, content, label) in [
+                ("primary", "onPrimary", "Primary"),
+                ("secondaryContainer", "onSecondaryContainer", "Tonal"),
+                ("surfaceContainerHighest", "onSurface", "Surface"),
+            ] {
+                let (rect, response) =
+                    ui.allocate_exact_size(Vec2::new(140.0, 120.0), Sense::click());
+                ui.painter()
+                    .rect_filled(rect, self.corner_radius, get_global_color(container));
+                Ripple::new(get_global_color(content))
+                    .corner_radius(self.corner_radius)
+                    .state_layer(self.state_layer)
+                    .show(ui, rect, &response);
+                ui.painter().text(
+                    rect.center(),
+                    Align2::CENTER_CENTER,
+                    label,
+                    FontId::proportional(14.0),
+                    get_global_color(content),
+                );
+                if response.clicked() {
+                    self.clicks += 1;
+                }
+            }
+        });
+    }
+}
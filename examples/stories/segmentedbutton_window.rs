@@ -0,0 +1,110 @@
+#![doc(hidden)]
+
+use crate::{ButtonSegment, MaterialButton, MaterialSegmentedButton};
+use eframe::egui::{self, Ui, Window};
+
+#[doc(hidden)]
+pub struct SegmentedButtonSetWindow {
+    pub open: bool,
+    show_checkmark: bool,
+    disable_segment: bool,
+    view: usize,
+    period: usize,
+    sizes: Vec<bool>,
+    formats: Vec<bool>,
+}
+
+impl Default for SegmentedButtonSetWindow {
+    fn default() -> Self {
+        Self {
+            open: false,
+            show_checkmark: true,
+            disable_segment: false,
+            view: 0,
+            period: 1,
+            sizes: vec![false, true, false, false],
+            formats: vec![false; 3],
+        }
+    }
+}
+
+impl SegmentedButtonSetWindow {
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let mut open = self.open;
+        Window::new("Segmented Button Stories")
+            .open(&mut open)
+            .default_size([600.0, 400.0])
+            .show(ctx, |ui| {
+                self.render_controls(ui);
+                ui.add_space(20.0);
+                self.render_single_select(ui);
+                ui.add_space(20.0);
+                self.render_multi_select(ui);
+            });
+        self.open = open;
+    }
+
+    fn render_controls(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.heading("Segmented Button Controls");
+
+            if ui.add(MaterialButton::filled("Target").small()).clicked() {
+                let _ = webbrowser::open(
+                    "https://m3.material.io/components/segmented-buttons/overview",
+                );
+            }
+        });
+
+        ui.checkbox(&mut self.show_checkmark, "Show checkmark");
+        ui.checkbox(&mut self.disable_segment, "Disable last segment");
+    }
+
+    fn render_single_select(&mut self, ui: &mut Ui) {
+        ui.heading("Single-select");
+
+        ui.label("Icon and label:");
+        MaterialSegmentedButton::single(&mut self.view)
+            .id("view_toggle")
+            .segment(ButtonSegment::new("List").icon("view_list"))
+            .segment(ButtonSegment::new("Grid").icon("grid_view"))
+            .show_checkmark(self.show_checkmark)
+            .show(ui);
+
+        ui.add_space(8.0);
+        ui.label("Label only:");
+        let response = MaterialSegmentedButton::single(&mut self.period)
+            .id("period")
+            .segment(ButtonSegment::new("Day"))
+            .segment(ButtonSegment::new("Week"))
+            .segment(ButtonSegment::new("Month"))
+            .segment(ButtonSegment::new("Year").enabled(!self.disable_segment))
+            .show_checkmark(self.show_checkmark)
+            .show(ui);
+        if let Some(selected) = response.selected {
+            ui.label(format!("Selected index: {}", selected));
+        }
+    }
+
+    fn render_multi_select(&mut self, ui: &mut Ui) {
+        ui.heading("Multi-select");
+
+        MaterialSegmentedButton::multi(&mut self.sizes)
+            .id("sizes")
+            .segment(ButtonSegment::new("XS"))
+            .segment(ButtonSegment::new("S"))
+            .segment(ButtonSegment::new("M"))
+            .segment(ButtonSegment::new("L").enabled(!self.disable_segment))
+            .show_checkmark(self.show_checkmark)
+            .show(ui);
+
+        ui.add_space(8.0);
+        ui.label("Icon only:");
+        MaterialSegmentedButton::multi(&mut self.formats)
+            .id("formats")
+            .segment(ButtonSegment::icon_only("format_bold"))
+            .segment(ButtonSegment::icon_only("format_italic"))
+            .segment(ButtonSegment::icon_only("format_underlined"))
+            .show_checkmark(false)
+            .show(ui);
+    }
+}
@@ -1,6 +1,6 @@
 #![doc(hidden)]
 
-use crate::{select, MaterialButton, SelectVariant};
+use crate::{select, MaterialButton, MaterialSelect, SelectVariant};
 use eframe::egui::{self, Window};
 
 #[doc(hidden)]
@@ -35,6 +35,8 @@ pub struct SelectWindow {
     countries_select: Option<usize>,
     long_text_select: Option<usize>,
     many_options_select: Option<usize>,
+    searchable_select: Option<usize>,
+    multi_select: Vec<usize>,
     // New variant examples
     variant_demo_filled: Option<usize>,
     variant_demo_outlined: Option<usize>,
@@ -75,6 +77,8 @@ impl Default for SelectWindow {
             countries_select: None,
             long_text_select: None,
             many_options_select: None,
+            searchable_select: None,
+            multi_select: vec![0, 2],
             // New variant examples
             variant_demo_filled: Some(1),
             variant_demo_outlined: Some(1),
@@ -596,6 +600,45 @@ impl SelectWindow {
                     
                     ui.label("⚠️ This select tests scroll attachment to edge.");
                 });
+
+                ui.add_space(20.0);
+
+                ui.vertical(|ui| {
+                    ui.label("Searchable (Filter as you type):").on_hover_text("Type to narrow the options, use the arrow keys to move and Enter to select");
+                    let mut searchable_select = select(&mut self.searchable_select)
+                        .variant(self.variant)
+                        .label("Language")
+                        .searchable(true)
+                        .width(220.0);
+
+                    let languages = [
+                        "Rust", "Python", "JavaScript", "TypeScript", "Go", "Kotlin", "Swift",
+                        "Haskell", "OCaml", "Elixir", "Erlang", "Clojure", "Scala", "Zig",
+                    ];
+                    for (i, language) in languages.iter().enumerate() {
+                        searchable_select = searchable_select.option(i, *language);
+                    }
+
+                    ui.add(searchable_select);
+                    ui.label(format!("Selected: {:?}", self.searchable_select));
+                });
+
+                ui.add_space(20.0);
+
+                ui.vertical(|ui| {
+                    ui.label("Multi-select (Column picker):").on_hover_text("Toggle several options; the menu stays open while toggling");
+                    let columns = ["Product", "Category", "Price", "Stock", "Supplier", "Updated"];
+                    let multi_select = MaterialSelect::multi(&mut self.multi_select, columns)
+                        .variant(self.variant)
+                        .label("Visible columns")
+                        .placeholder("No columns")
+                        .width(220.0);
+
+                    if ui.add(multi_select).changed() {
+                        println!("Visible columns: {:?}", self.multi_select);
+                    }
+                    ui.label(format!("Selected: {:?}", self.multi_select));
+                });
             });
             
         });
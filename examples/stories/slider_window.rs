@@ -1,6 +1,9 @@
 #![doc(hidden)]
 
-use crate::{slider, range_slider, MaterialButton, RangeValues, SliderInteraction, ThumbShape};
+use crate::{
+    range_slider, slider, MaterialButton, MaterialSlider, RangeThumb, RangeValues,
+    SliderInteraction, ThumbShape, ValueIndicator,
+};
 use eframe::egui::{self, Window};
 
 #[doc(hidden)]
@@ -12,12 +15,15 @@ pub struct SliderWindow {
     continuous_value: f32,
     labeled_value: f32,
     stepped_value: f32,
+    quantity_value: f32,
     
     // Range slider values
     range_values: RangeValues,
     price_range: RangeValues,
     time_range: RangeValues,
     age_range: RangeValues,
+    tuple_range: (f32, f32),
+    last_moved_thumb: Option<RangeThumb>,
 
     // Real-world examples
     brightness_value: f32,
@@ -39,6 +45,7 @@ pub struct SliderWindow {
     // Value indicators
     indicator_continuous: f32,
     indicator_discrete: f32,
+    indicator_frequency: f32,
     
     // Thumb shapes
     round_value: f32,
@@ -56,10 +63,13 @@ impl Default for SliderWindow {
             continuous_value: 50.0,
             labeled_value: 30.0,
             stepped_value: 40.0,
+            quantity_value: 3.0,
             range_values: RangeValues::new(20.0, 80.0),
             price_range: RangeValues::new(100.0, 500.0),
             time_range: RangeValues::new(9.0, 17.0),
             age_range: RangeValues::new(25.0, 45.0),
+            tuple_range: (30.0, 70.0),
+            last_moved_thumb: None,
             brightness_value: 80.0,
             contrast_value: 50.0,
             saturation_value: 50.0,
@@ -73,6 +83,7 @@ impl Default for SliderWindow {
             slide_thumb_value: 50.0,
             indicator_continuous: 50.0,
             indicator_discrete: 50.0,
+            indicator_frequency: 4400.0,
             round_value: 50.0,
             handle_value: 50.0,
             temperature_value: 72.0,
@@ -156,6 +167,18 @@ impl SliderWindow {
                 stepped_slider = stepped_slider.enabled(false);
             }
             ui.add(stepped_slider);
+
+            ui.add_space(10.0);
+
+            ui.label("Uneven steps (0 - 10 by 3, ends at 10; Page Up/Down jumps):");
+            let mut quantity_slider = slider(&mut self.quantity_value, 0.0..=10.0)
+                .step(3.0)
+                .text("Quantity")
+                .width(300.0);
+            if self.disabled {
+                quantity_slider = quantity_slider.enabled(false);
+            }
+            ui.add(quantity_slider);
         });
     }
 
@@ -178,6 +201,24 @@ impl SliderWindow {
                 "Selected range: {:.0} - {:.0}",
                 self.range_values.start, self.range_values.end
             ));
+
+            ui.add_space(10.0);
+
+            ui.label("Tuple Range (Tab between thumbs, arrow keys to nudge):");
+            let mut tuple_slider = MaterialSlider::range(&mut self.tuple_range, 0.0..=100.0)
+                .step(5.0)
+                .width(300.0);
+            if self.disabled {
+                tuple_slider = tuple_slider.enabled(false);
+            }
+            let response = tuple_slider.show(ui);
+            if response.changed() {
+                self.last_moved_thumb = response.moved;
+            }
+            ui.label(format!(
+                "Selected range: {:.0} - {:.0} (last moved: {:?})",
+                self.tuple_range.0, self.tuple_range.1, self.last_moved_thumb
+            ));
         });
     }
 
@@ -432,6 +473,18 @@ impl SliderWindow {
                 discrete_indicator = discrete_indicator.enabled(false);
             }
             ui.add(discrete_indicator);
+
+            ui.add_space(10.0);
+
+            ui.label("Large range, always showing a formatted value:");
+            let mut frequency = slider(&mut self.indicator_frequency, 20.0..=20_000.0)
+                .value_indicator(ValueIndicator::Always)
+                .value_formatter(|v| format!("{:.0} Hz", v))
+                .width(300.0);
+            if self.disabled {
+                frequency = frequency.enabled(false);
+            }
+            ui.add(frequency);
         });
     }
 
@@ -1,6 +1,10 @@
 #![doc(hidden)]
 
-use crate::{snackbar, snackbar_with_action, MaterialButton, MaterialCheckbox, SnackbarPosition, SnackBarBehavior};
+use crate::theme::{get_reduced_motion, set_reduced_motion};
+use crate::{
+    snackbar, MaterialButton, MaterialCheckbox, MaterialSnackbar, SnackBarBehavior, SnackbarHost,
+    SnackbarPosition,
+};
 use eframe::egui::{self, Ui, Window};
 use std::time::Instant;
 
@@ -26,6 +30,9 @@ pub struct SnackbarWindow {
     show_leading_icon: bool,
     leading_icon: String,
     action_overflow_threshold: f32,
+    // Queued snackbars
+    host: SnackbarHost<usize>,
+    queued_count: usize,
 }
 
 impl Default for SnackbarWindow {
@@ -49,6 +56,8 @@ impl Default for SnackbarWindow {
             show_leading_icon: false,
             leading_icon: "✓".to_string(),
             action_overflow_threshold: 0.25,
+            host: SnackbarHost::new().id_salt("snackbar_window_host"),
+            queued_count: 0,
         }
     }
 }
@@ -70,6 +79,9 @@ impl SnackbarWindow {
 
         // Render snackbars outside the window (they should overlay everything)
         self.render_active_snackbars(ctx);
+        if let Some(index) = self.host.render(ctx) {
+            println!("Undo clicked for queued message #{}", index);
+        }
 
         // Request repaint to ensure auto-dismiss works properly
         if self.show_basic_snackbar || self.show_action_snackbar || self.show_top_snackbar {
@@ -276,6 +288,48 @@ impl SnackbarWindow {
                 self.action_snackbar_start = Some(Instant::now());
             }
         });
+
+        ui.add_space(20.0);
+        ui.heading("Snackbar Queue");
+        ui.label("Messages are shown one at a time, in the order they were queued.");
+        ui.label("Drag a snackbar sideways to swipe it away.");
+
+        let mut reduced_motion = get_reduced_motion();
+        if ui
+            .add(MaterialCheckbox::new(&mut reduced_motion, "Reduced Motion"))
+            .changed()
+        {
+            set_reduced_motion(reduced_motion);
+        }
+
+        ui.horizontal_wrapped(|ui| {
+            if ui.add(MaterialButton::filled("Queue Message")).clicked() {
+                self.queued_count += 1;
+                self.host
+                    .show(format!("Queued message #{}", self.queued_count), None);
+            }
+
+            if ui.add(MaterialButton::filled("Queue With Undo")).clicked() {
+                self.queued_count += 1;
+                self.host.show(
+                    format!("Deleted item #{}", self.queued_count),
+                    Some(("Undo", self.queued_count)),
+                );
+            }
+
+            if ui.add(MaterialButton::outlined("Queue Three")).clicked() {
+                for _ in 0..3 {
+                    self.queued_count += 1;
+                    self.host
+                        .show(format!("Queued message #{}", self.queued_count), None);
+                }
+            }
+
+            if ui.add(MaterialButton::outlined("Clear Queue")).clicked() {
+                self.host.clear();
+            }
+        });
+        ui.label(format!("Waiting: {}", self.host.queued_len()));
     }
 
     fn render_active_snackbars(&mut self, ctx: &egui::Context) {
@@ -363,12 +417,13 @@ impl SnackbarWindow {
                     let message = self.message_text.clone();
                     let action_text = self.action_text.clone();
 
-                    let mut snackbar = snackbar_with_action(message, action_text, || {
-                        println!("Snackbar action clicked!");
-                    })
-                    .auto_dismiss(None)
-                    .behavior(self.behavior)
-                    .action_overflow_threshold(self.action_overflow_threshold);
+                    let mut snackbar = MaterialSnackbar::new(message)
+                        .action(action_text, || {
+                            println!("Snackbar action clicked!");
+                        })
+                        .auto_dismiss(None)
+                        .behavior(self.behavior)
+                        .action_overflow_threshold(self.action_overflow_threshold);
 
                     if self.use_custom_width {
                         snackbar = snackbar.width(self.custom_width);
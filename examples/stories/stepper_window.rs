@@ -0,0 +1,117 @@
+#![doc(hidden)]
+
+use crate::{stepper, MaterialCheckbox, Step, StepperOrientation};
+use eframe::egui::{self, Ui, Window};
+
+#[doc(hidden)]
+pub struct StepperWindow {
+    pub open: bool,
+    vertical: bool,
+    linear: bool,
+    disable_third_step: bool,
+    active_step: usize,
+    name: String,
+    newsletter: bool,
+    last_event: String,
+}
+
+impl Default for StepperWindow {
+    fn default() -> Self {
+        Self {
+            open: false,
+            vertical: false,
+            linear: true,
+            disable_third_step: false,
+            active_step: 0,
+            name: String::new(),
+            newsletter: false,
+            last_event: "None".to_string(),
+        }
+    }
+}
+
+impl StepperWindow {
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let mut open = self.open;
+        Window::new("Stepper Stories")
+            .open(&mut open)
+            .default_size([800.0, 600.0])
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    self.render_controls(ui);
+                    ui.add_space(20.0);
+                    self.render_onboarding(ui);
+                });
+            });
+        self.open = open;
+    }
+
+    fn render_controls(&mut self, ui: &mut Ui) {
+        ui.heading("Stepper Controls");
+        ui.horizontal(|ui| {
+            ui.add(MaterialCheckbox::new(&mut self.vertical, "Vertical"));
+            ui.add(MaterialCheckbox::new(&mut self.linear, "Linear"));
+            ui.add(MaterialCheckbox::new(
+                &mut self.disable_third_step,
+                "Disable \"Preferences\"",
+            ));
+        });
+        ui.label(format!("Last event: {}", self.last_event));
+    }
+
+    fn render_onboarding(&mut self, ui: &mut Ui) {
+        ui.heading("Onboarding Flow");
+
+        let orientation = if self.vertical {
+            StepperOrientation::Vertical
+        } else {
+            StepperOrientation::Horizontal
+        };
+        let name = &mut self.name;
+        let newsletter = &mut self.newsletter;
+        let name_entered = !name.trim().is_empty();
+
+        let response = stepper(&mut self.active_step)
+            .orientation(orientation)
+            .linear(self.linear)
+            .step(Step::new("Account").content(|ui| {
+                ui.label("What should we call you?");
+                ui.text_edit_singleline(name);
+            }))
+            .step(
+                Step::new("Profile")
+                    .supporting_text("Optional")
+                    .content(|ui| {
+                        ui.label("Add a photo and a short bio later from your profile page.");
+                    }),
+            )
+            .step(
+                Step::new("Preferences")
+                    .enabled(!self.disable_third_step)
+                    .content(|ui| {
+                        ui.add(MaterialCheckbox::new(
+                            newsletter,
+                            "Subscribe to the newsletter",
+                        ));
+                    }),
+            )
+            .step(Step::new("Done").content(|ui| {
+                ui.label("Review your choices and press Finish.");
+            }))
+            .show(ui);
+
+        if response.finished {
+            self.last_event = if name_entered {
+                format!("Finished as {}", self.name.trim())
+            } else {
+                "Finished without a name".to_string()
+            };
+        } else if response.next_clicked {
+            self.last_event = format!("Next to step {}", response.active + 1);
+        } else if response.back_clicked {
+            self.last_event = format!("Back to step {}", response.active + 1);
+        } else if let Some(index) = response.step_clicked {
+            self.last_event = format!("Jumped to step {}", index + 1);
+        }
+    }
+}
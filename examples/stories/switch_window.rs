@@ -131,8 +131,7 @@ impl SwitchWindow {
             if self.show_only_selected_icon {
                 basic_switch_control = basic_switch_control.selected_icon(ICON_CHECK);
             } else {
-                basic_switch_control =
-                    basic_switch_control.with_icons(ICON_CHECK, ICON_CLOSE);
+                basic_switch_control = basic_switch_control.icons(true);
             }
         }
 
@@ -283,7 +282,9 @@ impl SwitchWindow {
 
         ui.vertical(|ui| {
             // Wi-Fi switch
-            let mut wifi_switch = switch(&mut self.wifi_enabled).text("Wi-Fi");
+            let mut wifi_switch = switch(&mut self.wifi_enabled)
+                .icons(true)
+                .with_label("Wi-Fi");
             if self.disabled {
                 wifi_switch = wifi_switch.enabled(false);
             }
@@ -1,6 +1,6 @@
 #![doc(hidden)]
 
-use crate::{tabs_primary, tabs_secondary, MaterialButton};
+use crate::{tabs_primary, tabs_secondary, MaterialBadge, MaterialButton, TabItem};
 use eframe::egui::{self, Window};
 
 #[doc(hidden)]
@@ -24,6 +24,10 @@ pub struct TabsWindow {
     m3_secondary_selected: usize,
     icon_text_primary_selected: usize,
     icon_text_secondary_selected: usize,
+    // Editor tabs
+    editor_tabs: Vec<String>,
+    editor_selected: usize,
+    editor_next_file: usize,
 }
 
 impl Default for TabsWindow {
@@ -47,6 +51,13 @@ impl Default for TabsWindow {
             m3_secondary_selected: 0,
             icon_text_primary_selected: 0,
             icon_text_secondary_selected: 0,
+            editor_tabs: vec![
+                "main.rs".to_string(),
+                "lib.rs".to_string(),
+                "tabs.rs".to_string(),
+            ],
+            editor_selected: 0,
+            editor_next_file: 1,
         }
     }
 }
@@ -74,6 +85,8 @@ impl TabsWindow {
                     self.render_custom_tabs(ui);
                     ui.add_space(20.0);
                     self.render_nested_tabs(ui);
+                    ui.add_space(20.0);
+                    self.render_editor_tabs(ui);
                 });
             });
         self.open = open;
@@ -115,72 +128,72 @@ impl TabsWindow {
         ui.push_id("primary_tabs_section", |ui| {
             ui.heading("Primary Tabs");
 
-            // Music instrument tabs
-            ui.add(
-                tabs_primary(&mut self.music_primary_selected)
-                    .id_salt("music_primary")
-                    .tab("🎹 Keyboard")
-                    .tab("🎸 Guitar")
-                    .tab("🥁 Drums")
-                    .tab("🎻 Bass")
-                    .tab("🎷 Saxophone"),
-            );
-
-            // Enhanced content for selected tab
-            ui.add_space(10.0);
-            ui.separator();
-            ui.add_space(10.0);
-
-            match self.music_primary_selected {
-                0 => {
-                    ui.heading("🎹 Keyboard");
-                    ui.label("Master the art of keyboard playing with these beautiful melodies:");
-                    ui.add_space(8.0);
-                    ui.label("• Classical compositions: Bach, Mozart, Chopin");
-                    ui.label("• Modern pieces: Jazz standards and pop hits");
-                    ui.label("• Technique: Scales, arpeggios, and finger exercises");
-                    ui.label("• Recommended practice: 30 minutes daily");
-                }
-                1 => {
-                    ui.heading("🎸 Guitar");
-                    ui.label("Strum your way to guitar mastery:");
-                    ui.add_space(8.0);
-                    ui.label("• Basic chords: G, C, D, Em, Am");
-                    ui.label("• Strumming patterns: Down-up, fingerpicking");
-                    ui.label("• Popular songs: Wonderwall, Hotel California");
-                    ui.label("• Equipment: Acoustic vs. Electric guitars");
-                }
-                2 => {
-                    ui.heading("🥁 Drums");
-                    ui.label("Keep the rhythm alive with powerful beats:");
-                    ui.add_space(8.0);
-                    ui.label("• Basic beats: 4/4 time, rock, jazz, funk");
-                    ui.label("• Rudiments: Paradiddles, flams, rolls");
-                    ui.label("• Kit setup: Kick, snare, hi-hat, toms");
-                    ui.label("• Timing: Use metronome for practice");
-                }
-                3 => {
-                    ui.heading("🎻 Bass");
-                    ui.label("Lay down the foundation with deep bass lines:");
-                    ui.add_space(8.0);
-                    ui.label("• Playing techniques: Fingerstyle, slap, pick");
-                    ui.label("• Music theory: Root notes, walking bass");
-                    ui.label("• Rhythm section: Locking in with drums");
-                    ui.label("• Genres: Rock, funk, jazz, reggae");
-                }
-                4 => {
-                    ui.heading("🎷 Saxophone");
-                    ui.label("Create smooth jazz vibes with saxophone:");
-                    ui.add_space(8.0);
-                    ui.label("• Saxophone types: Alto, tenor, soprano, baritone");
-                    ui.label("• Breathing technique: Diaphragmatic breathing");
-                    ui.label("• Embouchure: Proper mouthpiece positioning");
-                    ui.label("• Jazz standards: Take Five, Giant Steps");
-                }
-                _ => {
-                    ui.label("No instrument selected");
-                }
-            }
+            // Music instrument tabs; only the selected instrument's panel is rendered
+            tabs_primary(&mut self.music_primary_selected)
+                .id_salt("music_primary")
+                .tab("🎹 Keyboard")
+                .tab("🎸 Guitar")
+                .tab("🥁 Drums")
+                .tab("🎻 Bass")
+                .tab("🎷 Saxophone")
+                .show_with_panels(ui, |ui, index| {
+                    ui.add_space(10.0);
+                    ui.separator();
+                    ui.add_space(10.0);
+
+                    match index {
+                        0 => {
+                            ui.heading("🎹 Keyboard");
+                            ui.label(
+                                "Master the art of keyboard playing with these beautiful melodies:",
+                            );
+                            ui.add_space(8.0);
+                            ui.label("• Classical compositions: Bach, Mozart, Chopin");
+                            ui.label("• Modern pieces: Jazz standards and pop hits");
+                            ui.label("• Technique: Scales, arpeggios, and finger exercises");
+                            ui.label("• Recommended practice: 30 minutes daily");
+                        }
+                        1 => {
+                            ui.heading("🎸 Guitar");
+                            ui.label("Strum your way to guitar mastery:");
+                            ui.add_space(8.0);
+                            ui.label("• Basic chords: G, C, D, Em, Am");
+                            ui.label("• Strumming patterns: Down-up, fingerpicking");
+                            ui.label("• Popular songs: Wonderwall, Hotel California");
+                            ui.label("• Equipment: Acoustic vs. Electric guitars");
+                        }
+                        2 => {
+                            ui.heading("🥁 Drums");
+                            ui.label("Keep the rhythm alive with powerful beats:");
+                            ui.add_space(8.0);
+                            ui.label("• Basic beats: 4/4 time, rock, jazz, funk");
+                            ui.label("• Rudiments: Paradiddles, flams, rolls");
+                            ui.label("• Kit setup: Kick, snare, hi-hat, toms");
+                            ui.label("• Timing: Use metronome for practice");
+                        }
+                        3 => {
+                            ui.heading("🎻 Bass");
+                            ui.label("Lay down the foundation with deep bass lines:");
+                            ui.add_space(8.0);
+                            ui.label("• Playing techniques: Fingerstyle, slap, pick");
+                            ui.label("• Music theory: Root notes, walking bass");
+                            ui.label("• Rhythm section: Locking in with drums");
+                            ui.label("• Genres: Rock, funk, jazz, reggae");
+                        }
+                        4 => {
+                            ui.heading("🎷 Saxophone");
+                            ui.label("Create smooth jazz vibes with saxophone:");
+                            ui.add_space(8.0);
+                            ui.label("• Saxophone types: Alto, tenor, soprano, baritone");
+                            ui.label("• Breathing technique: Diaphragmatic breathing");
+                            ui.label("• Embouchure: Proper mouthpiece positioning");
+                            ui.label("• Jazz standards: Take Five, Giant Steps");
+                        }
+                        _ => {
+                            ui.label("No instrument selected");
+                        }
+                    }
+                });
         });
     }
 
@@ -247,12 +260,13 @@ impl TabsWindow {
 
     fn render_scrolling_tabs(&mut self, ui: &mut egui::Ui) {
         ui.heading("Scrolling Tabs");
-        ui.label("(Simulated with many tabs)");
+        ui.label("Tabs keep their natural width and the row scrolls horizontally.");
 
         // Create many tabs to demonstrate scrolling
         ui.add(
             tabs_primary(&mut self.scrolling_selected)
                 .id_salt("scrolling_primary")
+                .scrollable(true)
                 .tab("Tab 1")
                 .tab("Tab 2")
                 .tab("Tab 3")
@@ -600,4 +614,46 @@ impl TabsWindow {
             }
         });
     }
+
+    fn render_editor_tabs(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Closable & Reorderable Tabs");
+        ui.label("Drag tabs to reorder them, or close them with the x button.");
+        ui.label("lib.rs carries a dot badge for unsaved changes.");
+
+        let mut tabs = tabs_secondary(&mut self.editor_selected)
+            .id_salt("editor_tabs")
+            .reorderable(true);
+        for name in &self.editor_tabs {
+            let mut item = TabItem::new(name).closable(true);
+            if name == "lib.rs" {
+                item = item.badge(MaterialBadge::dot());
+            }
+            tabs = tabs.item(item);
+        }
+        let response = tabs.show(ui);
+
+        if let Some((from, to)) = response.reordered {
+            self.editor_tabs.swap(from, to);
+        }
+        if let Some(index) = response.closed {
+            self.editor_tabs.remove(index);
+            if index < self.editor_selected || self.editor_selected >= self.editor_tabs.len() {
+                self.editor_selected = self.editor_selected.saturating_sub(1);
+            }
+        }
+
+        ui.add_space(10.0);
+        ui.horizontal(|ui| {
+            if ui.add(MaterialButton::outlined("New File")).clicked() {
+                self.editor_tabs
+                    .push(format!("untitled-{}.rs", self.editor_next_file));
+                self.editor_next_file += 1;
+                self.editor_selected = self.editor_tabs.len() - 1;
+            }
+            match self.editor_tabs.get(self.editor_selected) {
+                Some(name) => ui.label(format!("Editing {}", name)),
+                None => ui.label("All files closed"),
+            };
+        });
+    }
 }
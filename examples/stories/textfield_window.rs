@@ -0,0 +1,120 @@
+#![doc(hidden)]
+
+use crate::{MaterialButton, MaterialTextField, TextFieldVariant};
+use eframe::egui::{self, Ui, Window};
+
+#[doc(hidden)]
+pub struct TextFieldWindow {
+    pub open: bool,
+    variant: TextFieldVariant,
+    enabled: bool,
+    name: String,
+    email: String,
+    password: String,
+    search: String,
+    bio: String,
+}
+
+impl Default for TextFieldWindow {
+    fn default() -> Self {
+        Self {
+            open: false,
+            variant: TextFieldVariant::Filled,
+            enabled: true,
+            name: String::new(),
+            email: String::new(),
+            password: String::new(),
+            search: String::new(),
+            bio: String::new(),
+        }
+    }
+}
+
+impl TextFieldWindow {
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let mut open = self.open;
+        Window::new("Text Field Stories")
+            .open(&mut open)
+            .default_size([500.0, 600.0])
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    self.render_controls(ui);
+                    ui.add_space(20.0);
+                    self.render_fields(ui);
+                });
+            });
+        self.open = open;
+    }
+
+    fn render_controls(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.heading("Text Field Controls");
+
+            if ui.add(MaterialButton::filled("Target").small()).clicked() {
+                let _ = webbrowser::open("https://m3.material.io/components/text-fields/overview");
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Variant:");
+            ui.selectable_value(&mut self.variant, TextFieldVariant::Filled, "Filled");
+            ui.selectable_value(&mut self.variant, TextFieldVariant::Outlined, "Outlined");
+        });
+        ui.checkbox(&mut self.enabled, "Enabled");
+    }
+
+    fn render_fields(&mut self, ui: &mut Ui) {
+        ui.add(
+            MaterialTextField::new(&mut self.name)
+                .variant(self.variant)
+                .label("Name")
+                .placeholder("Jane Doe")
+                .enabled(self.enabled),
+        );
+        ui.add_space(8.0);
+
+        let email_error = !self.email.is_empty() && !self.email.contains('@');
+        let mut email = MaterialTextField::new(&mut self.email)
+            .variant(self.variant)
+            .label("Email")
+            .leading_icon("mail")
+            .enabled(self.enabled);
+        email = if email_error {
+            email.error_text("Enter a valid email address")
+        } else {
+            email.helper_text("We'll never share your email")
+        };
+        ui.add(email);
+        ui.add_space(8.0);
+
+        ui.add(
+            MaterialTextField::new(&mut self.password)
+                .variant(self.variant)
+                .label("Password")
+                .password(true)
+                .enabled(self.enabled),
+        );
+        ui.add_space(8.0);
+
+        let search = MaterialTextField::new(&mut self.search)
+            .variant(self.variant)
+            .label("Search")
+            .leading_icon("search")
+            .trailing_icon("close")
+            .enabled(self.enabled)
+            .show(ui);
+        if search.trailing_icon_clicked {
+            self.search.clear();
+        }
+        ui.add_space(8.0);
+
+        ui.add(
+            MaterialTextField::new(&mut self.bio)
+                .variant(self.variant)
+                .label("Bio")
+                .helper_text("A short tagline")
+                .max_length(40)
+                .enabled(self.enabled),
+        );
+    }
+}
@@ -0,0 +1,70 @@
+#![doc(hidden)]
+
+use crate::{MaterialButton, MaterialTimePicker};
+use eframe::egui::{self, Ui, Window};
+
+#[doc(hidden)]
+pub struct TimePickerWindow {
+    pub open: bool,
+    dialog_open: bool,
+    hour_24: bool,
+    input_mode: bool,
+    time: Option<(u32, u32)>,
+}
+
+impl Default for TimePickerWindow {
+    fn default() -> Self {
+        Self {
+            open: false,
+            dialog_open: false,
+            hour_24: false,
+            input_mode: false,
+            time: Some((7, 30)),
+        }
+    }
+}
+
+impl TimePickerWindow {
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let mut open = self.open;
+        Window::new("Time Picker Stories")
+            .open(&mut open)
+            .default_size([400.0, 300.0])
+            .show(ctx, |ui| {
+                self.render_controls(ui);
+                ui.add_space(20.0);
+                self.render_picker(ui);
+            });
+        self.open = open;
+
+        MaterialTimePicker::new("stories_time_picker", &mut self.time)
+            .hour_24(self.hour_24)
+            .input_mode(self.input_mode)
+            .show(ctx, &mut self.dialog_open);
+    }
+
+    fn render_controls(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.heading("Time Picker Controls");
+
+            if ui.add(MaterialButton::filled("Target").small()).clicked() {
+                let _ = webbrowser::open("https://m3.material.io/components/time-pickers/overview");
+            }
+        });
+
+        ui.checkbox(&mut self.hour_24, "24-hour clock");
+        ui.checkbox(&mut self.input_mode, "Open in keyboard input mode");
+    }
+
+    fn render_picker(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            if ui.add(MaterialButton::filled("Pick a time")).clicked() {
+                self.dialog_open = true;
+            }
+            ui.label(match self.time {
+                Some((hour, minute)) => format!("Selected: {hour:02}:{minute:02}"),
+                None => "Nothing selected".to_string(),
+            });
+        });
+    }
+}
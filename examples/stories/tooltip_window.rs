@@ -1,8 +1,8 @@
 #![doc(hidden)]
 
 use crate::{
-    noto_emoji, show_tooltip_on_hover, tooltip, MaterialButton, MaterialCard2, MaterialIconButton,
-    TooltipPosition,
+    noto_emoji, rich_tooltip, show_tooltip_on_hover, tooltip, MaterialButton, MaterialCard2,
+    MaterialIconButton, RichTooltip, TooltipPosition,
 };
 use eframe::egui::{self, Ui, Window};
 
@@ -117,21 +117,46 @@ impl TooltipWindow {
 
         // Different Icon Button Styles
         ui.heading("Tooltips on Icon Button Variants");
-        ui.label("All icon button variants support tooltips:");
+        ui.label("All icon button variants support tooltips via `.tooltip()`:");
 
         ui.horizontal_wrapped(|ui| {
-            let icon1 = ui.add(MaterialIconButton::standard(noto_emoji::INFORMATION_SOURCE).size(40.0));
-            show_tooltip_on_hover(ui, &icon1, "Standard icon button", TooltipPosition::Top);
-
-            let icon2 = ui.add(MaterialIconButton::filled(noto_emoji::WHITE_HEAVY_CHECK_MARK).size(40.0));
-            show_tooltip_on_hover(ui, &icon2, "Filled icon button", TooltipPosition::Top);
+            ui.add(
+                MaterialIconButton::standard(noto_emoji::INFORMATION_SOURCE)
+                    .size(40.0)
+                    .tooltip("Standard icon button"),
+            );
+            ui.add(
+                MaterialIconButton::filled(noto_emoji::WHITE_HEAVY_CHECK_MARK)
+                    .size(40.0)
+                    .tooltip("Filled icon button"),
+            );
+            ui.add(
+                MaterialIconButton::filled_tonal(noto_emoji::BELL)
+                    .size(40.0)
+                    .tooltip("Filled tonal icon button"),
+            );
+            ui.add(
+                MaterialIconButton::outlined(noto_emoji::ENVELOPE)
+                    .size(40.0)
+                    .tooltip("Outlined icon button"),
+            );
+        });
 
-            let icon3 = ui.add(MaterialIconButton::filled_tonal(noto_emoji::BELL).size(40.0));
-            show_tooltip_on_hover(ui, &icon3, "Filled tonal icon button", TooltipPosition::Top);
+        ui.add_space(20.0);
 
-            let icon4 = ui.add(MaterialIconButton::outlined(noto_emoji::ENVELOPE).size(40.0));
-            show_tooltip_on_hover(ui, &icon4, "Outlined icon button", TooltipPosition::Top);
-        });
+        // Rich Tooltip
+        ui.heading("Rich Tooltip");
+        ui.label("Rich tooltips add a title and an action, and stay open while hovered:");
+
+        let sync = ui.add(MaterialButton::outlined("Sync settings"));
+        let tooltip = RichTooltip::new(
+            "Your settings are saved to your account and applied on every device you sign in to.",
+        )
+        .title("Automatic sync")
+        .action("Learn more");
+        if rich_tooltip(&sync, tooltip) {
+            println!("Rich tooltip action clicked!");
+        }
 
         ui.add_space(20.0);
 
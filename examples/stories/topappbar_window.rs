@@ -2,7 +2,7 @@
 
 use crate::{
     center_aligned_top_app_bar, large_top_app_bar, medium_top_app_bar, menu, menu_item,
-    top_app_bar, MaterialButton, MaterialCheckbox, MaterialTopAppBar,
+    top_app_bar, AppBarAction, MaterialButton, MaterialCheckbox, MaterialTopAppBar,
 };
 use egui_material3::material_symbol::{
     ICON_ACCOUNT_CIRCLE, ICON_ARROW_BACK, ICON_CLOSE, ICON_CLOUD_UPLOAD,
@@ -34,6 +34,11 @@ pub struct TopAppBarWindow {
     menu_open: bool,
     menu_anchor_rect: Option<Rect>,
     menu_selected: String,
+    // Collapsing demo state
+    collapsing_scroll_offset: f32,
+    // Overflow demo state
+    overflow_bar_width: f32,
+    last_overflow_action: String,
 }
 
 impl Default for TopAppBarWindow {
@@ -52,6 +57,9 @@ impl Default for TopAppBarWindow {
             menu_open: false,
             menu_anchor_rect: None,
             menu_selected: String::new(),
+            collapsing_scroll_offset: 0.0,
+            overflow_bar_width: 360.0,
+            last_overflow_action: String::new(),
         }
     }
 }
@@ -252,6 +260,70 @@ impl TopAppBarWindow {
 
         ui.add_space(30.0);
 
+        // --- Collapsing Large ---
+        ui.heading("Collapsing Large Top App Bar");
+        ui.label("Scroll the content below to collapse the bar into a small app bar.");
+
+        ui.push_id("collapsing_topappbar_demo", |ui| {
+            let mut collapsing_bar = large_top_app_bar(&self.title_text)
+                .id_salt("collapsing_topappbar")
+                .with_scroll_offset(self.collapsing_scroll_offset)
+                .navigation_icon_char(ICON_ARROW_BACK, || println!("Back clicked!"));
+
+            if self.show_actions {
+                collapsing_bar = collapsing_bar
+                    .action_icon_char(ICON_SEARCH, || println!("Search clicked!"))
+                    .action_icon_char(ICON_MORE_VERT, || println!("More clicked!"));
+            }
+
+            ui.add(collapsing_bar);
+
+            let output = egui::ScrollArea::vertical()
+                .id_salt("collapsing_topappbar_content")
+                .max_height(240.0)
+                .show(ui, |ui| {
+                    for i in 1..=30 {
+                        ui.label(format!("Message {}", i));
+                    }
+                });
+            self.collapsing_scroll_offset = output.state.offset.y;
+        });
+
+        ui.add_space(30.0);
+
+        // --- Overflow Actions ---
+        ui.heading("Action Overflow Menu");
+        ui.label("Actions that don't fit move into the \"more\" menu. Narrow the bar to see it.");
+        ui.add(egui::Slider::new(&mut self.overflow_bar_width, 200.0..=800.0).text("Bar width"));
+
+        ui.push_id("overflow_topappbar_demo", |ui| {
+            let response = ui
+                .allocate_ui(egui::vec2(self.overflow_bar_width, 64.0), |ui| {
+                    top_app_bar("Photos")
+                        .id_salt("overflow_topappbar")
+                        .navigation_icon_char(ICON_MENU, || println!("Navigation clicked!"))
+                        .actions(vec![
+                            AppBarAction::new("search", "search", "Search"),
+                            AppBarAction::new("favorite", "favorite", "Favorite"),
+                            AppBarAction::new("share", "share", "Share"),
+                            AppBarAction::new("download", "download", "Download"),
+                            AppBarAction::new("delete", "delete", "Delete"),
+                            AppBarAction::new("settings", "settings", "Settings"),
+                        ])
+                        .show(ui)
+                })
+                .inner;
+
+            if let Some(id) = response.clicked_action {
+                self.last_overflow_action = id;
+            }
+        });
+        if !self.last_overflow_action.is_empty() {
+            ui.label(format!("Last action: {}", self.last_overflow_action));
+        }
+
+        ui.add_space(30.0);
+
         // --- About Page Demo ---
         ui.heading("About Page Demo");
         ui.label(
@@ -1,9 +1,9 @@
 use eframe::egui;
 use egui_material3::{
     theme::{
-        load_fonts, load_themes, setup_google_fonts, setup_local_fonts, setup_local_theme,
-        update_global_theme, update_window_background, ContrastLevel, MaterialThemeContext,
-        MaterialThemeFile, ThemeMode,
+        apply_if_changed, invalidate_theme_cache, load_fonts, load_themes, setup_google_fonts,
+        setup_local_fonts, setup_local_theme, update_global_theme, update_window_background,
+        ContrastLevel, MaterialThemeContext, MaterialThemeFile, ThemeMode,
     },
     MaterialButton, MaterialCard2, MaterialCheckbox, MaterialChip, MaterialDialog, MaterialDrawer,
     MaterialFab, MaterialIcon, MaterialIconButton, MaterialList, MaterialProgress, MaterialRadio,
@@ -106,21 +106,21 @@ impl WidgetGalleryApp {
         Ok(theme)
     }
 
-    fn update_theme_mode(&mut self, ctx: &egui::Context) {
+    fn update_theme_mode(&mut self) {
         // Update the global theme context with new theme mode and contrast level
         if let Ok(mut global_theme) = egui_material3::theme::get_global_theme().lock() {
             global_theme.theme_mode = self.theme_mode;
             global_theme.contrast_level = self.contrast_level;
         }
-        // Update window background to reflect the new theme settings
-        update_window_background(ctx);
+        // Direct edits don't bump the theme version; apply_if_changed picks this up next frame
+        invalidate_theme_cache();
     }
 }
 
 impl eframe::App for WidgetGalleryApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Update window background based on theme
-        update_window_background(ctx);
+        // Re-apply the background whenever the theme changed
+        apply_if_changed(ctx);
 
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Material Design Widget Gallery 테스트");
@@ -163,7 +163,7 @@ impl eframe::App for WidgetGalleryApp {
                     .changed();
 
                 if changed {
-                    self.update_theme_mode(ctx);
+                    self.update_theme_mode();
                     ctx.request_repaint();
                 }
             });
@@ -195,6 +195,13 @@ impl eframe::App for WidgetGalleryApp {
                             ui.add(MaterialIcon::new("star").size(24.0));
                             ui.end_row();
 
+                            ui.label("MaterialIcon Spinning");
+                            ui.horizontal(|ui| {
+                                ui.add(MaterialIcon::new("progress_activity").spinning(true));
+                                ui.add(MaterialIcon::new("expand_more").rotation(180.0));
+                            });
+                            ui.end_row();
+
                             ui.label("MaterialButton");
                             ui.add(MaterialButton::filled("Filled Button"));
                             ui.end_row();
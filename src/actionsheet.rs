@@ -8,7 +8,7 @@
 //! - **scrim @ 32%**: Modal overlay
 //! - **State layers**: onSurface @ 8% (hover), 12% (press)
 
-use crate::theme::get_global_color;
+use crate::theme::{dismiss_on_escape, get_global_color};
 use egui::{
     ecolor::Color32,
     epaint::{CornerRadius, Stroke},
@@ -200,7 +200,7 @@ impl<'a> MaterialActionSheet<'a> {
         }
 
         // Handle ESC key
-        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+        if dismiss_on_escape(ctx) {
             *self.open = false;
         }
 
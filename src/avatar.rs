@@ -0,0 +1,423 @@
+//! Material Design 3 Avatars
+//!
+//! An avatar shows a person or entity as an image, their initials or an icon,
+//! clipped to a circle or a rounded square.
+//!
+//! # M3 Color Role Usage
+//!
+//! - **Initials**: tone 90 (light) or 30 (dark) of a hue derived from the seed
+//!   string, with tone 10 or 90 text, so the same name always gets the same color
+//! - **secondaryContainer / onSecondaryContainer**: Icon avatars
+//! - **surfaceContainerHighest / onSurfaceVariant**: Image avatars while the
+//!   image loads or when it fails to load
+//! - **surface**: Ring separating the status dot from the avatar
+//!
+//! ## Dimensions
+//! - **Small**: 24dp, **Medium**: 40dp (list items), **Large**: 56dp
+//! - **Rounded square**: 8, 12 and 16dp corners for the three sizes
+//! - **Status dot**: 6, 10 and 14dp with a 2dp surface ring, on the bottom
+//!   trailing edge
+//!
+//! ## Images
+//!
+//! Image sources are the ones [`crate::MaterialImageList`] understands: http(s)
+//! URLs (with the `ondemand` feature), data URLs, file paths and embedded bytes.
+//! They share its image cache, so each source is decoded once; URLs download in
+//! the background.
+//!
+//! ```rust
+//! # egui::__run_test_ui(|ui| {
+//! use egui_material3::{AvatarShape, AvatarSize, MaterialAvatar};
+//!
+//! ui.add(MaterialAvatar::initials("Ada Lovelace"));
+//! ui.add(MaterialAvatar::icon("person").size(AvatarSize::Small));
+//! ui.add(
+//!     MaterialAvatar::image("https://example.com/ada.png")
+//!         .shape(AvatarShape::RoundedSquare)
+//!         .status(egui::Color32::from_rgb(0x2E, 0x7D, 0x32)),
+//! );
+//! # });
+//! ```
+
+use crate::imagelist::{cached_image, image_cache_config, is_remote_source, ImageSlot};
+use crate::material_symbol::material_symbol_text;
+use crate::theme::{
+    get_global_color, is_rtl, mirror_x, shape_radius, type_font_id, Shape, TonalPalette, TypeRole,
+};
+use egui::{
+    self, Color32, CornerRadius, Painter, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget,
+    WidgetInfo, WidgetType,
+};
+
+/// Chroma of the initials background palette
+const SEED_CHROMA: f32 = 36.0;
+
+/// Avatar size variants
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AvatarSize {
+    /// 24dp, e.g. inside chips and dense rows
+    Small,
+    /// 40dp, e.g. list items and app bars
+    #[default]
+    Medium,
+    /// 56dp, e.g. profile headers and cards
+    Large,
+}
+
+impl AvatarSize {
+    /// Width and height in points
+    pub fn diameter(self) -> f32 {
+        match self {
+            AvatarSize::Small => 24.0,
+            AvatarSize::Medium => 40.0,
+            AvatarSize::Large => 56.0,
+        }
+    }
+
+    fn corner_shape(self) -> Shape {
+        match self {
+            AvatarSize::Small => Shape::Small,
+            AvatarSize::Medium => Shape::Medium,
+            AvatarSize::Large => Shape::Large,
+        }
+    }
+
+    fn initials_font(self) -> TypeRole {
+        match self {
+            AvatarSize::Small => TypeRole::LabelSmall,
+            AvatarSize::Medium => TypeRole::TitleMedium,
+            AvatarSize::Large => TypeRole::TitleLarge,
+        }
+    }
+
+    fn status_diameter(self) -> f32 {
+        match self {
+            AvatarSize::Small => 6.0,
+            AvatarSize::Medium => 10.0,
+            AvatarSize::Large => 14.0,
+        }
+    }
+}
+
+/// Outline of an avatar
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AvatarShape {
+    #[default]
+    Circle,
+    /// Square with corners rounded to match the size
+    RoundedSquare,
+}
+
+#[derive(Clone, Debug)]
+enum AvatarContent {
+    Initials(String),
+    Icon(String),
+    /// Image source string, as taken by the image list
+    Image(String),
+    Texture(egui::TextureId),
+}
+
+/// Material Design avatar.
+///
+/// # Example
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// // "AL" on a color derived from the name
+/// ui.add(MaterialAvatar::initials("Ada Lovelace"));
+///
+/// // Same color for everyone called "Ada Lovelace", whatever the initials
+/// ui.add(MaterialAvatar::initials("Ada").seed("Ada Lovelace"));
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct MaterialAvatar {
+    content: AvatarContent,
+    seed: Option<String>,
+    size: AvatarSize,
+    shape: AvatarShape,
+    status: Option<Color32>,
+}
+
+impl MaterialAvatar {
+    fn new(content: AvatarContent) -> Self {
+        Self {
+            content,
+            seed: None,
+            size: AvatarSize::default(),
+            shape: AvatarShape::default(),
+            status: None,
+        }
+    }
+
+    /// Show the initials of `name` on a background color derived from it.
+    ///
+    /// The first letters of the first and last words are used, so
+    /// "Ada Lovelace" shows "AL". Names of one or two letters are shown as
+    /// given, in upper case.
+    pub fn initials(name: impl Into<String>) -> Self {
+        Self::new(AvatarContent::Initials(name.into()))
+    }
+
+    /// Show a Material Symbols icon, such as `"person"` or `"group"`.
+    pub fn icon(name: impl Into<String>) -> Self {
+        Self::new(AvatarContent::Icon(name.into()))
+    }
+
+    /// Show an image from a URL, data URL or file path.
+    pub fn image(source: impl Into<String>) -> Self {
+        Self::new(AvatarContent::Image(source.into()))
+    }
+
+    /// Show an encoded PNG, JPEG or WebP image.
+    pub fn image_bytes(bytes: impl AsRef<[u8]>) -> Self {
+        Self::image(format!("bytes:{}", hex::encode(bytes)))
+    }
+
+    /// Show an already uploaded texture.
+    pub fn texture(texture: impl Into<egui::TextureId>) -> Self {
+        Self::new(AvatarContent::Texture(texture.into()))
+    }
+
+    /// Derive the initials background from `seed` instead of the name.
+    ///
+    /// Use a stable identifier, such as a user id or e-mail address, to keep
+    /// the color when the display name changes.
+    pub fn seed(mut self, seed: impl Into<String>) -> Self {
+        self.seed = Some(seed.into());
+        self
+    }
+
+    /// Set the size.
+    pub fn size(mut self, size: AvatarSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the shape.
+    pub fn shape(mut self, shape: AvatarShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Show a status dot of `color`, e.g. green for "online".
+    pub fn status(mut self, color: Color32) -> Self {
+        self.status = Some(color);
+        self
+    }
+
+    /// Width and height in points
+    pub fn diameter(&self) -> f32 {
+        self.size.diameter()
+    }
+
+    fn corner_radius(&self, rect: Rect) -> CornerRadius {
+        match self.shape {
+            AvatarShape::Circle => CornerRadius::same((rect.width() / 2.0).round() as u8),
+            AvatarShape::RoundedSquare => shape_radius(self.size.corner_shape()),
+        }
+    }
+
+    /// Text announced by screen readers
+    fn accessible_label(&self) -> &str {
+        match &self.content {
+            AvatarContent::Initials(name) => name,
+            _ => "",
+        }
+    }
+
+    /// Paint the avatar filling `rect`, faded to 38% when not `enabled`.
+    pub(crate) fn paint(&self, painter: &Painter, rect: Rect, enabled: bool, rtl: bool) {
+        let opacity = if enabled { 1.0 } else { 0.38 };
+        let radius = self.corner_radius(rect);
+        let dark_mode = painter.ctx().style().visuals.dark_mode;
+
+        let placeholder = |painter: &Painter| {
+            painter.rect_filled(
+                rect,
+                radius,
+                get_global_color("surfaceContainerHighest").linear_multiply(opacity),
+            );
+            paint_icon(
+                painter,
+                rect,
+                "person",
+                get_global_color("onSurfaceVariant").linear_multiply(opacity),
+            );
+        };
+
+        match &self.content {
+            AvatarContent::Initials(name) => {
+                let (background, foreground) =
+                    seed_colors(self.seed.as_deref().unwrap_or(name), dark_mode);
+                painter.rect_filled(rect, radius, background.linear_multiply(opacity));
+                painter.text(
+                    rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    initials_of(name),
+                    type_font_id(self.size.initials_font()),
+                    foreground.linear_multiply(opacity),
+                );
+            }
+            AvatarContent::Icon(icon) => {
+                painter.rect_filled(
+                    rect,
+                    radius,
+                    get_global_color("secondaryContainer").linear_multiply(opacity),
+                );
+                paint_icon(
+                    painter,
+                    rect,
+                    icon,
+                    get_global_color("onSecondaryContainer").linear_multiply(opacity),
+                );
+            }
+            AvatarContent::Image(source) => {
+                let tmppath = image_cache_config().dir.to_string_lossy().to_string();
+                let lazy = is_remote_source(source);
+                match cached_image(painter.ctx(), source, &tmppath, lazy) {
+                    Some(ImageSlot::Loaded { texture, .. }) => {
+                        paint_texture(painter, rect, radius, texture.id(), opacity)
+                    }
+                    _ => placeholder(painter),
+                }
+            }
+            AvatarContent::Texture(texture) => {
+                paint_texture(painter, rect, radius, *texture, opacity)
+            }
+        }
+
+        if let Some(color) = self.status {
+            let dot = self.size.status_diameter();
+            // On the circle's edge at 45°, or in the corner of a square
+            let inset = match self.shape {
+                AvatarShape::Circle => rect.width() / 2.0 * (1.0 - std::f32::consts::FRAC_1_SQRT_2),
+                AvatarShape::RoundedSquare => dot / 4.0,
+            };
+            let center = Pos2::new(mirror_x(rtl, rect, rect.max.x - inset), rect.max.y - inset);
+            painter.circle(
+                center,
+                dot / 2.0,
+                color.linear_multiply(opacity),
+                Stroke::new(2.0, get_global_color("surface")),
+            );
+        }
+    }
+}
+
+impl From<&str> for MaterialAvatar {
+    fn from(name: &str) -> Self {
+        Self::initials(name)
+    }
+}
+
+impl From<String> for MaterialAvatar {
+    fn from(name: String) -> Self {
+        Self::initials(name)
+    }
+}
+
+impl Widget for MaterialAvatar {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(self.diameter()), Sense::hover());
+        response.widget_info(|| {
+            WidgetInfo::labeled(WidgetType::Image, ui.is_enabled(), self.accessible_label())
+        });
+        if ui.is_rect_visible(rect) {
+            self.paint(ui.painter(), rect, ui.is_enabled(), is_rtl(ui));
+        }
+        response
+    }
+}
+
+fn paint_icon(painter: &Painter, rect: Rect, icon: &str, color: Color32) {
+    painter.text(
+        rect.center(),
+        egui::Align2::CENTER_CENTER,
+        material_symbol_text(icon),
+        egui::FontId::proportional(rect.height() * 0.6),
+        color,
+    );
+}
+
+fn paint_texture(
+    painter: &Painter,
+    rect: Rect,
+    radius: CornerRadius,
+    texture: egui::TextureId,
+    opacity: f32,
+) {
+    let uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+    painter.add(
+        egui::epaint::RectShape::filled(rect, radius, Color32::WHITE.linear_multiply(opacity))
+            .with_texture(texture, uv),
+    );
+}
+
+/// The initials shown for `name`
+fn initials_of(name: &str) -> String {
+    let name = name.trim();
+    let mut words = name.split_whitespace();
+    let first = words.next().and_then(|word| word.chars().next());
+    let last = words.last().and_then(|word| word.chars().next());
+    match (first, last) {
+        (Some(first), Some(last)) => first.to_uppercase().chain(last.to_uppercase()).collect(),
+        _ if name.chars().count() <= 2 => name.to_uppercase(),
+        (Some(first), None) => first.to_uppercase().collect(),
+        (None, _) => String::new(),
+    }
+}
+
+/// FNV-1a, which unlike `DefaultHasher` is the same on every platform and release
+fn seed_hash(seed: &str) -> u32 {
+    seed.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// Background and text color of an initials avatar for `seed`.
+///
+/// The hue comes from a hash of `seed`; tones follow the container roles,
+/// 90 on 10 in light mode and 30 on 90 in dark mode.
+pub fn seed_colors(seed: &str, dark_mode: bool) -> (Color32, Color32) {
+    let palette = TonalPalette {
+        hue: (seed_hash(seed) % 360) as f32,
+        chroma: SEED_CHROMA,
+    };
+    if dark_mode {
+        (palette.tone(30), palette.tone(90))
+    } else {
+        (palette.tone(90), palette.tone(10))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::contrast_ratio;
+
+    #[test]
+    fn test_initials_of_name() {
+        assert_eq!(initials_of("Ada Lovelace"), "AL");
+        assert_eq!(initials_of("grace brewster murray hopper"), "GH");
+        assert_eq!(initials_of("Linus"), "L");
+        assert_eq!(initials_of("al"), "AL");
+        assert_eq!(initials_of("  "), "");
+    }
+
+    #[test]
+    fn test_seed_colors_are_stable_and_readable() {
+        assert_eq!(
+            seed_colors("Ada Lovelace", false),
+            seed_colors("Ada Lovelace", false)
+        );
+        assert_ne!(
+            seed_colors("Ada Lovelace", false).0,
+            seed_colors("Alan Turing", false).0
+        );
+        for dark_mode in [false, true] {
+            let (background, foreground) = seed_colors("Grace Hopper", dark_mode);
+            assert!(contrast_ratio(foreground, background) >= 4.5);
+        }
+    }
+}
@@ -48,9 +48,13 @@ pub enum BadgeSize {
 }
 
 /// Badge positioning relative to parent element
-#[derive(Clone, Copy, PartialEq, Debug)]
+///
+/// Use [`BadgePosition::TopLeft`] (or [`BadgePosition::trailing_top`]) for
+/// right-to-left layouts so the badge sits on the trailing edge of the icon.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
 pub enum BadgePosition {
     /// Top-right corner
+    #[default]
     TopRight,
     /// Top-left corner
     TopLeft,
@@ -62,6 +66,30 @@ pub enum BadgePosition {
     Custom(Vec2),
 }
 
+impl BadgePosition {
+    /// The trailing top corner for the given text direction: top-right for
+    /// left-to-right layouts, top-left for right-to-left layouts.
+    pub fn trailing_top(rtl: bool) -> Self {
+        if rtl {
+            BadgePosition::TopLeft
+        } else {
+            BadgePosition::TopRight
+        }
+    }
+}
+
+/// Default maximum count shown by [`MaterialBadge::count`] before collapsing to "99+".
+pub const DEFAULT_BADGE_MAX_COUNT: u32 = 99;
+
+/// Label for a numeric badge, collapsing to `"{max}+"` past `max`.
+fn count_label(count: u32, max: u32) -> String {
+    if count > max {
+        format!("{}+", max)
+    } else {
+        count.to_string()
+    }
+}
+
 /// Material Design badge component.
 ///
 /// Badges are small status descriptors for UI elements. They typically appear
@@ -84,9 +112,13 @@ pub enum BadgePosition {
 /// ui.add(MaterialBadge::new("3")
 ///     .color(BadgeColor::Primary)
 ///     .size(BadgeSize::Small));
+///
+/// // Count badge overlaid on an icon button, shows "99+"
+/// with_badge(ui, MaterialIconButton::standard("mail"), MaterialBadge::count(120));
 /// # });
 /// ```
 #[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+#[derive(Clone, Debug)]
 pub struct MaterialBadge {
     /// Text content of the badge
     content: String,
@@ -98,6 +130,10 @@ pub struct MaterialBadge {
     dot: bool,
     /// Custom position offset when used as overlay
     position_offset: Vec2,
+    /// Corner used by [`with_badge`] when overlaying another widget
+    position: BadgePosition,
+    /// Numeric value for count badges, re-labelled when `max_count` changes
+    count: Option<u32>,
 }
 
 impl MaterialBadge {
@@ -112,9 +148,21 @@ impl MaterialBadge {
             size: BadgeSize::Regular,
             dot: false,
             position_offset: Vec2::new(0.0, 0.0),
+            position: BadgePosition::TopRight,
+            count: None,
         }
     }
 
+    /// Create a numbered badge, collapsing to "99+" for counts above 99
+    ///
+    /// Use [`MaterialBadge::max_count`] to change the threshold.
+    pub fn count(count: u32) -> Self {
+        let mut badge = Self::new(count_label(count, DEFAULT_BADGE_MAX_COUNT));
+        badge.size = BadgeSize::Small;
+        badge.count = Some(count);
+        badge
+    }
+
     /// Create a badge showing just a dot (no text)
     pub fn dot() -> Self {
         Self {
@@ -123,6 +171,8 @@ impl MaterialBadge {
             size: BadgeSize::Small,
             dot: true,
             position_offset: Vec2::new(0.0, 0.0),
+            position: BadgePosition::TopRight,
+            count: None,
         }
     }
 
@@ -150,6 +200,39 @@ impl MaterialBadge {
         self
     }
 
+    /// Set the largest count shown before collapsing to "{max}+"
+    ///
+    /// Only affects badges created with [`MaterialBadge::count`].
+    pub fn max_count(mut self, max: u32) -> Self {
+        if let Some(count) = self.count {
+            self.content = count_label(count, max);
+        }
+        self
+    }
+
+    /// Set the corner used when the badge is overlaid with [`with_badge`]
+    ///
+    /// Defaults to [`BadgePosition::TopRight`]; use
+    /// [`BadgePosition::trailing_top`] to follow the layout direction.
+    pub fn position(mut self, position: BadgePosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// The text shown in the badge (empty for dot badges)
+    pub fn label(&self) -> &str {
+        if self.dot {
+            ""
+        } else {
+            &self.content
+        }
+    }
+
+    /// Draw the badge as an overlay at its configured [`BadgePosition`]
+    pub fn draw_on_rect(&self, ui: &mut Ui, target_rect: Rect) -> Response {
+        self.draw_on(ui, target_rect, self.position)
+    }
+
     /// Draw the badge as an overlay on a specific rectangle
     ///
     /// This is useful for adding badges to other UI elements like buttons or icons.
@@ -242,7 +325,13 @@ impl MaterialBadge {
             );
         }
 
-        ui.interact(badge_rect, ui.id().with("badge"), Sense::hover())
+        // Key the hover response on the target so several badges in one ui don't clash
+        let badge_id = ui.id().with((
+            "badge",
+            target_rect.min.x.to_bits(),
+            target_rect.min.y.to_bits(),
+        ));
+        ui.interact(badge_rect, badge_id, Sense::hover())
     }
 
     fn get_colors(&self) -> (Color32, Color32) {
@@ -252,10 +341,11 @@ impl MaterialBadge {
                 let text = get_global_color("onPrimary");
                 (bg, text)
             }
-            BadgeColor::Error => (
-                Color32::from_rgb(239, 68, 68), // red-500
-                Color32::WHITE,
-            ),
+            BadgeColor::Error => {
+                let bg = get_global_color("error");
+                let text = get_global_color("onError");
+                (bg, text)
+            }
             BadgeColor::Success => (
                 Color32::from_rgb(34, 197, 94), // green-500
                 Color32::WHITE,
@@ -369,3 +459,34 @@ pub fn badge(content: impl Into<String>) -> MaterialBadge {
 pub fn badge_dot() -> MaterialBadge {
     MaterialBadge::dot()
 }
+
+/// Add a widget and overlay a badge on it
+///
+/// The badge is drawn at the badge's [`BadgePosition`] relative to the
+/// widget's rect. The widget's response is returned.
+///
+/// # Example
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// with_badge(ui, MaterialIconButton::standard("notifications"), MaterialBadge::dot());
+/// # });
+/// ```
+pub fn with_badge(ui: &mut Ui, widget: impl Widget, badge: MaterialBadge) -> Response {
+    let response = ui.add(widget);
+    badge.draw_on_rect(ui, response.rect);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_collapses_past_max() {
+        assert_eq!(MaterialBadge::count(5).label(), "5");
+        assert_eq!(MaterialBadge::count(99).label(), "99");
+        assert_eq!(MaterialBadge::count(100).label(), "99+");
+        assert_eq!(MaterialBadge::count(12).max_count(9).label(), "9+");
+        assert_eq!(MaterialBadge::dot().label(), "");
+    }
+}
@@ -35,16 +35,43 @@
 //! - **onSecondaryContainer**: Text and icon color on tinted background
 //! - **State layers**: onSecondaryContainer @ 8% (hover), 12% (press)
 //! - **Disabled**: surface background, onSurface @ 12% outline, onSurface @ 38% content
-
-use crate::{get_global_color, material_symbol::material_symbol_text};
+//!
+//! ## Loading State
+//! `.loading(true)` keeps the container, elevation and size of the button but replaces
+//! its content with a small indeterminate circular progress indicator in the content
+//! color. The button stops reacting to the pointer and never reports `.clicked()`.
+//!
+//! ## Icons
+//! `.leading_icon()` / `.trailing_icon()` take Material Symbols names and draw 18dp glyphs
+//! in the variant's content color, 8dp from the label. The icon and label are centered as
+//! one group. [`MaterialButton::icon`] creates a square icon-only button.
+//!
+//! ## Rich and Custom Content
+//! Every constructor takes [`WidgetText`], so a [`RichText`] or a
+//! [`egui::text::LayoutJob`] with several colors works as a label; text without its own
+//! color uses the variant's content color. [`MaterialButton::custom`] lays out any widgets,
+//! e.g. a label with an inline badge, inside the variant's container, elevation, ripple
+//! and shape, and the button hugs their size.
+
+use crate::{
+    get_global_color,
+    material_symbol::material_symbol_text,
+    progress::circular_progress,
+    ripple::Ripple,
+    theme::{elevation_shadow, shape_radius, Shape},
+    tooltip::material_tooltip,
+};
 use egui::{
     ecolor::Color32,
     emath::NumExt,
-    epaint::{CornerRadius, Shadow, Stroke},
-    Align, Image, Rect, Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo,
-    WidgetText, WidgetType,
+    epaint::{CornerRadius, Shadow, Shape as PaintShape, Stroke},
+    Align, Image, Layout, Rect, Response, RichText, Sense, TextStyle, TextWrapMode, Ui, UiBuilder,
+    Vec2, Widget, WidgetInfo, WidgetText, WidgetType,
 };
 
+/// M3 icon size inside buttons, for both font and SVG icons
+const BUTTON_ICON_SIZE: f32 = 18.0;
+
 /// Material Design button with support for different variants.
 ///
 /// Supports filled, outlined, text, elevated, and filled tonal button variants
@@ -80,6 +107,11 @@ use egui::{
 ///     do_stuff();
 /// }
 ///
+/// // Buttons with icons, and an icon-only button
+/// ui.add(MaterialButton::filled("Add").leading_icon("add"));
+/// ui.add(MaterialButton::outlined("Download").trailing_icon("download"));
+/// ui.add(MaterialButton::icon("edit").variant(MaterialButtonVariant::FilledTonal));
+///
 /// // Button with custom properties
 /// if ui.add(
 ///     MaterialButton::filled("Custom")
@@ -164,6 +196,12 @@ pub struct MaterialButton<'a> {
     trailing_svg: Option<String>,
     /// Custom text color override (None uses variant default)
     text_color: Option<Color32>,
+    /// Whether to show a spinner in place of the content and ignore clicks
+    loading: bool,
+    /// Optional plain tooltip shown on hover
+    tooltip: Option<String>,
+    /// Widgets laid out inside the button instead of the text and icons
+    content: Option<Box<dyn FnOnce(&mut Ui) + 'a>>,
 }
 
 impl<'a> MaterialButton<'a> {
@@ -220,12 +258,7 @@ impl<'a> MaterialButton<'a> {
     /// - Elevation: 1dp shadow
     /// - Corner radius: 20dp  
     pub fn elevated(text: impl Into<WidgetText>) -> Self {
-        Self::new_with_variant(MaterialButtonVariant::Elevated, text).elevation(Shadow {
-            offset: [0, 2],
-            blur: 6,
-            spread: 0,
-            color: Color32::from_rgba_unmultiplied(0, 0, 0, 30),
-        })
+        Self::new_with_variant(MaterialButtonVariant::Elevated, text).elevation(elevation_shadow(1))
     }
 
     /// Create a filled tonal Material Design button with medium emphasis
@@ -247,10 +280,57 @@ impl<'a> MaterialButton<'a> {
         Self::opt_image_and_text_with_variant(variant, None, Some(text.into()))
     }
 
+    /// Create a filled button with any [`WidgetText`], e.g. a [`RichText`] label
+    ///
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::RichText;
+    /// use egui_material3::MaterialButton;
+    ///
+    /// ui.add(MaterialButton::new(RichText::new("Delete").strong()));
+    /// # });
+    /// ```
     pub fn new(text: impl Into<WidgetText>) -> Self {
         Self::filled(text)
     }
 
+    /// Create a filled button showing the widgets `add_contents` adds
+    ///
+    /// The contents are laid out left to right and vertically centered, and
+    /// labels without their own color use the variant's content color. The
+    /// button keeps the variant's container, elevation, ripple and shape and
+    /// hugs the contents. Change the variant with [`variant`](Self::variant).
+    ///
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_material3::{MaterialBadge, MaterialButton, MaterialButtonVariant};
+    ///
+    /// let inbox = MaterialButton::custom(|ui| {
+    ///     ui.label("Inbox");
+    ///     ui.add(MaterialBadge::new("3"));
+    /// })
+    /// .variant(MaterialButtonVariant::FilledTonal);
+    /// if ui.add(inbox).clicked() {
+    ///     // open the inbox
+    /// }
+    /// # });
+    /// ```
+    pub fn custom(add_contents: impl FnOnce(&mut Ui) + 'a) -> Self {
+        let mut button = Self::opt_image_and_text(None, None);
+        button.content = Some(Box::new(add_contents));
+        button
+    }
+
+    /// Create a square icon-only button showing a Material Symbols icon
+    ///
+    /// Uses the filled variant; change it with [`variant`](Self::variant).
+    ///
+    /// ## Parameters
+    /// - `icon`: Material Symbols icon name (e.g., "add", "edit")
+    pub fn icon(icon: impl Into<String>) -> Self {
+        Self::opt_image_and_text(None, None).leading_icon(icon)
+    }
+
     /// Creates a button with an image. The size of the image as displayed is defined by the provided size.
     #[allow(clippy::needless_pass_by_value)]
     pub fn image(image: impl Into<Image<'a>>) -> Self {
@@ -309,7 +389,23 @@ impl<'a> MaterialButton<'a> {
             leading_svg: None,
             trailing_svg: None,
             text_color: None,
+            loading: false,
+            tooltip: None,
+            content: None,
+        }
+    }
+
+    /// Set the button variant (filled, outlined, text, elevated, filled tonal).
+    ///
+    /// Switching to [`MaterialButtonVariant::Elevated`] adds the default elevated shadow
+    /// unless an elevation was already set.
+    #[inline]
+    pub fn variant(mut self, variant: MaterialButtonVariant) -> Self {
+        self.variant = variant;
+        if variant == MaterialButtonVariant::Elevated && self.elevation.is_none() {
+            self.elevation = Some(elevation_shadow(1));
         }
+        self
     }
 
     /// Set the wrap mode for the text.
@@ -401,6 +497,12 @@ impl<'a> MaterialButton<'a> {
         self
     }
 
+    /// Set the rounding of the button from an M3 shape token; buttons are [`Shape::Full`] by default.
+    #[inline]
+    pub fn shape(self, shape: Shape) -> Self {
+        self.corner_radius(shape_radius(shape))
+    }
+
     #[inline]
     #[deprecated = "Renamed to `corner_radius`"]
     pub fn rounding(self, corner_radius: impl Into<CornerRadius>) -> Self {
@@ -444,6 +546,23 @@ impl<'a> MaterialButton<'a> {
         self
     }
 
+    /// Show a loading spinner instead of the content, e.g. while a save is in flight.
+    ///
+    /// The button keeps the size its content would take so the layout doesn't jump,
+    /// and it doesn't emit `.clicked()` while loading.
+    #[inline]
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Show a Material plain tooltip on hover, e.g. to label an icon-only button.
+    #[inline]
+    pub fn tooltip(mut self, text: impl Into<String>) -> Self {
+        self.tooltip = Some(text.into());
+        self
+    }
+
     /// Set the elevation shadow for the button.
     #[inline]
     pub fn elevation(mut self, elevation: Shadow) -> Self {
@@ -526,6 +645,9 @@ impl Widget for MaterialButton<'_> {
             leading_svg,
             trailing_svg,
             text_color: custom_text_color,
+            loading,
+            tooltip,
+            content,
         } = self;
 
         // M3 Color Roles - Button Variants
@@ -533,7 +655,7 @@ impl Widget for MaterialButton<'_> {
         let on_primary = get_global_color("onPrimary"); // Content on primary background
         let secondary_container = get_global_color("secondaryContainer"); // Tonal button background
         let on_secondary_container = get_global_color("onSecondaryContainer"); // Content on tonal background
-        let surface = get_global_color("surface"); // Elevated button background, disabled button background
+        let surface = get_global_color("surface"); // Elevated button background
         let on_surface = get_global_color("onSurface"); // Content on surface, disabled content @ 38%
         let outline = get_global_color("outline"); // Outlined button border
 
@@ -542,31 +664,31 @@ impl Widget for MaterialButton<'_> {
             MaterialButtonVariant::Filled => (
                 Some(primary), // Use primary for high-emphasis filled button background
                 Some(Stroke::NONE),
-                CornerRadius::from(20),
+                shape_radius(Shape::Full),
                 false,
             ),
             MaterialButtonVariant::Outlined => (
                 Some(Color32::TRANSPARENT), // Transparent to show parent surface
                 Some(Stroke::new(1.0, outline)), // Use outline for medium-emphasis border
-                CornerRadius::from(20),
+                shape_radius(Shape::Full),
                 false,
             ),
             MaterialButtonVariant::Text => (
                 Some(Color32::TRANSPARENT), // Transparent to show parent surface
                 Some(Stroke::NONE), // No border for low-emphasis text button
-                CornerRadius::from(20),
+                shape_radius(Shape::Full),
                 false,
             ),
             MaterialButtonVariant::Elevated => (
                 Some(surface), // Use surface for elevated container background
                 Some(Stroke::NONE),
-                CornerRadius::from(20),
+                shape_radius(Shape::Full),
                 true,
             ),
             MaterialButtonVariant::FilledTonal => (
                 Some(secondary_container), // Use secondaryContainer for toned-down emphasis
                 Some(Stroke::NONE),
-                CornerRadius::from(20),
+                shape_radius(Shape::Full),
                 false,
             ),
         };
@@ -584,7 +706,9 @@ impl Widget for MaterialButton<'_> {
         // Build icon galleys early (only if no SVG provided)
         let leading_icon_galley = if leading_svg_texture.is_none() {
             leading_icon.map(|name| {
-                let icon_str: WidgetText = material_symbol_text(&name).into();
+                let icon_str: WidgetText = RichText::new(material_symbol_text(&name))
+                    .size(BUTTON_ICON_SIZE)
+                    .into();
                 icon_str.into_galley(ui, Some(TextWrapMode::Extend), f32::INFINITY, TextStyle::Body)
             })
         } else {
@@ -592,7 +716,9 @@ impl Widget for MaterialButton<'_> {
         };
         let trailing_icon_galley = if trailing_svg_texture.is_none() {
             trailing_icon.map(|name| {
-                let icon_str: WidgetText = material_symbol_text(&name).into();
+                let icon_str: WidgetText = RichText::new(material_symbol_text(&name))
+                    .size(BUTTON_ICON_SIZE)
+                    .into();
                 icon_str.into_galley(ui, Some(TextWrapMode::Extend), f32::INFINITY, TextStyle::Body)
             })
         } else {
@@ -607,26 +733,50 @@ impl Widget for MaterialButton<'_> {
         // For small buttons: 4px (with icon) or 6px (without icon)
         let has_leading = leading_icon_galley.is_some() || leading_svg_texture.is_some() || image.is_some();
         let has_trailing = trailing_icon_galley.is_some() || trailing_svg_texture.is_some();
+        // Material Design minimum button height
+        let min_button_height = if small { 32.0 } else { 40.0 };
+        let svg_icon_size = BUTTON_ICON_SIZE;
+
+        // Icon-only buttons are square: pad the icon evenly up to the button height
+        let icon_only = has_leading
+            && !has_trailing
+            && image.is_none()
+            && text.is_none()
+            && shortcut_text.is_empty();
         let padding_multiplier = if small { 0.25 } else { 1.0 };
-        let padding_left = if has_leading { 16.0 } else { 24.0 } * padding_multiplier;
-        let padding_right = if has_trailing { 16.0 } else { 24.0 } * padding_multiplier;
+        let leading_icon_size = leading_icon_galley
+            .as_ref()
+            .map_or(Vec2::splat(svg_icon_size), |galley| galley.size());
+        let icon_only_padding =
+            ((Vec2::splat(min_button_height) - leading_icon_size) / 2.0).max(Vec2::ZERO);
+        let (padding_left, padding_right) = if icon_only {
+            (icon_only_padding.x, icon_only_padding.x)
+        } else {
+            (
+                if has_leading { 16.0 } else { 24.0 } * padding_multiplier,
+                if has_trailing { 16.0 } else { 24.0 } * padding_multiplier,
+            )
+        };
         let button_padding_left;
         let button_padding_right;
         let button_padding_y;
         if frame || variant == MaterialButtonVariant::Text {
             button_padding_left = padding_left;
             button_padding_right = padding_right;
-            button_padding_y = if small { 4.0 } else { 10.0 };
+            button_padding_y = if icon_only {
+                icon_only_padding.y
+            } else if small {
+                4.0
+            } else {
+                10.0
+            };
         } else {
             button_padding_left = 0.0;
             button_padding_right = 0.0;
             button_padding_y = 0.0;
         }
 
-        // Material Design minimum button height
-        let min_button_height = if small { 32.0 } else { 40.0 };
         let icon_spacing = if small { 4.0 } else { 8.0 }; // Material Design icon-to-text gap
-        let svg_icon_size = 18.0; // Size for SVG icons
 
         // Resolve the variant-based text color (used for text and icons)
         let resolved_text_color = if disabled {
@@ -644,6 +794,38 @@ impl Widget for MaterialButton<'_> {
             }
         };
 
+        // Custom content is laid out first to learn its size; slots for the
+        // shadow and container are reserved underneath it
+        let custom_content = content.map(|add_contents| {
+            let background = [
+                ui.painter().add(PaintShape::Noop),
+                ui.painter().add(PaintShape::Noop),
+            ];
+            let outer = ui.available_rect_before_wrap();
+            let inner_height =
+                (min_button_height.max(min_size.y) - 2.0 * button_padding_y).max(0.0);
+            let inner_rect = Rect::from_min_size(
+                outer.min + Vec2::new(button_padding_left, button_padding_y),
+                Vec2::new(
+                    (outer.width() - button_padding_left - button_padding_right).max(0.0),
+                    inner_height,
+                ),
+            );
+            let mut builder = UiBuilder::new()
+                .max_rect(inner_rect)
+                .layout(Layout::left_to_right(Align::Center));
+            if disabled {
+                builder = builder.disabled();
+            }
+            if loading {
+                builder = builder.invisible();
+            }
+            let mut content_ui = ui.new_child(builder);
+            content_ui.visuals_mut().override_text_color = Some(resolved_text_color);
+            add_contents(&mut content_ui);
+            (background, content_ui.min_rect())
+        });
+
         let space_available_for_image = if let Some(_text) = &text {
             let font_height = ui.text_style_height(&TextStyle::Body);
             Vec2::splat(font_height)
@@ -748,21 +930,52 @@ impl Widget for MaterialButton<'_> {
             desired_size.y = desired_size.y.max(shortcut_galley.size().y);
         }
 
+        if let Some((_, content_rect)) = &custom_content {
+            desired_size = desired_size.max(content_rect.size());
+        }
+
+        // Width of the leading/image/text/trailing group, centered in the button below
+        let content_width = desired_size.x;
+
         desired_size.x += button_padding_left + button_padding_right;
         desired_size.y += 2.0 * button_padding_y;
         if !small {
             desired_size.y = desired_size.y.at_least(min_button_height);
         }
+        if icon_only {
+            desired_size = desired_size.at_least(Vec2::splat(min_button_height));
+        }
         desired_size = desired_size.at_least(min_size);
 
-        let (rect, response) = ui.allocate_at_least(desired_size, sense);
+        // A loading or disabled button only senses hover, so it can't be clicked or focused
+        let sense = if loading || disabled {
+            Sense::hover()
+        } else {
+            sense
+        };
+        let (rect, response) = match &custom_content {
+            // The contents are already in place, so the button goes around them
+            Some((_, content_rect)) => {
+                let min = content_rect.min - Vec2::new(button_padding_left, button_padding_y);
+                let rect = Rect::from_min_size(min, desired_size);
+                (rect, ui.allocate_rect(rect, sense))
+            }
+            None => ui.allocate_at_least(desired_size, sense),
+        };
         response.widget_info(|| {
             if let Some(galley) = &galley {
-                WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), galley.text())
+                WidgetInfo::labeled(
+                    WidgetType::Button,
+                    ui.is_enabled() && !loading && !disabled,
+                    galley.text(),
+                )
             } else {
                 WidgetInfo::new(WidgetType::Button)
             }
         });
+        if let Some(text) = tooltip {
+            material_tooltip(&response, text);
+        }
 
         if ui.is_rect_visible(rect) {
             let visuals = ui.style().interact(&response);
@@ -790,9 +1003,14 @@ impl Widget for MaterialButton<'_> {
             let mut frame_fill = fill.unwrap_or(default_fill.unwrap_or(frame_fill));
             let mut frame_stroke = stroke.unwrap_or(default_stroke.unwrap_or(frame_stroke));
 
-            // Apply disabled styling (M3 spec: 38% opacity content, 12% opacity outline)
+            // Apply disabled styling (M3 spec: 38% opacity content, 12% opacity container and outline)
             if disabled {
-                frame_fill = surface; // Use surface for disabled button background
+                frame_fill = match variant {
+                    MaterialButtonVariant::Outlined | MaterialButtonVariant::Text => {
+                        Color32::TRANSPARENT
+                    }
+                    _ => on_surface.linear_multiply(0.12),
+                };
                 frame_stroke.color = on_surface.linear_multiply(0.12); // 12% opacity for disabled outline
                 frame_stroke.width = if matches!(variant, MaterialButtonVariant::Outlined) {
                     1.0 // Keep 1dp border for outlined variant
@@ -801,22 +1019,10 @@ impl Widget for MaterialButton<'_> {
                 };
             }
 
-            // M3 state layers: interactive overlay on hover/press
-            if !disabled {
-                let state_layer_color = resolved_text_color;
-                if response.is_pointer_button_down_on() {
-                    // Pressed state: 12% opacity overlay (M3 interaction state)
-                    frame_fill = blend_overlay(frame_fill, state_layer_color, 0.12);
-                } else if response.hovered() {
-                    // Hover state: 8% opacity overlay (M3 interaction state)
-                    frame_fill = blend_overlay(frame_fill, state_layer_color, 0.08);
-                }
-            }
-
             // Draw elevation shadow if present
             if let Some(shadow) = &elevation {
                 // Hover elevation boost for elevated buttons
-                let shadow = if !disabled && response.hovered() {
+                let shadow = if !disabled && !loading && response.hovered() {
                     Shadow {
                         offset: [shadow.offset[0], shadow.offset[1] + 2],
                         blur: shadow.blur + 4,
@@ -826,21 +1032,54 @@ impl Widget for MaterialButton<'_> {
                 } else {
                     *shadow
                 };
-                let shadow_offset = Vec2::new(shadow.offset[0] as f32, shadow.offset[1] as f32);
-                let shadow_rect = rect.expand2(frame_expansion).translate(shadow_offset);
-                ui.painter()
-                    .rect_filled(shadow_rect, frame_cr, shadow.color);
+                let shadow = shadow.as_shape(rect.expand2(frame_expansion), frame_cr);
+                match &custom_content {
+                    Some(([shadow_slot, _], _)) => ui.painter().set(*shadow_slot, shadow),
+                    None => {
+                        ui.painter().add(shadow);
+                    }
+                }
             }
 
-            ui.painter().rect(
+            let container = egui::epaint::RectShape::new(
                 rect.expand2(frame_expansion),
                 frame_cr,
                 frame_fill,
                 frame_stroke,
                 egui::epaint::StrokeKind::Outside,
             );
+            match &custom_content {
+                Some(([_, container_slot], _)) => ui.painter().set(*container_slot, container),
+                None => {
+                    ui.painter().add(container);
+                }
+            }
 
+            // M3 state layers and press ripple in the content color
+            if !disabled && !loading {
+                Ripple::new(resolved_text_color)
+                    .corner_radius(frame_cr)
+                    .show(ui, rect.expand2(frame_expansion), &response);
+            }
+
+            if loading {
+                // Spinner sized like a button icon, centered in the unchanged button
+                let spinner_size = if small { 14.0 } else { 18.0 };
+                let spinner_rect = Rect::from_center_size(rect.center(), Vec2::splat(spinner_size));
+                circular_progress(None)
+                    .active_color(resolved_text_color)
+                    .track_color(Color32::TRANSPARENT)
+                    .stroke_width(2.0)
+                    .paint_at(ui, spinner_rect);
+                return response;
+            }
+
+            // Center the content group; with a shortcut the label stays left-aligned
             let mut cursor_x = rect.min.x + button_padding_left;
+            if shortcut_galley.is_none() {
+                let inner_width = rect.width() - button_padding_left - button_padding_right;
+                cursor_x += ((inner_width - content_width) / 2.0).max(0.0);
+            }
             let content_rect_y_min = rect.min.y + button_padding_y;
             let content_rect_y_max = rect.max.y - button_padding_y;
             let content_height = content_rect_y_max - content_rect_y_min;
@@ -887,9 +1126,8 @@ impl Widget for MaterialButton<'_> {
                         ),
                     )
                     .min;
-                if galley.is_some() || shortcut_galley.is_some() || trailing_icon_galley.is_some() {
-                    image_pos.x = cursor_x;
-                }
+                // The content group is already centered horizontally
+                image_pos.x = cursor_x;
                 let image_rect = Rect::from_min_size(image_pos, image_size);
                 cursor_x += image_size.x + icon_spacing;
                 let mut image_widget = image.clone();
@@ -903,33 +1141,7 @@ impl Widget for MaterialButton<'_> {
             let has_text = galley.is_some();
             if let Some(galley) = galley {
                 let text_y = content_rect_y_min + (content_height - galley.size().y) / 2.0 + if small { 1.0 } else { 0.0 };
-                let mut text_pos = egui::pos2(cursor_x, text_y);
-                // Center text if no leading/trailing elements
-                if leading_icon_galley.is_none()
-                    && leading_svg_texture.is_none()
-                    && image.is_none()
-                    && trailing_icon_galley.is_none()
-                    && trailing_svg_texture.is_none()
-                    && shortcut_galley.is_none()
-                {
-                    text_pos = ui
-                        .layout()
-                        .align_size_within_rect(
-                            galley.size(),
-                            Rect::from_min_max(
-                                egui::pos2(
-                                    rect.min.x + button_padding_left,
-                                    content_rect_y_min,
-                                ),
-                                egui::pos2(
-                                    rect.max.x - button_padding_right,
-                                    content_rect_y_max,
-                                ),
-                            ),
-                        )
-                        .min;
-                }
-
+                let text_pos = egui::pos2(cursor_x, text_y);
                 cursor_x = text_pos.x + galley.size().x;
                 ui.painter().galley(text_pos, galley, resolved_text_color);
             }
@@ -988,7 +1200,7 @@ impl Widget for MaterialButton<'_> {
         }
 
         if let Some(cursor) = ui.visuals().interact_cursor {
-            if response.hovered() {
+            if response.hovered() && !loading {
                 ui.ctx().set_cursor_icon(cursor);
             }
         }
@@ -997,16 +1209,89 @@ impl Widget for MaterialButton<'_> {
     }
 }
 
-/// Blend an overlay color on top of a base color with given opacity.
-fn blend_overlay(base: Color32, overlay: Color32, opacity: f32) -> Color32 {
-    let alpha = (opacity * 255.0) as u8;
-    let overlay_with_alpha = Color32::from_rgba_unmultiplied(overlay.r(), overlay.g(), overlay.b(), alpha);
-    // Simple alpha blending
-    let inv_alpha = 255 - alpha;
-    Color32::from_rgba_unmultiplied(
-        ((base.r() as u16 * inv_alpha as u16 + overlay_with_alpha.r() as u16 * alpha as u16) / 255) as u8,
-        ((base.g() as u16 * inv_alpha as u16 + overlay_with_alpha.g() as u16 * alpha as u16) / 255) as u8,
-        ((base.b() as u16 * inv_alpha as u16 + overlay_with_alpha.b() as u16 * alpha as u16) / 255) as u8,
-        base.a(),
-    )
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Press and release the primary button over a button and report whether it clicked
+    fn click_button(button: impl Fn() -> MaterialButton<'static>) -> bool {
+        let ctx = egui::Context::default();
+        let pos = egui::pos2(30.0, 25.0);
+        let mut clicked = false;
+        for (frame, pressed) in [None, Some(true), Some(false)].into_iter().enumerate() {
+            let mut input = egui::RawInput {
+                time: Some(frame as f64 * 0.05),
+                ..Default::default()
+            };
+            input.events.push(egui::Event::PointerMoved(pos));
+            if let Some(pressed) = pressed {
+                input.events.push(egui::Event::PointerButton {
+                    pos,
+                    button: egui::PointerButton::Primary,
+                    pressed,
+                    modifiers: Default::default(),
+                });
+            }
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let response = ui.add(button());
+                    clicked |= response.clicked();
+                });
+            });
+        }
+        clicked
+    }
+
+    #[test]
+    fn test_loading_button_does_not_click() {
+        assert!(click_button(|| MaterialButton::filled("Save")));
+        assert!(!click_button(
+            || MaterialButton::filled("Save").loading(true)
+        ));
+    }
+
+    #[test]
+    fn test_disabled_button_does_not_click() {
+        assert!(!click_button(
+            || MaterialButton::filled("Save").enabled(false)
+        ));
+        assert!(!click_button(
+            || MaterialButton::outlined("Save").enabled(false)
+        ));
+    }
+
+    #[test]
+    fn test_icon_only_button_is_square() {
+        let ctx = egui::Context::default();
+        let mut sizes = Vec::new();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                sizes.push(ui.add(MaterialButton::icon("add")).rect.size());
+                sizes.push(ui.add(MaterialButton::icon("add").small()).rect.size());
+            });
+        });
+        for (size, expected) in sizes.into_iter().zip([40.0, 32.0]) {
+            assert!((size.x - expected).abs() < 0.01 && (size.y - expected).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_custom_button_hugs_contents_and_clicks() {
+        let ctx = egui::Context::default();
+        let mut size = Vec2::ZERO;
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let button = MaterialButton::custom(|ui| {
+                    ui.allocate_exact_size(Vec2::new(100.0, 20.0), Sense::hover());
+                });
+                size = ui.add(button).rect.size();
+            });
+        });
+        // 24dp padding on both sides, and the 40dp minimum height
+        assert_eq!(size, Vec2::new(148.0, 40.0));
+
+        assert!(click_button(|| MaterialButton::custom(|ui| {
+            ui.label("Save");
+        })));
+    }
 }
@@ -20,16 +20,33 @@
 //! - **onSurface**: Content text
 //! - **State layers**: onSurface @ 8% (hover), 12% (press)
 //!
+//! ## Interaction
+//! - **Hover**: elevation raised one level (clickable and draggable cards)
+//! - **Pressed**: elevation back at its resting level
+//! - **Dragged**: elevation raised three levels
+//! - Elevation changes animate over 150ms unless reduced motion is on
+//! - **State layer and ripple**: onSurface, drawn over the card content and
+//!   clipped to the card's corner radius
+//!
 //! ## Dimensions
-//! - **Corner radius**: 12dp
+//! - **Corner radius**: 12dp (the medium shape token)
 //! - **Padding**: 16dp
 
-use crate::theme::get_global_color;
+use crate::ripple::Ripple;
+use crate::theme::{
+    elevation_level, elevation_shadow_at, get_global_color, reduced_motion, shape_radius,
+    tinted_surface, type_font_id, TypeRole,
+};
 use egui::{
     ecolor::Color32,
-    epaint::{CornerRadius, Stroke},
-    Rect, Response, Sense, Ui, Vec2, Widget,
+    epaint::{CornerRadius, RectShape, Shape, Stroke},
+    layers::ShapeIdx,
+    Pos2, Rect, Response, Sense, Ui, Vec2, Widget,
 };
+use std::sync::Arc;
+
+/// Seconds for a clickable card to lift or settle between elevation levels
+const ELEVATION_ANIMATION_S: f32 = 0.15;
 
 /// Material Design card component variants (enhanced version).
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -61,6 +78,22 @@ pub enum Card2Variant {
 ///         }
 ///     }));
 /// # });
+///
+/// Clickable and draggable cards lift on hover and report clicks and drags
+/// through the returned [`Response`]:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut offset = egui::Vec2::ZERO;
+/// let response = ui.add(MaterialCard2::outlined()
+///     .header("Task", None::<String>)
+///     .clickable(true)
+///     .draggable(true));
+/// if response.clicked() {
+///     println!("Open task");
+/// }
+/// offset += response.drag_delta();
+/// # });
 /// ```
 #[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
 #[allow(clippy::type_complexity)]
@@ -74,6 +107,7 @@ pub struct MaterialCard2<'a> {
     min_size: Vec2,
     corner_radius: CornerRadius,
     clickable: bool,
+    draggable: bool,
     media_height: f32,
     elevation: Option<f32>,
     surface_tint_color: Option<Color32>,
@@ -108,8 +142,9 @@ impl<'a> MaterialCard2<'a> {
             main_content: None,
             actions_content: None,
             min_size: Vec2::new(280.0, 200.0), // Larger default size for enhanced card
-            corner_radius: CornerRadius::from(12.0),
+            corner_radius: shape_radius(crate::theme::Shape::Medium),
             clickable: false,
+            draggable: false,
             media_height: 160.0,
             elevation: None,
             surface_tint_color: None,
@@ -127,8 +162,21 @@ impl<'a> MaterialCard2<'a> {
         self
     }
 
-    /// Set media area content.
-    pub fn media_area<F>(mut self, content: F) -> Self
+    /// Set media content, such as an image, shown below the header.
+    ///
+    /// The content is clipped to the media area. Where the media area meets a
+    /// corner of the card, rects and images painted in it are rounded to the
+    /// card's corner radius, so an image filling the area follows the card shape.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let texture = ui.ctx().load_texture("media", egui::ColorImage::example(), Default::default());
+    /// ui.add(MaterialCard2::elevated().media(|ui| {
+    ///     ui.add(egui::Image::new(&texture).fit_to_exact_size(ui.available_size()));
+    /// }));
+    /// # });
+    /// ```
+    pub fn media<F>(mut self, content: F) -> Self
     where
         F: FnOnce(&mut Ui) + 'a,
     {
@@ -139,6 +187,14 @@ impl<'a> MaterialCard2<'a> {
         self
     }
 
+    /// Set media area content. Same as [`media`](Self::media).
+    pub fn media_area<F>(self, content: F) -> Self
+    where
+        F: FnOnce(&mut Ui) + 'a,
+    {
+        self.media(content)
+    }
+
     /// Set media area height.
     pub fn media_height(mut self, height: f32) -> Self {
         self.media_height = height;
@@ -181,14 +237,34 @@ impl<'a> MaterialCard2<'a> {
         self
     }
 
+    /// Set the corner radius from an M3 shape token; cards are
+    /// [`Shape::Medium`](crate::theme::Shape::Medium) by default.
+    pub fn shape(self, shape: crate::theme::Shape) -> Self {
+        self.corner_radius(shape_radius(shape))
+    }
+
     /// Make the card clickable.
+    ///
+    /// A clickable card shows state layers and a ripple, and rises one
+    /// elevation level on hover. Check `clicked()` on the returned response.
     pub fn clickable(mut self, clickable: bool) -> Self {
         self.clickable = clickable;
         self
     }
 
-    /// Set the elevation of the card.
+    /// Let the card be dragged, for example between columns of a board.
+    ///
+    /// The card doesn't move by itself: read `drag_delta()` from the returned
+    /// response and move it where it belongs. While dragged it rises three
+    /// elevation levels.
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
+    /// Set the elevation of the card in dp.
     /// For Material 3: Elevated = 1.0, Filled = 0.0, Outlined = 0.0
+    /// The shadow and tint use the nearest M3 elevation level, see [`elevation_shadow`].
     pub fn elevation(mut self, elevation: f32) -> Self {
         self.elevation = Some(elevation.max(0.0));
         self
@@ -252,36 +328,16 @@ impl<'a> MaterialCard2<'a> {
         }
     }
 
-    /// Calculate surface tint overlay based on elevation level.
-    /// Material 3 uses elevation levels: 0 (0%), 1 (5%), 2 (8%), 3 (11%), 4 (12%), 5 (14%)
-    fn calculate_tint_overlay(&self, elevation: f32) -> f32 {
-        
-        match elevation as i32 {
-            0 => 0.0,
-            1 => 0.05,
-            2..=3 => 0.08,
-            4..=6 => 0.11,
-            7..=8 => 0.12,
-            _ => 0.14,
-        }
-    }
-
     /// Blend surface tint color with base color based on elevation.
     fn apply_surface_tint(&self, base_color: Color32, elevation: f32) -> Color32 {
         if elevation <= 0.0 {
             return base_color;
         }
 
-        let tint_color = self.surface_tint_color.unwrap_or_else(|| get_global_color("primary"));
-        let tint_opacity = self.calculate_tint_overlay(elevation);
-
-        // Blend tint color over base color
-        Color32::from_rgba_premultiplied(
-            (base_color.r() as f32 * (1.0 - tint_opacity) + tint_color.r() as f32 * tint_opacity) as u8,
-            (base_color.g() as f32 * (1.0 - tint_opacity) + tint_color.g() as f32 * tint_opacity) as u8,
-            (base_color.b() as f32 * (1.0 - tint_opacity) + tint_color.b() as f32 * tint_opacity) as u8,
-            255,
-        )
+        let tint_color = self
+            .surface_tint_color
+            .unwrap_or_else(|| get_global_color("surfaceTint"));
+        tinted_surface(base_color, tint_color, elevation_level(elevation) as usize)
     }
 }
 
@@ -309,6 +365,7 @@ impl Widget for MaterialCard2<'_> {
             min_size,
             corner_radius,
             clickable,
+            draggable,
             media_height,
             elevation: _,
             surface_tint_color: _,
@@ -318,7 +375,9 @@ impl Widget for MaterialCard2<'_> {
             border_on_foreground,
         } = self;
 
-        let sense = if clickable {
+        let sense = if draggable {
+            Sense::click_and_drag()
+        } else if clickable {
             Sense::click()
         } else {
             Sense::hover()
@@ -360,27 +419,47 @@ impl Widget for MaterialCard2<'_> {
             desired_size,
         );
 
+        // Clickable and draggable cards lift on hover and while dragged (M3 card states)
+        let interactive = clickable || draggable;
+        let mut level = elevation_level(elevation);
+        if interactive && response.dragged() {
+            level += 3;
+        } else if interactive && response.hovered() && !response.is_pointer_button_down_on() {
+            level += 1;
+        }
+        let target_level = level.min(5) as f32;
+        let level = if reduced_motion(ui.ctx()) || !interactive {
+            target_level
+        } else {
+            ui.ctx().animate_value_with_time(
+                response.id.with("elevation"),
+                target_level,
+                ELEVATION_ANIMATION_S,
+            )
+        };
+
+        if draggable {
+            if response.dragged() {
+                ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+            } else if response.hovered() {
+                ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+            }
+        }
+
+        // The card's own interaction, before content responses are merged in
+        let card_response = response.clone();
+
         if ui.is_rect_visible(rect) {
             // Draw shadow based on elevation
-            if elevation > 0.0 {
-                let shadow_offset = (elevation * 0.5).min(4.0);
-                let _shadow_blur = elevation * 0.5;
-                let shadow_alpha = (elevation * 3.0).min(30.0) as u8;
-                
-                let shadow_rect = Rect::from_min_size(
-                    rect.min + Vec2::new(0.0, shadow_offset),
-                    rect.size(),
-                );
-                ui.painter().rect_filled(
-                    shadow_rect,
-                    corner_radius,
-                    Color32::from_rgba_unmultiplied(
-                        shadow_color.r(),
-                        shadow_color.g(),
-                        shadow_color.b(),
-                        shadow_alpha,
-                    ),
+            if level > 0.0 {
+                let mut shadow = elevation_shadow_at(level);
+                shadow.color = Color32::from_rgba_unmultiplied(
+                    shadow_color.r(),
+                    shadow_color.g(),
+                    shadow_color.b(),
+                    shadow.color.a(),
                 );
+                ui.painter().add(shadow.as_shape(rect, corner_radius));
             }
 
             // Draw border behind if needed
@@ -425,7 +504,7 @@ impl Widget for MaterialCard2<'_> {
                         subtitle_pos,
                         egui::Align2::LEFT_TOP,
                         subtitle,
-                        egui::FontId::proportional(14.0),
+                        type_font_id(TypeRole::BodyMedium),
                         get_global_color("onSurfaceVariant"),
                     );
                 }
@@ -440,23 +519,34 @@ impl Widget for MaterialCard2<'_> {
                     Vec2::new(rect.width(), media_height),
                 );
 
-                // Clip media content to card bounds
-                let mut media_ui_builder = egui::UiBuilder::new().max_rect(media_rect);
-                if clip_behavior {
-                    // Enable clipping for media area
-                    media_ui_builder = media_ui_builder.sense(Sense::hover());
-                }
-                
-                let media_response = ui.scope_builder(media_ui_builder, |ui| {
-                    // Draw media background
-                    ui.painter().rect_filled(
-                        media_rect,
-                        CornerRadius::ZERO,
-                        get_global_color("surfaceVariant"),
-                    );
-
-                    media_fn(ui)
-                });
+                // Media spans the card's width; corners it shares with the card
+                // follow the card's rounding
+                let at_top = media_rect.min.y == rect.min.y;
+                let at_bottom = media_rect.max.y == rect.max.y;
+                let media_corner_radius = CornerRadius {
+                    nw: if at_top { corner_radius.nw } else { 0 },
+                    ne: if at_top { corner_radius.ne } else { 0 },
+                    sw: if at_bottom { corner_radius.sw } else { 0 },
+                    se: if at_bottom { corner_radius.se } else { 0 },
+                };
+
+                let first_shape = ui.painter().add(Shape::Noop);
+                let media_response =
+                    ui.scope_builder(egui::UiBuilder::new().max_rect(media_rect), |ui| {
+                        // Media never spills out of its area
+                        ui.set_clip_rect(media_rect.intersect(ui.clip_rect()));
+
+                        // Draw media background
+                        ui.painter().rect_filled(
+                            media_rect,
+                            media_corner_radius,
+                            get_global_color("surfaceVariant"),
+                        );
+
+                        media_fn(ui)
+                    });
+                let end_shape = ui.painter().add(Shape::Noop);
+                round_media_corners(ui, first_shape, end_shape, media_rect, media_corner_radius);
 
                 response = response.union(media_response.response);
                 current_y += media_height;
@@ -471,7 +561,12 @@ impl Widget for MaterialCard2<'_> {
 
                 let content_response = ui.scope_builder(
                     egui::UiBuilder::new().max_rect(content_rect.shrink(16.0)),
-                    |ui| content_fn(ui),
+                    |ui| {
+                        if clip_behavior {
+                            ui.set_clip_rect(content_rect.intersect(ui.clip_rect()));
+                        }
+                        content_fn(ui)
+                    },
                 );
 
                 response = response.union(content_response.response);
@@ -488,6 +583,9 @@ impl Widget for MaterialCard2<'_> {
                 let actions_response = ui.scope_builder(
                     egui::UiBuilder::new().max_rect(actions_rect.shrink2(Vec2::new(8.0, 8.0))),
                     |ui| {
+                        if clip_behavior {
+                            ui.set_clip_rect(actions_rect.intersect(ui.clip_rect()));
+                        }
                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                             actions_fn(ui)
                         })
@@ -498,6 +596,13 @@ impl Widget for MaterialCard2<'_> {
                 response = response.union(actions_response.response);
             }
 
+            // State layers and ripple in onSurface, over the content
+            if interactive {
+                Ripple::new(get_global_color("onSurface"))
+                    .corner_radius(corner_radius)
+                    .show(ui, rect, &card_response);
+            }
+
             // Draw border on foreground if needed
             if border_on_foreground {
                 if let Some(stroke) = stroke {
@@ -515,6 +620,70 @@ impl Widget for MaterialCard2<'_> {
     }
 }
 
+/// Round the rects and images painted between `first` and `end` where they meet
+/// a rounded corner of the media area.
+fn round_media_corners(
+    ui: &Ui,
+    first: ShapeIdx,
+    end: ShapeIdx,
+    media_rect: Rect,
+    corner_radius: CornerRadius,
+) {
+    if corner_radius == CornerRadius::ZERO {
+        return;
+    }
+    ui.ctx().graphics_mut(|graphics| {
+        let shapes = graphics.entry(ui.layer_id());
+        for idx in first.0..end.0 {
+            shapes.mutate_shape(ShapeIdx(idx), |clipped| {
+                if let Shape::Rect(rect_shape) = &mut clipped.shape {
+                    clip_rect_shape(rect_shape, media_rect, corner_radius);
+                }
+            });
+        }
+    });
+}
+
+/// Trim a rect or image to `clip`, keeping the visible part of its texture, and
+/// round the corners it shares with `clip` by at least `corner_radius`.
+///
+/// Trimming first means an image that overflows the media area, such as a
+/// cropped photo, is rounded at the corner that is actually visible.
+fn clip_rect_shape(shape: &mut RectShape, clip: Rect, corner_radius: CornerRadius) {
+    let visible = shape.rect.intersect(clip);
+    if !visible.is_positive() {
+        return;
+    }
+    if visible != shape.rect {
+        if let Some(brush) = &mut shape.brush {
+            let (rect, uv) = (shape.rect, brush.uv);
+            let to_uv = |pos: Pos2| {
+                Pos2::new(
+                    uv.min.x + (pos.x - rect.min.x) / rect.width() * uv.width(),
+                    uv.min.y + (pos.y - rect.min.y) / rect.height() * uv.height(),
+                )
+            };
+            Arc::make_mut(brush).uv = Rect::from_min_max(to_uv(visible.min), to_uv(visible.max));
+        }
+        shape.rect = visible;
+    }
+
+    let meets = |a: Pos2, b: Pos2| a.distance(b) < 0.5;
+    let radius = &mut shape.corner_radius;
+    if meets(visible.left_top(), clip.left_top()) {
+        radius.nw = radius.nw.max(corner_radius.nw);
+    }
+    if meets(visible.right_top(), clip.right_top()) {
+        radius.ne = radius.ne.max(corner_radius.ne);
+    }
+    if meets(visible.left_bottom(), clip.left_bottom()) {
+        radius.sw = radius.sw.max(corner_radius.sw);
+    }
+    if meets(visible.right_bottom(), clip.right_bottom()) {
+        radius.se = radius.se.max(corner_radius.se);
+    }
+}
+
 /// Convenience function to create an elevated enhanced card.
 pub fn elevated_card2() -> MaterialCard2<'static> {
     MaterialCard2::elevated()
@@ -529,3 +698,44 @@ pub fn filled_card2() -> MaterialCard2<'static> {
 pub fn outlined_card2() -> MaterialCard2<'static> {
     MaterialCard2::outlined()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::{pos2, TextureId};
+
+    #[test]
+    fn test_media_image_is_trimmed_and_rounded_at_card_corners() {
+        let media = Rect::from_min_max(pos2(0.0, 0.0), pos2(200.0, 100.0));
+        let corner_radius = CornerRadius {
+            nw: 12,
+            ne: 12,
+            sw: 0,
+            se: 0,
+        };
+
+        // A cropped image overflowing the media area on every side
+        let full_uv = Rect::from_min_max(Pos2::ZERO, pos2(1.0, 1.0));
+        let mut image = RectShape::filled(
+            Rect::from_min_max(pos2(-100.0, -50.0), pos2(300.0, 150.0)),
+            CornerRadius::ZERO,
+            Color32::WHITE,
+        )
+        .with_texture(TextureId::Managed(1), full_uv);
+        clip_rect_shape(&mut image, media, corner_radius);
+
+        assert_eq!(image.rect, media);
+        assert_eq!(
+            image.brush.as_ref().unwrap().uv,
+            Rect::from_min_max(pos2(0.25, 0.25), pos2(0.75, 0.75))
+        );
+        assert_eq!(image.corner_radius, corner_radius);
+
+        // A shape away from the corners is left alone
+        let inner = Rect::from_min_max(pos2(50.0, 20.0), pos2(150.0, 80.0));
+        let mut fill = RectShape::filled(inner, CornerRadius::same(4), Color32::WHITE);
+        clip_rect_shape(&mut fill, media, corner_radius);
+        assert_eq!(fill.rect, inner);
+        assert_eq!(fill.corner_radius, CornerRadius::same(4));
+    }
+}
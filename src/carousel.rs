@@ -9,6 +9,7 @@
 //! - **onSurfaceVariant**: Inactive indicator dots
 
 use crate::get_global_color;
+use crate::theme::reduced_motion;
 use egui::{self, FontId, Pos2, Rect, Response, Sense, Ui, Vec2};
 use egui::epaint::CornerRadius;
 
@@ -197,7 +198,7 @@ impl<'a> egui::Widget for MaterialCarousel<'a> {
             let nearest_item = (*self.scroll_offset / item_step).round();
             let target = (nearest_item * item_step).clamp(0.0, max_scroll);
             let diff = target - *self.scroll_offset;
-            if diff.abs() > 0.5 {
+            if diff.abs() > 0.5 && !reduced_motion(ui.ctx()) {
                 *self.scroll_offset += diff * 0.15;
                 ui.ctx().request_repaint();
             } else {
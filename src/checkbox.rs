@@ -29,7 +29,65 @@
 //! - **18x18dp**: Visible checkbox size
 
 use crate::get_global_color;
-use egui::{self, Color32, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget};
+use egui::{
+    self, accesskit, Color32, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget, WidgetInfo,
+    WidgetType,
+};
+
+/// State of a tri-state checkbox
+///
+/// `Indeterminate` is only set by the app, typically on a parent checkbox
+/// whose children are partly selected; see [`CheckState::from_children`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+impl CheckState {
+    /// The state after a click: checked becomes unchecked, anything else checked
+    pub fn toggled(self) -> Self {
+        match self {
+            CheckState::Checked => CheckState::Unchecked,
+            CheckState::Unchecked | CheckState::Indeterminate => CheckState::Checked,
+        }
+    }
+
+    /// Parent state for a group of child checkboxes
+    ///
+    /// `Checked` when all children are checked, `Unchecked` when none are
+    /// (or there are none) and `Indeterminate` otherwise.
+    pub fn from_children(children: impl IntoIterator<Item = bool>) -> Self {
+        let (mut any_checked, mut any_unchecked) = (false, false);
+        for checked in children {
+            any_checked |= checked;
+            any_unchecked |= !checked;
+        }
+        match (any_checked, any_unchecked) {
+            (true, false) => CheckState::Checked,
+            (true, true) => CheckState::Indeterminate,
+            _ => CheckState::Unchecked,
+        }
+    }
+}
+
+impl From<bool> for CheckState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            CheckState::Checked
+        } else {
+            CheckState::Unchecked
+        }
+    }
+}
+
+/// The value a checkbox edits
+enum CheckValue<'a> {
+    Bool(&'a mut bool),
+    Tristate(&'a mut CheckState),
+}
 
 /// Material Design checkbox component following Material Design 3 specifications
 ///
@@ -53,6 +111,10 @@ use egui::{self, Color32, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget}
 /// let mut disabled_checked = false;  
 /// ui.add(MaterialCheckbox::new(&mut disabled_checked, "Disabled option")
 ///     .enabled(false));
+///
+/// // Required checkbox that failed validation
+/// let mut agreed = false;
+/// ui.add(MaterialCheckbox::new(&mut agreed, "I agree").error(!agreed));
 /// # });
 /// ```
 ///
@@ -63,7 +125,7 @@ use egui::{self, Color32, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget}
 /// - States: Normal, hover, focus, pressed, disabled, error
 pub struct MaterialCheckbox<'a> {
     /// Mutable reference to the checked state
-    checked: &'a mut bool,
+    checked: CheckValue<'a>,
     /// Text label displayed next to the checkbox
     text: String,
     /// Whether the checkbox is in indeterminate state (partially checked)
@@ -90,6 +152,38 @@ impl<'a> MaterialCheckbox<'a> {
     /// ## Returns
     /// A new MaterialCheckbox instance with default settings
     pub fn new(checked: &'a mut bool, text: impl Into<String>) -> Self {
+        Self::with_value(CheckValue::Bool(checked), text)
+    }
+
+    /// Create a tri-state checkbox
+    ///
+    /// Clicking cycles between unchecked and checked; an indeterminate
+    /// checkbox becomes checked. Set `CheckState::Indeterminate` yourself,
+    /// e.g. for a "select all" checkbox whose children are partly selected.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use egui_material3::{CheckState, MaterialCheckbox};
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut toppings = [("Cheese", true), ("Olives", false), ("Basil", true)];
+    ///
+    /// let mut all = CheckState::from_children(toppings.iter().map(|(_, on)| *on));
+    /// if ui.add(MaterialCheckbox::tristate(&mut all, "All toppings")).changed() {
+    ///     // Checking or unchecking the parent applies to every child
+    ///     for (_, on) in &mut toppings {
+    ///         *on = all == CheckState::Checked;
+    ///     }
+    /// }
+    /// for (name, on) in &mut toppings {
+    ///     ui.add(MaterialCheckbox::new(on, *name));
+    /// }
+    /// # });
+    /// ```
+    pub fn tristate(state: &'a mut CheckState, text: impl Into<String>) -> Self {
+        Self::with_value(CheckValue::Tristate(state), text)
+    }
+
+    fn with_value(checked: CheckValue<'a>, text: impl Into<String>) -> Self {
         Self {
             checked,
             text: text.into(),
@@ -106,6 +200,7 @@ impl<'a> MaterialCheckbox<'a> {
     ///
     /// Indeterminate checkboxes are used when the checkbox represents
     /// a collection of items where some, but not all, are selected.
+    /// For a checkbox that stores the state itself, see [`Self::tristate`].
     ///
     /// ## Parameters  
     /// - `indeterminate`: True for indeterminate state, false for normal
@@ -137,6 +232,13 @@ impl<'a> MaterialCheckbox<'a> {
         self
     }
 
+    /// Show the checkbox in the error color, e.g. for a required field
+    ///
+    /// Same as [`Self::is_error`].
+    pub fn error(self, error: bool) -> Self {
+        self.is_error(error)
+    }
+
     /// Set custom check mark color
     ///
     /// Overrides the default M3 **onPrimary** color role (or **onError** in error state).
@@ -172,7 +274,7 @@ impl<'a> MaterialCheckbox<'a> {
 }
 
 impl<'a> Widget for MaterialCheckbox<'a> {
-    fn ui(self, ui: &mut Ui) -> Response {
+    fn ui(mut self, ui: &mut Ui) -> Response {
         let checkbox_size = 18.0;
         let spacing = 4.0;
 
@@ -190,16 +292,42 @@ impl<'a> Widget for MaterialCheckbox<'a> {
         let desired_width = checkbox_size + spacing + text_width;
         let desired_size = Vec2::new(desired_width, 24.0);
 
-        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+        let sense = if self.enabled {
+            Sense::click()
+        } else {
+            Sense::hover()
+        };
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, sense);
+
+        let mut state = match &self.checked {
+            CheckValue::Bool(_) if self.indeterminate => CheckState::Indeterminate,
+            CheckValue::Bool(checked) => CheckState::from(**checked),
+            CheckValue::Tristate(value) => **value,
+        };
 
         if response.clicked() && self.enabled {
-            if self.indeterminate {
-                *self.checked = true;
-            } else {
-                *self.checked = !*self.checked;
+            state = state.toggled();
+            match &mut self.checked {
+                CheckValue::Bool(checked) => **checked = state == CheckState::Checked,
+                CheckValue::Tristate(value) => **value = state,
             }
             response.mark_changed();
         }
+        let filled = state != CheckState::Unchecked;
+        response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::Checkbox,
+                self.enabled,
+                state == CheckState::Checked,
+                &self.text,
+            )
+        });
+        if state == CheckState::Indeterminate {
+            // Announced as "mixed" rather than unchecked
+            ui.ctx().accesskit_node_builder(response.id, |node| {
+                node.set_toggled(accesskit::Toggled::Mixed);
+            });
+        }
 
         let _visuals = ui.style().interact(&response);
         let checkbox_rect = Rect::from_min_size(
@@ -219,21 +347,21 @@ impl<'a> Widget for MaterialCheckbox<'a> {
         let (bg_color, border_color, check_color, border_width) = if !self.enabled {
             // Disabled state: onSurface @ 38% opacity for all elements (M3 spec)
             let disabled_color = on_surface.linear_multiply(0.38);
-            if *self.checked || self.indeterminate {
+            if filled {
                 (disabled_color, Color32::TRANSPARENT, disabled_color, 0.0)
             } else {
                 (Color32::TRANSPARENT, disabled_color, disabled_color, self.border_width)
             }
         } else if self.is_error {
             // Error state: use error color for container/border
-            if *self.checked || self.indeterminate {
+            if filled {
                 // Checked error state: error background with onError check mark
                 (error, Color32::TRANSPARENT, on_error, 0.0)
             } else {
                 // Unchecked error state: error border
                 (Color32::TRANSPARENT, error, on_surface, self.border_width)
             }
-        } else if *self.checked || self.indeterminate {
+        } else if filled {
             // Checked/indeterminate state: primary background with onPrimary check mark
             (primary, Color32::TRANSPARENT, on_primary, 0.0)
         } else if response.hovered() {
@@ -258,7 +386,7 @@ impl<'a> Widget for MaterialCheckbox<'a> {
         }
 
         // Draw checkmark or indeterminate mark
-        if *self.checked && !self.indeterminate {
+        if state == CheckState::Checked {
             // Draw checkmark
             let center = checkbox_rect.center();
             let checkmark_size = checkbox_size * 0.6;
@@ -277,7 +405,7 @@ impl<'a> Widget for MaterialCheckbox<'a> {
                 .line_segment([start, middle], Stroke::new(2.0, check_color));
             ui.painter()
                 .line_segment([middle, end], Stroke::new(2.0, check_color));
-        } else if self.indeterminate {
+        } else if state == CheckState::Indeterminate {
             // Draw indeterminate mark (horizontal line)
             let center = checkbox_rect.center();
             let line_width = checkbox_size * 0.5;
@@ -315,7 +443,7 @@ impl<'a> Widget for MaterialCheckbox<'a> {
                 // Pressed state: 10% opacity (M3 interaction state)
                 if self.is_error {
                     error.linear_multiply(0.10)
-                } else if *self.checked || self.indeterminate {
+                } else if filled {
                     primary.linear_multiply(0.10)
                 } else {
                     on_surface.linear_multiply(0.10)
@@ -324,7 +452,7 @@ impl<'a> Widget for MaterialCheckbox<'a> {
                 // Hover state: 8% opacity (M3 interaction state)
                 if self.is_error {
                     error.linear_multiply(0.08)
-                } else if *self.checked || self.indeterminate {
+                } else if filled {
                     primary.linear_multiply(0.08)
                 } else {
                     on_surface.linear_multiply(0.08)
@@ -333,7 +461,7 @@ impl<'a> Widget for MaterialCheckbox<'a> {
                 // Focus state: 10% opacity (M3 interaction state)
                 if self.is_error {
                     error.linear_multiply(0.10)
-                } else if *self.checked || self.indeterminate {
+                } else if filled {
                     primary.linear_multiply(0.10)
                 } else {
                     on_surface.linear_multiply(0.10)
@@ -358,3 +486,61 @@ impl<'a> Widget for MaterialCheckbox<'a> {
 pub fn checkbox(checked: &mut bool, text: impl Into<String>) -> MaterialCheckbox<'_> {
     MaterialCheckbox::new(checked, text)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tristate_click_cycle() {
+        assert_eq!(CheckState::Unchecked.toggled(), CheckState::Checked);
+        assert_eq!(CheckState::Checked.toggled(), CheckState::Unchecked);
+        assert_eq!(CheckState::Indeterminate.toggled(), CheckState::Checked);
+    }
+
+    #[test]
+    fn test_parent_state_from_children() {
+        assert_eq!(CheckState::from_children([true, true]), CheckState::Checked);
+        assert_eq!(
+            CheckState::from_children([false, false]),
+            CheckState::Unchecked
+        );
+        assert_eq!(
+            CheckState::from_children([true, false]),
+            CheckState::Indeterminate
+        );
+        assert_eq!(CheckState::from_children([]), CheckState::Unchecked);
+    }
+
+    #[test]
+    fn test_checkbox_exposes_toggle_state_to_screen_readers() {
+        let ctx = egui::Context::default();
+        ctx.enable_accesskit();
+        let mut ids = Vec::new();
+        let output = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let (mut wifi, mut bluetooth) = (true, false);
+                ids = vec![
+                    ui.add(MaterialCheckbox::new(&mut wifi, "Wi-Fi")).id,
+                    ui.add(MaterialCheckbox::new(&mut bluetooth, "Bluetooth").indeterminate(true))
+                        .id,
+                ];
+            });
+        });
+
+        let update = output.platform_output.accesskit_update.unwrap();
+        let node = |id: egui::Id| {
+            update
+                .nodes
+                .iter()
+                .find(|(node_id, _)| *node_id == id.accesskit_id())
+                .map(|(_, node)| node.clone())
+                .unwrap()
+        };
+        let wifi = node(ids[0]);
+        assert_eq!(wifi.role(), accesskit::Role::CheckBox);
+        assert_eq!(wifi.label(), Some("Wi-Fi"));
+        assert_eq!(wifi.toggled(), Some(accesskit::Toggled::True));
+        assert_eq!(node(ids[1]).toggled(), Some(accesskit::Toggled::Mixed));
+    }
+}
@@ -42,9 +42,12 @@
 //! - **Icon size**: 18dp (in 24dp chip), 24dp (in 32dp chip, displayed at 20dp for balance)
 //! - **Touch target**: 48x48dp minimum
 
-use crate::{get_global_color, image_utils};
+use crate::theme::{
+    is_rtl, mirror_align, mirror_rect, mirror_x, reduced_motion, shape_radius, Shape,
+};
+use crate::{get_global_color, image_utils, ripple::Ripple};
 use egui::{
-    self, Color32, Pos2, Rect, Response, Sense, Stroke, TextureHandle, Ui, Vec2, Widget,
+    self, Color32, Id, Pos2, Rect, Response, Sense, Stroke, TextureHandle, Ui, Vec2, Widget,
 };
 
 /// Material Design chip variants following Material Design 3 specifications
@@ -102,7 +105,7 @@ pub enum IconType {
 ///
 /// ## Material Design Spec
 /// - Height: 32dp
-/// - Corner radius: 8dp
+/// - Corner radius: 8dp (the small shape token)
 /// - Text: Label Large (14sp/500 weight)
 /// - Touch target: Minimum 48x48dp
 pub struct MaterialChip<'a> {
@@ -128,6 +131,10 @@ pub struct MaterialChip<'a> {
     is_small: bool,
     /// Optional action callback when chip is clicked
     action: Option<Box<dyn Fn() + 'a>>,
+    /// Optional salt for a stable ID across frames (recommended for removable chips)
+    id_salt: Option<String>,
+    /// Shape token for the container corners
+    shape: Shape,
 }
 
 impl<'a> MaterialChip<'a> {
@@ -149,6 +156,8 @@ impl<'a> MaterialChip<'a> {
             avatar: false, // regular chips are more rectangular by default
             is_small: false,
             action: None,
+            id_salt: None,
+            shape: Shape::Small,
         }
     }
 
@@ -250,6 +259,12 @@ impl<'a> MaterialChip<'a> {
         self
     }
 
+    /// Set the corner radius from an M3 shape token; chips are [`Shape::Small`] by default
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.shape = shape;
+        self
+    }
+
     /// Set whether the chip can be removed
     ///
     /// Removable chips show an X icon that allows users to remove the chip
@@ -306,6 +321,13 @@ impl<'a> MaterialChip<'a> {
         self
     }
 
+    /// Set an ID salt so the chip keeps its animation state when chips
+    /// before it are added or removed
+    pub fn id_salt(mut self, salt: impl Into<String>) -> Self {
+        self.id_salt = Some(salt.into());
+        self
+    }
+
     /// Set a callback function to be called when the chip is clicked
     ///
     /// This allows defining custom actions for each chip, such as navigating to
@@ -320,6 +342,7 @@ impl<'a> MaterialChip<'a> {
 }
 
 /// Resolved chip colors for rendering
+#[derive(Clone, Copy)]
 struct ChipColors {
     bg: Color32,
     border: Color32,
@@ -336,8 +359,6 @@ fn resolve_chip_colors(
     enabled: bool,
     soft_disabled: bool,
     elevated: bool,
-    is_hovered: bool,
-    is_pressed: bool,
 ) -> ChipColors {
     // M3 Color Roles - Chip Variants
     let on_surface = get_global_color("onSurface"); // Disabled background/text
@@ -375,21 +396,12 @@ fn resolve_chip_colors(
         };
     }
 
-    // M3 state layers: hover @ 8%, press @ 12%
-    let state_layer_base = if is_selected {
+    // M3 state layers (hover @ 8%, press @ 12%) are drawn by the ripple in this color
+    let state_layer = if is_selected {
         on_secondary_container // Selected chips use onSecondaryContainer for state layers
     } else {
         on_surface_variant // Unselected chips use onSurfaceVariant for state layers
     };
-    let state_layer = if is_pressed {
-        // Pressed state: 12% opacity (M3 interaction state)
-        state_layer_base.linear_multiply(0.12)
-    } else if is_hovered {
-        // Hover state: 8% opacity (M3 interaction state)
-        state_layer_base.linear_multiply(0.08)
-    } else {
-        Color32::TRANSPARENT
-    };
 
     // Selected filter chip: secondaryContainer background with onSecondaryContainer content
     if variant == ChipVariant::Filter && is_selected {
@@ -426,50 +438,188 @@ fn resolve_chip_colors(
     }
 }
 
-impl<'a> Widget for MaterialChip<'a> {
-    fn ui(self, ui: &mut Ui) -> Response {
+/// Duration of the selection (checkmark and container color) animation
+const SELECTION_ANIMATION_S: f32 = 0.15;
+/// Duration of the shrink-and-fade animation played before a chip is removed
+const REMOVE_ANIMATION_S: f64 = 0.2;
+
+/// Response from showing a [`MaterialChip`]
+pub struct ChipResponse {
+    /// The response of the chip itself
+    pub response: Response,
+    /// Whether the chip body (not its remove icon) was clicked this frame
+    pub clicked: bool,
+    /// Whether a filter chip's selection was toggled this frame
+    pub toggled: bool,
+    /// Whether the removal animation finished this frame; the caller should delete the chip now
+    pub removed: bool,
+}
+
+/// Blend two resolved color sets, `t = 0` giving `from` and `t = 1` giving `to`
+fn lerp_chip_colors(from: ChipColors, to: ChipColors, t: f32) -> ChipColors {
+    ChipColors {
+        bg: from.bg.lerp_to_gamma(to.bg, t),
+        border: from.border.lerp_to_gamma(to.border, t),
+        text: from.text.lerp_to_gamma(to.text, t),
+        icon: from.icon.lerp_to_gamma(to.icon, t),
+        delete_icon: from.delete_icon.lerp_to_gamma(to.delete_icon, t),
+        state_layer: from.state_layer.lerp_to_gamma(to.state_layer, t),
+    }
+}
+
+impl<'a> MaterialChip<'a> {
+    fn icon_size(&self) -> f32 {
+        if self.is_small {
+            18.0
+        } else {
+            24.0
+        }
+    }
+
+    /// Width of the leading slot; the filter checkmark slot opens with `selection`
+    fn leading_width(&self, selection: f32) -> f32 {
+        if self.leading_icon.is_some() {
+            self.icon_size()
+        } else if self.variant == ChipVariant::Filter {
+            self.icon_size() * selection
+        } else {
+            0.0
+        }
+    }
+
+    /// Width of the chip for a given selection progress
+    fn width(&self, ui: &Ui, selection: f32) -> f32 {
+        let text_width = ui
+            .painter()
+            .layout_no_wrap(
+                self.text.clone(),
+                egui::FontId::default(),
+                egui::Color32::WHITE,
+            )
+            .rect
+            .width();
+        let remove_width = if self.removable {
+            self.icon_size()
+        } else {
+            0.0
+        };
+        let padding = if self.is_small { 12.0 } else { 16.0 };
+        text_width + self.leading_width(selection) + remove_width + padding
+    }
+
+    /// Width of the chip once its selection animation has settled
+    fn resting_width(&self, ui: &Ui) -> f32 {
         let is_selected = self.selected.as_ref().is_some_and(|s| **s);
+        self.width(ui, if is_selected { 1.0 } else { 0.0 })
+    }
 
-        let text_width = ui.painter().layout_no_wrap(
-            self.text.clone(),
-            egui::FontId::default(),
-            egui::Color32::WHITE,
-        ).rect.width();
+    /// Show the chip, reporting selection toggles and completed removals.
+    ///
+    /// Clicking the remove icon of a [`removable`](Self::removable) chip plays a
+    /// short shrink-and-fade animation; [`ChipResponse::removed`] is set once it
+    /// has finished, so the chip should stay in the caller's list until then.
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut tags = vec!["rust".to_string(), "egui".to_string()];
+    /// tags.retain(|tag| {
+    ///     !MaterialChip::input(tag.as_str())
+    ///         .id_salt(tag.as_str())
+    ///         .removable(true)
+    ///         .show(ui)
+    ///         .removed
+    /// });
+    /// # });
+    /// ```
+    pub fn show(self, ui: &mut Ui) -> ChipResponse {
+        let is_selected = self.selected.as_ref().is_some_and(|s| **s);
+        let reduced_motion = reduced_motion(ui.ctx());
 
-        let has_leading = self.leading_icon.is_some()
-            || (self.variant == ChipVariant::Filter && is_selected);
+        let chip_id = match &self.id_salt {
+            Some(salt) => Id::new((salt, "chip")),
+            None => ui.next_auto_id().with("chip"),
+        };
+
+        // Selection progress drives the checkmark and the container color
+        let selection = if reduced_motion {
+            if is_selected {
+                1.0
+            } else {
+                0.0
+            }
+        } else {
+            ui.ctx().animate_bool_with_time(
+                chip_id.with("selected"),
+                is_selected,
+                SELECTION_ANIMATION_S,
+            )
+        };
+
+        // Removal progress, from the time the remove icon was clicked
+        let removing_id = chip_id.with("removing");
+        let now = ui.input(|i| i.time);
+        let removal = ui
+            .ctx()
+            .data(|d| d.get_temp::<f64>(removing_id))
+            .map_or(0.0, |start| {
+                ((now - start) / REMOVE_ANIMATION_S).clamp(0.0, 1.0) as f32
+            });
+
+        let draws_checkmark = self.leading_icon.is_none() && self.variant == ChipVariant::Filter;
         let height = if self.is_small { 24.0 } else { 32.0 };
-        let icon_size = if self.is_small { 18.0 } else { 24.0 };
-        let icon_width = if has_leading { icon_size } else { 0.0 };
-        let remove_width = if self.removable { icon_size } else { 0.0 };
-        let padding = if self.is_small { 12.0 } else { 16.0 };
+        let icon_size = self.icon_size();
+        let icon_width = self.leading_width(selection);
 
-        let desired_size = Vec2::new(
-            (text_width + icon_width + remove_width + padding).min(ui.available_width()),
-            height,
-        );
+        let full_width = self.width(ui, selection).min(ui.available_width());
+        let desired_size = Vec2::new(full_width * (1.0 - removal), height);
+
+        let sense = if self.enabled {
+            Sense::click()
+        } else {
+            Sense::hover()
+        };
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, sense);
+        let rtl = is_rtl(ui);
 
-        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+        // The remove icon gets its own hit area on top of the chip
+        let remove_response = self.removable.then(|| {
+            let remove_rect = Rect::from_min_size(
+                Pos2::new(rect.max.x - icon_size, rect.center().y - icon_size / 2.0),
+                Vec2::splat(icon_size),
+            );
+            let remove_rect = mirror_rect(rtl, rect, remove_rect);
+            ui.interact(remove_rect, chip_id.with("remove"), sense)
+        });
 
-        let is_pressed = response.is_pointer_button_down_on();
-        let is_hovered = response.hovered();
+        let resolve = |selected: bool| {
+            resolve_chip_colors(
+                self.variant,
+                selected,
+                self.enabled,
+                self.soft_disabled,
+                self.elevated,
+            )
+        };
+        let colors = if self.variant == ChipVariant::Filter {
+            lerp_chip_colors(resolve(false), resolve(true), selection)
+        } else {
+            resolve(is_selected)
+        };
 
-        let colors = resolve_chip_colors(
-            self.variant,
-            is_selected,
-            self.enabled,
-            self.soft_disabled,
-            self.elevated,
-            is_hovered,
-            is_pressed,
-        );
+        // While being removed the chip is clipped to its shrinking rect and fades out
+        let mut painter = ui.painter().clone();
+        if removal > 0.0 {
+            painter.set_clip_rect(rect.expand(2.0).intersect(ui.clip_rect()));
+            painter.multiply_opacity(1.0 - removal);
+        }
 
-        let corner_radius = 8.0;
+        let corner_radius = shape_radius(self.shape);
 
         // Draw elevation shadow (before background)
         if self.elevated && self.enabled {
             let shadow_rect = rect.translate(Vec2::new(0.0, 2.0));
-            ui.painter().rect_filled(
+            painter.rect_filled(
                 shadow_rect,
                 corner_radius,
                 Color32::from_rgba_unmultiplied(0, 0, 0, 30),
@@ -477,17 +627,18 @@ impl<'a> Widget for MaterialChip<'a> {
         }
 
         // Draw chip background
-        ui.painter().rect_filled(rect, corner_radius, colors.bg);
+        painter.rect_filled(rect, corner_radius, colors.bg);
 
-        // Draw state layer (hover/pressed overlay)
-        if colors.state_layer != Color32::TRANSPARENT {
-            ui.painter()
-                .rect_filled(rect, corner_radius, colors.state_layer);
+        // M3 state layers and press ripple (skipped while the chip is being removed)
+        if self.enabled && removal == 0.0 {
+            Ripple::new(colors.state_layer)
+                .corner_radius(corner_radius)
+                .show(ui, rect, &response);
         }
 
         // Draw chip border
         if colors.border != Color32::TRANSPARENT {
-            ui.painter().rect_stroke(
+            painter.rect_stroke(
                 rect,
                 corner_radius,
                 Stroke::new(1.0, colors.border),
@@ -495,7 +646,7 @@ impl<'a> Widget for MaterialChip<'a> {
             );
         }
 
-        // Layout content
+        // Layout content from the start edge, mirrored in RTL
         let mut content_x = rect.min.x + 8.0;
 
         // Draw leading icon or checkmark
@@ -505,11 +656,12 @@ impl<'a> Widget for MaterialChip<'a> {
                 Pos2::new(content_x, rect.center().y - icon_display_size / 2.0),
                 Vec2::splat(icon_display_size),
             );
+            let icon_rect = mirror_rect(rtl, rect, icon_rect);
 
             match icon {
                 IconType::MaterialIcon(icon_str) => {
                     let font_size = if self.is_small { 14.0 } else { 16.0 };
-                    ui.painter().text(
+                    painter.text(
                         icon_rect.center(),
                         egui::Align2::CENTER_CENTER,
                         icon_str,
@@ -523,7 +675,7 @@ impl<'a> Widget for MaterialChip<'a> {
                         svg_data,
                         &format!("chip_svg_{}", svg_data.len()),
                     ) {
-                        ui.painter().image(
+                        painter.image(
                             texture.id(),
                             icon_rect,
                             Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)),
@@ -537,7 +689,7 @@ impl<'a> Widget for MaterialChip<'a> {
                         png_bytes,
                         &format!("chip_png_{}", png_bytes.len()),
                     ) {
-                        ui.painter().image(
+                        painter.image(
                             texture.id(),
                             icon_rect,
                             Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)),
@@ -546,7 +698,7 @@ impl<'a> Widget for MaterialChip<'a> {
                     }
                 }
                 IconType::Texture(texture) => {
-                    ui.painter().image(
+                    painter.image(
                         texture.id(),
                         icon_rect,
                         Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)),
@@ -555,13 +707,17 @@ impl<'a> Widget for MaterialChip<'a> {
                 }
             }
             content_x += icon_size;
-        } else if self.variant == ChipVariant::Filter && is_selected {
-            // Draw checkmark for selected filter chips
+        } else if draws_checkmark && selection > 0.0 {
+            // Checkmark slides in with the leading slot and is drawn stroke by stroke
             let icon_display_size = icon_size * 0.833; // 20/24 ratio for visual balance
             let icon_rect = Rect::from_min_size(
-                Pos2::new(content_x, rect.center().y - icon_display_size / 2.0),
+                Pos2::new(
+                    content_x - icon_size * (1.0 - selection),
+                    rect.center().y - icon_display_size / 2.0,
+                ),
                 Vec2::splat(icon_display_size),
             );
+            let icon_rect = mirror_rect(rtl, rect, icon_rect);
 
             let center = icon_rect.center();
             let checkmark_size = if self.is_small { 10.0 } else { 12.0 };
@@ -576,43 +732,46 @@ impl<'a> Widget for MaterialChip<'a> {
                 center.y - checkmark_size * 0.2,
             );
 
-            let stroke_width = if self.is_small { 1.5 } else { 2.0 };
-            ui.painter()
-                .line_segment([start, middle], Stroke::new(stroke_width, colors.icon));
-            ui.painter()
-                .line_segment([middle, end], Stroke::new(stroke_width, colors.icon));
-            content_x += icon_size;
+            let stroke = Stroke::new(if self.is_small { 1.5 } else { 2.0 }, colors.icon);
+            let first = (selection / 0.4).min(1.0);
+            let second = ((selection - 0.4) / 0.6).clamp(0.0, 1.0);
+            painter.line_segment([start, start.lerp(middle, first)], stroke);
+            if second > 0.0 {
+                painter.line_segment([middle, middle.lerp(end, second)], stroke);
+            }
+            content_x += icon_width;
         }
 
         // Draw text (offset by 1px to visually center, compensating for font descender space)
-        let text_pos = Pos2::new(content_x, rect.center().y + 2.0);
-        ui.painter().text(
+        let text_pos = Pos2::new(mirror_x(rtl, rect, content_x), rect.center().y + 2.0);
+        painter.text(
             text_pos,
-            egui::Align2::LEFT_CENTER,
+            mirror_align(rtl, egui::Align2::LEFT_CENTER),
             &self.text,
             egui::FontId::default(),
             colors.text,
         );
 
         // Draw remove button for removable chips
-        if self.removable {
-            let icon_display_size = icon_size * 0.833; // 20/24 ratio for visual balance
-            let remove_rect = Rect::from_min_size(
-                Pos2::new(rect.max.x - icon_size, rect.center().y - icon_display_size / 2.0),
-                Vec2::splat(icon_display_size),
-            );
-
-            let center = remove_rect.center();
+        if let Some(remove_response) = &remove_response {
+            let center = remove_response.rect.center();
+            if remove_response.hovered() && self.enabled {
+                painter.circle_filled(
+                    center,
+                    icon_size / 2.0 - 2.0,
+                    colors.delete_icon.linear_multiply(0.08),
+                );
+            }
             let cross_size = if self.is_small { 6.0 } else { 8.0 };
             let stroke_width = if self.is_small { 1.2 } else { 1.5 };
-            ui.painter().line_segment(
+            painter.line_segment(
                 [
                     Pos2::new(center.x - cross_size / 2.0, center.y - cross_size / 2.0),
                     Pos2::new(center.x + cross_size / 2.0, center.y + cross_size / 2.0),
                 ],
                 Stroke::new(stroke_width, colors.delete_icon),
             );
-            ui.painter().line_segment(
+            painter.line_segment(
                 [
                     Pos2::new(center.x + cross_size / 2.0, center.y - cross_size / 2.0),
                     Pos2::new(center.x - cross_size / 2.0, center.y + cross_size / 2.0),
@@ -621,12 +780,32 @@ impl<'a> Widget for MaterialChip<'a> {
             );
         }
 
+        // Handle removal
+        let mut removed = false;
+        let remove_clicked = remove_response.as_ref().is_some_and(|r| r.clicked());
+        if remove_clicked && self.enabled && removal == 0.0 {
+            if reduced_motion {
+                removed = true;
+            } else {
+                ui.ctx().data_mut(|d| d.insert_temp(removing_id, now));
+                ui.ctx().request_repaint();
+            }
+        } else if removal >= 1.0 {
+            ui.ctx().data_mut(|d| d.remove::<f64>(removing_id));
+            removed = true;
+        } else if removal > 0.0 {
+            ui.ctx().request_repaint();
+        }
+
         // Handle interactions
-        if response.clicked() && self.enabled {
+        let clicked = response.clicked() && self.enabled && removal == 0.0;
+        let mut toggled = false;
+        if clicked {
             match self.variant {
                 ChipVariant::Filter => {
                     if let Some(selected) = self.selected {
                         *selected = !*selected;
+                        toggled = true;
                         response.mark_changed();
                     }
                 }
@@ -638,7 +817,284 @@ impl<'a> Widget for MaterialChip<'a> {
             }
         }
 
-        response
+        if let Some(remove_response) = remove_response {
+            response = response.union(remove_response);
+        }
+
+        ChipResponse {
+            response,
+            clicked,
+            toggled,
+            removed,
+        }
+    }
+}
+
+impl<'a> Widget for MaterialChip<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+/// Horizontal and vertical gap between chips in a [`MaterialChipSet`]
+const CHIP_SET_SPACING: f32 = 8.0;
+/// How much wider the available space must grow before a chip set re-wraps its rows.
+/// Shrinking always re-wraps so chips never overflow.
+const CHIP_SET_REWRAP_SLACK: f32 = 16.0;
+
+/// Split chips into rows, returning how many chips go on each row
+fn pack_rows(widths: &[f32], max_width: f32, spacing: f32) -> Vec<usize> {
+    let mut rows = Vec::new();
+    let mut row_len = 0;
+    let mut x = 0.0;
+    for &width in widths {
+        if row_len > 0 && x + spacing + width > max_width {
+            rows.push(row_len);
+            row_len = 0;
+            x = 0.0;
+        }
+        if row_len > 0 {
+            x += spacing;
+        }
+        x += width;
+        row_len += 1;
+    }
+    if row_len > 0 {
+        rows.push(row_len);
+    }
+    rows
+}
+
+/// Number of leading chips that fit in `max_rows` rows together with a trailing "+N" chip
+fn visible_chip_count(
+    widths: &[f32],
+    max_width: f32,
+    spacing: f32,
+    max_rows: usize,
+    overflow_width: impl Fn(usize) -> f32,
+) -> usize {
+    if pack_rows(widths, max_width, spacing).len() <= max_rows {
+        return widths.len();
+    }
+    let mut row_widths = Vec::with_capacity(widths.len());
+    (0..widths.len())
+        .rev()
+        .find(|&visible| {
+            row_widths.clear();
+            row_widths.extend_from_slice(&widths[..visible]);
+            row_widths.push(overflow_width(widths.len() - visible));
+            pack_rows(&row_widths, max_width, spacing).len() <= max_rows
+        })
+        .unwrap_or(0)
+}
+
+/// Response from showing a [`MaterialChipSet`]
+pub struct ChipSetResponse {
+    /// The response of the whole chip set
+    pub response: Response,
+    /// Index of the chip whose body was clicked this frame
+    pub clicked: Option<usize>,
+    /// Index of the filter chip whose selection was toggled this frame
+    pub toggled: Option<usize>,
+    /// Index of the chip whose removal animation finished this frame
+    pub removed: Option<usize>,
+    /// Whether the set currently shows all chips
+    pub expanded: bool,
+}
+
+/// A wrapping container for chips.
+///
+/// Chips are laid out left to right with 8dp spacing and wrap onto new rows.
+/// With [`max_rows`](Self::max_rows), chips that don't fit are replaced by a
+/// trailing "+N" chip which shows them all when clicked.
+///
+/// # Example
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// let tags = ["rust", "egui", "material", "design"];
+/// let response = MaterialChipSet::new(
+///     tags.iter().map(|tag| MaterialChip::input(*tag).removable(true)).collect(),
+/// )
+/// .max_rows(1)
+/// .show(ui);
+/// if let Some(index) = response.removed {
+///     println!("Removed {}", tags[index]);
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct MaterialChipSet<'a> {
+    chips: Vec<MaterialChip<'a>>,
+    max_rows: Option<usize>,
+    id_salt: Option<String>,
+}
+
+impl<'a> MaterialChipSet<'a> {
+    /// Create a chip set from a list of chips.
+    pub fn new(chips: Vec<MaterialChip<'a>>) -> Self {
+        Self {
+            chips,
+            max_rows: None,
+            id_salt: None,
+        }
+    }
+
+    /// Add a chip to the end of the set.
+    pub fn chip(mut self, chip: MaterialChip<'a>) -> Self {
+        self.chips.push(chip);
+        self
+    }
+
+    /// Limit the number of visible rows, collapsing the rest into a "+N" chip.
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows.max(1));
+        self
+    }
+
+    /// Set an ID salt, needed when several chip sets share a ui.
+    pub fn id_salt(mut self, salt: impl Into<String>) -> Self {
+        self.id_salt = Some(salt.into());
+        self
+    }
+
+    /// Show the chip set, reporting which chip was clicked, toggled or removed.
+    pub fn show(self, ui: &mut Ui) -> ChipSetResponse {
+        let set_id = match &self.id_salt {
+            Some(salt) => Id::new((salt, "chip_set")),
+            None => ui.next_auto_id().with("chip_set"),
+        };
+        let expanded_id = set_id.with("expanded");
+        let layout_width_id = set_id.with("layout_width");
+        let mut expanded = ui
+            .ctx()
+            .data(|d| d.get_temp::<bool>(expanded_id))
+            .unwrap_or(false);
+
+        // Keep wrapping at the previous width until the space grows noticeably,
+        // so the rows don't flicker when the width hovers around a wrap point
+        let available = ui.available_width();
+        let layout_width = match ui.ctx().data(|d| d.get_temp::<f32>(layout_width_id)) {
+            Some(previous)
+                if available >= previous && available < previous + CHIP_SET_REWRAP_SLACK =>
+            {
+                previous
+            }
+            _ => available,
+        };
+        ui.ctx()
+            .data_mut(|d| d.insert_temp(layout_width_id, layout_width));
+
+        let widths: Vec<f32> = self
+            .chips
+            .iter()
+            .map(|chip| chip.resting_width(ui))
+            .collect();
+        let overflows = self.max_rows.is_some_and(|max_rows| {
+            pack_rows(&widths, layout_width, CHIP_SET_SPACING).len() > max_rows
+        });
+        if !overflows {
+            expanded = false;
+        }
+
+        let overflow_chip = |hidden: usize| {
+            MaterialChip::assist(format!("+{}", hidden)).id_salt(format!("{:?}/more", set_id))
+        };
+        let visible = match self.max_rows {
+            Some(max_rows) if overflows && !expanded => visible_chip_count(
+                &widths,
+                layout_width,
+                CHIP_SET_SPACING,
+                max_rows,
+                |hidden| overflow_chip(hidden).resting_width(ui),
+            ),
+            _ => self.chips.len(),
+        };
+        let hidden = self.chips.len() - visible;
+
+        // Trailing chip expands the set, or collapses it again once expanded
+        let trailing = if hidden > 0 {
+            Some(overflow_chip(hidden))
+        } else if expanded {
+            Some(MaterialChip::assist("Show less").id_salt(format!("{:?}/less", set_id)))
+        } else {
+            None
+        };
+
+        let mut row_widths = widths[..visible].to_vec();
+        if let Some(chip) = &trailing {
+            row_widths.push(chip.resting_width(ui));
+        }
+        let rows = pack_rows(&row_widths, layout_width, CHIP_SET_SPACING);
+
+        let mut chips = self
+            .chips
+            .into_iter()
+            .take(visible)
+            .chain(trailing)
+            .enumerate();
+
+        let mut clicked = None;
+        let mut toggled = None;
+        let mut removed = None;
+        let mut trailing_clicked = false;
+
+        // In RTL the rows fill from the right edge
+        let (column, row) = if is_rtl(ui) {
+            (
+                egui::Layout::top_down(egui::Align::Max),
+                egui::Layout::right_to_left(egui::Align::Center),
+            )
+        } else {
+            (
+                egui::Layout::top_down(egui::Align::Min),
+                egui::Layout::left_to_right(egui::Align::Center),
+            )
+        };
+        let response = ui
+            .with_layout(column, |ui| {
+                ui.spacing_mut().item_spacing = Vec2::splat(CHIP_SET_SPACING);
+                for row_len in rows {
+                    let row_size = Vec2::new(ui.available_width(), ui.spacing().interact_size.y);
+                    ui.allocate_ui_with_layout(row_size, row, |ui| {
+                        for (index, chip) in chips.by_ref().take(row_len) {
+                            let chip_response = chip.show(ui);
+                            if index == visible {
+                                trailing_clicked = chip_response.clicked;
+                                continue;
+                            }
+                            if chip_response.clicked {
+                                clicked = Some(index);
+                            }
+                            if chip_response.toggled {
+                                toggled = Some(index);
+                            }
+                            if chip_response.removed {
+                                removed = Some(index);
+                            }
+                        }
+                    });
+                }
+            })
+            .response;
+
+        if trailing_clicked {
+            expanded = !expanded;
+        }
+        ui.ctx().data_mut(|d| d.insert_temp(expanded_id, expanded));
+
+        ChipSetResponse {
+            response,
+            clicked,
+            toggled,
+            removed,
+            expanded: expanded || hidden == 0,
+        }
+    }
+}
+
+impl<'a> Widget for MaterialChipSet<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
     }
 }
 
@@ -657,3 +1113,65 @@ pub fn input_chip(text: impl Into<String>) -> MaterialChip<'static> {
 pub fn suggestion_chip(text: impl Into<String>) -> MaterialChip<'static> {
     MaterialChip::suggestion(text)
 }
+
+pub fn chip_set(chips: Vec<MaterialChip<'_>>) -> MaterialChipSet<'_> {
+    MaterialChipSet::new(chips)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::set_context_rtl;
+
+    #[test]
+    fn test_pack_rows_wraps_at_width() {
+        assert_eq!(pack_rows(&[40.0, 40.0, 40.0], 100.0, 8.0), vec![2, 1]);
+        assert_eq!(pack_rows(&[40.0, 40.0, 40.0], 136.0, 8.0), vec![3]);
+        // A chip wider than the row still gets a row of its own
+        assert_eq!(pack_rows(&[150.0, 40.0], 100.0, 8.0), vec![1, 1]);
+        assert!(pack_rows(&[], 100.0, 8.0).is_empty());
+    }
+
+    #[test]
+    fn test_visible_chip_count_leaves_room_for_overflow_chip() {
+        let widths = [40.0; 6];
+        // Everything fits in two rows of two
+        assert_eq!(visible_chip_count(&widths, 100.0, 8.0, 3, |_| 30.0), 6);
+        // One row: a single chip plus the "+5" chip
+        assert_eq!(visible_chip_count(&widths, 100.0, 8.0, 1, |_| 30.0), 1);
+        // Two rows: three chips plus the "+3" chip
+        assert_eq!(visible_chip_count(&widths, 100.0, 8.0, 2, |_| 30.0), 3);
+    }
+
+    /// Horizontal centers of a chip's leading icon and label
+    fn paint_chip(rtl: bool) -> (f32, f32) {
+        let ctx = egui::Context::default();
+        set_context_rtl(&ctx, Some(rtl));
+        let output = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.add(assist_chip("Assist").leading_icon("event"));
+            });
+        });
+        let center_of = |text: &str| {
+            output
+                .shapes
+                .iter()
+                .find_map(|clipped| match &clipped.shape {
+                    egui::Shape::Text(shape) if shape.galley.text() == text => {
+                        Some(shape.visual_bounding_rect().center().x)
+                    }
+                    _ => None,
+                })
+                .unwrap()
+        };
+        (center_of("event"), center_of("Assist"))
+    }
+
+    #[test]
+    fn test_rtl_moves_leading_icon_to_the_right_of_the_label() {
+        let (icon, label) = paint_chip(false);
+        assert!(icon < label);
+        let (icon, label) = paint_chip(true);
+        assert!(icon > label);
+    }
+}
@@ -0,0 +1,523 @@
+//! Material Design 3 Command Palette
+//!
+//! A search-anything overlay: a search field over a list of registered
+//! commands, narrowed as you type. It is the ⌘K / Ctrl+K palette of desktop
+//! apps, built from a modal surface, a [`MaterialTextField`] and list rows.
+//!
+//! # M3 Color Role Usage
+//!
+//! - **surfaceContainerHigh**: Palette surface
+//! - **scrim @ 32%**: Overlay behind the palette
+//! - **onSurface**: Command labels
+//! - **onSurfaceVariant**: Command icons, shortcuts and the empty-results text
+//! - **primary**: Characters of a label that match the search text
+//! - **secondaryContainer**: Highlighted command
+//! - **State layers**: onSurface @ 8% (hover)
+//!
+//! ## Dimensions
+//! - **Width**: 560dp by default, at most the viewport width minus 32dp
+//! - **Command rows**: 48dp, 24dp icons
+//! - **Corner radius**: extra large (28dp)
+//!
+//! ## Matching
+//! A command matches when the search text appears in its label in order,
+//! ignoring case, with other characters allowed in between (`"nwin"`
+//! matches "New Window"). Matches at the start of words and runs of
+//! adjacent characters rank higher, and the matched characters are drawn
+//! in the primary color.
+//!
+//! ## Keyboard
+//! Up/Down move the highlight, Enter chooses the highlighted command and
+//! Escape closes the palette, and Tab stays within it while it is open. With
+//! [`MaterialCommandPalette::open_shortcut`] the palette also opens and closes
+//! from the keyboard.
+
+use crate::material_symbol::material_symbol_text_or_default;
+use crate::theme::{
+    dismiss_on_escape, elevation_shadow, get_global_color, shape_radius, trap_focus, type_font_id,
+    MaterialScrollAreaExt, Shape, Shortcut, TypeRole,
+};
+use crate::MaterialTextField;
+use egui::{
+    self, pos2, text::LayoutJob, Area, Color32, Context, Frame, Id, Key, Modal, Modifiers, Order,
+    Sense, TextFormat, Ui, Vec2, WidgetInfo, WidgetType,
+};
+use std::ops::Range;
+
+const ROW_HEIGHT: f32 = 48.0;
+const ICON_SIZE: f32 = 24.0;
+const VIEWPORT_MARGIN: f32 = 16.0;
+
+/// A command that can be chosen from a [`MaterialCommandPalette`].
+#[derive(Clone, Debug)]
+pub struct Command {
+    /// Identifier returned when the command is chosen
+    pub id: String,
+    /// Text shown in the list and searched
+    pub label: String,
+    /// Material Symbols name (e.g. `"save"`) or any other string drawn as-is
+    pub icon: Option<String>,
+    /// Keyboard shortcut shown at the end of the row
+    pub shortcut: Option<Shortcut>,
+}
+
+impl Command {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            shortcut: None,
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Show a shortcut at the end of the row.
+    ///
+    /// The shortcut is only displayed; the palette doesn't run it.
+    pub fn shortcut(mut self, shortcut: Shortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+}
+
+/// Search text and keyboard highlight of an open palette
+#[derive(Clone, Default)]
+struct PaletteState {
+    query: String,
+    /// Position in the filtered and ranked command list
+    highlighted: usize,
+    /// Whether the palette was shown open last frame
+    was_open: bool,
+}
+
+/// Material Design command palette.
+///
+/// Opens over a scrim with a search field and the commands matching it, and
+/// returns the id of the command chosen with Enter or a click. The palette
+/// closes itself after a choice, on Escape and on a click on the scrim.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_material3::{theme::Shortcut, Command, MaterialCommandPalette};
+///
+/// let mut palette_open = false;
+/// let chosen = MaterialCommandPalette::new(&mut palette_open)
+///     .open_shortcut(Shortcut::command(egui::Key::K))
+///     .command(Command::new("new", "New window").icon("add"))
+///     .command(
+///         Command::new("save", "Save")
+///             .icon("save")
+///             .shortcut(Shortcut::command(egui::Key::S)),
+///     )
+///     .show(ui.ctx());
+/// if chosen.as_deref() == Some("save") {
+///     // save the document
+/// }
+/// # });
+/// ```
+pub struct MaterialCommandPalette<'a> {
+    open: &'a mut bool,
+    commands: Vec<Command>,
+    placeholder: String,
+    open_shortcut: Option<Shortcut>,
+    width: f32,
+    max_visible_rows: usize,
+    id: Id,
+}
+
+impl<'a> MaterialCommandPalette<'a> {
+    /// Create a palette shown while `open` is `true`.
+    pub fn new(open: &'a mut bool) -> Self {
+        Self {
+            open,
+            commands: Vec::new(),
+            placeholder: "Type a command".to_string(),
+            open_shortcut: None,
+            width: 560.0,
+            max_visible_rows: 8,
+            id: Id::new("material_command_palette"),
+        }
+    }
+
+    /// Add a command.
+    pub fn command(mut self, command: Command) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Add several commands at once.
+    pub fn commands(mut self, commands: impl IntoIterator<Item = Command>) -> Self {
+        self.commands.extend(commands);
+        self
+    }
+
+    /// Set the text shown in the empty search field.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Toggle the palette when `shortcut` is pressed, e.g. `Shortcut::command(Key::K)`.
+    ///
+    /// The shortcut is checked every frame [`Self::show`] is called.
+    pub fn open_shortcut(mut self, shortcut: Shortcut) -> Self {
+        self.open_shortcut = Some(shortcut);
+        self
+    }
+
+    /// Set the palette width.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set how many command rows are visible before the list scrolls.
+    pub fn max_visible_rows(mut self, rows: usize) -> Self {
+        self.max_visible_rows = rows.max(1);
+        self
+    }
+
+    /// Use a custom id, needed to show more than one palette.
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id = Id::new("material_command_palette").with(id_salt);
+        self
+    }
+
+    /// Show the palette and return the id of the command chosen this frame.
+    pub fn show(self, ctx: &Context) -> Option<String> {
+        if let Some(shortcut) = self.open_shortcut {
+            if ctx.input_mut(|i| shortcut.consume(i)) {
+                *self.open = !*self.open;
+            }
+        }
+
+        let state_id = self.id.with("state");
+        let mut state: PaletteState = ctx.data(|d| d.get_temp(state_id)).unwrap_or_default();
+        if !*self.open {
+            ctx.data_mut(|d| d.remove::<PaletteState>(state_id));
+            return None;
+        }
+
+        // Every opening starts from an empty search
+        let just_opened = !state.was_open;
+        if just_opened {
+            state = PaletteState {
+                was_open: true,
+                ..Default::default()
+            };
+        }
+
+        let ranked = rank_commands(&self.commands, &state.query);
+        state.highlighted = state.highlighted.min(ranked.len().saturating_sub(1));
+
+        // Keys are taken before the search field sees them, so they don't
+        // move its cursor or take its focus away
+        let (down, up, enter) = ctx.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::Enter),
+            )
+        });
+        let escape = dismiss_on_escape(ctx);
+        let highlight_moved = down || up;
+        if down && !ranked.is_empty() {
+            state.highlighted = (state.highlighted + 1).min(ranked.len() - 1);
+        } else if up {
+            state.highlighted = state.highlighted.saturating_sub(1);
+        }
+        let mut chosen = if enter {
+            ranked.get(state.highlighted).map(|(index, _)| *index)
+        } else {
+            None
+        };
+
+        let screen_rect = ctx.content_rect();
+        let width = self.width.min(screen_rect.width() - VIEWPORT_MARGIN * 2.0);
+        let area = Area::new(self.id.with("area"))
+            .order(Order::Foreground)
+            .fixed_pos(pos2(
+                screen_rect.center().x - width / 2.0,
+                screen_rect.min.y + (screen_rect.height() * 0.15).max(VIEWPORT_MARGIN),
+            ));
+        let frame = Frame::new()
+            .fill(get_global_color("surfaceContainerHigh"))
+            .corner_radius(shape_radius(Shape::ExtraLarge))
+            .shadow(elevation_shadow(3))
+            .inner_margin(8.0);
+
+        let search_id = self.id.with("search");
+        let list_height = ROW_HEIGHT * self.max_visible_rows as f32;
+        let modal = Modal::new(self.id.with("modal"))
+            .area(area)
+            .frame(frame)
+            .backdrop_color(get_global_color("scrim").gamma_multiply(0.32))
+            .show(ctx, |ui| {
+                trap_focus(ui, self.id.with("focus_trap"), |ui| {
+                    ui.set_width(width - 16.0);
+                    let field = MaterialTextField::new(&mut state.query)
+                        .id(search_id)
+                        .leading_icon("search")
+                        .placeholder(self.placeholder.clone())
+                        .width(width - 16.0)
+                        .show(ui);
+                    if just_opened {
+                        field.response.request_focus();
+                    }
+                    if field.response.changed() {
+                        // The best match is highlighted so Enter picks it straight away
+                        state.highlighted = 0;
+                    }
+                    ui.add_space(8.0);
+
+                    if ranked.is_empty() {
+                        ui.add_sized(
+                            Vec2::new(width - 16.0, ROW_HEIGHT),
+                            egui::Label::new(
+                                egui::RichText::new("No matching commands")
+                                    .font(type_font_id(TypeRole::BodyMedium))
+                                    .color(get_global_color("onSurfaceVariant")),
+                            ),
+                        );
+                        return;
+                    }
+
+                    egui::ScrollArea::vertical()
+                        .id_salt(self.id.with("results"))
+                        .max_height(list_height)
+                        .auto_shrink([false, true])
+                        .show_material(ui, |ui| {
+                            for (position, (index, ranges)) in ranked.iter().enumerate() {
+                                let highlighted = position == state.highlighted;
+                                let response =
+                                    command_row(ui, &self.commands[*index], ranges, highlighted);
+                                if highlighted && highlight_moved {
+                                    response.scroll_to_me(None);
+                                }
+                                if response.clicked() {
+                                    chosen = Some(*index);
+                                }
+                            }
+                        });
+                })
+            });
+
+        if escape || modal.backdrop_response.clicked() || chosen.is_some() {
+            *self.open = false;
+            ctx.data_mut(|d| d.remove::<PaletteState>(state_id));
+        } else {
+            ctx.data_mut(|d| d.insert_temp(state_id, state));
+        }
+        chosen.map(|index| self.commands[index].id.clone())
+    }
+}
+
+/// Draw one command row and return its click response
+fn command_row(
+    ui: &mut Ui,
+    command: &Command,
+    ranges: &[Range<usize>],
+    highlighted: bool,
+) -> egui::Response {
+    let (rect, response) =
+        ui.allocate_exact_size(Vec2::new(ui.available_width(), ROW_HEIGHT), Sense::click());
+    let on_surface = get_global_color("onSurface");
+    let on_surface_variant = get_global_color("onSurfaceVariant");
+    let radius = shape_radius(Shape::Large);
+
+    if highlighted {
+        ui.painter()
+            .rect_filled(rect, radius, get_global_color("secondaryContainer"));
+    }
+    if response.hovered() {
+        ui.painter()
+            .rect_filled(rect, radius, on_surface.linear_multiply(0.08));
+    }
+
+    let mut text_x = rect.min.x + 16.0;
+    if let Some(icon) = &command.icon {
+        ui.painter().text(
+            pos2(text_x + ICON_SIZE / 2.0, rect.center().y),
+            egui::Align2::CENTER_CENTER,
+            material_symbol_text_or_default(icon, icon),
+            egui::FontId::proportional(ICON_SIZE),
+            on_surface_variant,
+        );
+        text_x += ICON_SIZE + 16.0;
+    }
+
+    let mut text_end = rect.max.x - 16.0;
+    if let Some(shortcut) = command.shortcut {
+        let is_mac = ui.ctx().os() == egui::os::OperatingSystem::Mac;
+        let galley = ui.painter().layout_no_wrap(
+            shortcut.format_for(is_mac),
+            type_font_id(TypeRole::LabelLarge),
+            on_surface_variant,
+        );
+        let pos = pos2(
+            text_end - galley.size().x,
+            rect.center().y - galley.size().y / 2.0,
+        );
+        text_end = pos.x - 16.0;
+        ui.painter().galley(pos, galley, on_surface_variant);
+    }
+
+    let mut job = highlighted_label(
+        &command.label,
+        ranges,
+        type_font_id(TypeRole::BodyLarge),
+        on_surface,
+        get_global_color("primary"),
+    );
+    job.wrap.max_width = (text_end - text_x).max(0.0);
+    job.wrap.max_rows = 1;
+    let galley = ui.painter().layout_job(job);
+    let label_pos = pos2(text_x, rect.center().y - galley.size().y / 2.0);
+    ui.painter().galley(label_pos, galley, on_surface);
+
+    response.widget_info(|| {
+        WidgetInfo::selected(WidgetType::Button, true, highlighted, &command.label)
+    });
+    response
+}
+
+/// Lay out `label` with the byte `ranges` in `match_color` and the rest in `color`
+fn highlighted_label(
+    label: &str,
+    ranges: &[Range<usize>],
+    font_id: egui::FontId,
+    color: Color32,
+    match_color: Color32,
+) -> LayoutJob {
+    let mut job = LayoutJob::default();
+    let mut append = |range: Range<usize>, color: Color32| {
+        if !range.is_empty() {
+            job.append(
+                &label[range],
+                0.0,
+                TextFormat::simple(font_id.clone(), color),
+            );
+        }
+    };
+    let mut end = 0;
+    for range in ranges {
+        append(end..range.start, color);
+        append(range.clone(), match_color);
+        end = range.end;
+    }
+    append(end..label.len(), color);
+    job
+}
+
+/// Commands matching `query` as `(index, matched byte ranges)`, best match first.
+///
+/// Commands that rank equally keep the order they were added in.
+fn rank_commands(commands: &[Command], query: &str) -> Vec<(usize, Vec<Range<usize>>)> {
+    let mut ranked: Vec<(i32, usize, Vec<Range<usize>>)> = commands
+        .iter()
+        .enumerate()
+        .filter_map(|(index, command)| {
+            fuzzy_match(&command.label, query).map(|(score, ranges)| (score, index, ranges))
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0));
+    ranked
+        .into_iter()
+        .map(|(_, index, ranges)| (index, ranges))
+        .collect()
+}
+
+/// Match `query` against `text` as an in-order subsequence, ignoring case.
+///
+/// Returns a score, higher for matches at word starts and for adjacent
+/// matched characters, and the matched byte ranges of `text`, merged where
+/// they touch. An empty query matches everything with no ranges.
+fn fuzzy_match(text: &str, query: &str) -> Option<(i32, Vec<Range<usize>>)> {
+    let mut query_chars = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .peekable();
+    let mut score = 0;
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut previous: Option<char> = None;
+    for (start, c) in text.char_indices() {
+        let Some(&wanted) = query_chars.peek() else {
+            break;
+        };
+        let at_word_start = !previous.is_some_and(char::is_alphanumeric);
+        previous = Some(c);
+        if !c.to_lowercase().eq(std::iter::once(wanted)) {
+            continue;
+        }
+        query_chars.next();
+        let end = start + c.len_utf8();
+        match ranges.last_mut() {
+            Some(last) if last.end == start => {
+                last.end = end;
+                score += 3;
+            }
+            _ => ranges.push(start..end),
+        }
+        score += if at_word_start { 5 } else { 1 };
+    }
+    if query_chars.peek().is_some() {
+        return None;
+    }
+    Some((score, ranges))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match_finds_ranges_in_order() {
+        let (_, ranges) = fuzzy_match("New Window", "nwin").unwrap();
+        assert_eq!(ranges, vec![0..1, 2..3, 5..7]);
+        assert!(fuzzy_match("New Window", "nwz").is_none());
+        assert_eq!(fuzzy_match("Save", ""), Some((0, Vec::new())));
+        assert_eq!(fuzzy_match("Open File", "OPEN").unwrap().1, vec![0..4]);
+    }
+
+    #[test]
+    fn test_word_starts_and_runs_rank_first() {
+        let commands = [
+            Command::new("reload", "Reload window"),
+            Command::new("rename", "Rename symbol"),
+            Command::new("format", "Format document"),
+        ];
+        let ids = |query: &str| -> Vec<&str> {
+            rank_commands(&commands, query)
+                .into_iter()
+                .map(|(index, _)| commands[index].id.as_str())
+                .collect()
+        };
+        assert_eq!(ids(""), vec!["reload", "rename", "format"]);
+        assert_eq!(ids("ren"), vec!["rename", "reload", "format"]);
+        assert_eq!(ids("d"), vec!["format", "reload"]);
+        assert_eq!(ids("fd"), vec!["format"]);
+    }
+
+    #[test]
+    fn test_highlighted_label_splits_sections() {
+        let job = highlighted_label(
+            "New Window",
+            &[0..1, 5..7],
+            egui::FontId::proportional(14.0),
+            Color32::WHITE,
+            Color32::RED,
+        );
+        let colors: Vec<Color32> = job.sections.iter().map(|s| s.format.color).collect();
+        assert_eq!(
+            colors,
+            vec![Color32::RED, Color32::WHITE, Color32::RED, Color32::WHITE]
+        );
+        assert_eq!(job.text, "New Window");
+    }
+}
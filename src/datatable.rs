@@ -18,9 +18,46 @@
 //! - **outlineVariant**: Cell borders, dividers
 //! - **surfaceContainerHighest**: Selected row background, header background
 //! - **State layers**: onSurface @ 8% (hover on rows)
+//!
+//! # Expandable Rows
+//!
+//! Rows built with [`DataTableRow::expandable`] get a leading chevron that
+//! expands a detail panel spanning all columns below the row. Expanded rows are
+//! tracked in [`DataTableState::expanded_rows`] by row id (see
+//! [`DataTableRow::id`]), so they stay expanded when the table is re-sorted.
+//! Use [`MaterialDataTable::accordion`] to keep at most one row expanded.
+//!
+//! # Selection
+//!
+//! With [`MaterialDataTable::allow_selection`], clicking a row selects only that
+//! row, ctrl/cmd-click toggles it, and shift-click also selects every row
+//! between it and the last clicked row in the current sort order. The
+//! checkboxes toggle single rows (shift-click on a checkbox selects a range
+//! too). Changes are reported in [`DataTableResponse::newly_selected`] and
+//! [`DataTableResponse::newly_deselected`]. Like expanded rows, selected rows
+//! are tracked by row id in [`DataTableState::selected_rows`], so a selection
+//! survives sorting, filtering and inserted rows.
+//!
+//! # Editing
+//!
+//! Rows listed in [`DataTableState::editing_rows`] show an editor in every cell
+//! that has text, chosen by the column's [`EditKind`] (see
+//! [`MaterialDataTable::column_edit_kind`]). When the row is saved, the cells
+//! whose value changed are reported in [`DataTableResponse::cell_edits`].
+//! Widget cells are only editable when given an underlying
+//! [`value`](DataTableCell::value).
+//!
+//! # Empty Tables
+//!
+//! A table without rows shows a [`MaterialEmptyState`] below the header,
+//! "No data" unless replaced with [`MaterialDataTable::empty_state`]. Its
+//! action button is reported in [`DataTableResponse::empty_action_clicked`].
+//! Nothing is shown while the progress indicator is visible.
 
 use crate::button::MaterialButton;
-use crate::theme::get_global_color;
+use crate::checkbox::MaterialCheckbox;
+use crate::emptystate::MaterialEmptyState;
+use crate::theme::{get_global_color, reduced_motion, shape_radius, MaterialScrollAreaExt, Shape};
 use egui::{
     ecolor::Color32,
     epaint::{CornerRadius, Stroke},
@@ -28,6 +65,15 @@ use egui::{
 };
 use std::collections::{HashMap, HashSet};
 
+/// Narrowest width a column can be resized to.
+const MIN_COLUMN_WIDTH: f32 = 48.0;
+/// Width of the drag handle at the right edge of each resizable header cell.
+const RESIZE_HANDLE_WIDTH: f32 = 8.0;
+/// Duration of the expand chevron rotation.
+const EXPAND_ANIMATION_S: f32 = 0.15;
+/// Height of the empty state shown below the header of a table without rows.
+const EMPTY_STATE_HEIGHT: f32 = 192.0;
+
 /// Theme/styling configuration for MaterialDataTable
 #[derive(Clone, Debug)]
 pub struct DataTableTheme {
@@ -97,22 +143,28 @@ impl Default for ColumnWidth {
 /// sorting, and editing state across frames.
 #[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub struct DataTableState {
-    /// Selection state for each row (true if selected)
-    pub selected_rows: Vec<bool>,
+    /// Ids of the selected rows.
+    /// Rows without an explicit `.id()` are keyed by their original index.
+    pub selected_rows: HashSet<String>,
     /// State of the header checkbox (for select-all functionality)
     pub header_checkbox: bool,
+    /// Key of the last clicked row, where a shift-click range starts.
+    /// Rows without an explicit `.id()` are keyed by their original index.
+    pub selection_anchor: Option<String>,
     /// Sort states for each column by column name
     pub column_sorts: HashMap<String, SortDirection>,
-    /// Index of the currently sorted column (if any)
-    pub sorted_column: Option<usize>,
-    /// Current sort direction for the sorted column
-    pub sort_direction: SortDirection,
+    /// Sort keys in priority order: (column index, direction).
+    /// Shift-clicking a header appends a key; a plain click replaces them all.
+    pub sort_keys: Vec<(usize, SortDirection)>,
     /// Set of row indices currently being edited
     pub editing_rows: std::collections::HashSet<usize>,
     /// Temporary edit data for rows being edited (row_index -> cell_values)
     pub edit_data: HashMap<usize, Vec<String>>,
-    /// Set of row indices with their drawer expanded
-    pub drawer_open_rows: HashSet<usize>,
+    /// Ids of the rows whose detail panel is expanded.
+    /// Rows without an explicit `.id()` are keyed by their original index.
+    pub expanded_rows: HashSet<String>,
+    /// Widths of user-resized columns by column title
+    pub column_widths: HashMap<String, f32>,
 
     // Performance optimizations - Option 1: Caching
     /// Cached row heights to avoid recalculating text layout every frame
@@ -134,6 +186,38 @@ pub struct DataTableState {
     pub last_refresh_time: f64,
 }
 
+impl DataTableState {
+    /// The state of the table shown with `.id(id)`, as of the last frame
+    pub fn load(ctx: &egui::Context, id: Id) -> Self {
+        ctx.data_mut(|d| d.get_persisted(id).unwrap_or_default())
+    }
+
+    /// Index of the primary sorted column (if any)
+    #[deprecated(note = "use the first entry of `sort_keys`")]
+    pub fn sorted_column(&self) -> Option<usize> {
+        self.sort_keys.first().map(|(column, _)| *column)
+    }
+
+    /// Sort direction of the primary sorted column
+    #[deprecated(note = "use the first entry of `sort_keys`")]
+    pub fn sort_direction(&self) -> SortDirection {
+        self.sort_keys
+            .first()
+            .map(|(_, direction)| direction.clone())
+            .unwrap_or_default()
+    }
+
+    /// Original indices of the rows with an expanded drawer. Rows with an
+    /// explicit `.id()` are only listed in `expanded_rows`.
+    #[deprecated(note = "use `expanded_rows`, which is keyed by row id")]
+    pub fn drawer_open_rows(&self) -> HashSet<usize> {
+        self.expanded_rows
+            .iter()
+            .filter_map(|key| key.parse().ok())
+            .collect()
+    }
+}
+
 /// Response returned by the data table widget.
 ///
 /// Contains both the standard egui Response and additional table-specific
@@ -142,16 +226,81 @@ pub struct DataTableState {
 pub struct DataTableResponse {
     /// The standard egui widget response
     pub response: Response,
-    /// Current selection state for each row
+    /// Current selection state for each row by original index. Empty for
+    /// tables built from a [`DataTableSource`]; use `selected_keys` there.
     pub selected_rows: Vec<bool>,
+    /// Ids of the selected rows, as in [`DataTableState::selected_rows`]
+    pub selected_keys: HashSet<String>,
     /// Current state of the header checkbox
     pub header_checkbox: bool,
+    /// Whether any row's selection changed this frame
+    pub selection_changed: bool,
+    /// Original indices of the rows selected this frame
+    pub newly_selected: Vec<usize>,
+    /// Original indices of the rows deselected this frame
+    pub newly_deselected: Vec<usize>,
     /// Index of column that was clicked for sorting (if any)
     pub column_clicked: Option<usize>,
-    /// Current sort state (column index, direction)
+    /// Current primary sort state (column index, direction)
     pub sort_state: (Option<usize>, SortDirection),
+    /// All sort keys in priority order
+    pub sort_keys: Vec<(usize, SortDirection)>,
     /// List of row actions performed (edit, delete, save)
     pub row_actions: Vec<RowAction>,
+    /// Cells changed in rows saved this frame
+    pub cell_edits: Vec<CellEdit>,
+    /// `(original row index, response)` for each row shown this frame, e.g.
+    /// to attach a [`material_context_menu`](crate::menu::material_context_menu)
+    pub row_responses: Vec<(usize, Response)>,
+    /// Whether the action of the empty state was clicked this frame
+    pub empty_action_clicked: bool,
+}
+
+/// A cell value committed when an edited row is saved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellEdit {
+    /// Original index of the edited row
+    pub row: usize,
+    /// Index of the edited column
+    pub column: usize,
+    /// New value, typed by the column's [`EditKind`]
+    pub value: CellValue,
+}
+
+/// Typed value of an edited cell.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellValue {
+    /// From [`EditKind::Text`] and [`EditKind::Dropdown`]
+    Text(String),
+    /// From [`EditKind::Number`]
+    Number(f64),
+    /// From [`EditKind::Checkbox`]
+    Bool(bool),
+}
+
+/// Editor used for a column's cells while their row is in edit mode.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum EditKind {
+    /// Single-line text field
+    #[default]
+    Text,
+    /// Numeric field that can be typed into or dragged
+    Number,
+    /// Choice from a fixed list of options
+    Dropdown(Vec<String>),
+    /// Checkbox, stored as `"true"` / `"false"`
+    Checkbox,
+}
+
+impl EditKind {
+    /// Convert the edit buffer of a cell to its typed value.
+    fn value(&self, text: &str) -> CellValue {
+        match self {
+            EditKind::Text | EditKind::Dropdown(_) => CellValue::Text(text.to_string()),
+            EditKind::Number => CellValue::Number(parse_number(text)),
+            EditKind::Checkbox => CellValue::Bool(parse_bool(text)),
+        }
+    }
 }
 
 /// Actions that can be performed on data table rows.
@@ -209,17 +358,30 @@ pub struct MaterialDataTable<'a> {
     allow_selection: bool,
     allow_drawer: bool,
     drawer_row_height: Option<f32>,
+    /// Collapse other rows when one is expanded
+    accordion: bool,
     sticky_header: bool,
     progress_visible: bool,
     corner_radius: CornerRadius,
-    sorted_column: Option<usize>,
-    sort_direction: SortDirection,
+    sort_keys: Vec<(usize, SortDirection)>,
     default_row_height: f32,
     theme: DataTableTheme,
     auto_height: bool,
     /// Minimum time between full refreshes in seconds (0.0 = no throttling)
     /// Set to 0.05-0.1 for smooth scrolling with large tables
     refresh_interval: f32,
+    /// Lazily supplies rows instead of `rows` when set
+    source: Option<&'a dyn DataTableSource>,
+    /// Only lay out rows that intersect the clip rect
+    virtualized: bool,
+    /// Only emit selected rows from `to_csv`/`to_tsv`
+    export_selected_only: bool,
+    /// Keys of the selected rows, from [`DataTableState::selected_rows`]
+    export_selection: HashSet<String>,
+    /// Allow resizing columns by dragging the header dividers
+    resizable_columns: bool,
+    /// Shown below the header when there are no rows
+    empty_state: Option<MaterialEmptyState>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -267,6 +429,8 @@ pub struct DataTableColumn {
     pub heading_alignment: Option<HAlign>,
     /// Column width specification
     pub column_width: ColumnWidth,
+    /// Editor used while a row is being edited
+    pub edit_kind: EditKind,
 }
 
 #[derive(Clone, Debug, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
@@ -289,6 +453,8 @@ pub struct DataTableCell {
     pub v_align: Option<VAlign>,
     pub placeholder: bool,
     pub show_edit_icon: bool,
+    /// Underlying text of a widget cell, used for sorting, export and editing
+    pub value: Option<String>,
 }
 
 impl DataTableCell {
@@ -299,19 +465,25 @@ impl DataTableCell {
             v_align: None,
             placeholder: false,
             show_edit_icon: false,
+            value: None,
         }
     }
 
-    pub fn widget<F>(f: F) -> Self
+    /// Cell rendering arbitrary content, such as a chip or a progress bar.
+    /// Whatever the closure returns (usually a [`Response`]) is discarded.
+    pub fn widget<F, R>(f: F) -> Self
     where
-        F: Fn(&mut Ui) + Send + Sync + 'static,
+        F: Fn(&mut Ui) -> R + Send + Sync + 'static,
     {
         Self {
-            content: CellContent::Widget(std::sync::Arc::new(f)),
+            content: CellContent::Widget(std::sync::Arc::new(move |ui: &mut Ui| {
+                let _ = f(ui);
+            })),
             h_align: None,
             v_align: None,
             placeholder: false,
             show_edit_icon: false,
+            value: None,
         }
     }
 
@@ -334,6 +506,14 @@ impl DataTableCell {
         self.show_edit_icon = show;
         self
     }
+
+    /// Set the underlying text of a widget cell.
+    ///
+    /// Widget cells sort, export and edit as empty text unless given a value.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
 }
 
 pub struct DataTableRow<'a> {
@@ -347,7 +527,7 @@ pub struct DataTableRow<'a> {
     id: Option<String>,
     color: Option<Color32>,
     on_hover: bool,
-    /// Optional drawer widget rendered below the row when expanded
+    /// Optional detail panel rendered below the row when expanded
     #[allow(clippy::type_complexity)]
     drawer: Option<std::sync::Arc<dyn Fn(&mut Ui) + Send + Sync>>,
     _phantom: std::marker::PhantomData<&'a ()>,
@@ -386,10 +566,12 @@ impl<'a> DataTableRow<'a> {
         self
     }
 
-    /// Add a widget cell
-    pub fn widget_cell<F>(mut self, f: F) -> Self
+    /// Add a widget cell, e.g. `.widget_cell(|ui| ui.add(progress))`.
+    /// Use [`custom_cell`](Self::custom_cell) with [`DataTableCell::value`] to
+    /// make it sortable or editable.
+    pub fn widget_cell<F, R>(mut self, f: F) -> Self
     where
-        F: Fn(&mut Ui) + Send + Sync + 'static,
+        F: Fn(&mut Ui) -> R + Send + Sync + 'static,
     {
         self.cells.push(DataTableCell::widget(f));
         self
@@ -421,15 +603,372 @@ impl<'a> DataTableRow<'a> {
         self
     }
 
-    /// Set a drawer widget shown below this row when expanded.
-    /// A clickable arrow (> closed / v open) is displayed in the drawer column.
-    pub fn drawer<F>(mut self, f: F) -> Self
+    /// Make this row expandable into a detail panel spanning all columns.
+    ///
+    /// The table shows a leading chevron that rotates when the row is
+    /// expanded. Give the row an `.id()` so it stays expanded when the rows
+    /// are re-sorted or rebuilt.
+    pub fn expandable<F>(mut self, f: F) -> Self
     where
         F: Fn(&mut Ui) + Send + Sync + 'static,
     {
         self.drawer = Some(std::sync::Arc::new(f));
         self
     }
+
+    /// Set a drawer widget shown below this row when expanded.
+    /// Same as [`expandable`](Self::expandable).
+    pub fn drawer<F>(self, f: F) -> Self
+    where
+        F: Fn(&mut Ui) + Send + Sync + 'static,
+    {
+        self.expandable(f)
+    }
+
+    /// Stable key of this row in [`DataTableState`]: its id, or its original index.
+    fn row_key(&self, index: usize) -> String {
+        self.id.clone().unwrap_or_else(|| index.to_string())
+    }
+
+    /// Text of the cell at `index`, or `None` for widget cells without a value.
+    fn cell_text(&self, index: usize) -> Option<&str> {
+        self.cells.get(index).and_then(|c| match &c.content {
+            CellContent::Text(t) => Some(t.text()),
+            CellContent::Widget(_) => c.value.as_deref(),
+        })
+    }
+}
+
+/// A row either borrowed from the table or built on demand by a [`DataTableSource`].
+enum RowHandle<'r, 'a> {
+    Borrowed(&'r DataTableRow<'a>),
+    Built(DataTableRow<'a>),
+}
+
+impl<'a> std::ops::Deref for RowHandle<'_, 'a> {
+    type Target = DataTableRow<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            RowHandle::Borrowed(row) => row,
+            RowHandle::Built(row) => row,
+        }
+    }
+}
+
+/// Look up a row by original index, building it from `source` if one is set.
+fn fetch_row<'r, 'a>(
+    rows: &'r [DataTableRow<'a>],
+    source: Option<&'a dyn DataTableSource>,
+    index: usize,
+) -> Option<RowHandle<'r, 'a>> {
+    match source {
+        Some(source) => source.get_row(index).map(RowHandle::Built),
+        None => rows.get(index).map(RowHandle::Borrowed),
+    }
+}
+
+/// Number shown in a cell, ignoring a leading `$`; 0 when it isn't a number.
+fn parse_number(text: &str) -> f64 {
+    text.trim().trim_start_matches('$').parse().unwrap_or(0.0)
+}
+
+/// Checked state of a checkbox cell.
+fn parse_bool(text: &str) -> bool {
+    text.trim().eq_ignore_ascii_case("true")
+}
+
+/// Compare two cell texts, numerically for numeric columns.
+fn compare_cells(a: &str, b: &str, numeric: bool) -> std::cmp::Ordering {
+    if numeric {
+        // Try to parse as numbers for numeric columns
+        let a_num = parse_number(a);
+        let b_num = parse_number(b);
+        a_num
+            .partial_cmp(&b_num)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    } else {
+        // Alphabetical comparison for text columns
+        a.cmp(b)
+    }
+}
+
+/// Cells of `row` whose edit buffer differs from their current text.
+fn changed_cells(
+    row: &DataTableRow<'_>,
+    columns: &[DataTableColumn],
+    row_idx: usize,
+    edit_data: &[String],
+) -> Vec<CellEdit> {
+    columns
+        .iter()
+        .enumerate()
+        .filter_map(|(column_idx, column)| {
+            let current = row.cell_text(column_idx)?;
+            let edited = edit_data.get(column_idx)?;
+            (edited != current).then(|| CellEdit {
+                row: row_idx,
+                column: column_idx,
+                value: column.edit_kind.value(edited),
+            })
+        })
+        .collect()
+}
+
+/// Sort `order` (original row indices) by the cell text of each sort key in
+/// turn. The sort is stable, so rows that tie on every key keep their order.
+fn sort_order<'a>(
+    order: &mut [usize],
+    rows: &[DataTableRow<'a>],
+    source: Option<&'a dyn DataTableSource>,
+    columns: &[DataTableColumn],
+    sort_keys: &[(usize, SortDirection)],
+) {
+    let sort_keys: Vec<(usize, bool, &SortDirection)> = sort_keys
+        .iter()
+        .filter_map(|(column, direction)| {
+            columns
+                .get(*column)
+                .map(|col| (*column, col.numeric, direction))
+        })
+        .collect();
+    if sort_keys.is_empty() {
+        return;
+    }
+
+    // keys[position][key] is the text of that sort key's cell
+    let keys: Vec<Vec<String>> = order
+        .iter()
+        .map(|&idx| {
+            let row = fetch_row(rows, source, idx);
+            sort_keys
+                .iter()
+                .map(|(column, _, _)| {
+                    row.as_ref()
+                        .and_then(|row| row.cell_text(*column).map(str::to_owned))
+                        .unwrap_or_default()
+                })
+                .collect()
+        })
+        .collect();
+    let mut positions: Vec<usize> = (0..order.len()).collect();
+    positions.sort_by(|&a, &b| {
+        sort_keys
+            .iter()
+            .enumerate()
+            .map(|(key, (_, numeric, direction))| {
+                let comparison = compare_cells(&keys[a][key], &keys[b][key], *numeric);
+                match direction {
+                    SortDirection::Ascending => comparison,
+                    SortDirection::Descending => comparison.reverse(),
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let sorted: Vec<usize> = positions.into_iter().map(|pos| order[pos]).collect();
+    order.copy_from_slice(&sorted);
+}
+
+/// How a click on a row changes the selection.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SelectionClick {
+    /// Select only the clicked row
+    Only,
+    /// Flip the clicked row
+    Toggle,
+    /// Also select every row between the anchor and the clicked row
+    Range,
+}
+
+/// Add `key` to the selection, or remove it.
+fn set_selected(selected: &mut HashSet<String>, key: String, is_selected: bool) {
+    if is_selected {
+        selected.insert(key);
+    } else {
+        selected.remove(&key);
+    }
+}
+
+/// Apply a click on row `clicked` (by original index) to the `selected` row keys.
+///
+/// Ranges run between the anchor and the clicked row in `display_order`, so
+/// they follow the current sort. Rows for which `selectable` is false keep
+/// their state; any other row that isn't shown loses its selection on a plain click.
+fn apply_selection_click(
+    selected: &mut HashSet<String>,
+    display_order: &[usize],
+    key: impl Fn(usize) -> Option<String>,
+    selectable: impl Fn(usize) -> bool,
+    anchor: Option<usize>,
+    clicked: usize,
+    click: SelectionClick,
+) {
+    let position = |idx: usize| display_order.iter().position(|&row| row == idx);
+    match click {
+        SelectionClick::Toggle => {
+            if let Some(key) = key(clicked) {
+                let was_selected = selected.contains(&key);
+                set_selected(selected, key, !was_selected);
+            }
+        }
+        SelectionClick::Only => {
+            let locked: HashSet<String> = display_order
+                .iter()
+                .filter(|&&idx| idx != clicked && !selectable(idx))
+                .filter_map(|&idx| key(idx))
+                .collect();
+            selected.retain(|key| locked.contains(key));
+            selected.extend(key(clicked));
+        }
+        SelectionClick::Range => {
+            let range = match (anchor.and_then(position), position(clicked)) {
+                (Some(a), Some(b)) => a.min(b)..=a.max(b),
+                (_, Some(b)) => b..=b,
+                _ => return,
+            };
+            for &idx in &display_order[range] {
+                if idx == clicked || selectable(idx) {
+                    selected.extend(key(idx));
+                }
+            }
+        }
+    }
+}
+
+/// Update `sort_keys` for a click on the header of `column`.
+///
+/// A plain click sorts by that column alone, toggling its direction if it was
+/// already the primary key. With `add_key` (shift held) the column is appended as
+/// the lowest-priority key, or its direction toggled if it is already a key.
+fn click_sort_key(sort_keys: &mut Vec<(usize, SortDirection)>, column: usize, add_key: bool) {
+    let toggle = |direction: &SortDirection| match direction {
+        SortDirection::Ascending => SortDirection::Descending,
+        SortDirection::Descending => SortDirection::Ascending,
+    };
+    if add_key {
+        match sort_keys.iter_mut().find(|(key, _)| *key == column) {
+            Some((_, direction)) => *direction = toggle(direction),
+            None => sort_keys.push((column, SortDirection::Ascending)),
+        }
+    } else {
+        let direction = match sort_keys.first() {
+            Some((key, direction)) if *key == column => toggle(direction),
+            _ => SortDirection::Ascending,
+        };
+        *sort_keys = vec![(column, direction)];
+    }
+}
+
+/// The primary sort key as (column, direction), ascending when unsorted.
+fn primary_sort(sort_keys: &[(usize, SortDirection)]) -> (Option<usize>, SortDirection) {
+    sort_keys
+        .first()
+        .map_or((None, SortDirection::Ascending), |(column, direction)| {
+            (Some(*column), direction.clone())
+        })
+}
+
+/// Quote a field per RFC 4180 if it contains the delimiter, a quote or a line break.
+fn escape_field(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter) || field.contains(['"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Find the rows (by display position) that intersect `clip`, given the height
+/// of each displayed row and the y coordinate of the first one. Returns the
+/// range and the y coordinate of the first row in it.
+fn visible_rows(
+    heights: impl IntoIterator<Item = f32>,
+    top: f32,
+    clip: egui::Rangef,
+) -> (std::ops::Range<usize>, f32) {
+    let mut y = top;
+    let mut first = None;
+    let mut first_y = top;
+    let mut end = 0;
+    for (idx, height) in heights.into_iter().enumerate() {
+        if y >= clip.max {
+            break;
+        }
+        if first.is_none() && y + height > clip.min {
+            first = Some(idx);
+            first_y = y;
+        }
+        y += height;
+        end = idx + 1;
+    }
+    match first {
+        Some(first) => (first..end, first_y),
+        None => (end..end, y),
+    }
+}
+
+/// Measure the height needed to show a row's text cells.
+fn measure_row_height(
+    ui: &Ui,
+    row: &DataTableRow<'_>,
+    columns: &[DataTableColumn],
+    theme: &DataTableTheme,
+    auto_height: bool,
+    min_row_height: f32,
+) -> f32 {
+    // In auto_height mode, start with a minimal height, otherwise use min_row_height
+    let base_height = if auto_height { 20.0 } else { min_row_height };
+    let mut max_height: f32 = base_height;
+
+    for (cell_idx, cell) in row.cells.iter().enumerate() {
+        if let Some(column) = columns.get(cell_idx) {
+            match &cell.content {
+                CellContent::Text(cell_text) => {
+                    let available_width = column.width - 32.0;
+                    let cell_font = if let Some((ref font_id, _)) = theme.data_text_style {
+                        font_id.clone()
+                    } else {
+                        FontId::new(14.0, FontFamily::Proportional)
+                    };
+
+                    let galley = ui.painter().layout_job(egui::text::LayoutJob {
+                        text: cell_text.text().to_string(),
+                        sections: vec![egui::text::LayoutSection {
+                            leading_space: 0.0,
+                            byte_range: 0..cell_text.text().len(),
+                            format: egui::TextFormat {
+                                font_id: cell_font,
+                                color: get_global_color("onSurface"),
+                                ..Default::default()
+                            },
+                        }],
+                        wrap: egui::text::TextWrapping {
+                            max_width: available_width,
+                            ..Default::default()
+                        },
+                        break_on_newline: true,
+                        halign: egui::Align::LEFT, // Always left-align within galley; positioning handles cell alignment
+                        justify: false,
+                        first_row_min_height: 0.0,
+                        round_output_to_gui: true,
+                    });
+
+                    let content_height: f32 = galley.size().y + 16.0; // Add padding
+                    max_height = max_height.max(content_height);
+                }
+                CellContent::Widget(_) => {
+                    // For widgets, use minimum height - they will size themselves
+                    // In auto mode, don't force a minimum for widget rows
+                    if !auto_height {
+                        max_height = max_height.max(min_row_height);
+                    }
+                }
+            }
+        }
+    }
+
+    // Apply minimum height constraint
+    max_height.max(min_row_height)
 }
 
 impl<'a> MaterialDataTable<'a> {
@@ -442,28 +981,41 @@ impl<'a> MaterialDataTable<'a> {
             allow_selection: false,
             allow_drawer: false,
             drawer_row_height: None,
+            accordion: false,
             sticky_header: false,
             progress_visible: false,
-            corner_radius: CornerRadius::from(4.0),
-            sorted_column: None,
-            sort_direction: SortDirection::Ascending,
+            corner_radius: shape_radius(Shape::ExtraSmall),
+            sort_keys: Vec::new(),
             default_row_height: 52.0,
             theme: DataTableTheme::default(),
             auto_height: false,
             refresh_interval: 0.05, // Default 50ms throttle for smooth scrolling
+            source: None,
+            virtualized: false,
+            export_selected_only: false,
+            export_selection: HashSet::new(),
+            resizable_columns: false,
+            empty_state: None,
         }
     }
 
     /// Set the initial sort column and direction
     pub fn sort_by(mut self, column_index: usize, direction: SortDirection) -> Self {
-        self.sorted_column = Some(column_index);
-        self.sort_direction = direction;
+        self.sort_keys = vec![(column_index, direction)];
+        self
+    }
+
+    /// Add a lower-priority initial sort key, used to order rows that tie on
+    /// the keys before it.
+    pub fn then_sort_by(mut self, column_index: usize, direction: SortDirection) -> Self {
+        self.sort_keys.retain(|(column, _)| *column != column_index);
+        self.sort_keys.push((column_index, direction));
         self
     }
 
     /// Get current sorting state
     pub fn get_sort_state(&self) -> (Option<usize>, SortDirection) {
-        (self.sorted_column, self.sort_direction.clone())
+        primary_sort(&self.sort_keys)
     }
 
     /// Set refresh throttle interval in seconds (0.0 = no throttling)
@@ -487,6 +1039,7 @@ impl<'a> MaterialDataTable<'a> {
             tooltip: None,
             heading_alignment: None,
             column_width: ColumnWidth::Fixed(width),
+            edit_kind: EditKind::Text,
         });
         self
     }
@@ -505,6 +1058,7 @@ impl<'a> MaterialDataTable<'a> {
             tooltip: None,
             heading_alignment: None,
             column_width: ColumnWidth::Fixed(width),
+            edit_kind: EditKind::Text,
         });
         self
     }
@@ -529,6 +1083,7 @@ impl<'a> MaterialDataTable<'a> {
             tooltip: None,
             heading_alignment: None,
             column_width: ColumnWidth::Fixed(width),
+            edit_kind: EditKind::Text,
         });
         self
     }
@@ -554,6 +1109,7 @@ impl<'a> MaterialDataTable<'a> {
             tooltip: None,
             heading_alignment: None,
             column_width: ColumnWidth::Fixed(width),
+            edit_kind: EditKind::Text,
         });
         self
     }
@@ -566,6 +1122,14 @@ impl<'a> MaterialDataTable<'a> {
         self
     }
 
+    /// Set the editor for the most recently added column (default: [`EditKind::Text`])
+    pub fn column_edit_kind(mut self, kind: EditKind) -> Self {
+        if let Some(column) = self.columns.last_mut() {
+            column.edit_kind = kind;
+        }
+        self
+    }
+
     /// Add a row using a builder pattern.
     pub fn row<F>(mut self, f: F) -> Self
     where
@@ -576,6 +1140,50 @@ impl<'a> MaterialDataTable<'a> {
         self
     }
 
+    /// Supply rows lazily from a [`DataTableSource`] instead of `.row()`.
+    ///
+    /// Combined with `.virtualized(true)`, `get_row` is only called for the
+    /// rows that are visible (plus every row once when the sort order changes).
+    pub fn source(mut self, source: &'a dyn DataTableSource) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Only lay out the rows that intersect the clip rect.
+    ///
+    /// Place the table inside an `egui::ScrollArea`; the table still reserves its
+    /// full height so scrolling works as usual. Rows use a fixed height of
+    /// `default_row_height` (auto row height is ignored), so no text has to be
+    /// measured for rows that are never shown.
+    pub fn virtualized(mut self, virtualized: bool) -> Self {
+        self.virtualized = virtualized;
+        self
+    }
+
+    /// Only export the rows selected in the state passed to [`Self::export_state`]
+    /// from [`Self::to_csv`] and [`Self::to_tsv`]. Has no effect unless selection
+    /// is enabled.
+    pub fn export_selected_only(mut self, selected_only: bool) -> Self {
+        self.export_selected_only = selected_only;
+        self
+    }
+
+    /// Take the selection for [`Self::export_selected_only`] from `state`, e.g.
+    /// [`DataTableState::load`] with this table's id, so rows the user checked
+    /// are exported as well as those set with `.selected(true)`.
+    pub fn export_state(mut self, state: &DataTableState) -> Self {
+        self.export_selection = state.selected_rows.clone();
+        self
+    }
+
+    /// Let users resize columns by dragging the divider at the right edge of a
+    /// header cell. Columns can't be made narrower than 48px, and the resized
+    /// widths are kept in [`DataTableState::column_widths`] by column title.
+    pub fn resizable_columns(mut self, resizable: bool) -> Self {
+        self.resizable_columns = resizable;
+        self
+    }
+
     /// Set the ID for state persistence.
     pub fn id(mut self, id: impl Into<Id>) -> Self {
         self.id = Some(id.into());
@@ -590,11 +1198,21 @@ impl<'a> MaterialDataTable<'a> {
 
     /// Enable row drawers. Rows with a `.drawer()` closure will show a clickable
     /// arrow (> closed, v open) that expands a panel below the row.
+    ///
+    /// Enabled automatically when any row added with `.row()` is
+    /// [`expandable`](DataTableRow::expandable); rows from a
+    /// [`DataTableSource`] need this set explicitly.
     pub fn allow_drawer(mut self, allow: bool) -> Self {
         self.allow_drawer = allow;
         self
     }
 
+    /// Keep at most one row expanded: expanding a row collapses the others.
+    pub fn accordion(mut self, accordion: bool) -> Self {
+        self.accordion = accordion;
+        self
+    }
+
     /// Set the fixed height of expanded drawer panels (default: automatic sizing).
     /// If not set, drawer height will automatically adjust to fit its contents.
     pub fn drawer_row_height(mut self, height: f32) -> Self {
@@ -614,12 +1232,25 @@ impl<'a> MaterialDataTable<'a> {
         self
     }
 
+    /// Set what the table shows below the header when it has no rows.
+    ///
+    /// Defaults to a "No data" empty state.
+    pub fn empty_state(mut self, empty_state: MaterialEmptyState) -> Self {
+        self.empty_state = Some(empty_state);
+        self
+    }
+
     /// Set corner radius.
     pub fn corner_radius(mut self, corner_radius: impl Into<CornerRadius>) -> Self {
         self.corner_radius = corner_radius.into();
         self
     }
 
+    /// Set the corner radius from an M3 shape token; tables are [`Shape::ExtraSmall`] by default.
+    pub fn shape(self, shape: Shape) -> Self {
+        self.corner_radius(shape_radius(shape))
+    }
+
     /// Set default row height in pixels.
     /// This sets a fixed minimum height for all rows.
     pub fn default_row_height(mut self, height: f32) -> Self {
@@ -654,6 +1285,72 @@ impl<'a> MaterialDataTable<'a> {
         self
     }
 
+    /// Serialize the columns and rows as RFC 4180 CSV.
+    ///
+    /// Rows follow the sort order set with `.sort_by()`; pass the table's current
+    /// `sort_state` back into `.sort_by()` to export what the user sees. Cells are
+    /// exported as their raw text, and widget cells are exported empty.
+    pub fn to_csv(&self) -> String {
+        self.to_delimited(',')
+    }
+
+    /// Serialize the columns and rows as tab-separated values.
+    ///
+    /// Uses the same ordering and quoting rules as [`Self::to_csv`].
+    pub fn to_tsv(&self) -> String {
+        self.to_delimited('\t')
+    }
+
+    fn to_delimited(&self, delimiter: char) -> String {
+        let separator = delimiter.to_string();
+        let mut out = String::new();
+        let mut push_record = |fields: Vec<String>| {
+            out.push_str(&fields.join(&separator));
+            out.push_str("\r\n");
+        };
+
+        push_record(
+            self.columns
+                .iter()
+                .map(|col| escape_field(&col.title, delimiter))
+                .collect(),
+        );
+
+        let mut order: Vec<usize> = (0..self.row_count()).collect();
+        sort_order(
+            &mut order,
+            &self.rows,
+            self.source,
+            &self.columns,
+            &self.sort_keys,
+        );
+
+        for idx in order {
+            let Some(row) = fetch_row(&self.rows, self.source, idx) else {
+                continue;
+            };
+            if self.allow_selection
+                && self.export_selected_only
+                && !self.export_selection.contains(&row.row_key(idx))
+            {
+                continue;
+            }
+            push_record(
+                (0..self.columns.len())
+                    .map(|col| escape_field(row.cell_text(col).unwrap_or(""), delimiter))
+                    .collect(),
+            );
+        }
+
+        out
+    }
+
+    /// Number of rows, from the source if one is set.
+    fn row_count(&self) -> usize {
+        self.source
+            .map_or(self.rows.len(), |source| source.row_count())
+    }
+
     fn get_table_style(&self) -> (Color32, Stroke) {
         let md_surface = self.theme.decoration.unwrap_or_else(|| get_global_color("surface"));
         let md_outline = get_global_color("outline");
@@ -685,7 +1382,7 @@ impl<'a> MaterialDataTable<'a> {
                     }
                 }
             }
-            self.rows.len().hash(&mut hasher);
+            self.row_count().hash(&mut hasher);
             Id::new(format!("datatable_{}", hasher.finish()))
         });
 
@@ -705,23 +1402,21 @@ impl<'a> MaterialDataTable<'a> {
         }
 
         // Initialize sorting state from widget if not set
-        if state.sorted_column.is_none() && self.sorted_column.is_some() {
-            state.sorted_column = self.sorted_column;
-            state.sort_direction = self.sort_direction.clone();
+        if state.sort_keys.is_empty() && !self.sort_keys.is_empty() {
+            state.sort_keys = self.sort_keys.clone();
         }
 
-        // Ensure state vectors match current row count
-        if state.selected_rows.len() != self.rows.len() {
-            state.selected_rows.resize(self.rows.len(), false);
-        }
+        let row_count = self.row_count();
 
         // Sync selection state only for rows where the caller explicitly set `.selected()`.
         // Rows without an explicit `.selected()` call preserve their internally-clicked state.
         for (i, row) in self.rows.iter().enumerate() {
-            if i < state.selected_rows.len() && row.selection_externally_set {
-                state.selected_rows[i] = row.selected;
+            if row.selection_externally_set {
+                set_selected(&mut state.selected_rows, row.row_key(i), row.selected);
             }
         }
+        // Selection before this frame's clicks, to report what changed
+        let mut selection_before = state.selected_rows.clone();
 
         // === PERFORMANCE OPTIMIZATION: Option 2 - Throttling ===
         // Check if enough time has passed since last refresh
@@ -730,11 +1425,12 @@ impl<'a> MaterialDataTable<'a> {
             && (current_time - state.last_refresh_time) < self.refresh_interval as f64;
 
         let MaterialDataTable {
-            columns,
-            mut rows,
+            mut columns,
+            rows,
             allow_selection,
             allow_drawer,
             drawer_row_height,
+            accordion,
             sticky_header: _,
             progress_visible,
             corner_radius,
@@ -742,76 +1438,63 @@ impl<'a> MaterialDataTable<'a> {
             theme,
             auto_height,
             refresh_interval,
+            source,
+            virtualized,
+            resizable_columns,
+            empty_state,
             ..
         } = self;
 
+        let allow_drawer = allow_drawer || rows.iter().any(|row| row.drawer.is_some());
+
+        // Apply widths the user has dragged to, before anything is laid out
+        if resizable_columns {
+            for col in &mut columns {
+                if let Some(&width) = state.column_widths.get(&col.title) {
+                    col.width = width;
+                }
+            }
+        }
+
         // === PERFORMANCE OPTIMIZATION: Option 1 - Caching ===
         // Calculate hash of layout-affecting properties
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
         let mut hasher = DefaultHasher::new();
-        rows.len().hash(&mut hasher);
+        row_count.hash(&mut hasher);
         columns.len().hash(&mut hasher);
         for col in &columns {
             col.width.to_bits().hash(&mut hasher);
         }
-        state.sorted_column.hash(&mut hasher);
-        state.sort_direction.clone().hash(&mut hasher);
+        state.sort_keys.hash(&mut hasher);
         auto_height.hash(&mut hasher);
+        virtualized.hash(&mut hasher);
         default_row_height.to_bits().hash(&mut hasher);
 
         let current_layout_hash = hasher.finish();
+        // Row content may change without affecting the hash, so the cache is also
+        // refreshed once the throttle interval has elapsed. Virtualized tables skip
+        // the periodic refresh so that large sources are not re-read every interval.
+        let refresh_due = !should_throttle && !virtualized;
         let cache_is_valid = state.layout_cache_hash == current_layout_hash
-            && state.cached_row_heights.len() == rows.len()
-            && !should_throttle;
+            && state.cached_row_heights.len() == row_count
+            && state.cached_sorted_indices.len() == row_count
+            && !refresh_due;
 
         // Mark that we need to update the cache
         let needs_cache_update = !cache_is_valid;
 
         // === PERFORMANCE OPTIMIZATION: Cached Sorting ===
-        // Sort rows if a column is selected for sorting
+        // `display_order` maps display position -> original row index. Rows keep
+        // their original index for selection, drawers and actions.
         // Only re-sort if cache is invalid
-        if needs_cache_update {
-            if let Some(sort_col_idx) = state.sorted_column {
-                if let Some(sort_column) = columns.get(sort_col_idx) {
-                    rows.sort_by(|a, b| {
-                        let cell_a_text = a
-                            .cells
-                            .get(sort_col_idx)
-                            .and_then(|c| match &c.content {
-                                CellContent::Text(t) => Some(t.text()),
-                                CellContent::Widget(_) => None,
-                            })
-                            .unwrap_or("");
-                        let cell_b_text = b
-                            .cells
-                            .get(sort_col_idx)
-                            .and_then(|c| match &c.content {
-                                CellContent::Text(t) => Some(t.text()),
-                                CellContent::Widget(_) => None,
-                            })
-                            .unwrap_or("");
-
-                        let comparison = if sort_column.numeric {
-                            // Try to parse as numbers for numeric columns
-                            let a_num: f64 = cell_a_text.trim_start_matches('$').parse().unwrap_or(0.0);
-                            let b_num: f64 = cell_b_text.trim_start_matches('$').parse().unwrap_or(0.0);
-                            a_num
-                                .partial_cmp(&b_num)
-                                .unwrap_or(std::cmp::Ordering::Equal)
-                        } else {
-                            // Alphabetical comparison for text columns
-                            cell_a_text.cmp(cell_b_text)
-                        };
-
-                        match state.sort_direction {
-                            SortDirection::Ascending => comparison,
-                            SortDirection::Descending => comparison.reverse(),
-                        }
-                    });
-                }
-            }
-        }
+        let display_order: Vec<usize> = if needs_cache_update {
+            let mut order: Vec<usize> = (0..row_count).collect();
+            sort_order(&mut order, &rows, source, &columns, &state.sort_keys);
+            order
+        } else {
+            std::mem::take(&mut state.cached_sorted_indices)
+        };
 
         // Calculate table dimensions with dynamic row heights.
         // Use the data-columns width to decide whether to use compact special-column widths:
@@ -873,88 +1556,42 @@ impl<'a> MaterialDataTable<'a> {
         };
 
         // === PERFORMANCE OPTIMIZATION: Cached Row Heights ===
-        // Calculate individual row heights based on content (use cache if valid)
-        let row_heights: Vec<f32> = if cache_is_valid && state.cached_row_heights.len() == rows.len() {
+        // Calculate individual row heights based on content (use cache if valid).
+        // Heights are indexed by original row index.
+        let row_heights: Vec<f32> = if cache_is_valid {
             // Use cached row heights for maximum performance
-            state.cached_row_heights.clone()
+            std::mem::take(&mut state.cached_row_heights)
+        } else if virtualized {
+            // Virtualized rows have a known, fixed height
+            vec![min_row_height; row_count]
         } else {
             // Recalculate row heights and cache them
-            let mut heights = Vec::new();
-            for row in &rows {
-                // In auto_height mode, start with a minimal height, otherwise use min_row_height
-                let base_height = if auto_height { 20.0 } else { min_row_height };
-                let mut max_height: f32 = base_height;
-
-                for (cell_idx, cell) in row.cells.iter().enumerate() {
-                    if let Some(column) = columns.get(cell_idx) {
-                        match &cell.content {
-                            CellContent::Text(cell_text) => {
-                                let available_width = column.width - 32.0;
-                                let cell_font = if let Some((ref font_id, _)) = theme.data_text_style {
-                                    font_id.clone()
-                                } else {
-                                    FontId::new(14.0, FontFamily::Proportional)
-                                };
-
-                                let galley = ui.painter().layout_job(egui::text::LayoutJob {
-                                    text: cell_text.text().to_string(),
-                                    sections: vec![egui::text::LayoutSection {
-                                        leading_space: 0.0,
-                                        byte_range: 0..cell_text.text().len(),
-                                        format: egui::TextFormat {
-                                            font_id: cell_font,
-                                            color: get_global_color("onSurface"),
-                                            ..Default::default()
-                                        },
-                                    }],
-                                    wrap: egui::text::TextWrapping {
-                                        max_width: available_width,
-                                        ..Default::default()
-                                    },
-                                    break_on_newline: true,
-                                    halign: egui::Align::LEFT, // Always left-align within galley; positioning handles cell alignment
-                                    justify: false,
-                                    first_row_min_height: 0.0,
-                                    round_output_to_gui: true,
-                                });
-
-                                let content_height: f32 = galley.size().y + 16.0; // Add padding
-                                max_height = max_height.max(content_height);
-                            }
-                            CellContent::Widget(_) => {
-                                // For widgets, use minimum height - they will size themselves
-                                // In auto mode, don't force a minimum for widget rows
-                                if !auto_height {
-                                    max_height = max_height.max(min_row_height);
-                                }
-                            }
-                        }
-                    }
-                }
-
-                // Apply minimum height constraint
-                let final_height = max_height.max(min_row_height);
-                heights.push(final_height);
-            }
-            heights
+            (0..row_count)
+                .map(|idx| {
+                    fetch_row(&rows, source, idx).map_or(min_row_height, |row| {
+                        measure_row_height(ui, &row, &columns, &theme, auto_height, min_row_height)
+                    })
+                })
+                .collect()
         };
 
-        // Calculate drawer heights for open rows (0.0 when closed)
-        let drawer_heights: Vec<f32> = rows
-            .iter()
-            .enumerate()
-            .map(|(row_idx, row)| {
-                if allow_drawer
-                    && row.drawer.is_some()
-                    && state.drawer_open_rows.contains(&row_idx)
-                {
+        // Calculate drawer heights for expanded rows (0.0 when collapsed).
+        // Rows are only fetched for their id while some row is expanded.
+        let any_expanded = allow_drawer && !state.expanded_rows.is_empty();
+        let drawer_heights: Vec<f32> = (0..row_count)
+            .map(|row_idx| {
+                let expanded_key = fetch_row(&rows, source, row_idx)
+                    .filter(|row| any_expanded && row.drawer.is_some())
+                    .map(|row| row.row_key(row_idx))
+                    .filter(|key| state.expanded_rows.contains(key));
+                if let Some(key) = expanded_key {
                     // Use fixed height if specified, otherwise check cached height from previous frame
                     if let Some(fixed_height) = drawer_row_height {
                         fixed_height
                     } else {
                         // Try to get cached height from previous frame's rendering
                         let cached_height = ui.data(|data| {
-                            data.get_temp::<f32>(table_id.with(format!("drawer_height_{}", row_idx)))
+                            data.get_temp::<f32>(table_id.with(("drawer_height", key)))
                         });
                         cached_height.unwrap_or(120.0) // Default to 120 if not cached yet
                     }
@@ -964,12 +1601,22 @@ impl<'a> MaterialDataTable<'a> {
             })
             .collect();
 
+        let show_empty_state = row_count == 0 && !progress_visible;
+        let empty_state_height = if show_empty_state {
+            EMPTY_STATE_HEIGHT
+        } else {
+            0.0
+        };
         let total_height = header_height
             + row_heights.iter().sum::<f32>()
-            + drawer_heights.iter().sum::<f32>();
+            + drawer_heights.iter().sum::<f32>()
+            + empty_state_height;
 
         // Collect all row actions from this frame
         let mut all_row_actions: Vec<RowAction> = Vec::new();
+        let mut cell_edits: Vec<CellEdit> = Vec::new();
+        let mut row_responses: Vec<(usize, Response)> = Vec::new();
+        let mut empty_action_clicked = false;
 
         // Apply Material theme styling
         let surface = get_global_color("surface");
@@ -1034,7 +1681,9 @@ impl<'a> MaterialDataTable<'a> {
                 let checkbox_size = Vec2::splat(18.0);
                 let checkbox_inner_rect = Rect::from_center_size(checkbox_center, checkbox_size);
 
-                let checkbox_color = if state.header_checkbox {
+                // Some but not all rows selected
+                let indeterminate = !state.header_checkbox && !state.selected_rows.is_empty();
+                let checkbox_color = if state.header_checkbox || indeterminate {
                     get_global_color("primary")
                 } else {
                     Color32::TRANSPARENT
@@ -1067,6 +1716,16 @@ impl<'a> MaterialDataTable<'a> {
                         [check_points[1], check_points[2]],
                         Stroke::new(2.0, Color32::WHITE),
                     );
+                } else if indeterminate {
+                    // Draw dash
+                    let y = checkbox_inner_rect.center().y;
+                    ui.painter().line_segment(
+                        [
+                            egui::pos2(checkbox_inner_rect.min.x + 4.0, y),
+                            egui::pos2(checkbox_inner_rect.max.x - 4.0, y),
+                        ],
+                        Stroke::new(2.0, Color32::WHITE),
+                    );
                 }
 
                 // Handle header checkbox click
@@ -1076,10 +1735,14 @@ impl<'a> MaterialDataTable<'a> {
                 if checkbox_response.clicked() {
                     state.header_checkbox = !state.header_checkbox;
                     // Only update non-readonly rows
-                    for (idx, selected) in state.selected_rows.iter_mut().enumerate() {
-                        if let Some(row) = rows.get(idx) {
+                    for idx in 0..row_count {
+                        if let Some(row) = fetch_row(&rows, source, idx) {
                             if !row.readonly {
-                                *selected = state.header_checkbox;
+                                set_selected(
+                                    &mut state.selected_rows,
+                                    row.row_key(idx),
+                                    state.header_checkbox,
+                                );
                             }
                         }
                     }
@@ -1136,8 +1799,15 @@ impl<'a> MaterialDataTable<'a> {
 
                 // Handle column header clicks for sorting
                 if column.sortable {
+                    // Keep the resize handle out of the click area so dragging it never sorts
+                    let click_rect = if resizable_columns {
+                        col_rect.with_max_x(col_rect.max.x - RESIZE_HANDLE_WIDTH)
+                    } else {
+                        col_rect
+                    };
                     let header_click_id = table_id.with(format!("column_header_{}", col_idx));
-                    let mut header_response = ui.interact(col_rect, header_click_id, Sense::click());
+                    let mut header_response =
+                        ui.interact(click_rect, header_click_id, Sense::click());
                     
                     // Show tooltip if available
                     if let Some(ref tooltip) = column.tooltip {
@@ -1145,18 +1815,8 @@ impl<'a> MaterialDataTable<'a> {
                     }
                     
                     if header_response.clicked() {
-                        // Handle sorting logic
-                        if state.sorted_column == Some(col_idx) {
-                            // Same column clicked, toggle direction
-                            state.sort_direction = match state.sort_direction {
-                                SortDirection::Ascending => SortDirection::Descending,
-                                SortDirection::Descending => SortDirection::Ascending,
-                            };
-                        } else {
-                            // New column clicked
-                            state.sorted_column = Some(col_idx);
-                            state.sort_direction = SortDirection::Ascending;
-                        }
+                        let add_key = ui.input(|i| i.modifiers.shift);
+                        click_sort_key(&mut state.sort_keys, col_idx, add_key);
                         ui.memory_mut(|mem| {
                             mem.data
                                 .insert_temp(table_id.with("column_clicked"), Some(col_idx));
@@ -1169,13 +1829,14 @@ impl<'a> MaterialDataTable<'a> {
                     );
                     let icon_rect = Rect::from_min_size(icon_pos, Vec2::splat(24.0));
 
-                    // Determine if this column is currently sorted
-                    let is_sorted = state.sorted_column == Some(col_idx);
-                    let sort_direction = if is_sorted {
-                        Some(&state.sort_direction)
-                    } else {
-                        None
-                    };
+                    // Determine if this column is currently sorted, and at which priority
+                    let sort_priority = state
+                        .sort_keys
+                        .iter()
+                        .position(|(column, _)| *column == col_idx);
+                    let is_sorted = sort_priority.is_some();
+                    let sort_direction =
+                        sort_priority.map(|priority| &state.sort_keys[priority].1);
 
                     // Draw sort arrow with enhanced visual feedback
                     let arrow_color = if is_sorted {
@@ -1270,6 +1931,45 @@ impl<'a> MaterialDataTable<'a> {
                             );
                         }
                     }
+
+                    // Numbered priority badge when sorting by more than one column
+                    if let Some(priority) = sort_priority.filter(|_| state.sort_keys.len() > 1) {
+                        let badge_center = center + Vec2::new(9.0, -7.0);
+                        ui.painter().circle_filled(badge_center, 6.0, arrow_color);
+                        ui.painter().text(
+                            badge_center,
+                            egui::Align2::CENTER_CENTER,
+                            (priority + 1).to_string(),
+                            FontId::new(9.0, FontFamily::Proportional),
+                            get_global_color("onPrimary"),
+                        );
+                    }
+                }
+
+                if resizable_columns {
+                    let handle_rect = Rect::from_min_max(
+                        egui::pos2(col_rect.max.x - RESIZE_HANDLE_WIDTH, col_rect.min.y),
+                        col_rect.max,
+                    );
+                    let handle_response = ui.interact(
+                        handle_rect,
+                        table_id.with(("column_resize", col_idx)),
+                        Sense::drag(),
+                    );
+                    if handle_response.hovered() || handle_response.dragged() {
+                        ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeColumn);
+                        ui.painter().vline(
+                            col_rect.max.x - 1.0,
+                            col_rect.y_range(),
+                            Stroke::new(2.0, get_global_color("primary")),
+                        );
+                    }
+                    if handle_response.dragged() {
+                        let new_width =
+                            (column.width + handle_response.drag_delta().x).max(MIN_COLUMN_WIDTH);
+                        state.column_widths.insert(column.title.clone(), new_width);
+                        ui.ctx().request_repaint();
+                    }
                 }
 
                 current_x += column.width;
@@ -1277,15 +1977,73 @@ impl<'a> MaterialDataTable<'a> {
 
             current_y += header_height;
 
+            if show_empty_state {
+                let empty_rect = Rect::from_min_size(
+                    egui::pos2(rect.min.x, current_y),
+                    Vec2::new(total_width, EMPTY_STATE_HEIGHT),
+                );
+                let empty_state = empty_state
+                    .unwrap_or_else(|| MaterialEmptyState::new("No data").icon("table_rows"));
+                empty_action_clicked = ui
+                    .scope_builder(egui::UiBuilder::new().max_rect(empty_rect), |ui| {
+                        empty_state.show(ui).action_clicked
+                    })
+                    .inner;
+            }
+
+            // In virtualized mode only the rows intersecting the clip rect are laid out
+            let display_heights = display_order.iter().map(|&idx| {
+                row_heights.get(idx).copied().unwrap_or(min_row_height)
+                    + drawer_heights.get(idx).copied().unwrap_or(0.0)
+            });
+            let (visible_range, visible_top) = if virtualized {
+                visible_rows(display_heights, current_y, ui.clip_rect().y_range())
+            } else {
+                (0..row_count, current_y)
+            };
+            current_y = visible_top;
+
             // Draw rows with dynamic heights
-            for (row_idx, row) in rows.iter().enumerate() {
+            for display_idx in visible_range {
+                let row_idx = display_order[display_idx];
+                let Some(row) = fetch_row(&rows, source, row_idx) else {
+                    continue;
+                };
                 let row_height = row_heights.get(row_idx).copied().unwrap_or(min_row_height);
+
+                // Sourced rows are only built here, so sync explicit selection now
+                if source.is_some() && row.selection_externally_set {
+                    let key = row.row_key(row_idx);
+                    set_selected(&mut state.selected_rows, key.clone(), row.selected);
+                    set_selected(&mut selection_before, key, row.selected);
+                }
+
+                // Row click: select only this row, ctrl/cmd toggles, shift selects a range.
+                // Registered before the cells so their widgets stay clickable.
+                let mut selection_click = None;
+                let row_click_id = table_id.with(("row_click", row_idx));
+                let row_click_rect = Rect::from_min_size(
+                    egui::pos2(rect.min.x, current_y),
+                    Vec2::new(total_width, row_height),
+                );
+                let row_response = ui.interact(row_click_rect, row_click_id, Sense::click());
+                if allow_selection && !row.readonly && row_response.clicked() {
+                    let modifiers = ui.input(|i| i.modifiers);
+                    selection_click = Some(if modifiers.shift {
+                        SelectionClick::Range
+                    } else if modifiers.command {
+                        SelectionClick::Toggle
+                    } else {
+                        SelectionClick::Only
+                    });
+                }
+                row_responses.push((row_idx, row_response));
                 let row_rect = Rect::from_min_size(
                     egui::pos2(rect.min.x, current_y),
                     Vec2::new(total_width, row_height),
                 );
 
-                let row_selected = state.selected_rows.get(row_idx).copied().unwrap_or(false);
+                let row_selected = state.selected_rows.contains(&row.row_key(row_idx));
                 
                 // Determine row background color with priority: custom color > selected > readonly > alternating
                 let row_bg = if let Some(custom_color) = row.color {
@@ -1301,7 +2059,7 @@ impl<'a> MaterialDataTable<'a> {
                         surface_variant.b(),
                         (surface_variant.a() as f32 * 0.3) as u8,
                     )
-                } else if row_idx % 2 == 1 {
+                } else if display_idx % 2 == 1 {
                     theme.data_row_color.unwrap_or_else(|| get_global_color("surfaceVariant"))
                 } else {
                     background_color
@@ -1311,10 +2069,8 @@ impl<'a> MaterialDataTable<'a> {
                     .rect_filled(row_rect, CornerRadius::ZERO, row_bg);
                     
                 // Draw divider below row — skip when a drawer immediately follows
-                let row_has_open_drawer = allow_drawer
-                    && row.drawer.is_some()
-                    && state.drawer_open_rows.contains(&row_idx);
-                if !row_has_open_drawer && (row_idx < rows.len() - 1 || theme.show_bottom_border) {
+                let row_has_open_drawer = drawer_heights.get(row_idx).is_some_and(|&h| h > 0.0);
+                if !row_has_open_drawer && (display_idx + 1 < row_count || theme.show_bottom_border) {
                     let divider_y = current_y + row_height;
                     let divider_thickness = theme.divider_thickness.unwrap_or(1.0);
                     let divider_color = theme.divider_color.unwrap_or_else(|| get_global_color("outlineVariant"));
@@ -1387,35 +2143,51 @@ impl<'a> MaterialDataTable<'a> {
                     let checkbox_response =
                         ui.interact(checkbox_inner_rect, row_checkbox_id, Sense::click());
                     if checkbox_response.clicked() && !row.readonly {
-                        if let Some(selected) = state.selected_rows.get_mut(row_idx) {
-                            *selected = !*selected;
-                        }
-
-                        // Update header checkbox state based on row selections
-                        // Only consider non-readonly rows for header checkbox state
-                        let non_readonly_indices: Vec<usize> = rows
-                            .iter()
-                            .enumerate()
-                            .filter(|(_, row)| !row.readonly)
-                            .map(|(idx, _)| idx)
-                            .collect();
-
-                        if !non_readonly_indices.is_empty() {
-                            let all_non_readonly_selected = non_readonly_indices
-                                .iter()
-                                .all(|&idx| state.selected_rows.get(idx).copied().unwrap_or(false));
-                            let none_non_readonly_selected =
-                                non_readonly_indices.iter().all(|&idx| {
-                                    !state.selected_rows.get(idx).copied().unwrap_or(false)
-                                });
-                            state.header_checkbox =
-                                all_non_readonly_selected && !none_non_readonly_selected;
-                        }
+                        selection_click = Some(if ui.input(|i| i.modifiers.shift) {
+                            SelectionClick::Range
+                        } else {
+                            SelectionClick::Toggle
+                        });
                     }
 
                     current_x += checkbox_width;
                 }
 
+                if let Some(click) = selection_click {
+                    let anchor = state.selection_anchor.as_ref().and_then(|key| {
+                        (0..row_count).find(|&idx| {
+                            fetch_row(&rows, source, idx)
+                                .is_some_and(|row| row.row_key(idx) == *key)
+                        })
+                    });
+                    let key =
+                        |idx: usize| fetch_row(&rows, source, idx).map(|row| row.row_key(idx));
+                    let selectable =
+                        |idx: usize| fetch_row(&rows, source, idx).is_some_and(|row| !row.readonly);
+                    apply_selection_click(
+                        &mut state.selected_rows,
+                        &display_order,
+                        key,
+                        selectable,
+                        anchor,
+                        row_idx,
+                        click,
+                    );
+                    state.selection_anchor = Some(row.row_key(row_idx));
+
+                    // Update header checkbox state based on row selections
+                    // Only consider non-readonly rows for header checkbox state
+                    let non_readonly_indices: Vec<usize> =
+                        (0..row_count).filter(|&idx| selectable(idx)).collect();
+
+                    if !non_readonly_indices.is_empty() {
+                        state.header_checkbox = non_readonly_indices.iter().all(|&idx| {
+                            key(idx).is_some_and(|key| state.selected_rows.contains(&key))
+                        });
+                    }
+                    ui.ctx().request_repaint();
+                }
+
                 // Row drawer arrow
                 if allow_drawer {
                     let arrow_area_rect = Rect::from_min_size(
@@ -1424,52 +2196,47 @@ impl<'a> MaterialDataTable<'a> {
                     );
 
                     if row.drawer.is_some() {
-                        let is_open = state.drawer_open_rows.contains(&row_idx);
-                        let arrow_color = get_global_color("onSurfaceVariant");
-                        let center = arrow_area_rect.center();
-
-                        if is_open {
-                            // Down chevron: v
-                            let pts = [
-                                center + Vec2::new(-5.0, -3.0),
-                                center + Vec2::new(0.0, 3.0),
-                                center + Vec2::new(5.0, -3.0),
-                            ];
-                            ui.painter().line_segment(
-                                [pts[0], pts[1]],
-                                Stroke::new(2.0, arrow_color),
-                            );
-                            ui.painter().line_segment(
-                                [pts[1], pts[2]],
-                                Stroke::new(2.0, arrow_color),
-                            );
-                        } else {
-                            // Right chevron: >
-                            let pts = [
-                                center + Vec2::new(-3.0, -5.0),
-                                center + Vec2::new(3.0, 0.0),
-                                center + Vec2::new(-3.0, 5.0),
-                            ];
-                            ui.painter().line_segment(
-                                [pts[0], pts[1]],
-                                Stroke::new(2.0, arrow_color),
-                            );
-                            ui.painter().line_segment(
-                                [pts[1], pts[2]],
-                                Stroke::new(2.0, arrow_color),
-                            );
-                        }
-
-                        let arrow_id = table_id.with(format!("drawer_arrow_{}", row_idx));
+                        let key = row.row_key(row_idx);
+                        let is_open = state.expanded_rows.contains(&key);
+                        let arrow_id = table_id.with(("drawer_arrow", &key));
                         let arrow_response =
                             ui.interact(arrow_area_rect, arrow_id, Sense::click());
                         if arrow_response.clicked() {
                             if is_open {
-                                state.drawer_open_rows.remove(&row_idx);
+                                state.expanded_rows.remove(&key);
                             } else {
-                                state.drawer_open_rows.insert(row_idx);
+                                if accordion {
+                                    state.expanded_rows.clear();
+                                }
+                                state.expanded_rows.insert(key);
                             }
                         }
+
+                        // Chevron pointing right when collapsed, rotating to point down
+                        let turn = if reduced_motion(ui.ctx()) {
+                            if is_open {
+                                1.0
+                            } else {
+                                0.0
+                            }
+                        } else {
+                            ui.ctx().animate_bool_with_time(
+                                arrow_id.with("open"),
+                                is_open,
+                                EXPAND_ANIMATION_S,
+                            )
+                        };
+                        let rotation =
+                            egui::emath::Rot2::from_angle(turn * std::f32::consts::FRAC_PI_2);
+                        let center = arrow_area_rect.center();
+                        let pts = [
+                            center + rotation * Vec2::new(-3.0, -5.0),
+                            center + rotation * Vec2::new(3.0, 0.0),
+                            center + rotation * Vec2::new(-3.0, 5.0),
+                        ];
+                        let arrow_stroke = Stroke::new(2.0, get_global_color("onSurfaceVariant"));
+                        ui.painter().line_segment([pts[0], pts[1]], arrow_stroke);
+                        ui.painter().line_segment([pts[1], pts[2]], arrow_stroke);
                     }
 
                     current_x += drawer_arrow_width;
@@ -1500,7 +2267,7 @@ impl<'a> MaterialDataTable<'a> {
                                 egui::ScrollArea::horizontal()
                                     .id_salt(format!("actions_scroll_{}", row_idx))
                                     .auto_shrink([false, true])
-                                    .show(ui, |ui| {
+                                    .show_material(ui, |ui| {
                                     ui.horizontal(|ui| {
                                         if is_row_editing {
                                             if ui.add(MaterialButton::filled("Save").small()).clicked() {
@@ -1520,8 +2287,8 @@ impl<'a> MaterialDataTable<'a> {
                                     });
                                 });
                             });
-                        } else if is_row_editing {
-                            // Render editable text field
+                        } else if is_row_editing && row.cell_text(cell_idx).is_some() {
+                            // Render the column's editor
                             let edit_rect = Rect::from_min_size(
                                 egui::pos2(current_x + 8.0, current_y + (row_height - 24.0) / 2.0),
                                 Vec2::new(column.width - 16.0, 24.0),
@@ -1529,12 +2296,8 @@ impl<'a> MaterialDataTable<'a> {
 
                             // Get or initialize edit data
                             let edit_data = state.edit_data.entry(row_idx).or_insert_with(|| {
-                                row.cells
-                                    .iter()
-                                    .map(|c| match &c.content {
-                                        CellContent::Text(t) => t.text().to_string(),
-                                        CellContent::Widget(_) => String::new(),
-                                    })
+                                (0..row.cells.len())
+                                    .map(|i| row.cell_text(i).unwrap_or_default().to_string())
                                     .collect()
                             });
 
@@ -1546,10 +2309,44 @@ impl<'a> MaterialDataTable<'a> {
                             let edit_text = &mut edit_data[cell_idx];
 
                             ui.scope_builder(egui::UiBuilder::new().max_rect(edit_rect), |ui| {
-                                ui.add(
-                                    egui::TextEdit::singleline(edit_text)
-                                        .desired_width(column.width - 16.0),
-                                );
+                                match &column.edit_kind {
+                                    EditKind::Text => {
+                                        ui.add(
+                                            egui::TextEdit::singleline(edit_text)
+                                                .desired_width(column.width - 16.0),
+                                        );
+                                    }
+                                    EditKind::Number => {
+                                        let mut number = parse_number(edit_text);
+                                        if ui.add(egui::DragValue::new(&mut number)).changed() {
+                                            *edit_text = number.to_string();
+                                        }
+                                    }
+                                    EditKind::Dropdown(options) => {
+                                        let dropdown_id =
+                                            table_id.with(("edit_dropdown", row_idx, cell_idx));
+                                        egui::ComboBox::from_id_salt(dropdown_id)
+                                            .selected_text(edit_text.as_str())
+                                            .width(column.width - 16.0)
+                                            .show_ui(ui, |ui| {
+                                                for option in options {
+                                                    ui.selectable_value(
+                                                        edit_text,
+                                                        option.clone(),
+                                                        option.as_str(),
+                                                    );
+                                                }
+                                            });
+                                    }
+                                    EditKind::Checkbox => {
+                                        let mut checked = parse_bool(edit_text);
+                                        let checkbox =
+                                            ui.add(MaterialCheckbox::new(&mut checked, ""));
+                                        if checkbox.changed() {
+                                            *edit_text = checked.to_string();
+                                        }
+                                    }
+                                }
                             });
                         } else {
                             // Determine alignment from cell or column
@@ -1721,6 +2518,13 @@ impl<'a> MaterialDataTable<'a> {
                     }
                 }
 
+                // Commit the changed cells of a saved row
+                if row_actions.iter().any(|action| matches!(action, RowAction::Save(_))) {
+                    if let Some(edit_data) = state.edit_data.get(&row_idx) {
+                        cell_edits.extend(changed_cells(&row, &columns, row_idx, edit_data));
+                    }
+                }
+
                 // Add this row's actions to the global collection
                 all_row_actions.extend(row_actions);
 
@@ -1774,7 +2578,7 @@ impl<'a> MaterialDataTable<'a> {
                                 egui::UiBuilder::new()
                                     .max_rect(content_rect)
                                     .layout(egui::Layout::top_down(egui::Align::LEFT))
-                                    .id_salt(format!("drawer_{}", row_idx))
+                                    .id_salt(("drawer", row.row_key(row_idx)))
                             );
                             child_ui.set_clip_rect(clipped_rect);
                             drawer_fn(&mut child_ui);
@@ -1783,7 +2587,7 @@ impl<'a> MaterialDataTable<'a> {
                             if drawer_row_height.is_none() {
                                 let actual_height = child_ui.min_rect().height().max(40.0);
                                 ui.data_mut(|data| {
-                                    data.insert_temp(table_id.with(format!("drawer_height_{}", row_idx)), actual_height);
+                                    data.insert_temp(table_id.with(("drawer_height", row.row_key(row_idx))), actual_height);
                                 });
                             }
 
@@ -1831,8 +2635,9 @@ impl<'a> MaterialDataTable<'a> {
 
         // === PERFORMANCE OPTIMIZATION: Update Cache ===
         // Update cached values if we recalculated them
+        state.cached_row_heights = row_heights;
+        state.cached_sorted_indices = display_order;
         if needs_cache_update {
-            state.cached_row_heights = row_heights.clone();
             state.cached_header_height = header_height;
             state.layout_cache_hash = current_layout_hash;
             state.last_refresh_time = current_time;
@@ -1867,13 +2672,49 @@ impl<'a> MaterialDataTable<'a> {
         // This is critical when child UIs are used for drawer content
         ui.expand_to_include_rect(rect);
 
+        // Map the row keys whose selection changed back to original indices
+        let mut newly_selected = Vec::new();
+        let mut newly_deselected = Vec::new();
+        if state.selected_rows != selection_before {
+            for idx in 0..row_count {
+                let Some(row) = fetch_row(&rows, source, idx) else {
+                    continue;
+                };
+                let key = row.row_key(idx);
+                match (
+                    state.selected_rows.contains(&key),
+                    selection_before.contains(&key),
+                ) {
+                    (true, false) => newly_selected.push(idx),
+                    (false, true) => newly_deselected.push(idx),
+                    _ => {}
+                }
+            }
+        }
+        let selected_rows = if source.is_some() {
+            Vec::new()
+        } else {
+            rows.iter()
+                .enumerate()
+                .map(|(idx, row)| state.selected_rows.contains(&row.row_key(idx)))
+                .collect()
+        };
+
         DataTableResponse {
             response,
-            selected_rows: state.selected_rows,
+            selected_rows,
+            selected_keys: state.selected_rows,
             header_checkbox: state.header_checkbox,
+            selection_changed: !newly_selected.is_empty() || !newly_deselected.is_empty(),
+            newly_selected,
+            newly_deselected,
             column_clicked,
-            sort_state: (state.sorted_column, state.sort_direction.clone()),
+            sort_state: primary_sort(&state.sort_keys),
+            sort_keys: state.sort_keys,
             row_actions: all_row_actions,
+            cell_edits,
+            row_responses,
+            empty_action_clicked,
         }
     }
 }
@@ -1894,3 +2735,499 @@ impl Widget for MaterialDataTable<'_> {
 pub fn data_table() -> MaterialDataTable<'static> {
     MaterialDataTable::new()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct CountingSource {
+        rows: usize,
+        built: Cell<usize>,
+    }
+
+    impl DataTableSource for CountingSource {
+        fn row_count(&self) -> usize {
+            self.rows
+        }
+
+        fn get_row(&self, index: usize) -> Option<DataTableRow<'_>> {
+            self.built.set(self.built.get() + 1);
+            Some(
+                DataTableRow::new()
+                    .cell(format!("Row {}", index))
+                    .cell(index.to_string()),
+            )
+        }
+    }
+
+    #[test]
+    fn test_visible_rows_culls_to_clip() {
+        let heights = vec![50.0; 100];
+        let (range, top) = visible_rows(
+            heights.iter().copied(),
+            0.0,
+            egui::Rangef::new(120.0, 260.0),
+        );
+        assert_eq!(range, 2..6);
+        assert_eq!(top, 100.0);
+
+        let (range, _) = visible_rows(
+            heights.iter().copied(),
+            0.0,
+            egui::Rangef::new(-100.0, -10.0),
+        );
+        assert!(range.is_empty());
+    }
+
+    #[test]
+    fn test_virtualized_table_only_builds_visible_rows() {
+        let source = CountingSource {
+            rows: 50_000,
+            built: Cell::new(0),
+        };
+        let ctx = egui::Context::default();
+        let input = egui::RawInput {
+            screen_rect: Some(Rect::from_min_size(
+                egui::Pos2::ZERO,
+                Vec2::new(800.0, 600.0),
+            )),
+            ..Default::default()
+        };
+
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                egui::ScrollArea::vertical()
+                    .max_height(300.0)
+                    .show(ui, |ui| {
+                        MaterialDataTable::new()
+                            .id(Id::new("virtualized_table"))
+                            .column("Name", 200.0, false)
+                            .column("Index", 100.0, true)
+                            .source(&source)
+                            .virtualized(true)
+                            .show(ui);
+                    });
+            });
+        });
+
+        // 300px viewport with 52px rows: at most a handful of rows are built
+        let built = source.built.get();
+        assert!(built > 0 && built <= 8, "built {} rows", built);
+    }
+
+    #[test]
+    fn test_empty_table_reserves_room_for_empty_state() {
+        let ctx = egui::Context::default();
+        let mut heights = Vec::new();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                for progress in [false, true] {
+                    let response = MaterialDataTable::new()
+                        .id(Id::new(("empty_table", progress)))
+                        .column("Name", 200.0, false)
+                        .show_progress(progress)
+                        .show(ui);
+                    assert!(!response.empty_action_clicked);
+                    heights.push(response.response.rect.height());
+                }
+            });
+        });
+
+        // Loading tables show the header only
+        assert_eq!(heights[0] - heights[1], EMPTY_STATE_HEIGHT);
+    }
+
+    fn expandable_table_height(expanded: &[&str]) -> f32 {
+        let ctx = egui::Context::default();
+        let table_id = Id::new("expandable_table");
+        ctx.data_mut(|d| {
+            d.insert_persisted(
+                table_id,
+                DataTableState {
+                    expanded_rows: expanded.iter().map(|key| key.to_string()).collect(),
+                    ..Default::default()
+                },
+            )
+        });
+
+        let mut height = 0.0;
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                height = MaterialDataTable::new()
+                    .id(table_id)
+                    .column("Name", 200.0, false)
+                    .drawer_row_height(100.0)
+                    .row(|r| {
+                        r.id("a").cell("A").expandable(|ui| {
+                            ui.label("Detail A");
+                        })
+                    })
+                    .row(|r| {
+                        r.id("b").cell("B").expandable(|ui| {
+                            ui.label("Detail B");
+                        })
+                    })
+                    // Not expandable: its index key is ignored
+                    .row(|r| r.cell("C"))
+                    .show(ui)
+                    .response
+                    .rect
+                    .height();
+            });
+        });
+        height
+    }
+
+    #[test]
+    fn test_expanded_rows_grow_table() {
+        let collapsed = expandable_table_height(&[]);
+        assert_eq!(expandable_table_height(&["b"]), collapsed + 100.0);
+        assert_eq!(expandable_table_height(&["a", "b"]), collapsed + 200.0);
+        assert_eq!(expandable_table_height(&["2"]), collapsed);
+    }
+
+    #[test]
+    fn test_changed_cells_are_typed_by_edit_kind() {
+        let table = MaterialDataTable::new()
+            .column("Name", 120.0, false)
+            .column("Stock", 80.0, true)
+            .column_edit_kind(EditKind::Number)
+            .column("Active", 80.0, false)
+            .column_edit_kind(EditKind::Checkbox)
+            .column("Chart", 80.0, false)
+            .row(|r| {
+                r.cell("Widget")
+                    .cell("15")
+                    .custom_cell(DataTableCell::widget(|ui| ui.label("yes")).value("true"))
+                    .widget_cell(|ui| ui.label("chart"))
+            });
+        let edit_data = ["Widget", "20", "false", "ignored"].map(String::from);
+
+        assert_eq!(
+            changed_cells(&table.rows[0], &table.columns, 3, &edit_data),
+            vec![
+                CellEdit {
+                    row: 3,
+                    column: 1,
+                    value: CellValue::Number(20.0),
+                },
+                CellEdit {
+                    row: 3,
+                    column: 2,
+                    value: CellValue::Bool(false),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_widget_cells_sort_by_value() {
+        let table = MaterialDataTable::new()
+            .column("Progress", 100.0, true)
+            .row(|r| r.custom_cell(DataTableCell::widget(|_| ()).value("0.8")))
+            .row(|r| r.custom_cell(DataTableCell::widget(|_| ()).value("0.35")));
+        let mut order = vec![0, 1];
+        sort_order(
+            &mut order,
+            &table.rows,
+            None,
+            &table.columns,
+            &[(0, SortDirection::Ascending)],
+        );
+        assert_eq!(order, vec![1, 0]);
+    }
+
+    #[test]
+    fn test_multi_column_sort_is_stable() {
+        let table = MaterialDataTable::new()
+            .column("Name", 120.0, false)
+            .column("Category", 120.0, false)
+            .column("Value", 80.0, true)
+            .row(|r| r.cell("a").cell("Fruit").cell("3"))
+            .row(|r| r.cell("b").cell("Dairy").cell("5"))
+            .row(|r| r.cell("c").cell("Fruit").cell("7"))
+            .row(|r| r.cell("d").cell("Dairy").cell("5"))
+            .row(|r| r.cell("e").cell("Fruit").cell("3"))
+            .sort_by(1, SortDirection::Ascending)
+            .then_sort_by(2, SortDirection::Descending);
+
+        let mut order: Vec<usize> = (0..5).collect();
+        sort_order(
+            &mut order,
+            &table.rows,
+            None,
+            &table.columns,
+            &table.sort_keys,
+        );
+        // Dairy before Fruit, higher values first, ties in their original order
+        assert_eq!(order, vec![1, 3, 2, 0, 4]);
+
+        // Export applies the same order
+        let names: Vec<String> = parse_delimited(&table.to_csv(), ',')
+            .into_iter()
+            .skip(1)
+            .map(|record| record[0].clone())
+            .collect();
+        assert_eq!(names, ["b", "d", "c", "a", "e"]);
+    }
+
+    #[test]
+    fn test_shift_click_adds_sort_keys() {
+        let mut keys = Vec::new();
+        click_sort_key(&mut keys, 1, false);
+        click_sort_key(&mut keys, 2, true);
+        click_sort_key(&mut keys, 2, true);
+        assert_eq!(
+            keys,
+            vec![
+                (1, SortDirection::Ascending),
+                (2, SortDirection::Descending)
+            ]
+        );
+
+        // A plain click on the primary column toggles it and drops the others
+        click_sort_key(&mut keys, 1, false);
+        assert_eq!(keys, vec![(1, SortDirection::Descending)]);
+
+        click_sort_key(&mut keys, 0, false);
+        assert_eq!(keys, vec![(0, SortDirection::Ascending)]);
+    }
+
+    #[test]
+    fn test_selection_clicks_follow_display_order() {
+        // Rows sorted so that original row 4 is shown first
+        let display_order = [4, 2, 0, 3, 1];
+        let readonly = |idx: usize| idx == 0;
+        let key = |idx: usize| Some(idx.to_string());
+        let keys = |keys: &[&str]| keys.iter().map(|k| k.to_string()).collect::<HashSet<_>>();
+        let mut selected = keys(&["3"]);
+
+        apply_selection_click(
+            &mut selected,
+            &display_order,
+            key,
+            |idx| !readonly(idx),
+            None,
+            2,
+            SelectionClick::Only,
+        );
+        assert_eq!(selected, keys(&["2"]));
+
+        // Shift-click from row 2 to row 1 covers rows 0 and 3 between them,
+        // skipping the readonly row 0
+        apply_selection_click(
+            &mut selected,
+            &display_order,
+            key,
+            |idx| !readonly(idx),
+            Some(2),
+            1,
+            SelectionClick::Range,
+        );
+        assert_eq!(selected, keys(&["1", "2", "3"]));
+
+        apply_selection_click(
+            &mut selected,
+            &display_order,
+            key,
+            |idx| !readonly(idx),
+            Some(1),
+            3,
+            SelectionClick::Toggle,
+        );
+        assert_eq!(selected, keys(&["1", "2"]));
+
+        apply_selection_click(
+            &mut selected,
+            &display_order,
+            key,
+            |idx| !readonly(idx),
+            Some(3),
+            4,
+            SelectionClick::Only,
+        );
+        assert_eq!(selected, keys(&["4"]));
+    }
+
+    #[test]
+    fn test_selection_follows_row_ids_across_insertion() {
+        let ctx = egui::Context::default();
+        let mut selected_rows = Vec::new();
+        for names in [&["a", "b", "c"][..], &["new", "a", "b", "c"][..]] {
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let mut table = MaterialDataTable::new()
+                        .id(Id::new("keyed_selection_table"))
+                        .column("Name", 200.0, false)
+                        .allow_selection(true);
+                    // Only the first frame selects "b"; later frames keep it selected
+                    let first_frame = names.len() == 3;
+                    for &name in names {
+                        table = table.row(|row| {
+                            let row = row.id(name).cell(name);
+                            if first_frame && name == "b" {
+                                row.selected(true)
+                            } else {
+                                row
+                            }
+                        });
+                    }
+                    selected_rows = table.show(ui).selected_rows;
+                });
+            });
+        }
+        // "b" moved from index 1 to 2 and is still the only selected row
+        assert_eq!(selected_rows, vec![false, false, true, false]);
+    }
+
+    /// Minimal RFC 4180 reader used to check the exporter.
+    fn parse_delimited(input: &str, delimiter: char) -> Vec<Vec<String>> {
+        let mut records = Vec::new();
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut chars = input.chars().peekable();
+        while let Some(c) = chars.next() {
+            if in_quotes {
+                match c {
+                    '"' if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    '"' => in_quotes = false,
+                    _ => field.push(c),
+                }
+            } else if c == '"' {
+                in_quotes = true;
+            } else if c == delimiter {
+                record.push(std::mem::take(&mut field));
+            } else if c == '\r' && chars.peek() == Some(&'\n') {
+                chars.next();
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            } else {
+                field.push(c);
+            }
+        }
+        records
+    }
+
+    fn export_table() -> MaterialDataTable<'static> {
+        MaterialDataTable::new()
+            .column("Name", 200.0, false)
+            .column("Price", 100.0, true)
+            .row(|r| r.cell("Widget, large").cell("$12.50"))
+            .row(|r| r.cell("Say \"hi\"").cell("3"))
+            .row(|r| r.cell("Two\nlines").cell("$7").selected(true))
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let table = export_table().sort_by(1, SortDirection::Ascending);
+        let csv = table.to_csv();
+        assert!(csv.contains("\"Say \"\"hi\"\"\""));
+        assert_eq!(
+            parse_delimited(&csv, ','),
+            vec![
+                vec!["Name", "Price"],
+                vec!["Say \"hi\"", "3"],
+                vec!["Two\nlines", "$7"],
+                vec!["Widget, large", "$12.50"],
+            ]
+        );
+
+        let tsv = table.to_tsv();
+        assert_eq!(parse_delimited(&tsv, '\t'), parse_delimited(&csv, ','));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_sort_accessors_follow_primary_key() {
+        let mut state = DataTableState::default();
+        assert_eq!(state.sorted_column(), None);
+        assert_eq!(state.sort_direction(), SortDirection::Ascending);
+
+        state.sort_keys = vec![
+            (2, SortDirection::Descending),
+            (0, SortDirection::Ascending),
+        ];
+        assert_eq!(state.sorted_column(), Some(2));
+        assert_eq!(state.sort_direction(), SortDirection::Descending);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_drawer_open_rows_lists_index_keys() {
+        let state = DataTableState {
+            expanded_rows: HashSet::from(["1".to_string(), "4".to_string(), "a".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(state.drawer_open_rows(), HashSet::from([1, 4]));
+    }
+
+    #[test]
+    fn test_export_selected_only() {
+        let state = DataTableState {
+            selected_rows: HashSet::from(["0".to_string()]),
+            ..Default::default()
+        };
+        let table = export_table()
+            .export_selected_only(true)
+            .export_state(&state);
+        assert_eq!(parse_delimited(&table.to_csv(), ',').len(), 4);
+
+        // The state decides, not the `.selected(true)` the rows were built with
+        let table = export_table()
+            .allow_selection(true)
+            .export_selected_only(true)
+            .export_state(&state);
+        assert_eq!(
+            parse_delimited(&table.to_csv(), ','),
+            vec![vec!["Name", "Price"], vec!["Widget, large", "$12.50"]]
+        );
+    }
+
+    #[test]
+    fn test_export_includes_rows_checked_by_the_user() {
+        let ctx = egui::Context::default();
+        let table_id = Id::new("export_table");
+        let mut row_center = egui::Pos2::ZERO;
+        for pressed in [None, Some(true), Some(false)] {
+            let events = pressed.map_or_else(Vec::new, |pressed| {
+                vec![
+                    egui::Event::PointerMoved(row_center),
+                    egui::Event::PointerButton {
+                        pos: row_center,
+                        button: egui::PointerButton::Primary,
+                        pressed,
+                        modifiers: Default::default(),
+                    },
+                ]
+            });
+            let input = egui::RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let response = export_table().id(table_id).allow_selection(true).show(ui);
+                    row_center = response.row_responses[0].1.rect.center();
+                });
+            });
+        }
+
+        let table = export_table()
+            .allow_selection(true)
+            .export_selected_only(true)
+            .export_state(&DataTableState::load(&ctx, table_id));
+        assert_eq!(
+            parse_delimited(&table.to_csv(), ','),
+            vec![
+                vec!["Name", "Price"],
+                vec!["Widget, large", "$12.50"],
+                vec!["Two\nlines", "$7"],
+            ]
+        );
+    }
+}
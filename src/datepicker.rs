@@ -0,0 +1,745 @@
+//! Material Design 3 Date Picker
+//!
+//! Lets users pick a single date from a calendar.
+//!
+//! - [`MaterialDatePicker::show`] opens the modal picker inside a [`MaterialDialog`],
+//!   with Cancel and OK actions.
+//! - [`MaterialDatePicker::show_docked`] shows an outlined [`MaterialTextField`]
+//!   that accepts typed dates (`MM/DD/YYYY`); its calendar icon drops a calendar
+//!   down below the field.
+//!
+//! Dates are `(year, month, day)` tuples with 1-based months and days. Month
+//! lengths, leap years and weekdays are computed internally (proleptic
+//! Gregorian calendar, weeks starting on Sunday).
+//!
+//! # M3 Color Role Usage
+//!
+//! - **surfaceContainerHigh**: Docked calendar container
+//! - **onSurfaceVariant**: "Select date" label, month/year button, navigation icons
+//! - **onSurface**: Headline date, weekday labels, day and year numbers
+//! - **primary**: Selected day/year container; today's outline and number
+//! - **onPrimary**: Selected day/year number
+//! - **outlineVariant**: Divider below the modal headline
+//! - **State layers**: content color @ 8% (hover), ripple on press
+//!
+//! ## Dimensions
+//! - **Day cell**: up to 48dp, with a 40dp selected/today circle
+//! - **Year cell**: 72x36dp pill, three per row
+//! - **Modal width**: 360dp; docked calendar 360dp
+
+use crate::dialog::MaterialDialog;
+use crate::iconbutton::MaterialIconButton;
+use crate::material_symbol::material_symbol_text_or_default;
+use crate::ripple::Ripple;
+use crate::textfield::MaterialTextField;
+use crate::theme::{elevation_shadow, get_global_color, MaterialScrollAreaExt};
+use egui::{
+    pos2, vec2, Align, Align2, Context, FontId, Id, Key, Layout, Order, Rect, Sense, Stroke, Ui,
+    WidgetInfo, WidgetType,
+};
+use std::ops::RangeInclusive;
+
+/// A calendar date: `(year, month, day)`
+type Date = (i32, u32, u32);
+
+/// Largest day cell; cells shrink to fit narrower containers
+const MAX_DAY_CELL: f32 = 48.0;
+/// Diameter of the selected-day and today circles
+const DAY_CIRCLE: f32 = 40.0;
+/// Year pill size and the row height of the year grid
+const YEAR_PILL: egui::Vec2 = vec2(72.0, 36.0);
+const YEAR_ROW_HEIGHT: f32 = 52.0;
+/// Width of the modal dialog and the docked calendar
+const PICKER_WIDTH: f32 = 360.0;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// Name of `month`, clamped to 1..=12 since dates can come from the caller
+fn month_name(month: u32) -> &'static str {
+    MONTH_NAMES[month.clamp(1, 12) as usize - 1]
+}
+
+/// Calendar view state, kept in temp memory while the picker is open
+#[derive(Clone, Copy, Debug)]
+struct PickerState {
+    /// Month shown in the calendar
+    year: i32,
+    month: u32,
+    /// Day chosen in the modal calendar, committed by OK
+    pending: Option<Date>,
+    /// Whether the year grid replaces the day grid
+    choosing_year: bool,
+    /// Scroll the year grid to `year` on its first frame
+    scroll_to_year: bool,
+}
+
+impl PickerState {
+    fn new(selected: Option<Date>, today: Option<Date>, years: &RangeInclusive<i32>) -> Self {
+        let (year, month, _) = selected.or(today).unwrap_or((2000, 1, 1));
+        Self {
+            year: year.clamp(*years.start(), *years.end()),
+            month: month.clamp(1, 12),
+            pending: selected,
+            choosing_year: false,
+            scroll_to_year: false,
+        }
+    }
+}
+
+/// Material Design date picker.
+///
+/// # Example
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// let mut birthday: Option<(i32, u32, u32)> = None;
+/// let mut picker_open = false;
+///
+/// // Docked: a text field with a drop-down calendar
+/// MaterialDatePicker::new("birthday", &mut birthday)
+///     .label("Birthday")
+///     .show_docked(ui);
+///
+/// // Modal: a dialog with Cancel and OK
+/// if ui.button("Pick date").clicked() {
+///     picker_open = true;
+/// }
+/// if let Some((year, month, day)) =
+///     MaterialDatePicker::new("birthday_modal", &mut birthday).show(ui.ctx(), &mut picker_open)
+/// {
+///     println!("{year}-{month:02}-{day:02}");
+/// }
+/// # });
+/// ```
+pub struct MaterialDatePicker<'a> {
+    id: Id,
+    selected: &'a mut Option<(i32, u32, u32)>,
+    title: String,
+    label: String,
+    today: Option<Date>,
+    years: RangeInclusive<i32>,
+    width: f32,
+}
+
+impl<'a> MaterialDatePicker<'a> {
+    /// Create a date picker editing `selected`.
+    pub fn new(id: impl Into<Id>, selected: &'a mut Option<(i32, u32, u32)>) -> Self {
+        Self {
+            id: id.into(),
+            selected,
+            title: "Select date".to_string(),
+            label: "Date".to_string(),
+            today: None,
+            years: 1900..=2100,
+            width: PICKER_WIDTH,
+        }
+    }
+
+    /// Set the modal dialog title (default: "Select date").
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Set the docked text field label (default: "Date").
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Set the date marked as today.
+    ///
+    /// Defaults to the current UTC date from the system clock. On the web
+    /// there is no clock available, so no day is marked unless this is set.
+    pub fn today(mut self, year: i32, month: u32, day: u32) -> Self {
+        self.today = Some((year, month, day));
+        self
+    }
+
+    /// Limit the years that can be shown and picked (default: 1900–2100).
+    pub fn year_range(mut self, years: RangeInclusive<i32>) -> Self {
+        self.years = years;
+        self
+    }
+
+    /// Set the width of the docked text field (default: 360dp).
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Show the modal picker while `open` is true.
+    ///
+    /// The chosen day is only written to `selected` when OK is clicked.
+    ///
+    /// ## Returns
+    /// The date confirmed this frame, if any
+    pub fn show(self, ctx: &Context, open: &mut bool) -> Option<(i32, u32, u32)> {
+        let state_id = self.id.with("date_picker_state");
+        if !*open {
+            ctx.data_mut(|d| d.remove::<PickerState>(state_id));
+            return None;
+        }
+
+        let today = self.today.or_else(system_today);
+        let years = self.years.clone();
+        let mut state = ctx
+            .data(|d| d.get_temp::<PickerState>(state_id))
+            .unwrap_or_else(|| PickerState::new(*self.selected, today, &years));
+
+        let result = MaterialDialog::new(self.id, self.title.clone(), &mut *open)
+            .min_width(PICKER_WIDTH)
+            .max_width(PICKER_WIDTH)
+            .content(|ui| {
+                headline_ui(ui, state.pending);
+                let selected = state.pending;
+                if let Some(date) = calendar_ui(ui, self.id, &mut state, selected, today, years) {
+                    state.pending = Some(date);
+                }
+            })
+            .text_action("Cancel", || {})
+            .confirm_action("OK", || {})
+            .show(ctx);
+
+        let mut confirmed = None;
+        if result.confirmed {
+            if let Some(date) = state.pending {
+                *self.selected = Some(date);
+                confirmed = Some(date);
+            }
+        }
+        if *open {
+            ctx.data_mut(|d| d.insert_temp(state_id, state));
+        } else {
+            ctx.data_mut(|d| d.remove::<PickerState>(state_id));
+        }
+        confirmed
+    }
+
+    /// Show the docked picker: a text field with a drop-down calendar.
+    ///
+    /// Typed dates are written to `selected` as soon as they are valid;
+    /// clicking a day in the calendar picks it and closes the calendar.
+    ///
+    /// ## Returns
+    /// The date picked or typed this frame, if any
+    pub fn show_docked(self, ui: &mut Ui) -> Option<(i32, u32, u32)> {
+        let state_id = self.id.with("date_picker_state");
+        let text_id = self.id.with("date_picker_text");
+        let field_id = self.id.with("date_picker_field");
+        let today = self.today.or_else(system_today);
+
+        // The typed text, reset when `selected` was changed from outside
+        let mut text = ui
+            .data(|d| d.get_temp::<(String, Option<Date>)>(text_id))
+            .filter(|(_, date)| date == self.selected)
+            .map(|(text, _)| text)
+            .unwrap_or_else(|| self.selected.map(format_date).unwrap_or_default());
+
+        let invalid = !text.is_empty()
+            && parse_date(&text).is_none_or(|(year, _, _)| !self.years.contains(&year));
+        let mut field = MaterialTextField::outlined(&mut text)
+            .id(field_id)
+            .label(self.label.clone())
+            .placeholder("MM/DD/YYYY")
+            .trailing_icon("calendar_today")
+            .width(self.width);
+        if invalid && !ui.memory(|m| m.has_focus(field_id)) {
+            field = field.error_text("Invalid date");
+        }
+        let scope = ui.scope(|ui| field.show(ui));
+        let field_rect = scope.response.rect;
+        let field_response = scope.inner;
+
+        let mut picked = None;
+        if field_response.response.changed() {
+            match parse_date(&text).filter(|(year, _, _)| self.years.contains(year)) {
+                Some(date) => {
+                    if *self.selected != Some(date) {
+                        *self.selected = Some(date);
+                        picked = Some(date);
+                    }
+                }
+                None if text.is_empty() => *self.selected = None,
+                None => {}
+            }
+        }
+
+        let mut state = ui.data(|d| d.get_temp::<PickerState>(state_id));
+        if field_response.trailing_icon_clicked {
+            state = match state {
+                Some(_) => None,
+                None => Some(PickerState::new(*self.selected, today, &self.years)),
+            };
+        }
+
+        if let Some(mut calendar) = state {
+            let selected = *self.selected;
+            let years = self.years.clone();
+            let id = self.id;
+            let popup = egui::Area::new(self.id.with("date_picker_popup"))
+                .fixed_pos(pos2(field_rect.min.x, field_rect.max.y + 4.0))
+                .order(Order::Foreground)
+                .show(ui.ctx(), |ui| {
+                    egui::Frame::new()
+                        .fill(get_global_color("surfaceContainerHigh"))
+                        .corner_radius(16)
+                        .shadow(elevation_shadow(2))
+                        .inner_margin(12.0)
+                        .show(ui, |ui| {
+                            ui.set_width(PICKER_WIDTH - 24.0);
+                            calendar_ui(ui, id, &mut calendar, selected, today, years)
+                        })
+                        .inner
+                });
+
+            if let Some(date) = popup.inner {
+                *self.selected = Some(date);
+                text = format_date(date);
+                picked = Some(date);
+                state = None;
+            } else if (popup.response.clicked_elsewhere() && !field_response.trailing_icon_clicked)
+                || ui.input(|i| i.key_pressed(Key::Escape))
+            {
+                state = None;
+            } else {
+                state = Some(calendar);
+            }
+        }
+
+        ui.data_mut(|d| {
+            match state {
+                Some(state) => d.insert_temp(state_id, state),
+                None => d.remove::<PickerState>(state_id),
+            }
+            d.insert_temp(text_id, (text, *self.selected));
+        });
+        picked
+    }
+}
+
+/// The modal headline: the pending date, e.g. "Mon, Aug 17"
+fn headline_ui(ui: &mut Ui, date: Option<Date>) {
+    let (text, color) = match date {
+        Some((year, month, day)) => (
+            format!(
+                "{}, {} {}",
+                WEEKDAY_NAMES[weekday(year, month, day) as usize],
+                &month_name(month)[..3],
+                day
+            ),
+            get_global_color("onSurface"),
+        ),
+        None => ("No date".to_string(), get_global_color("onSurfaceVariant")),
+    };
+    ui.label(egui::RichText::new(text).size(32.0).color(color));
+    ui.add_space(12.0);
+    let (rect, _) = ui.allocate_exact_size(vec2(ui.available_width(), 1.0), Sense::hover());
+    ui.painter().hline(
+        rect.x_range(),
+        rect.center().y,
+        Stroke::new(1.0, get_global_color("outlineVariant")),
+    );
+    ui.add_space(8.0);
+}
+
+/// Month/year navigation plus the day or year grid.
+///
+/// Returns the day clicked this frame.
+fn calendar_ui(
+    ui: &mut Ui,
+    id: Id,
+    state: &mut PickerState,
+    selected: Option<Date>,
+    today: Option<Date>,
+    years: RangeInclusive<i32>,
+) -> Option<Date> {
+    let on_surface_variant = get_global_color("onSurfaceVariant");
+    let first_month = (*years.start(), 1);
+    let last_month = (*years.end(), 12);
+
+    ui.horizontal(|ui| {
+        // Month and year; opens the year grid
+        let text = format!("{} {}", month_name(state.month), state.year);
+        let arrow = if state.choosing_year {
+            "arrow_drop_up"
+        } else {
+            "arrow_drop_down"
+        };
+        let galley = ui.painter().layout_no_wrap(
+            text.clone(),
+            FontId::proportional(14.0),
+            on_surface_variant,
+        );
+        let size = vec2(galley.size().x + 48.0, 40.0);
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, true, &text));
+        Ripple::new(on_surface_variant)
+            .corner_radius(20.0)
+            .show(ui, rect, &response);
+        ui.painter().galley(
+            pos2(rect.min.x + 12.0, rect.center().y - galley.size().y / 2.0),
+            galley,
+            on_surface_variant,
+        );
+        ui.painter().text(
+            pos2(rect.max.x - 20.0, rect.center().y),
+            Align2::CENTER_CENTER,
+            material_symbol_text_or_default(arrow, "▾"),
+            FontId::proportional(18.0),
+            on_surface_variant,
+        );
+        if response.clicked() {
+            state.choosing_year = !state.choosing_year;
+            state.scroll_to_year = state.choosing_year;
+        }
+
+        if !state.choosing_year {
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                let next = add_months(state.year, state.month, 1);
+                let previous = add_months(state.year, state.month, -1);
+                if ui
+                    .add(MaterialIconButton::standard("chevron_right").enabled(next <= last_month))
+                    .clicked()
+                {
+                    (state.year, state.month) = next;
+                }
+                if ui
+                    .add(
+                        MaterialIconButton::standard("chevron_left")
+                            .enabled(previous >= first_month),
+                    )
+                    .clicked()
+                {
+                    (state.year, state.month) = previous;
+                }
+            });
+        }
+    });
+
+    let cell = (ui.available_width() / 7.0).min(MAX_DAY_CELL);
+    if state.choosing_year {
+        year_grid_ui(ui, id, state, today, years, cell * 7.0);
+        None
+    } else {
+        day_grid_ui(ui, id, state, selected, today, cell)
+    }
+}
+
+/// Weekday header and the days of `state`'s month
+fn day_grid_ui(
+    ui: &mut Ui,
+    id: Id,
+    state: &PickerState,
+    selected: Option<Date>,
+    today: Option<Date>,
+    cell: f32,
+) -> Option<Date> {
+    let on_surface = get_global_color("onSurface");
+    let primary = get_global_color("primary");
+    let on_primary = get_global_color("onPrimary");
+
+    // A header row and up to six weeks
+    let (grid_rect, _) = ui.allocate_exact_size(vec2(cell * 7.0, cell * 7.0), Sense::hover());
+    let cell_rect = |row: u32, column: u32| {
+        Rect::from_min_size(
+            grid_rect.min + vec2(column as f32 * cell, row as f32 * cell),
+            vec2(cell, cell),
+        )
+    };
+
+    for (column, name) in WEEKDAY_NAMES.iter().enumerate() {
+        ui.painter().text(
+            cell_rect(0, column as u32).center(),
+            Align2::CENTER_CENTER,
+            &name[..1],
+            FontId::proportional(16.0),
+            on_surface,
+        );
+    }
+
+    let offset = weekday(state.year, state.month, 1);
+    let mut clicked = None;
+    for day in 1..=days_in_month(state.year, state.month) {
+        let index = offset + day - 1;
+        let rect = cell_rect(index / 7 + 1, index % 7);
+        let date = (state.year, state.month, day);
+        let is_selected = selected == Some(date);
+        let is_today = today == Some(date);
+
+        let response = ui.interact(rect, id.with(("day", date)), Sense::click());
+        response.widget_info(|| {
+            WidgetInfo::selected(WidgetType::Button, true, is_selected, day.to_string())
+        });
+        let circle = Rect::from_center_size(rect.center(), vec2(DAY_CIRCLE, DAY_CIRCLE));
+        let text_color = if is_selected {
+            ui.painter()
+                .circle_filled(circle.center(), DAY_CIRCLE / 2.0, primary);
+            on_primary
+        } else if is_today {
+            ui.painter().circle_stroke(
+                circle.center(),
+                DAY_CIRCLE / 2.0 - 0.5,
+                Stroke::new(1.0, primary),
+            );
+            primary
+        } else {
+            on_surface
+        };
+        Ripple::new(text_color)
+            .corner_radius(DAY_CIRCLE / 2.0)
+            .show(ui, circle, &response);
+        ui.painter().text(
+            rect.center(),
+            Align2::CENTER_CENTER,
+            day.to_string(),
+            FontId::proportional(16.0),
+            text_color,
+        );
+
+        if response.clicked() {
+            clicked = Some(date);
+        }
+    }
+    clicked
+}
+
+/// Scrollable grid of the years in `years`; picking one returns to the day grid
+fn year_grid_ui(
+    ui: &mut Ui,
+    id: Id,
+    state: &mut PickerState,
+    today: Option<Date>,
+    years: RangeInclusive<i32>,
+    height: f32,
+) {
+    let on_surface_variant = get_global_color("onSurfaceVariant");
+    let primary = get_global_color("primary");
+    let on_primary = get_global_color("onPrimary");
+    let width = ui.available_width();
+    let years: Vec<i32> = years.collect();
+
+    egui::ScrollArea::vertical()
+        .id_salt(id.with("years"))
+        .max_height(height)
+        .min_scrolled_height(height)
+        .show_material(ui, |ui| {
+            for row in years.chunks(3) {
+                let (row_rect, _) =
+                    ui.allocate_exact_size(vec2(width, YEAR_ROW_HEIGHT), Sense::hover());
+                if state.scroll_to_year && row.contains(&state.year) {
+                    ui.scroll_to_rect(row_rect, Some(Align::Center));
+                    state.scroll_to_year = false;
+                }
+                for (column, &year) in row.iter().enumerate() {
+                    let center = pos2(
+                        row_rect.min.x + (column as f32 + 0.5) * width / 3.0,
+                        row_rect.center().y,
+                    );
+                    let rect = Rect::from_center_size(center, YEAR_PILL);
+                    let response = ui.interact(rect, id.with(("year", year)), Sense::click());
+                    let is_selected = year == state.year;
+                    response.widget_info(|| {
+                        WidgetInfo::selected(
+                            WidgetType::Button,
+                            true,
+                            is_selected,
+                            year.to_string(),
+                        )
+                    });
+
+                    let text_color = if is_selected {
+                        ui.painter().rect_filled(rect, YEAR_PILL.y / 2.0, primary);
+                        on_primary
+                    } else if today.is_some_and(|(today_year, _, _)| today_year == year) {
+                        ui.painter().rect_stroke(
+                            rect,
+                            YEAR_PILL.y / 2.0,
+                            Stroke::new(1.0, primary),
+                            egui::epaint::StrokeKind::Inside,
+                        );
+                        primary
+                    } else {
+                        on_surface_variant
+                    };
+                    Ripple::new(text_color)
+                        .corner_radius(YEAR_PILL.y / 2.0)
+                        .show(ui, rect, &response);
+                    ui.painter().text(
+                        center,
+                        Align2::CENTER_CENTER,
+                        year.to_string(),
+                        FontId::proportional(16.0),
+                        text_color,
+                    );
+
+                    if response.clicked() {
+                        state.year = year;
+                        state.choosing_year = false;
+                    }
+                }
+            }
+        });
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Month `delta` months away from `year`/`month`
+fn add_months(year: i32, month: u32, delta: i32) -> (i32, u32) {
+    let index = year * 12 + month as i32 - 1 + delta;
+    (index.div_euclid(12), index.rem_euclid(12) as u32 + 1)
+}
+
+/// Days since 1970-01-01 (Howard Hinnant's `days_from_civil`)
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    // Count years from March so the leap day ends the year
+    let year = i64::from(if month <= 2 { year - 1 } else { year });
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let shifted_month = i64::from(if month > 2 { month - 3 } else { month + 9 });
+    let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Inverse of [`days_from_civil`]
+fn civil_from_days(days: i64) -> Date {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as i32, month as u32, day)
+}
+
+/// Day of the week, 0 = Sunday
+fn weekday(year: i32, month: u32, day: u32) -> u32 {
+    // 1970-01-01 was a Thursday
+    (days_from_civil(year, month, day) + 4).rem_euclid(7) as u32
+}
+
+/// Today's UTC date from the system clock
+fn system_today() -> Option<Date> {
+    if cfg!(target_arch = "wasm32") {
+        // `SystemTime::now` panics on the web
+        return None;
+    }
+    let since_epoch = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?;
+    Some(civil_from_days((since_epoch.as_secs() / 86_400) as i64))
+}
+
+fn format_date((year, month, day): Date) -> String {
+    format!("{month:02}/{day:02}/{year:04}")
+}
+
+/// Parse `MM/DD/YYYY`, rejecting days that don't exist
+fn parse_date(text: &str) -> Option<Date> {
+    let mut parts = text.trim().split('/');
+    let month: u32 = parts.next()?.trim().parse().ok()?;
+    let day: u32 = parts.next()?.trim().parse().ok()?;
+    let year: i32 = parts.next()?.trim().parse().ok()?;
+    if parts.next().is_some()
+        || !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+    {
+        return None;
+    }
+    Some((year, month, day))
+}
+
+/// Convenience function to create a date picker.
+pub fn date_picker<'a>(
+    id: impl Into<Id>,
+    selected: &'a mut Option<(i32, u32, u32)>,
+) -> MaterialDatePicker<'a> {
+    MaterialDatePicker::new(id, selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calendar_math() {
+        assert!(is_leap_year(2024) && is_leap_year(2000));
+        assert!(!is_leap_year(2023) && !is_leap_year(1900));
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2023, 4), 30);
+        assert_eq!(days_in_month(2023, 12), 31);
+
+        assert_eq!(weekday(1970, 1, 1), 4);
+        assert_eq!(weekday(2024, 1, 1), 1);
+        assert_eq!(weekday(2000, 2, 29), 2);
+        for date in [(1969, 12, 31), (2000, 2, 29), (2100, 3, 1)] {
+            assert_eq!(
+                civil_from_days(days_from_civil(date.0, date.1, date.2)),
+                date
+            );
+        }
+
+        assert_eq!(add_months(2024, 1, -1), (2023, 12));
+        assert_eq!(add_months(2024, 12, 1), (2025, 1));
+
+        assert_eq!(parse_date("02/29/2024"), Some((2024, 2, 29)));
+        assert_eq!(parse_date("02/29/2023"), None);
+        assert_eq!(parse_date("13/01/2023"), None);
+        assert_eq!(format_date((2024, 2, 9)), "02/09/2024");
+    }
+
+    #[test]
+    fn test_out_of_range_month_does_not_panic() {
+        assert_eq!(month_name(0), "January");
+        assert_eq!(month_name(13), "December");
+
+        let years = 1900..=2100;
+        assert_eq!(PickerState::new(Some((2024, 0, 5)), None, &years).month, 1);
+        assert_eq!(
+            PickerState::new(None, Some((2024, 14, 1)), &years).month,
+            12
+        );
+
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                headline_ui(ui, Some((2024, 0, 5)));
+                headline_ui(ui, Some((2024, 13, 1)));
+            });
+        });
+    }
+}
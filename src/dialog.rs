@@ -13,15 +13,153 @@
 //! - **Filled Tonal button**: secondaryContainer background, onSecondaryContainer text (medium emphasis)
 //! - **Filled button**: primary background, onPrimary text (high emphasis - primary action)
 //!
+//! ## Full-screen Dialogs
+//! - **surface**: Container background (edge-to-edge, no corner radius)
+//! - **onSurface**: Close icon and title in the 64dp top app bar
+//! - **primary**: Confirm action text
+//! - **outlineVariant**: Dividers around scrollable content (basic dialogs)
+//!
 //! ## Dimensions
 //! - **Max width**: 560dp, **Min width**: 280dp
-//! - **Corner radius**: 28dp
+//! - **Corner radius**: 28dp (the extra-large shape token)
 //! - **Title padding**: 24dp horizontal
 //! - **Content padding**: 24dp horizontal, 16dp vertical
 //! - **Actions padding**: 24dp all sides, 8dp spacing between buttons
 
 use crate::get_global_color;
-use egui::{self, Color32, Context, Id, Modal, Response, Sense, Stroke, Ui, Vec2};
+use crate::theme::{
+    dismiss_on_escape, shape_radius, type_font_id, FocusTrap, MaterialScrollAreaExt, Shape,
+    TypeRole,
+};
+use egui::{self, accesskit, Color32, Context, Id, Modal, Response, Sense, Stroke, Ui, Vec2};
+
+/// Height of the top app bar in full-screen dialogs
+const FULL_SCREEN_BAR_HEIGHT: f32 = 64.0;
+
+/// Announce the dialog to screen readers as a modal dialog named by its title
+fn describe_dialog(ctx: &Context, response: &Response, title: &str) {
+    ctx.accesskit_node_builder(response.id, |node| {
+        node.set_role(accesskit::Role::Dialog);
+        node.set_label(title);
+        node.set_modal();
+    });
+}
+
+/// Scrim behind a dialog; only the topmost dialog darkens what is below it
+fn scrim_color(is_top: bool) -> Color32 {
+    if is_top {
+        get_global_color("scrim").gamma_multiply(0.32)
+    } else {
+        Color32::TRANSPARENT
+    }
+}
+
+/// Drop per-dialog state so the dialog opens fresh next time
+fn forget_dialog_state(ctx: &Context, dialog_id: Id) {
+    DialogStack::remove(ctx, dialog_id);
+    FocusTrap::remove(ctx, dialog_id.with("focus_trap"));
+    ctx.data_mut(|d| {
+        d.remove::<Vec2>(dialog_id.with("drag_offset"));
+    });
+}
+
+/// The stack of open dialogs, bottom first.
+///
+/// Every [`MaterialDialog`] registers itself while it is shown. Only the
+/// topmost dialog draws a scrim and closes on Escape or a click outside, so a
+/// "Discard changes?" confirmation opened over an edit dialog darkens the app
+/// once and dismisses on its own.
+///
+/// Custom overlays can take part with [`DialogStack::push`] and
+/// [`DialogStack::pop`]; they stay on the stack until popped or removed.
+///
+/// # Example
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// let ctx = ui.ctx();
+/// DialogStack::push(ctx, egui::Id::new("my_overlay"));
+/// assert_eq!(DialogStack::top(ctx), Some(egui::Id::new("my_overlay")));
+/// DialogStack::pop(ctx);
+/// # });
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DialogStack {
+    /// Open dialogs, bottom first. Dialogs registered by [`MaterialDialog`]
+    /// carry the pass they were last shown in; pushed overlays carry `None`.
+    entries: Vec<(Id, Option<u64>)>,
+}
+
+impl DialogStack {
+    fn storage_id() -> Id {
+        Id::new("material_dialog_stack")
+    }
+
+    fn load(ctx: &Context) -> Self {
+        ctx.data(|d| d.get_temp::<Self>(Self::storage_id()))
+            .unwrap_or_default()
+    }
+
+    fn store(self, ctx: &Context) {
+        ctx.data_mut(|d| d.insert_temp(Self::storage_id(), self));
+    }
+
+    /// Put `id` on top of the stack, moving it there if it is already open.
+    pub fn push(ctx: &Context, id: Id) {
+        let mut stack = Self::load(ctx);
+        stack.entries.retain(|(entry, _)| *entry != id);
+        stack.entries.push((id, None));
+        stack.store(ctx);
+    }
+
+    /// Remove and return the topmost dialog.
+    pub fn pop(ctx: &Context) -> Option<Id> {
+        let mut stack = Self::load(ctx);
+        let top = stack.entries.pop().map(|(id, _)| id);
+        stack.store(ctx);
+        top
+    }
+
+    /// Remove `id` wherever it is in the stack.
+    pub fn remove(ctx: &Context, id: Id) {
+        let mut stack = Self::load(ctx);
+        stack.entries.retain(|(entry, _)| *entry != id);
+        stack.store(ctx);
+    }
+
+    /// The topmost open dialog, if any.
+    pub fn top(ctx: &Context) -> Option<Id> {
+        Self::load(ctx).entries.last().map(|(id, _)| *id)
+    }
+
+    /// Number of open dialogs.
+    pub fn len(ctx: &Context) -> usize {
+        Self::load(ctx).entries.len()
+    }
+
+    /// Whether no dialog is open.
+    pub fn is_empty(ctx: &Context) -> bool {
+        Self::len(ctx) == 0
+    }
+
+    /// Register `id` as shown this pass, returning whether it is the topmost dialog.
+    ///
+    /// Dialogs that were not shown in the previous pass are dropped, so a
+    /// dialog closed by clearing its `open` flag never blocks the stack.
+    fn touch(ctx: &Context, id: Id) -> bool {
+        let pass = ctx.cumulative_pass_nr();
+        let mut stack = Self::load(ctx);
+        stack
+            .entries
+            .retain(|(_, shown)| shown.is_none_or(|shown| shown + 1 >= pass));
+        match stack.entries.iter_mut().find(|(entry, _)| *entry == id) {
+            Some(entry) => entry.1 = Some(pass),
+            None => stack.entries.push((id, Some(pass))),
+        }
+        let is_top = stack.entries.last().is_some_and(|(entry, _)| *entry == id);
+        stack.store(ctx);
+        is_top
+    }
+}
 
 /// Material Design dialog types following Material Design 3 specifications
 #[derive(Clone, Copy, PartialEq)]
@@ -105,6 +243,24 @@ pub struct MaterialDialog<'a> {
     scrollable: bool,
     /// Spacing between action buttons (default: 8dp)
     actions_spacing: f32,
+    /// Whether the dialog covers the whole screen with a top app bar
+    full_screen: bool,
+    /// Whether Escape, a scrim click or the close icon dismiss the dialog
+    dismissible: bool,
+    /// Whether the dialog can be moved by dragging its title
+    draggable: bool,
+    /// Shape token for the container corners (basic dialogs only)
+    shape: Shape,
+}
+
+/// Outcome of showing a [`MaterialDialog`] for one frame
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DialogResult {
+    /// A confirming action was clicked this frame (see [`MaterialDialog::confirm_action`])
+    pub confirmed: bool,
+    /// The dialog was closed without confirming: Escape, a scrim click,
+    /// the full-screen close icon, or a non-confirming action
+    pub dismissed: bool,
 }
 
 /// Represents an action button in a Material Design dialog
@@ -115,6 +271,8 @@ pub struct DialogAction<'a> {
     action_type: ActionType,
     /// Whether the action is currently enabled
     _enabled: bool,
+    /// Whether clicking the action reports [`DialogResult::confirmed`]
+    confirms: bool,
     /// Callback function executed when action is triggered
     action: Box<dyn FnOnce() + 'a>,
 }
@@ -160,9 +318,31 @@ impl<'a> MaterialDialog<'a> {
             button_padding: None,
             scrollable: false,
             actions_spacing: 8.0,
+            full_screen: false,
+            dismissible: true,
+            draggable: false,
+            shape: Shape::ExtraLarge,
         }
     }
 
+    /// Create a full-screen dialog
+    ///
+    /// Full-screen dialogs cover the whole window and replace the title and
+    /// action rows with a top app bar: a close icon, the title, and the
+    /// dialog's actions as text buttons at the trailing end. Use them for
+    /// mobile-style flows such as composing or editing an item.
+    ///
+    /// ## Parameters
+    /// - `id`: Unique identifier for the dialog (used for egui state)
+    /// - `title`: Title text displayed in the top app bar
+    /// - `open`: Mutable reference to boolean controlling dialog visibility
+    pub fn full_screen(id: impl Into<Id>, title: impl Into<String>, open: &'a mut bool) -> Self {
+        let mut dialog = Self::new(id, title, open);
+        dialog.full_screen = true;
+        dialog.scrollable = true;
+        dialog
+    }
+
     /// Set the dialog type (affects styling and behavior)
     ///
     /// ## Parameters
@@ -238,6 +418,15 @@ impl<'a> MaterialDialog<'a> {
         self
     }
 
+    /// Set the corner radius of a basic dialog from an M3 shape token
+    ///
+    /// Dialogs are [`Shape::ExtraLarge`] by default. Full-screen dialogs
+    /// always have square corners.
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.shape = shape;
+        self
+    }
+
     /// Set the minimum width constraint for the dialog
     ///
     /// ## Parameters
@@ -322,6 +511,46 @@ impl<'a> MaterialDialog<'a> {
         self
     }
 
+    /// Place long content in a scroll area between dividers
+    ///
+    /// Equivalent to `.scrollable(true)`.
+    ///
+    /// ## Returns
+    /// Self for method chaining
+    pub fn scrollable_content(self) -> Self {
+        self.scrollable(true)
+    }
+
+    /// Set whether the dialog can be dismissed without an action
+    ///
+    /// When false, Escape, clicking the scrim and the full-screen close icon
+    /// no longer close the dialog; only its actions do.
+    ///
+    /// ## Parameters
+    /// - `dismissible`: If false, the dialog stays open until an action is clicked (default: true)
+    ///
+    /// ## Returns
+    /// Self for method chaining
+    pub fn dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
+    }
+
+    /// Set whether the dialog can be moved by dragging its title
+    ///
+    /// Useful on desktop where a dialog may cover content the user needs to see.
+    /// Has no effect on full-screen dialogs.
+    ///
+    /// ## Parameters
+    /// - `draggable`: If true, dragging the title moves the dialog
+    ///
+    /// ## Returns
+    /// Self for method chaining
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
     /// Set the spacing between action buttons
     ///
     /// ## Parameters
@@ -350,6 +579,7 @@ impl<'a> MaterialDialog<'a> {
             text: text.into(),
             action_type: ActionType::Text,
             _enabled: true,
+            confirms: false,
             action: Box::new(action),
         });
         self
@@ -371,6 +601,7 @@ impl<'a> MaterialDialog<'a> {
             text: text.into(),
             action_type: ActionType::FilledTonal,
             _enabled: true,
+            confirms: false,
             action: Box::new(action),
         });
         self
@@ -392,6 +623,33 @@ impl<'a> MaterialDialog<'a> {
             text: text.into(),
             action_type: ActionType::Filled,
             _enabled: true,
+            confirms: true,
+            action: Box::new(action),
+        });
+        self
+    }
+
+    /// Add a text action button that confirms the dialog
+    ///
+    /// Like [`text_action`](Self::text_action), but clicking it reports
+    /// [`DialogResult::confirmed`] instead of `dismissed`. Filled actions
+    /// always confirm.
+    ///
+    /// ## Parameters
+    /// - `text`: The text label for the button
+    /// - `action`: A closure that is called when the button is clicked
+    ///
+    /// ## Returns
+    /// Self for method chaining
+    pub fn confirm_action<F>(mut self, text: impl Into<String>, action: F) -> Self
+    where
+        F: FnOnce() + 'a,
+    {
+        self.actions.push(DialogAction {
+            text: text.into(),
+            action_type: ActionType::Text,
+            _enabled: true,
+            confirms: true,
             action: Box::new(action),
         });
         self
@@ -439,14 +697,23 @@ impl<'a> MaterialDialog<'a> {
     ///
     /// ## Behavior
     /// - The dialog will be displayed as an overlay, blocking interaction with other windows
-    /// - Clicking outside the dialog or pressing the escape key will close the dialog
+    /// - Clicking outside the dialog or pressing the escape key will close the dialog,
+    ///   unless it was made non-[`dismissible`](Self::dismissible)
     /// - Action buttons will execute their associated actions when clicked
-    pub fn show(mut self, ctx: &Context) {
+    /// - Keyboard focus stays inside the dialog unless `no_focus_trap` is set
+    /// - Screen readers announce it as a modal dialog named by its title
+    ///
+    /// ## Returns
+    /// Whether the dialog was confirmed or dismissed this frame
+    pub fn show(mut self, ctx: &Context) -> DialogResult {
         if !*self.open {
-            return;
+            return DialogResult::default();
+        }
+        let is_top = DialogStack::touch(ctx, self.id);
+        if self.full_screen {
+            return self.show_full_screen(ctx, is_top);
         }
 
-        let mut should_close = false;
         let mut pending_actions = Vec::new();
 
         // Extract values we need before moving into closure
@@ -468,7 +735,14 @@ impl<'a> MaterialDialog<'a> {
         let title = self.title.clone();
         let icon = self.icon.clone();
         let actions = std::mem::take(&mut self.actions);
-        let open_ref = self.open as *mut bool;
+        let dialog_id = self.id;
+        let draggable = self.draggable;
+        let drag_offset_id = self.id.with("drag_offset");
+        let mut drag_offset = if draggable {
+            ctx.data(|d| d.get_temp::<Vec2>(drag_offset_id)).unwrap_or_default()
+        } else {
+            Vec2::ZERO
+        };
         
         let title_padding = self.title_padding;
         let content_padding = self.content_padding;
@@ -481,12 +755,17 @@ impl<'a> MaterialDialog<'a> {
         let modal_frame = egui::Frame::default()
             .inner_margin(egui::vec2(0.0, 24.0))
             .fill(get_global_color("surfaceContainerHigh"))
-            .corner_radius(egui::CornerRadius::same(28))
+            .corner_radius(shape_radius(self.shape))
             .stroke(Stroke::NONE);
         
+        let modal_area =
+            Modal::default_area(self.id).anchor(egui::Align2::CENTER_CENTER, drag_offset);
+        let focus_trap = FocusTrap::new(self.id.with("focus_trap")).enabled(!self.no_focus_trap);
         let modal = Modal::new(self.id)
+            .area(modal_area)
+            .backdrop_color(scrim_color(is_top))
             .frame(modal_frame)
-            .show(ctx, |ui| {
+            .show(ctx, |ui| focus_trap.show(ui, |ui| {
             ui.set_min_width(dialog_min_width);
             ui.set_max_width(dialog_max_width);
             // Only set max_height for scrollable dialogs to avoid empty space at bottom
@@ -530,7 +809,7 @@ impl<'a> MaterialDialog<'a> {
                 let [title_left, title_right, _title_top, title_bottom] = 
                     title_padding.unwrap_or([24.0, 24.0, 0.0, 0.0]);
                 
-                ui.horizontal(|ui| {
+                let title_response = ui.horizontal(|ui| {
                     ui.add_space(title_left);
                     // Center title if there's an icon
                     let layout = if icon.is_some() {
@@ -541,14 +820,28 @@ impl<'a> MaterialDialog<'a> {
                     ui.with_layout(layout, |ui| {
                         ui.label(
                             egui::RichText::new(&title)
-                                .size(24.0)
-                                .color(on_surface)
-                                .family(egui::FontFamily::Proportional),
+                                .font(type_font_id(TypeRole::HeadlineSmall))
+                                .color(on_surface),
                         );
                     });
                     ui.add_space(title_right);
                 });
 
+                // Dragging the title moves the dialog
+                if draggable {
+                    let drag_response = ui.interact(
+                        title_response.response.rect,
+                        dialog_id.with("title_drag"),
+                        Sense::drag(),
+                    );
+                    if drag_response.dragged() {
+                        drag_offset += drag_response.drag_delta();
+                        ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+                    } else if drag_response.hovered() {
+                        ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+                    }
+                }
+
                 ui.add_space(if title_bottom > 0.0 { title_bottom } else { 16.0 });
 
                 // Content area with optional scrolling and custom padding
@@ -556,9 +849,12 @@ impl<'a> MaterialDialog<'a> {
                     content_padding.unwrap_or([24.0, 24.0, 0.0, 24.0]);
                 
                 if scrollable {
-                    // Scrollable content - use fixed width area
+                    // Scrollable content - use fixed width area between M3 dividers
                     let scroll_width = ui.available_width() - content_left - content_right;
                     let scroll_height = ui.available_height() - content_bottom;
+                    let divider = Stroke::new(1.0, get_global_color("outlineVariant"));
+                    let divider_x = ui.max_rect().x_range();
+                    ui.painter().hline(divider_x, ui.cursor().top(), divider);
                     
                     ui.horizontal(|ui| {
                         ui.add_space(content_left);
@@ -571,7 +867,7 @@ impl<'a> MaterialDialog<'a> {
                                 egui::ScrollArea::vertical()
                                     .id_salt("dialog_content_scroll")
                                     .auto_shrink([false, false])
-                                    .show(ui, |ui| {
+                                    .show_material(ui, |ui| {
                                         ui.set_width(scroll_width - 20.0); // Account for scrollbar
                                         ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Wrap);
                                         if content_top > 0.0 {
@@ -584,6 +880,7 @@ impl<'a> MaterialDialog<'a> {
                         
                         ui.add_space(content_right);
                     });
+                    ui.painter().hline(divider_x, ui.cursor().top(), divider);
                 } else {
                     // Non-scrollable content - render directly with width constraint
                     let content_width = ui.available_width() - content_left - content_right;
@@ -625,7 +922,7 @@ impl<'a> MaterialDialog<'a> {
                             let button_response = Self::draw_action_button_static(ui, &action, button_padding);
 
                             if button_response.clicked() {
-                                pending_actions.push((index, action.action));
+                                pending_actions.push((action.confirms, action.action));
                             }
 
                             if index > 0 {
@@ -638,20 +935,171 @@ impl<'a> MaterialDialog<'a> {
                     // Bottom padding now handled by Modal frame margin
                 }
             });
-        });
+        }));
+
+        if draggable {
+            ctx.data_mut(|d| d.insert_temp(drag_offset_id, drag_offset));
+        }
+        describe_dialog(ctx, &modal.response, &self.title);
 
         // Execute pending actions
-        for (_index, action) in pending_actions {
+        let mut result = DialogResult::default();
+        for (confirms, action) in pending_actions {
             action();
-            should_close = true;
+            if confirms {
+                result.confirmed = true;
+            } else {
+                result.dismissed = true;
+            }
         }
 
         // Handle modal close events (escape key, click outside, etc.)
-        if modal.should_close() || should_close {
-            unsafe {
-                *open_ref = false;
+        if !result.confirmed
+            && self.dismissible
+            && is_top
+            && (modal.backdrop_response.clicked() || dismiss_on_escape(ctx))
+        {
+            result.dismissed = true;
+        }
+        if result.confirmed || result.dismissed {
+            *self.open = false;
+            forget_dialog_state(ctx, self.id);
+        }
+        result
+    }
+
+    /// Render the full-screen variant: a top app bar with a close icon, the
+    /// title and the actions, above the (scrollable) content.
+    fn show_full_screen(mut self, ctx: &Context, is_top: bool) -> DialogResult {
+        let screen_rect = ctx.content_rect();
+        let actions = std::mem::take(&mut self.actions);
+        let mut pending_actions = Vec::new();
+        let mut close_clicked = false;
+
+        let surface = get_global_color("surface");
+        let on_surface = get_global_color("onSurface");
+        let on_surface_variant = get_global_color("onSurfaceVariant");
+
+        let modal_frame = egui::Frame::default()
+            .fill(surface)
+            .corner_radius(egui::CornerRadius::ZERO)
+            .stroke(Stroke::NONE);
+
+        let modal = Modal::new(self.id)
+            .backdrop_color(scrim_color(is_top))
+            .frame(modal_frame)
+            .show(ctx, |ui| {
+                FocusTrap::new(self.id.with("focus_trap"))
+                    .enabled(!self.no_focus_trap)
+                    .show(ui, |ui| {
+                        ui.set_min_size(screen_rect.size());
+                        ui.set_max_size(screen_rect.size());
+                        ui.spacing_mut().item_spacing.y = 0.0;
+
+                        // Top app bar: close icon, title, trailing actions
+                        let (bar_rect, _) = ui.allocate_exact_size(
+                            Vec2::new(screen_rect.width(), FULL_SCREEN_BAR_HEIGHT),
+                            Sense::hover(),
+                        );
+                        ui.scope_builder(egui::UiBuilder::new().max_rect(bar_rect), |ui| {
+                            ui.horizontal_centered(|ui| {
+                                ui.add_space(4.0);
+                                let (close_rect, close_response) =
+                                    ui.allocate_exact_size(Vec2::splat(48.0), Sense::click());
+                                if close_response.hovered() && self.dismissible {
+                                    ui.painter().circle_filled(
+                                        close_rect.center(),
+                                        20.0,
+                                        on_surface_variant.linear_multiply(0.08),
+                                    );
+                                }
+                                let close_color = if self.dismissible {
+                                    on_surface
+                                } else {
+                                    on_surface.linear_multiply(0.38)
+                                };
+                                ui.painter().text(
+                                    close_rect.center(),
+                                    egui::Align2::CENTER_CENTER,
+                                    crate::material_symbol::material_symbol_text("close"),
+                                    egui::FontId::proportional(24.0),
+                                    close_color,
+                                );
+                                close_clicked = close_response.clicked();
+
+                                ui.add_space(8.0);
+                                ui.label(
+                                    egui::RichText::new(&self.title)
+                                        .font(type_font_id(TypeRole::TitleLarge))
+                                        .color(on_surface),
+                                );
+
+                                ui.with_layout(
+                                    egui::Layout::right_to_left(egui::Align::Center),
+                                    |ui| {
+                                        ui.add_space(12.0);
+                                        for mut action in actions.into_iter().rev() {
+                                            action.action_type = ActionType::Text;
+                                            let response = Self::draw_action_button_static(
+                                                ui,
+                                                &action,
+                                                self.button_padding,
+                                            );
+                                            ui.add_space(self.actions_spacing);
+                                            if response.clicked() {
+                                                pending_actions
+                                                    .push((action.confirms, action.action));
+                                            }
+                                        }
+                                    },
+                                );
+                            });
+                        });
+
+                        let [content_left, content_right, content_top, content_bottom] =
+                            self.content_padding.unwrap_or([24.0, 24.0, 16.0, 24.0]);
+                        egui::ScrollArea::vertical()
+                            .id_salt("dialog_content_scroll")
+                            .auto_shrink([false, false])
+                            .show_material(ui, |ui| {
+                                egui::Frame::new()
+                                    .inner_margin(egui::Margin {
+                                        left: content_left as i8,
+                                        right: content_right as i8,
+                                        top: content_top as i8,
+                                        bottom: content_bottom as i8,
+                                    })
+                                    .show(ui, |ui| {
+                                        ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Wrap);
+                                        (self.content)(ui);
+                                    });
+                            });
+                    });
+            });
+
+        describe_dialog(ctx, &modal.response, &self.title);
+
+        let mut result = DialogResult::default();
+        for (confirms, action) in pending_actions {
+            action();
+            if confirms {
+                result.confirmed = true;
+            } else {
+                result.dismissed = true;
             }
         }
+        if !result.confirmed
+            && self.dismissible
+            && (close_clicked
+                || (is_top && (modal.backdrop_response.clicked() || dismiss_on_escape(ctx))))
+        {
+            result.dismissed = true;
+        }
+        if result.confirmed || result.dismissed {
+            *self.open = false;
+            forget_dialog_state(ctx, self.id);
+        }
+        result
     }
 
     fn draw_action_button_static(ui: &mut Ui, action: &DialogAction, button_padding: Option<[f32; 2]>) -> Response {
@@ -721,7 +1169,7 @@ impl<'a> MaterialDialog<'a> {
             rect.center(),
             egui::Align2::CENTER_CENTER,
             &action.text,
-            egui::FontId::proportional(14.0),
+            type_font_id(TypeRole::LabelLarge),
             text_color,
         );
 
@@ -801,3 +1249,105 @@ pub fn form_dialog(
 ) -> MaterialDialog<'_> {
     MaterialDialog::new(id, title, open).dialog_type(DialogType::Form)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::{Event, Key, Modifiers, RawInput};
+
+    fn escape_input() -> RawInput {
+        RawInput {
+            events: vec![Event::Key {
+                key: Key::Escape,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers: Modifiers::NONE,
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn run_dialog(
+        ctx: &Context,
+        input: RawInput,
+        open: &mut bool,
+        dismissible: bool,
+    ) -> DialogResult {
+        let mut result = DialogResult::default();
+        let _ = ctx.run(input, |ctx| {
+            result = MaterialDialog::new("test_dialog", "Title", &mut *open)
+                .dismissible(dismissible)
+                .show(ctx);
+        });
+        result
+    }
+
+    #[test]
+    fn test_escape_sets_dismissed() {
+        let ctx = Context::default();
+        let mut open = true;
+
+        let result = run_dialog(&ctx, RawInput::default(), &mut open, true);
+        assert_eq!(result, DialogResult::default());
+        assert!(open);
+
+        let result = run_dialog(&ctx, escape_input(), &mut open, true);
+        assert!(result.dismissed);
+        assert!(!result.confirmed);
+        assert!(!open);
+    }
+
+    #[test]
+    fn test_escape_closes_only_top_dialog() {
+        let ctx = Context::default();
+        let mut outer_open = true;
+        let mut inner_open = true;
+        let run = |input: RawInput, outer_open: &mut bool, inner_open: &mut bool| {
+            let _ = ctx.run(input, |ctx| {
+                MaterialDialog::new("outer_dialog", "Edit", &mut *outer_open).show(ctx);
+                MaterialDialog::new("inner_dialog", "Discard changes?", &mut *inner_open).show(ctx);
+            });
+        };
+
+        run(RawInput::default(), &mut outer_open, &mut inner_open);
+        assert_eq!(DialogStack::top(&ctx), Some(Id::new("inner_dialog")));
+        assert_eq!(DialogStack::len(&ctx), 2);
+
+        run(escape_input(), &mut outer_open, &mut inner_open);
+        assert!(outer_open);
+        assert!(!inner_open);
+        assert_eq!(DialogStack::top(&ctx), Some(Id::new("outer_dialog")));
+    }
+
+    #[test]
+    fn test_escape_ignored_when_not_dismissible() {
+        let ctx = Context::default();
+        let mut open = true;
+
+        run_dialog(&ctx, RawInput::default(), &mut open, false);
+        let result = run_dialog(&ctx, escape_input(), &mut open, false);
+        assert!(!result.dismissed);
+        assert!(open);
+    }
+
+    #[test]
+    fn test_dialog_is_announced_with_its_title() {
+        let ctx = Context::default();
+        ctx.enable_accesskit();
+        let mut open = true;
+        let output = ctx.run(RawInput::default(), |ctx| {
+            MaterialDialog::new("test_dialog", "Discard draft?", &mut open).show(ctx);
+        });
+
+        let update = output.platform_output.accesskit_update.unwrap();
+        let dialog = update
+            .nodes
+            .iter()
+            .map(|(_, node)| node)
+            .find(|node| node.role() == accesskit::Role::Dialog)
+            .unwrap();
+        assert_eq!(dialog.label(), Some("Discard draft?"));
+        assert!(dialog.is_modal());
+    }
+}
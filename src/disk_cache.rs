@@ -0,0 +1,75 @@
+//! On-disk caching shared by downloaded images and fonts
+
+use std::path::{Path, PathBuf};
+
+/// The per-user cache directory of the OS, or the temp directory if there is none
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn os_cache_dir() -> PathBuf {
+    use std::env;
+
+    let var = |name: &str| {
+        env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    };
+    let dir = if cfg!(target_os = "windows") {
+        var("LOCALAPPDATA")
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|home| home.join("Library").join("Caches"))
+    } else {
+        var("XDG_CACHE_HOME").or_else(|| var("HOME").map(|home| home.join(".cache")))
+    };
+    dir.unwrap_or_else(env::temp_dir)
+}
+
+/// Browsers have no filesystem, so the web build never caches anything on disk
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn os_cache_dir() -> PathBuf {
+    PathBuf::new()
+}
+
+/// Write `bytes` to `path`, creating its directory if needed.
+///
+/// The data goes to a temp file next to `path` that is then renamed into place, so
+/// a crash or a concurrent reader never sees a half-written file.
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+pub(crate) fn write_atomically(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    let dir = path.parent().unwrap_or(Path::new(""));
+    if !dir.as_os_str().is_empty() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    // A leading dot keeps the temp file out of the image cache's `img_` pruning
+    let temp_path = dir.join(format!(".{}.{}.tmp", file_name, std::process::id()));
+    std::fs::write(&temp_path, bytes)
+        .and_then(|()| std::fs::rename(&temp_path, path))
+        .inspect_err(|_| {
+            let _ = std::fs::remove_file(&temp_path);
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_atomically_replaces_file_and_leaves_no_temp_file() {
+        let dir = std::env::temp_dir().join(format!(
+            "egui_material3_disk_cache_test_{}",
+            std::process::id()
+        ));
+        let path = dir.join("nested").join("font.ttf");
+
+        write_atomically(&path, b"first").unwrap();
+        write_atomically(&path, b"second").unwrap();
+
+        assert_eq!(std::fs::read(&path).unwrap(), b"second");
+        let entries: Vec<_> = std::fs::read_dir(path.parent().unwrap())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(entries, vec![std::ffi::OsString::from("font.ttf")]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
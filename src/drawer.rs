@@ -14,13 +14,35 @@
 //! ## Dimensions
 //! - **Width**: 360dp (standard), 256dp (compact)
 //! - **Elevation**: 0dp (permanent), 1dp (dismissible), 1dp (modal)
-
-use crate::theme::get_global_color;
+//!
+//! ## Motion
+//! Modal and dismissible drawers slide in from their edge over 250ms when
+//! `open` becomes `true` and slide back out when it becomes `false`, with the
+//! modal scrim fading along. Items don't react while the drawer slides out.
+//! Reduced motion (see [`crate::theme::set_reduced_motion`]) snaps them instead.
+//!
+//! ## Items
+//! Labels that don't fit are cut short with an ellipsis before the trailing
+//! badge, and show the full text on hover. Items take keyboard focus with Tab
+//! and activate with Space or Enter. Tab and Shift+Tab cycle through the items
+//! of an open modal drawer without reaching the app behind it.
+
+use crate::badge::{BadgePosition, MaterialBadge};
+use crate::material_symbol::material_symbol_text_or_default;
+use crate::theme::{
+    dismiss_on_escape, elevation_level, elevation_shadow, get_global_color, is_rtl, mirror_align,
+    mirror_x, motion_duration, rtl, trap_focus, type_font_id, TypeRole,
+};
 use egui::{
     ecolor::Color32,
     epaint::{CornerRadius, Stroke},
-    pos2, Area, Id, Order, Rect, Response, Sense, SidePanel, Ui, Vec2, Widget,
+    pos2, Area, Frame, Id, Modal, Rect, Response, Sense, SidePanel, Ui, UiBuilder, Vec2, Widget,
+    WidgetInfo, WidgetType,
 };
+use std::sync::Arc;
+
+/// How long the modal and dismissible drawers take to slide in or out
+const DRAWER_ANIMATION_S: f32 = 0.25;
 
 /// Material Design navigation drawer variants.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -175,7 +197,7 @@ impl DrawerHeader {
                 title_pos,
                 egui::Align2::LEFT_TOP,
                 title,
-                egui::FontId::proportional(22.0),
+                type_font_id(TypeRole::TitleLarge),
                 get_global_color("onSurface"),
             );
         }
@@ -186,7 +208,7 @@ impl DrawerHeader {
                 subtitle_pos,
                 egui::Align2::LEFT_TOP,
                 subtitle,
-                egui::FontId::proportional(14.0),
+                type_font_id(TypeRole::BodyMedium),
                 get_global_color("onSurfaceVariant"),
             );
         }
@@ -232,20 +254,36 @@ pub struct MaterialDrawer<'a> {
     id: Id,
 }
 
+/// Result of showing a [`MaterialDrawer`].
+pub struct DrawerResponse {
+    /// The standard egui response covering the drawer surface
+    pub response: Response,
+    /// Index of the item clicked this frame, counted across sections in display order
+    pub clicked: Option<usize>,
+    /// Whether the drawer was closed this frame (scrim click or Escape)
+    pub closed: bool,
+}
+
 /// A section in the navigation drawer with a label and items.
+#[derive(Clone)]
 pub struct DrawerSection {
     pub label: Option<String>,
     pub items: Vec<DrawerItem>,
 }
 
 /// A navigation item in a drawer.
+///
+/// `icon` accepts a Material Symbols name (e.g. `"inbox"`); any other string,
+/// such as an emoji, is drawn as-is.
+#[derive(Clone)]
 pub struct DrawerItem {
     pub text: String,
     pub icon: Option<String>,
     pub active: bool,
     pub enabled: bool,
     pub badge: Option<String>,
-    pub on_click: Option<Box<dyn Fn() + Send + Sync>>,
+    pub icon_badge: Option<MaterialBadge>,
+    pub on_click: Option<Arc<dyn Fn() + Send + Sync>>,
 }
 
 impl DrawerItem {
@@ -256,6 +294,7 @@ impl DrawerItem {
             active: false,
             enabled: true,
             badge: None,
+            icon_badge: None,
             on_click: None,
         }
     }
@@ -280,11 +319,19 @@ impl DrawerItem {
         self
     }
 
+    /// Overlay a [`MaterialBadge`] on the top-right of the item's icon.
+    ///
+    /// Items without an icon show the badge as a trailing pill instead.
+    pub fn icon_badge(mut self, badge: MaterialBadge) -> Self {
+        self.icon_badge = Some(badge);
+        self
+    }
+
     pub fn on_click<F>(mut self, callback: F) -> Self
     where
         F: Fn() + Send + Sync + 'static,
     {
-        self.on_click = Some(Box::new(callback));
+        self.on_click = Some(Arc::new(callback));
         self
     }
 }
@@ -416,6 +463,7 @@ impl<'a> MaterialDrawer<'a> {
             active,
             enabled: true,
             badge: None,
+            icon_badge: None,
             on_click: None,
         });
         self
@@ -438,7 +486,8 @@ impl<'a> MaterialDrawer<'a> {
             active,
             enabled: true,
             badge: None,
-            on_click: Some(Box::new(callback)),
+            icon_badge: None,
+            on_click: Some(Arc::new(callback)),
         });
         self
     }
@@ -449,6 +498,12 @@ impl<'a> MaterialDrawer<'a> {
         self
     }
 
+    /// Add several drawer items at once.
+    pub fn items(mut self, items: impl IntoIterator<Item = DrawerItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
     /// Add a section with label and items.
     pub fn section(mut self, label: Option<impl Into<String>>, items: Vec<DrawerItem>) -> Self {
         self.sections.push(DrawerSection {
@@ -464,12 +519,41 @@ impl<'a> MaterialDrawer<'a> {
         self
     }
 
-    /// Set elevation value.
+    /// Set elevation value in dp, drawn as a drop shadow behind modal and dismissible drawers.
     pub fn elevation(mut self, elevation: f32) -> Self {
         self.elevation = Some(elevation);
         self
     }
 
+    /// Set the opacity (0.0–1.0) of the scrim behind the modal drawer.
+    ///
+    /// Keeps the color of the theme's scrim, which defaults to black at 54%.
+    pub fn scrim_opacity(mut self, opacity: f32) -> Self {
+        let color = self.scrim_color();
+        self.theme.scrim_color = Some(Color32::from_rgba_unmultiplied(
+            color.r(),
+            color.g(),
+            color.b(),
+            (opacity.clamp(0.0, 1.0) * 255.0).round() as u8,
+        ));
+        self
+    }
+
+    fn scrim_color(&self) -> Color32 {
+        self.theme
+            .scrim_color
+            .unwrap_or(Color32::from_rgba_unmultiplied(0, 0, 0, 138))
+    }
+
+    /// How far the drawer has slid in, from 0.0 (closed) to 1.0 (open).
+    fn openness(&self, ctx: &egui::Context) -> f32 {
+        ctx.animate_bool_with_time(
+            self.id.with("openness"),
+            *self.open,
+            motion_duration(ctx, DRAWER_ANIMATION_S),
+        )
+    }
+
     fn get_drawer_style(&self) -> (Color32, Option<Stroke>, f32) {
         let background_color = self.theme.background_color
             .unwrap_or_else(|| get_global_color("surfaceContainerLow"));
@@ -495,97 +579,193 @@ impl<'a> MaterialDrawer<'a> {
     }
 
     /// Show the drawer using appropriate egui layout.
-    pub fn show(self, ctx: &egui::Context) -> Response {
-        match self.variant {
+    ///
+    /// Permanent and dismissible drawers are shown as a side panel, the modal
+    /// drawer as an overlay with its own scrim. Clicking the scrim (when
+    /// `barrier_dismissible`) or pressing Escape sets the `open` flag to `false`.
+    pub fn show(mut self, ctx: &egui::Context) -> DrawerResponse {
+        let was_open = *self.open;
+        let (response, clicked) = match self.variant {
             DrawerVariant::Permanent => self.show_permanent(ctx),
             DrawerVariant::Dismissible => self.show_dismissible(ctx),
             DrawerVariant::Modal => self.show_modal(ctx),
+        };
+        DrawerResponse {
+            response,
+            clicked,
+            closed: was_open && !*self.open,
         }
     }
 
-    fn show_permanent(self, ctx: &egui::Context) -> Response {
-        SidePanel::left(self.id.with("permanent"))
-            .default_width(self.width)
-            .resizable(false)
-            .show(ctx, |ui| self.render_drawer_content(ui))
-            .response
+    /// Whether the drawer sits at the left edge, given the layout direction.
+    fn on_left(&self, rtl: bool) -> bool {
+        (self.alignment == DrawerAlignment::Start) != rtl
     }
 
-    fn show_dismissible(self, ctx: &egui::Context) -> Response {
-        if *self.open {
-            SidePanel::left(self.id.with("dismissible"))
-                .default_width(self.width)
-                .resizable(false)
-                .show(ctx, |ui| self.render_drawer_content(ui))
-                .response
+    fn side_panel(&self, ctx: &egui::Context, id: Id) -> SidePanel {
+        let panel = if self.on_left(rtl(ctx)) {
+            SidePanel::left(id)
         } else {
-            // Return empty response when closed
-            Area::new(self.id.with("dismissible_dummy"))
-                .fixed_pos(pos2(-1000.0, -1000.0)) // Place offscreen
-                .show(ctx, |ui| ui.allocate_response(Vec2::ZERO, Sense::hover()))
-                .response
-        }
-    }
-
-    fn show_modal(self, ctx: &egui::Context) -> Response {
-        if *self.open {
-            // Draw scrim background
-            let screen_rect = ctx.content_rect();
-            let scrim_color = self.theme.scrim_color
-                .unwrap_or(Color32::from_rgba_unmultiplied(0, 0, 0, 138));
-            
-            Area::new(self.id.with("modal_scrim"))
-                .order(Order::Background)
-                .show(ctx, |ui| {
-                    let scrim_response = ui.allocate_response(screen_rect.size(), Sense::click());
-                    ui.painter().rect_filled(
-                        screen_rect,
-                        CornerRadius::ZERO,
-                        scrim_color,
-                    );
+            SidePanel::right(id)
+        };
+        panel.default_width(self.width).resizable(false)
+    }
 
-                    // Close drawer if scrim is clicked and barrier is dismissible
-                    if scrim_response.clicked() && self.barrier_dismissible {
-                        *self.open = false;
-                    }
-                });
+    fn show_permanent(&mut self, ctx: &egui::Context) -> (Response, Option<usize>) {
+        let panel = self.side_panel(ctx, self.id.with("permanent"));
+        panel.show(ctx, |ui| self.render_drawer_content(ui)).inner
+    }
+
+    fn show_dismissible(&mut self, ctx: &egui::Context) -> (Response, Option<usize>) {
+        let openness = self.openness(ctx);
+        if openness > 0.0 {
+            // The panel grows with the drawer so the content beside it moves along
+            let panel = self
+                .side_panel(ctx, self.id.with("dismissible"))
+                .exact_width(self.width * openness)
+                .frame(Frame::NONE);
+            panel
+                .show(ctx, |ui| self.render_sliding(ui, openness))
+                .inner
+        } else {
+            (self.closed_response(ctx), None)
+        }
+    }
+
+    fn show_modal(&mut self, ctx: &egui::Context) -> (Response, Option<usize>) {
+        let openness = self.openness(ctx);
+        if openness == 0.0 {
+            return (self.closed_response(ctx), None);
+        }
 
-            // Draw the actual modal drawer
-            Area::new(self.id.with("modal_drawer"))
-                .order(Order::Foreground)
-                .fixed_pos(pos2(0.0, 0.0))
-                .show(ctx, |ui| {
+        let screen_rect = ctx.content_rect();
+        let scrim_color = self.scrim_color().gamma_multiply(openness);
+        let hidden = self.width * (1.0 - openness);
+        let x = if self.on_left(rtl(ctx)) {
+            screen_rect.min.x - hidden
+        } else {
+            screen_rect.max.x - self.width + hidden
+        };
+        // Not constrained to the screen, so it can slide in from past its edge
+        let area = Area::new(self.id.with("modal_drawer"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(pos2(x, screen_rect.min.y))
+            .constrain(false);
+
+        if !*self.open {
+            // Sliding out: draw the fading scrim ourselves and take no input
+            let closing = area.interactable(false).show(ctx, |ui| {
+                ui.painter().with_clip_rect(screen_rect).rect_filled(
+                    screen_rect,
+                    CornerRadius::ZERO,
+                    scrim_color,
+                );
+                ui.set_width(self.width);
+                ui.set_height(screen_rect.height());
+                self.render_drawer_content(ui);
+            });
+            return (closing.response, None);
+        }
+
+        // egui's Modal draws the scrim and blocks interaction with everything below it
+        let modal = Modal::new(self.id.with("modal"))
+            .area(area)
+            .frame(Frame::NONE)
+            .backdrop_color(scrim_color)
+            .show(ctx, |ui| {
+                trap_focus(ui, self.id.with("focus_trap"), |ui| {
                     ui.set_width(self.width);
                     ui.set_height(screen_rect.height());
                     self.render_drawer_content(ui)
                 })
-                .response
+            });
+
+        if modal.backdrop_response.clicked() && self.barrier_dismissible {
+            *self.open = false;
+        }
+        modal.inner
+    }
+
+    /// Render the drawer `openness` of the way in from its edge, clipped to the
+    /// part that has slid into view. Clicks are ignored while it slides out.
+    fn render_sliding(&mut self, ui: &mut Ui, openness: f32) -> (Response, Option<usize>) {
+        let visible = Vec2::new(self.width * openness, ui.available_height());
+        let (rect, _) = ui.allocate_exact_size(visible, Sense::hover());
+        let x = if self.on_left(is_rtl(ui)) {
+            rect.max.x - self.width
         } else {
-            // Return empty response when closed
-            Area::new(self.id.with("modal_dummy"))
-                .fixed_pos(pos2(-1000.0, -1000.0)) // Place offscreen
-                .show(ctx, |ui| ui.allocate_response(Vec2::ZERO, Sense::hover()))
-                .response
+            rect.min.x
+        };
+        let content_rect =
+            Rect::from_min_size(pos2(x, rect.min.y), Vec2::new(self.width, rect.height()));
+        let mut child = ui.new_child(UiBuilder::new().max_rect(content_rect));
+        child.set_clip_rect(rect.intersect(ui.clip_rect()));
+        let (response, clicked) = self.render_drawer_content(&mut child);
+        (response, clicked.filter(|_| *self.open))
+    }
+
+    /// Render the drawer inside an existing `Ui` instead of as a side panel.
+    ///
+    /// Useful for embedding a permanent drawer in a custom layout. The modal
+    /// variant still overlays the whole viewport.
+    pub fn show_inside(mut self, ui: &mut Ui) -> DrawerResponse {
+        if self.variant == DrawerVariant::Modal {
+            return self.show(ui.ctx());
+        }
+        let was_open = *self.open;
+        let (response, clicked) = if self.variant == DrawerVariant::Dismissible {
+            let openness = self.openness(ui.ctx());
+            if openness > 0.0 {
+                self.render_sliding(ui, openness)
+            } else {
+                (ui.allocate_response(Vec2::ZERO, Sense::hover()), None)
+            }
+        } else {
+            self.render_drawer_content(ui)
+        };
+        DrawerResponse {
+            response,
+            clicked,
+            closed: was_open && !*self.open,
         }
     }
 
-    fn render_drawer_content(self, ui: &mut Ui) -> Response {
-        let (background_color, border_stroke, _elevation) = self.get_drawer_style();
+    fn closed_response(&self, ctx: &egui::Context) -> Response {
+        Area::new(self.id.with("closed_dummy"))
+            .fixed_pos(pos2(-1000.0, -1000.0)) // Place offscreen
+            .show(ctx, |ui| ui.allocate_response(Vec2::ZERO, Sense::hover()))
+            .response
+    }
+
+    fn render_drawer_content(&mut self, ui: &mut Ui) -> (Response, Option<usize>) {
+        let (background_color, border_stroke, elevation) = self.get_drawer_style();
 
         // Handle ESC key for dismissible and modal drawers
-        if matches!(
-            self.variant,
-            DrawerVariant::Dismissible | DrawerVariant::Modal
-        )
-            && ui.input(|i| i.key_pressed(egui::Key::Escape)) {
-                *self.open = false;
-            }
+        if *self.open
+            && matches!(
+                self.variant,
+                DrawerVariant::Dismissible | DrawerVariant::Modal
+            )
+            && dismiss_on_escape(ui.ctx())
+        {
+            *self.open = false;
+        }
 
         let available_rect = ui.available_rect_before_wrap();
         let drawer_rect = Rect::from_min_size(
             available_rect.min,
             Vec2::new(self.width, available_rect.height()),
         );
+        let rtl = is_rtl(ui);
+        let start_x = |offset: f32| mirror_x(rtl, drawer_rect, drawer_rect.min.x + offset);
+
+        // Modal and dismissible drawers float above the content on a drop shadow
+        if self.variant != DrawerVariant::Permanent {
+            ui.painter().add(
+                elevation_shadow(elevation_level(elevation))
+                    .as_shape(drawer_rect, self.corner_radius),
+            );
+        }
 
         // Draw drawer background with corner radius
         ui.painter()
@@ -616,22 +796,22 @@ impl<'a> MaterialDrawer<'a> {
             );
 
             // Header text with proper padding
-            let title_pos = egui::pos2(header_rect.min.x + 28.0, header_rect.min.y + 16.0);
+            let title_pos = egui::pos2(start_x(28.0), header_rect.min.y + 16.0);
             ui.painter().text(
                 title_pos,
-                egui::Align2::LEFT_TOP,
+                mirror_align(rtl, egui::Align2::LEFT_TOP),
                 title,
-                egui::FontId::proportional(22.0),
+                type_font_id(TypeRole::TitleLarge),
                 get_global_color("onSurfaceVariant"),
             );
 
             if let Some(subtitle) = &self.header_subtitle {
-                let subtitle_pos = egui::pos2(header_rect.min.x + 28.0, header_rect.min.y + 42.0);
+                let subtitle_pos = egui::pos2(start_x(28.0), header_rect.min.y + 42.0);
                 ui.painter().text(
                     subtitle_pos,
-                    egui::Align2::LEFT_TOP,
+                    mirror_align(rtl, egui::Align2::LEFT_TOP),
                     subtitle,
-                    egui::FontId::proportional(14.0),
+                    type_font_id(TypeRole::BodyMedium),
                     get_global_color("onSurfaceVariant"),
                 );
             }
@@ -640,6 +820,8 @@ impl<'a> MaterialDrawer<'a> {
         }
 
         let mut response = ui.allocate_response(drawer_rect.size(), Sense::hover());
+        let mut clicked = None;
+        let mut flat_index = 0;
 
         // Render sections if any
         if !self.sections.is_empty() {
@@ -647,12 +829,12 @@ impl<'a> MaterialDrawer<'a> {
                 // Draw section label if present
                 if let Some(label) = &section.label {
                     current_y += section_padding_top;
-                    let label_pos = egui::pos2(drawer_rect.min.x + 28.0, current_y);
+                    let label_pos = egui::pos2(start_x(28.0), current_y);
                     ui.painter().text(
                         label_pos,
-                        egui::Align2::LEFT_TOP,
+                        mirror_align(rtl, egui::Align2::LEFT_TOP),
                         label,
-                        egui::FontId::proportional(14.0),
+                        type_font_id(TypeRole::TitleSmall),
                         get_global_color("onSurfaceVariant"),
                     );
                     current_y += section_padding_bottom + 10.0;
@@ -669,8 +851,12 @@ impl<'a> MaterialDrawer<'a> {
                         horizontal_padding,
                         self.id.with("section").with(section_idx).with(index),
                     );
+                    if item_response.clicked() && item.enabled {
+                        clicked = Some(flat_index);
+                    }
                     response = response.union(item_response);
                     current_y += item_height;
+                    flat_index += 1;
                 }
 
                 // Add divider between sections (except after last section)
@@ -699,12 +885,15 @@ impl<'a> MaterialDrawer<'a> {
                     horizontal_padding,
                     self.id.with("item").with(index),
                 );
+                if item_response.clicked() && item.enabled {
+                    clicked = Some(index);
+                }
                 response = response.union(item_response);
                 current_y += item_height;
             }
         }
 
-        response
+        (response, clicked)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -724,62 +913,42 @@ impl<'a> MaterialDrawer<'a> {
             Vec2::new(self.width - horizontal_padding * 2.0, item_height),
         );
 
-        let item_response = ui.interact(item_outer_rect, item_id, Sense::click());
+        let sense = if item.enabled { Sense::click() } else { Sense::hover() };
+        let item_response = ui.interact(item_outer_rect, item_id, sense);
 
-        // Active indicator (rounded rectangle on the left)
+        // Active indicator / hover state layer (full-width pill)
+        let indicator_height = 56.0_f32.min(item_height);
+        let indicator_rect = Rect::from_min_size(
+            egui::pos2(item_outer_rect.min.x, y_pos + (item_height - indicator_height) / 2.0),
+            Vec2::new(item_outer_rect.width(), indicator_height),
+        );
+        let indicator_radius = CornerRadius::same((indicator_height / 2.0) as u8);
         if item.active {
-            let indicator_width = item_outer_rect.width();
-            let indicator_height = 32.0;
-            let indicator_y = y_pos + (item_height - indicator_height) / 2.0;
-            
-            let indicator_rect = Rect::from_min_size(
-                egui::pos2(item_outer_rect.min.x, indicator_y),
-                Vec2::new(indicator_width, indicator_height),
-            );
-
-            let active_color = get_global_color("secondaryContainer");
             ui.painter().rect_filled(
                 indicator_rect,
-                CornerRadius::same(16),
-                active_color,
-            );
-        } else if item_response.hovered() && item.enabled {
-            let indicator_width = item_outer_rect.width();
-            let indicator_height = 32.0;
-            let indicator_y = y_pos + (item_height - indicator_height) / 2.0;
-            
-            let indicator_rect = Rect::from_min_size(
-                egui::pos2(item_outer_rect.min.x, indicator_y),
-                Vec2::new(indicator_width, indicator_height),
-            );
-
-            let hover_color = get_global_color("onSurface").linear_multiply(0.08);
-            ui.painter().rect_filled(
-                indicator_rect,
-                CornerRadius::same(16),
-                hover_color,
+                indicator_radius,
+                get_global_color("secondaryContainer"),
             );
         }
-
-        let mut current_x = item_outer_rect.min.x + 16.0;
-
-        // Draw icon if present
-        if let Some(_icon) = &item.icon {
-            let icon_center = egui::pos2(current_x + 12.0, y_pos + item_height / 2.0);
-            let icon_color = if !item.enabled {
-                get_global_color("onSurface").linear_multiply(0.38)
-            } else if item.active {
+        if item.enabled && item_response.hovered() {
+            let state_layer = if item_response.is_pointer_button_down_on() { 0.12 } else { 0.08 };
+            let layer_color = if item.active {
                 get_global_color("onSecondaryContainer")
             } else {
-                get_global_color("onSurfaceVariant")
+                get_global_color("onSurface")
             };
-
-            ui.painter().circle_filled(icon_center, 12.0, icon_color);
-            current_x += 40.0;
+            ui.painter().rect_filled(
+                indicator_rect,
+                indicator_radius,
+                layer_color.linear_multiply(state_layer),
+            );
         }
 
-        // Draw item text
-        let text_color = if !item.enabled {
+        // Leading icon and label run from the start edge, mirrored in RTL
+        let rtl = is_rtl(ui);
+        let at = |x: f32| mirror_x(rtl, item_outer_rect, x);
+
+        let content_color = if !item.enabled {
             get_global_color("onSurface").linear_multiply(0.38)
         } else if item.active {
             get_global_color("onSecondaryContainer")
@@ -787,41 +956,80 @@ impl<'a> MaterialDrawer<'a> {
             get_global_color("onSurfaceVariant")
         };
 
-        let text_pos = egui::pos2(
-            current_x,
-            y_pos + (item_height - 20.0) / 2.0,
-        );
-        
-        ui.painter().text(
-            text_pos,
-            egui::Align2::LEFT_CENTER,
-            &item.text,
-            egui::FontId::proportional(14.0),
-            text_color,
-        );
+        let mut current_x = item_outer_rect.min.x + 16.0;
 
-        // Draw badge if present
-        if let Some(badge) = &item.badge {
-            let badge_x = item_outer_rect.max.x - 40.0;
-            let badge_center = egui::pos2(badge_x, y_pos + item_height / 2.0);
-            
-            // Badge background
-            ui.painter().circle_filled(
-                badge_center,
-                10.0,
-                get_global_color("error"),
-            );
-            
-            // Badge text
+        // Draw icon if present
+        if let Some(icon) = &item.icon {
+            let icon_center = egui::pos2(at(current_x + 12.0), y_pos + item_height / 2.0);
             ui.painter().text(
-                badge_center,
+                icon_center,
                 egui::Align2::CENTER_CENTER,
-                badge,
-                egui::FontId::proportional(10.0),
-                get_global_color("onError"),
+                material_symbol_text_or_default(icon, icon),
+                egui::FontId::proportional(24.0),
+                content_color,
+            );
+            if let Some(badge) = &item.icon_badge {
+                let icon_rect = Rect::from_center_size(icon_center, Vec2::splat(24.0));
+                if rtl {
+                    badge.draw_on(ui, icon_rect, BadgePosition::trailing_top(rtl));
+                } else {
+                    badge.draw_on_rect(ui, icon_rect);
+                }
+            }
+            current_x += 36.0;
+        } else if let Some(badge) = &item.icon_badge {
+            let trailing_center =
+                egui::pos2(at(item_outer_rect.max.x - 24.0), y_pos + item_height / 2.0);
+            badge.draw_on(
+                ui,
+                Rect::from_center_size(trailing_center, Vec2::ZERO),
+                BadgePosition::Custom(Vec2::ZERO),
+            );
+        }
+
+        // Trailing badge text (per M3 navigation drawer spec); the label ends before it
+        let center_y = y_pos + item_height / 2.0;
+        let mut label_end = item_outer_rect.max.x - 24.0;
+        if let Some(badge) = &item.badge {
+            let galley = ui.painter().layout_no_wrap(
+                badge.clone(),
+                type_font_id(TypeRole::LabelLarge),
+                content_color,
+            );
+            let badge_rect = mirror_align(rtl, egui::Align2::RIGHT_CENTER)
+                .anchor_size(egui::pos2(at(label_end), center_y), galley.size());
+            ui.painter().galley(badge_rect.min, galley, content_color);
+            label_end = item_outer_rect.max.x - 24.0 - badge_rect.width() - 12.0;
+        } else if item.icon.is_none() && item.icon_badge.is_some() {
+            label_end -= 16.0;
+        }
+
+        // Item label, cut short with an ellipsis where the badge begins
+        let label = item_label_galley(
+            ui.painter(),
+            &item.text,
+            content_color,
+            (label_end - current_x).max(0.0),
+        );
+        let label_elided = label.elided;
+        let label_rect = mirror_align(rtl, egui::Align2::LEFT_CENTER)
+            .anchor_size(egui::pos2(at(current_x), center_y), label.size());
+        ui.painter().galley(label_rect.min, label, content_color);
+
+        // M3 focus indicator: 3dp secondary ring around the pill
+        if item_response.has_focus() && item.enabled {
+            ui.painter().rect_stroke(
+                indicator_rect,
+                indicator_radius,
+                Stroke::new(3.0, get_global_color("secondary")),
+                egui::epaint::StrokeKind::Outside,
             );
         }
 
+        item_response.widget_info(|| {
+            WidgetInfo::selected(WidgetType::Button, item.enabled, item.active, &item.text)
+        });
+
         // Handle item click
         if item_response.clicked() && item.enabled {
             if let Some(callback) = &item.on_click {
@@ -829,34 +1037,182 @@ impl<'a> MaterialDrawer<'a> {
             }
         }
 
-        item_response
+        // The full label is still available on hover when it was cut short
+        if label_elided {
+            item_response.on_hover_text(&item.text)
+        } else {
+            item_response
+        }
     }
 }
 
+/// Lay out a drawer item label on a single row no wider than `max_width`,
+/// ending in an ellipsis when it doesn't fit.
+fn item_label_galley(
+    painter: &egui::Painter,
+    text: &str,
+    color: Color32,
+    max_width: f32,
+) -> Arc<egui::Galley> {
+    let mut job = egui::text::LayoutJob::simple(
+        text.to_owned(),
+        type_font_id(TypeRole::LabelLarge),
+        color,
+        max_width,
+    );
+    job.wrap.max_rows = 1;
+    job.wrap.break_anywhere = true;
+    painter.layout_job(job)
+}
+
 impl Widget for MaterialDrawer<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
-        // This implementation is kept for backward compatibility
-        // but the preferred way is to use the show() method
-        self.render_drawer_content(ui)
+        self.show_inside(ui).response
     }
 }
 
-/// Convenience function to create a permanent drawer.
-pub fn permanent_drawer(open: &mut bool) -> MaterialDrawer<'_> {
-    MaterialDrawer::new(DrawerVariant::Permanent, open)
+/// Convenience function to create a permanent drawer with the given items.
+pub fn permanent_drawer<'a>(open: &'a mut bool, items: &[DrawerItem]) -> MaterialDrawer<'a> {
+    MaterialDrawer::new(DrawerVariant::Permanent, open).items(items.iter().cloned())
 }
 
-/// Convenience function to create a dismissible drawer.
-pub fn dismissible_drawer(open: &mut bool) -> MaterialDrawer<'_> {
-    MaterialDrawer::new(DrawerVariant::Dismissible, open)
+/// Convenience function to create a dismissible drawer with the given items.
+///
+/// The drawer is only shown while `open` is `true`; Escape closes it.
+pub fn dismissible_drawer<'a>(open: &'a mut bool, items: &[DrawerItem]) -> MaterialDrawer<'a> {
+    MaterialDrawer::new(DrawerVariant::Dismissible, open).items(items.iter().cloned())
 }
 
-/// Convenience function to create a modal drawer.
-pub fn modal_drawer(open: &mut bool) -> MaterialDrawer<'_> {
-    MaterialDrawer::new(DrawerVariant::Modal, open)
+/// Convenience function to create a modal drawer with the given items.
+///
+/// The modal drawer draws its own scrim; clicking it or pressing Escape closes the drawer.
+///
+/// ```no_run
+/// # egui::__run_test_ui(|ui| {
+/// # use egui_material3::{modal_drawer, DrawerItem};
+/// let mut open = true;
+/// let items = vec![DrawerItem::new("Inbox").icon("inbox").active(true)];
+/// ui.add(modal_drawer(&mut open, &items));
+/// # });
+/// ```
+pub fn modal_drawer<'a>(open: &'a mut bool, items: &[DrawerItem]) -> MaterialDrawer<'a> {
+    MaterialDrawer::new(DrawerVariant::Modal, open).items(items.iter().cloned())
 }
 
 // Legacy support - these will be deprecated
-pub fn standard_drawer(open: &mut bool) -> MaterialDrawer<'_> {
-    permanent_drawer(open)
+pub fn standard_drawer<'a>(open: &'a mut bool, items: &[DrawerItem]) -> MaterialDrawer<'a> {
+    permanent_drawer(open, items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::{set_context_reduced_motion, set_context_rtl};
+
+    /// Lay out `text` as a drawer item label inside a real frame
+    fn label_galley(text: &str, max_width: f32) -> Arc<egui::Galley> {
+        let ctx = egui::Context::default();
+        let mut galley = None;
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                galley = Some(item_label_galley(
+                    ui.painter(),
+                    text,
+                    Color32::WHITE,
+                    max_width,
+                ));
+            });
+        });
+        galley.unwrap()
+    }
+
+    #[test]
+    fn test_long_item_label_is_truncated() {
+        let label = "A very long navigation destination name that cannot fit in a drawer";
+        let galley = label_galley(label, 120.0);
+        assert!(galley.elided);
+        assert_eq!(galley.rows.len(), 1);
+        assert!(galley.size().x <= 120.0 + 0.5);
+    }
+
+    #[test]
+    fn test_scrim_opacity_keeps_scrim_color() {
+        let mut open = true;
+        let theme = DrawerThemeData {
+            scrim_color: Some(Color32::from_rgb(10, 20, 30)),
+            ..DrawerThemeData::material3_defaults()
+        };
+        let drawer = MaterialDrawer::new(DrawerVariant::Modal, &mut open)
+            .theme(theme)
+            .scrim_opacity(0.5);
+        assert_eq!(
+            drawer.scrim_color(),
+            Color32::from_rgba_unmultiplied(10, 20, 30, 128)
+        );
+    }
+
+    /// Rect of a modal drawer opened on the second of frames at `times`, and the screen
+    fn modal_drawer_rect(rtl: bool, times: &[f64]) -> (Rect, Rect) {
+        let ctx = egui::Context::default();
+        set_context_reduced_motion(&ctx, Some(false));
+        set_context_rtl(&ctx, Some(rtl));
+        let screen = Rect::from_min_size(pos2(0.0, 0.0), Vec2::new(800.0, 600.0));
+        let mut rect = Rect::NOTHING;
+        for (frame, time) in times.iter().enumerate() {
+            let mut open = frame > 0;
+            let input = egui::RawInput {
+                time: Some(*time),
+                screen_rect: Some(screen),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                rect = MaterialDrawer::new(DrawerVariant::Modal, &mut open)
+                    .show(ctx)
+                    .response
+                    .rect;
+            });
+        }
+        (rect, screen)
+    }
+
+    #[test]
+    fn test_rtl_modal_drawer_slides_in_from_the_right_edge() {
+        // Halfway through sliding in, part of the drawer is still past its edge
+        let (rect, screen) = modal_drawer_rect(false, &[0.0, 0.05]);
+        assert!(rect.min.x < screen.min.x);
+        let (rect, screen) = modal_drawer_rect(true, &[0.0, 0.05]);
+        assert!(rect.max.x > screen.max.x);
+
+        // Fully open, it rests against that edge
+        let (rect, screen) = modal_drawer_rect(false, &[0.0, 0.05, 1.0]);
+        assert!((rect.min.x - screen.min.x).abs() < 0.5);
+        let (rect, screen) = modal_drawer_rect(true, &[0.0, 0.05, 1.0]);
+        assert!((rect.max.x - screen.max.x).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_drawer_slides_open_over_several_frames() {
+        let ctx = egui::Context::default();
+        set_context_reduced_motion(&ctx, Some(false));
+        let mut openness = Vec::new();
+        for (frame, open) in [false, true, true].into_iter().enumerate() {
+            let mut open = open;
+            let input = egui::RawInput {
+                time: Some(frame as f64 * 0.05),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                openness.push(MaterialDrawer::new(DrawerVariant::Modal, &mut open).openness(ctx));
+            });
+        }
+        assert_eq!(openness[0], 0.0);
+        assert!(openness[1] > 0.0 && openness[1] < openness[2] && openness[2] < 1.0);
+    }
+
+    #[test]
+    fn test_short_item_label_is_kept_whole() {
+        let galley = label_galley("Inbox", 120.0);
+        assert!(!galley.elided);
+        assert_eq!(galley.text(), "Inbox");
+    }
 }
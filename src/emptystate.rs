@@ -0,0 +1,188 @@
+//! Material Design 3 Empty States
+//!
+//! A centered icon, title, body text and optional action for views with
+//! nothing to show yet, such as a search without results or an empty table.
+//!
+//! # M3 Color Role Usage
+//!
+//! - **onSurfaceVariant**: Icon, title and body text
+//! - **secondaryContainer / onSecondaryContainer**: Action button (filled tonal)
+//!
+//! ## Typography
+//! - **Default**: 48dp icon, Title Medium title, Body Medium body, 24dp padding
+//! - **Compact**: 24dp icon, Title Small title, Body Small body, 8dp padding,
+//!   used inside menus such as the searchable select
+//!
+//! [`MaterialSelect`](crate::MaterialSelect),
+//! [`MaterialDataTable`](crate::MaterialDataTable) and
+//! [`MaterialImageList`](crate::MaterialImageList) show one when they have
+//! nothing to list; each takes an `empty_state` builder to replace the default.
+//!
+//! ```rust
+//! # egui::__run_test_ui(|ui| {
+//! use egui_material3::MaterialEmptyState;
+//!
+//! let response = MaterialEmptyState::new("No messages")
+//!     .icon("inbox")
+//!     .body("Messages you receive will show up here")
+//!     .action("Compose")
+//!     .show(ui);
+//! if response.action_clicked {
+//!     // open the composer
+//! }
+//! # });
+//! ```
+
+use crate::button::MaterialButton;
+use crate::material_symbol::material_symbol_text;
+use crate::theme::{get_global_color, type_font_id, TypeRole};
+use egui::{FontId, Response, RichText, Ui, Widget};
+
+/// Response from showing a [`MaterialEmptyState`].
+pub struct EmptyStateResponse {
+    /// The response covering the whole empty state
+    pub response: Response,
+    /// Whether the action button was clicked this frame
+    pub action_clicked: bool,
+}
+
+/// Material Design empty state.
+///
+/// # Example
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// ui.add(MaterialEmptyState::new("No results").icon("search_off"));
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct MaterialEmptyState {
+    icon: Option<String>,
+    title: String,
+    body: Option<String>,
+    action: Option<String>,
+    compact: bool,
+}
+
+/// Create an empty state with a title.
+pub fn empty_state(title: impl Into<String>) -> MaterialEmptyState {
+    MaterialEmptyState::new(title)
+}
+
+impl MaterialEmptyState {
+    /// Create an empty state with a title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            icon: None,
+            title: title.into(),
+            body: None,
+            action: None,
+            compact: false,
+        }
+    }
+
+    /// Show a Material Symbols icon above the title, e.g. `"search_off"`.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the supporting text below the title.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Show a filled tonal button labelled `label` below the text.
+    ///
+    /// Check [`EmptyStateResponse::action_clicked`] to react to it.
+    pub fn action(mut self, label: impl Into<String>) -> Self {
+        self.action = Some(label.into());
+        self
+    }
+
+    /// Use a smaller icon, type and padding, e.g. inside a menu.
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Show the empty state, centered in the available width.
+    pub fn show(self, ui: &mut Ui) -> EmptyStateResponse {
+        let color = get_global_color("onSurfaceVariant");
+        let (padding, icon_size, title_role, body_role) = if self.compact {
+            (8.0, 24.0, TypeRole::TitleSmall, TypeRole::BodySmall)
+        } else {
+            (24.0, 48.0, TypeRole::TitleMedium, TypeRole::BodyMedium)
+        };
+
+        let mut action_clicked = false;
+        let response = ui
+            .vertical_centered(|ui| {
+                ui.add_space(padding);
+                if let Some(icon) = &self.icon {
+                    ui.label(
+                        RichText::new(material_symbol_text(icon))
+                            .font(FontId::proportional(icon_size))
+                            .color(color),
+                    );
+                    ui.add_space(padding / 2.0);
+                }
+                ui.label(
+                    RichText::new(&self.title)
+                        .font(type_font_id(title_role))
+                        .color(color),
+                );
+                if let Some(body) = &self.body {
+                    ui.add_space(4.0);
+                    ui.label(
+                        RichText::new(body)
+                            .font(type_font_id(body_role))
+                            .color(color),
+                    );
+                }
+                if let Some(label) = self.action {
+                    ui.add_space(padding.min(16.0));
+                    action_clicked = ui.add(MaterialButton::filled_tonal(label)).clicked();
+                }
+                ui.add_space(padding);
+            })
+            .response;
+
+        EmptyStateResponse {
+            response,
+            action_clicked,
+        }
+    }
+}
+
+impl Widget for MaterialEmptyState {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact_empty_state_is_shorter() {
+        let ctx = egui::Context::default();
+        let mut heights = Vec::new();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                for compact in [false, true] {
+                    let response = MaterialEmptyState::new("No results")
+                        .icon("search_off")
+                        .body("Try a different search")
+                        .compact(compact)
+                        .show(ui);
+                    assert!(!response.action_clicked);
+                    heights.push(response.response.rect.height());
+                }
+            });
+        });
+        assert!(heights[1] < heights[0]);
+    }
+}
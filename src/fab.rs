@@ -7,8 +7,6 @@
 //! ## Surface FAB (Neutral Actions)
 //! - **surface**: FAB container background (neutral emphasis)
 //! - **onSurface**: Icon and text color on surface
-//! - **surfaceContainerHigh**: Hover state background
-//! - **surfaceContainerHighest**: Pressed state background
 //! - **State layers**: onSurface @ 8% (hover), 12% (press)
 //!
 //! ## Primary FAB (Main Actions - Most Common)
@@ -31,10 +29,14 @@
 //! - **Standard elevation**: 6dp shadow (raised above content)
 //! - **Hover elevation**: 8dp shadow (slight increase on hover)
 //! - **Pressed elevation**: 6dp shadow (returns to standard on press)
+//! - **Lowered**: 1dp shadow, 3dp on hover, for FABs placed on top of other surfaces
 
 use crate::get_global_color;
 use crate::icon::MaterialIcon;
 use crate::material_symbol::material_symbol_text;
+use crate::ripple::Ripple;
+use crate::theme::{elevation_shadow, motion_duration, type_font_id, TypeRole};
+use crate::tooltip::material_tooltip;
 use egui::{self, Color32, Pos2, Rect, Response, Sense, Ui, Vec2, Widget};
 
 /// Material Design FAB (Floating Action Button) variants
@@ -78,11 +80,8 @@ pub enum FabSize {
 ///     .icon("add")
 ///     .action(|| println!("Add clicked")));
 ///
-/// // Extended FAB with text
-/// ui.add(MaterialFab::primary()
-///     .size(FabSize::Extended)
-///     .icon("edit")
-///     .text("Compose")
+/// // Extended FAB with icon and label
+/// ui.add(MaterialFab::extended("edit", "Compose")
 ///     .action(|| println!("Compose clicked")));
 ///
 /// // Large FAB for prominent action
@@ -97,6 +96,7 @@ pub enum FabSize {
 /// - Elevation: 6dp (raised above content)
 /// - Corner radius: 50% (fully rounded)
 /// - Sizes: Small (40dp), Regular (56dp), Large (96dp), Extended (≥80dp)
+/// - Extended padding: 16dp leading, 20dp trailing, 12dp between icon and label
 /// - Icon size: 24dp for regular, 32dp for large
 /// - Placement: 16dp from screen edge, above navigation bars
 pub struct MaterialFab<'a> {
@@ -114,11 +114,31 @@ pub struct MaterialFab<'a> {
     svg_data: Option<String>,
     /// Whether the FAB is interactive
     enabled: bool,
+    /// Whether an extended FAB hides its label and shrinks to an icon-only FAB
+    collapsed: bool,
+    /// Whether the FAB uses the lower elevation of FABs on top of other surfaces
+    lowered: bool,
     /// Action callback when FAB is pressed
     action: Option<Box<dyn Fn() + 'a>>,
+    /// Optional plain tooltip shown on hover
+    tooltip: Option<String>,
 }
 
 /// SVG icon data for custom FAB icons
+///
+/// The paths are rasterized with resvg and scaled from the viewbox into the
+/// FAB's icon box, so multi-color marks keep their own colors.
+///
+/// ```rust
+/// # use egui_material3::{SvgIcon, SvgPath};
+/// # use egui::Color32;
+/// let logo = SvgIcon::from_paths(vec![
+///     SvgPath::new("M2 2h20v20H2z").fill(Color32::from_rgb(0x1E, 0x88, 0xE5)),
+///     SvgPath::new("M6 6h12v12H6zM9 9v6h6V9z")
+///         .fill(Color32::WHITE)
+///         .even_odd(true),
+/// ]);
+/// ```
 #[derive(Clone)]
 pub struct SvgIcon {
     /// Vector of SVG paths that make up the icon
@@ -127,6 +147,44 @@ pub struct SvgIcon {
     pub viewbox_size: Vec2,
 }
 
+impl SvgIcon {
+    /// Create an icon from paths drawn in a 24x24 viewbox, the Material icon grid
+    pub fn from_paths(paths: Vec<SvgPath>) -> Self {
+        Self {
+            paths,
+            viewbox_size: Vec2::splat(24.0),
+        }
+    }
+
+    /// Set the viewbox the path coordinates are relative to
+    pub fn viewbox(mut self, width: f32, height: f32) -> Self {
+        self.viewbox_size = Vec2::new(width, height);
+        self
+    }
+
+    /// SVG document drawing all paths in order
+    fn to_svg(&self) -> String {
+        let (width, height) = (self.viewbox_size.x, self.viewbox_size.y);
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        );
+        for path in &self.paths {
+            let [r, g, b, a] = path.fill.to_srgba_unmultiplied();
+            svg.push_str(&format!(
+                r##"<path d="{}" fill="#{:02x}{:02x}{:02x}" fill-opacity="{}" fill-rule="{}"/>"##,
+                path.path,
+                r,
+                g,
+                b,
+                a as f32 / 255.0,
+                if path.even_odd { "evenodd" } else { "nonzero" },
+            ));
+        }
+        svg.push_str("</svg>");
+        svg
+    }
+}
+
 /// Individual SVG path with styling
 #[derive(Clone)]
 pub struct SvgPath {
@@ -134,6 +192,31 @@ pub struct SvgPath {
     pub path: String,
     /// Fill color for the path
     pub fill: Color32,
+    /// Whether overlapping subpaths use the even-odd fill rule instead of nonzero
+    pub even_odd: bool,
+}
+
+impl SvgPath {
+    /// Create a black path from SVG path data, e.g. `"M4 4h16v16H4z"`
+    pub fn new(path_data: impl Into<String>) -> Self {
+        Self {
+            path: path_data.into(),
+            fill: Color32::BLACK,
+            even_odd: false,
+        }
+    }
+
+    /// Set the fill color
+    pub fn fill(mut self, color: Color32) -> Self {
+        self.fill = color;
+        self
+    }
+
+    /// Use the even-odd fill rule, so inner subpaths cut holes whatever their direction
+    pub fn even_odd(mut self, even_odd: bool) -> Self {
+        self.even_odd = even_odd;
+        self
+    }
 }
 
 impl<'a> MaterialFab<'a> {
@@ -150,10 +233,22 @@ impl<'a> MaterialFab<'a> {
             svg_icon: None,
             svg_data: None,
             enabled: true,
+            collapsed: false,
+            lowered: false,
             action: None,
+            tooltip: None,
         }
     }
 
+    /// Create a primary extended FAB showing an icon followed by a label
+    ///
+    /// ## Parameters
+    /// - `icon`: Material symbol name shown at the leading edge
+    /// - `label`: Text shown after the icon
+    pub fn extended(icon: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::primary().icon(icon).text(label)
+    }
+
     /// Create a surface FAB
     pub fn surface() -> Self {
         Self::new(FabVariant::Surface)
@@ -204,10 +299,18 @@ impl<'a> MaterialFab<'a> {
         self
     }
 
-    /// Set the lowered state of the FAB (elevation effect)
-    pub fn lowered(self, _lowered: bool) -> Self {
-        // Placeholder for lowered state (elevation effect)
-        // In a real implementation, this would affect the visual elevation
+    /// Collapse an extended FAB to an icon-only FAB
+    ///
+    /// The label width animates in and out, so this can be driven by scroll
+    /// position to hide the label while content is scrolling.
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Set the lowered state of the FAB, which drops its elevation from 6dp to 1dp
+    pub fn lowered(mut self, lowered: bool) -> Self {
+        self.lowered = lowered;
         self
     }
 
@@ -231,17 +334,33 @@ impl<'a> MaterialFab<'a> {
         self.action = Some(Box::new(f));
         self
     }
+
+    /// Show a Material plain tooltip on hover, e.g. for an icon-only or collapsed FAB
+    pub fn tooltip(mut self, text: impl Into<String>) -> Self {
+        self.tooltip = Some(text.into());
+        self
+    }
 }
 
 impl<'a> Widget for MaterialFab<'a> {
     fn ui(self, ui: &mut Ui) -> Response {
+        // How much of the extended label is shown (0.0 = collapsed to an icon-only FAB)
+        let label_t = if self.size == FabSize::Extended {
+            let label_id = ui.next_auto_id().with("fab_extended_label");
+            let animation_time = motion_duration(ui.ctx(), ui.style().animation_time);
+            ui.ctx()
+                .animate_bool_with_time(label_id, !self.collapsed, animation_time)
+        } else {
+            1.0
+        };
+
         let size = match self.size {
             FabSize::Small => Vec2::splat(40.0),
             FabSize::Regular => Vec2::splat(56.0),
             FabSize::Large => Vec2::splat(96.0),
             FabSize::Extended => {
                 let left_margin = 16.0;
-                let right_margin = 24.0;
+                let right_margin = 20.0;
                 let icon_width = if self.icon.is_some() || self.svg_icon.is_some() || self.svg_data.is_some() {
                     24.0 + 12.0
                 } else {
@@ -249,7 +368,7 @@ impl<'a> Widget for MaterialFab<'a> {
                 };
 
                 let text_width = if let Some(ref text) = self.text {
-                    let font_id = egui::FontId::proportional(14.0);
+                    let font_id = type_font_id(TypeRole::LabelLarge);
                     ui.painter().layout_no_wrap(text.clone(), font_id, Color32::WHITE)
                         .size()
                         .x
@@ -258,7 +377,8 @@ impl<'a> Widget for MaterialFab<'a> {
                 };
 
                 let total_width = left_margin + icon_width + text_width + right_margin;
-                Vec2::new(total_width.max(80.0), 56.0) // Minimum width of 80px
+                let expanded_width = total_width.max(80.0); // Minimum width of 80px
+                Vec2::new(egui::lerp(56.0..=expanded_width, label_t), 56.0)
             }
         };
 
@@ -273,6 +393,7 @@ impl<'a> Widget for MaterialFab<'a> {
         let text = self.text;
         let svg_icon = self.svg_icon;
         let svg_data = self.svg_data;
+        let lowered = self.lowered;
 
         let clicked = response.clicked() && enabled;
 
@@ -292,8 +413,6 @@ impl<'a> Widget for MaterialFab<'a> {
         let surface = get_global_color("surface"); // Surface FAB container (neutral emphasis)
         let on_surface = get_global_color("onSurface"); // Icon/text on surface
         let surface_container = get_global_color("surfaceContainer"); // Disabled FAB background
-        let surface_container_high = get_global_color("surfaceContainerHigh"); // Surface FAB hover state
-        let surface_container_highest = get_global_color("surfaceContainerHighest"); // Surface FAB pressed state
         let outline = get_global_color("outline"); // Disabled icon color
 
         let (bg_color, icon_color) = if !enabled {
@@ -303,76 +422,18 @@ impl<'a> Widget for MaterialFab<'a> {
                 outline.linear_multiply(0.38),
             )
         } else {
+            // Hover and press feedback is drawn on top by the ripple
             match variant {
-                FabVariant::Surface => {
-                    // Surface FAB: use surface container variants for state layers
-                    if response.is_pointer_button_down_on() {
-                        // Pressed state: surfaceContainerHighest (highest emphasis surface)
-                        (surface_container_highest, on_surface)
-                    } else if response.hovered() {
-                        // Hover state: surfaceContainerHigh (high emphasis surface)
-                        (surface_container_high, on_surface)
-                    } else {
-                        // Default state: surface with onSurface for neutral emphasis
-                        (surface, on_surface)
-                    }
-                }
-                FabVariant::Primary => {
-                    // Primary FAB: use primary background with state layer overlay
-                    let base_color = primary;
-                    let content_color = on_primary;
-                    if response.is_pointer_button_down_on() {
-                        // Pressed state: 12% onPrimary overlay (M3 interaction state)
-                        (blend_state_layer(base_color, content_color, 0.12), content_color)
-                    } else if response.hovered() {
-                        // Hover state: 8% onPrimary overlay (M3 interaction state)
-                        (blend_state_layer(base_color, content_color, 0.08), content_color)
-                    } else {
-                        (base_color, content_color)
-                    }
-                }
-                FabVariant::Secondary => {
-                    // Secondary FAB: use secondary background with state layer overlay
-                    let base_color = secondary;
-                    let content_color = on_secondary;
-                    if response.is_pointer_button_down_on() {
-                        // Pressed state: 12% onSecondary overlay (M3 interaction state)
-                        (blend_state_layer(base_color, content_color, 0.12), content_color)
-                    } else if response.hovered() {
-                        // Hover state: 8% onSecondary overlay (M3 interaction state)
-                        (blend_state_layer(base_color, content_color, 0.08), content_color)
-                    } else {
-                        (base_color, content_color)
-                    }
-                }
-                FabVariant::Tertiary => {
-                    // Tertiary FAB: use tertiary background with state layer overlay
-                    let base_color = tertiary;
-                    let content_color = on_tertiary;
-                    if response.is_pointer_button_down_on() {
-                        // Pressed state: 12% onTertiary overlay (M3 interaction state)
-                        (blend_state_layer(base_color, content_color, 0.12), content_color)
-                    } else if response.hovered() {
-                        // Hover state: 8% onTertiary overlay (M3 interaction state)
-                        (blend_state_layer(base_color, content_color, 0.08), content_color)
-                    } else {
-                        (base_color, content_color)
-                    }
-                }
-                FabVariant::Branded => {
-                    // Branded FAB: custom brand colors (e.g., Google brand blue)
-                    let google_brand = Color32::from_rgb(66, 133, 244);
-                    let content_color = Color32::WHITE; // White icon/text on branded background
-                    if response.is_pointer_button_down_on() {
-                        // Pressed state: 12% white overlay
-                        (blend_state_layer(google_brand, content_color, 0.12), content_color)
-                    } else if response.hovered() {
-                        // Hover state: 8% white overlay
-                        (blend_state_layer(google_brand, content_color, 0.08), content_color)
-                    } else {
-                        (google_brand, content_color)
-                    }
-                }
+                // Surface FAB: surface with onSurface for neutral emphasis
+                FabVariant::Surface => (surface, on_surface),
+                // Primary FAB: primary background with onPrimary content
+                FabVariant::Primary => (primary, on_primary),
+                // Secondary FAB: secondary background with onSecondary content
+                FabVariant::Secondary => (secondary, on_secondary),
+                // Tertiary FAB: tertiary background with onTertiary content
+                FabVariant::Tertiary => (tertiary, on_tertiary),
+                // Branded FAB: custom brand colors (e.g., Google brand blue) with white content
+                FabVariant::Branded => (Color32::from_rgb(66, 133, 244), Color32::WHITE),
             }
         };
 
@@ -383,15 +444,35 @@ impl<'a> Widget for MaterialFab<'a> {
             _ => 14.0,
         };
 
+        // Elevation: level 3 (6dp) raised to level 4 (8dp) on hover, or 1dp/3dp when lowered
+        if enabled {
+            let hovered = response.hovered() && !response.is_pointer_button_down_on();
+            let level = match (lowered, hovered) {
+                (false, false) => 3,
+                (false, true) => 4,
+                (true, false) => 1,
+                (true, true) => 2,
+            };
+            ui.painter()
+                .add(elevation_shadow(level).as_shape(rect, corner_radius));
+        }
+
         // Draw FAB background with less rounded corners
         ui.painter().rect_filled(rect, corner_radius, bg_color);
 
+        // M3 state layers and press ripple in the content color
+        if enabled {
+            Ripple::new(icon_color)
+                .corner_radius(corner_radius)
+                .show(ui, rect, &response);
+        }
+
         // Draw content
         match size_enum {
             FabSize::Extended => {
                 // Draw icon and text with proper spacing
                 let left_margin = 16.0;
-                let _right_margin = 24.0;
+                let _right_margin = 20.0;
                 let icon_text_gap = 12.0;
                 let mut content_x = rect.min.x + left_margin;
 
@@ -424,21 +505,29 @@ impl<'a> Widget for MaterialFab<'a> {
                     });
 
                     content_x += 24.0 + icon_text_gap;
-                } else if let Some(ref _svg_icon) = svg_icon {
-                    // Render simplified Google logo for branded FAB
-                    draw_google_logo(ui, Pos2::new(content_x + 12.0, rect.center().y), 24.0);
+                } else if let Some(ref svg_icon) = svg_icon {
+                    let icon_rect = Rect::from_center_size(
+                        Pos2::new(content_x + 12.0, rect.center().y),
+                        Vec2::splat(24.0),
+                    );
+                    paint_svg_icon(ui, svg_icon, icon_rect);
                     content_x += 24.0 + icon_text_gap;
                 }
 
                 if let Some(ref text) = text {
-                    let text_pos = Pos2::new(content_x, rect.center().y);
-                    ui.painter().text(
-                        text_pos,
-                        egui::Align2::LEFT_CENTER,
-                        text,
-                        egui::FontId::proportional(14.0),
-                        icon_color,
-                    );
+                    if label_t > 0.0 {
+                        // Clip the label to the animating container and fade it with the width
+                        let text_pos = Pos2::new(content_x, rect.center().y);
+                        let label_painter =
+                            ui.painter().with_clip_rect(rect.intersect(ui.clip_rect()));
+                        label_painter.text(
+                            text_pos,
+                            egui::Align2::LEFT_CENTER,
+                            text,
+                            type_font_id(TypeRole::LabelLarge),
+                            icon_color.gamma_multiply(label_t),
+                        );
+                    }
                 }
             }
             _ => {
@@ -460,15 +549,15 @@ impl<'a> Widget for MaterialFab<'a> {
                             Color32::WHITE,
                         );
                     }
-                } else if let Some(ref _svg_icon) = svg_icon {
+                } else if let Some(ref svg_icon) = svg_icon {
                     let icon_size = match size_enum {
                         FabSize::Small => 18.0,
                         FabSize::Large => 36.0,
                         _ => 24.0,
                     };
 
-                    // Render simplified Google logo for branded FAB
-                    draw_google_logo(ui, rect.center(), icon_size);
+                    let icon_rect = Rect::from_center_size(rect.center(), Vec2::splat(icon_size));
+                    paint_svg_icon(ui, svg_icon, icon_rect);
                 } else if let Some(ref icon_name) = icon {
                     let icon_size = match size_enum {
                         FabSize::Small => 18.0,
@@ -502,71 +591,312 @@ impl<'a> Widget for MaterialFab<'a> {
             }
         }
 
+        if let Some(text) = self.tooltip {
+            material_tooltip(&response, text);
+        }
+
         response
     }
 }
 
-/// Blend a state layer overlay on top of a base color.
+/// A single action shown when a [`FabMenu`] is expanded
+struct FabMenuAction {
+    /// Material Design icon name for the mini FAB
+    icon: String,
+    /// Optional label shown next to the mini FAB
+    label: Option<String>,
+}
+
+/// Response returned by [`FabMenu::show`]
+pub struct FabMenuResponse {
+    /// Response of the main FAB
+    pub response: Response,
+    /// Index of the action chosen this frame, if any
+    pub selected: Option<usize>,
+    /// Whether the menu is expanded after this frame
+    pub open: bool,
+}
+
+#[derive(Clone, Copy, Default)]
+struct FabMenuState {
+    open: bool,
+    /// Keyboard-focused action, counted upwards from the main FAB
+    focused: Option<usize>,
+}
+
+/// Material Design FAB menu (speed dial)
+///
+/// A main FAB that expands into a vertical stack of mini FABs when pressed.
+/// The actions animate out one after another above a scrim; choosing one
+/// collapses the menu and reports its index. Escape or a click outside the
+/// actions also collapses it.
+///
+/// While expanded, Up/Down move the keyboard focus between actions and
+/// Enter/Space choose the focused one.
+///
+/// ## Usage Examples
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// let response = FabMenu::new("create_menu", MaterialFab::primary().icon("add"))
+///     .labeled_action("description", "Document")
+///     .labeled_action("image", "Image")
+///     .action("folder")
+///     .show(ui);
 ///
-/// Used for M3 interactive states (hover: 8%, press: 12%).
-fn blend_state_layer(base: Color32, overlay: Color32, opacity: f32) -> Color32 {
-    let alpha = (opacity * 255.0) as u8;
-    let overlay_with_alpha = Color32::from_rgba_unmultiplied(overlay.r(), overlay.g(), overlay.b(), alpha);
-    // Alpha blending
-    let inv_alpha = 255 - alpha;
-    Color32::from_rgba_unmultiplied(
-        ((base.r() as u16 * inv_alpha as u16 + overlay_with_alpha.r() as u16 * alpha as u16) / 255) as u8,
-        ((base.g() as u16 * inv_alpha as u16 + overlay_with_alpha.g() as u16 * alpha as u16) / 255) as u8,
-        ((base.b() as u16 * inv_alpha as u16 + overlay_with_alpha.b() as u16 * alpha as u16) / 255) as u8,
-        base.a(),
-    )
+/// if let Some(index) = response.selected {
+///     println!("Action {} chosen", index);
+/// }
+/// # });
+/// ```
+pub struct FabMenu<'a> {
+    /// Unique ID used to store the expanded state
+    id: egui::Id,
+    /// FAB that toggles the menu
+    fab: MaterialFab<'a>,
+    /// Actions stacked above the main FAB, nearest first
+    actions: Vec<FabMenuAction>,
 }
 
-// Helper function to draw Google logo
-fn draw_google_logo(ui: &mut Ui, center: Pos2, size: f32) {
-    let half_size = size / 2.0;
-    let quarter_size = size / 4.0;
-
-    // Google 4-color logo - simplified version
-    // Green (top right)
-    ui.painter().rect_filled(
-        Rect::from_min_size(
-            Pos2::new(center.x, center.y - half_size),
-            Vec2::new(half_size, quarter_size),
-        ),
-        0.0,
-        Color32::from_rgb(52, 168, 83), // Green #34A853
-    );
+impl<'a> FabMenu<'a> {
+    /// Create a FAB menu around a main FAB
+    ///
+    /// ## Parameters
+    /// - `id`: Unique identifier for the menu state
+    /// - `fab`: The FAB that toggles the menu
+    pub fn new(id: impl Into<egui::Id>, fab: MaterialFab<'a>) -> Self {
+        Self {
+            id: id.into(),
+            fab,
+            actions: Vec::new(),
+        }
+    }
 
-    // Blue (right)
-    ui.painter().rect_filled(
-        Rect::from_min_size(
-            Pos2::new(center.x, center.y - quarter_size),
-            Vec2::new(half_size, half_size),
-        ),
-        0.0,
-        Color32::from_rgb(66, 133, 244), // Blue #4285F4
-    );
+    /// Add an icon-only action
+    pub fn action(mut self, icon: impl Into<String>) -> Self {
+        self.actions.push(FabMenuAction {
+            icon: icon.into(),
+            label: None,
+        });
+        self
+    }
 
-    // Yellow (bottom left)
-    ui.painter().rect_filled(
-        Rect::from_min_size(
-            Pos2::new(center.x - half_size, center.y + quarter_size),
-            Vec2::new(half_size, quarter_size),
-        ),
-        0.0,
-        Color32::from_rgb(251, 188, 5), // Yellow #FBBC05
-    );
+    /// Add an action with a label shown next to its icon
+    pub fn labeled_action(mut self, icon: impl Into<String>, label: impl Into<String>) -> Self {
+        self.actions.push(FabMenuAction {
+            icon: icon.into(),
+            label: Some(label.into()),
+        });
+        self
+    }
 
-    // Red (left)
-    ui.painter().rect_filled(
-        Rect::from_min_size(
-            Pos2::new(center.x - half_size, center.y - half_size),
-            Vec2::new(quarter_size, size),
-        ),
-        0.0,
-        Color32::from_rgb(234, 67, 53), // Red #EA4335
-    );
+    /// Show the main FAB and, when expanded, its actions
+    pub fn show(self, ui: &mut Ui) -> FabMenuResponse {
+        let ctx = ui.ctx().clone();
+        let state_id = self.id.with("fab_menu_state");
+        let mut state: FabMenuState = ctx.data(|d| d.get_temp(state_id)).unwrap_or_default();
+        let action_count = self.actions.len();
+        let mut selected = None;
+
+        // Keyboard handling happens before the main FAB so that Enter on a focused
+        // action doesn't also count as a click on the (still focused) main FAB
+        let mut key_handled = false;
+        if state.open {
+            let (up, down, activate, escape) = ctx.input(|i| {
+                (
+                    i.key_pressed(egui::Key::ArrowUp),
+                    i.key_pressed(egui::Key::ArrowDown),
+                    i.key_pressed(egui::Key::Enter) || i.key_pressed(egui::Key::Space),
+                    i.key_pressed(egui::Key::Escape),
+                )
+            });
+            if escape {
+                state.open = false;
+                key_handled = true;
+            } else if up && action_count > 0 {
+                state.focused = Some(state.focused.map_or(0, |i| (i + 1).min(action_count - 1)));
+            } else if down {
+                state.focused = state.focused.and_then(|i| i.checked_sub(1));
+            } else if activate {
+                if let Some(index) = state.focused {
+                    selected = Some(index);
+                    state.open = false;
+                    key_handled = true;
+                }
+            }
+        }
+
+        let variant = self.fab.variant;
+        let fab_size = self.fab.size;
+        let response = ui.add(self.fab);
+        if response.clicked() && !key_handled {
+            state.open = !state.open;
+            state.focused = None;
+        }
+        if state.open {
+            // Keep arrow keys and Escape for the menu instead of moving egui's focus
+            ctx.memory_mut(|mem| {
+                mem.set_focus_lock_filter(
+                    response.id,
+                    egui::EventFilter {
+                        vertical_arrows: true,
+                        escape: true,
+                        ..Default::default()
+                    },
+                )
+            });
+        }
+
+        let progress = ctx.animate_bool_with_time(
+            self.id.with("fab_menu_anim"),
+            state.open,
+            motion_duration(&ctx, 0.2),
+        );
+        if progress > 0.0 {
+            let screen_rect = ctx.viewport_rect();
+            let fab_rect = response.rect;
+            let interactive = state.open;
+
+            egui::Area::new(self.id.with("fab_menu"))
+                .order(egui::Order::Foreground)
+                .fixed_pos(screen_rect.min)
+                .show(&ctx, |ui| {
+                    // Scrim: clicking anywhere outside the actions collapses the menu
+                    let scrim_sense = if interactive {
+                        Sense::click()
+                    } else {
+                        Sense::hover()
+                    };
+                    let scrim_response = ui.allocate_rect(screen_rect, scrim_sense);
+                    let scrim_color = get_global_color("scrim").gamma_multiply(0.32 * progress);
+                    ui.painter().rect_filled(screen_rect, 0.0, scrim_color);
+                    if scrim_response.clicked() {
+                        state.open = false;
+                    }
+
+                    // Actions stack upwards from the main FAB, each starting once the
+                    // previous one is partway out
+                    let mini_size = 40.0;
+                    let spacing = 16.0;
+                    for (index, action) in self.actions.iter().enumerate() {
+                        let item_t =
+                            (progress * (action_count + 1) as f32 - index as f32).clamp(0.0, 1.0);
+                        if item_t <= 0.0 {
+                            continue;
+                        }
+
+                        let target_y = fab_rect.min.y
+                            - spacing
+                            - mini_size / 2.0
+                            - index as f32 * (mini_size + spacing);
+                        let center = Pos2::new(
+                            fab_rect.center().x,
+                            egui::lerp(fab_rect.center().y..=target_y, item_t),
+                        );
+                        let mini_rect = Rect::from_center_size(center, Vec2::splat(mini_size));
+
+                        ui.scope(|ui| {
+                            ui.multiply_opacity(item_t);
+                            let mini = MaterialFab::new(variant)
+                                .size(FabSize::Small)
+                                .icon(action.icon.clone());
+                            if ui.put(mini_rect, mini).clicked() && interactive {
+                                selected = Some(index);
+                                state.open = false;
+                            }
+
+                            if let Some(label) = &action.label {
+                                let galley = ui.painter().layout_no_wrap(
+                                    label.clone(),
+                                    type_font_id(TypeRole::LabelLarge),
+                                    get_global_color("onSurface"),
+                                );
+                                let label_rect = Rect::from_center_size(
+                                    Pos2::new(
+                                        mini_rect.min.x - 8.0 - (galley.size().x + 24.0) / 2.0,
+                                        center.y,
+                                    ),
+                                    galley.size() + Vec2::new(24.0, 12.0),
+                                );
+                                ui.painter().rect_filled(
+                                    label_rect,
+                                    8.0,
+                                    get_global_color("surfaceContainerHigh"),
+                                );
+                                ui.painter().galley(
+                                    label_rect.center() - galley.size() / 2.0,
+                                    galley,
+                                    get_global_color("onSurface"),
+                                );
+                            }
+
+                            if state.focused == Some(index) {
+                                ui.painter().rect_stroke(
+                                    mini_rect.expand(3.0),
+                                    15.0,
+                                    egui::Stroke::new(2.0, get_global_color("secondary")),
+                                    egui::epaint::StrokeKind::Outside,
+                                );
+                            }
+                        });
+                    }
+
+                    // Close button drawn over the main FAB, above the scrim
+                    let close_size = match fab_size {
+                        FabSize::Small => 40.0,
+                        FabSize::Large => 96.0,
+                        _ => 56.0,
+                    };
+                    let close = MaterialFab::new(variant)
+                        .size(match fab_size {
+                            FabSize::Extended => FabSize::Regular,
+                            size => size,
+                        })
+                        .icon("close");
+                    let close_rect =
+                        Rect::from_center_size(fab_rect.center(), Vec2::splat(close_size));
+                    if ui.put(close_rect, close).clicked() && interactive {
+                        state.open = false;
+                    }
+                });
+        }
+
+        if !state.open {
+            state.focused = None;
+        }
+        ctx.data_mut(|d| d.insert_temp(state_id, state));
+
+        FabMenuResponse {
+            response,
+            selected,
+            open: state.open,
+        }
+    }
+}
+
+// Helper function to paint vector FAB icons into the icon box
+fn paint_svg_icon(ui: &Ui, svg_icon: &SvgIcon, icon_rect: Rect) {
+    // Rasterize at the physical pixel size so the edges stay crisp
+    let size = (icon_rect.width() * ui.ctx().pixels_per_point()).round() as u32;
+    if let Some(texture) = svg_texture(ui.ctx(), &svg_icon.to_svg(), size.max(1)) {
+        ui.painter().image(
+            texture.id(),
+            icon_rect,
+            Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)),
+            Color32::WHITE,
+        );
+    }
+}
+
+/// Texture of `svg_data` at `size` pixels, rasterized once and then kept in the
+/// memory of `ctx`. An SVG that fails to render is remembered too.
+fn svg_texture(ctx: &egui::Context, svg_data: &str, size: u32) -> Option<egui::TextureHandle> {
+    let id = egui::Id::new(("fab_svg", svg_data, size));
+    if let Some(texture) = ctx.data(|d| d.get_temp::<Option<egui::TextureHandle>>(id)) {
+        return texture;
+    }
+    let texture = render_svg_to_texture(ctx, svg_data, size).ok();
+    ctx.data_mut(|d| d.insert_temp(id, texture.clone()));
+    texture
 }
 
 // Helper function to render SVG data to texture
@@ -626,27 +956,79 @@ pub fn fab_branded() -> MaterialFab<'static> {
     MaterialFab::branded()
 }
 
+pub fn extended_fab_primary(
+    icon: impl Into<String>,
+    label: impl Into<String>,
+) -> MaterialFab<'static> {
+    MaterialFab::extended(icon, label)
+}
+
 /// Create Google branded icon (4-color logo)
 pub fn google_branded_icon() -> SvgIcon {
     SvgIcon {
         paths: vec![
-            SvgPath {
-                path: "M16 16v14h4V20z".to_string(),
-                fill: Color32::from_rgb(52, 168, 83), // Green #34A853
-            },
-            SvgPath {
-                path: "M30 16H20l-4 4h14z".to_string(),
-                fill: Color32::from_rgb(66, 133, 244), // Blue #4285F4
-            },
-            SvgPath {
-                path: "M6 16v4h10l4-4z".to_string(),
-                fill: Color32::from_rgb(251, 188, 5), // Yellow #FBBC05
-            },
-            SvgPath {
-                path: "M20 16V6h-4v14z".to_string(),
-                fill: Color32::from_rgb(234, 67, 53), // Red #EA4335
-            },
+            SvgPath::new("M16 16v14h4V20z").fill(Color32::from_rgb(52, 168, 83)), // Green #34A853
+            SvgPath::new("M30 16H20l-4 4h14z").fill(Color32::from_rgb(66, 133, 244)), // Blue #4285F4
+            SvgPath::new("M6 16v4h10l4-4z").fill(Color32::from_rgb(251, 188, 5)), // Yellow #FBBC05
+            SvgPath::new("M20 16V6h-4v14z").fill(Color32::from_rgb(234, 67, 53)), // Red #EA4335
         ],
         viewbox_size: Vec2::new(36.0, 36.0),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use resvg::{tiny_skia, usvg};
+
+    #[test]
+    fn test_svg_icon_keeps_viewbox_colors_and_fill_rule() {
+        let icon = SvgIcon::from_paths(vec![
+            SvgPath::new("M0 0h48v48H0z").fill(Color32::from_rgb(0x12, 0x34, 0x56)),
+            SvgPath::new("M8 8h32v32H8zM16 16v16h16V16z")
+                .fill(Color32::WHITE)
+                .even_odd(true),
+        ])
+        .viewbox(48.0, 48.0);
+        let svg = icon.to_svg();
+        assert!(svg.contains(r#"viewBox="0 0 48 48""#));
+        assert!(svg.contains(r##"fill="#123456""##));
+        assert!(svg.contains(r#"fill-rule="nonzero""#));
+        assert!(svg.contains(r#"fill-rule="evenodd""#));
+
+        let tree = usvg::Tree::from_str(&svg, &usvg::Options::default())
+            .expect("generated SVG should parse");
+        assert_eq!(tree.size().width(), 48.0);
+    }
+
+    #[test]
+    fn test_even_odd_paths_leave_a_hole() {
+        let ring = SvgIcon::from_paths(vec![SvgPath::new("M2 2h20v20H2zM8 8h8v8H8z")
+            .fill(Color32::RED)
+            .even_odd(true)]);
+        let tree = usvg::Tree::from_str(&ring.to_svg(), &usvg::Options::default()).unwrap();
+        let mut pixmap = tiny_skia::Pixmap::new(24, 24).unwrap();
+        resvg::render(
+            &tree,
+            tiny_skia::Transform::identity(),
+            &mut pixmap.as_mut(),
+        );
+        let alpha = |x: u32, y: u32| pixmap.pixel(x, y).unwrap().alpha();
+        assert_eq!(alpha(4, 4), 255);
+        assert_eq!(alpha(12, 12), 0);
+    }
+
+    #[test]
+    fn test_svg_texture_is_rendered_once_per_size() {
+        let ctx = egui::Context::default();
+        let svg = SvgIcon::from_paths(vec![SvgPath::new("M2 2h20v20H2z")]).to_svg();
+
+        let first = svg_texture(&ctx, &svg, 24).unwrap();
+        let again = svg_texture(&ctx, &svg, 24).unwrap();
+        assert_eq!(first.id(), again.id());
+
+        let larger = svg_texture(&ctx, &svg, 48).unwrap();
+        assert_ne!(first.id(), larger.id());
+        assert_eq!(larger.size(), [48, 48]);
+    }
+}
@@ -4,14 +4,92 @@
 //! - **primary**: Accent/emphasis icons
 //! - **onSurface**: Default icon color
 //! - **onSurfaceVariant**: Lower emphasis icons
+//!
+//! ## Icon names
+//! `MaterialIcon::new("settings")` looks the name up with
+//! [`codepoint`](crate::material_symbol::codepoint). A name that isn't in the
+//! Material Symbols codepoint table draws the `broken_image` glyph instead of
+//! tofu; emoji and glyph strings such as `noto_emoji::SPARKLES` are drawn as is.
+//!
+//! ## Variable font axes
+//! Material Symbols is a variable font with four axes: FILL (0–1), wght
+//! (100–700), GRAD (-25–200) and opsz (20–48). [`MaterialIcon`] accepts all
+//! of them through [`IconAxes`] and its builder methods.
+//!
+//! **Limitation:** egui's text layout cannot apply font variations yet, so
+//! icons are currently drawn with the font's default instance (FILL 0,
+//! wght 400, GRAD 0, opsz 24) whatever axes are set. The values are kept on
+//! the icon and validated, so code written against this API will pick up the
+//! styles once variations are supported. See [`variable_axes_supported`].
+//!
+//! ## Rotation
+//! `.rotation(degrees)` turns the glyph around its center, e.g. to flip a
+//! chevron on expand/collapse, and `.spinning(true)` rotates it continuously
+//! for inline loading states. Neither changes the space the icon takes up.
+//! Spinning icons hold still while reduced motion is on.
+
+use crate::material_symbol::{codepoint, ICON_BROKEN_IMAGE};
+use crate::theme::reduced_motion;
+use egui::{self, emath::Rot2, epaint::TextShape, Color32, Response, Sense, Ui, Vec2, Widget};
+
+/// Seconds for one full turn of a spinning icon
+const SPIN_PERIOD_S: f64 = 1.0;
+
+/// Whether [`IconAxes`] currently change how icons are drawn.
+///
+/// Returns `false` while egui renders variable fonts at their default instance.
+pub fn variable_axes_supported() -> bool {
+    false
+}
+
+/// Values for the Material Symbols variable font axes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IconAxes {
+    /// FILL axis: 0.0 is outlined, 1.0 is filled
+    pub fill: f32,
+    /// wght axis: stroke weight from 100 to 700
+    pub weight: u16,
+    /// GRAD axis: fine weight adjustment from -25 to 200
+    pub grade: i16,
+    /// opsz axis from 20 to 48; `None` follows the icon size
+    pub optical_size: Option<f32>,
+}
+
+impl Default for IconAxes {
+    fn default() -> Self {
+        Self {
+            fill: 0.0,
+            weight: 400,
+            grade: 0,
+            optical_size: None,
+        }
+    }
+}
+
+impl IconAxes {
+    /// Clamp every axis into the range supported by Material Symbols
+    pub fn clamped(self) -> Self {
+        Self {
+            fill: self.fill.clamp(0.0, 1.0),
+            weight: self.weight.clamp(100, 700),
+            grade: self.grade.clamp(-25, 200),
+            optical_size: self.optical_size.map(|opsz| opsz.clamp(20.0, 48.0)),
+        }
+    }
 
-use egui::{self, Color32, Response, Sense, Ui, Vec2, Widget};
+    /// The optical size to use for an icon drawn at `size` points
+    pub fn optical_size_for(&self, size: f32) -> f32 {
+        self.optical_size.unwrap_or(size).clamp(20.0, 48.0)
+    }
+}
 
 pub struct MaterialIcon {
     name: String,
     size: f32,
     color: Option<Color32>,
-    filled: bool,
+    axes: IconAxes,
+    rotation: f32,
+    spinning: bool,
 }
 
 impl MaterialIcon {
@@ -20,7 +98,9 @@ impl MaterialIcon {
             name: name.into(),
             size: 24.0,
             color: None,
-            filled: false,
+            axes: IconAxes::default(),
+            rotation: 0.0,
+            spinning: false,
         }
     }
 
@@ -34,10 +114,91 @@ impl MaterialIcon {
         self
     }
 
+    /// Shorthand for `.fill(1.0)` or `.fill(0.0)`
     pub fn filled(mut self, filled: bool) -> Self {
-        self.filled = filled;
+        self.axes.fill = if filled { 1.0 } else { 0.0 };
+        self
+    }
+
+    /// Set the FILL axis, from 0.0 (outlined) to 1.0 (filled)
+    pub fn fill(mut self, fill: f32) -> Self {
+        self.axes.fill = fill.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the wght axis, from 100 (thin) to 700 (bold); default 400
+    pub fn weight(mut self, weight: u16) -> Self {
+        self.axes.weight = weight.clamp(100, 700);
+        self
+    }
+
+    /// Set the GRAD axis, from -25 (low emphasis) to 200 (high emphasis); default 0
+    pub fn grade(mut self, grade: i16) -> Self {
+        self.axes.grade = grade.clamp(-25, 200);
+        self
+    }
+
+    /// Set the opsz axis, from 20 to 48; defaults to the icon size
+    pub fn optical_size(mut self, optical_size: f32) -> Self {
+        self.axes.optical_size = Some(optical_size.clamp(20.0, 48.0));
+        self
+    }
+
+    /// Set all variable font axes at once
+    pub fn axes(mut self, axes: IconAxes) -> Self {
+        self.axes = axes.clamped();
         self
     }
+
+    /// The variable font axes requested for this icon
+    pub fn get_axes(&self) -> IconAxes {
+        self.axes
+    }
+
+    /// Rotate the glyph clockwise around its center by `degrees`
+    pub fn rotation(mut self, degrees: f32) -> Self {
+        self.rotation = degrees;
+        self
+    }
+
+    /// Whether the icon can be drawn as asked: `false` for a Material Symbols
+    /// name that isn't in the codepoint table.
+    pub fn is_valid(&self) -> bool {
+        codepoint(&self.name).is_some() || !looks_like_icon_name(&self.name)
+    }
+
+    /// Continuously rotate the glyph, e.g. `MaterialIcon::new("progress_activity")`.
+    ///
+    /// The angle is derived from the input time, so every spinning icon turns
+    /// in step and no per-icon state is kept. Repaints are only requested
+    /// while the icon is visible.
+    pub fn spinning(mut self, spinning: bool) -> Self {
+        self.spinning = spinning;
+        self
+    }
+}
+
+/// Whether `text` is written like a Material Symbols name, e.g. `arrow_back`
+fn looks_like_icon_name(text: &str) -> bool {
+    text.len() > 1
+        && text
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+}
+
+/// Text to draw for an icon: the glyph of a known name, a broken image glyph
+/// for an unknown name, or the text itself for emoji and raw glyphs
+fn icon_text(name: &str) -> String {
+    match codepoint(name) {
+        Some(glyph) => glyph.to_string(),
+        None if looks_like_icon_name(name) => ICON_BROKEN_IMAGE.to_string(),
+        None => name.to_owned(),
+    }
+}
+
+/// Clockwise angle in radians for a spinning icon at `time` seconds
+fn spin_angle(time: f64) -> f32 {
+    ((time / SPIN_PERIOD_S).fract() * std::f64::consts::TAU) as f32
 }
 
 impl Widget for MaterialIcon {
@@ -49,14 +210,39 @@ impl Widget for MaterialIcon {
             .color
             .unwrap_or_else(|| Color32::from_gray(if ui.visuals().dark_mode { 230 } else { 30 }));
 
-        // Render icon character from MaterialSymbolsOutlined font
-        ui.painter().text(
-            rect.center(),
-            egui::Align2::CENTER_CENTER,
-            &self.name,
-            egui::FontId::proportional(self.size),
-            icon_color,
-        );
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        let mut angle = self.rotation.to_radians();
+        if self.spinning && !reduced_motion(ui.ctx()) {
+            angle += spin_angle(ui.input(|i| i.time));
+            // Repaint requests are merged per frame, so several spinners
+            // on screen still cost a single repaint.
+            ui.ctx().request_repaint();
+        }
+
+        // Render icon character from MaterialSymbolsOutlined font. The axes
+        // can't be passed to egui's text layout yet, so the default instance
+        // is drawn (see the module docs).
+        let font_id = egui::FontId::proportional(self.size);
+        let text = icon_text(&self.name);
+        if angle == 0.0 {
+            ui.painter().text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                text,
+                font_id,
+                icon_color,
+            );
+        } else {
+            let galley = ui.painter().layout_no_wrap(text, font_id, icon_color);
+            // Text shapes rotate around their top-left corner, so offset the
+            // origin to keep the glyph pivoting around the icon center.
+            let pos = rect.center() - Rot2::from_angle(angle) * (galley.size() / 2.0);
+            ui.painter()
+                .add(TextShape::new(pos, galley, icon_color).with_angle(angle));
+        }
 
         response
     }
@@ -65,3 +251,47 @@ impl Widget for MaterialIcon {
 pub fn icon(name: impl Into<String>) -> MaterialIcon {
     MaterialIcon::new(name)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axes_are_clamped_to_font_ranges() {
+        let axes = MaterialIcon::new("home")
+            .fill(2.0)
+            .weight(900)
+            .grade(-100)
+            .optical_size(64.0)
+            .get_axes();
+        assert_eq!(axes.fill, 1.0);
+        assert_eq!(axes.weight, 700);
+        assert_eq!(axes.grade, -25);
+        assert_eq!(axes.optical_size, Some(48.0));
+    }
+
+    #[test]
+    fn test_optical_size_follows_icon_size() {
+        let axes = IconAxes::default();
+        assert_eq!(axes.optical_size_for(24.0), 24.0);
+        assert_eq!(axes.optical_size_for(16.0), 20.0);
+        assert_eq!(MaterialIcon::new("home").filled(true).get_axes().fill, 1.0);
+    }
+
+    #[test]
+    fn test_icon_names_resolve_to_glyphs() {
+        assert_eq!(icon_text("settings"), '\u{e8b8}'.to_string());
+        assert_eq!(icon_text("no_such_icon"), ICON_BROKEN_IMAGE.to_string());
+        let sparkles = crate::noto_emoji::SPARKLES;
+        assert_eq!(icon_text(sparkles), sparkles);
+        assert!(MaterialIcon::new("settings").is_valid());
+        assert!(!MaterialIcon::new("no_such_icon").is_valid());
+    }
+
+    #[test]
+    fn test_spin_angle_wraps_each_period() {
+        assert_eq!(spin_angle(0.0), 0.0);
+        assert!((spin_angle(SPIN_PERIOD_S * 0.25) - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+        assert!((spin_angle(SPIN_PERIOD_S * 3.25) - spin_angle(SPIN_PERIOD_S * 0.25)).abs() < 1e-4);
+    }
+}
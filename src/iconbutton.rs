@@ -30,20 +30,36 @@
 //! - **onSurfaceVariant**: Icon color
 //! - **outline**: Border stroke color
 //! - **State layers**: onSurface @ 8% (hover)
-//! - **Selected**: primary @ 10% background, primary icon and border
+//! - **Selected**: inverseSurface background, inverseOnSurface icon, no border
 //! - **Disabled**: surfaceContainer background, outline @ 38% icon
 //!
+//! ## Toggle Icon Buttons
+//! Toggles ([`MaterialIconButton::toggle`], [`icon_button_toggle`]) animate
+//! between their unselected and selected colors and can swap to a distinct
+//! [`selected_icon`](MaterialIconButton::selected_icon):
+//! - **Standard**: onSurfaceVariant icon → primary icon
+//! - **Filled**: surfaceContainerHighest + primary icon → primary + onPrimary icon
+//! - **Filled tonal**: surfaceContainerHighest + onSurfaceVariant icon →
+//!   secondaryContainer + onSecondaryContainer icon
+//! - **Outlined**: outline border → inverseSurface + inverseOnSurface icon
+//!
+//! State layers and the press ripple always use the current icon color.
+//!
 //! ## Container Shape
 //! - **Circular (default)**: 50% corner radius (fully rounded)
 //! - **Rectangular**: 20% corner radius (rounded rectangle)
+//!
+//! ## Dimensions
+//! - **Container**: 40dp (see [`size`](MaterialIconButton::size)), icon 60% of it (24dp)
+//! - **Touch target**: at least 40dp, even for smaller containers
 
-use crate::get_global_color;
+use crate::material_symbol::material_symbol_text_or_default;
+use crate::theme::reduced_motion;
+use crate::{get_global_color, ripple::Ripple, tooltip::material_tooltip};
 use egui::{
     Align2, Color32, ColorImage, FontId, Rect, Response, Sense, Stroke, TextureHandle, TextureOptions, Ui, Vec2,
-    Widget,
+    Widget, WidgetInfo, WidgetType,
 };
-use std::path::Path;
-use std::fs;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -51,6 +67,13 @@ use resvg::usvg::{Options, Tree};
 use resvg::tiny_skia::{Pixmap, Transform};
 use resvg::render;
 
+/// Smallest area that reacts to the pointer, whatever the container size
+const MIN_TOUCH_TARGET: f32 = 40.0;
+/// Icon size relative to the container: 24dp in the default 40dp button
+const ICON_SIZE_RATIO: f32 = 0.6;
+/// Duration of the toggle container and icon color animation
+const TOGGLE_ANIMATION_S: f32 = 0.15;
+
 lazy_static::lazy_static! {
     /// Cache to store pre-rendered SVG textures (ColorImage)
     static ref SVG_IMAGE_CACHE: Mutex<HashMap<String, Arc<ColorImage>>> = Mutex::new(HashMap::new());
@@ -82,10 +105,10 @@ pub enum IconButtonVariant {
 ///     println!("Favorite clicked!");
 /// }
 ///
-/// // Filled icon button with toggle state
+/// // Filled toggle icon button
 /// let mut liked = false;
-/// ui.add(MaterialIconButton::filled("favorite")
-///     .toggle(&mut liked)
+/// ui.add(MaterialIconButton::toggle("favorite", &mut liked)
+///     .variant(IconButtonVariant::Filled)
 ///     .size(48.0));
 /// # });
 /// ```
@@ -93,6 +116,8 @@ pub enum IconButtonVariant {
 pub struct MaterialIconButton<'a> {
     /// Icon identifier (e.g., "favorite", "settings", "delete")
     icon: String,
+    /// Icon shown instead of `icon` while a toggle is selected
+    selected_icon: Option<String>,
     /// Visual variant of the button
     variant: IconButtonVariant,
     /// Optional toggle state for the button
@@ -111,6 +136,8 @@ pub struct MaterialIconButton<'a> {
     icon_color_override: Option<Color32>,
     /// Optional callback to execute when clicked
     action: Option<Box<dyn Fn() + 'a>>,
+    /// Optional plain tooltip shown on hover
+    tooltip: Option<String>,
 }
 
 impl<'a> MaterialIconButton<'a> {
@@ -129,6 +156,7 @@ impl<'a> MaterialIconButton<'a> {
     pub fn new(icon: impl Into<String>, variant: IconButtonVariant) -> Self {
         Self {
             icon: icon.into(),
+            selected_icon: None,
             variant,
             selected: None,
             enabled: true,
@@ -138,6 +166,7 @@ impl<'a> MaterialIconButton<'a> {
             svg_data: None,
             icon_color_override: None,
             action: None,
+            tooltip: None,
         }
     }
 
@@ -203,7 +232,9 @@ impl<'a> MaterialIconButton<'a> {
 
     /// Create a toggleable icon button.
     ///
-    /// The button's appearance will change based on the `selected` state.
+    /// Clicking flips `selected` and marks the response as changed; the
+    /// button's colors animate to match. Use [`variant`](Self::variant) for a
+    /// filled, tonal or outlined toggle.
     ///
     /// # Arguments
     /// * `icon` - Icon identifier
@@ -222,6 +253,26 @@ impl<'a> MaterialIconButton<'a> {
         button
     }
 
+    /// Set the visual variant, e.g. to make a filled toggle.
+    pub fn variant(mut self, variant: IconButtonVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Show a different icon while the toggle is selected.
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut visible = false;
+    /// ui.add(MaterialIconButton::toggle("visibility_off", &mut visible).selected_icon("visibility"));
+    /// # });
+    /// ```
+    pub fn selected_icon(mut self, icon: impl Into<String>) -> Self {
+        self.selected_icon = Some(icon.into());
+        self
+    }
+
     /// Set the size of the icon button.
     ///
     /// # Arguments
@@ -271,6 +322,9 @@ impl<'a> MaterialIconButton<'a> {
     }
 
     /// Use an SVG file as the icon. The path will be loaded and rasterized.
+    ///
+    /// There is no filesystem on the web; on wasm32 use [`Self::svg_data`] with
+    /// `include_str!` instead.
     pub fn svg(mut self, path: impl Into<String>) -> Self {
         self.svg_path = Some(path.into());
         self
@@ -288,7 +342,7 @@ impl<'a> MaterialIconButton<'a> {
     /// - Standard: onSurfaceVariant (default), primary (selected)
     /// - Filled: onPrimary
     /// - FilledTonal: onSecondaryContainer
-    /// - Outlined: onSurfaceVariant (default), primary (selected)
+    /// - Outlined: onSurfaceVariant (default), inverseOnSurface (selected)
     pub fn icon_color(mut self, color: Color32) -> Self {
         self.icon_color_override = Some(color);
         self
@@ -314,119 +368,155 @@ impl<'a> MaterialIconButton<'a> {
         self.action = Some(Box::new(f));
         self
     }
+
+    /// Show a Material plain tooltip on hover, e.g. the action an icon stands for.
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(MaterialIconButton::standard("delete").tooltip("Delete"));
+    /// # });
+    /// ```
+    pub fn tooltip(mut self, text: impl Into<String>) -> Self {
+        self.tooltip = Some(text.into());
+        self
+    }
 }
 
-impl<'a> Widget for MaterialIconButton<'a> {
-    fn ui(self, ui: &mut Ui) -> Response {
-        let desired_size = Vec2::splat(self.size);
-        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+/// Read an SVG icon from disk, or `None` when it doesn't exist.
+#[cfg(not(target_arch = "wasm32"))]
+fn read_svg_file(path: &str) -> Option<Vec<u8>> {
+    std::fs::read(path).ok()
+}
 
-        let is_selected = self.selected.as_ref().is_some_and(|s| **s);
+/// The web has no filesystem, so file-backed SVG icons render nothing there.
+#[cfg(target_arch = "wasm32")]
+fn read_svg_file(_path: &str) -> Option<Vec<u8>> {
+    None
+}
+
+/// Container, icon and border colors of an enabled button, before hover and press feedback
+fn variant_colors(
+    variant: IconButtonVariant,
+    toggle: bool,
+    selected: bool,
+) -> (Color32, Color32, Color32) {
+    let transparent = Color32::TRANSPARENT;
+    match (variant, toggle, selected) {
+        // Standard: no container; selected toggles use the primary icon color
+        (IconButtonVariant::Standard, true, true) => {
+            (transparent, get_global_color("primary"), transparent)
+        }
+        (IconButtonVariant::Standard, _, _) => (
+            transparent,
+            get_global_color("onSurfaceVariant"),
+            transparent,
+        ),
+        // Filled: unselected toggles sit on surfaceContainerHighest
+        (IconButtonVariant::Filled, true, false) => (
+            get_global_color("surfaceContainerHighest"),
+            get_global_color("primary"),
+            transparent,
+        ),
+        (IconButtonVariant::Filled, _, _) => (
+            get_global_color("primary"),
+            get_global_color("onPrimary"),
+            transparent,
+        ),
+        // Filled tonal: unselected toggles sit on surfaceContainerHighest
+        (IconButtonVariant::FilledTonal, true, false) => (
+            get_global_color("surfaceContainerHighest"),
+            get_global_color("onSurfaceVariant"),
+            transparent,
+        ),
+        (IconButtonVariant::FilledTonal, _, _) => (
+            get_global_color("secondaryContainer"),
+            get_global_color("onSecondaryContainer"),
+            transparent,
+        ),
+        // Outlined: selected toggles fill with inverseSurface and drop the border
+        (IconButtonVariant::Outlined, true, true) => (
+            get_global_color("inverseSurface"),
+            get_global_color("inverseOnSurface"),
+            transparent,
+        ),
+        (IconButtonVariant::Outlined, _, _) => (
+            transparent,
+            get_global_color("onSurfaceVariant"),
+            get_global_color("outline"),
+        ),
+    }
+}
+
+impl<'a> Widget for MaterialIconButton<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        // React across the whole touch target, paint the container centered in it
+        let target_size = Vec2::splat(self.size.max(MIN_TOUCH_TARGET));
+        let sense = if self.enabled {
+            Sense::click()
+        } else {
+            Sense::hover()
+        };
+        let (target_rect, mut response) = ui.allocate_exact_size(target_size, sense);
+        let rect = Rect::from_center_size(target_rect.center(), Vec2::splat(self.size));
 
         if response.clicked() && self.enabled {
-            if let Some(selected) = self.selected {
-                *selected = !*selected;
+            if let Some(selected) = &mut self.selected {
+                **selected = !**selected;
                 response.mark_changed();
             }
-            if let Some(action) = self.action {
+            if let Some(action) = self.action.take() {
                 action();
             }
         }
 
-        // M3 Color Roles - Icon Button Variants
-        let primary = get_global_color("primary"); // Filled button background, selected icon/border
-        let on_primary = get_global_color("onPrimary"); // Icon on primary background
-        let secondary_container = get_global_color("secondaryContainer"); // Tonal button background
-        let on_secondary_container = get_global_color("onSecondaryContainer"); // Icon on tonal background
-        let on_surface = get_global_color("onSurface"); // Hover state layer color
-        let on_surface_variant = get_global_color("onSurfaceVariant"); // Default icon color (lower emphasis)
-        let outline = get_global_color("outline"); // Border color, disabled icon @ 38%
-        let surface_container = get_global_color("surfaceContainer"); // Disabled background
+        let is_toggle = self.selected.is_some();
+        let is_selected = self.selected.as_ref().is_some_and(|s| **s);
+        if is_toggle {
+            let label = self.tooltip.clone().unwrap_or_else(|| self.icon.clone());
+            response.widget_info(|| {
+                WidgetInfo::selected(WidgetType::Button, self.enabled, is_selected, &label)
+            });
+        }
 
         let (bg_color, icon_color, border_color) = if !self.enabled {
-            // Disabled state: surfaceContainer background, outline @ 38% icon (M3 spec)
-            (
-                surface_container,
-                outline.linear_multiply(0.38),
-                Color32::TRANSPARENT,
-            )
-        } else {
-            match self.variant {
-                IconButtonVariant::Standard => {
-                    if is_selected {
-                        // Selected state: transparent background with primary icon
-                        (Color32::TRANSPARENT, primary, Color32::TRANSPARENT)
-                    } else if response.hovered() {
-                        // Hover state: onSurface @ 8% state layer (M3 interaction state)
-                        (
-                            on_surface.linear_multiply(0.08),
-                            on_surface,
-                            Color32::TRANSPARENT,
-                        )
-                    } else {
-                        // Default state: transparent with onSurfaceVariant icon (lower emphasis)
-                        (
-                            Color32::TRANSPARENT,
-                            on_surface_variant,
-                            Color32::TRANSPARENT,
-                        )
-                    }
-                }
-                IconButtonVariant::Filled => {
-                    // Filled button: primary background with onPrimary icon
-                    let base_color = primary;
-                    let content_color = on_primary;
-                    if is_selected {
-                        // Selected state: same as default for filled variant
-                        (base_color, content_color, Color32::TRANSPARENT)
-                    } else if response.is_pointer_button_down_on() {
-                        // Pressed state: 12% onPrimary overlay (M3 interaction state)
-                        (blend_state_layer(base_color, content_color, 0.12), content_color, Color32::TRANSPARENT)
-                    } else if response.hovered() {
-                        // Hover state: 8% onPrimary overlay (M3 interaction state)
-                        (blend_state_layer(base_color, content_color, 0.08), content_color, Color32::TRANSPARENT)
-                    } else {
-                        (base_color, content_color, Color32::TRANSPARENT)
-                    }
-                }
-                IconButtonVariant::FilledTonal => {
-                    // Tonal button: secondaryContainer background with onSecondaryContainer icon
-                    let base_color = secondary_container;
-                    let content_color = on_secondary_container;
-                    if is_selected {
-                        // Selected state: same as default for tonal variant
-                        (base_color, content_color, Color32::TRANSPARENT)
-                    } else if response.is_pointer_button_down_on() {
-                        // Pressed state: 12% onSecondaryContainer overlay (M3 interaction state)
-                        (blend_state_layer(base_color, content_color, 0.12), content_color, Color32::TRANSPARENT)
-                    } else if response.hovered() {
-                        // Hover state: 8% onSecondaryContainer overlay (M3 interaction state)
-                        (blend_state_layer(base_color, content_color, 0.08), content_color, Color32::TRANSPARENT)
-                    } else {
-                        (base_color, content_color, Color32::TRANSPARENT)
-                    }
+            // Disabled state: onSurface @ 38% icon, 12% container or outline (M3 spec)
+            let on_surface = get_global_color("onSurface");
+            let (container, outline) = match self.variant {
+                IconButtonVariant::Standard => (Color32::TRANSPARENT, Color32::TRANSPARENT),
+                IconButtonVariant::Filled | IconButtonVariant::FilledTonal => {
+                    (on_surface.linear_multiply(0.12), Color32::TRANSPARENT)
                 }
                 IconButtonVariant::Outlined => {
-                    if is_selected {
-                        // Selected state: primary @ 10% background with primary icon and border
-                        (
-                            primary.linear_multiply(0.10),
-                            primary,
-                            primary,
-                        )
-                    } else if response.hovered() {
-                        // Hover state: onSurface @ 8% state layer (M3 interaction state)
-                        (
-                            on_surface.linear_multiply(0.08),
-                            on_surface_variant,
-                            outline,
-                        )
-                    } else {
-                        // Default state: transparent with onSurfaceVariant icon and outline border
-                        (Color32::TRANSPARENT, on_surface_variant, outline)
-                    }
+                    (Color32::TRANSPARENT, on_surface.linear_multiply(0.12))
                 }
-            }
+            };
+            (container, on_surface.linear_multiply(0.38), outline)
+        } else if is_toggle {
+            // Toggles blend from their unselected to their selected colors
+            let t = if reduced_motion(ui.ctx()) {
+                if is_selected {
+                    1.0
+                } else {
+                    0.0
+                }
+            } else {
+                ui.ctx().animate_bool_with_time(
+                    response.id.with("toggle"),
+                    is_selected,
+                    TOGGLE_ANIMATION_S,
+                )
+            };
+            let (from_bg, from_icon, from_border) = variant_colors(self.variant, true, false);
+            let (to_bg, to_icon, to_border) = variant_colors(self.variant, true, true);
+            (
+                from_bg.lerp_to_gamma(to_bg, t),
+                from_icon.lerp_to_gamma(to_icon, t),
+                from_border.lerp_to_gamma(to_border, t),
+            )
+        } else {
+            // Hover and press feedback is drawn on top by the ripple
+            variant_colors(self.variant, false, false)
         };
 
         // Calculate corner radius based on container style
@@ -453,8 +543,15 @@ impl<'a> Widget for MaterialIconButton<'a> {
             );
         }
 
+        // M3 state layers and press ripple in the icon color
+        if self.enabled {
+            Ripple::new(icon_color)
+                .corner_radius(corner_radius)
+                .show(ui, rect, &response);
+        }
+
         // Draw icon: SVG (if provided) or emoji/text fallback
-        let icon_size = self.size * 0.6;
+        let icon_size = self.size * ICON_SIZE_RATIO;
         let icon_rect = Rect::from_center_size(rect.center(), Vec2::splat(icon_size));
 
         // Helper function to render SVG from bytes with caching
@@ -532,50 +629,29 @@ impl<'a> Widget for MaterialIconButton<'a> {
             render_svg(ui, bytes, &cache_key, icon_rect, icon_size);
         } else if let Some(path) = &self.svg_path {
             // Try to load and rasterize SVG from file
-            if Path::new(path).exists() {
-                if let Ok(bytes) = fs::read(path) {
-                    render_svg(ui, &bytes, path, icon_rect, icon_size);
-                }
+            if let Some(bytes) = read_svg_file(path) {
+                render_svg(ui, &bytes, path, icon_rect, icon_size);
             }
         } else {
-            // Fallback: draw provided icon string (emoji constants from `noto_emoji` or raw text)
-            let text = &self.icon;
+            // Material Symbols name, or an emoji constant from `noto_emoji` / raw text
+            let icon = match &self.selected_icon {
+                Some(selected_icon) if is_selected => selected_icon,
+                _ => &self.icon,
+            };
+            let text = material_symbol_text_or_default(icon, icon);
             let font = FontId::proportional(icon_size);
             let final_icon_color = self.icon_color_override.unwrap_or(icon_color);
             ui.painter().text(icon_rect.center(), Align2::CENTER_CENTER, text, font, final_icon_color);
         }
 
-        // Add ripple effect on hover (skip for Filled variant as it already has state changes)
-        if response.hovered() && self.enabled && self.variant != IconButtonVariant::Filled {
-            let ripple_color = Color32::from_rgba_premultiplied(
-                icon_color.r(),
-                icon_color.g(),
-                icon_color.b(),
-                30,
-            );
-            ui.painter().rect_filled(rect, corner_radius, ripple_color);
+        if let Some(text) = self.tooltip {
+            material_tooltip(&response, text);
         }
 
         response
     }
 }
 
-/// Blend a state layer overlay on top of a base color.
-///
-/// Used for M3 interactive states (hover: 8%, press: 12%).
-fn blend_state_layer(base: Color32, overlay: Color32, opacity: f32) -> Color32 {
-    let alpha = (opacity * 255.0) as u8;
-    let overlay_with_alpha = Color32::from_rgba_unmultiplied(overlay.r(), overlay.g(), overlay.b(), alpha);
-    // Alpha blending
-    let inv_alpha = 255 - alpha;
-    Color32::from_rgba_unmultiplied(
-        ((base.r() as u16 * inv_alpha as u16 + overlay_with_alpha.r() as u16 * alpha as u16) / 255) as u8,
-        ((base.g() as u16 * inv_alpha as u16 + overlay_with_alpha.g() as u16 * alpha as u16) / 255) as u8,
-        ((base.b() as u16 * inv_alpha as u16 + overlay_with_alpha.b() as u16 * alpha as u16) / 255) as u8,
-        base.a(),
-    )
-}
-
 /// Convenience function to create a standard icon button.
 ///
 /// # Arguments
@@ -636,19 +712,26 @@ pub fn icon_button_outlined(icon: impl Into<String>) -> MaterialIconButton<'stat
     MaterialIconButton::outlined(icon)
 }
 
-/// Convenience function to create a toggleable icon button.
+/// Convenience function to create a toggle icon button that swaps icons when selected.
 ///
 /// # Arguments
-/// * `icon` - Icon identifier
 /// * `selected` - Mutable reference to the toggle state
+/// * `unselected_icon` - Icon shown while unselected
+/// * `selected_icon` - Icon shown while selected
 ///
 /// # Example
 /// ```rust
 /// # egui::__run_test_ui(|ui| {
-/// let mut is_liked = false;
-/// ui.add(icon_button_toggle("favorite", &mut is_liked));
+/// let mut bookmarked = false;
+/// if ui.add(icon_button_toggle(&mut bookmarked, "bookmark_border", "bookmark")).changed() {
+///     println!("Bookmarked: {bookmarked}");
+/// }
 /// # });
 /// ```
-pub fn icon_button_toggle(icon: impl Into<String>, selected: &mut bool) -> MaterialIconButton<'_> {
-    MaterialIconButton::toggle(icon, selected)
+pub fn icon_button_toggle(
+    selected: &mut bool,
+    unselected_icon: impl Into<String>,
+    selected_icon: impl Into<String>,
+) -> MaterialIconButton<'_> {
+    MaterialIconButton::toggle(unselected_icon, selected).selected_icon(selected_icon)
 }
@@ -61,27 +61,272 @@
 //! When the `ondemand` feature is enabled, the image list provides:
 //!
 //! - **Automatic downloading**: Images are downloaded from URLs on first access
-//! - **Smart caching**: Downloaded images are saved to `/tmp/egui_material3_img/` with proper extensions
+//! - **Smart caching**: Downloaded images are saved to the image cache directory with proper extensions
 //! - **Format detection**: File extensions are determined from content (PNG, JPEG, GIF, WebP)
 //! - **Efficient reuse**: Cached images are reused without re-downloading
 //! - **Performance optimization**: UI only repaints when new images are available
 //! - **Error handling**: Failed downloads show visual indicators instead of crashing
 //!
+//! On the web (wasm32-unknown-unknown) images are fetched with the browser's
+//! fetch API through `ehttp` and kept in memory only; there is no disk cache,
+//! and local file paths can't be loaded. Use `items_from_bytes` or data URLs
+//! for bundled images. Other wasm targets, such as WASI, don't download
+//! remote images.
+//!
 //! ### Cache Management
 //!
-//! - Cache directory: `/tmp/egui_material3_img/`
-//! - File naming: `img_{hash}.{extension}` (e.g., `img_abc123.png`)
-//! - Automatic cleanup: Cache persists between runs for efficiency
-//! - Manual cleanup: Remove `/tmp/egui_material3_img/` to clear cache
-
-use crate::theme::get_global_color;
+//! - Cache directory: `egui_material3_img` in the OS cache directory
+//!   (`$XDG_CACHE_HOME` or `~/.cache` on Linux, `~/Library/Caches` on macOS,
+//!   `%LOCALAPPDATA%` on Windows), falling back to the temp directory
+//! - File naming: `img_{hash}.{extension}`, where the hash is a 128-bit hash of the URL
+//! - Size limit: once the directory holds more than `max_bytes` of images, the
+//!   least recently used ones are deleted (256 MiB by default)
+//! - Configuration: [`set_image_cache_config`] with an [`ImageCacheConfig`]
+//! - Manual cleanup: [`clear_image_cache`] deletes every cached image
+//!
+//! ```rust,no_run
+//! use egui_material3::{set_image_cache_config, ImageCacheConfig};
+//!
+//! set_image_cache_config(ImageCacheConfig {
+//!     dir: "/var/cache/my_app/images".into(),
+//!     max_bytes: 64 * 1024 * 1024,
+//! });
+//! ```
+//!
+//! ## Load States
+//!
+//! While an image loads its cell shows a shimmering placeholder. If loading
+//! fails the cell shows a broken-image icon and a "Retry" button, which drops
+//! the cached failure so the next attempt really goes back to the source.
+//! [`ImageListItem::load_state`] reports where an item currently stands.
+//!
+//! ## Lazy Loading
+//!
+//! Only items whose cells are within `.prefetch_margin(px)` of the visible
+//! area load, and off-screen textures beyond `.texture_budget(n)` are freed and
+//! loaded again when scrolled back into view. Long galleries should also enable
+//! `.lazy(true)` so images load on a background thread instead of blocking the
+//! frame that scrolls them into view.
+//!
+//! Textures belong to one `egui::Context`, so each context keeps its own cache.
+//!
+//! ```rust,no_run
+//! use egui_material3::woven_image_list;
+//!
+//! egui::ScrollArea::vertical().show(ui, |ui| {
+//!     ui.add(woven_image_list()
+//!         .lazy(true)
+//!         .prefetch_margin(300.0)
+//!         .items_from_urls(urls));
+//! });
+//! ```
+//!
+//! ## Empty Lists
+//!
+//! A list without items shows a [`MaterialEmptyState`], "No images" unless
+//! replaced with [`MaterialImageList::empty_state`]. Its action button is
+//! reported in [`ImageListResponse::empty_action_clicked`].
+
+use crate::disk_cache::{os_cache_dir, write_atomically};
+use crate::emptystate::MaterialEmptyState;
+use crate::material_symbol::ICON_BROKEN_IMAGE;
+use crate::theme::{get_global_color, reduced_motion};
 use egui::{
     ecolor::Color32,
     epaint::{CornerRadius, Stroke},
-    Rect, Response, Sense, Ui, Vec2, Widget,
+    Id, Pos2, Rect, Response, Sense, TextureHandle, Ui, Vec2, Widget,
 };
 use image::GenericImageView;
-use std::env;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::SystemTime;
+
+/// Where downloaded images are cached on disk and how large the cache may grow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImageCacheConfig {
+    /// Directory holding the cached files
+    pub dir: PathBuf,
+    /// Size above which the least recently used images are deleted
+    pub max_bytes: u64,
+}
+
+impl Default for ImageCacheConfig {
+    fn default() -> Self {
+        Self {
+            dir: os_cache_dir().join("egui_material3_img"),
+            max_bytes: 256 * 1024 * 1024,
+        }
+    }
+}
+
+static IMAGE_CACHE_CONFIG: LazyLock<Mutex<ImageCacheConfig>> =
+    LazyLock::new(|| Mutex::new(ImageCacheConfig::default()));
+
+/// Set where downloaded images are cached and how large the cache may grow.
+///
+/// The directory is created if needed, and pruned right away if it already
+/// holds more than `max_bytes`. On the web the configuration is only stored.
+pub fn set_image_cache_config(config: ImageCacheConfig) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = std::fs::create_dir_all(&config.dir);
+        prune_image_cache(&config);
+    }
+    *IMAGE_CACHE_CONFIG.lock().unwrap() = config;
+}
+
+/// The current image cache configuration.
+pub fn image_cache_config() -> ImageCacheConfig {
+    IMAGE_CACHE_CONFIG.lock().unwrap().clone()
+}
+
+/// Delete every cached image file from the image cache directory.
+///
+/// Only files named by the cache are removed, so pointing the cache at a
+/// shared directory is safe. Images already on screen stay loaded. Does
+/// nothing on the web, where there is no disk cache.
+pub fn clear_image_cache() {
+    #[cfg(not(target_arch = "wasm32"))]
+    for (path, _, _) in cached_image_files(&image_cache_config().dir) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// File name stem for the cached copy of `url`.
+///
+/// Two differently seeded 64-bit hashes are combined so that distinct URLs
+/// practically never share a file.
+#[cfg_attr(
+    any(not(feature = "ondemand"), target_family = "wasm"),
+    allow(dead_code)
+)]
+fn image_cache_key(url: &str) -> String {
+    use std::hash::{Hash, Hasher};
+
+    let mut high = std::collections::hash_map::DefaultHasher::new();
+    ("egui_material3_img", url).hash(&mut high);
+    let mut low = std::collections::hash_map::DefaultHasher::new();
+    url.hash(&mut low);
+    format!("img_{:016x}{:016x}", high.finish(), low.finish())
+}
+
+/// Cached image files in `dir` with their size and last use time
+#[cfg(not(target_arch = "wasm32"))]
+fn cached_image_files(dir: &std::path::Path) -> Vec<(PathBuf, u64, SystemTime)> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("img_"))
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            if !metadata.is_file() {
+                return None;
+            }
+            let last_used = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            Some((entry.path(), metadata.len(), last_used))
+        })
+        .collect()
+}
+
+/// Pick the least recently used files to delete so the rest fit in `max_bytes`
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+fn files_to_evict(mut files: Vec<(PathBuf, u64, SystemTime)>, max_bytes: u64) -> Vec<PathBuf> {
+    let mut total: u64 = files.iter().map(|(_, size, _)| size).sum();
+    files.sort_by_key(|(_, _, last_used)| *last_used);
+
+    let mut evicted = Vec::new();
+    for (path, size, _) in files {
+        if total <= max_bytes {
+            break;
+        }
+        total -= size;
+        evicted.push(path);
+    }
+    evicted
+}
+
+/// Delete the least recently used cached images until the cache fits its size limit
+#[cfg(not(target_arch = "wasm32"))]
+fn prune_image_cache(config: &ImageCacheConfig) {
+    for path in files_to_evict(cached_image_files(&config.dir), config.max_bytes) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// Default distance in points around the visible area within which lazy lists load images.
+pub const DEFAULT_PREFETCH_MARGIN: f32 = 200.0;
+
+/// Default number of off-screen textures a lazy list keeps decoded.
+pub const DEFAULT_TEXTURE_BUDGET: usize = 64;
+
+/// Maximum number of images loading in the background at once
+const MAX_CONCURRENT_LOADS: usize = 4;
+
+/// Seconds for the loading shimmer to sweep across a cell
+const SHIMMER_PERIOD_S: f64 = 1.5;
+
+/// Load state of an image list item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageLoadState {
+    /// Not loaded yet; lazy items that haven't been scrolled near count as loading too
+    Loading,
+    Loaded,
+    Failed,
+}
+
+/// Current load state of `image_source` in the image cache of `ctx`.
+pub fn image_load_state(ctx: &egui::Context, image_source: &str) -> ImageLoadState {
+    match image_cache(ctx)
+        .lock()
+        .unwrap()
+        .slots
+        .get(&Id::new(image_source))
+    {
+        Some(ImageSlot::Loaded { .. }) => ImageLoadState::Loaded,
+        Some(ImageSlot::Failed) => ImageLoadState::Failed,
+        Some(ImageSlot::Loading) | None => ImageLoadState::Loading,
+    }
+}
+
+/// Forget a failed load of `image_source` so the next frame tries again.
+///
+/// Images that loaded fine, or are still loading, are left alone.
+pub fn retry_image(ctx: &egui::Context, image_source: &str) {
+    let cache = image_cache(ctx);
+    let mut cache = cache.lock().unwrap();
+    let key = Id::new(image_source);
+    if matches!(cache.slots.get(&key), Some(ImageSlot::Failed)) {
+        cache.slots.remove(&key);
+    }
+}
+
+/// Load state of a single image source, shared by all image lists of a context
+#[derive(Clone)]
+pub(crate) enum ImageSlot {
+    Loading,
+    Loaded {
+        texture: TextureHandle,
+        last_used: u64,
+    },
+    Failed,
+}
+
+#[derive(Default)]
+struct ImageCache {
+    slots: HashMap<Id, ImageSlot>,
+    loading: usize,
+}
+
+/// The image cache of `ctx`; its textures are only valid in that context
+fn image_cache(ctx: &egui::Context) -> Arc<Mutex<ImageCache>> {
+    ctx.data_mut(|d| {
+        d.get_temp_mut_or_default::<Arc<Mutex<ImageCache>>>(Id::new("material3_image_cache"))
+            .clone()
+    })
+}
 
 /// Material Design image list variants.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -91,6 +336,150 @@ pub enum ImageListVariant {
     Woven,
 }
 
+/// How an image is scaled into its cell.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ImageFit {
+    /// Scale to cover the whole cell, cropping the overflow
+    Cover,
+    /// Scale to fit inside the cell, letterboxing the rest
+    Contain,
+    /// Stretch to the cell, ignoring the aspect ratio
+    #[default]
+    Fill,
+}
+
+/// Destination rect and UV rect for drawing an image of `image_size` into `rect`
+fn fit_image(fit: ImageFit, image_size: Vec2, rect: Rect) -> (Rect, Rect) {
+    let full_uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+    if image_size.x <= 0.0 || image_size.y <= 0.0 {
+        return (rect, full_uv);
+    }
+
+    match fit {
+        ImageFit::Fill => (rect, full_uv),
+        ImageFit::Contain => {
+            let scale = (rect.width() / image_size.x).min(rect.height() / image_size.y);
+            (
+                Rect::from_center_size(rect.center(), image_size * scale),
+                full_uv,
+            )
+        }
+        ImageFit::Cover => {
+            let scale = (rect.width() / image_size.x).max(rect.height() / image_size.y);
+            let visible = rect.size() / (image_size * scale);
+            let uv = Rect::from_center_size(Pos2::new(0.5, 0.5), visible);
+            (rect, uv)
+        }
+    }
+}
+
+/// Height of the caption scrim for one or two lines of text
+fn caption_scrim_height(two_line: bool) -> f32 {
+    if two_line {
+        68.0
+    } else {
+        48.0
+    }
+}
+
+/// Draw a caption over the bottom of an image, on a gradient scrim that keeps it legible
+fn paint_caption(ui: &Ui, image_rect: Rect, caption: &str, secondary_text: Option<&str>) {
+    let height = caption_scrim_height(secondary_text.is_some()).min(image_rect.height());
+    let scrim_rect = Rect::from_min_max(
+        Pos2::new(image_rect.min.x, image_rect.max.y - height),
+        image_rect.max,
+    );
+
+    // Transparent at the top, fading to a dark scrim at the bottom edge
+    let clear = Color32::TRANSPARENT;
+    let dark = Color32::from_black_alpha(160);
+    let mut mesh = egui::Mesh::default();
+    mesh.colored_vertex(scrim_rect.left_top(), clear);
+    mesh.colored_vertex(scrim_rect.right_top(), clear);
+    mesh.colored_vertex(scrim_rect.left_bottom(), dark);
+    mesh.colored_vertex(scrim_rect.right_bottom(), dark);
+    mesh.add_triangle(0, 1, 2);
+    mesh.add_triangle(1, 2, 3);
+    ui.painter().add(mesh);
+
+    let painter = ui.painter().with_clip_rect(image_rect);
+    let text_x = image_rect.min.x + 16.0;
+    match secondary_text {
+        Some(secondary_text) => {
+            painter.text(
+                Pos2::new(text_x, image_rect.max.y - 34.0),
+                egui::Align2::LEFT_BOTTOM,
+                caption,
+                egui::FontId::proportional(14.0),
+                Color32::WHITE,
+            );
+            painter.text(
+                Pos2::new(text_x, image_rect.max.y - 14.0),
+                egui::Align2::LEFT_BOTTOM,
+                secondary_text,
+                egui::FontId::proportional(12.0),
+                Color32::from_white_alpha(200),
+            );
+        }
+        None => {
+            painter.text(
+                Pos2::new(text_x, image_rect.max.y - 16.0),
+                egui::Align2::LEFT_BOTTOM,
+                caption,
+                egui::FontId::proportional(14.0),
+                Color32::WHITE,
+            );
+        }
+    }
+}
+
+/// Result of showing a [`MaterialImageList`].
+pub struct ImageListResponse {
+    /// The response of the whole list
+    pub response: Response,
+    /// Index of the item clicked this frame
+    pub clicked: Option<usize>,
+    /// Index of the item under the pointer
+    pub hovered: Option<usize>,
+    /// Index of the failed item whose Retry button was clicked this frame
+    pub retried: Option<usize>,
+    /// Whether the action of the empty state was clicked this frame
+    pub empty_action_clicked: bool,
+}
+
+/// Lay out `count` cells of a list `width` points wide, relative to its top-left corner.
+///
+/// Painting and hit testing both use these rects, so the gaps left by
+/// `item_spacing` never count as part of a cell.
+fn cell_rects(
+    variant: ImageListVariant,
+    count: usize,
+    columns: usize,
+    item_spacing: f32,
+    width: f32,
+) -> Vec<Rect> {
+    let item_width = (width - (columns - 1) as f32 * item_spacing) / columns as f32;
+    let item_height = match variant {
+        ImageListVariant::Standard => item_width, // Square items
+        ImageListVariant::Masonry => item_width * 1.2, // Slightly taller
+        ImageListVariant::Woven => item_width * 0.8, // Slightly shorter
+    };
+
+    (0..count)
+        .map(|index| {
+            let row = index / columns;
+            let col = index % columns;
+            Rect::from_min_size(
+                Pos2::new(
+                    col as f32 * (item_width + item_spacing),
+                    row as f32 * (item_height + item_spacing),
+                ),
+                Vec2::new(item_width, item_height),
+            )
+        })
+        .collect()
+}
+
 /// Material Design image list component.
 ///
 /// Image lists display a collection of images in an organized grid.
@@ -117,12 +506,23 @@ pub struct MaterialImageList<'a> {
     corner_radius: CornerRadius,
     id_salt: Option<String>,
     tmppath: String,
+    lazy: bool,
+    prefetch_margin: f32,
+    texture_budget: usize,
+    selection: Option<&'a mut Vec<usize>>,
+    image_fit: ImageFit,
+    on_retry: Option<Box<dyn Fn(usize) + Send + Sync>>,
+    empty_state: Option<MaterialEmptyState>,
 }
 
 pub struct ImageListItem<'a> {
     pub label: String,
     pub image_source: Option<String>,
     pub supporting_text: Option<String>,
+    /// Caption drawn over the bottom of the image on a gradient scrim
+    pub caption: Option<String>,
+    /// Second caption line below `caption`
+    pub secondary_text: Option<String>,
     pub on_click: Option<Box<dyn Fn() + Send + Sync>>,
     pub loaded_image: Option<egui::ColorImage>,
     _phantom: std::marker::PhantomData<&'a ()>,
@@ -134,6 +534,8 @@ impl<'a> ImageListItem<'a> {
             label: label.into(),
             image_source: Some(image_source.into()),
             supporting_text: None,
+            caption: None,
+            secondary_text: None,
             on_click: None,
             loaded_image: None,
             _phantom: std::marker::PhantomData,
@@ -145,6 +547,29 @@ impl<'a> ImageListItem<'a> {
         self
     }
 
+    /// Where this item's image currently stands in the image cache of `ctx`.
+    pub fn load_state(&self, ctx: &egui::Context) -> ImageLoadState {
+        if self.loaded_image.is_some() {
+            return ImageLoadState::Loaded;
+        }
+        match &self.image_source {
+            Some(image_source) => image_load_state(ctx, image_source),
+            None => ImageLoadState::Failed,
+        }
+    }
+
+    /// Overlay a caption on the bottom of the image, replacing the label text.
+    pub fn caption(mut self, text: impl Into<String>) -> Self {
+        self.caption = Some(text.into());
+        self
+    }
+
+    /// Add a second, smaller caption line below the caption.
+    pub fn secondary_text(mut self, text: impl Into<String>) -> Self {
+        self.secondary_text = Some(text.into());
+        self
+    }
+
     pub fn on_click<F>(mut self, callback: F) -> Self
     where
         F: Fn() + Send + Sync + 'static,
@@ -155,6 +580,7 @@ impl<'a> ImageListItem<'a> {
 }
 
 /// Load image from a local file path
+#[cfg(not(target_arch = "wasm32"))]
 fn load_image_from_file(file_path: &str) -> Option<egui::ColorImage> {
     if std::path::Path::new(file_path).exists() {
         match image::open(file_path) {
@@ -183,16 +609,18 @@ fn load_image_from_file(file_path: &str) -> Option<egui::ColorImage> {
     }
 }
 
+/// Local files can't be read from a web page
+#[cfg(target_arch = "wasm32")]
+fn load_image_from_file(_file_path: &str) -> Option<egui::ColorImage> {
+    None
+}
+
 /// Load image from URL (requires ondemand feature)
-#[cfg(feature = "ondemand")]
+#[cfg(all(feature = "ondemand", not(target_family = "wasm")))]
 fn load_image_from_url(url: &str, tmppath: &str) -> Option<egui::ColorImage> {
-    use std::hash::{Hash, Hasher};
     use std::io::Read;
 
-    let mut hasher = std::collections::hash_map::DefaultHasher::new();
-    url.hash(&mut hasher);
-    let url_hash = format!("{:x}", hasher.finish());
-    let filename = format!("img_{}", url_hash);
+    let filename = image_cache_key(url);
     let filepath = std::path::Path::new(tmppath).join(&filename);
 
     // Check if file already exists with any extension
@@ -238,7 +666,9 @@ fn load_image_from_url(url: &str, tmppath: &str) -> Option<egui::ColorImage> {
                             };
 
                             let filepath_with_ext = filepath.with_extension(extension);
-                            let _ = std::fs::write(&filepath_with_ext, &bytes);
+                            if write_atomically(&filepath_with_ext, &bytes).is_ok() {
+                                prune_image_cache(&image_cache_config());
+                            }
                         }
                     }
                 }
@@ -249,6 +679,10 @@ fn load_image_from_url(url: &str, tmppath: &str) -> Option<egui::ColorImage> {
 
     // Try to load the image from cache
     if let Some(existing_filepath) = possible_files.iter().find(|f| f.exists()) {
+        // Bump the modification time so eviction treats the file as recently used
+        if let Ok(file) = std::fs::File::options().write(true).open(existing_filepath) {
+            let _ = file.set_modified(SystemTime::now());
+        }
         match image::open(existing_filepath) {
             Ok(image) => {
                 let original_size = image.dimensions();
@@ -296,6 +730,162 @@ fn load_image_from_data_url(data_url: &str) -> Option<egui::ColorImage> {
     None
 }
 
+/// Load an image from whichever kind of source the string refers to
+fn load_image_source(
+    image_source: &str,
+    #[cfg_attr(
+        any(not(feature = "ondemand"), target_family = "wasm"),
+        allow(unused_variables)
+    )]
+    tmppath: &str,
+) -> Option<egui::ColorImage> {
+    if is_remote_source(image_source) {
+        // On the web, remote images are fetched asynchronously by `fetch_image`
+        #[cfg(all(feature = "ondemand", not(target_family = "wasm")))]
+        {
+            load_image_from_url(image_source, tmppath)
+        }
+        #[cfg(any(not(feature = "ondemand"), target_family = "wasm"))]
+        {
+            None
+        }
+    } else if image_source.starts_with("data:") {
+        load_image_from_data_url(image_source)
+    } else if let Some(bytes_str) = image_source.strip_prefix("bytes:") {
+        // Remove "bytes:" prefix
+        load_image_from_bytes(bytes_str)
+    } else {
+        load_image_from_file(image_source)
+    }
+}
+
+/// Whether the source is an http(s) URL
+pub(crate) fn is_remote_source(image_source: &str) -> bool {
+    image_source.starts_with("http://") || image_source.starts_with("https://")
+}
+
+/// Download an image with the browser's fetch API and store it in the cache.
+///
+/// The cache slot must already be marked loading; it is filled in, and a
+/// repaint requested, once the response arrives.
+#[cfg(all(feature = "ondemand", target_family = "wasm", target_os = "unknown"))]
+fn fetch_image(ctx: &egui::Context, key: Id, url: &str) {
+    let ctx = ctx.clone();
+    ehttp::fetch(ehttp::Request::get(url), move |result| {
+        let image = result
+            .ok()
+            .filter(|response| response.ok)
+            .and_then(|response| image::load_from_memory(&response.bytes).ok())
+            .map(|image| {
+                let size = image.dimensions();
+                egui::ColorImage::from_rgba_unmultiplied(
+                    [size.0 as usize, size.1 as usize],
+                    &image.to_rgba8().into_raw(),
+                )
+            });
+        let slot = image_slot_from(&ctx, key, image);
+        let cache = image_cache(&ctx);
+        let mut cache = cache.lock().unwrap();
+        cache.loading -= 1;
+        cache.slots.insert(key, slot);
+        drop(cache);
+        ctx.request_repaint();
+    });
+}
+
+/// Turn a load result into a cache slot, uploading the texture
+fn image_slot_from(ctx: &egui::Context, key: Id, image: Option<egui::ColorImage>) -> ImageSlot {
+    match image {
+        Some(image) => ImageSlot::Loaded {
+            texture: ctx.load_texture(format!("image_list_{:?}", key), image, Default::default()),
+            last_used: ctx.cumulative_pass_nr(),
+        },
+        None => ImageSlot::Failed,
+    }
+}
+
+/// Look up the cached state of `image_source`, loading it if it isn't cached.
+///
+/// Lazy lists load in the background and get `ImageSlot::Loading` until the
+/// image is ready; other lists decode on the spot. On the web, remote images
+/// are always fetched in the background since blocking isn't possible there.
+pub(crate) fn cached_image(
+    ctx: &egui::Context,
+    image_source: &str,
+    tmppath: &str,
+    lazy: bool,
+) -> Option<ImageSlot> {
+    let key = Id::new(image_source);
+    let cache_handle = image_cache(ctx);
+    let mut cache = cache_handle.lock().unwrap();
+    if let Some(slot) = cache.slots.get_mut(&key) {
+        if let ImageSlot::Loaded { last_used, .. } = slot {
+            *last_used = ctx.cumulative_pass_nr();
+        }
+        return Some(slot.clone());
+    }
+
+    #[cfg(all(feature = "ondemand", target_family = "wasm", target_os = "unknown"))]
+    if is_remote_source(image_source) {
+        if cache.loading >= MAX_CONCURRENT_LOADS {
+            return None;
+        }
+        cache.loading += 1;
+        cache.slots.insert(key, ImageSlot::Loading);
+        drop(cache);
+        fetch_image(ctx, key, image_source);
+        return Some(ImageSlot::Loading);
+    }
+
+    if !lazy || cfg!(target_arch = "wasm32") {
+        drop(cache);
+        let slot = image_slot_from(ctx, key, load_image_source(image_source, tmppath));
+        cache_handle.lock().unwrap().slots.insert(key, slot.clone());
+        return Some(slot);
+    }
+
+    // Remaining items are picked up on the repaint that follows a finished load
+    if cache.loading >= MAX_CONCURRENT_LOADS {
+        return None;
+    }
+    cache.loading += 1;
+    cache.slots.insert(key, ImageSlot::Loading);
+    drop(cache);
+
+    let ctx = ctx.clone();
+    let image_source = image_source.to_owned();
+    let tmppath = tmppath.to_owned();
+    std::thread::spawn(move || {
+        let slot = image_slot_from(&ctx, key, load_image_source(&image_source, &tmppath));
+        let mut cache = cache_handle.lock().unwrap();
+        cache.loading -= 1;
+        cache.slots.insert(key, slot);
+        drop(cache);
+        ctx.request_repaint();
+    });
+    Some(ImageSlot::Loading)
+}
+
+/// Free the least recently used textures of `ctx` among `keys`, keeping at most `budget`
+fn evict_textures(ctx: &egui::Context, keys: &[Id], budget: usize) {
+    let cache = image_cache(ctx);
+    let mut cache = cache.lock().unwrap();
+    let mut loaded: Vec<(u64, Id)> = keys
+        .iter()
+        .filter_map(|key| match cache.slots.get(key) {
+            Some(ImageSlot::Loaded { last_used, .. }) => Some((*last_used, *key)),
+            _ => None,
+        })
+        .collect();
+    if loaded.len() <= budget {
+        return;
+    }
+    loaded.sort_unstable_by_key(|(last_used, _)| *last_used);
+    for (_, key) in &loaded[..loaded.len() - budget] {
+        cache.slots.remove(key);
+    }
+}
+
 /// Load image from bytes (hex encoded)
 fn load_image_from_bytes(bytes_str: &str) -> Option<egui::ColorImage> {
     if let Ok(bytes) = hex::decode(bytes_str) {
@@ -329,9 +919,8 @@ impl<'a> MaterialImageList<'a> {
     }
 
     fn new(variant: ImageListVariant) -> Self {
-        // create img folder in tmp dir using let dir = env::temp_dir(), save the path to tmppath on MaterialImageList
-        let tmppath = env::temp_dir().join("egui_material3_img");
-        let _ = std::fs::create_dir_all(&tmppath);
+        // Downloads go to the configured image cache directory, created on first download
+        let tmppath = image_cache_config().dir;
 
         Self {
             variant,
@@ -342,6 +931,13 @@ impl<'a> MaterialImageList<'a> {
             corner_radius: CornerRadius::from(4.0),
             id_salt: None,
             tmppath: tmppath.to_string_lossy().to_string(),
+            lazy: false,
+            prefetch_margin: DEFAULT_PREFETCH_MARGIN,
+            texture_budget: DEFAULT_TEXTURE_BUDGET,
+            selection: None,
+            image_fit: ImageFit::default(),
+            on_retry: None,
+            empty_state: None,
         }
     }
 
@@ -357,6 +953,12 @@ impl<'a> MaterialImageList<'a> {
         self
     }
 
+    /// Add a fully configured item, e.g. one with a caption.
+    pub fn add_item(mut self, item: ImageListItem<'a>) -> Self {
+        self.items.push(item);
+        self
+    }
+
     /// Add an image item with callback.
     pub fn item_with_callback<F>(
         mut self,
@@ -384,6 +986,29 @@ impl<'a> MaterialImageList<'a> {
         self
     }
 
+    /// Set how images are scaled into their cells; defaults to [`ImageFit::Fill`].
+    pub fn image_fit(mut self, fit: ImageFit) -> Self {
+        self.image_fit = fit;
+        self
+    }
+
+    /// Called with the item index when the Retry button of a failed image is clicked.
+    pub fn on_retry<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.on_retry = Some(Box::new(callback));
+        self
+    }
+
+    /// Set what the list shows when it has no items.
+    ///
+    /// Defaults to a "No images" empty state.
+    pub fn empty_state(mut self, empty_state: MaterialEmptyState) -> Self {
+        self.empty_state = Some(empty_state);
+        self
+    }
+
     /// Set corner radius.
     pub fn corner_radius(mut self, corner_radius: impl Into<CornerRadius>) -> Self {
         self.corner_radius = corner_radius.into();
@@ -396,6 +1021,35 @@ impl<'a> MaterialImageList<'a> {
         self
     }
 
+    /// Load images in the background instead of while drawing the frame.
+    ///
+    /// Put the list in a `ScrollArea`; in either mode, cells outside the
+    /// visible rect plus the prefetch margin are neither loaded nor painted.
+    pub fn lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// Set how far beyond the visible area images start loading, in points.
+    pub fn prefetch_margin(mut self, margin: f32) -> Self {
+        self.prefetch_margin = margin.max(0.0);
+        self
+    }
+
+    /// Set how many off-screen textures the list keeps before freeing the oldest.
+    pub fn texture_budget(mut self, budget: usize) -> Self {
+        self.texture_budget = budget;
+        self
+    }
+
+    /// Enable multi-select: clicking an item toggles its index in `selected`.
+    ///
+    /// Selected items are marked with a checkmark, e.g. for photo pickers.
+    pub fn multi_select(mut self, selected: &'a mut Vec<usize>) -> Self {
+        self.selection = Some(selected);
+        self
+    }
+
     /// Add items from a collection of file paths.
     pub fn items_from_paths<I, P>(mut self, paths: I) -> Self
     where
@@ -453,41 +1107,56 @@ impl<'a> Default for MaterialImageList<'a> {
     }
 }
 
-impl Widget for MaterialImageList<'_> {
-    fn ui(self, ui: &mut Ui) -> Response {
+impl MaterialImageList<'_> {
+    /// Show the list and report which item was clicked or hovered.
+    pub fn show(self, ui: &mut Ui) -> ImageListResponse {
         let background_color = self.get_image_list_style();
 
         let MaterialImageList {
             variant,
-            mut items,
+            items,
             columns,
             item_spacing,
             text_protected,
             corner_radius,
             id_salt,
-            #[cfg_attr(not(feature = "ondemand"), allow(unused_variables))]
             tmppath,
+            lazy,
+            prefetch_margin,
+            texture_budget,
+            mut selection,
+            image_fit,
+            on_retry,
+            empty_state,
         } = self;
 
+        let mut clicked = None;
+        let mut hovered = None;
+        let mut retried = None;
+
         if items.is_empty() {
-            return ui.allocate_response(Vec2::ZERO, Sense::hover());
+            let empty = empty_state
+                .unwrap_or_else(|| MaterialEmptyState::new("No images").icon("photo_library"))
+                .show(ui);
+            return ImageListResponse {
+                response: empty.response,
+                clicked,
+                hovered,
+                retried,
+                empty_action_clicked: empty.action_clicked,
+            };
         }
 
         // Calculate grid dimensions
         let available_width = ui.available_width();
-        let item_width = (available_width - (columns - 1) as f32 * item_spacing) / columns as f32;
-        let item_height = match variant {
-            ImageListVariant::Standard => item_width, // Square items
-            ImageListVariant::Masonry => item_width * 1.2, // Slightly taller
-            ImageListVariant::Woven => item_width * 0.8, // Slightly shorter
-        };
-
-        let rows = items.len().div_ceil(columns);
-        let total_height = rows as f32 * (item_height + item_spacing) - item_spacing;
+        let cells = cell_rects(variant, items.len(), columns, item_spacing, available_width);
+        let total_height = cells.iter().map(|cell| cell.max.y).fold(0.0, f32::max);
         let total_width = available_width;
 
         let response = ui.allocate_response(Vec2::new(total_width, total_height), Sense::hover());
         let rect = response.rect;
+        let load_rect = ui.clip_rect().expand(prefetch_margin);
+        let mut offscreen_keys = Vec::new();
 
         if ui.is_rect_visible(rect) {
             // Draw background
@@ -495,17 +1164,15 @@ impl Widget for MaterialImageList<'_> {
                 .rect_filled(rect, corner_radius, background_color);
 
             // Draw items in grid
-            for (index, item) in items.iter_mut().enumerate() {
-                let row = index / columns;
-                let col = index % columns;
-
-                let item_x = rect.min.x + col as f32 * (item_width + item_spacing);
-                let item_y = rect.min.y + row as f32 * (item_height + item_spacing);
+            for (index, (item, cell)) in items.iter().zip(&cells).enumerate() {
+                let item_rect = cell.translate(rect.min.to_vec2());
 
-                let item_rect = Rect::from_min_size(
-                    egui::pos2(item_x, item_y),
-                    Vec2::new(item_width, item_height),
-                );
+                if !load_rect.intersects(item_rect) {
+                    if let Some(ref image_source) = item.image_source {
+                        offscreen_keys.push(Id::new(image_source.as_str()));
+                    }
+                    continue;
+                }
 
                 // Handle item interaction with unique ID
                 let item_id = if let Some(ref salt) = id_salt {
@@ -516,12 +1183,21 @@ impl Widget for MaterialImageList<'_> {
 
                 let item_response = ui.interact(item_rect, item_id, Sense::click());
                 if item_response.hovered() {
+                    hovered = Some(index);
                     let hover_color = get_global_color("primary").linear_multiply(0.08);
                     ui.painter()
                         .rect_filled(item_rect, corner_radius, hover_color);
                 }
 
                 if item_response.clicked() {
+                    clicked = Some(index);
+                    if let Some(selected) = selection.as_deref_mut() {
+                        if let Some(pos) = selected.iter().position(|&i| i == index) {
+                            selected.remove(pos);
+                        } else {
+                            selected.push(index);
+                        }
+                    }
                     if let Some(callback) = &item.on_click {
                         callback();
                     }
@@ -541,69 +1217,55 @@ impl Widget for MaterialImageList<'_> {
                     egui::epaint::StrokeKind::Outside,
                 );
 
-                // Load and cache image if not already loaded
-                if item.loaded_image.is_none() {
-                    if let Some(ref image_source) = item.image_source {
-                        let loaded_image = if image_source.starts_with("http://")
-                            || image_source.starts_with("https://")
-                        {
-                            #[cfg(feature = "ondemand")]
-                            {
-                                load_image_from_url(image_source, &tmppath)
-                            }
-                            #[cfg(not(feature = "ondemand"))]
-                            {
-                                None
-                            }
-                        } else if image_source.starts_with("data:") {
-                            load_image_from_data_url(image_source)
-                        } else if let Some(bytes_str) = image_source.strip_prefix("bytes:") {
-                            // Remove "bytes:" prefix
-                            load_image_from_bytes(bytes_str)
-                        } else {
-                            load_image_from_file(image_source)
-                        };
-
-                        // Cache the loaded image (even if None for failed loads)
-                        item.loaded_image = loaded_image;
-                    }
-                }
-
-                // Render the image if available
-                let mut failed = false;
-                if let Some(ref color_image) = item.loaded_image {
+                // Images given up front are uploaded as-is; sources go through
+                // the shared cache so they're decoded once, not every frame
+                let slot = if let Some(ref color_image) = item.loaded_image {
                     let texture_name = format!("image_texture_{}_{}", item_id.value(), item.label);
-                    let texture_id = ui.ctx().load_texture(
-                        texture_name,
-                        color_image.clone(),
-                        Default::default(),
-                    );
-                    ui.painter().image(
-                        texture_id.id(),
-                        image_rect,
-                        egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
-                        Color32::WHITE,
-                    );
+                    Some(ImageSlot::Loaded {
+                        texture: ui.ctx().load_texture(
+                            texture_name,
+                            color_image.clone(),
+                            Default::default(),
+                        ),
+                        last_used: 0,
+                    })
+                } else if let Some(ref image_source) = item.image_source {
+                    cached_image(ui.ctx(), image_source, &tmppath, lazy)
                 } else {
-                    failed = true;
+                    Some(ImageSlot::Failed)
+                };
+
+                // Cells in the prefetch margin only start loading
+                if !ui.is_rect_visible(item_rect) {
+                    continue;
                 }
 
-                if failed {
-                    // Debug: Print when showing X marks
-                    println!("SHOWING X MARK for item: {}", item.label);
-                    // Draw a simple "X" to indicate failed image load
-                    let line_color = get_global_color("error");
-                    ui.painter().line_segment(
-                        [image_rect.min, image_rect.max],
-                        Stroke::new(2.0, line_color),
-                    );
-                    ui.painter().line_segment(
-                        [
-                            egui::pos2(image_rect.min.x, image_rect.max.y),
-                            egui::pos2(image_rect.max.x, image_rect.min.y),
-                        ],
-                        Stroke::new(2.0, line_color),
-                    );
+                // Render the image if available
+                match slot {
+                    Some(ImageSlot::Loaded { texture, .. }) => {
+                        let (dest_rect, uv) = fit_image(image_fit, texture.size_vec2(), image_rect);
+                        ui.painter()
+                            .image(texture.id(), dest_rect, uv, Color32::WHITE);
+                    }
+                    Some(ImageSlot::Failed) => {
+                        let retry_clicked = paint_failed_state(
+                            ui,
+                            image_rect,
+                            item_id,
+                            item.image_source.is_some(),
+                        );
+                        if retry_clicked {
+                            if let Some(ref image_source) = item.image_source {
+                                retry_image(ui.ctx(), image_source);
+                            }
+                            retried = Some(index);
+                            if let Some(callback) = &on_retry {
+                                callback(index);
+                            }
+                            ui.ctx().request_repaint();
+                        }
+                    }
+                    Some(ImageSlot::Loading) | None => paint_shimmer(ui, image_rect),
                 }
 
                 // Draw text overlay or below image
@@ -613,7 +1275,9 @@ impl Widget for MaterialImageList<'_> {
                     get_global_color("onSurface")
                 };
 
-                if text_protected {
+                if let Some(caption) = &item.caption {
+                    paint_caption(ui, image_rect, caption, item.secondary_text.as_deref());
+                } else if text_protected {
                     // Draw dark overlay for text protection
                     let overlay_rect = Rect::from_min_size(
                         egui::pos2(image_rect.min.x, image_rect.max.y - 40.0),
@@ -679,10 +1343,142 @@ impl Widget for MaterialImageList<'_> {
                         );
                     }
                 }
+
+                if let Some(selected) = selection.as_deref() {
+                    paint_selection_indicator(
+                        ui,
+                        image_rect,
+                        corner_radius,
+                        selected.contains(&index),
+                    );
+                }
             }
         }
 
-        response
+        evict_textures(ui.ctx(), &offscreen_keys, texture_budget);
+
+        ImageListResponse {
+            response,
+            clicked,
+            hovered,
+            retried,
+            empty_action_clicked: false,
+        }
+    }
+}
+
+/// Sweep a highlight band across a loading image's placeholder
+fn paint_shimmer(ui: &Ui, image_rect: Rect) {
+    if reduced_motion(ui.ctx()) {
+        return;
+    }
+
+    let t = (ui.input(|i| i.time) / SHIMMER_PERIOD_S).fract() as f32;
+    let band = image_rect.width() * 0.5;
+    let x = image_rect.min.x - band + t * (image_rect.width() + band * 2.0);
+
+    let clear = Color32::TRANSPARENT;
+    let highlight = get_global_color("surface").gamma_multiply(0.5);
+    let mut mesh = egui::Mesh::default();
+    for (dx, color) in [(-band, clear), (0.0, highlight), (band, clear)] {
+        mesh.colored_vertex(Pos2::new(x + dx, image_rect.min.y), color);
+        mesh.colored_vertex(Pos2::new(x + dx, image_rect.max.y), color);
+    }
+    mesh.add_triangle(0, 1, 2);
+    mesh.add_triangle(1, 2, 3);
+    mesh.add_triangle(2, 3, 4);
+    mesh.add_triangle(3, 4, 5);
+    ui.painter().with_clip_rect(image_rect).add(mesh);
+
+    ui.ctx().request_repaint();
+}
+
+/// Draw the broken-image icon and, if the item can be retried, a Retry button.
+///
+/// Returns whether the Retry button was clicked.
+fn paint_failed_state(ui: &Ui, image_rect: Rect, item_id: Id, can_retry: bool) -> bool {
+    let painter = ui.painter().with_clip_rect(image_rect);
+    let center = image_rect.center();
+    let icon_center = if can_retry {
+        center - Vec2::new(0.0, 16.0)
+    } else {
+        center
+    };
+    painter.text(
+        icon_center,
+        egui::Align2::CENTER_CENTER,
+        ICON_BROKEN_IMAGE.to_string(),
+        egui::FontId::proportional(24.0),
+        get_global_color("onSurfaceVariant"),
+    );
+
+    if !can_retry {
+        return false;
+    }
+
+    let button_rect = Rect::from_center_size(center + Vec2::new(0.0, 16.0), Vec2::new(72.0, 28.0))
+        .intersect(image_rect);
+    let response = ui.interact(button_rect, item_id.with("retry"), Sense::click());
+    if response.hovered() {
+        painter.rect_filled(
+            button_rect,
+            CornerRadius::from(14.0),
+            get_global_color("primary").linear_multiply(0.08),
+        );
+    }
+    painter.text(
+        button_rect.center(),
+        egui::Align2::CENTER_CENTER,
+        "Retry",
+        egui::FontId::proportional(14.0),
+        get_global_color("primary"),
+    );
+
+    response.clicked()
+}
+
+/// Draw the multi-select check circle in the top-left corner of an image
+fn paint_selection_indicator(
+    ui: &Ui,
+    image_rect: Rect,
+    corner_radius: CornerRadius,
+    selected: bool,
+) {
+    let painter = ui.painter();
+    let center = image_rect.left_top() + Vec2::splat(20.0);
+    let radius = 10.0;
+
+    if selected {
+        let primary = get_global_color("primary");
+        painter.rect_stroke(
+            image_rect,
+            corner_radius,
+            Stroke::new(3.0, primary),
+            egui::epaint::StrokeKind::Inside,
+        );
+        painter.circle_filled(center, radius, primary);
+
+        let size = 12.0;
+        let start = Pos2::new(center.x - size * 0.3, center.y);
+        let middle = Pos2::new(center.x - size * 0.1, center.y + size * 0.2);
+        let end = Pos2::new(center.x + size * 0.3, center.y - size * 0.2);
+        let stroke = Stroke::new(2.0, get_global_color("onPrimary"));
+        painter.line_segment([start, middle], stroke);
+        painter.line_segment([middle, end], stroke);
+    } else {
+        // Unselected items show an empty circle so the mode is discoverable
+        painter.circle(
+            center,
+            radius,
+            Color32::from_black_alpha(64),
+            Stroke::new(2.0, Color32::WHITE),
+        );
+    }
+}
+
+impl Widget for MaterialImageList<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
     }
 }
 
@@ -700,3 +1496,147 @@ pub fn masonry_image_list() -> MaterialImageList<'static> {
 pub fn woven_image_list() -> MaterialImageList<'static> {
     MaterialImageList::woven()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_cache_key_is_stable_and_distinct() {
+        let key = image_cache_key("https://example.com/a.png");
+        assert_eq!(key, image_cache_key("https://example.com/a.png"));
+        assert_ne!(key, image_cache_key("https://example.com/b.png"));
+        assert_eq!(key.len(), "img_".len() + 32);
+    }
+
+    #[test]
+    fn test_files_to_evict_removes_least_recently_used() {
+        let at = |secs| SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(secs);
+        let files = vec![
+            (PathBuf::from("img_new.png"), 40, at(30)),
+            (PathBuf::from("img_old.png"), 40, at(10)),
+            (PathBuf::from("img_mid.jpg"), 40, at(20)),
+        ];
+
+        assert_eq!(
+            files_to_evict(files.clone(), 80),
+            vec![PathBuf::from("img_old.png")]
+        );
+        assert_eq!(
+            files_to_evict(files.clone(), 50),
+            vec![PathBuf::from("img_old.png"), PathBuf::from("img_mid.jpg")]
+        );
+        assert!(files_to_evict(files, 120).is_empty());
+    }
+
+    #[test]
+    fn test_retry_forgets_failed_load() {
+        let ctx = egui::Context::default();
+        let source = "retry_test_missing.png";
+        image_cache(&ctx)
+            .lock()
+            .unwrap()
+            .slots
+            .insert(Id::new(source), ImageSlot::Failed);
+        assert_eq!(image_load_state(&ctx, source), ImageLoadState::Failed);
+
+        retry_image(&ctx, source);
+        assert_eq!(image_load_state(&ctx, source), ImageLoadState::Loading);
+    }
+
+    #[test]
+    fn test_contexts_do_not_share_textures() {
+        let ctx = egui::Context::default();
+        let other = egui::Context::default();
+        let source = "context_test.png";
+        let texture = ctx.load_texture(
+            "context_test",
+            egui::ColorImage::new([1, 1], vec![Color32::WHITE]),
+            Default::default(),
+        );
+        image_cache(&ctx).lock().unwrap().slots.insert(
+            Id::new(source),
+            ImageSlot::Loaded {
+                texture,
+                last_used: 0,
+            },
+        );
+
+        assert_eq!(image_load_state(&ctx, source), ImageLoadState::Loaded);
+        assert_eq!(image_load_state(&other, source), ImageLoadState::Loading);
+    }
+
+    #[test]
+    fn test_fit_image_modes() {
+        let cell = Rect::from_min_size(Pos2::ZERO, Vec2::new(100.0, 100.0));
+        let wide = Vec2::new(200.0, 100.0);
+
+        let (rect, uv) = fit_image(ImageFit::Fill, wide, cell);
+        assert_eq!(rect, cell);
+        assert_eq!(uv, Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)));
+
+        // Cover crops the sides of a wide image
+        let (rect, uv) = fit_image(ImageFit::Cover, wide, cell);
+        assert_eq!(rect, cell);
+        assert_eq!(
+            uv,
+            Rect::from_min_max(Pos2::new(0.25, 0.0), Pos2::new(0.75, 1.0))
+        );
+
+        // Contain letterboxes it top and bottom
+        let (rect, _) = fit_image(ImageFit::Contain, wide, cell);
+        assert_eq!(
+            rect,
+            Rect::from_min_max(Pos2::new(0.0, 25.0), Pos2::new(100.0, 75.0))
+        );
+    }
+
+    #[test]
+    fn test_cell_rects_leave_spacing_gaps() {
+        let cells = cell_rects(ImageListVariant::Masonry, 5, 2, 10.0, 210.0);
+        assert_eq!(cells.len(), 5);
+        assert_eq!(
+            cells[0],
+            Rect::from_min_size(Pos2::ZERO, Vec2::new(100.0, 120.0))
+        );
+        assert_eq!(cells[1].min, Pos2::new(110.0, 0.0));
+        assert_eq!(cells[2].min, Pos2::new(0.0, 130.0));
+
+        // A point in the gap between two cells belongs to neither
+        let gap = Pos2::new(105.0, 50.0);
+        assert!(cells.iter().all(|cell| !cell.contains(gap)));
+    }
+
+    #[test]
+    fn test_evict_textures_keeps_most_recently_used() {
+        let ctx = egui::Context::default();
+        let keys: Vec<Id> = (0..4).map(|i| Id::new(("evict_test", i))).collect();
+        {
+            let cache = image_cache(&ctx);
+            let mut cache = cache.lock().unwrap();
+            for (i, key) in keys.iter().enumerate() {
+                let texture = ctx.load_texture(
+                    format!("evict_test_{}", i),
+                    egui::ColorImage::new([1, 1], vec![Color32::WHITE]),
+                    Default::default(),
+                );
+                cache.slots.insert(
+                    *key,
+                    ImageSlot::Loaded {
+                        texture,
+                        last_used: i as u64,
+                    },
+                );
+            }
+        }
+
+        evict_textures(&ctx, &keys, 2);
+
+        let cache = image_cache(&ctx);
+        let cache = cache.lock().unwrap();
+        assert!(!cache.slots.contains_key(&keys[0]));
+        assert!(!cache.slots.contains_key(&keys[1]));
+        assert!(cache.slots.contains_key(&keys[2]));
+        assert!(cache.slots.contains_key(&keys[3]));
+    }
+}
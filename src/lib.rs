@@ -104,6 +104,19 @@
 //! load_themes();
 //! ```
 //!
+//! ### Switching Between Themes
+//!
+//! Every prepared theme stays available, so an app can offer a theme picker:
+//!
+//! ```rust,no_run
+//! use egui_material3::theme::{activate_theme, available_theme_names, setup_local_theme};
+//!
+//! setup_local_theme(Some("themes/ocean.json"));
+//! setup_local_theme(Some("themes/forest.json"));
+//! assert_eq!(available_theme_names(), ["ocean", "forest"]);
+//! activate_theme("forest").unwrap();
+//! ```
+//!
 //! ### Theme Modes and Contrast
 //!
 //! Support for multiple theme modes and contrast levels:
@@ -129,25 +142,36 @@
 //! - [`MaterialSwitch`] - Toggle switches
 //! - [`MaterialRadio`] - Radio button groups
 //! - [`MaterialSelect`] - Dropdown selection components
+//! - [`MaterialTextField`] - Filled and outlined text fields with floating labels
+//! - [`MaterialSegmentedButton`] - Connected single- and multi-select button sets
 //!
 //! ### Advanced Components
 //! - [`MaterialChip`] - Filter and action chips
 //! - [`MaterialCard2`] - Material Design cards
 //! - [`MaterialDialog`] - Modal dialogs and alerts
+//! - [`MaterialCommandPalette`] - Searchable command overlay for ⌘K / Ctrl+K
+//! - [`MaterialDatePicker`] - Modal and docked date pickers with month and year views
+//! - [`MaterialTimePicker`] - Clock-dial and keyboard-input time pickers
 //! - [`MaterialFab`] - Floating Action Buttons
 //! - [`MaterialProgress`] - Progress indicators and loading states
 //! - [`MaterialDataTable`] - Data tables with sorting and selection
 //!
 //! ### Navigation Components
 //! - [`MaterialTabs`] - Tab navigation
+//! - [`MaterialStepper`] - Numbered steps for onboarding and multi-step forms
 //! - [`MaterialDrawer`] - Navigation drawers
 //! - [`MaterialTopAppBar`] - App bars and toolbars
+//! - [`MaterialNavigationBar`] - Bottom navigation bars for compact layouts
+//! - [`MaterialNavigationRail`] - Navigation rails for medium-width layouts
 //!
 //! ### Icons and Visual Elements
 //! - [`MaterialIcon`] - Material Design icons with font support
+//! - [`MaterialEmptyState`] - Centered icon, title, body and action for views with nothing to show
+//! - [`MaterialAvatar`] - Image, initials and icon avatars with an optional status dot
 //! - [`MaterialList`] - Lists following Material Design patterns
 //! - [`MaterialImageList`] - Image lists with online/offline support and smart caching
 //! - [`MaterialTimeline`] - Timeline component for displaying chronological events
+//! - [`Ripple`] - Shared hover/focus state layers and press ripple for custom widgets
 //!
 //! ## Image Lists and OnDemand Feature
 //!
@@ -230,6 +254,7 @@
 //! accessible, and beautiful user interfaces.
 
 pub mod actionsheet;
+pub mod avatar;
 pub mod badge;
 pub mod breadcrumbs;
 pub mod button;
@@ -237,10 +262,14 @@ pub mod card2;
 pub mod carousel;
 pub mod checkbox;
 pub mod chips;
+pub mod commandpalette;
 pub mod dashcounter;
 pub mod datatable;
+pub mod datepicker;
 pub mod dialog;
+mod disk_cache;
 pub mod drawer;
+pub mod emptystate;
 pub mod fab;
 pub mod icon;
 pub mod iconbutton;
@@ -253,19 +282,26 @@ pub mod imagelist;
 pub mod layoutgrid;
 pub mod list;
 pub mod menu;
+pub mod navigationbar;
+pub mod navigationrail;
 pub mod notification;
 pub mod progress;
 pub mod radio;
+pub mod ripple;
+pub mod segmentedbutton;
 pub mod select;
 pub mod slider;
 pub mod snackbar;
+pub mod stepper;
 #[cfg(feature = "spreadsheet")]
 pub mod egui_async_std;
 pub mod spreadsheet;
 pub mod switch;
 pub mod tabs;
+pub mod textfield;
 pub mod theme;
 pub mod timeline;
+pub mod timepicker;
 pub mod toolbar;
 pub mod tooltip;
 pub mod topappbar;
@@ -273,65 +309,106 @@ pub mod treeview;
 
 pub use {
     actionsheet::{action_sheet, ActionButton, ActionGroup, MaterialActionSheet},
-    badge::{badge, badge_dot, BadgeColor, BadgePosition, BadgeSize, MaterialBadge},
+    avatar::{AvatarShape, AvatarSize, MaterialAvatar},
+    badge::{badge, badge_dot, with_badge, BadgeColor, BadgePosition, BadgeSize, MaterialBadge},
     breadcrumbs::{breadcrumbs, MaterialBreadcrumbs},
     button::{MaterialButton, MaterialButtonVariant},
     card2::{elevated_card2, filled_card2, outlined_card2, Card2Variant, MaterialCard2},
     carousel::{carousel, CarouselItem, MaterialCarousel},
-    checkbox::{checkbox, MaterialCheckbox},
-    chips::{assist_chip, filter_chip, input_chip, suggestion_chip, ChipVariant, MaterialChip},
+    checkbox::{checkbox, CheckState, MaterialCheckbox},
+    chips::{
+        assist_chip, chip_set, filter_chip, input_chip, suggestion_chip, ChipResponse,
+        ChipSetResponse, ChipVariant, MaterialChip, MaterialChipSet,
+    },
+    commandpalette::{Command, MaterialCommandPalette},
     dashcounter::{dashcounter, CounterCard, MaterialDashCounter},
     datatable::{
-        data_table, CellContent, ColumnWidth, DataTableCell, DataTableColumn, DataTableRow,
-        DataTableSource, DataTableState, DataTableTheme, HAlign, MaterialDataTable, RowAction,
-        SortDirection, VAlign,
+        data_table, CellContent, CellEdit, CellValue, ColumnWidth, DataTableCell, DataTableColumn,
+        DataTableRow, DataTableSource, DataTableState, DataTableTheme, EditKind, HAlign,
+        MaterialDataTable, RowAction, SortDirection, VAlign,
     },
-    dialog::{dialog, MaterialDialog},
+    datepicker::{date_picker, MaterialDatePicker},
+    dialog::{dialog, DialogResult, DialogStack, MaterialDialog},
     drawer::{
         dismissible_drawer, modal_drawer, permanent_drawer, standard_drawer, DrawerAlignment,
-        DrawerHeader, DrawerItem, DrawerSection, DrawerThemeData, DrawerVariant, MaterialDrawer,
+        DrawerHeader, DrawerItem, DrawerResponse, DrawerSection, DrawerThemeData, DrawerVariant,
+        MaterialDrawer,
     },
     egui::TextEdit, // Re-export egui's TextEdit
+    emptystate::{empty_state, EmptyStateResponse, MaterialEmptyState},
     fab::{
-        fab_branded, fab_primary, fab_secondary, fab_surface, fab_tertiary, google_branded_icon,
-        FabSize, FabVariant, MaterialFab, SvgIcon, SvgPath,
+        extended_fab_primary, fab_branded, fab_primary, fab_secondary, fab_surface, fab_tertiary,
+        google_branded_icon, FabMenu, FabMenuResponse, FabSize, FabVariant, MaterialFab, SvgIcon,
+        SvgPath,
     },
-    icon::{icon, MaterialIcon},
+    icon::{icon, variable_axes_supported, IconAxes, MaterialIcon},
     iconbutton::{
         icon_button_filled, icon_button_filled_tonal, icon_button_outlined, icon_button_standard,
         icon_button_toggle, IconButtonVariant, MaterialIconButton,
     },
     imagelist::{
-        image_list, masonry_image_list, woven_image_list, ImageListItem, ImageListVariant,
-        MaterialImageList,
+        clear_image_cache, image_cache_config, image_list, masonry_image_list,
+        set_image_cache_config, woven_image_list, ImageCacheConfig, ImageFit, ImageListItem,
+        ImageListResponse, ImageListVariant, ImageLoadState, MaterialImageList,
     },
     layoutgrid::{debug_layout_grid, layout_grid, GridTile, GridTileBar, MaterialLayoutGrid},
-    list::{list, list_item, ListItem, ListTileStyle, ListTileTitleAlignment, MaterialList, VisualDensity},
+    list::{
+        list, list_item, ListItem, ListResponse, ListTileStyle, ListTileTitleAlignment,
+        MaterialList, SwipeAction, VisualDensity,
+    },
     menu::{
-        menu, menu_item, Corner, FocusState, MaterialMenu, MenuBarThemeData,
-        MenuButtonThemeData, MenuItem, MenuStyle, MenuThemeData, Positioning,
+        material_context_menu, menu, menu_item, ContextMenu, Corner, FocusState, MaterialMenu,
+        MenuBarThemeData, MenuButtonThemeData, MenuItem, MenuStyle, MenuThemeData, Positioning,
+    },
+    navigationbar::{
+        navigation_bar, BarDestination, LabelBehavior, MaterialNavigationBar,
+        NavigationBarResponse,
+    },
+    navigationrail::{
+        navigation_rail, MaterialNavigationRail, NavigationRailResponse, RailAlignment,
+        RailDestination,
     },
     notification::{notification, MaterialNotification, MaterialNotificationWithOffset, NotificationAlign},
     progress::{circular_progress, linear_progress, MaterialProgress, ProgressVariant},
     radio::{radio, radio_group, radio_list_tile, MaterialRadio, MaterialRadioGroup, RadioListTile, ListTileControlAffinity},
+    ripple::Ripple,
+    segmentedbutton::{
+        segmented_button, ButtonSegment, MaterialSegmentedButton, SegmentedButtonResponse,
+    },
     select::{select, MaterialSelect, SelectVariant, MenuAlignment},
-    slider::{slider, range_slider, MaterialSlider, MaterialRangeSlider, RangeValues, SliderInteraction, ThumbShape},
-    snackbar::{snackbar, snackbar_with_action, MaterialSnackbar, SnackbarPosition, SnackBarBehavior},
+    slider::{
+        range_slider, slider, MaterialRangeSlider, MaterialSlider, RangeSliderResponse, RangeThumb,
+        RangeValues, SliderInteraction, ThumbShape, ValueIndicator,
+    },
+    snackbar::{
+        snackbar, snackbar_with_action, MaterialSnackbar, SnackBarBehavior, SnackbarDuration,
+        SnackbarHost, SnackbarPosition, SnackbarResponse,
+    },
+    stepper::{stepper, MaterialStepper, Step, StepState, StepperOrientation, StepperResponse},
     switch::{switch, MaterialSwitch},
-    tabs::{tabs_primary, tabs_secondary, MaterialTabs, TabVariant},
+    tabs::{tabs_primary, tabs_secondary, MaterialTabs, TabItem, TabVariant, TabsResponse},
+    textfield::{
+        outlined_text_field, text_field, MaterialTextField, TextFieldResponse, TextFieldVariant,
+    },
     theme::{
-        get_global_color, get_global_theme, update_global_theme, ContrastLevel,
-        MaterialThemeContext, MaterialThemeFile, ThemeMode,
+        contrast_ratio, dismiss_on_escape, get_global_color, get_global_theme, trap_focus,
+        update_global_theme, with_color_provider, ColorProvider, ContrastLevel, ContrastWarning,
+        FocusTrap, GlobalColors, MaterialScrollAreaExt, MaterialThemeContext, MaterialThemeFile,
+        Shortcut, ThemeAnimator, ThemeError, ThemeMode, ThemeScope,
     },
     timeline::{
         timeline, MaterialTimeline, TimelineDot, TimelineDotColor, TimelineDotVariant,
         TimelineItem, TimelinePosition,
     },
+    timepicker::{time_picker, MaterialTimePicker},
     toolbar::{toolbar, MaterialToolbar, ToolbarElevation},
-    tooltip::{show_tooltip_on_hover, show_tooltip_on_hover_custom, tooltip, with_tooltip, MaterialTooltip, TooltipPosition},
+    tooltip::{
+        material_tooltip, rich_tooltip, show_tooltip_on_hover, show_tooltip_on_hover_custom,
+        tooltip, with_tooltip, MaterialTooltip, RichTooltip, TooltipPosition,
+    },
     topappbar::{
         center_aligned_top_app_bar, large_top_app_bar, medium_top_app_bar, top_app_bar,
-        MaterialTopAppBar, TopAppBarVariant,
+        AppBarAction, MaterialTopAppBar, TopAppBarResponse, TopAppBarVariant,
     },
     treeview::{tree_view, MaterialTreeView, TreeViewItem, TreeViewState},
 };
@@ -13,14 +13,44 @@
 //! ## Accent Color Roles (Selection)
 //! - **primaryContainer**: Selected item background (less emphasized fill)
 //! - **onPrimaryContainer**: Text and icons on selected items
+//! - Leading avatars take their colors from [`MaterialAvatar`]
 //!
 //! ## State Layers
 //! - **Hover**: onSurface @ 8% opacity (M3 interaction state)
 //! - **Disabled**: 38% opacity applied to text/icons (M3 disabled state)
+//!
+//! ## Reordering
+//! - **surfaceContainerHigh**: Lifted item while dragged (elevation level 2)
+//! - **primary**: Drop indicator line
+//!
+//! ## Swipe Actions
+//! - **surfaceContainerLowest**: Swiped item surface, sliding over its actions
+//! - Action backgrounds use the color given to each [`SwipeAction`], such as
+//!   **error** for a delete action
 
+use crate::avatar::MaterialAvatar;
 use crate::material_symbol::material_symbol_text;
-use crate::theme::get_global_color;
-use egui::{self, Color32, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget};
+use crate::ripple::Ripple;
+use crate::theme::{
+    contrast_ratio, elevation_shadow, get_global_color, is_rtl, mirror_align, mirror_rect,
+    mirror_x, reduced_motion,
+};
+use egui::{self, Color32, Painter, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget};
+
+/// Width of the trailing drag handle in reorderable lists
+const DRAG_HANDLE_WIDTH: f32 = 40.0;
+/// Seconds the other items take to slide out of the way of a dragged item
+const REORDER_ANIMATION_S: f32 = 0.15;
+/// Seconds an item must be held before dragging its body reorders it
+const LONG_PRESS_S: f64 = 0.5;
+/// Distance from the visible edge within which dragging scrolls the list
+const AUTOSCROLL_MARGIN: f32 = 32.0;
+/// Width of each action revealed by swiping an item
+const SWIPE_ACTION_WIDTH: f32 = 72.0;
+/// Fraction of the item width a swipe must travel to invoke the primary action
+const FULL_SWIPE_FRACTION: f32 = 0.6;
+/// Seconds for a released item to snap open or closed
+const SWIPE_ANIMATION_S: f64 = 0.2;
 
 /// Defines the title font used for ListTile descendants.
 ///
@@ -131,6 +161,130 @@ pub struct MaterialList<'a> {
     dividers: bool,
     /// Optional unique ID for this list to avoid widget ID collisions
     id: Option<egui::Id>,
+    /// Whether to skip items that fall outside the visible clip rect
+    virtualized: bool,
+    /// Whether items can be dragged to a new position
+    reorderable: bool,
+}
+
+/// Response from showing a [`MaterialList`].
+pub struct ListResponse {
+    /// The standard egui response covering the list
+    pub response: Response,
+    /// `(from, to)` when an item was dropped at a new index this frame.
+    /// Apply it with `let item = items.remove(from); items.insert(to, item);`
+    pub moved: Option<(usize, usize)>,
+    /// `(item index, action id)` when a [`SwipeAction`] was invoked this frame
+    pub swiped: Option<(usize, String)>,
+    /// `(item index, response)` for each item shown this frame, e.g. to
+    /// attach a [`material_context_menu`](crate::menu::material_context_menu)
+    pub item_responses: Vec<(usize, Response)>,
+}
+
+/// An item being dragged to a new position, kept in temp memory under the list id
+#[derive(Clone, Debug)]
+struct ReorderDrag {
+    /// Original index of the dragged item
+    from: usize,
+    /// Pointer distance below the top of the dragged item
+    grab_offset: f32,
+    /// Last known pointer y position
+    pointer_y: f32,
+    /// Current vertical offset of every item, easing toward its new slot
+    offsets: Vec<f32>,
+}
+
+/// An item swiped sideways, kept in temp memory under the list id
+#[derive(Clone, Copy, Debug)]
+struct SwipeState {
+    /// Index of the swiped item
+    index: usize,
+    /// Horizontal offset of the item; positive reveals the leading actions
+    offset: f32,
+    /// Whether the current drag moves the item rather than scrolling the list
+    horizontal: bool,
+    /// Offset the item settles at once released
+    target: f32,
+    /// Offset and time at release, animating toward `target`
+    release: Option<(f32, f64)>,
+}
+
+impl SwipeState {
+    fn settle(&mut self, target: f32, now: f64) {
+        self.target = target;
+        self.release = Some((self.offset, now));
+    }
+}
+
+/// What happens when a swiped item is let go
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SwipeRelease {
+    /// Spring back to rest
+    Close,
+    /// Stay open at this offset with the actions showing
+    Open(f32),
+    /// Invoke the primary action on the leading or trailing side
+    Trigger { leading: bool },
+}
+
+/// An action revealed by swiping a [`ListItem`] sideways.
+///
+/// # Example
+/// ```rust
+/// let delete = SwipeAction::new("delete", "delete", get_global_color("error"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SwipeAction {
+    /// Identifier reported in [`ListResponse::swiped`]
+    id: String,
+    /// Material Symbols icon name
+    icon: String,
+    /// Background color behind the icon
+    color: Color32,
+    /// Icon color, picked for contrast with `color` when unset
+    icon_color: Option<Color32>,
+}
+
+impl SwipeAction {
+    /// Create an action with an `id`, a Material Symbols `icon` and a background `color`.
+    pub fn new(id: impl Into<String>, icon: impl Into<String>, color: Color32) -> Self {
+        Self {
+            id: id.into(),
+            icon: icon.into(),
+            color,
+            icon_color: None,
+        }
+    }
+
+    /// Set the icon color (default: white or black, whichever contrasts more
+    /// with the background).
+    pub fn icon_color(mut self, color: Color32) -> Self {
+        self.icon_color = Some(color);
+        self
+    }
+
+    fn content_color(&self) -> Color32 {
+        self.icon_color.unwrap_or_else(|| {
+            if contrast_ratio(Color32::WHITE, self.color)
+                >= contrast_ratio(Color32::BLACK, self.color)
+            {
+                Color32::WHITE
+            } else {
+                Color32::BLACK
+            }
+        })
+    }
+}
+
+/// Leading element of a list item other than an icon
+#[derive(Clone, Debug)]
+enum LeadingMedia {
+    /// Avatar, 40dp unless sized otherwise
+    Avatar(MaterialAvatar),
+    /// 56dp square image
+    Image(egui::TextureId),
+    /// 114x64dp video thumbnail
+    Video(egui::TextureId),
 }
 
 /// Individual item in a Material Design list.
@@ -155,6 +309,8 @@ pub struct ListItem<'a> {
     overline_text: Option<String>,
     /// Optional icon displayed at the start of the item
     leading_icon: Option<String>,
+    /// Optional avatar, image or video thumbnail displayed at the start of the item
+    leading_media: Option<LeadingMedia>,
     /// Optional icon displayed at the end of the item
     trailing_icon: Option<String>,
     /// Optional text displayed at the end of the item
@@ -191,8 +347,14 @@ pub struct ListItem<'a> {
     icon_color: Option<Color32>,
     /// Text color for title, subtitle, leading, and trailing
     text_color: Option<Color32>,
+    /// Optional widget displayed at the end of the item, such as a checkbox or switch
+    trailing_widget: Option<Box<dyn FnOnce(&mut Ui) + 'a>>,
     /// Callback function to execute when the item is clicked
     action: Option<Box<dyn Fn() + 'a>>,
+    /// Actions revealed by swiping the item toward the end
+    leading_actions: Vec<SwipeAction>,
+    /// Actions revealed by swiping the item toward the start
+    trailing_actions: Vec<SwipeAction>,
 }
 
 impl<'a> Default for MaterialList<'a> {
@@ -213,6 +375,8 @@ impl<'a> MaterialList<'a> {
             items: Vec::new(),
             dividers: true,
             id: None,
+            virtualized: false,
+            reorderable: false,
         }
     }
 
@@ -264,6 +428,36 @@ impl<'a> MaterialList<'a> {
         self.id = Some(id.into());
         self
     }
+
+    /// Only lay out and paint items that intersect the visible clip rect.
+    ///
+    /// The list still reserves its full height so scrolling behaves as usual,
+    /// which makes this worthwhile for long lists inside a `ScrollArea`.
+    ///
+    /// # Example
+    /// ```rust
+    /// let list = MaterialList::new().virtualized(true);
+    /// ```
+    pub fn virtualized(mut self, virtualized: bool) -> Self {
+        self.virtualized = virtualized;
+        self
+    }
+
+    /// Let the user drag items to a new position.
+    ///
+    /// Each item gets a trailing drag handle; a long press anywhere on an item
+    /// also starts a drag. Use [`show`](Self::show) to learn where an item was
+    /// dropped, and give the list an [`id`](Self::id) so the drag survives
+    /// the caller reordering its data.
+    ///
+    /// # Example
+    /// ```rust
+    /// let list = MaterialList::new().id("todo").reorderable(true);
+    /// ```
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
 }
 
 impl<'a> ListItem<'a> {
@@ -282,6 +476,7 @@ impl<'a> ListItem<'a> {
             secondary_text: None,
             overline_text: None,
             leading_icon: None,
+            leading_media: None,
             trailing_icon: None,
             trailing_text: None,
             enabled: true,
@@ -300,7 +495,10 @@ impl<'a> ListItem<'a> {
             selected_color: None,
             icon_color: None,
             text_color: None,
+            trailing_widget: None,
             action: None,
+            leading_actions: Vec::new(),
+            trailing_actions: Vec::new(),
         }
     }
 
@@ -321,6 +519,20 @@ impl<'a> ListItem<'a> {
         self
     }
 
+    /// Set the supporting text shown below the headline.
+    ///
+    /// Same as [`secondary_text`](Self::secondary_text). In a
+    /// [three-line](Self::is_three_line) item it wraps onto a second line.
+    ///
+    /// # Example
+    /// ```rust
+    /// let item = ListItem::new("Wi-Fi")
+    ///     .supporting_text("Connected to Home");
+    /// ```
+    pub fn supporting_text(self, text: impl Into<String>) -> Self {
+        self.secondary_text(text)
+    }
+
     /// Set the overline text for the item.
     ///
     /// Overline text is displayed above the primary text.
@@ -353,6 +565,58 @@ impl<'a> ListItem<'a> {
     /// ```
     pub fn leading_icon(mut self, icon: impl Into<String>) -> Self {
         self.leading_icon = Some(icon.into());
+        self.leading_media = None;
+        self
+    }
+
+    /// Show an avatar at the start of the item.
+    ///
+    /// Takes a [`MaterialAvatar`], or a name to show the initials of.
+    ///
+    /// # Example
+    /// ```rust
+    /// let item = ListItem::new("Ada Lovelace")
+    ///     .leading_avatar("Ada Lovelace");
+    /// let item = ListItem::new("Team")
+    ///     .leading_avatar(MaterialAvatar::icon("group"));
+    /// ```
+    pub fn leading_avatar(mut self, avatar: impl Into<MaterialAvatar>) -> Self {
+        self.leading_media = Some(LeadingMedia::Avatar(avatar.into()));
+        self.leading_icon = None;
+        self
+    }
+
+    /// Show a 40dp circular avatar image at the start of the item.
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let texture = ui.ctx().load_texture("avatar", egui::ColorImage::example(), Default::default());
+    /// let item = ListItem::new("Ada Lovelace")
+    ///     .leading_avatar_image(&texture);
+    /// # });
+    /// ```
+    pub fn leading_avatar_image(mut self, texture: impl Into<egui::TextureId>) -> Self {
+        self.leading_media = Some(LeadingMedia::Avatar(MaterialAvatar::texture(texture)));
+        self.leading_icon = None;
+        self
+    }
+
+    /// Show a 56dp square image at the start of the item.
+    ///
+    /// The item grows to at least 72dp to fit it.
+    pub fn leading_image(mut self, texture: impl Into<egui::TextureId>) -> Self {
+        self.leading_media = Some(LeadingMedia::Image(texture.into()));
+        self.leading_icon = None;
+        self
+    }
+
+    /// Show a 114x64dp video thumbnail at the start of the item.
+    ///
+    /// The item grows to at least 80dp to fit it.
+    pub fn leading_video(mut self, texture: impl Into<egui::TextureId>) -> Self {
+        self.leading_media = Some(LeadingMedia::Video(texture.into()));
+        self.leading_icon = None;
         self
     }
 
@@ -654,6 +918,26 @@ impl<'a> ListItem<'a> {
         self
     }
 
+    /// Show a widget at the end of the item, such as a checkbox or switch.
+    ///
+    /// The widget is laid out right to left and vertically centered; the
+    /// headline and supporting text are truncated to leave room for it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut enabled = true;
+    /// let item = ListItem::new("Notifications")
+    ///     .trailing(|ui| {
+    ///         ui.checkbox(&mut enabled, "");
+    ///     });
+    /// # });
+    /// ```
+    pub fn trailing(mut self, add_contents: impl FnOnce(&mut Ui) + 'a) -> Self {
+        self.trailing_widget = Some(Box::new(add_contents));
+        self
+    }
+
     /// Set a click action for the item.
     ///
     /// # Arguments
@@ -673,61 +957,74 @@ impl<'a> ListItem<'a> {
         self.action = Some(Box::new(f));
         self
     }
+
+    /// Reveal actions when the item is swiped sideways.
+    ///
+    /// Swiping right reveals the `leading` actions and swiping left the
+    /// `trailing` ones. A short swipe snaps the item open so an action can be
+    /// tapped; a long swipe invokes the first action of that side, which sits
+    /// at the outer edge. Invoked actions are reported by
+    /// [`MaterialList::show`] in [`ListResponse::swiped`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let item = ListItem::new("Meeting notes").swipe_actions(
+    ///     vec![SwipeAction::new("archive", "archive", get_global_color("tertiary"))],
+    ///     vec![SwipeAction::new("delete", "delete", get_global_color("error"))],
+    /// );
+    /// ```
+    pub fn swipe_actions(mut self, leading: Vec<SwipeAction>, trailing: Vec<SwipeAction>) -> Self {
+        self.leading_actions = leading;
+        self.trailing_actions = trailing;
+        self
+    }
 }
 
-impl<'a> Widget for MaterialList<'a> {
-    fn ui(self, ui: &mut Ui) -> Response {
+impl<'a> MaterialList<'a> {
+    /// Show the list and report how the user reordered it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut tracks = vec!["Intro", "Verse", "Outro"];
+    /// let mut list = MaterialList::new().id("playlist").reorderable(true);
+    /// for track in &tracks {
+    ///     list = list.item(ListItem::new(*track));
+    /// }
+    /// if let Some((from, to)) = list.show(ui).moved {
+    ///     let track = tracks.remove(from);
+    ///     tracks.insert(to, track);
+    /// }
+    /// # });
+    /// ```
+    pub fn show(self, ui: &mut Ui) -> ListResponse {
         // Material Design 3 Color Roles
         // Surface & Outline Roles - for backgrounds and low-emphasis areas
         let surface_container_lowest = get_global_color("surfaceContainerLowest");
         let on_surface = get_global_color("onSurface"); // Content on surface
-        let on_surface_variant = get_global_color("onSurfaceVariant"); // Lower emphasis content
         let outline_variant = get_global_color("outlineVariant"); // Borders and dividers
 
         // Accent Color Roles - for selection states
         let primary_container = get_global_color("primaryContainer"); // Selected background
-        let on_primary_container = get_global_color("onPrimaryContainer"); // Content on selected
+
+        let handle_width = if self.reorderable {
+            DRAG_HANDLE_WIDTH
+        } else {
+            0.0
+        };
+        let rtl = is_rtl(ui);
 
         // Calculate total height and max width
-        let mut total_height = 0.0;
+        let heights: Vec<f32> = self.items.iter().map(ListItem::tile_height).collect();
+        let mut total_height: f32 = heights.iter().sum();
         let mut max_content_width = 200.0;
 
         for item in &self.items {
-            // Calculate item height based on configuration
-            let visual_density = item.visual_density.unwrap_or_default();
-            let density_adjustment = visual_density.base_size_adjustment().y;
-            let is_dense = item.dense.unwrap_or(false);
-
-            let base_height = if item.is_three_line.unwrap_or(false)
-                || (item.overline_text.is_some() && item.secondary_text.is_some())
-            {
-                if is_dense {
-                    76.0
-                } else {
-                    88.0
-                }
-            } else if item.secondary_text.is_some() || item.overline_text.is_some() {
-                if is_dense {
-                    64.0
-                } else {
-                    72.0
-                }
-            } else {
-                if is_dense {
-                    48.0
-                } else {
-                    56.0
-                }
-            };
-
-            let item_height = item
-                .min_tile_height
-                .unwrap_or(base_height + density_adjustment);
-            total_height += item_height;
-
             // Calculate item width
             let mut item_width = 32.0; // base padding
-            if item.leading_icon.is_some() {
+            if let Some(media) = &item.leading_media {
+                item_width += media.size().x.max(item.min_leading_width.unwrap_or(40.0));
+            } else if item.leading_icon.is_some() {
                 item_width += item.min_leading_width.unwrap_or(40.0);
             }
             let primary_text_width = item.primary_text.len() as f32 * 8.0;
@@ -749,15 +1046,19 @@ impl<'a> Widget for MaterialList<'a> {
             if item.trailing_icon.is_some() {
                 item_width += 40.0;
             }
-            item_width += 32.0;
+            if item.trailing_widget.is_some() {
+                item_width += 56.0;
+            }
+            item_width += 32.0 + handle_width;
 
             if item_width > max_content_width {
                 max_content_width = item_width;
             }
         }
 
-        if self.dividers && self.items.len() > 1 {
-            total_height += (self.items.len() - 1) as f32;
+        let gap = if self.dividers { 1.0 } else { 0.0 };
+        if self.items.len() > 1 {
+            total_height += (self.items.len() - 1) as f32 * gap;
         }
 
         let list_width = max_content_width.min(ui.available_width());
@@ -774,51 +1075,247 @@ impl<'a> Widget for MaterialList<'a> {
             egui::epaint::StrokeKind::Outside,
         );
 
-        let mut current_y = rect.min.y;
-        let mut pending_actions = Vec::new();
+        // Use list's ID (or auto-generate one) to scope item IDs and avoid collisions
+        let list_id = self.id.unwrap_or_else(|| ui.id().with("material_list"));
         let items_len = self.items.len();
+        let tops = item_tops(&heights, gap);
+
+        // Reorder drag in progress, kept in temp memory across frames
+        let drag_id = list_id.with("reorder_drag");
+        let mut drag = if self.reorderable {
+            ui.data(|d| d.get_temp::<ReorderDrag>(drag_id))
+                .filter(|drag| drag.from < items_len && drag.offsets.len() == items_len)
+        } else {
+            None
+        };
+        let (pointer_y, pointer_down, now, dt) = ui.input(|i| {
+            (
+                i.pointer.interact_pos().map(|pos| pos.y),
+                i.pointer.primary_down(),
+                i.time,
+                i.stable_dt,
+            )
+        });
+        let mut moved = None;
+        let mut dropped = false;
+        let mut layout = None;
+
+        if let Some(drag) = &mut drag {
+            if let Some(y) = pointer_y {
+                drag.pointer_y = y;
+            }
+            let center = drag.pointer_y - drag.grab_offset - rect.min.y + heights[drag.from] / 2.0;
+            let (to, new_tops) = reorder_layout(&heights, gap, drag.from, center);
 
-        for (index, item) in self.items.into_iter().enumerate() {
-            // Calculate item-specific dimensions
-            let visual_density = item.visual_density.unwrap_or_default();
-            let density_adjustment = visual_density.base_size_adjustment().y;
-            let is_dense = item.dense.unwrap_or(false);
+            // Slide the other items toward their new positions; once dropped,
+            // settle everything in its new slot for this last frame
+            let ease = if reduced_motion(ui.ctx()) || !pointer_down {
+                1.0
+            } else {
+                (dt / REORDER_ANIMATION_S).min(1.0)
+            };
+            for (index, offset) in drag.offsets.iter_mut().enumerate() {
+                *offset += (new_tops[index] - tops[index] - *offset) * ease;
+            }
 
-            let base_height = if item.is_three_line.unwrap_or(false)
-                || (item.overline_text.is_some() && item.secondary_text.is_some())
-            {
-                if is_dense {
-                    76.0
+            if pointer_down {
+                // Scroll when dragging near the visible edges
+                let clip = ui.clip_rect();
+                let scroll = if drag.pointer_y < clip.top() + AUTOSCROLL_MARGIN {
+                    clip.top() + AUTOSCROLL_MARGIN - drag.pointer_y
+                } else if drag.pointer_y > clip.bottom() - AUTOSCROLL_MARGIN {
+                    clip.bottom() - AUTOSCROLL_MARGIN - drag.pointer_y
                 } else {
-                    88.0
-                }
-            } else if item.secondary_text.is_some() || item.overline_text.is_some() {
-                if is_dense {
-                    64.0
-                } else {
-                    72.0
+                    0.0
+                };
+                if scroll != 0.0 {
+                    ui.scroll_with_delta(Vec2::new(0.0, scroll * 0.25));
                 }
+                ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+                ui.ctx().request_repaint();
             } else {
-                if is_dense {
-                    48.0
-                } else {
-                    56.0
+                dropped = true;
+                if to != drag.from {
+                    moved = Some((drag.from, to));
                 }
-            };
+            }
+            layout = Some((to, new_tops));
+        }
+
+        // Item swiped sideways to reveal its actions
+        let swipe_id = list_id.with("swipe");
+        let mut swipe = ui
+            .data(|d| d.get_temp::<SwipeState>(swipe_id))
+            .filter(|swipe| swipe.index < items_len);
+        let mut swiped = None;
+        let mut swipe_dragged = false;
+        let mut item_responses = Vec::new();
 
-            let item_height = item
-                .min_tile_height
-                .unwrap_or(base_height + density_adjustment);
+        let mut pending_actions = Vec::new();
+        let mut floating = None;
 
+        for (index, mut item) in self.items.into_iter().enumerate() {
             let item_rect = Rect::from_min_size(
-                Pos2::new(rect.min.x, current_y),
-                Vec2::new(rect.width(), item_height),
+                Pos2::new(rect.min.x, rect.min.y + tops[index]),
+                Vec2::new(rect.width(), heights[index]),
             );
+            let is_dragged = drag.as_ref().is_some_and(|drag| drag.from == index);
+
+            // Where the item is painted while the list is being reordered
+            let paint_rect = match &drag {
+                Some(drag) if is_dragged && !dropped => {
+                    let top = (drag.pointer_y - drag.grab_offset)
+                        .clamp(rect.min.y, rect.max.y - heights[index]);
+                    item_rect.translate(Vec2::new(0.0, top - item_rect.min.y))
+                }
+                Some(drag) => item_rect.translate(Vec2::new(0.0, drag.offsets[index])),
+                None => item_rect,
+            };
+            let is_last = match &layout {
+                Some((to, new_tops)) => {
+                    // The moved item is last when it lands at the end, otherwise
+                    // the item with the greatest new top is
+                    if is_dragged {
+                        *to == items_len - 1
+                    } else {
+                        new_tops
+                            .iter()
+                            .enumerate()
+                            .all(|(other, top)| other == index || *top < new_tops[index])
+                    }
+                }
+                None => index == items_len - 1,
+            };
+
+            // The dragged item floats above the others and is painted last
+            if is_dragged && !dropped {
+                floating = Some((item, paint_rect));
+                continue;
+            }
+
+            // Skip items scrolled out of view, keeping the running offset intact
+            if self.virtualized && !ui.clip_rect().intersects(paint_rect) {
+                continue;
+            }
 
-            // Use list's ID (or auto-generate one) to scope item IDs and avoid collisions
-            let list_id = self.id.unwrap_or_else(|| ui.id().with("material_list"));
             let unique_id = list_id.with(("item", index));
-            let item_response = ui.interact(item_rect, unique_id, Sense::click());
+            let has_swipe_actions = item.enabled
+                && !(item.leading_actions.is_empty() && item.trailing_actions.is_empty());
+            let sense = if self.reorderable || has_swipe_actions {
+                Sense::click_and_drag()
+            } else {
+                Sense::click()
+            };
+            let item_response = ui.interact(item_rect, unique_id, sense);
+            let mut reorder_started = false;
+
+            if self.reorderable && item.enabled {
+                let handle_rect = mirror_rect(
+                    rtl,
+                    paint_rect,
+                    Rect::from_min_max(
+                        Pos2::new(paint_rect.max.x - handle_width, paint_rect.min.y),
+                        paint_rect.max,
+                    ),
+                );
+                let handle_response =
+                    ui.interact(handle_rect, unique_id.with("drag_handle"), Sense::drag());
+                if handle_response.hovered() && drag.is_none() {
+                    ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+                }
+
+                // Drag from the handle, or from anywhere after a long press
+                let long_pressed = item_response.drag_started()
+                    && ui
+                        .input(|i| i.pointer.press_start_time())
+                        .is_some_and(|start| now - start >= LONG_PRESS_S);
+                if drag.is_none() && (handle_response.drag_started() || long_pressed) {
+                    if let Some(y) = pointer_y {
+                        let start = ReorderDrag {
+                            from: index,
+                            grab_offset: y - item_rect.min.y,
+                            pointer_y: y,
+                            offsets: vec![0.0; items_len],
+                        };
+                        ui.data_mut(|d| d.insert_temp(drag_id, start));
+                        ui.ctx().request_repaint();
+                        reorder_started = true;
+                    }
+                }
+            }
+
+            if has_swipe_actions && drag.is_none() && !reorder_started {
+                if item_response.drag_started() {
+                    // Lock the drag to the axis it started along, so vertical
+                    // drags keep scrolling the list
+                    let travel = ui.input(|i| {
+                        i.pointer
+                            .interact_pos()
+                            .zip(i.pointer.press_origin())
+                            .map_or(Vec2::ZERO, |(pos, origin)| pos - origin)
+                    });
+                    let offset = swipe
+                        .filter(|swipe| swipe.index == index)
+                        .map_or(0.0, |swipe| swipe.offset);
+                    swipe = Some(SwipeState {
+                        index,
+                        offset,
+                        horizontal: travel.x.abs() > travel.y.abs(),
+                        target: offset,
+                        release: None,
+                    });
+                }
+
+                if let Some(state) = swipe.as_mut().filter(|swipe| swipe.index == index) {
+                    swipe_dragged = item_response.dragged();
+                    let width = item_rect.width();
+                    if item_response.drag_stopped() && state.horizontal {
+                        let (left, right) = item.swipe_actions_by_side(rtl);
+                        match swipe_release(state.offset, width, left.len(), right.len()) {
+                            SwipeRelease::Close => state.settle(0.0, now),
+                            SwipeRelease::Open(offset) => state.settle(offset, now),
+                            SwipeRelease::Trigger { leading } => {
+                                let actions = if leading { left } else { right };
+                                swiped = Some((index, actions[0].id.clone()));
+                                state.settle(0.0, now);
+                            }
+                        }
+                    } else if item_response.dragged() {
+                        let delta = item_response.drag_delta();
+                        if state.horizontal {
+                            let (left, right) = item.swipe_actions_by_side(rtl);
+                            let min = if right.is_empty() { 0.0 } else { -width };
+                            let max = if left.is_empty() { 0.0 } else { width };
+                            state.offset = (state.offset + delta.x).clamp(min, max);
+                        } else {
+                            ui.scroll_with_delta(Vec2::new(0.0, delta.y));
+                        }
+                    } else if item_response.clicked() && state.offset != 0.0 {
+                        // Tapping an open item closes it instead of activating it
+                        state.settle(0.0, now);
+                        item.action = None;
+                    }
+
+                    if let Some((from, released_at)) = state.release {
+                        let t = if reduced_motion(ui.ctx()) {
+                            1.0
+                        } else {
+                            ((now - released_at) / SWIPE_ANIMATION_S).clamp(0.0, 1.0) as f32
+                        };
+                        let eased = 1.0 - (1.0 - t).powi(3);
+                        state.offset = from + (state.target - from) * eased;
+                        if t < 1.0 {
+                            ui.ctx().request_repaint();
+                        } else {
+                            state.release = None;
+                        }
+                    }
+                }
+            }
+
+            let swipe_offset = swipe
+                .filter(|swipe| swipe.index == index)
+                .map_or(0.0, |swipe| swipe.offset);
 
             // Determine background color using M3 color roles
             let bg_color = if item.selected {
@@ -836,247 +1333,760 @@ impl<'a> Widget for MaterialList<'a> {
                 item.tile_color.unwrap_or(Color32::TRANSPARENT)
             };
 
+            // Actions revealed behind a swiped item
+            if swipe_offset != 0.0 {
+                let leading = swipe_offset > 0.0;
+                let (left, right) = item.swipe_actions_by_side(rtl);
+                let (actions, strip) = if leading {
+                    (
+                        left,
+                        Rect::from_min_max(
+                            paint_rect.min,
+                            Pos2::new(paint_rect.min.x + swipe_offset, paint_rect.max.y),
+                        ),
+                    )
+                } else {
+                    (
+                        right,
+                        Rect::from_min_max(
+                            Pos2::new(paint_rect.max.x + swipe_offset, paint_rect.min.y),
+                            paint_rect.max,
+                        ),
+                    )
+                };
+                // Past the full-swipe distance only the primary action shows
+                let full =
+                    swipe_offset.abs() >= full_swipe_distance(paint_rect.width(), actions.len());
+                let shown = if full { 1 } else { actions.len() };
+                let slot_width = strip.width() / shown.max(1) as f32;
+
+                if let Some(primary) = actions.first() {
+                    ui.painter().rect_filled(strip, 0.0, primary.color);
+                }
+                for (slot, action) in actions.iter().take(shown).enumerate() {
+                    // The first action sits at the outer edge of the strip
+                    let min_x = if leading {
+                        strip.min.x + slot as f32 * slot_width
+                    } else {
+                        strip.max.x - (slot + 1) as f32 * slot_width
+                    };
+                    let slot_rect = Rect::from_min_size(
+                        Pos2::new(min_x, strip.min.y),
+                        Vec2::new(slot_width, strip.height()),
+                    );
+                    ui.painter().rect_filled(slot_rect, 0.0, action.color);
+                    ui.painter().text(
+                        slot_rect.center(),
+                        egui::Align2::CENTER_CENTER,
+                        material_symbol_text(&action.icon),
+                        egui::FontId::proportional(24.0),
+                        action.content_color(),
+                    );
+
+                    let action_response = ui.interact(
+                        slot_rect,
+                        unique_id.with(("swipe_action", slot, leading)),
+                        Sense::click(),
+                    );
+                    if action_response.clicked() {
+                        swiped = Some((index, action.id.clone()));
+                        if let Some(state) = swipe.as_mut() {
+                            state.settle(0.0, now);
+                        }
+                    }
+                }
+            }
+
+            // The item slides over its actions, clipped to its own slot
+            let content_rect = paint_rect.translate(Vec2::new(swipe_offset, 0.0));
+            let painter = ui.painter().with_clip_rect(paint_rect);
+            let bg_color = if swipe_offset != 0.0 && bg_color == Color32::TRANSPARENT {
+                surface_container_lowest
+            } else {
+                bg_color
+            };
+
             // Draw background
             if bg_color != Color32::TRANSPARENT {
-                ui.painter().rect_filled(item_rect, 0.0, bg_color);
+                painter.rect_filled(content_rect, 0.0, bg_color);
             }
 
-            // Draw hover state layer (M3 interaction state overlay)
-            if item_response.hovered() && item.enabled {
-                // Use onSurface with 8% opacity for hover state layer (M3 spec)
-                let hover_color = Color32::from_rgba_premultiplied(
-                    on_surface.r(),
-                    on_surface.g(),
-                    on_surface.b(),
-                    20, // ~8% opacity (20/255 ≈ 0.078)
+            // M3 state layers and press ripple in onSurface
+            if item.enabled && drag.is_none() && swipe_offset == 0.0 {
+                Ripple::new(on_surface).show(ui, paint_rect, &item_response);
+            }
+
+            // Trailing widget, laid out from the end of the item
+            let mut trailing_width = 0.0;
+            if let Some(add_contents) = item.trailing_widget.take() {
+                let end = content_rect.max.x - handle_width - 16.0;
+                let max_rect = Rect::from_min_max(
+                    Pos2::new(content_rect.center().x, content_rect.min.y),
+                    Pos2::new(end, content_rect.max.y),
+                );
+                let layout = if rtl {
+                    egui::Layout::left_to_right(egui::Align::Center)
+                } else {
+                    egui::Layout::right_to_left(egui::Align::Center)
+                };
+                let mut trailing_ui = ui.new_child(
+                    egui::UiBuilder::new()
+                        .id_salt(unique_id.with("trailing"))
+                        .max_rect(mirror_rect(rtl, content_rect, max_rect))
+                        .layout(layout),
                 );
-                ui.painter().rect_filled(item_rect, 0.0, hover_color);
+                trailing_ui.set_clip_rect(paint_rect.intersect(ui.clip_rect()));
+                if !item.enabled {
+                    trailing_ui.disable();
+                }
+                add_contents(&mut trailing_ui);
+                let used = trailing_ui.min_rect();
+                trailing_width = if rtl {
+                    used.max.x - mirror_x(rtl, content_rect, end)
+                } else {
+                    end - used.min.x
+                } + 16.0;
             }
 
+            item.paint_content(&painter, content_rect, handle_width, trailing_width, rtl);
+
             // Handle click
             if item_response.clicked() && item.enabled {
-                if let Some(action) = item.action {
+                if let Some(action) = item.action.take() {
                     pending_actions.push(action);
                 }
             }
+            item_responses.push((index, item_response.clone()));
 
-            // Calculate colors using M3 color roles
-            let icon_color = if item.selected {
-                // Selected: use onPrimaryContainer (content on primaryContainer)
-                item.selected_color.unwrap_or(on_primary_container)
-            } else if item.enabled {
-                // Enabled: use onSurfaceVariant (lower emphasis for icons)
-                item.icon_color.unwrap_or(on_surface_variant)
-            } else {
-                // Disabled: use onSurfaceVariant with 38% opacity (M3 disabled state)
-                on_surface_variant.linear_multiply(0.38)
-            };
+            // Draw divider between items
+            if self.dividers && !is_last {
+                let divider_y = paint_rect.max.y;
+                let divider_start = Pos2::new(rect.min.x + 16.0, divider_y);
+                let divider_end = Pos2::new(rect.max.x - 16.0, divider_y);
 
-            let text_color = if item.selected {
-                // Selected: use onPrimaryContainer (content on primaryContainer)
-                item.selected_color.unwrap_or(on_primary_container)
-            } else if item.enabled {
-                // Enabled: use onSurface (standard content color on surface)
-                item.text_color.unwrap_or(on_surface)
-            } else {
-                // Disabled: use onSurface with 38% opacity (M3 disabled state)
-                on_surface.linear_multiply(0.38)
-            };
+                // Divider: use outlineVariant (less emphasized outline for structure)
+                ui.painter().line_segment(
+                    [divider_start, divider_end],
+                    Stroke::new(1.0, outline_variant),
+                );
+            }
+        }
 
-            // Layout constants
-            let horizontal_title_gap = item.horizontal_title_gap.unwrap_or(16.0)
-                + visual_density.horizontal * 2.0;
-            let _min_vertical_padding = item.min_vertical_padding.unwrap_or(8.0);
-            let min_leading_width = item.min_leading_width.unwrap_or(40.0);
-            
-            let mut content_x = item_rect.min.x + 16.0;
-            let content_y = item_rect.center().y;
+        if let (Some((item, paint_rect)), Some((_, new_tops))) = (floating, &layout) {
+            // Drop indicator where the item will land
+            let from = drag.as_ref().map_or(0, |drag| drag.from);
+            let indicator_y = (rect.min.y + new_tops[from] - gap / 2.0).max(rect.min.y + 1.0);
+            ui.painter().line_segment(
+                [
+                    Pos2::new(rect.min.x + 16.0, indicator_y),
+                    Pos2::new(rect.max.x - 16.0, indicator_y),
+                ],
+                Stroke::new(2.0, get_global_color("primary")),
+            );
 
-            // Draw leading icon
-            if let Some(icon_name) = &item.leading_icon {
-                let leading_width = min_leading_width;
-                let icon_pos = Pos2::new(content_x + leading_width / 2.0, content_y);
-
-                let icon_string = material_symbol_text(icon_name);
-                ui.painter().text(
-                    icon_pos,
-                    egui::Align2::CENTER_CENTER,
-                    &icon_string,
-                    egui::FontId::proportional(20.0),
-                    icon_color,
-                );
-                content_x += leading_width + horizontal_title_gap;
+            // Lifted item on a raised surface
+            let painter = ui
+                .painter()
+                .clone()
+                .with_layer_id(egui::LayerId::new(egui::Order::Foreground, drag_id));
+            painter.add(elevation_shadow(2).as_shape(paint_rect, 0.0));
+            painter.rect_filled(paint_rect, 0.0, get_global_color("surfaceContainerHigh"));
+            item.paint_content(&painter, paint_rect, handle_width, 0.0, rtl);
+        }
+
+        // Forget the swipe once the item is back at rest
+        match swipe {
+            Some(state) if swipe_dragged || state.offset != 0.0 || state.release.is_some() => {
+                ui.data_mut(|d| d.insert_temp(swipe_id, state));
             }
+            _ => ui.data_mut(|d| d.remove::<SwipeState>(swipe_id)),
+        }
 
-            // Calculate trailing width
-            let trailing_icon_width = if item.trailing_icon.is_some() {
-                40.0
-            } else {
-                0.0
-            };
-            let trailing_text_width = if item.trailing_text.is_some() {
-                80.0
-            } else {
-                0.0
-            };
-            let _total_trailing_width = trailing_icon_width + trailing_text_width;
+        if dropped {
+            ui.data_mut(|d| d.remove::<ReorderDrag>(drag_id));
+        } else if let Some(drag) = drag {
+            ui.data_mut(|d| d.insert_temp(drag_id, drag));
+        }
 
-            // Draw text content based on configuration
-            match (&item.overline_text, &item.secondary_text) {
-                (Some(overline), Some(secondary)) => {
-                    // Three-line layout
-                    let overline_pos = Pos2::new(content_x, content_y - 20.0);
-                    let primary_pos = Pos2::new(content_x, content_y);
-                    let secondary_pos = Pos2::new(content_x, content_y + 20.0);
+        // Execute pending actions
+        for action in pending_actions {
+            action();
+        }
 
-                    // Overline: use onSurfaceVariant (lower emphasis supporting text)
-                    ui.painter().text(
-                        overline_pos,
-                        egui::Align2::LEFT_CENTER,
-                        overline,
-                        egui::FontId::proportional(if is_dense { 10.0 } else { 11.0 }),
-                        on_surface_variant,
-                    );
+        ListResponse {
+            response,
+            moved,
+            swiped,
+            item_responses,
+        }
+    }
+}
 
-                    // Primary text: use calculated text_color (onSurface or onPrimaryContainer)
-                    ui.painter().text(
-                        primary_pos,
-                        egui::Align2::LEFT_CENTER,
-                        &item.primary_text,
-                        egui::FontId::proportional(if is_dense { 13.0 } else { 14.0 }),
-                        text_color,
-                    );
+impl<'a> Widget for MaterialList<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
 
-                    // Secondary text: use onSurfaceVariant (lower emphasis supporting text)
-                    ui.painter().text(
-                        secondary_pos,
-                        egui::Align2::LEFT_CENTER,
-                        secondary,
-                        egui::FontId::proportional(if is_dense { 11.0 } else { 12.0 }),
-                        on_surface_variant,
-                    );
-                }
-                (Some(overline), None) => {
-                    // Two-line layout: overline + primary
-                    let overline_pos = Pos2::new(content_x, content_y - 10.0);
-                    let primary_pos = Pos2::new(content_x, content_y + 10.0);
+impl ListItem<'_> {
+    /// Whether the item lays out three lines of text.
+    fn three_line(&self) -> bool {
+        self.is_three_line.unwrap_or(false)
+            || (self.overline_text.is_some() && self.secondary_text.is_some())
+    }
 
-                    // Overline: use onSurfaceVariant (lower emphasis supporting text)
-                    ui.painter().text(
-                        overline_pos,
-                        egui::Align2::LEFT_CENTER,
-                        overline,
-                        egui::FontId::proportional(if is_dense { 10.0 } else { 11.0 }),
-                        on_surface_variant,
-                    );
+    /// Height of the tile, from its line count, leading element, density and minimum height.
+    fn tile_height(&self) -> f32 {
+        let visual_density = self.visual_density.unwrap_or_default();
+        let density_adjustment = visual_density.base_size_adjustment().y;
+        let is_dense = self.dense.unwrap_or(false);
 
-                    // Primary text: use calculated text_color (onSurface or onPrimaryContainer)
-                    ui.painter().text(
-                        primary_pos,
-                        egui::Align2::LEFT_CENTER,
-                        &item.primary_text,
-                        egui::FontId::proportional(if is_dense { 13.0 } else { 14.0 }),
-                        text_color,
-                    );
-                }
-                (None, Some(secondary)) => {
-                    // Two-line layout: primary + secondary
-                    let primary_pos = Pos2::new(content_x, content_y - 10.0);
-                    let secondary_pos = Pos2::new(content_x, content_y + 10.0);
+        let base_height = if self.three_line() {
+            if is_dense {
+                76.0
+            } else {
+                88.0
+            }
+        } else if self.secondary_text.is_some() || self.overline_text.is_some() {
+            if is_dense {
+                64.0
+            } else {
+                72.0
+            }
+        } else {
+            if is_dense {
+                48.0
+            } else {
+                56.0
+            }
+        };
 
-                    // Primary text: use calculated text_color (onSurface or onPrimaryContainer)
-                    ui.painter().text(
-                        primary_pos,
-                        egui::Align2::LEFT_CENTER,
-                        &item.primary_text,
-                        egui::FontId::proportional(if is_dense { 13.0 } else { 14.0 }),
-                        text_color,
-                    );
+        // Images and video thumbnails keep 8dp above and below them
+        let media_height = self
+            .leading_media
+            .as_ref()
+            .map_or(0.0, |media| media.size().y + 16.0);
 
-                    // Secondary text: use onSurfaceVariant (lower emphasis supporting text)
-                    ui.painter().text(
-                        secondary_pos,
-                        egui::Align2::LEFT_CENTER,
-                        secondary,
-                        egui::FontId::proportional(if is_dense { 11.0 } else { 12.0 }),
-                        on_surface_variant,
-                    );
-                }
-                (None, None) => {
-                    // Single-line layout
-                    let text_pos = Pos2::new(content_x, content_y);
-                    // Primary text: use calculated text_color (onSurface or onPrimaryContainer)
-                    ui.painter().text(
-                        text_pos,
-                        egui::Align2::LEFT_CENTER,
-                        &item.primary_text,
-                        egui::FontId::proportional(if is_dense { 13.0 } else { 14.0 }),
-                        text_color,
-                    );
-                }
+        self.min_tile_height
+            .unwrap_or((base_height + density_adjustment).max(media_height))
+    }
+
+    /// Swipe actions revealed at the left and right edges; the leading
+    /// actions sit under the start edge, which is the right edge in RTL.
+    fn swipe_actions_by_side(&self, rtl: bool) -> (&[SwipeAction], &[SwipeAction]) {
+        if rtl {
+            (&self.trailing_actions, &self.leading_actions)
+        } else {
+            (&self.leading_actions, &self.trailing_actions)
+        }
+    }
+
+    /// Paint the leading element, text and trailing icon or text into `item_rect`,
+    /// leaving `trailing_width` free for a trailing widget and `handle_width`
+    /// for the drag handle at the end. With `rtl` the row is mirrored.
+    fn paint_content(
+        &self,
+        painter: &Painter,
+        item_rect: Rect,
+        handle_width: f32,
+        trailing_width: f32,
+        rtl: bool,
+    ) {
+        let on_surface = get_global_color("onSurface");
+        let on_surface_variant = get_global_color("onSurfaceVariant");
+        let on_primary_container = get_global_color("onPrimaryContainer");
+        let visual_density = self.visual_density.unwrap_or_default();
+        let is_dense = self.dense.unwrap_or(false);
+
+        // Calculate colors using M3 color roles
+        let icon_color = if self.selected {
+            // Selected: use onPrimaryContainer (content on primaryContainer)
+            self.selected_color.unwrap_or(on_primary_container)
+        } else if self.enabled {
+            // Enabled: use onSurfaceVariant (lower emphasis for icons)
+            self.icon_color.unwrap_or(on_surface_variant)
+        } else {
+            // Disabled: use onSurfaceVariant with 38% opacity (M3 disabled state)
+            on_surface_variant.linear_multiply(0.38)
+        };
+
+        let text_color = if self.selected {
+            // Selected: use onPrimaryContainer (content on primaryContainer)
+            self.selected_color.unwrap_or(on_primary_container)
+        } else if self.enabled {
+            // Enabled: use onSurface (standard content color on surface)
+            self.text_color.unwrap_or(on_surface)
+        } else {
+            // Disabled: use onSurface with 38% opacity (M3 disabled state)
+            on_surface.linear_multiply(0.38)
+        };
+
+        // Layout constants
+        let horizontal_title_gap =
+            self.horizontal_title_gap.unwrap_or(16.0) + visual_density.horizontal * 2.0;
+        let _min_vertical_padding = self.min_vertical_padding.unwrap_or(8.0);
+        let min_leading_width = self.min_leading_width.unwrap_or(40.0);
+
+        // Positions are worked out left-to-right and mirrored on the way out
+        let at = |x: f32| mirror_x(rtl, item_rect, x);
+        let mut content_x = item_rect.min.x + 16.0;
+        let content_y = item_rect.center().y;
+        let content_right = item_rect.max.x - handle_width - trailing_width;
+
+        // Three-line items put leading and trailing elements at the top, 12dp down
+        let three_line = self.three_line();
+        let top_aligned = three_line
+            && matches!(
+                self.title_alignment
+                    .unwrap_or(ListTileTitleAlignment::ThreeLine),
+                ListTileTitleAlignment::ThreeLine | ListTileTitleAlignment::Top
+            );
+        let align_y = |height: f32| {
+            if top_aligned {
+                item_rect.min.y + 12.0 + height / 2.0
+            } else {
+                content_y
             }
+        };
+
+        if let Some(media) = &self.leading_media {
+            // Draw leading avatar, image or video thumbnail
+            let size = media.size();
+            let leading_width = size.x.max(min_leading_width);
+            let media_rect = Rect::from_center_size(
+                Pos2::new(at(content_x + leading_width / 2.0), align_y(size.y)),
+                size,
+            );
+            media.paint(painter, media_rect, self.enabled, rtl);
+            content_x += leading_width + horizontal_title_gap;
+        } else if let Some(icon_name) = &self.leading_icon {
+            // Draw leading icon
+            let leading_width = min_leading_width;
+            let icon_pos = Pos2::new(at(content_x + leading_width / 2.0), align_y(24.0));
+
+            let icon_string = material_symbol_text(icon_name);
+            painter.text(
+                icon_pos,
+                egui::Align2::CENTER_CENTER,
+                &icon_string,
+                egui::FontId::proportional(20.0),
+                icon_color,
+            );
+            content_x += leading_width + horizontal_title_gap;
+        }
 
-            // Draw trailing text (e.g., badges, counts)
-            if let Some(ref trailing_text) = item.trailing_text {
-                let trailing_text_pos = Pos2::new(
-                    item_rect.max.x - trailing_icon_width - trailing_text_width + 10.0,
-                    content_y,
+        // Calculate trailing width
+        let trailing_icon_width = if self.trailing_icon.is_some() {
+            40.0
+        } else {
+            0.0
+        };
+        let trailing_text_width = if self.trailing_text.is_some() {
+            80.0
+        } else {
+            0.0
+        };
+
+        // Text is cut short with an ellipsis where the trailing elements begin
+        let text_width = ((content_right - trailing_icon_width - trailing_text_width)
+            .min(content_right - 16.0)
+            - content_x)
+            .max(0.0);
+        let text = |pos: Pos2, text: &str, size: f32, color: Color32, max_rows: usize| {
+            paint_truncated(
+                painter,
+                Pos2::new(at(pos.x), pos.y),
+                text,
+                egui::FontId::proportional(size),
+                color,
+                text_width,
+                max_rows,
+                rtl,
+            );
+        };
+        let overline_size = if is_dense { 10.0 } else { 11.0 };
+        let primary_size = if is_dense { 13.0 } else { 14.0 };
+        let secondary_size = if is_dense { 11.0 } else { 12.0 };
+
+        // Draw text content based on configuration
+        // Overline and supporting text use onSurfaceVariant (lower emphasis),
+        // the headline uses text_color (onSurface or onPrimaryContainer)
+        match (&self.overline_text, &self.secondary_text) {
+            (Some(overline), Some(secondary)) => {
+                // Three-line layout: overline + headline + supporting text
+                text(
+                    Pos2::new(content_x, content_y - 20.0),
+                    overline,
+                    overline_size,
+                    on_surface_variant,
+                    1,
                 );
-
-                // Trailing text: use onSurfaceVariant (lower emphasis supporting content)
-                ui.painter().text(
-                    trailing_text_pos,
-                    egui::Align2::LEFT_CENTER,
-                    trailing_text,
-                    egui::FontId::proportional(12.0),
+                text(
+                    Pos2::new(content_x, content_y),
+                    &self.primary_text,
+                    primary_size,
+                    text_color,
+                    1,
+                );
+                text(
+                    Pos2::new(content_x, content_y + 20.0),
+                    secondary,
+                    secondary_size,
                     on_surface_variant,
+                    1,
                 );
             }
-
-            // Draw trailing icon
-            if let Some(icon_name) = &item.trailing_icon {
-                let icon_pos = Pos2::new(item_rect.max.x - 28.0, content_y);
-
-                let icon_string = material_symbol_text(icon_name);
-                ui.painter().text(
-                    icon_pos,
-                    egui::Align2::CENTER_CENTER,
-                    &icon_string,
-                    egui::FontId::proportional(20.0),
-                    icon_color,
+            (Some(overline), None) => {
+                // Two-line layout: overline + headline
+                text(
+                    Pos2::new(content_x, content_y - 10.0),
+                    overline,
+                    overline_size,
+                    on_surface_variant,
+                    1,
+                );
+                text(
+                    Pos2::new(content_x, content_y + 10.0),
+                    &self.primary_text,
+                    primary_size,
+                    text_color,
+                    1,
+                );
+            }
+            (None, Some(secondary)) if three_line => {
+                // Three-line layout: headline + supporting text wrapped to two lines
+                text(
+                    Pos2::new(content_x, content_y - 20.0),
+                    &self.primary_text,
+                    primary_size,
+                    text_color,
+                    1,
+                );
+                text(
+                    Pos2::new(content_x, content_y + 10.0),
+                    secondary,
+                    secondary_size,
+                    on_surface_variant,
+                    2,
+                );
+            }
+            (None, Some(secondary)) => {
+                // Two-line layout: headline + supporting text
+                text(
+                    Pos2::new(content_x, content_y - 10.0),
+                    &self.primary_text,
+                    primary_size,
+                    text_color,
+                    1,
+                );
+                text(
+                    Pos2::new(content_x, content_y + 10.0),
+                    secondary,
+                    secondary_size,
+                    on_surface_variant,
+                    1,
+                );
+            }
+            (None, None) => {
+                // Single-line layout
+                text(
+                    Pos2::new(content_x, content_y),
+                    &self.primary_text,
+                    primary_size,
+                    text_color,
+                    1,
                 );
             }
+        }
 
-            current_y += item_height;
+        // Draw trailing text (e.g., badges, counts)
+        if let Some(ref trailing_text) = self.trailing_text {
+            let trailing_text_pos = Pos2::new(
+                at(content_right - trailing_icon_width - trailing_text_width + 10.0),
+                align_y(16.0),
+            );
 
-            // Draw divider between items
-            if self.dividers && index < items_len - 1 {
-                let divider_y = current_y;
-                let divider_start = Pos2::new(rect.min.x + 16.0, divider_y);
-                let divider_end = Pos2::new(rect.max.x - 16.0, divider_y);
+            // Trailing text: use onSurfaceVariant (lower emphasis supporting content)
+            painter.text(
+                trailing_text_pos,
+                mirror_align(rtl, egui::Align2::LEFT_CENTER),
+                trailing_text,
+                egui::FontId::proportional(12.0),
+                on_surface_variant,
+            );
+        }
 
-                // Divider: use outlineVariant (less emphasized outline for structure)
-                ui.painter().line_segment(
-                    [divider_start, divider_end],
-                    Stroke::new(1.0, outline_variant),
-                );
-                current_y += 1.0;
-            }
+        // Draw trailing icon
+        if let Some(icon_name) = &self.trailing_icon {
+            let icon_pos = Pos2::new(at(content_right - 28.0), align_y(24.0));
+
+            let icon_string = material_symbol_text(icon_name);
+            painter.text(
+                icon_pos,
+                egui::Align2::CENTER_CENTER,
+                &icon_string,
+                egui::FontId::proportional(20.0),
+                icon_color,
+            );
         }
 
-        // Execute pending actions
-        for action in pending_actions {
-            action();
+        // Draw drag handle for reorderable lists
+        if handle_width > 0.0 {
+            painter.text(
+                Pos2::new(at(item_rect.max.x - handle_width / 2.0), content_y),
+                egui::Align2::CENTER_CENTER,
+                material_symbol_text("drag_indicator"),
+                egui::FontId::proportional(20.0),
+                icon_color,
+            );
+        }
+    }
+}
+
+impl LeadingMedia {
+    fn size(&self) -> Vec2 {
+        match self {
+            Self::Avatar(avatar) => Vec2::splat(avatar.diameter()),
+            Self::Image(_) => Vec2::splat(56.0),
+            Self::Video(_) => Vec2::new(114.0, 64.0),
         }
+    }
 
-        response
+    fn paint(&self, painter: &Painter, rect: Rect, enabled: bool, rtl: bool) {
+        let opacity = if enabled { 1.0 } else { 0.38 };
+        let tint = Color32::WHITE.linear_multiply(opacity);
+        let uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+        match self {
+            Self::Avatar(avatar) => avatar.paint(painter, rect, enabled, rtl),
+            Self::Image(texture) | Self::Video(texture) => {
+                painter.image(*texture, rect, uv, tint);
+            }
+        }
     }
 }
 
-pub fn list_item(primary_text: impl Into<String>) -> ListItem<'static> {
+/// Paint `text` starting at `pos`, vertically centered on it, wrapped to at
+/// most `max_rows` rows of `max_width` and ending in an ellipsis when cut short.
+/// With `rtl` the text starts at `pos` and runs leftwards, aligned right.
+#[allow(clippy::too_many_arguments)]
+fn paint_truncated(
+    painter: &Painter,
+    pos: Pos2,
+    text: &str,
+    font_id: egui::FontId,
+    color: Color32,
+    max_width: f32,
+    max_rows: usize,
+    rtl: bool,
+) {
+    let mut job = egui::text::LayoutJob::simple(text.to_owned(), font_id, color, max_width);
+    job.wrap.max_rows = max_rows;
+    if rtl {
+        job.halign = egui::Align::RIGHT;
+    }
+    let galley = painter.layout_job(job);
+    let x = if rtl {
+        pos.x - galley.rect.max.x
+    } else {
+        pos.x
+    };
+    let top_left = Pos2::new(x, pos.y - galley.size().y / 2.0);
+    painter.galley(top_left, galley, color);
+}
+
+/// Top of each item relative to the list top, with `gap` between items.
+fn item_tops(heights: &[f32], gap: f32) -> Vec<f32> {
+    heights
+        .iter()
+        .scan(0.0, |top, height| {
+            let item_top = *top;
+            *top += height + gap;
+            Some(item_top)
+        })
+        .collect()
+}
+
+/// Where the items go while item `from` is dragged with its center at `center`
+/// (relative to the list top): the index it would be dropped at, and the new
+/// top of every item, by original index.
+fn reorder_layout(heights: &[f32], gap: f32, from: usize, center: f32) -> (usize, Vec<f32>) {
+    let tops = item_tops(heights, gap);
+    // Dropped after every other item whose midpoint it has passed
+    let to = (0..heights.len())
+        .filter(|&i| i != from && tops[i] + heights[i] / 2.0 < center)
+        .count();
+
+    let mut order: Vec<usize> = (0..heights.len()).filter(|&i| i != from).collect();
+    order.insert(to, from);
+    let mut new_tops = vec![0.0; heights.len()];
+    let mut top = 0.0;
+    for index in order {
+        new_tops[index] = top;
+        top += heights[index] + gap;
+    }
+    (to, new_tops)
+}
+
+/// Swipe distance past which releasing an item invokes its primary action,
+/// always leaving room to snap open with all `actions` showing.
+fn full_swipe_distance(item_width: f32, actions: usize) -> f32 {
+    (item_width * FULL_SWIPE_FRACTION).max((actions as f32 + 0.5) * SWIPE_ACTION_WIDTH)
+}
+
+/// Where an item swiped to `offset` goes when released.
+fn swipe_release(offset: f32, item_width: f32, leading: usize, trailing: usize) -> SwipeRelease {
+    let leading_side = offset > 0.0;
+    let actions = if leading_side { leading } else { trailing };
+    let revealed = actions as f32 * SWIPE_ACTION_WIDTH;
+    if actions == 0 || offset == 0.0 {
+        SwipeRelease::Close
+    } else if offset.abs() >= full_swipe_distance(item_width, actions) {
+        SwipeRelease::Trigger {
+            leading: leading_side,
+        }
+    } else if offset.abs() >= revealed / 2.0 {
+        SwipeRelease::Open(revealed.copysign(offset))
+    } else {
+        SwipeRelease::Close
+    }
+}
+
+pub fn list_item<'a>(primary_text: impl Into<String>) -> ListItem<'a> {
     ListItem::new(primary_text)
 }
 
-pub fn list() -> MaterialList<'static> {
+pub fn list<'a>() -> MaterialList<'a> {
     MaterialList::new()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::set_context_rtl;
+
+    #[test]
+    fn test_reorder_layout_makes_room_for_dragged_item() {
+        let heights = [56.0, 72.0, 56.0, 56.0];
+
+        // Dragged item still over its own slot: nothing moves
+        let (to, tops) = reorder_layout(&heights, 1.0, 1, 57.0 + 36.0);
+        assert_eq!(to, 1);
+        assert_eq!(tops, item_tops(&heights, 1.0));
+
+        // Item 0 dragged past the midpoint of item 2: items 1 and 2 move up
+        let (to, tops) = reorder_layout(&heights, 1.0, 0, 160.0);
+        assert_eq!(to, 2);
+        assert_eq!(tops, vec![130.0, 0.0, 73.0, 187.0]);
+
+        // Item 3 dragged above everything
+        let (to, tops) = reorder_layout(&heights, 0.0, 3, -10.0);
+        assert_eq!(to, 0);
+        assert_eq!(tops, vec![56.0, 112.0, 184.0, 0.0]);
+    }
+
+    #[test]
+    fn test_tile_height_fits_lines_and_media() {
+        assert_eq!(ListItem::new("One line").tile_height(), 56.0);
+        assert_eq!(
+            ListItem::new("Two lines")
+                .supporting_text("Supporting")
+                .tile_height(),
+            72.0
+        );
+        assert_eq!(
+            ListItem::new("Three lines")
+                .supporting_text("Supporting text that wraps")
+                .is_three_line(true)
+                .tile_height(),
+            88.0
+        );
+
+        // Leading media grows the tile to fit
+        let texture = egui::TextureId::Managed(1);
+        assert_eq!(
+            ListItem::new("Image").leading_image(texture).tile_height(),
+            72.0
+        );
+        assert_eq!(
+            ListItem::new("Video")
+                .supporting_text("Supporting")
+                .leading_video(texture)
+                .tile_height(),
+            80.0
+        );
+        assert_eq!(
+            ListItem::new("Avatar").leading_avatar("AV").tile_height(),
+            56.0
+        );
+    }
+
+    #[test]
+    fn test_swipe_release_snaps_open_or_triggers() {
+        // One leading action (72px) and two trailing actions (144px) on a 400px item
+        assert_eq!(swipe_release(20.0, 400.0, 1, 2), SwipeRelease::Close);
+        assert_eq!(swipe_release(50.0, 400.0, 1, 2), SwipeRelease::Open(72.0));
+        assert_eq!(
+            swipe_release(-100.0, 400.0, 1, 2),
+            SwipeRelease::Open(-144.0)
+        );
+        assert_eq!(
+            swipe_release(-250.0, 400.0, 1, 2),
+            SwipeRelease::Trigger { leading: false }
+        );
+        assert_eq!(
+            swipe_release(240.0, 400.0, 1, 2),
+            SwipeRelease::Trigger { leading: true }
+        );
+
+        // No actions on that side
+        assert_eq!(swipe_release(300.0, 400.0, 0, 2), SwipeRelease::Close);
+
+        // On a narrow item a full swipe still has to pass all the actions
+        assert_eq!(
+            swipe_release(-150.0, 200.0, 0, 2),
+            SwipeRelease::Open(-144.0)
+        );
+    }
+
+    #[test]
+    fn test_rtl_layout_mirrors_leading_icon_and_text() {
+        // Horizontal centers of the leading icon and the headline
+        let paint = |layout: egui::Layout, rtl: bool| {
+            let ctx = egui::Context::default();
+            set_context_rtl(&ctx, Some(rtl));
+            let output = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    ui.with_layout(layout, |ui| {
+                        list()
+                            .item(ListItem::new("Inbox").leading_icon("inbox"))
+                            .show(ui);
+                    });
+                });
+            });
+            let center_of = |text: &str| {
+                output
+                    .shapes
+                    .iter()
+                    .find_map(|clipped| match &clipped.shape {
+                        egui::Shape::Text(shape) if shape.galley.text() == text => {
+                            Some(shape.visual_bounding_rect().center().x)
+                        }
+                        _ => None,
+                    })
+                    .unwrap()
+            };
+            (
+                center_of(&material_symbol_text("inbox")),
+                center_of("Inbox"),
+            )
+        };
+
+        let (icon, text) = paint(egui::Layout::left_to_right(egui::Align::Min), false);
+        assert!(icon < text);
+        let (icon, text) = paint(egui::Layout::right_to_left(egui::Align::Min), false);
+        assert!(icon > text);
+        // The context-wide setting mirrors items in a left-to-right layout too
+        let (icon, text) = paint(egui::Layout::left_to_right(egui::Align::Min), true);
+        assert!(icon > text);
+    }
+}
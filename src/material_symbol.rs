@@ -4484,6 +4484,29 @@ lazy_static::lazy_static! {
 
         m
     };
+    static ref ICON_NAMES: Vec<&'static str> = {
+        let mut names: Vec<&'static str> = ICON_NAME_MAP.keys().copied().collect();
+        names.sort_unstable();
+        names
+    };
+}
+
+/// Codepoint of the Material Symbol called `name`, or `None` for unknown names
+///
+/// # Examples
+/// ```
+/// use egui_material3::material_symbol::codepoint;
+///
+/// assert_eq!(codepoint("settings"), Some('\u{e8b8}'));
+/// assert_eq!(codepoint("not_an_icon"), None);
+/// ```
+pub fn codepoint(name: &str) -> Option<char> {
+    ICON_NAME_MAP.get(name).copied()
+}
+
+/// Every Material Symbol name in the font's codepoint table, sorted
+pub fn all_names() -> &'static [&'static str] {
+    &ICON_NAMES
 }
 
 /// Get Material Symbol icon character by name
@@ -4494,7 +4517,7 @@ lazy_static::lazy_static! {
 /// let open_icon = get_material_symbol("open_in_new"); // Returns '\u{e89e}'
 /// ```
 pub fn get_material_symbol(name: &str) -> Option<char> {
-    ICON_NAME_MAP.get(name).copied()
+    codepoint(name)
 }
 
 /// Get Material Symbol icon character by name, with fallback
@@ -4530,3 +4553,32 @@ pub fn material_symbol_text_or_default(name: &str, default: &str) -> String {
         .map(|c| c.to_string())
         .unwrap_or_else(|| default.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_well_known_icons_resolve() {
+        assert_eq!(codepoint("home"), Some(ICON_HOME));
+        assert_eq!(codepoint("search"), Some(ICON_SEARCH));
+        assert_eq!(codepoint("settings"), Some('\u{e8b8}'));
+        assert_eq!(codepoint("broken_image"), Some(ICON_BROKEN_IMAGE));
+    }
+
+    #[test]
+    fn test_unknown_names_return_none() {
+        assert_eq!(codepoint("not_an_icon"), None);
+        assert_eq!(codepoint(""), None);
+        assert_eq!(codepoint("Settings"), None);
+    }
+
+    #[test]
+    fn test_all_names_cover_the_codepoint_table() {
+        let names = all_names();
+        assert_eq!(names.len(), ICON_NAME_MAP.len());
+        assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(names.iter().all(|name| codepoint(name).is_some()));
+        assert!(names.binary_search(&"settings").is_ok());
+    }
+}
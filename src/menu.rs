@@ -15,12 +15,47 @@
 //! - **Item height**: 48dp
 //! - **Corner radius**: 4dp
 //! - **Padding**: 8dp vertical
+//!
+//! ## Checkable and Radio Items
+//! [`MenuItem::checkable`] toggles a `bool` and [`MenuItem::radio_group`]
+//! builds items that select one value out of several. Selected items show a
+//! trailing checkmark, and the menu stays open so several options can be
+//! changed in a row.
+//!
+//! ## Opening and Closing
+//! A menu is driven by the `&mut bool` given to [`MaterialMenu::new`]: set it
+//! to `true` from any widget, e.g. a custom button, and anchor the menu to
+//! that widget with [`MaterialMenu::anchor`]. The menu sets it back to `false`
+//! on Escape, on a click outside the menu and its anchor, and when an item
+//! is activated. Tab and Shift+Tab cycle through the items of an open menu
+//! without reaching the app behind it. Menus scale and fade in from the
+//! corner they are anchored to and fade back out when closed, unless
+//! [`MaterialMenu::quick`] or reduced motion is set.
+//!
+//! ## Keyboard Shortcuts
+//! Items given a [`Shortcut`] with [`MenuItem::key_shortcut`] show it at
+//! their end and are selected when it is pressed, even while the menu is
+//! closed, as long as the menu's `show` is called every frame.
+//!
+//! ## Context Menus
+//! [`material_context_menu`] opens a `MaterialMenu` at the pointer when a
+//! widget is secondary-clicked. It works with any [`Response`], including
+//! cards, [`ListResponse::item_responses`](crate::list::ListResponse::item_responses)
+//! and [`DataTableResponse::row_responses`](crate::datatable::DataTableResponse::row_responses).
 
 use crate::get_global_color;
+use crate::material_symbol::{codepoint, material_symbol_text_or_default, ICON_CHECK};
+use crate::theme::{dismiss_on_escape, motion_duration, trap_focus, Shortcut};
+use egui::emath::TSTransform;
 use egui::{self, Color32, Context, Id, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Duration of the open and close animation of a menu
+const MENU_ANIMATION_S: f32 = 0.15;
 
 /// Corner position for menu positioning.
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum Corner {
     TopLeft,
     TopRight,
@@ -28,6 +63,26 @@ pub enum Corner {
     BottomRight,
 }
 
+impl Corner {
+    /// The corner on the opposite side along the flipped axes.
+    fn mirrored(self, horizontal: bool, vertical: bool) -> Corner {
+        let (mut right, mut bottom) = match self {
+            Corner::TopLeft => (false, false),
+            Corner::TopRight => (true, false),
+            Corner::BottomLeft => (false, true),
+            Corner::BottomRight => (true, true),
+        };
+        right ^= horizontal;
+        bottom ^= vertical;
+        match (right, bottom) {
+            (false, false) => Corner::TopLeft,
+            (true, false) => Corner::TopRight,
+            (false, true) => Corner::BottomLeft,
+            (true, true) => Corner::BottomRight,
+        }
+    }
+}
+
 /// Focus state for keyboard navigation.
 #[derive(Clone, Copy, PartialEq)]
 pub enum FocusState {
@@ -270,7 +325,7 @@ pub struct MaterialMenu<'a> {
     default_focus: FocusState,
     /// Positioning mode
     positioning: Positioning,
-    /// Whether the menu uses quick animation
+    /// Whether the menu opens and closes without animation
     quick: bool,
     /// Whether the menu has overflow scrolling
     has_overflow: bool,
@@ -302,6 +357,12 @@ pub struct MaterialMenu<'a> {
 pub struct MenuItem<'a> {
     /// Display text for the menu item
     text: String,
+    /// Identifier reported when the item is selected
+    id: Option<String>,
+    /// Keyboard shortcut hint shown at the end of the item
+    shortcut: Option<String>,
+    /// Key combination that activates the item, shown when there is no hint
+    key_shortcut: Option<Shortcut>,
     /// Optional icon to display at the start of the item
     leading_icon: Option<String>,
     /// Optional icon to display at the end of the item
@@ -312,6 +373,12 @@ pub struct MenuItem<'a> {
     divider_after: bool,
     /// Callback function to execute when the item is clicked
     action: Option<Box<dyn Fn() + 'a>>,
+    /// Check state of a checkable or radio item, `None` for plain items
+    checked: Option<bool>,
+    /// Updates the bound value of a checkable or radio item when selected
+    on_select: Option<Box<dyn Fn() + 'a>>,
+    /// Child items shown in a cascading submenu
+    submenu: Vec<MenuItem<'a>>,
 }
 
 impl<'a> MaterialMenu<'a> {
@@ -376,6 +443,29 @@ impl<'a> MaterialMenu<'a> {
         self
     }
 
+    /// Anchor the menu to a widget, such as the button that opens it.
+    ///
+    /// Clicks on the anchor don't count as outside clicks, so the widget can
+    /// toggle the menu itself.
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut menu_open = false;
+    /// let button = ui.button("More");
+    /// if button.clicked() {
+    ///     menu_open = !menu_open;
+    /// }
+    /// MaterialMenu::new("more_menu", &mut menu_open)
+    ///     .anchor(&button)
+    ///     .item(MenuItem::new("Settings"))
+    ///     .show(ui.ctx());
+    /// # });
+    /// ```
+    pub fn anchor(self, response: &Response) -> Self {
+        self.anchor_rect(response.rect)
+    }
+
     /// Add an item to the menu.
     ///
     /// # Arguments
@@ -394,6 +484,12 @@ impl<'a> MaterialMenu<'a> {
         self
     }
 
+    /// Add several items to the menu, e.g. from [`MenuItem::radio_group`].
+    pub fn items(mut self, items: impl IntoIterator<Item = MenuItem<'a>>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
     /// Set the menu style, overriding Material Design 3 defaults.
     ///
     /// # Arguments
@@ -449,7 +545,7 @@ impl<'a> MaterialMenu<'a> {
         self
     }
 
-    /// Enable or disable quick animation for the menu.
+    /// Open and close the menu instantly, without the scale and fade animation.
     pub fn quick(mut self, quick: bool) -> Self {
         self.quick = quick;
         self
@@ -516,10 +612,54 @@ impl<'a> MaterialMenu<'a> {
     }
 
     /// Show the menu in the given context.
-    pub fn show(self, ctx: &Context) {
-        if !*self.open {
-            return;
+    ///
+    /// Items created with [`MenuItem::submenu`] open a cascading child menu
+    /// next to the item when hovered or when activated with the Right arrow
+    /// key. Left/Right move between nesting levels, Up/Down move between
+    /// items, and Escape closes the deepest open submenu first.
+    ///
+    /// An anchored menu flips to the other side of its anchor when it would
+    /// overflow the screen, and is clamped inside the screen otherwise.
+    ///
+    /// Returns the [`MenuItem::id`] of the item selected this frame.
+    pub fn show(self, ctx: &Context) -> Option<String> {
+        // Use a stable ID for the menu
+        let stable_id = stable_menu_id(self.id);
+        let state_id = stable_id.with("submenu_state");
+        let placement_id = stable_id.with("placement");
+
+        // Items fire from their key shortcut whether or not the menu is open
+        let shortcut_path = ctx.input_mut(|i| take_shortcut_path(&self.items, i));
+        let shortcut_selected = shortcut_path
+            .as_deref()
+            .and_then(|path| item_at_path(&self.items, path))
+            .map(|item| {
+                if let Some(on_select) = &item.on_select {
+                    on_select();
+                }
+                if let Some(action) = &item.action {
+                    action();
+                }
+                if item.closes_menu() {
+                    *self.open = false;
+                }
+                item.item_id().to_owned()
+            });
+
+        // Grow from closed when opened, even the first time the menu is shown
+        let openness_id = stable_id.with("openness");
+        let duration = if self.quick {
+            0.0
+        } else {
+            motion_duration(ctx, MENU_ANIMATION_S)
+        };
+        let was_open = ctx
+            .data(|d| d.get_temp::<bool>(stable_id.with("was_open_last_frame")))
+            .unwrap_or(false);
+        if *self.open && !was_open {
+            ctx.animate_bool_with_time(openness_id, false, duration);
         }
+        let openness = ctx.animate_bool_with_time(openness_id, *self.open, duration);
 
         let resolved_style = self
             .menu_style
@@ -532,8 +672,31 @@ impl<'a> MaterialMenu<'a> {
             .unwrap_or(&MenuButtonThemeData::default())
             .resolve();
 
-        // Use a stable ID for the menu
-        let stable_id = egui::Id::new(format!("menu_{}", self.id.value()));
+        if !*self.open {
+            ctx.data_mut(|d| {
+                d.remove::<SubmenuState>(state_id);
+                d.insert_temp(stable_id.with("was_open_last_frame"), false);
+            });
+
+            // Keep drawing the root menu, without interaction, while it closes
+            let placement = ctx.data(|d| d.get_temp::<(Pos2, Pos2)>(placement_id));
+            if let (true, Some((position, origin))) = (openness > 0.0, placement) {
+                let menu_size = measure_menu(&self.items, &resolved_style, &resolved_button);
+                show_root_surface(ctx, stable_id, position, origin, openness, false, |ui| {
+                    render_menu_content(
+                        ui,
+                        menu_size,
+                        &self.items,
+                        0,
+                        &mut SubmenuState::default(),
+                        &resolved_style,
+                        &resolved_button,
+                        stable_id,
+                    )
+                });
+            }
+            return shortcut_selected;
+        }
 
         // Track how many frames the menu has been open. A mouse click can span
         // two frames (press on frame N, release on frame N+1), so we need to
@@ -562,98 +725,537 @@ impl<'a> MaterialMenu<'a> {
             ctx.memory_mut(|mem| mem.request_focus(stable_id));
         }
 
-        let item_height = resolved_button.min_height;
-        let vertical_padding = resolved_style.padding * 2.0;
-        let total_height = self.items.len() as f32 * item_height
-            + self.items.iter().filter(|item| item.divider_after).count() as f32
-            + vertical_padding;
-        let menu_width = resolved_style.max_width;
+        // Submenu state never survives a close/reopen cycle, and is validated
+        // against the current item tree so stale paths are dropped.
+        let mut state = if frames_since_opened == 0 {
+            SubmenuState::default()
+        } else {
+            ctx.data_mut(|d| d.get_temp::<SubmenuState>(state_id))
+                .unwrap_or_default()
+        };
+        state.sanitize(&self.items);
 
-        let menu_size = Vec2::new(menu_width, total_height);
+        let menu_size = measure_menu(&self.items, &resolved_style, &resolved_button);
 
         // Determine position based on anchor corner and menu corner
-        let position = if let Some(anchor) = self.anchor_rect {
-            let anchor_point = match self.anchor_corner {
-                Corner::TopLeft => anchor.min,
-                Corner::TopRight => Pos2::new(anchor.max.x, anchor.min.y),
-                Corner::BottomLeft => Pos2::new(anchor.min.x, anchor.max.y),
-                Corner::BottomRight => anchor.max,
-            };
-
-            let menu_offset = match self.menu_corner {
-                Corner::TopLeft => Vec2::ZERO,
-                Corner::TopRight => Vec2::new(-menu_size.x, 0.0),
-                Corner::BottomLeft => Vec2::new(0.0, -menu_size.y),
-                Corner::BottomRight => -menu_size,
-            };
-
-            // Apply the corner positioning and offsets
-            let base_position = anchor_point + menu_offset;
-            Pos2::new(
-                base_position.x + self.x_offset,
-                base_position.y + self.y_offset + 4.0, // 4px spacing from anchor
-            )
+        let screen_rect = ctx.content_rect();
+        let (position, origin) = if let Some(anchor) = self.anchor_rect {
+            let (position, menu_corner) = anchored_position(
+                anchor,
+                self.anchor_corner,
+                self.menu_corner,
+                Vec2::new(self.x_offset, self.y_offset + 4.0), // 4px spacing from anchor
+                menu_size,
+                screen_rect,
+                self.no_horizontal_flip,
+                self.no_vertical_flip,
+            );
+            // Grow from the corner touching the anchor, after any flip
+            let menu_rect = Rect::from_min_size(position, menu_size);
+            (position, corner_point(menu_rect, menu_corner))
         } else {
             // Center on screen
-            let screen_rect = ctx.content_rect();
-            screen_rect.center() - menu_size / 2.0
+            (screen_rect.center() - menu_size / 2.0, screen_rect.center())
         };
+        ctx.data_mut(|d| d.insert_temp(placement_id, (position, origin)));
+
+        // Submenus cascade in the same horizontal direction as the root menu.
+        let cascade_left = matches!(self.menu_corner, Corner::TopRight | Corner::BottomRight);
 
         let open_ref = self.open;
-        let _id = self.id;
         let items = self.items;
         let stay_open_on_outside_click = self.stay_open_on_outside_click;
         let _stay_open_on_focusout = self.stay_open_on_focusout;
 
+        let mut activated = handle_submenu_keys(ctx, &items, &mut state);
+        if state.close_requested {
+            *open_ref = false;
+            ctx.data_mut(|d| d.remove::<SubmenuState>(state_id));
+            return shortcut_selected;
+        }
+
         // Create a popup window for the menu with a stable layer and unique ID
-        let _area_response = egui::Area::new(stable_id)
-            .fixed_pos(position)
-            .order(egui::Order::Foreground)
-            .interactable(true)
-            .show(ctx, |ui| {
-                render_menu_content(
-                    ui,
-                    menu_size,
-                    items,
-                    &resolved_style,
-                    &resolved_button,
-                    open_ref,
-                )
+        let mut menu_rects = Vec::new();
+        let root_output =
+            show_root_surface(ctx, stable_id, position, origin, openness, true, |ui| {
+                trap_focus(ui, stable_id.with("focus_trap"), |ui| {
+                    render_menu_content(
+                        ui,
+                        menu_size,
+                        &items,
+                        0,
+                        &mut state,
+                        &resolved_style,
+                        &resolved_button,
+                        stable_id,
+                    )
+                })
             });
+        menu_rects.push(Rect::from_min_size(position, menu_size));
+        if activated.is_none() {
+            activated = root_output.clicked.map(|index| vec![index]);
+        }
 
-        // Handle closing behavior based on settings
-        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+        // Render each open submenu level, anchored to its parent item.
+        let mut level_items: &[MenuItem<'a>] = &items;
+        let mut item_rects = root_output.item_rects;
+        let mut level = 0;
+        while let Some(&open_index) = state.open_path.get(level) {
+            let (Some(parent), Some(anchor)) = (
+                level_items.get(open_index),
+                item_rects.get(open_index).copied(),
+            ) else {
+                break;
+            };
+            let child_items: &[MenuItem<'a>] = &parent.submenu;
+            let child_size = measure_menu(child_items, &resolved_style, &resolved_button);
+            let child_pos = submenu_position(
+                anchor,
+                child_size,
+                screen_rect,
+                resolved_style.padding,
+                cascade_left,
+                self.no_horizontal_flip,
+                self.no_vertical_flip,
+            );
+
+            level += 1;
+            let output = egui::Area::new(stable_id.with(("submenu", level)))
+                .fixed_pos(child_pos)
+                .order(egui::Order::Foreground)
+                .interactable(true)
+                .show(ctx, |ui| {
+                    render_menu_content(
+                        ui,
+                        child_size,
+                        child_items,
+                        level,
+                        &mut state,
+                        &resolved_style,
+                        &resolved_button,
+                        stable_id,
+                    )
+                })
+                .inner;
+            menu_rects.push(Rect::from_min_size(child_pos, child_size));
+            if activated.is_none() {
+                if let Some(index) = output.clicked {
+                    let mut path = state.open_path[..level].to_vec();
+                    path.push(index);
+                    activated = Some(path);
+                }
+            }
+
+            level_items = child_items;
+            item_rects = output.item_rects;
+        }
+
+        // Execute the activated item's action, if any
+        let selected = activated
+            .as_deref()
+            .and_then(|path| item_at_path(&items, path));
+        if let Some(on_select) = selected.and_then(|item| item.on_select.as_ref()) {
+            on_select();
+        }
+        if let Some(action) = selected.and_then(|item| item.action.as_ref()) {
+            action();
+        }
+        if selected.is_some_and(|item| item.closes_menu()) {
             *open_ref = false;
-        } else if !stay_open_on_outside_click && !was_recently_opened {
+        }
+        let selected_id = selected.map(|item| item.item_id().to_owned());
+
+        // Handle closing behavior based on settings
+        if *open_ref && !stay_open_on_outside_click && !was_recently_opened {
             // Only handle outside clicks if not staying open and not just opened
             if ctx.input(|i| i.pointer.any_click()) {
                 let pointer_pos = ctx.input(|i| i.pointer.interact_pos()).unwrap_or_default();
-                let menu_rect = Rect::from_min_size(position, menu_size);
 
                 // Include anchor rect in the "inside" area to prevent closing when clicking trigger
-                let mut inside_area = menu_rect;
-                if let Some(anchor) = self.anchor_rect {
-                    inside_area = inside_area.union(anchor);
-                }
+                let inside = menu_rects.iter().any(|rect| rect.contains(pointer_pos))
+                    || self
+                        .anchor_rect
+                        .is_some_and(|anchor| anchor.contains(pointer_pos));
 
-                // Only close if click was outside both menu and anchor areas
-                if !inside_area.contains(pointer_pos) {
+                // Only close if click was outside the menus and anchor areas
+                if !inside {
                     *open_ref = false;
                 }
             }
         }
+
+        if *open_ref {
+            ctx.data_mut(|d| d.insert_temp(state_id, state));
+        } else {
+            ctx.data_mut(|d| d.remove::<SubmenuState>(state_id));
+        }
+
+        selected_id.or(shortcut_selected)
+    }
+}
+
+/// Id under which the menu with `id` keeps its state between frames
+fn stable_menu_id(id: egui::Id) -> egui::Id {
+    egui::Id::new(format!("menu_{}", id.value()))
+}
+
+/// Forget the open submenus of the menu with `id` and mark it closed, so the
+/// next time it's shown open it grows in afresh
+fn reset_menu_state(ctx: &Context, id: egui::Id) {
+    let stable_id = stable_menu_id(id);
+    ctx.data_mut(|d| {
+        d.remove::<SubmenuState>(stable_id.with("submenu_state"));
+        d.insert_temp(stable_id.with("was_open_last_frame"), false);
+    });
+}
+
+/// Open/focus state of cascading submenus, persisted between frames.
+#[derive(Clone, Default, Debug, PartialEq)]
+struct SubmenuState {
+    /// Index of the open submenu item at each nesting level.
+    open_path: Vec<usize>,
+    /// Keyboard-focused item in the deepest open menu.
+    focused: Option<usize>,
+    /// Item last hovered by the pointer as `(level, index)`.
+    hovered: Option<(usize, usize)>,
+    /// Set when Escape is pressed with no submenu open.
+    close_requested: bool,
+}
+
+impl SubmenuState {
+    /// Drop any part of the open path that no longer points at an enabled
+    /// submenu item.
+    fn sanitize(&mut self, items: &[MenuItem<'_>]) {
+        let mut level_items = items;
+        let mut valid = 0;
+        for &index in &self.open_path {
+            match level_items.get(index) {
+                Some(item) if item.enabled && item.has_submenu() => {
+                    level_items = &item.submenu;
+                    valid += 1;
+                }
+                _ => break,
+            }
+        }
+        self.open_path.truncate(valid);
+        if self.focused.is_some_and(|index| index >= level_items.len()) {
+            self.focused = None;
+        }
+        self.close_requested = false;
+    }
+
+    /// Open the submenu of `index` at `level`, closing anything deeper.
+    fn open_submenu(&mut self, level: usize, index: usize) {
+        self.open_path.truncate(level);
+        self.open_path.push(index);
+    }
+
+    /// Record a pointer hover over `index` at `level`. Only a change of the
+    /// hovered item alters the open path, so keyboard navigation is not
+    /// overridden by a stationary pointer.
+    fn hover(&mut self, level: usize, index: usize, item: &MenuItem<'_>) {
+        if self.hovered == Some((level, index)) {
+            return;
+        }
+        self.hovered = Some((level, index));
+        self.open_path.truncate(level);
+        if item.enabled && item.has_submenu() {
+            self.open_path.push(index);
+            self.focused = None;
+        } else {
+            self.focused = Some(index);
+        }
+    }
+}
+
+/// Output of rendering a single menu surface.
+struct MenuLevelOutput {
+    /// Screen rectangles of each item, used to anchor submenus.
+    item_rects: Vec<Rect>,
+    /// Index of the item clicked this frame.
+    clicked: Option<usize>,
+}
+
+/// Show the root menu surface at `position` on the foreground layer, faded
+/// and scaled about `origin` by `openness`.
+fn show_root_surface<R>(
+    ctx: &Context,
+    id: Id,
+    position: Pos2,
+    origin: Pos2,
+    openness: f32,
+    interactable: bool,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> R {
+    let output = egui::Area::new(id)
+        .fixed_pos(position)
+        .order(egui::Order::Foreground)
+        .interactable(interactable)
+        .show(ctx, |ui| {
+            ui.multiply_opacity(openness);
+            add_contents(ui)
+        });
+    ctx.set_transform_layer(output.response.layer_id, grow_transform(origin, openness));
+    output.inner
+}
+
+/// Transform scaling a menu about `origin`, from 80% of its size when
+/// closed to its full size when open.
+fn grow_transform(origin: Pos2, openness: f32) -> TSTransform {
+    let scale = egui::lerp(0.8..=1.0, openness);
+    TSTransform::new(origin.to_vec2() * (1.0 - scale), scale)
+}
+
+/// The point of `rect` at `corner`.
+fn corner_point(rect: Rect, corner: Corner) -> Pos2 {
+    match corner {
+        Corner::TopLeft => rect.min,
+        Corner::TopRight => Pos2::new(rect.max.x, rect.min.y),
+        Corner::BottomLeft => Pos2::new(rect.min.x, rect.max.y),
+        Corner::BottomRight => rect.max,
+    }
+}
+
+/// Compute the top-left position of a menu of `size` so that `menu_corner`
+/// touches `anchor_corner` of `anchor`.
+fn corner_position(anchor: Rect, anchor_corner: Corner, menu_corner: Corner, size: Vec2) -> Pos2 {
+    let anchor_point = corner_point(anchor, anchor_corner);
+
+    let menu_offset = match menu_corner {
+        Corner::TopLeft => Vec2::ZERO,
+        Corner::TopRight => Vec2::new(-size.x, 0.0),
+        Corner::BottomLeft => Vec2::new(0.0, -size.y),
+        Corner::BottomRight => -size,
+    };
+
+    anchor_point + menu_offset
+}
+
+/// Position a root menu against `anchor`, flipping it to the opposite side
+/// of the anchor along any axis where it would overflow `screen`, then
+/// clamping it inside `screen`. Also returns the menu corner facing the
+/// anchor after flipping.
+#[allow(clippy::too_many_arguments)]
+fn anchored_position(
+    anchor: Rect,
+    anchor_corner: Corner,
+    menu_corner: Corner,
+    offset: Vec2,
+    size: Vec2,
+    screen: Rect,
+    no_horizontal_flip: bool,
+    no_vertical_flip: bool,
+) -> (Pos2, Corner) {
+    let place = |flip_x: bool, flip_y: bool| {
+        let anchor_corner = anchor_corner.mirrored(flip_x, flip_y);
+        let menu_corner = menu_corner.mirrored(flip_x, flip_y);
+        let offset = Vec2::new(
+            if flip_x { -offset.x } else { offset.x },
+            if flip_y { -offset.y } else { offset.y },
+        );
+        corner_position(anchor, anchor_corner, menu_corner, size) + offset
+    };
+    let overflows_x = |pos: Pos2| pos.x < screen.min.x || pos.x + size.x > screen.max.x;
+    let overflows_y = |pos: Pos2| pos.y < screen.min.y || pos.y + size.y > screen.max.y;
+
+    // Only flip when the other side actually fits
+    let preferred = place(false, false);
+    let flip_x = !no_horizontal_flip && overflows_x(preferred) && !overflows_x(place(true, false));
+    let flip_y = !no_vertical_flip && overflows_y(preferred) && !overflows_y(place(false, true));
+    let pos = place(flip_x, flip_y);
+
+    let pos = Pos2::new(
+        pos.x
+            .clamp(screen.min.x, (screen.max.x - size.x).max(screen.min.x)),
+        pos.y
+            .clamp(screen.min.y, (screen.max.y - size.y).max(screen.min.y)),
+    );
+    (pos, menu_corner.mirrored(flip_x, flip_y))
+}
+
+/// Position a submenu next to its parent item, flipping horizontally or
+/// vertically when it would leave `screen`.
+fn submenu_position(
+    item_rect: Rect,
+    size: Vec2,
+    screen: Rect,
+    padding: f32,
+    cascade_left: bool,
+    no_horizontal_flip: bool,
+    no_vertical_flip: bool,
+) -> Pos2 {
+    let to_right = corner_position(item_rect, Corner::TopRight, Corner::TopLeft, size);
+    let to_left = corner_position(item_rect, Corner::TopLeft, Corner::TopRight, size);
+
+    let mut pos = if cascade_left { to_left } else { to_right };
+    if !no_horizontal_flip {
+        if !cascade_left && pos.x + size.x > screen.max.x {
+            pos = to_left;
+        } else if cascade_left && pos.x < screen.min.x {
+            pos = to_right;
+        }
+    }
+
+    // Align the first child item with the parent item
+    pos.y -= padding;
+    if !no_vertical_flip && pos.y + size.y > screen.max.y {
+        pos.y = item_rect.max.y + padding - size.y;
+    }
+
+    pos
+}
+
+/// Total size of a menu surface containing `items`.
+fn measure_menu(
+    items: &[MenuItem<'_>],
+    style: &ResolvedMenuStyle,
+    button_theme: &ResolvedMenuButtonTheme,
+) -> Vec2 {
+    let vertical_padding = style.padding * 2.0;
+    let total_height = items.len() as f32 * button_theme.min_height
+        + items.iter().filter(|item| item.divider_after).count() as f32
+        + vertical_padding;
+    Vec2::new(style.max_width, total_height)
+}
+
+/// Look up the item at `path`, descending through submenus.
+fn item_at_path<'b, 'a>(items: &'b [MenuItem<'a>], path: &[usize]) -> Option<&'b MenuItem<'a>> {
+    let (&first, rest) = path.split_first()?;
+    let item = items.get(first)?;
+    if rest.is_empty() {
+        Some(item)
+    } else {
+        item_at_path(&item.submenu, rest)
+    }
+}
+
+/// Path to the first enabled item, searching submenus too, whose key shortcut
+/// was pressed this frame. The matching key press is consumed.
+fn take_shortcut_path(items: &[MenuItem<'_>], input: &mut egui::InputState) -> Option<Vec<usize>> {
+    items.iter().enumerate().find_map(|(index, item)| {
+        if !item.enabled {
+            return None;
+        }
+        if item
+            .key_shortcut
+            .is_some_and(|shortcut| shortcut.consume(input))
+        {
+            return Some(vec![index]);
+        }
+        let mut path = take_shortcut_path(&item.submenu, input)?;
+        path.insert(0, index);
+        Some(path)
+    })
+}
+
+/// Find the next enabled item after (or before) `from`, wrapping around.
+fn step_focus(items: &[MenuItem<'_>], from: Option<usize>, forward: bool) -> Option<usize> {
+    let len = items.len();
+    if len == 0 {
+        return None;
+    }
+    let start = match (from, forward) {
+        (Some(index), _) => index,
+        (None, true) => len - 1,
+        (None, false) => 0,
+    };
+    (1..=len)
+        .map(|offset| {
+            if forward {
+                (start + offset) % len
+            } else {
+                (start + len - offset % len) % len
+            }
+        })
+        .find(|&index| items[index].enabled)
+}
+
+/// Read navigation keys from `ctx` and apply them to the submenu state.
+fn handle_submenu_keys(
+    ctx: &Context,
+    items: &[MenuItem<'_>],
+    state: &mut SubmenuState,
+) -> Option<Vec<usize>> {
+    let escape = dismiss_on_escape(ctx);
+    let keys = ctx.input(|i| SubmenuKey {
+        up: i.key_pressed(egui::Key::ArrowUp),
+        down: i.key_pressed(egui::Key::ArrowDown),
+        left: i.key_pressed(egui::Key::ArrowLeft),
+        right: i.key_pressed(egui::Key::ArrowRight),
+        enter: i.key_pressed(egui::Key::Enter) || i.key_pressed(egui::Key::Space),
+        escape,
+    });
+    apply_submenu_key(items, state, keys)
+}
+
+/// Keys relevant to menu navigation pressed this frame.
+#[derive(Clone, Copy, Default)]
+struct SubmenuKey {
+    up: bool,
+    down: bool,
+    left: bool,
+    right: bool,
+    enter: bool,
+    escape: bool,
+}
+
+/// Update `state` for the keys pressed this frame. Returns the path of an
+/// item activated with Enter or Space.
+fn apply_submenu_key(
+    items: &[MenuItem<'_>],
+    state: &mut SubmenuState,
+    keys: SubmenuKey,
+) -> Option<Vec<usize>> {
+    let level = state.open_path.len();
+    let level_items = item_at_path(items, &state.open_path)
+        .map(|item| item.submenu.as_slice())
+        .unwrap_or(items);
+
+    if keys.escape {
+        // Close the deepest submenu first, then the menu itself
+        match state.open_path.pop() {
+            Some(parent) => state.focused = Some(parent),
+            None => state.close_requested = true,
+        }
+        return None;
     }
+
+    if keys.down {
+        state.focused = step_focus(level_items, state.focused, true);
+    } else if keys.up {
+        state.focused = step_focus(level_items, state.focused, false);
+    } else if keys.left {
+        if let Some(parent) = state.open_path.pop() {
+            state.focused = Some(parent);
+        }
+    } else if keys.right || keys.enter {
+        let focused = state.focused?;
+        let item = level_items.get(focused)?;
+        if item.enabled && item.has_submenu() {
+            state.open_submenu(level, focused);
+            state.focused = step_focus(&item.submenu, None, true);
+        } else if keys.enter && item.enabled {
+            let mut path = state.open_path.clone();
+            path.push(focused);
+            return Some(path);
+        }
+    }
+
+    None
 }
 
-fn render_menu_content<'a>(
+#[allow(clippy::too_many_arguments)]
+fn render_menu_content(
     ui: &mut Ui,
     size: Vec2,
-    items: Vec<MenuItem<'a>>,
+    items: &[MenuItem<'_>],
+    level: usize,
+    state: &mut SubmenuState,
     style: &ResolvedMenuStyle,
     button_theme: &ResolvedMenuButtonTheme,
-    open_ref: &'a mut bool,
-) -> Response {
-    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+    menu_id: Id,
+) -> MenuLevelOutput {
+    let (rect, _response) = ui.allocate_exact_size(size, Sense::hover());
 
     let outline_variant = get_global_color("outlineVariant");
 
@@ -685,26 +1287,34 @@ fn render_menu_content<'a>(
     );
 
     let mut current_y = rect.min.y + style.padding;
-    let mut pending_actions = Vec::new();
-    let mut should_close = false;
+    let mut item_rects = Vec::with_capacity(items.len());
+    let mut clicked = None;
 
-    for (index, item) in items.into_iter().enumerate() {
+    for (index, item) in items.iter().enumerate() {
         let item_rect = Rect::from_min_size(
             Pos2::new(rect.min.x + 8.0, current_y),
             Vec2::new(rect.width() - 16.0, button_theme.min_height),
         );
+        item_rects.push(item_rect);
 
         let item_response = ui.interact(
             item_rect,
-            egui::Id::new(format!("menu_item_{}_{}", rect.min.x as i32, index)),
+            menu_id.with(("menu_item", level, index)),
             Sense::click(),
         );
 
-        // Draw item background on hover/press
+        if item_response.hovered() {
+            state.hover(level, index, item);
+        }
+
+        let is_open = state.open_path.get(level) == Some(&index);
+        let is_focused = state.open_path.len() == level && state.focused == Some(index);
+
+        // Draw item background on hover/press/focus
         if item.enabled {
             let overlay_opacity = if item_response.is_pointer_button_down_on() {
                 button_theme.pressed_overlay_opacity
-            } else if item_response.hovered() {
+            } else if item_response.hovered() || is_open || is_focused {
                 button_theme.hover_overlay_opacity
             } else {
                 0.0
@@ -725,9 +1335,10 @@ fn render_menu_content<'a>(
 
         // Handle click
         if item_response.clicked() && item.enabled {
-            if let Some(action) = item.action {
-                pending_actions.push(action);
-                should_close = true;
+            if item.has_submenu() {
+                state.open_submenu(level, index);
+            } else {
+                clicked = Some(index);
             }
         }
 
@@ -735,22 +1346,27 @@ fn render_menu_content<'a>(
         let mut content_x = item_rect.min.x + button_theme.padding_horizontal;
         let content_y = item_rect.center().y;
 
+        let icon_color = if item.enabled {
+            button_theme.icon_color
+        } else {
+            button_theme.disabled_icon_color
+        };
+
         // Draw leading icon
-        if let Some(_icon) = &item.leading_icon {
+        if let Some(icon) = &item.leading_icon {
             let half_icon = button_theme.icon_size / 2.0;
             let icon_rect = Rect::from_min_size(
                 Pos2::new(content_x, content_y - half_icon),
                 Vec2::splat(button_theme.icon_size),
             );
 
-            let icon_color = if item.enabled {
-                button_theme.icon_color
-            } else {
-                button_theme.disabled_icon_color
-            };
-
-            ui.painter()
-                .circle_filled(icon_rect.center(), half_icon / 3.0 * 2.0, icon_color);
+            paint_item_icon(
+                ui,
+                icon,
+                icon_rect.center(),
+                button_theme.icon_size,
+                icon_color,
+            );
             content_x += button_theme.icon_size + button_theme.padding_horizontal;
         }
 
@@ -770,25 +1386,55 @@ fn render_menu_content<'a>(
             text_color,
         );
 
-        // Draw trailing icon
-        if let Some(_icon) = &item.trailing_icon {
-            let half_icon = button_theme.icon_size / 2.0;
-            let icon_rect = Rect::from_min_size(
-                Pos2::new(
-                    item_rect.max.x - button_theme.padding_horizontal - button_theme.icon_size,
-                    content_y - half_icon,
-                ),
-                Vec2::splat(button_theme.icon_size),
+        // Draw trailing icon, or a cascade arrow for submenu items
+        let trailing_center = Pos2::new(
+            item_rect.max.x - button_theme.padding_horizontal - button_theme.icon_size / 2.0,
+            content_y,
+        );
+        let is_mac = ui.ctx().os() == egui::os::OperatingSystem::Mac;
+        let shortcut = item.shortcut.clone().or_else(|| {
+            item.key_shortcut
+                .map(|shortcut| shortcut.format_for(is_mac))
+        });
+        if let Some(shortcut) = shortcut {
+            let mut shortcut_x = item_rect.max.x - button_theme.padding_horizontal;
+            if item.has_submenu() || item.trailing_icon.is_some() || item.checked.is_some() {
+                shortcut_x -= button_theme.icon_size + button_theme.padding_horizontal;
+            }
+            ui.painter().text(
+                Pos2::new(shortcut_x, content_y),
+                egui::Align2::RIGHT_CENTER,
+                shortcut,
+                button_theme.text_font.clone(),
+                icon_color,
+            );
+        }
+        if item.has_submenu() {
+            ui.painter().text(
+                trailing_center,
+                egui::Align2::CENTER_CENTER,
+                material_symbol_text_or_default("arrow_right", "›"),
+                egui::FontId::proportional(button_theme.icon_size),
+                icon_color,
+            );
+        } else if item.checked.is_some() {
+            if item.is_checked() {
+                ui.painter().text(
+                    trailing_center,
+                    egui::Align2::CENTER_CENTER,
+                    ICON_CHECK,
+                    egui::FontId::proportional(button_theme.icon_size),
+                    icon_color,
+                );
+            }
+        } else if let Some(icon) = &item.trailing_icon {
+            paint_item_icon(
+                ui,
+                icon,
+                trailing_center,
+                button_theme.icon_size,
+                icon_color,
             );
-
-            let icon_color = if item.enabled {
-                button_theme.icon_color
-            } else {
-                button_theme.disabled_icon_color
-            };
-
-            ui.painter()
-                .circle_filled(icon_rect.center(), half_icon / 3.0 * 2.0, icon_color);
         }
 
         current_y += button_theme.min_height;
@@ -807,16 +1453,28 @@ fn render_menu_content<'a>(
         }
     }
 
-    // Execute pending actions
-    for action in pending_actions {
-        action();
+    MenuLevelOutput {
+        item_rects,
+        clicked,
     }
+}
 
-    if should_close {
-        *open_ref = false;
+/// Draw a Material Symbols icon by name, or a dot for unknown names.
+fn paint_item_icon(ui: &Ui, icon: &str, center: Pos2, size: f32, color: Color32) {
+    match codepoint(icon) {
+        Some(glyph) => {
+            ui.painter().text(
+                center,
+                egui::Align2::CENTER_CENTER,
+                glyph,
+                egui::FontId::proportional(size),
+                color,
+            );
+        }
+        None => {
+            ui.painter().circle_filled(center, size / 3.0, color);
+        }
     }
-
-    response
 }
 
 impl<'a> MenuItem<'a> {
@@ -832,14 +1490,167 @@ impl<'a> MenuItem<'a> {
     pub fn new(text: impl Into<String>) -> Self {
         Self {
             text: text.into(),
+            id: None,
+            shortcut: None,
+            key_shortcut: None,
             leading_icon: None,
             trailing_icon: None,
             enabled: true,
             divider_after: false,
             action: None,
+            checked: None,
+            on_select: None,
+            submenu: Vec::new(),
+        }
+    }
+
+    /// Create a menu item that opens a cascading submenu.
+    ///
+    /// The submenu opens next to the item when it is hovered, clicked, or
+    /// activated with the Right arrow key. Submenus can be nested to any depth.
+    ///
+    /// # Arguments
+    /// * `text` - Display text for the menu item
+    /// * `items` - Items shown in the submenu
+    ///
+    /// # Example
+    /// ```rust
+    /// let item = MenuItem::submenu(
+    ///     "Share",
+    ///     vec![MenuItem::new("Email"), MenuItem::new("Link")],
+    /// );
+    /// ```
+    pub fn submenu(text: impl Into<String>, items: Vec<MenuItem<'a>>) -> Self {
+        Self {
+            submenu: items,
+            ..Self::new(text)
+        }
+    }
+
+    /// Create a menu item that toggles `value` when selected.
+    ///
+    /// A trailing checkmark is shown while `value` is `true`.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut show_grid = true;
+    /// let item = MenuItem::checkable("Show grid", &mut show_grid);
+    /// ```
+    pub fn checkable(text: impl Into<String>, value: &'a mut bool) -> Self {
+        let checked = *value;
+        let value = RefCell::new(value);
+        Self {
+            checked: Some(checked),
+            on_select: Some(Box::new(move || {
+                let mut value = value.borrow_mut();
+                **value = !**value;
+            })),
+            ..Self::new(text)
         }
     }
 
+    /// Create a group of radio items that set `selected` to the value of the
+    /// chosen option.
+    ///
+    /// Selecting one item deselects its peers. The item matching `selected`
+    /// shows a trailing checkmark.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut sort = "name";
+    /// let items = MenuItem::radio_group(
+    ///     &mut sort,
+    ///     [("Name", "name"), ("Date modified", "date"), ("Size", "size")],
+    /// );
+    /// ```
+    pub fn radio_group<V, L>(
+        selected: &'a mut V,
+        options: impl IntoIterator<Item = (L, V)>,
+    ) -> Vec<Self>
+    where
+        V: PartialEq + Clone + 'a,
+        L: Into<String>,
+    {
+        let selected = Rc::new(RefCell::new(selected));
+        options
+            .into_iter()
+            .map(|(text, value)| {
+                let checked = **selected.borrow() == value;
+                let selected = Rc::clone(&selected);
+                Self {
+                    checked: Some(checked),
+                    on_select: Some(Box::new(move || **selected.borrow_mut() = value.clone())),
+                    ..Self::new(text)
+                }
+            })
+            .collect()
+    }
+
+    /// Whether this item opens a submenu.
+    pub fn has_submenu(&self) -> bool {
+        !self.submenu.is_empty()
+    }
+
+    /// Whether this is a checkable or radio item that is currently selected.
+    pub fn is_checked(&self) -> bool {
+        self.checked == Some(true)
+    }
+
+    /// Whether activating this item closes the menu; checkable and radio items keep it open.
+    fn closes_menu(&self) -> bool {
+        self.checked.is_none()
+    }
+
+    /// Set the identifier reported by [`MaterialMenu::show`] and
+    /// [`material_context_menu`] when this item is selected.
+    ///
+    /// Items without an id report their display text.
+    ///
+    /// # Example
+    /// ```rust
+    /// let item = MenuItem::new("Copy").id("copy");
+    /// ```
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// The identifier reported when this item is selected.
+    pub fn item_id(&self) -> &str {
+        self.id.as_deref().unwrap_or(&self.text)
+    }
+
+    /// Show a keyboard shortcut hint at the end of the item.
+    ///
+    /// The hint is only displayed; handle the shortcut itself in your app, or
+    /// use [`MenuItem::key_shortcut`] to have the menu handle it.
+    ///
+    /// # Example
+    /// ```rust
+    /// let item = MenuItem::new("Copy").shortcut("Ctrl+C");
+    /// ```
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Activate the item with a key combination and show it at the end of the item.
+    ///
+    /// [`MaterialMenu::show`] checks the shortcuts of all enabled items, including
+    /// those in submenus, every frame it is called, even while the menu is closed.
+    /// A matching item is selected as if it had been clicked.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_material3::theme::Shortcut;
+    ///
+    /// let item = MenuItem::new("Copy").key_shortcut(Shortcut::command(egui::Key::C));
+    /// ```
+    pub fn key_shortcut(mut self, shortcut: Shortcut) -> Self {
+        self.key_shortcut = Some(shortcut);
+        self
+    }
+
     /// Set the leading icon for the menu item.
     ///
     /// # Arguments
@@ -950,3 +1761,426 @@ pub fn menu(id: impl Into<egui::Id>, open: &mut bool) -> MaterialMenu<'_> {
 pub fn menu_item(text: impl Into<String>) -> MenuItem<'static> {
     MenuItem::new(text)
 }
+
+/// Items of a context menu, collected by [`material_context_menu`].
+pub struct ContextMenu<'a> {
+    items: Vec<MenuItem<'a>>,
+}
+
+impl<'a> ContextMenu<'a> {
+    /// Add an item to the context menu.
+    pub fn item(&mut self, item: MenuItem<'a>) -> &mut Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Add several items, e.g. from [`MenuItem::radio_group`].
+    pub fn items(&mut self, items: impl IntoIterator<Item = MenuItem<'a>>) -> &mut Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Draw a divider after the last added item.
+    pub fn divider(&mut self) -> &mut Self {
+        if let Some(item) = self.items.last_mut() {
+            item.divider_after = true;
+        }
+        self
+    }
+}
+
+/// Show a Material context menu for `response` at the pointer when it is
+/// secondary-clicked.
+///
+/// `add_items` is only called while the menu is open. The menu closes when
+/// an item is selected, when Escape is pressed or when the user clicks
+/// outside of it, and it flips or clamps to stay inside the screen.
+///
+/// Returns the [`MenuItem::id`] of the item selected this frame.
+///
+/// # Example
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.label("Right-click me");
+/// let selected = material_context_menu(&response, |menu| {
+///     menu.item(MenuItem::new("Copy").id("copy").shortcut("Ctrl+C"))
+///         .item(MenuItem::new("Paste").id("paste").shortcut("Ctrl+V"))
+///         .divider()
+///         .item(MenuItem::new("Delete").id("delete").leading_icon("delete"));
+/// });
+/// if selected.as_deref() == Some("delete") {
+///     println!("Delete");
+/// }
+/// # });
+/// ```
+pub fn material_context_menu<'a>(
+    response: &Response,
+    add_items: impl FnOnce(&mut ContextMenu<'a>),
+) -> Option<String> {
+    let ctx = &response.ctx;
+    let menu_id = response.id.with("material_context_menu");
+    let mut pointer = ctx.data(|d| d.get_temp::<Pos2>(menu_id));
+
+    if response.secondary_clicked() {
+        if pointer.is_some() {
+            // Reopen at the new position instead of treating this as an outside click
+            reset_menu_state(ctx, menu_id);
+        }
+        pointer = response.interact_pointer_pos().or(pointer);
+    }
+    let pointer = pointer?;
+
+    let mut context_menu = ContextMenu { items: Vec::new() };
+    add_items(&mut context_menu);
+
+    let mut open = true;
+    let mut menu = MaterialMenu::new(menu_id, &mut open)
+        .anchor_rect(Rect::from_min_size(pointer, Vec2::ZERO))
+        .anchor_corner(Corner::TopLeft)
+        .menu_corner(Corner::TopLeft)
+        .y_offset(-4.0);
+    menu.items = context_menu.items;
+    let selected = menu.show(ctx);
+
+    if open && selected.is_none() {
+        ctx.data_mut(|d| d.insert_temp(menu_id, pointer));
+    } else {
+        reset_menu_state(ctx, menu_id);
+        ctx.data_mut(|d| d.remove::<Pos2>(menu_id));
+    }
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nested_items() -> Vec<MenuItem<'static>> {
+        vec![
+            MenuItem::new("Cut"),
+            MenuItem::submenu(
+                "Share",
+                vec![
+                    MenuItem::new("Email"),
+                    MenuItem::submenu(
+                        "Social",
+                        vec![MenuItem::submenu("More", vec![MenuItem::new("Mastodon")])],
+                    ),
+                ],
+            ),
+        ]
+    }
+
+    fn press(items: &[MenuItem<'_>], state: &mut SubmenuState, keys: SubmenuKey) {
+        apply_submenu_key(items, state, keys);
+    }
+
+    #[test]
+    fn test_keyboard_traverses_nested_submenus() {
+        let items = nested_items();
+        let mut state = SubmenuState::default();
+        let down = SubmenuKey {
+            down: true,
+            ..Default::default()
+        };
+        let right = SubmenuKey {
+            right: true,
+            ..Default::default()
+        };
+        let left = SubmenuKey {
+            left: true,
+            ..Default::default()
+        };
+
+        press(&items, &mut state, down);
+        press(&items, &mut state, down);
+        assert_eq!(state.focused, Some(1));
+
+        press(&items, &mut state, right);
+        assert_eq!(state.open_path, vec![1]);
+        assert_eq!(state.focused, Some(0));
+
+        press(&items, &mut state, down);
+        press(&items, &mut state, right);
+        press(&items, &mut state, right);
+        assert_eq!(state.open_path, vec![1, 1, 0]);
+
+        press(&items, &mut state, left);
+        assert_eq!(state.open_path, vec![1, 1]);
+        assert_eq!(state.focused, Some(0));
+    }
+
+    #[test]
+    fn test_escape_closes_deepest_submenu_first() {
+        let items = nested_items();
+        let escape = SubmenuKey {
+            escape: true,
+            ..Default::default()
+        };
+        let mut state = SubmenuState {
+            open_path: vec![1, 1, 0],
+            ..Default::default()
+        };
+
+        press(&items, &mut state, escape);
+        assert_eq!(state.open_path, vec![1, 1]);
+        assert!(!state.close_requested);
+
+        press(&items, &mut state, escape);
+        press(&items, &mut state, escape);
+        assert!(state.open_path.is_empty());
+        assert!(!state.close_requested);
+
+        press(&items, &mut state, escape);
+        assert!(state.close_requested);
+    }
+
+    #[test]
+    fn test_sanitize_drops_stale_open_path() {
+        let items = nested_items();
+        let mut state = SubmenuState {
+            open_path: vec![1, 0, 3],
+            focused: Some(7),
+            ..Default::default()
+        };
+
+        state.sanitize(&items);
+        assert_eq!(state.open_path, vec![1]);
+        assert_eq!(state.focused, None);
+    }
+
+    #[test]
+    fn test_hover_on_sibling_closes_child() {
+        let items = nested_items();
+        let mut state = SubmenuState::default();
+
+        state.hover(0, 1, &items[1]);
+        assert_eq!(state.open_path, vec![1]);
+
+        state.hover(0, 0, &items[0]);
+        assert!(state.open_path.is_empty());
+        assert_eq!(state.focused, Some(0));
+    }
+
+    #[test]
+    fn test_anchored_menu_flips_and_clamps_at_screen_edges() {
+        let screen = Rect::from_min_size(Pos2::ZERO, Vec2::new(800.0, 600.0));
+        let size = Vec2::new(200.0, 300.0);
+        let at_pointer = |pointer: Pos2, no_flip: bool| {
+            anchored_position(
+                Rect::from_min_size(pointer, Vec2::ZERO),
+                Corner::TopLeft,
+                Corner::TopLeft,
+                Vec2::ZERO,
+                size,
+                screen,
+                no_flip,
+                no_flip,
+            )
+            .0
+        };
+
+        assert_eq!(
+            at_pointer(Pos2::new(100.0, 100.0), false),
+            Pos2::new(100.0, 100.0)
+        );
+        // Near the bottom-right corner the menu opens up and to the left
+        assert_eq!(
+            at_pointer(Pos2::new(700.0, 500.0), false),
+            Pos2::new(500.0, 200.0)
+        );
+        // Without flipping it is pushed back inside the screen
+        assert_eq!(
+            at_pointer(Pos2::new(700.0, 500.0), true),
+            Pos2::new(600.0, 300.0)
+        );
+        // Only the vertical axis flips when only it overflows
+        assert_eq!(
+            at_pointer(Pos2::new(100.0, 350.0), false),
+            Pos2::new(100.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn test_menu_grows_from_corner_facing_anchor() {
+        let screen = Rect::from_min_size(Pos2::ZERO, Vec2::new(800.0, 600.0));
+        let size = Vec2::new(200.0, 300.0);
+        let button = |min: Pos2| Rect::from_min_size(min, Vec2::new(80.0, 40.0));
+        let place = |anchor: Rect| {
+            anchored_position(
+                anchor,
+                Corner::BottomLeft,
+                Corner::TopLeft,
+                Vec2::ZERO,
+                size,
+                screen,
+                false,
+                false,
+            )
+        };
+
+        // Below the button, growing from its top-left corner
+        let (position, corner) = place(button(Pos2::new(100.0, 100.0)));
+        assert_eq!(corner, Corner::TopLeft);
+        assert_eq!(
+            corner_point(Rect::from_min_size(position, size), corner),
+            Pos2::new(100.0, 140.0)
+        );
+
+        // Flipped above a button near the bottom, growing upwards
+        let (position, corner) = place(button(Pos2::new(100.0, 500.0)));
+        assert_eq!(corner, Corner::BottomLeft);
+        assert_eq!(
+            corner_point(Rect::from_min_size(position, size), corner),
+            Pos2::new(100.0, 500.0)
+        );
+
+        // Fully open menus are drawn untransformed
+        let origin = Pos2::new(100.0, 500.0);
+        assert_eq!(grow_transform(origin, 1.0), TSTransform::IDENTITY);
+        assert_eq!(grow_transform(origin, 0.0) * origin, origin);
+    }
+
+    #[test]
+    fn test_checkable_item_toggles_value() {
+        let mut show_grid = false;
+        let item = MenuItem::checkable("Show grid", &mut show_grid);
+        assert!(!item.is_checked());
+        (item.on_select.as_ref().unwrap())();
+        drop(item);
+        assert!(show_grid);
+    }
+
+    #[test]
+    fn test_radio_group_selects_one_value() {
+        let mut sort = "name";
+        let items = MenuItem::radio_group(&mut sort, [("Name", "name"), ("Size", "size")]);
+        assert!(items[0].is_checked());
+        assert!(!items[1].is_checked());
+        (items[1].on_select.as_ref().unwrap())();
+        drop(items);
+        assert_eq!(sort, "size");
+    }
+
+    #[test]
+    fn test_keyboard_focus_skips_disabled_items() {
+        let items = vec![
+            MenuItem::new("Undo").divider_after(true),
+            MenuItem::new("Cut").enabled(false),
+            MenuItem::new("Copy"),
+        ];
+        assert_eq!(step_focus(&items, Some(0), true), Some(2));
+        assert_eq!(step_focus(&items, Some(2), false), Some(0));
+    }
+
+    #[test]
+    fn test_selected_item_reports_id_or_text() {
+        assert_eq!(MenuItem::new("Copy").id("copy").item_id(), "copy");
+        assert_eq!(MenuItem::new("Paste").item_id(), "Paste");
+    }
+
+    #[test]
+    fn test_key_shortcut_selects_item_while_closed() {
+        let ctx = Context::default();
+        let mut input = egui::RawInput {
+            modifiers: egui::Modifiers::COMMAND,
+            ..Default::default()
+        };
+        input.events.push(egui::Event::Key {
+            key: egui::Key::V,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::COMMAND,
+        });
+
+        let mut open = false;
+        let mut selected = None;
+        let _ = ctx.run(input, |ctx| {
+            selected = MaterialMenu::new("edit_menu", &mut open)
+                .item(MenuItem::new("Copy").key_shortcut(Shortcut::command(egui::Key::C)))
+                .item(MenuItem::submenu(
+                    "More",
+                    vec![MenuItem::new("Paste")
+                        .id("paste")
+                        .key_shortcut(Shortcut::command(egui::Key::V))],
+                ))
+                .show(ctx);
+        });
+        assert_eq!(selected.as_deref(), Some("paste"));
+        assert!(!open);
+    }
+
+    #[test]
+    fn test_reset_menu_state_forgets_open_submenus() {
+        let ctx = Context::default();
+        let id = egui::Id::new("reset_test_menu");
+        let stable_id = stable_menu_id(id);
+        ctx.data_mut(|d| {
+            d.insert_temp(
+                stable_id.with("submenu_state"),
+                SubmenuState {
+                    open_path: vec![1],
+                    ..Default::default()
+                },
+            );
+            d.insert_temp(stable_id.with("was_open_last_frame"), true);
+        });
+
+        reset_menu_state(&ctx, id);
+
+        ctx.data(|d| {
+            assert!(d
+                .get_temp::<SubmenuState>(stable_id.with("submenu_state"))
+                .is_none());
+            assert_eq!(
+                d.get_temp::<bool>(stable_id.with("was_open_last_frame")),
+                Some(false)
+            );
+        });
+    }
+
+    #[test]
+    fn test_items_without_action_close_the_menu() {
+        let ctx = Context::default();
+        let press = |key| {
+            let mut input = egui::RawInput {
+                modifiers: egui::Modifiers::COMMAND,
+                ..Default::default()
+            };
+            input.events.push(egui::Event::Key {
+                key,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers: egui::Modifiers::COMMAND,
+            });
+            input
+        };
+
+        let mut open = true;
+        let mut show_grid = false;
+        let mut selected = Vec::new();
+        for key in [egui::Key::G, egui::Key::R] {
+            let _ = ctx.run(press(key), |ctx| {
+                selected.extend(
+                    MaterialMenu::new("view_menu", &mut open)
+                        .item(
+                            MenuItem::checkable("Show grid", &mut show_grid)
+                                .key_shortcut(Shortcut::command(egui::Key::G)),
+                        )
+                        .item(
+                            MenuItem::new("Reset zoom")
+                                .id("reset")
+                                .key_shortcut(Shortcut::command(egui::Key::R)),
+                        )
+                        .show(ctx),
+                );
+            });
+            // The checkable item keeps the menu open, the plain one closes it
+            assert_eq!(open, key == egui::Key::G);
+        }
+        assert!(show_grid);
+        assert_eq!(selected, vec!["Show grid", "reset"]);
+    }
+}
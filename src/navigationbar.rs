@@ -0,0 +1,391 @@
+//! Material Design 3 Navigation Bar
+//!
+//! Bottom navigation for compact layouts: 3–5 destinations spread evenly
+//! across the width, with an active indicator pill behind the selected icon.
+//!
+//! # M3 Color Role Usage
+//!
+//! - **surfaceContainer**: Bar container
+//! - **secondaryContainer**: Active indicator behind the selected destination
+//! - **onSecondaryContainer**: Selected destination icon
+//! - **onSurface**: Selected destination label
+//! - **onSurfaceVariant**: Unselected destination icon and label
+//! - **State layers**: onSurface @ 8% (hover), 12% (press)
+//!
+//! ## Dimensions
+//! - **Height**: 80dp
+//! - **Active indicator**: 64x32dp, fully rounded
+//! - **Icon**: 24dp, label 12sp, 4dp between indicator and label
+//!
+//! ## Labels
+//! [`LabelBehavior`] controls which destinations show their label. When the
+//! bar is too narrow for labels to fit under each destination they are hidden
+//! regardless of the behavior, and the icons are centered vertically.
+
+use crate::badge::MaterialBadge;
+use crate::material_symbol::material_symbol_text_or_default;
+use crate::theme::{get_global_color, motion_duration, type_font_id, TypeRole};
+use egui::{
+    epaint::CornerRadius, pos2, Align2, FontId, Id, Pos2, Rect, Response, Sense, Ui, Vec2, Widget,
+    WidgetInfo, WidgetType,
+};
+
+/// Bar height
+const BAR_HEIGHT: f32 = 80.0;
+/// Active indicator size
+const INDICATOR_SIZE: Vec2 = Vec2::new(64.0, 32.0);
+/// Padding above the indicator when a label is shown below it
+const BAR_TOP_PADDING: f32 = 12.0;
+/// Narrowest destination width that still shows labels
+const MIN_LABELED_ITEM_WIDTH: f32 = 72.0;
+
+/// Which destinations show their label.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LabelBehavior {
+    /// Every destination shows its label
+    Always,
+    /// Only the selected destination shows its label
+    Selected,
+    /// No labels; icons only
+    Never,
+}
+
+/// A destination shown in a [`MaterialNavigationBar`].
+///
+/// `icon` accepts a Material Symbols name (e.g. `"home"`); any other string,
+/// such as an emoji, is drawn as-is.
+#[derive(Clone)]
+pub struct BarDestination {
+    pub icon: String,
+    pub label: Option<String>,
+    pub enabled: bool,
+    pub badge: Option<MaterialBadge>,
+}
+
+impl BarDestination {
+    pub fn new(icon: impl Into<String>) -> Self {
+        Self {
+            icon: icon.into(),
+            label: None,
+            enabled: true,
+            badge: None,
+        }
+    }
+
+    /// Set the text shown below the icon; also used as the accessible name.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Overlay a [`MaterialBadge`] on the top-right of the destination's icon.
+    pub fn badge(mut self, badge: MaterialBadge) -> Self {
+        self.badge = Some(badge);
+        self
+    }
+}
+
+/// Result of showing a [`MaterialNavigationBar`].
+pub struct NavigationBarResponse {
+    /// The response covering the bar; `changed()` is set when the selection changed
+    pub response: Response,
+    /// Index of the selected destination after this frame
+    pub selected: usize,
+}
+
+/// Material Design navigation bar component.
+///
+/// Shows 3–5 destinations across the available width. Place it at the bottom
+/// of the window, e.g. in a `TopBottomPanel::bottom`.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut selected = 0;
+///
+/// let response = MaterialNavigationBar::new(&mut selected)
+///     .destination(BarDestination::new("home").label("Home"))
+///     .destination(BarDestination::new("search").label("Search"))
+///     .destination(BarDestination::new("person").label("Profile"))
+///     .label_behavior(LabelBehavior::Selected)
+///     .show(ui);
+///
+/// if response.response.changed() {
+///     println!("Now showing destination {}", response.selected);
+/// }
+/// # });
+/// ```
+pub struct MaterialNavigationBar<'a> {
+    selected: &'a mut usize,
+    destinations: Vec<BarDestination>,
+    label_behavior: LabelBehavior,
+    id: Option<Id>,
+}
+
+impl<'a> MaterialNavigationBar<'a> {
+    /// Create a navigation bar; `selected` is the index of the active destination.
+    pub fn new(selected: &'a mut usize) -> Self {
+        Self {
+            selected,
+            destinations: Vec::new(),
+            label_behavior: LabelBehavior::Always,
+            id: None,
+        }
+    }
+
+    /// Add a destination.
+    pub fn destination(mut self, destination: BarDestination) -> Self {
+        self.destinations.push(destination);
+        self
+    }
+
+    /// Add several destinations.
+    pub fn destinations(mut self, destinations: impl IntoIterator<Item = BarDestination>) -> Self {
+        self.destinations.extend(destinations);
+        self
+    }
+
+    /// Set which destinations show their label.
+    pub fn label_behavior(mut self, label_behavior: LabelBehavior) -> Self {
+        self.label_behavior = label_behavior;
+        self
+    }
+
+    /// Set an explicit id, needed when several bars are shown in the same `Ui`.
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Show the bar across the full available width.
+    pub fn show(self, ui: &mut Ui) -> NavigationBarResponse {
+        let id = self.id.unwrap_or_else(|| ui.id().with("navigation_bar"));
+        let width = ui.available_width();
+        let (bar_rect, mut response) =
+            ui.allocate_exact_size(Vec2::new(width, BAR_HEIGHT), Sense::hover());
+
+        ui.painter().rect_filled(
+            bar_rect,
+            CornerRadius::ZERO,
+            get_global_color("surfaceContainer"),
+        );
+
+        let count = self.destinations.len().max(1);
+        let item_width = bar_rect.width() / count as f32;
+
+        let previous = *self.selected;
+        for (index, destination) in self.destinations.iter().enumerate() {
+            let item_rect = Rect::from_min_size(
+                pos2(bar_rect.min.x + index as f32 * item_width, bar_rect.min.y),
+                Vec2::new(item_width, BAR_HEIGHT),
+            );
+            let selected = index == *self.selected;
+            let show_label = destination.label.is_some()
+                && label_visible(self.label_behavior, selected, item_width);
+            let item_id = id.with(index);
+
+            // Icons slide up to make room when their label appears
+            let label_t = ui.ctx().animate_bool_with_time(
+                item_id.with("label"),
+                show_label,
+                motion_duration(ui.ctx(), ui.style().animation_time),
+            );
+            let centered_y = item_rect.center().y;
+            let labeled_y = item_rect.min.y + BAR_TOP_PADDING + INDICATOR_SIZE.y / 2.0;
+            let indicator_center = pos2(
+                item_rect.center().x,
+                egui::lerp(centered_y..=labeled_y, label_t),
+            );
+
+            let item_response = paint_destination(
+                ui,
+                Destination {
+                    icon: &destination.icon,
+                    label: destination.label.as_deref(),
+                    badge: destination.badge.as_ref(),
+                    enabled: destination.enabled,
+                    selected,
+                },
+                item_rect,
+                item_id,
+                INDICATOR_SIZE,
+                indicator_center,
+                label_t,
+            );
+            if item_response.clicked() && destination.enabled {
+                *self.selected = index;
+            }
+        }
+
+        if *self.selected != previous {
+            response.mark_changed();
+        }
+
+        NavigationBarResponse {
+            response,
+            selected: *self.selected,
+        }
+    }
+}
+
+/// Whether a destination's label is drawn for the given behavior and width.
+fn label_visible(behavior: LabelBehavior, selected: bool, item_width: f32) -> bool {
+    if item_width < MIN_LABELED_ITEM_WIDTH {
+        return false;
+    }
+    match behavior {
+        LabelBehavior::Always => true,
+        LabelBehavior::Selected => selected,
+        LabelBehavior::Never => false,
+    }
+}
+
+/// A destination of a navigation bar or rail, as drawn by [`paint_destination`]
+pub(crate) struct Destination<'a> {
+    pub icon: &'a str,
+    pub label: Option<&'a str>,
+    pub badge: Option<&'a MaterialBadge>,
+    pub enabled: bool,
+    pub selected: bool,
+}
+
+/// Draw one destination (indicator, icon, label, badge) and return its response.
+///
+/// The indicator of `indicator_size` is centered on `indicator_center`, with the
+/// label below it faded in by `label_t`. Shared by the navigation bar and rail.
+pub(crate) fn paint_destination(
+    ui: &mut Ui,
+    destination: Destination<'_>,
+    rect: Rect,
+    id: Id,
+    indicator_size: Vec2,
+    indicator_center: Pos2,
+    label_t: f32,
+) -> Response {
+    let sense = if destination.enabled {
+        Sense::click()
+    } else {
+        Sense::hover()
+    };
+    let response = ui.interact(rect, id, sense);
+
+    // The indicator grows out from its center when a destination becomes selected
+    let indicator_t = ui.ctx().animate_bool_with_time(
+        id.with("indicator"),
+        destination.selected,
+        motion_duration(ui.ctx(), ui.style().animation_time),
+    );
+    let indicator_radius = CornerRadius::same((indicator_size.y / 2.0) as u8);
+    if indicator_t > 0.0 {
+        let indicator_rect = Rect::from_center_size(
+            indicator_center,
+            Vec2::new(indicator_size.x * indicator_t, indicator_size.y),
+        );
+        ui.painter().rect_filled(
+            indicator_rect,
+            indicator_radius,
+            get_global_color("secondaryContainer"),
+        );
+    }
+
+    // State layer on the full indicator shape
+    if destination.enabled && response.hovered() {
+        let state_layer = if response.is_pointer_button_down_on() {
+            0.12
+        } else {
+            0.08
+        };
+        ui.painter().rect_filled(
+            Rect::from_center_size(indicator_center, indicator_size),
+            indicator_radius,
+            get_global_color("onSurface").linear_multiply(state_layer),
+        );
+    }
+
+    let (icon_color, label_color) = if !destination.enabled {
+        let disabled = get_global_color("onSurface").linear_multiply(0.38);
+        (disabled, disabled)
+    } else if destination.selected {
+        (
+            get_global_color("onSecondaryContainer"),
+            get_global_color("onSurface"),
+        )
+    } else {
+        let on_surface_variant = get_global_color("onSurfaceVariant");
+        (on_surface_variant, on_surface_variant)
+    };
+
+    ui.painter().text(
+        indicator_center,
+        Align2::CENTER_CENTER,
+        material_symbol_text_or_default(destination.icon, destination.icon),
+        FontId::proportional(24.0),
+        icon_color,
+    );
+    if let Some(badge) = destination.badge {
+        badge.draw_on_rect(
+            ui,
+            Rect::from_center_size(indicator_center, Vec2::splat(24.0)),
+        );
+    }
+
+    if let Some(label) = destination.label {
+        if label_t > 0.0 {
+            ui.painter().text(
+                pos2(
+                    rect.center().x,
+                    indicator_center.y + indicator_size.y / 2.0 + 4.0,
+                ),
+                Align2::CENTER_TOP,
+                label,
+                type_font_id(TypeRole::LabelMedium),
+                label_color.gamma_multiply(label_t),
+            );
+        }
+    }
+
+    let accessible_name = destination.label.unwrap_or(destination.icon);
+    response.widget_info(|| {
+        WidgetInfo::selected(
+            WidgetType::RadioButton,
+            destination.enabled,
+            destination.selected,
+            accessible_name,
+        )
+    });
+
+    response
+}
+
+impl Widget for MaterialNavigationBar<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+/// Convenience function to create a navigation bar with the given destinations.
+pub fn navigation_bar<'a>(
+    selected: &'a mut usize,
+    destinations: &[BarDestination],
+) -> MaterialNavigationBar<'a> {
+    MaterialNavigationBar::new(selected).destinations(destinations.iter().cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_visibility() {
+        assert!(label_visible(LabelBehavior::Always, false, 100.0));
+        assert!(label_visible(LabelBehavior::Selected, true, 100.0));
+        assert!(!label_visible(LabelBehavior::Selected, false, 100.0));
+        assert!(!label_visible(LabelBehavior::Never, true, 100.0));
+        // Too narrow: labels are hidden whatever the behavior
+        assert!(!label_visible(LabelBehavior::Always, true, 60.0));
+    }
+}
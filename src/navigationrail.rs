@@ -0,0 +1,323 @@
+//! Material Design 3 Navigation Rail
+//!
+//! A slim vertical strip of destinations for medium-width layouts, between a
+//! bottom navigation bar on compact screens and a drawer on expanded ones.
+//!
+//! # M3 Color Role Usage
+//!
+//! - **surface**: Rail container
+//! - **secondaryContainer**: Active indicator behind the selected destination
+//! - **onSecondaryContainer**: Selected destination icon
+//! - **onSurface**: Selected destination label
+//! - **onSurfaceVariant**: Unselected destination icon and label, menu icon
+//! - **State layers**: onSurface @ 8% (hover), 12% (press)
+//!
+//! ## Dimensions
+//! - **Width**: 80dp
+//! - **Active indicator**: 56x32dp, fully rounded
+//! - **Icon**: 24dp, label 12sp, 4dp between indicator and label
+//! - **Destinations**: 56dp tall, 12dp apart
+//! - **Leading menu button**: 48dp touch target; leading FAB 56dp
+
+use crate::badge::MaterialBadge;
+use crate::fab::MaterialFab;
+use crate::material_symbol::material_symbol_text_or_default;
+use crate::navigationbar::{paint_destination, Destination};
+use crate::theme::get_global_color;
+use egui::{
+    epaint::CornerRadius, pos2, Align2, FontId, Id, Rect, Response, Sense, Ui, Vec2, Widget,
+};
+
+/// Rail width
+const RAIL_WIDTH: f32 = 80.0;
+/// Height of one destination: indicator, gap and label
+const DESTINATION_HEIGHT: f32 = 56.0;
+/// Vertical gap between destinations
+const DESTINATION_SPACING: f32 = 12.0;
+/// Active indicator size
+const INDICATOR_SIZE: Vec2 = Vec2::new(56.0, 32.0);
+/// Padding above the leading items and below the last destination
+const RAIL_PADDING: f32 = 12.0;
+
+/// Vertical placement of the destinations within the rail.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RailAlignment {
+    /// Directly below the leading menu button/FAB
+    Top,
+    /// Centered in the space below the leading items
+    Center,
+    /// At the bottom of the rail
+    Bottom,
+}
+
+/// A destination shown in a [`MaterialNavigationRail`].
+///
+/// `icon` accepts a Material Symbols name (e.g. `"inbox"`); any other string,
+/// such as an emoji, is drawn as-is.
+#[derive(Clone)]
+pub struct RailDestination {
+    pub icon: String,
+    pub label: String,
+    pub enabled: bool,
+    pub badge: Option<MaterialBadge>,
+}
+
+impl RailDestination {
+    pub fn new(icon: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            icon: icon.into(),
+            label: label.into(),
+            enabled: true,
+            badge: None,
+        }
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Overlay a [`MaterialBadge`] on the top-right of the destination's icon.
+    pub fn badge(mut self, badge: MaterialBadge) -> Self {
+        self.badge = Some(badge);
+        self
+    }
+}
+
+/// Result of showing a [`MaterialNavigationRail`].
+pub struct NavigationRailResponse {
+    /// The response covering the rail; `changed()` is set when the selection changed
+    pub response: Response,
+    /// Index of the selected destination after this frame
+    pub selected: usize,
+    /// Whether the leading menu button or FAB was clicked this frame
+    pub leading_clicked: bool,
+}
+
+/// Material Design navigation rail component.
+///
+/// Shows 3–7 destinations as icon + label, with an active indicator pill behind
+/// the selected one. An optional menu button or FAB sits at the top.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut selected = 0;
+///
+/// let rail = MaterialNavigationRail::new(&mut selected)
+///     .menu_button(true)
+///     .destination(RailDestination::new("inbox", "Inbox"))
+///     .destination(RailDestination::new("send", "Sent"))
+///     .destination(RailDestination::new("drafts", "Drafts"))
+///     .alignment(RailAlignment::Center);
+///
+/// if rail.show(ui).leading_clicked {
+///     // open the navigation drawer
+/// }
+/// # });
+/// ```
+pub struct MaterialNavigationRail<'a> {
+    selected: &'a mut usize,
+    destinations: Vec<RailDestination>,
+    alignment: RailAlignment,
+    menu_button: bool,
+    fab: Option<MaterialFab<'a>>,
+    id: Option<Id>,
+}
+
+impl<'a> MaterialNavigationRail<'a> {
+    /// Create a navigation rail; `selected` is the index of the active destination.
+    pub fn new(selected: &'a mut usize) -> Self {
+        Self {
+            selected,
+            destinations: Vec::new(),
+            alignment: RailAlignment::Top,
+            menu_button: false,
+            fab: None,
+            id: None,
+        }
+    }
+
+    /// Add a destination.
+    pub fn destination(mut self, destination: RailDestination) -> Self {
+        self.destinations.push(destination);
+        self
+    }
+
+    /// Add several destinations.
+    pub fn destinations(mut self, destinations: impl IntoIterator<Item = RailDestination>) -> Self {
+        self.destinations.extend(destinations);
+        self
+    }
+
+    /// Set where the destinations are placed vertically.
+    pub fn alignment(mut self, alignment: RailAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Show a leading menu button, typically used to open a modal drawer.
+    pub fn menu_button(mut self, menu_button: bool) -> Self {
+        self.menu_button = menu_button;
+        self
+    }
+
+    /// Show a leading FAB below the menu button.
+    pub fn fab(mut self, fab: MaterialFab<'a>) -> Self {
+        self.fab = Some(fab);
+        self
+    }
+
+    /// Set an explicit id, needed when several rails are shown in the same `Ui`.
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Show the rail along the left edge of the available space.
+    pub fn show(self, ui: &mut Ui) -> NavigationRailResponse {
+        let id = self.id.unwrap_or_else(|| ui.id().with("navigation_rail"));
+        let available = ui.available_rect_before_wrap();
+        let rail_rect =
+            Rect::from_min_size(available.min, Vec2::new(RAIL_WIDTH, available.height()));
+        let mut response = ui.allocate_rect(rail_rect, Sense::hover());
+
+        ui.painter()
+            .rect_filled(rail_rect, CornerRadius::ZERO, get_global_color("surface"));
+
+        let center_x = rail_rect.center().x;
+        let mut current_y = rail_rect.min.y + RAIL_PADDING;
+        let has_leading = self.menu_button || self.fab.is_some();
+        let mut leading_clicked = false;
+
+        // Leading menu button
+        if self.menu_button {
+            let menu_rect =
+                Rect::from_center_size(pos2(center_x, current_y + 24.0), Vec2::splat(48.0));
+            let menu_response = ui.interact(menu_rect, id.with("menu"), Sense::click());
+            if menu_response.hovered() {
+                let state_layer = if menu_response.is_pointer_button_down_on() {
+                    0.12
+                } else {
+                    0.08
+                };
+                ui.painter().circle_filled(
+                    menu_rect.center(),
+                    20.0,
+                    get_global_color("onSurface").linear_multiply(state_layer),
+                );
+            }
+            ui.painter().text(
+                menu_rect.center(),
+                Align2::CENTER_CENTER,
+                material_symbol_text_or_default("menu", "☰"),
+                FontId::proportional(24.0),
+                get_global_color("onSurfaceVariant"),
+            );
+            leading_clicked |= menu_response.clicked();
+            current_y += 48.0 + 4.0;
+        }
+
+        // Leading FAB
+        if let Some(fab) = self.fab {
+            let fab_rect = Rect::from_min_size(
+                pos2(rail_rect.min.x, current_y),
+                Vec2::new(RAIL_WIDTH, 56.0),
+            );
+            let fab_response = ui.put(fab_rect, fab);
+            leading_clicked |= fab_response.clicked();
+            current_y += 56.0;
+        }
+
+        if has_leading {
+            current_y += 8.0;
+        }
+
+        let count = self.destinations.len();
+        let group_height = count as f32 * DESTINATION_HEIGHT
+            + count.saturating_sub(1) as f32 * DESTINATION_SPACING;
+        let mut y = group_top(
+            current_y,
+            rail_rect.max.y - RAIL_PADDING,
+            group_height,
+            self.alignment,
+        );
+
+        let previous = *self.selected;
+        for (index, destination) in self.destinations.iter().enumerate() {
+            let destination_rect = Rect::from_min_size(
+                pos2(rail_rect.min.x, y),
+                Vec2::new(RAIL_WIDTH, DESTINATION_HEIGHT),
+            );
+            let destination_response = paint_destination(
+                ui,
+                Destination {
+                    icon: &destination.icon,
+                    label: Some(&destination.label),
+                    badge: destination.badge.as_ref(),
+                    enabled: destination.enabled,
+                    selected: index == *self.selected,
+                },
+                destination_rect,
+                id.with(index),
+                INDICATOR_SIZE,
+                pos2(
+                    destination_rect.center().x,
+                    destination_rect.min.y + INDICATOR_SIZE.y / 2.0,
+                ),
+                1.0,
+            );
+            if destination_response.clicked() && destination.enabled {
+                *self.selected = index;
+            }
+            y += DESTINATION_HEIGHT + DESTINATION_SPACING;
+        }
+
+        if *self.selected != previous {
+            response.mark_changed();
+        }
+
+        NavigationRailResponse {
+            response,
+            selected: *self.selected,
+            leading_clicked,
+        }
+    }
+}
+
+/// Top of the destination group for the given alignment, never above `min_y`.
+fn group_top(min_y: f32, max_y: f32, group_height: f32, alignment: RailAlignment) -> f32 {
+    let free = (max_y - min_y - group_height).max(0.0);
+    match alignment {
+        RailAlignment::Top => min_y,
+        RailAlignment::Center => min_y + free / 2.0,
+        RailAlignment::Bottom => min_y + free,
+    }
+}
+
+impl Widget for MaterialNavigationRail<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+/// Convenience function to create a navigation rail with the given destinations.
+pub fn navigation_rail<'a>(
+    selected: &'a mut usize,
+    destinations: &[RailDestination],
+) -> MaterialNavigationRail<'a> {
+    MaterialNavigationRail::new(selected).destinations(destinations.iter().cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_top_follows_alignment() {
+        assert_eq!(group_top(100.0, 500.0, 200.0, RailAlignment::Top), 100.0);
+        assert_eq!(group_top(100.0, 500.0, 200.0, RailAlignment::Center), 200.0);
+        assert_eq!(group_top(100.0, 500.0, 200.0, RailAlignment::Bottom), 300.0);
+        // Too many destinations: stay below the leading items
+        assert_eq!(group_top(100.0, 200.0, 300.0, RailAlignment::Bottom), 100.0);
+    }
+}
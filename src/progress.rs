@@ -16,14 +16,30 @@
 //! - **Linear height**: 4dp, **Track stop**: 0dp gap at completion
 //! - **Circular stroke**: 4dp, **Size**: 48dp (default)
 //! - **Corner radius**: Fully rounded track ends
+//!
+//! ## Label
+//! [`MaterialProgress::with_label`] shows the rounded percentage in the center of
+//! a determinate circular indicator, in Label Large (Label Small below 40dp) and
+//! onSurface. [`circular_progress`] takes an `Option<f32>`, so the same indicator
+//! can switch between determinate and indeterminate from frame to frame.
+//!
+//! ## Reduced Motion
+//! With [`set_reduced_motion`](crate::theme::set_reduced_motion) on, indeterminate
+//! indicators hold still mid-cycle and stop scheduling repaints.
 
 use crate::get_global_color;
+use crate::theme::{reduced_motion, type_font_id, TypeRole};
 use egui::{Color32, CornerRadius, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget};
 use std::f32::consts::PI;
 
 // Animation duration constants (from Flutter reference)
 const INDETERMINATE_LINEAR_DURATION_MS: f32 = 1800.0;
-const INDETERMINATE_CIRCULAR_DURATION_MS: f32 = 1333.0 * 2.222;
+const INDETERMINATE_CIRCULAR_PATH_DURATION_S: f64 = 1.333;
+
+// Where indeterminate indicators hold still with reduced motion: the first
+// linear bar covering the end of the track, and the circular arc at its longest
+const REDUCED_MOTION_LINEAR_TIME_S: f64 = 0.9;
+const REDUCED_MOTION_CIRCULAR_TIME_S: f64 = INDETERMINATE_CIRCULAR_PATH_DURATION_S / 4.0;
 
 // Track gap ramp-down threshold: below this progress value, the gap is
 // scaled proportionally to prevent it from appearing abruptly at 0%.
@@ -58,8 +74,10 @@ pub enum ProgressVariant {
 ///     .size(Vec2::splat(64.0)));
 ///
 /// // Indeterminate linear progress (loading)
-/// ui.add(MaterialProgress::linear()
-///     .indeterminate(true));
+/// ui.add(MaterialProgress::linear_indeterminate());
+///
+/// // Indeterminate circular spinner
+/// ui.add(MaterialProgress::circular_indeterminate());
 ///
 /// // Buffered linear progress (like video loading)
 /// ui.add(MaterialProgress::linear()
@@ -115,6 +133,8 @@ pub struct MaterialProgress {
     stop_indicator_radius: Option<f32>,
     /// Color of the stop indicator dot (default: primary)
     stop_indicator_color: Option<Color32>,
+    /// Whether a determinate circular indicator shows its percentage in the center
+    show_label: bool,
 }
 
 impl MaterialProgress {
@@ -139,6 +159,7 @@ impl MaterialProgress {
             track_gap: None,
             stop_indicator_radius: None,
             stop_indicator_color: None,
+            show_label: false,
         }
     }
 
@@ -152,22 +173,35 @@ impl MaterialProgress {
         Self::new(ProgressVariant::Circular)
     }
 
-    /// Set the current progress value (clamped between 0.0 and max)
+    /// Create an indeterminate linear progress bar (translating bars)
+    pub fn linear_indeterminate() -> Self {
+        Self::linear().indeterminate(true)
+    }
+
+    /// Create an indeterminate circular progress indicator (rotating, sweeping arc)
+    pub fn circular_indeterminate() -> Self {
+        Self::circular().indeterminate(true)
+    }
+
+    /// Set the current progress value (clamped between 0.0 and max when drawn)
     pub fn value(mut self, value: f32) -> Self {
-        self.value = value.clamp(0.0, self.max);
+        self.value = value;
         self
     }
 
     /// Set the maximum value for progress calculation (default: 1.0)
     pub fn max(mut self, max: f32) -> Self {
         self.max = max.max(0.001);
-        self.value = self.value.clamp(0.0, self.max);
         self
     }
 
     /// Set the buffer value for buffered progress (e.g., video buffering)
+    ///
+    /// The buffer is drawn in the lighter buffer color from the current value up
+    /// to `buffer`. It is clamped between 0.0 and max and never drawn below the
+    /// current value.
     pub fn buffer(mut self, buffer: f32) -> Self {
-        self.buffer = Some(buffer.clamp(0.0, self.max));
+        self.buffer = Some(buffer);
         self
     }
 
@@ -177,6 +211,29 @@ impl MaterialProgress {
         self
     }
 
+    /// Show `Some(value)` as determinate progress, or animate as indeterminate for `None`
+    ///
+    /// Lets one indicator switch between the two from frame to frame, e.g. while
+    /// an upload is waiting for its size and then reports how much was sent.
+    pub fn progress(mut self, value: Option<f32>) -> Self {
+        match value {
+            Some(value) => {
+                self.value = value;
+                self.indeterminate = false;
+            }
+            None => self.indeterminate = true,
+        }
+        self
+    }
+
+    /// Show the percentage in the center of a circular indicator
+    ///
+    /// The label is drawn only while the indicator is determinate.
+    pub fn with_label(mut self) -> Self {
+        self.show_label = true;
+        self
+    }
+
     /// Enable or disable four-color animation for indeterminate progress
     pub fn four_color_enabled(mut self, enabled: bool) -> Self {
         self.four_color_enabled = enabled;
@@ -257,15 +314,26 @@ impl MaterialProgress {
     }
 }
 
-impl Widget for MaterialProgress {
-    fn ui(self, ui: &mut Ui) -> Response {
-        let (rect, response) = ui.allocate_exact_size(self.size, Sense::hover());
+impl MaterialProgress {
+    /// Paint the indicator into `rect` without allocating space, e.g. inside
+    /// another widget such as a loading button.
+    pub(crate) fn paint_at(&self, ui: &mut Ui, rect: Rect) {
+        // Nothing to paint off-screen, and no reason to keep animating there
+        if !ui.is_rect_visible(rect) {
+            return;
+        }
 
         match self.variant {
             ProgressVariant::Linear => self.render_linear(ui, rect),
             ProgressVariant::Circular => self.render_circular(ui, rect),
         }
+    }
+}
 
+impl Widget for MaterialProgress {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let (rect, response) = ui.allocate_exact_size(self.size, Sense::hover());
+        self.paint_at(ui, rect);
         response
     }
 }
@@ -337,30 +405,46 @@ fn line2_tail(t: f32) -> f32 {
 }
 
 // --- Circular indeterminate animation (from Flutter) ---
+// Each path grows the arc's head and then retracts its tail over 1333ms, while
+// the whole arc rotates 5/6 of a turn per path. Both phases come straight from
+// the continuous clock, so every wrap-around is a whole turn and never snaps.
 
-const CIRCULAR_PATH_COUNT: f32 = 3.0;
-const CIRCULAR_ROTATION_COUNT: f32 = CIRCULAR_PATH_COUNT * 5.0 / 6.0;
+const CIRCULAR_ROTATION_DURATION_S: f64 = INDETERMINATE_CIRCULAR_PATH_DURATION_S * 6.0 / 5.0;
 
-fn sawtooth(t: f32, count: f32) -> f32 {
-    (t * count).fract()
-}
+/// Start angle and sweep (radians) of the indeterminate circular arc at `time` seconds.
+fn circular_indeterminate_arc(time: f64) -> (f32, f32) {
+    let path = (time / INDETERMINATE_CIRCULAR_PATH_DURATION_S).fract() as f32;
+    let rotation = (time / CIRCULAR_ROTATION_DURATION_S).fract() as f32;
+
+    let head = cubic_bezier(0.4, 0.0, 0.2, 1.0, interval(path, 0.0, 0.5));
+    let tail = cubic_bezier(0.4, 0.0, 0.2, 1.0, interval(path, 0.5, 1.0));
 
-fn circular_head_value(t: f32) -> f32 {
-    let st = sawtooth(t, CIRCULAR_PATH_COUNT);
-    interval(st, 0.0, 0.5)
+    let start = -PI / 2.0 + tail * 1.5 * PI + rotation * 2.0 * PI + path * 0.5 * PI;
+    let sweep = ((head - tail) * 1.5 * PI).max(0.001);
+    (start, sweep)
 }
 
-fn circular_tail_value(t: f32) -> f32 {
-    let st = sawtooth(t, CIRCULAR_PATH_COUNT);
-    interval(st, 0.5, 1.0)
+/// Start and end angle (radians) of the determinate circular arc for `progress`
+/// (0.0 to 1.0): it starts at 12 o'clock and, with y pointing down, grows clockwise.
+fn determinate_arc(progress: f32) -> (f32, f32) {
+    let start = -PI / 2.0;
+    (start, start + 2.0 * PI * progress - 0.001)
 }
 
-fn circular_offset_value(t: f32) -> f32 {
-    sawtooth(t, CIRCULAR_PATH_COUNT)
+/// Rounded percentage shown in the center of a circular indicator
+fn percent_label(progress: f32) -> String {
+    format!("{}%", (progress.clamp(0.0, 1.0) * 100.0).round() as u32)
 }
 
-fn circular_rotation_value(t: f32) -> f32 {
-    sawtooth(t, CIRCULAR_ROTATION_COUNT)
+/// Seconds on the clock driving indeterminate indicators. With reduced motion
+/// the indicator holds still at `rest` and no repaint is scheduled.
+fn indeterminate_time(ui: &Ui, rest: f64) -> f64 {
+    if reduced_motion(ui.ctx()) {
+        rest
+    } else {
+        ui.ctx().request_repaint();
+        ui.input(|i| i.time)
+    }
 }
 
 impl MaterialProgress {
@@ -418,8 +502,12 @@ impl MaterialProgress {
     }
 
     fn render_linear(&self, ui: &mut Ui, rect: Rect) {
+        let time = if self.indeterminate {
+            indeterminate_time(ui, REDUCED_MOTION_LINEAR_TIME_S) as f32
+        } else {
+            0.0
+        };
         let active_color = if self.four_color_enabled && self.indeterminate {
-            let time = ui.input(|i| i.time) as f32;
             self.get_four_color(time)
         } else {
             self.resolve_active_color()
@@ -433,7 +521,6 @@ impl MaterialProgress {
 
         if self.indeterminate {
             // Flutter-style dual-bar indeterminate animation
-            let time = ui.input(|i| i.time) as f32;
             let cycle_duration = INDETERMINATE_LINEAR_DURATION_MS / 1000.0;
             let animation_value = ((time % cycle_duration) / cycle_duration).clamp(0.0, 1.0);
 
@@ -493,8 +580,6 @@ impl MaterialProgress {
             if first_line_head <= 0.0 && second_line_head <= 0.0 {
                 self.draw_linear_segment(ui, rect, 0.0, 1.0, track_color, rounding);
             }
-
-            ui.ctx().request_repaint();
         } else {
             // Determinate progress
             let progress = (self.value / self.max).clamp(0.0, 1.0);
@@ -521,9 +606,9 @@ impl MaterialProgress {
                 ui.painter().circle_filled(center, stop_radius, stop_color);
             }
 
-            // Draw buffer if present
+            // Draw buffer if present (the buffer never trails the value)
             if let Some(buffer) = self.buffer {
-                let buffer_progress = (buffer / self.max).clamp(0.0, 1.0);
+                let buffer_progress = (buffer / self.max).clamp(progress, 1.0);
                 if buffer_progress > progress {
                     let buffer_start = if track_gap_fraction > 0.0 && progress > 0.0 {
                         progress + Self::effective_track_gap_fraction(progress, track_gap_fraction)
@@ -574,27 +659,15 @@ impl MaterialProgress {
         let track_gap = self.resolve_track_gap();
 
         if self.indeterminate {
-            let time = ui.input(|i| i.time) as f32;
-            let cycle_duration = INDETERMINATE_CIRCULAR_DURATION_MS / 1000.0;
-            let animation_value = ((time % cycle_duration) / cycle_duration).clamp(0.0, 1.0);
-
-            let head_value = circular_head_value(animation_value);
-            let tail_value = circular_tail_value(animation_value);
-            let offset_value = circular_offset_value(animation_value);
-            let rotation_value = circular_rotation_value(animation_value);
+            let time = indeterminate_time(ui, REDUCED_MOTION_CIRCULAR_TIME_S);
 
             // Draw track (full circle, no gap for indeterminate)
             ui.painter().circle_stroke(center, radius, Stroke::new(stroke_width, track_color));
 
-            // Calculate arc start and sweep (from Flutter reference)
-            let arc_start = -PI / 2.0
-                + tail_value * 3.0 / 2.0 * PI
-                + rotation_value * PI * 2.0
-                + offset_value * 0.5 * PI;
-            let arc_sweep = (head_value * 3.0 / 2.0 * PI - tail_value * 3.0 / 2.0 * PI).max(0.001);
+            let (arc_start, arc_sweep) = circular_indeterminate_arc(time);
 
             let active_color = if self.four_color_enabled {
-                self.get_four_color(time)
+                self.get_four_color(time as f32)
             } else {
                 self.resolve_active_color()
             };
@@ -608,8 +681,6 @@ impl MaterialProgress {
                 stroke_width,
                 active_color,
             );
-
-            ui.ctx().request_repaint();
         } else {
             let progress = (self.value / self.max).clamp(0.0, 1.0);
             let active_color = self.resolve_active_color();
@@ -647,17 +718,33 @@ impl MaterialProgress {
 
             // Draw progress arc
             if progress > 0.0 {
-                let arc_length = two_pi * progress - epsilon;
+                let (arc_start, arc_end) = determinate_arc(progress);
                 self.draw_arc(
                     ui,
                     center,
                     radius,
-                    -PI / 2.0,
-                    -PI / 2.0 + arc_length,
+                    arc_start,
+                    arc_end,
                     stroke_width,
                     active_color,
                 );
             }
+
+            if self.show_label {
+                // Label Large fits "100%" inside the default 48dp ring
+                let role = if rect.width().min(rect.height()) >= 40.0 {
+                    TypeRole::LabelLarge
+                } else {
+                    TypeRole::LabelSmall
+                };
+                ui.painter().text(
+                    center,
+                    egui::Align2::CENTER_CENTER,
+                    percent_label(progress),
+                    type_font_id(role),
+                    get_global_color("onSurface"),
+                );
+            }
         }
     }
 
@@ -694,10 +781,81 @@ impl MaterialProgress {
     }
 }
 
-pub fn linear_progress() -> MaterialProgress {
-    MaterialProgress::linear()
+/// Create a determinate linear progress bar showing `value` (0.0 to 1.0).
+///
+/// Combine with [`MaterialProgress::buffer`] for a buffering indicator:
+/// `ui.add(linear_progress(0.3).buffer(0.6))`.
+pub fn linear_progress(value: f32) -> MaterialProgress {
+    MaterialProgress::linear().value(value)
 }
 
-pub fn circular_progress() -> MaterialProgress {
-    MaterialProgress::circular()
+/// Create a circular progress indicator showing `Some(value)` (0.0 to 1.0), or an
+/// indeterminate spinner for `None`.
+///
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// use egui_material3::circular_progress;
+///
+/// let uploaded: Option<f32> = Some(0.42);
+/// ui.add(circular_progress(uploaded).with_label());
+/// # });
+/// ```
+pub fn circular_progress(value: impl Into<Option<f32>>) -> MaterialProgress {
+    MaterialProgress::circular().progress(value.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::set_context_reduced_motion;
+    use std::time::Duration;
+
+    /// Delay until the next repaint requested by a spinner's second frame
+    fn spinner_repaint_delay(reduced: bool) -> Duration {
+        let ctx = egui::Context::default();
+        set_context_reduced_motion(&ctx, Some(reduced));
+        let mut delay = Duration::ZERO;
+        for _ in 0..2 {
+            let output = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    ui.add(circular_progress(None));
+                });
+            });
+            delay = output.viewport_output[&egui::ViewportId::ROOT].repaint_delay;
+        }
+        delay
+    }
+
+    #[test]
+    fn test_spinner_schedules_repaints() {
+        assert_eq!(spinner_repaint_delay(false), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_reduced_motion_spinner_does_not_schedule_repaints() {
+        assert_eq!(spinner_repaint_delay(true), Duration::MAX);
+    }
+
+    #[test]
+    fn test_determinate_arc_starts_at_top_and_runs_clockwise() {
+        let point = |angle: f32| Vec2::new(angle.cos(), angle.sin());
+        let (start, end) = determinate_arc(0.25);
+        // y points down, so 12 o'clock is (0, -1) and 3 o'clock is (1, 0)
+        assert!((point(start) - Vec2::new(0.0, -1.0)).length() < 1e-3);
+        assert!((point(end) - Vec2::new(1.0, 0.0)).length() < 1e-2);
+        assert!(end > start);
+    }
+
+    #[test]
+    fn test_progress_switches_between_determinate_and_indeterminate() {
+        let determinate = circular_progress(0.4);
+        assert!(!determinate.indeterminate);
+        assert_eq!(determinate.value, 0.4);
+        assert!(circular_progress(None).indeterminate);
+        let finished = MaterialProgress::circular_indeterminate().progress(Some(1.0));
+        assert!(!finished.indeterminate);
+
+        assert_eq!(percent_label(0.456), "46%");
+        assert_eq!(percent_label(1.2), "100%");
+    }
 }
@@ -16,6 +16,13 @@
 //! ## Disabled State
 //! - **onSurfaceVariant @ 38%**: All elements (ring, dot) use this opacity (M3 spec)
 //!
+//! ## Focused State
+//! - **secondary**: 3dp focus indicator ring around the focused radio
+//!
+//! ## Helper Text
+//! - **onSurfaceVariant**: Supporting text under an option's label (Body Small),
+//!   part of the option's click target
+//!
 //! ## Radio List Tile
 //! - **surfaceVariant @ 50%**: Selected tile background
 //! - **onSurface**: Title text
@@ -26,9 +33,20 @@
 //! - **40x40dp**: Minimum touch target size (ripple overlay area)
 //! - **20x20dp**: Visible radio button size
 //! - **10x10dp**: Inner selected dot size
+//!
+//! ## Keyboard
+//! A [`MaterialRadioGroup`] is a single Tab stop: Tab focuses the selected
+//! option (or the first one) and the next Tab leaves the group. While the
+//! group has focus, Up/Left and Down/Right select the previous and next
+//! option, and Space/Enter select the focused one. This is the same whether
+//! the group is laid out vertically or in a horizontal, wrapping row.
 
 use crate::get_global_color;
-use egui::{self, Color32, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget, FontId};
+use crate::theme::{type_font_id, TypeRole};
+use egui::{
+    self, Align, Color32, FontId, Id, Key, Layout, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2,
+    Widget, WidgetInfo, WidgetType,
+};
 
 /// Material Design radio button component.
 ///
@@ -53,6 +71,8 @@ pub struct MaterialRadio<'a, T: PartialEq + Clone> {
     value: T,
     /// Text label for the radio button
     text: String,
+    /// Supporting text shown under the label
+    helper_text: Option<String>,
     /// Whether the radio button is enabled
     enabled: bool,
     /// Whether the radio can be toggled off when clicked while selected
@@ -67,6 +87,12 @@ pub struct MaterialRadio<'a, T: PartialEq + Clone> {
     inner_radius: Option<f32>,
     /// Custom splash radius for ripple effect
     splash_radius: Option<f32>,
+    /// Fixed id, set by a group so it can move focus between its radios
+    id: Option<Id>,
+    /// Whether Tab can move focus to this radio
+    focusable: bool,
+    /// Row width to fit into when a horizontal group sizes the radio to its label
+    hug_width: Option<f32>,
 }
 
 /// Material Design radio button group component.
@@ -96,12 +122,18 @@ pub struct MaterialRadioGroup<'a, T: PartialEq + Clone> {
     enabled: bool,
     /// Whether radios can be toggled off
     toggleable: bool,
+    /// Whether arrow keys wrap around from the last option to the first
+    wrap_around: bool,
+    /// Whether options are laid out in a wrapping row instead of a column
+    horizontal: bool,
 }
 
 /// Individual radio option data.
 pub struct RadioOption<T: PartialEq + Clone> {
     /// Display text for the option
     text: String,
+    /// Supporting text shown under the option's label
+    helper_text: Option<String>,
     /// Unique value identifying this option
     value: T,
 }
@@ -126,6 +158,7 @@ impl<'a, T: PartialEq + Clone> MaterialRadio<'a, T> {
             selected,
             value,
             text: text.into(),
+            helper_text: None,
             enabled: true,
             toggleable: false,
             fill_color: None,
@@ -133,9 +166,29 @@ impl<'a, T: PartialEq + Clone> MaterialRadio<'a, T> {
             background_color: None,
             inner_radius: None,
             splash_radius: None,
+            id: None,
+            focusable: true,
+            hug_width: None,
         }
     }
 
+    /// Show supporting text under the label in **onSurfaceVariant**.
+    ///
+    /// The text wraps to the radio's width, and clicking it selects the radio.
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut selection = Some(0);
+    /// ui.add(MaterialRadio::new(&mut selection, 0, "Wi-Fi only")
+    ///     .helper_text("Large downloads wait for a Wi-Fi connection"));
+    /// # });
+    /// ```
+    pub fn helper_text(mut self, text: impl Into<String>) -> Self {
+        self.helper_text = Some(text.into());
+        self
+    }
+
     /// Set whether the radio button is enabled.
     ///
     /// # Arguments
@@ -205,12 +258,52 @@ impl<'a, T: PartialEq + Clone> MaterialRadio<'a, T> {
 
 impl<'a, T: PartialEq + Clone> Widget for MaterialRadio<'a, T> {
     fn ui(self, ui: &mut Ui) -> Response {
-        let desired_size = Vec2::new(ui.available_width().min(300.0), 24.0);
+        let radio_size = 20.0;
+        let label_offset = radio_size + 8.0;
+        let max_width = self
+            .hug_width
+            .unwrap_or_else(|| ui.available_width().min(300.0));
+        let helper_galley = self.helper_text.as_ref().map(|helper| {
+            ui.painter().layout(
+                helper.clone(),
+                type_font_id(TypeRole::BodySmall),
+                Color32::PLACEHOLDER,
+                (max_width - label_offset).max(0.0),
+            )
+        });
+        let width = if self.hug_width.is_some() {
+            let label_width = ui
+                .painter()
+                .layout_no_wrap(self.text.clone(), FontId::default(), Color32::PLACEHOLDER)
+                .size()
+                .x;
+            let helper_width = helper_galley.as_ref().map_or(0.0, |galley| galley.size().x);
+            (label_offset + label_width.max(helper_width)).min(max_width)
+        } else {
+            max_width
+        };
+        let helper_height = helper_galley.as_ref().map_or(0.0, |galley| galley.size().y);
+        let desired_size = Vec2::new(width, 24.0 + helper_height);
 
-        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+        // Radios a group skips when tabbing still take clicks
+        let sense = if !self.enabled {
+            Sense::hover()
+        } else if self.focusable {
+            Sense::click()
+        } else {
+            Sense::CLICK
+        };
+        let (rect, mut response) = match self.id {
+            Some(id) => {
+                let (rect, _) = ui.allocate_exact_size(desired_size, Sense::hover());
+                (rect, ui.interact(rect, id, sense))
+            }
+            None => ui.allocate_exact_size(desired_size, sense),
+        };
 
         let is_selected = self.selected.as_ref() == Some(&self.value);
 
+        // Space/Enter on the focused radio arrive as clicks
         if response.clicked() && self.enabled {
             if self.toggleable && is_selected {
                 // Deselect if toggleable and already selected
@@ -221,6 +314,15 @@ impl<'a, T: PartialEq + Clone> Widget for MaterialRadio<'a, T> {
             }
             response.mark_changed();
         }
+        let is_selected = self.selected.as_ref() == Some(&self.value);
+        response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::RadioButton,
+                self.enabled,
+                is_selected,
+                &self.text,
+            )
+        });
 
         // M3 Color Roles - Radio Button States
         let primary = self.fill_color.unwrap_or_else(|| get_global_color("primary")); // Selected ring and dot
@@ -228,9 +330,10 @@ impl<'a, T: PartialEq + Clone> Widget for MaterialRadio<'a, T> {
         let on_surface_variant = get_global_color("onSurfaceVariant"); // Disabled @ 38%
         let outline = get_global_color("outline"); // Unselected ring (2dp stroke)
 
-        let radio_size = 20.0;
+        // The radio and label sit in the first 24dp row, helper text below them
+        let row_center_y = rect.min.y + 12.0;
         let radio_rect = Rect::from_min_size(
-            Pos2::new(rect.min.x, rect.center().y - radio_size / 2.0),
+            Pos2::new(rect.min.x, row_center_y - radio_size / 2.0),
             Vec2::splat(radio_size),
         );
 
@@ -278,7 +381,7 @@ impl<'a, T: PartialEq + Clone> Widget for MaterialRadio<'a, T> {
 
         // Draw label text
         if !self.text.is_empty() {
-            let text_pos = Pos2::new(radio_rect.max.x + 8.0, rect.center().y);
+            let text_pos = Pos2::new(rect.min.x + label_offset, row_center_y);
 
             // Label text: onSurface for enabled, onSurfaceVariant @ 38% for disabled (M3 spec)
             let text_color = if self.enabled {
@@ -296,6 +399,19 @@ impl<'a, T: PartialEq + Clone> Widget for MaterialRadio<'a, T> {
             );
         }
 
+        if let Some(galley) = helper_galley {
+            let helper_color = if self.enabled {
+                on_surface_variant
+            } else {
+                on_surface_variant.linear_multiply(0.38)
+            };
+            ui.painter().galley(
+                Pos2::new(rect.min.x + label_offset, rect.min.y + 24.0),
+                galley,
+                helper_color,
+            );
+        }
+
         // M3 ripple effect on hover (40x40dp touch target)
         if response.hovered() && self.enabled {
             let ripple_color = self.overlay_color.unwrap_or_else(|| {
@@ -313,10 +429,31 @@ impl<'a, T: PartialEq + Clone> Widget for MaterialRadio<'a, T> {
                 .circle_filled(radio_rect.center(), ripple_radius, ripple_color);
         }
 
+        // M3 focus indicator: 3dp secondary ring
+        if response.has_focus() && self.enabled {
+            ui.painter().circle_stroke(
+                radio_rect.center(),
+                radio_size / 2.0 + 6.0,
+                Stroke::new(3.0, get_global_color("secondary")),
+            );
+        }
+
         response
     }
 }
 
+/// Index of the option `step` places away from `index`, if there is one
+fn adjacent_index(index: usize, step: isize, len: usize, wrap_around: bool) -> Option<usize> {
+    let target = index as isize + step;
+    if wrap_around {
+        Some(target.rem_euclid(len as isize) as usize)
+    } else {
+        (0..len as isize)
+            .contains(&target)
+            .then_some(target as usize)
+    }
+}
+
 impl<'a, T: PartialEq + Clone> MaterialRadioGroup<'a, T> {
     /// Create a new radio button group.
     ///
@@ -336,6 +473,8 @@ impl<'a, T: PartialEq + Clone> MaterialRadioGroup<'a, T> {
             options: Vec::new(),
             enabled: true,
             toggleable: false,
+            wrap_around: true,
+            horizontal: false,
         }
     }
 
@@ -357,6 +496,32 @@ impl<'a, T: PartialEq + Clone> MaterialRadioGroup<'a, T> {
     pub fn option(mut self, value: T, text: impl Into<String>) -> Self {
         self.options.push(RadioOption {
             text: text.into(),
+            helper_text: None,
+            value,
+        });
+        self
+    }
+
+    /// Add an option with supporting text shown under its label.
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut quality = Some(1);
+    /// ui.add(MaterialRadioGroup::new(&mut quality)
+    ///     .option_with_helper_text(0, "Data saver", "Lower quality, less data")
+    ///     .option_with_helper_text(1, "High", "Best quality on fast connections"));
+    /// # });
+    /// ```
+    pub fn option_with_helper_text(
+        mut self,
+        value: T,
+        text: impl Into<String>,
+        helper_text: impl Into<String>,
+    ) -> Self {
+        self.options.push(RadioOption {
+            text: text.into(),
+            helper_text: Some(helper_text.into()),
             value,
         });
         self
@@ -386,19 +551,70 @@ impl<'a, T: PartialEq + Clone> MaterialRadioGroup<'a, T> {
         self.toggleable = toggleable;
         self
     }
+
+    /// Set whether arrow keys wrap from the last option to the first and back.
+    ///
+    /// Enabled by default, like native radio groups.
+    pub fn wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
+    /// Lay the options out in a row that wraps when it runs out of width.
+    ///
+    /// Each option is only as wide as its label and helper text, so a compact
+    /// settings panel can fit several on one line.
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut size = Some("M");
+    /// ui.add(MaterialRadioGroup::new(&mut size)
+    ///     .option("S", "Small")
+    ///     .option("M", "Medium")
+    ///     .option("L", "Large")
+    ///     .horizontal(true));
+    /// # });
+    /// ```
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.horizontal = horizontal;
+        self
+    }
 }
 
 impl<'a, T: PartialEq + Clone> Widget for MaterialRadioGroup<'a, T> {
     fn ui(self, ui: &mut Ui) -> Response {
-        let mut group_response = None;
+        let group_id = ui.next_auto_id().with("radio_group");
+        let ids: Vec<Id> = (0..self.options.len()).map(|i| group_id.with(i)).collect();
+
+        // Only one radio is a Tab stop, so Tab moves into and out of the group as a unit
+        let focused_index = ids.iter().position(|id| ui.memory(|m| m.has_focus(*id)));
+        let selected_index = self
+            .options
+            .iter()
+            .position(|option| self.selected.as_ref() == Some(&option.value));
+        let tab_stop = focused_index.or(selected_index).unwrap_or(0);
+
+        let mut group_response: Option<Response> = None;
+        let mut focused = None;
 
-        ui.vertical(|ui| {
-            for option in self.options {
-                let radio = MaterialRadio::new(self.selected, option.value, option.text)
-                    .enabled(self.enabled)
-                    .toggleable(self.toggleable);
+        // Horizontal options size themselves to their labels and wrap within the full row
+        let row_width = self.horizontal.then(|| ui.available_width());
+        let mut add_radios = |ui: &mut Ui| {
+            for (index, option) in self.options.iter().enumerate() {
+                let mut radio =
+                    MaterialRadio::new(self.selected, option.value.clone(), option.text.clone())
+                        .enabled(self.enabled)
+                        .toggleable(self.toggleable);
+                radio.helper_text = option.helper_text.clone();
+                radio.id = Some(ids[index]);
+                radio.focusable = index == tab_stop;
+                radio.hug_width = row_width;
 
                 let response = ui.add(radio);
+                if response.has_focus() {
+                    focused = Some(index);
+                }
 
                 if group_response.is_none() {
                     group_response = Some(response);
@@ -406,7 +622,50 @@ impl<'a, T: PartialEq + Clone> Widget for MaterialRadioGroup<'a, T> {
                     *group_resp = group_resp.union(response);
                 }
             }
-        });
+        };
+        if self.horizontal {
+            let layout = Layout::left_to_right(Align::Min).with_main_wrap(true);
+            ui.with_layout(layout, |ui| {
+                ui.spacing_mut().item_spacing.x = 16.0;
+                add_radios(ui);
+            });
+        } else {
+            ui.vertical(add_radios);
+        }
+
+        // Arrow keys move the selection, and focus with it, to the adjacent option
+        if let Some(index) = focused.filter(|_| self.enabled) {
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    ids[index],
+                    egui::EventFilter {
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        ..Default::default()
+                    },
+                )
+            });
+            let step = ui.input(|i| {
+                if i.key_pressed(Key::ArrowDown) || i.key_pressed(Key::ArrowRight) {
+                    1
+                } else if i.key_pressed(Key::ArrowUp) || i.key_pressed(Key::ArrowLeft) {
+                    -1
+                } else {
+                    0
+                }
+            });
+            let next = match step {
+                0 => None,
+                step => adjacent_index(index, step, ids.len(), self.wrap_around),
+            };
+            if let Some(next) = next {
+                *self.selected = Some(self.options[next].value.clone());
+                ui.memory_mut(|m| m.request_focus(ids[next]));
+                if let Some(response) = &mut group_response {
+                    response.mark_changed();
+                }
+            }
+        }
 
         group_response.unwrap_or_else(|| {
             let (_rect, response) = ui.allocate_exact_size(Vec2::ZERO, Sense::hover());
@@ -570,6 +829,15 @@ impl<'a, T: PartialEq + Clone> Widget for RadioListTile<'a, T> {
             }
             response.mark_changed();
         }
+        let is_selected = self.selected.as_ref() == Some(&self.value);
+        response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::RadioButton,
+                self.enabled,
+                is_selected,
+                self.title.as_deref().unwrap_or(""),
+            )
+        });
         
         // M3 Color Roles - Radio List Tile
         let on_surface = get_global_color("onSurface"); // Title text, hover state layer
@@ -765,3 +1033,48 @@ pub fn radio_list_tile<'a, T: PartialEq + Clone>(
 ) -> RadioListTile<'a, T> {
     RadioListTile::new(selected, value)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjacent_index_wraps_only_when_enabled() {
+        assert_eq!(adjacent_index(0, 1, 3, true), Some(1));
+        assert_eq!(adjacent_index(2, 1, 3, true), Some(0));
+        assert_eq!(adjacent_index(0, -1, 3, true), Some(2));
+        assert_eq!(adjacent_index(2, 1, 3, false), None);
+        assert_eq!(adjacent_index(0, -1, 3, false), None);
+        assert_eq!(adjacent_index(1, -1, 3, false), Some(0));
+    }
+
+    #[test]
+    fn test_horizontal_group_wraps_when_narrow() {
+        fn group_height(width: f32, helper: bool) -> f32 {
+            let ctx = egui::Context::default();
+            let mut height = 0.0;
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    ui.allocate_ui(Vec2::new(width, 400.0), |ui| {
+                        let mut selected = Some(0);
+                        let mut group = MaterialRadioGroup::new(&mut selected)
+                            .option(0, "First")
+                            .option(1, "Second")
+                            .horizontal(true);
+                        group = if helper {
+                            group.option_with_helper_text(2, "Third", "With helper text")
+                        } else {
+                            group.option(2, "Third")
+                        };
+                        height = ui.add(group).rect.height();
+                    });
+                });
+            });
+            height
+        }
+
+        assert_eq!(group_height(600.0, false), 24.0);
+        assert!(group_height(600.0, true) > 24.0);
+        assert!(group_height(120.0, false) >= 48.0);
+    }
+}
@@ -0,0 +1,290 @@
+//! Material Design 3 Ripple
+//!
+//! Shared interaction feedback for any clickable surface: the hover and focus
+//! state layers plus a ripple that expands from the press position and fades
+//! out after release. Components call [`Ripple::show`] after painting their
+//! container and before painting their content.
+//!
+//! # M3 State Layers
+//!
+//! - **Hover**: content color @ 8%
+//! - **Focus**: content color @ 12%
+//! - **Pressed**: ripple in the content color @ 12%, clipped to the container shape
+//!
+//! With [`set_reduced_motion`](crate::theme::set_reduced_motion) the ripple does
+//! not animate; the pressed layer covers the whole container instead.
+
+use crate::theme::{reduced_motion, HOVER_STATE_LAYER_OPACITY, PRESSED_STATE_LAYER_OPACITY};
+use egui::{
+    ecolor::Color32,
+    epaint::{CornerRadius, Shape, Stroke},
+    vec2, Pos2, Rect, Response, Ui, Vec2,
+};
+
+/// Seconds for the ripple to grow to its full size
+const RIPPLE_EXPAND_DURATION: f64 = 0.3;
+/// Seconds for the ripple to fade out once released
+const RIPPLE_FADE_DURATION: f64 = 0.15;
+/// Segments used for the ripple circle and each rounded corner
+const CIRCLE_SEGMENTS: usize = 32;
+const CORNER_SEGMENTS: usize = 8;
+
+/// An in-progress ripple, stored in temp memory under the widget's id
+#[derive(Clone, Copy, Debug)]
+struct RippleState {
+    origin: Pos2,
+    pressed_at: f64,
+    released_at: Option<f64>,
+}
+
+/// Material Design ripple and state layer painter.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # use egui_material3::{get_global_color, Ripple};
+/// let (rect, response) = ui.allocate_exact_size(egui::vec2(120.0, 40.0), egui::Sense::click());
+/// ui.painter().rect_filled(rect, 20.0, get_global_color("primary"));
+/// Ripple::new(get_global_color("onPrimary"))
+///     .corner_radius(20.0)
+///     .show(ui, rect, &response);
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Ripple {
+    color: Color32,
+    corner_radius: CornerRadius,
+    state_layer: bool,
+}
+
+impl Ripple {
+    /// Create a ripple in `color`, usually the content color of the component.
+    pub fn new(color: Color32) -> Self {
+        Self {
+            color,
+            corner_radius: CornerRadius::ZERO,
+            state_layer: true,
+        }
+    }
+
+    /// Set the container shape the ripple is clipped to.
+    pub fn corner_radius(mut self, corner_radius: impl Into<CornerRadius>) -> Self {
+        self.corner_radius = corner_radius.into();
+        self
+    }
+
+    /// Draw the hover and focus state layers (default: true).
+    ///
+    /// Turn this off for components that show hover by other means, such as a
+    /// change of container color.
+    pub fn state_layer(mut self, state_layer: bool) -> Self {
+        self.state_layer = state_layer;
+        self
+    }
+
+    /// Paint the state layer and ripple for `response` over `rect`.
+    pub fn show(&self, ui: &Ui, rect: Rect, response: &Response) {
+        let pressed = response.is_pointer_button_down_on();
+
+        if self.state_layer {
+            let opacity = if response.has_focus() {
+                PRESSED_STATE_LAYER_OPACITY
+            } else if response.hovered() {
+                HOVER_STATE_LAYER_OPACITY
+            } else {
+                0.0
+            };
+            if opacity > 0.0 {
+                ui.painter().rect_filled(
+                    rect,
+                    self.corner_radius,
+                    self.color.linear_multiply(opacity),
+                );
+            }
+        }
+
+        if reduced_motion(ui.ctx()) {
+            if pressed {
+                ui.painter().rect_filled(
+                    rect,
+                    self.corner_radius,
+                    self.color.linear_multiply(PRESSED_STATE_LAYER_OPACITY),
+                );
+            }
+            return;
+        }
+
+        let ctx = ui.ctx();
+        let id = response.id.with("ripple");
+        let now = ui.input(|i| i.time);
+        let origin = || {
+            response
+                .interact_pointer_pos()
+                .filter(|pos| rect.contains(*pos))
+                .unwrap_or(rect.center())
+        };
+
+        let mut state = ctx.data(|d| d.get_temp::<RippleState>(id));
+        let active = state.is_some_and(|state| state.released_at.is_none());
+        if pressed && !active {
+            state = Some(RippleState {
+                origin: origin(),
+                pressed_at: now,
+                released_at: None,
+            });
+        } else if !pressed && active {
+            if let Some(state) = &mut state {
+                state.released_at = Some(now);
+            }
+        } else if response.clicked() && state.is_none() {
+            // Keyboard activation, or a press and release within one frame
+            state = Some(RippleState {
+                origin: origin(),
+                pressed_at: now,
+                released_at: Some(now),
+            });
+        }
+
+        let Some(state) = state else {
+            return;
+        };
+
+        let expand_t = ((now - state.pressed_at) / RIPPLE_EXPAND_DURATION).clamp(0.0, 1.0) as f32;
+        let fade_t = state.released_at.map_or(0.0, |released_at| {
+            // Let a quick tap grow for a moment before it fades
+            let fade_start = released_at.max(state.pressed_at + RIPPLE_EXPAND_DURATION / 2.0);
+            ((now - fade_start) / RIPPLE_FADE_DURATION).clamp(0.0, 1.0) as f32
+        });
+
+        if fade_t >= 1.0 {
+            ctx.data_mut(|d| d.remove::<RippleState>(id));
+            return;
+        }
+        ctx.data_mut(|d| d.insert_temp(id, state));
+        ctx.request_repaint();
+
+        let eased = 1.0 - (1.0 - expand_t).powi(3);
+        let radius = max_radius(rect, state.origin) * eased;
+        let points = clip_to_rounded_rect(
+            circle_points(state.origin, radius),
+            rect,
+            self.corner_radius,
+        );
+        if points.len() >= 3 {
+            let alpha = PRESSED_STATE_LAYER_OPACITY * (1.0 - fade_t);
+            ui.painter().add(Shape::convex_polygon(
+                points,
+                self.color.linear_multiply(alpha),
+                Stroke::NONE,
+            ));
+        }
+    }
+}
+
+/// Distance from `origin` to the farthest corner of `rect`, where the ripple covers it fully.
+fn max_radius(rect: Rect, origin: Pos2) -> f32 {
+    [
+        rect.left_top(),
+        rect.right_top(),
+        rect.left_bottom(),
+        rect.right_bottom(),
+    ]
+    .iter()
+    .map(|corner| corner.distance(origin))
+    .fold(0.0, f32::max)
+}
+
+fn circle_points(center: Pos2, radius: f32) -> Vec<Pos2> {
+    (0..CIRCLE_SEGMENTS)
+        .map(|i| {
+            let angle = i as f32 / CIRCLE_SEGMENTS as f32 * std::f32::consts::TAU;
+            center + radius * Vec2::angled(angle)
+        })
+        .collect()
+}
+
+/// Outline of a rounded rectangle, clockwise on screen starting at the top-left corner.
+pub(crate) fn rounded_rect_points(rect: Rect, corner_radius: CornerRadius) -> Vec<Pos2> {
+    let max_radius = rect.width().min(rect.height()) / 2.0;
+    let corners = [
+        (corner_radius.nw, rect.left_top(), vec2(1.0, 1.0), 0.5),
+        (corner_radius.ne, rect.right_top(), vec2(-1.0, 1.0), 0.75),
+        (corner_radius.se, rect.right_bottom(), vec2(-1.0, -1.0), 0.0),
+        (corner_radius.sw, rect.left_bottom(), vec2(1.0, -1.0), 0.25),
+    ];
+
+    let mut points = Vec::new();
+    for (radius, corner, inward, start_turn) in corners {
+        let radius = (radius as f32).min(max_radius);
+        if radius <= 0.0 {
+            points.push(corner);
+            continue;
+        }
+        let center = corner + inward * radius;
+        for i in 0..=CORNER_SEGMENTS {
+            let turn = start_turn + 0.25 * i as f32 / CORNER_SEGMENTS as f32;
+            points.push(center + radius * Vec2::angled(turn * std::f32::consts::TAU));
+        }
+    }
+    points
+}
+
+/// Clip a convex polygon to a rounded rectangle (Sutherland–Hodgman).
+fn clip_to_rounded_rect(polygon: Vec<Pos2>, rect: Rect, corner_radius: CornerRadius) -> Vec<Pos2> {
+    let clip = rounded_rect_points(rect, corner_radius);
+    // Positive on the inner side of the clockwise edge a -> b
+    let side = |a: Pos2, b: Pos2, p: Pos2| (b - a).x * (p - a).y - (b - a).y * (p - a).x;
+
+    let mut output = polygon;
+    for (i, &a) in clip.iter().enumerate() {
+        let b = clip[(i + 1) % clip.len()];
+        if a == b || output.is_empty() {
+            continue;
+        }
+        let input = std::mem::take(&mut output);
+        for (j, &current) in input.iter().enumerate() {
+            let previous = input[(j + input.len() - 1) % input.len()];
+            let current_side = side(a, b, current);
+            let previous_side = side(a, b, previous);
+            if (current_side >= 0.0) != (previous_side >= 0.0) {
+                let t = previous_side / (previous_side - current_side);
+                output.push(previous + (current - previous) * t);
+            }
+            if current_side >= 0.0 {
+                output.push(current);
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::pos2;
+
+    #[test]
+    fn test_ripple_is_clipped_to_rounded_container() {
+        let rect = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 40.0));
+        let corner_radius = CornerRadius::same(20);
+
+        // A ripple covering the whole container takes the container's shape
+        let full = clip_to_rounded_rect(
+            circle_points(rect.center(), max_radius(rect, rect.center()) + 1.0),
+            rect,
+            corner_radius,
+        );
+        assert!(full.len() > 4);
+        for point in &full {
+            assert!(rect.expand(0.01).contains(*point));
+            // Nothing in the rounded-off top-left corner
+            assert!(!(point.x < 5.0 && point.y < 5.0));
+        }
+
+        // A small ripple well inside the container is left unchanged
+        let small = circle_points(pos2(50.0, 20.0), 5.0);
+        assert_eq!(
+            clip_to_rounded_rect(small.clone(), rect, corner_radius).len(),
+            small.len()
+        );
+    }
+}
@@ -0,0 +1,494 @@
+//! Material Design 3 Segmented Button
+//!
+//! A row of 2–5 connected segments for choosing between options or toggling
+//! views, e.g. list vs. grid. Segments share one pill-shaped outline: only the
+//! outer corners of the first and last segment are rounded.
+//!
+//! # Selection Modes
+//!
+//! - [`MaterialSegmentedButton::single`]: exactly one segment is selected, like a radio group
+//! - [`MaterialSegmentedButton::multi`]: each segment toggles independently, like checkboxes
+//!
+//! # M3 Color Role Usage
+//!
+//! - **secondaryContainer**: Selected segment container
+//! - **onSecondaryContainer**: Selected segment label, icon and checkmark
+//! - **onSurface**: Unselected segment label and icon
+//! - **outline**: Outline and dividers between segments
+//! - **State layers**: onSurface/onSecondaryContainer @ 8% (hover), 12% (press)
+//!
+//! ## Dimensions
+//! - **Height**: 40dp, fully rounded outer corners
+//! - **Icon/checkmark**: 18dp, label 14sp
+//! - **Padding**: 12dp on each side of the content, 8dp between icon and label
+//!
+//! ## Keyboard
+//! Left/Right arrows move focus between enabled segments; Space or Enter
+//! selects the focused segment.
+
+use crate::material_symbol::material_symbol_text_or_default;
+use crate::theme::{get_global_color, type_font_id, TypeRole};
+use egui::{
+    epaint::CornerRadius, pos2, Align2, FontId, Id, Key, Rect, Response, Sense, Stroke, Ui, Vec2,
+    Widget, WidgetInfo, WidgetType,
+};
+
+/// Height of the button set
+const SEGMENT_HEIGHT: f32 = 40.0;
+/// Horizontal padding on each side of a segment's content
+const SEGMENT_PADDING: f32 = 12.0;
+/// Narrowest a segment may be
+const MIN_SEGMENT_WIDTH: f32 = 48.0;
+/// Icon and checkmark size
+const ICON_SIZE: f32 = 18.0;
+/// Gap between the icon/checkmark and the label
+const ICON_LABEL_GAP: f32 = 8.0;
+
+/// A segment shown in a [`MaterialSegmentedButton`].
+///
+/// `icon` accepts a Material Symbols name (e.g. `"grid_view"`); any other
+/// string, such as an emoji, is drawn as-is.
+#[derive(Clone)]
+pub struct ButtonSegment {
+    pub label: Option<String>,
+    pub icon: Option<String>,
+    pub enabled: bool,
+}
+
+impl ButtonSegment {
+    /// Create a segment with a text label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: Some(label.into()),
+            icon: None,
+            enabled: true,
+        }
+    }
+
+    /// Create a segment that shows only an icon.
+    pub fn icon_only(icon: impl Into<String>) -> Self {
+        Self {
+            label: None,
+            icon: Some(icon.into()),
+            enabled: true,
+        }
+    }
+
+    /// Add a leading icon; it is replaced by the checkmark while selected.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+/// The state a segmented button edits.
+enum Selection<'a> {
+    Single(&'a mut usize),
+    Multi(&'a mut Vec<bool>),
+}
+
+impl Selection<'_> {
+    fn is_selected(&self, index: usize) -> bool {
+        match self {
+            Selection::Single(selected) => **selected == index,
+            Selection::Multi(selected) => selected.get(index).copied().unwrap_or(false),
+        }
+    }
+
+    fn toggle(&mut self, index: usize) {
+        match self {
+            Selection::Single(selected) => **selected = index,
+            Selection::Multi(selected) => {
+                if selected.len() <= index {
+                    selected.resize(index + 1, false);
+                }
+                selected[index] = !selected[index];
+            }
+        }
+    }
+}
+
+/// Result of showing a [`MaterialSegmentedButton`].
+pub struct SegmentedButtonResponse {
+    /// The response covering all segments; `changed()` is set when the selection changed
+    pub response: Response,
+    /// In single-select mode, the selected segment index after this frame
+    pub selected: Option<usize>,
+    /// The segment that was clicked this frame, if any
+    pub clicked: Option<usize>,
+}
+
+/// Material Design segmented button component.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// // Single-select: a view toggle
+/// let mut view = 0;
+/// MaterialSegmentedButton::single(&mut view)
+///     .segment(ButtonSegment::new("List").icon("list"))
+///     .segment(ButtonSegment::new("Grid").icon("grid_view"))
+///     .show(ui);
+///
+/// // Multi-select: independent filters
+/// let mut sizes = vec![false, true, false];
+/// MaterialSegmentedButton::multi(&mut sizes)
+///     .segments(["S", "M", "L"].map(ButtonSegment::new))
+///     .show(ui);
+/// # });
+/// ```
+pub struct MaterialSegmentedButton<'a> {
+    selection: Selection<'a>,
+    segments: Vec<ButtonSegment>,
+    show_checkmark: bool,
+    id: Option<Id>,
+}
+
+impl<'a> MaterialSegmentedButton<'a> {
+    /// Create a single-select segmented button; `selected` is the chosen segment index.
+    pub fn single(selected: &'a mut usize) -> Self {
+        Self::with_selection(Selection::Single(selected))
+    }
+
+    /// Create a multi-select segmented button; `selected[i]` is whether segment `i` is on.
+    pub fn multi(selected: &'a mut Vec<bool>) -> Self {
+        Self::with_selection(Selection::Multi(selected))
+    }
+
+    fn with_selection(selection: Selection<'a>) -> Self {
+        Self {
+            selection,
+            segments: Vec::new(),
+            show_checkmark: true,
+            id: None,
+        }
+    }
+
+    /// Add a segment.
+    pub fn segment(mut self, segment: ButtonSegment) -> Self {
+        self.segments.push(segment);
+        self
+    }
+
+    /// Add several segments.
+    pub fn segments(mut self, segments: impl IntoIterator<Item = ButtonSegment>) -> Self {
+        self.segments.extend(segments);
+        self
+    }
+
+    /// Show a checkmark in selected segments (default: true).
+    pub fn show_checkmark(mut self, show_checkmark: bool) -> Self {
+        self.show_checkmark = show_checkmark;
+        self
+    }
+
+    /// Set an explicit id, needed when several segmented buttons are shown in the same `Ui`.
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn show(mut self, ui: &mut Ui) -> SegmentedButtonResponse {
+        let id = self.id.unwrap_or_else(|| ui.id().with("segmented_button"));
+        let count = self.segments.len();
+        let label_font = type_font_id(TypeRole::LabelLarge);
+        let icon_font = FontId::proportional(ICON_SIZE);
+
+        // All segments share the width of the widest one
+        let content_width = self
+            .segments
+            .iter()
+            .map(|segment| {
+                let label_width = segment.label.as_ref().map_or(0.0, |label| {
+                    ui.painter()
+                        .layout_no_wrap(label.clone(), label_font.clone(), Default::default())
+                        .size()
+                        .x
+                });
+                // Room for an icon or the checkmark that replaces it
+                let leading = if segment.icon.is_some() || self.show_checkmark {
+                    ICON_SIZE
+                } else {
+                    0.0
+                };
+                let gap = if leading > 0.0 && label_width > 0.0 {
+                    ICON_LABEL_GAP
+                } else {
+                    0.0
+                };
+                leading + gap + label_width
+            })
+            .fold(0.0, f32::max);
+        let segment_width = (content_width + SEGMENT_PADDING * 2.0).max(MIN_SEGMENT_WIDTH);
+
+        let (rect, mut response) = ui.allocate_exact_size(
+            Vec2::new(segment_width * count as f32, SEGMENT_HEIGHT),
+            Sense::hover(),
+        );
+
+        let ids: Vec<Id> = (0..count).map(|index| id.with(index)).collect();
+        let mut clicked = None;
+        let mut focused = None;
+
+        for (index, segment) in self.segments.iter().enumerate() {
+            let segment_rect = Rect::from_min_size(
+                pos2(rect.min.x + index as f32 * segment_width, rect.min.y),
+                Vec2::new(segment_width, SEGMENT_HEIGHT),
+            );
+            let selected = self.selection.is_selected(index);
+            let segment_response = paint_segment(
+                ui,
+                segment,
+                segment_rect,
+                segment_corner_radius(index, count),
+                selected,
+                self.show_checkmark,
+                matches!(self.selection, Selection::Multi(_)),
+                ids[index],
+            );
+            if segment_response.clicked() && segment.enabled {
+                clicked = Some(index);
+            }
+            if segment_response.has_focus() {
+                focused = Some(index);
+            }
+        }
+
+        if let Some(index) = clicked {
+            let was_selected = self.selection.is_selected(index);
+            self.selection.toggle(index);
+            if self.selection.is_selected(index) != was_selected {
+                response.mark_changed();
+            }
+        }
+
+        // Outline around the whole set, with dividers between segments
+        let outline = get_global_color("outline");
+        ui.painter().rect_stroke(
+            rect,
+            CornerRadius::same((SEGMENT_HEIGHT / 2.0) as u8),
+            Stroke::new(1.0, outline),
+            egui::StrokeKind::Inside,
+        );
+        for index in 1..count {
+            let x = rect.min.x + index as f32 * segment_width;
+            ui.painter()
+                .vline(x, rect.y_range(), Stroke::new(1.0, outline));
+        }
+
+        // Arrow keys move focus to the adjacent enabled segment
+        if let Some(index) = focused {
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    ids[index],
+                    egui::EventFilter {
+                        horizontal_arrows: true,
+                        ..Default::default()
+                    },
+                )
+            });
+            let step = ui.input(|i| {
+                if i.key_pressed(Key::ArrowRight) {
+                    1
+                } else if i.key_pressed(Key::ArrowLeft) {
+                    -1
+                } else {
+                    0
+                }
+            });
+            if step != 0 {
+                let enabled: Vec<bool> = self.segments.iter().map(|s| s.enabled).collect();
+                if let Some(next) = next_enabled(index, step, &enabled) {
+                    ui.memory_mut(|m| m.request_focus(ids[next]));
+                }
+            }
+        }
+
+        let selected = match &self.selection {
+            Selection::Single(selected) => Some(**selected),
+            Selection::Multi(_) => None,
+        };
+
+        SegmentedButtonResponse {
+            response,
+            selected,
+            clicked,
+        }
+    }
+}
+
+/// Corner radius for a segment: only the outer corners of the set are rounded.
+fn segment_corner_radius(index: usize, count: usize) -> CornerRadius {
+    let radius = (SEGMENT_HEIGHT / 2.0) as u8;
+    let first = index == 0;
+    let last = index + 1 == count;
+    CornerRadius {
+        nw: if first { radius } else { 0 },
+        sw: if first { radius } else { 0 },
+        ne: if last { radius } else { 0 },
+        se: if last { radius } else { 0 },
+    }
+}
+
+/// The next enabled segment in direction `step`, wrapping around the ends.
+fn next_enabled(from: usize, step: i32, enabled: &[bool]) -> Option<usize> {
+    let count = enabled.len() as i32;
+    (1..count)
+        .map(|offset| (from as i32 + step * offset).rem_euclid(count) as usize)
+        .find(|&index| enabled[index])
+}
+
+/// Draw one segment (container, state layer, icon/checkmark, label) and return its response.
+#[allow(clippy::too_many_arguments)]
+fn paint_segment(
+    ui: &mut Ui,
+    segment: &ButtonSegment,
+    rect: Rect,
+    corner_radius: CornerRadius,
+    selected: bool,
+    show_checkmark: bool,
+    multi: bool,
+    id: Id,
+) -> Response {
+    let sense = if segment.enabled {
+        Sense::click()
+    } else {
+        Sense::hover()
+    };
+    let response = ui.interact(rect, id, sense);
+
+    if selected {
+        ui.painter()
+            .rect_filled(rect, corner_radius, get_global_color("secondaryContainer"));
+    }
+
+    let content_color = if !segment.enabled {
+        get_global_color("onSurface").linear_multiply(0.38)
+    } else if selected {
+        get_global_color("onSecondaryContainer")
+    } else {
+        get_global_color("onSurface")
+    };
+
+    if segment.enabled && response.hovered() {
+        let state_layer = if response.is_pointer_button_down_on() {
+            0.12
+        } else {
+            0.08
+        };
+        ui.painter().rect_filled(
+            rect,
+            corner_radius,
+            content_color.linear_multiply(state_layer),
+        );
+    }
+
+    // Focus ring around the segment for keyboard navigation
+    if segment.enabled && response.has_focus() {
+        ui.painter().rect_stroke(
+            rect,
+            corner_radius,
+            Stroke::new(3.0, get_global_color("secondary")),
+            egui::StrokeKind::Outside,
+        );
+    }
+
+    // The checkmark takes the icon's place while selected
+    let leading = if selected && show_checkmark {
+        Some(material_symbol_text_or_default("check", "✓"))
+    } else {
+        segment
+            .icon
+            .as_ref()
+            .map(|icon| material_symbol_text_or_default(icon, icon))
+    };
+
+    let label_galley = segment.label.as_ref().map(|label| {
+        ui.painter().layout_no_wrap(
+            label.clone(),
+            type_font_id(TypeRole::LabelLarge),
+            content_color,
+        )
+    });
+    let label_width = label_galley.as_ref().map_or(0.0, |galley| galley.size().x);
+    let leading_width = if leading.is_some() { ICON_SIZE } else { 0.0 };
+    let gap = if leading.is_some() && label_galley.is_some() {
+        ICON_LABEL_GAP
+    } else {
+        0.0
+    };
+
+    let mut x = rect.center().x - (leading_width + gap + label_width) / 2.0;
+    if let Some(leading) = leading {
+        ui.painter().text(
+            pos2(x + ICON_SIZE / 2.0, rect.center().y),
+            Align2::CENTER_CENTER,
+            leading,
+            FontId::proportional(ICON_SIZE),
+            content_color,
+        );
+        x += leading_width + gap;
+    }
+    if let Some(galley) = label_galley {
+        let pos = pos2(x, rect.center().y - galley.size().y / 2.0);
+        ui.painter().galley(pos, galley, content_color);
+    }
+
+    let widget_type = if multi {
+        WidgetType::Checkbox
+    } else {
+        WidgetType::RadioButton
+    };
+    let accessible_name = segment
+        .label
+        .clone()
+        .or_else(|| segment.icon.clone())
+        .unwrap_or_default();
+    response.widget_info(|| {
+        WidgetInfo::selected(widget_type, segment.enabled, selected, &accessible_name)
+    });
+
+    response
+}
+
+impl Widget for MaterialSegmentedButton<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+/// Convenience function to create a single-select segmented button from labels.
+pub fn segmented_button<'a>(
+    selected: &'a mut usize,
+    labels: &[&str],
+) -> MaterialSegmentedButton<'a> {
+    MaterialSegmentedButton::single(selected)
+        .segments(labels.iter().map(|label| ButtonSegment::new(*label)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_outer_corners_are_rounded() {
+        let first = segment_corner_radius(0, 3);
+        assert!(first.nw > 0 && first.sw > 0 && first.ne == 0 && first.se == 0);
+        assert_eq!(segment_corner_radius(1, 3), CornerRadius::ZERO);
+        let last = segment_corner_radius(2, 3);
+        assert!(last.ne > 0 && last.se > 0 && last.nw == 0 && last.sw == 0);
+    }
+
+    #[test]
+    fn test_arrow_navigation_skips_disabled_and_wraps() {
+        let enabled = [true, false, true, true];
+        assert_eq!(next_enabled(0, 1, &enabled), Some(2));
+        assert_eq!(next_enabled(3, 1, &enabled), Some(0));
+        assert_eq!(next_enabled(0, -1, &enabled), Some(3));
+        assert_eq!(next_enabled(0, 1, &[true, false]), None);
+    }
+}
@@ -10,10 +10,13 @@
 //! - **secondaryContainer**: Selected item background in dropdown
 //! - **onSecondaryContainer**: Selected item text
 //! - **State layers**: onSurface @ 8% (hover), 12% (press)
+//! - A search without matches shows a compact [`MaterialEmptyState`]
 
-use crate::theme::get_global_color;
+use crate::emptystate::MaterialEmptyState;
+use crate::theme::{get_global_color, MaterialScrollAreaExt};
 use egui::{
-    self, Color32, FontFamily, FontId, Key, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget,
+    self, Color32, FontFamily, FontId, Key, Modifiers, Pos2, Rect, Response, Sense, Stroke, Ui,
+    Vec2, Widget, WidgetInfo, WidgetType,
 };
 
 /// Material Design select/dropdown component.
@@ -65,8 +68,8 @@ pub enum MenuAlignment {
 
 #[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
 pub struct MaterialSelect<'a> {
-    /// Reference to the currently selected option
-    selected: &'a mut Option<usize>,
+    /// Reference to the currently selected option(s)
+    selection: Selection<'a>,
     /// List of available options
     options: Vec<SelectOption>,
     /// Placeholder text when no option is selected
@@ -93,6 +96,10 @@ pub struct MaterialSelect<'a> {
     enable_filter: bool,
     /// Enable search highlighting while typing
     enable_search: bool,
+    /// Show a search field at the top of the menu that filters the options
+    searchable: bool,
+    /// Use fuzzy (in-order subsequence) matching for the search field
+    fuzzy_search: bool,
     /// Mark field as required
     required: bool,
     /// Independent menu width
@@ -103,6 +110,97 @@ pub struct MaterialSelect<'a> {
     border_radius: Option<f32>,
     /// Menu alignment
     menu_alignment: MenuAlignment,
+    /// Shown in the menu when no option matches the search
+    empty_state: Option<MaterialEmptyState>,
+}
+
+/// Where a select component stores its selection.
+enum Selection<'a> {
+    /// At most one selected option value
+    Single(&'a mut Option<usize>),
+    /// Sorted, deduplicated set of selected option values
+    Multi(&'a mut Vec<usize>),
+}
+
+impl Selection<'_> {
+    fn contains(&self, value: usize) -> bool {
+        match self {
+            Selection::Single(selected) => **selected == Some(value),
+            Selection::Multi(selected) => selected.binary_search(&value).is_ok(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Selection::Single(selected) => selected.is_none(),
+            Selection::Multi(selected) => selected.is_empty(),
+        }
+    }
+
+    fn is_multi(&self) -> bool {
+        matches!(self, Selection::Multi(_))
+    }
+
+    /// Select `value`, or toggle it in multi-select mode.
+    fn pick(&mut self, value: usize) {
+        match self {
+            Selection::Single(selected) => **selected = Some(value),
+            Selection::Multi(selected) => match selected.binary_search(&value) {
+                Ok(index) => {
+                    selected.remove(index);
+                }
+                Err(index) => selected.insert(index, value),
+            },
+        }
+    }
+
+    /// Add or remove all of `values` in multi-select mode.
+    fn set_all(&mut self, values: impl Iterator<Item = usize>, on: bool) {
+        if let Selection::Multi(selected) = self {
+            if on {
+                selected.extend(values);
+                selected.sort_unstable();
+                selected.dedup();
+            } else {
+                let values: Vec<usize> = values.collect();
+                selected.retain(|value| !values.contains(value));
+            }
+        }
+    }
+}
+
+/// Paint a checkbox for a multi-select menu row.
+fn paint_checkbox(painter: &egui::Painter, center: Pos2, checked: bool, partial: bool) {
+    let rect = Rect::from_center_size(center, Vec2::splat(18.0));
+    if checked || partial {
+        let on_primary = get_global_color("onPrimary");
+        painter.rect_filled(rect, 2.0, get_global_color("primary"));
+        if checked {
+            painter.add(egui::Shape::line(
+                vec![
+                    rect.min + Vec2::new(4.0, 9.0),
+                    rect.min + Vec2::new(7.5, 12.5),
+                    rect.min + Vec2::new(14.0, 5.5),
+                ],
+                Stroke::new(2.0, on_primary),
+            ));
+        } else {
+            painter.line_segment(
+                [
+                    rect.left_center() + Vec2::new(4.0, 0.0),
+                    rect.right_center() - Vec2::new(4.0, 0.0),
+                ],
+                Stroke::new(2.0, on_primary),
+            );
+        }
+    } else {
+        painter.rect_stroke(
+            rect,
+            2.0,
+            Stroke::new(2.0, get_global_color("onSurfaceVariant")),
+            egui::epaint::StrokeKind::Inside,
+        );
+    }
 }
 
 /// Individual option in a select component.
@@ -128,8 +226,48 @@ impl<'a> MaterialSelect<'a> {
     /// # });
     /// ```
     pub fn new(selected: &'a mut Option<usize>) -> Self {
+        Self::with_selection(Selection::Single(selected))
+    }
+
+    /// Create a multi-select component.
+    ///
+    /// Each option gets a checkbox and the menu stays open while options are
+    /// toggled. The closed field shows a summary such as "3 selected", and a
+    /// "Select all" row at the top of the menu selects or clears every option.
+    /// `selected` holds the indices of the selected options and is kept sorted
+    /// and free of duplicates.
+    ///
+    /// # Arguments
+    /// * `selected` - Mutable reference to the selected option indices
+    /// * `options` - Display text of the options, in index order
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut columns = vec![0, 2];
+    /// let response = ui.add(MaterialSelect::multi(&mut columns, ["Name", "Price", "Stock"])
+    ///     .label("Visible columns"));
+    /// if response.changed() {
+    ///     println!("Now showing {:?}", columns);
+    /// }
+    /// # });
+    /// ```
+    pub fn multi<S: Into<String>>(
+        selected: &'a mut Vec<usize>,
+        options: impl IntoIterator<Item = S>,
+    ) -> Self {
+        selected.sort_unstable();
+        selected.dedup();
+        let mut select = Self::with_selection(Selection::Multi(selected));
+        for (index, text) in options.into_iter().enumerate() {
+            select = select.option(index, text);
+        }
+        select
+    }
+
+    fn with_selection(selection: Selection<'a>) -> Self {
         Self {
-            selected,
+            selection,
             options: Vec::new(),
             placeholder: "Select an option".to_string(),
             label: None,
@@ -143,11 +281,14 @@ impl<'a> MaterialSelect<'a> {
             keep_open_on_select: false,
             enable_filter: false,
             enable_search: true,
+            searchable: false,
+            fuzzy_search: false,
             required: false,
             menu_width: None,
             menu_max_height: None,
             border_radius: None,
             menu_alignment: MenuAlignment::default(),
+            empty_state: None,
         }
     }
 
@@ -373,6 +514,52 @@ impl<'a> MaterialSelect<'a> {
         self
     }
 
+    /// Show a search field at the top of the menu that filters options as you type.
+    ///
+    /// Options are matched with a case-insensitive substring match (see
+    /// [`Self::fuzzy_search`]). Arrow keys move through the filtered options and
+    /// Enter selects the highlighted one; the selected value always refers to the
+    /// original option, not its position in the filtered list.
+    ///
+    /// # Arguments
+    /// * `searchable` - If true, shows the search field
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut selection = None;
+    /// ui.add(MaterialSelect::new(&mut selection)
+    ///     .searchable(true)
+    ///     .option(0, "Apple")
+    ///     .option(1, "Banana"));
+    /// # });
+    /// ```
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// Set what the menu shows when no option matches the search.
+    ///
+    /// Defaults to a compact "No results" empty state. It is shown compact
+    /// either way, two rows tall.
+    pub fn empty_state(mut self, empty_state: MaterialEmptyState) -> Self {
+        self.empty_state = Some(empty_state);
+        self
+    }
+
+    /// Use fuzzy matching for the search field.
+    ///
+    /// The characters of the search text only have to appear in the option in
+    /// the same order, so "bna" matches "Banana".
+    ///
+    /// # Arguments
+    /// * `fuzzy` - If true, matches options fuzzily instead of by substring
+    pub fn fuzzy_search(mut self, fuzzy: bool) -> Self {
+        self.fuzzy_search = fuzzy;
+        self
+    }
+
     /// Mark the field as required.
     ///
     /// # Arguments
@@ -419,13 +606,65 @@ impl<'a> MaterialSelect<'a> {
     }
 }
 
+/// Search text and keyboard highlight of an open select menu.
+#[derive(Clone, Default)]
+struct SelectMenuState {
+    /// Current search text
+    query: String,
+    /// Highlighted position in the filtered option list
+    highlighted: Option<usize>,
+    /// Whether the search field still has to take keyboard focus
+    focus_search: bool,
+}
+
+/// Check whether an option matches the search text, ignoring case.
+fn option_matches(text: &str, query: &str, fuzzy: bool) -> bool {
+    if query.is_empty() {
+        return true;
+    }
+    let text = text.to_lowercase();
+    let query = query.to_lowercase();
+    if fuzzy {
+        let mut chars = text.chars();
+        query.chars().all(|q| chars.any(|c| c == q))
+    } else {
+        text.contains(&query)
+    }
+}
+
+impl<'a> MaterialSelect<'a> {
+    /// Text shown in the closed field.
+    fn display_text(&self) -> String {
+        let option_text = |value: usize| {
+            self.options
+                .iter()
+                .find(|option| option.value == value)
+                .map(|option| option.text.clone())
+        };
+        match &self.selection {
+            Selection::Single(selected) => (**selected).and_then(option_text),
+            Selection::Multi(selected) => match selected.as_slice() {
+                [] => None,
+                [value] => option_text(*value),
+                values => Some(format!("{} selected", values.len())),
+            },
+        }
+        .unwrap_or_else(|| self.placeholder.clone())
+    }
+}
+
 impl<'a> Widget for MaterialSelect<'a> {
-    fn ui(self, ui: &mut Ui) -> Response {
+    fn ui(mut self, ui: &mut Ui) -> Response {
         let width = self.width.unwrap_or(200.0);
         let height = 56.0;
         let desired_size = Vec2::new(width, height);
 
-        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+        let sense = if self.enabled {
+            Sense::click()
+        } else {
+            Sense::hover()
+        };
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, sense);
 
         // Use persistent state for dropdown open/close with global coordination
         let select_id = egui::Id::new((
@@ -448,7 +687,61 @@ impl<'a> Widget for MaterialSelect<'a> {
         let current_open_select =
             ui.memory(|mem| mem.data.get_temp::<egui::Id>(global_open_select_id));
 
-        if response.clicked() && self.enabled {
+        // Options shown in the menu, narrowed by the search text when searchable
+        let menu_state_id = select_id.with("menu_state");
+        let mut menu_state: SelectMenuState = ui
+            .memory(|mem| mem.data.get_temp(menu_state_id))
+            .unwrap_or_default();
+        let mut filtered: Vec<SelectOption> = self
+            .options
+            .iter()
+            .filter(|option| {
+                !self.searchable
+                    || option_matches(&option.text, &menu_state.query, self.fuzzy_search)
+            })
+            .cloned()
+            .collect();
+        menu_state.highlighted = menu_state.highlighted.filter(|&h| h < filtered.len());
+
+        // Keyboard navigation over the filtered options while the search field has
+        // focus. Without search the keys are left to egui's focus navigation.
+        let mut highlight_moved = false;
+        let mut enter_selected = false;
+        if open && self.enabled && self.searchable {
+            let (down, up, enter) = ui.input_mut(|i| {
+                (
+                    i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                    i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                    i.consume_key(Modifiers::NONE, Key::Enter),
+                )
+            });
+            if down && !filtered.is_empty() {
+                menu_state.highlighted = Some(
+                    menu_state
+                        .highlighted
+                        .map_or(0, |h| (h + 1).min(filtered.len() - 1)),
+                );
+                highlight_moved = true;
+            } else if up {
+                menu_state.highlighted = menu_state.highlighted.map(|h| h.saturating_sub(1));
+                highlight_moved = true;
+            } else if enter {
+                if let Some(option) = menu_state.highlighted.and_then(|h| filtered.get(h)) {
+                    self.selection.pick(option.value);
+                    response.mark_changed();
+                    enter_selected = true;
+                    if !self.keep_open_on_select && !self.selection.is_multi() {
+                        open = false;
+                        ui.memory_mut(|mem| {
+                            mem.data.insert_temp(select_id, false);
+                            mem.data.remove::<egui::Id>(global_open_select_id);
+                        });
+                    }
+                }
+            }
+        }
+
+        if response.clicked() && self.enabled && !enter_selected {
             if open {
                 // Close this select
                 open = false;
@@ -462,10 +755,33 @@ impl<'a> Widget for MaterialSelect<'a> {
                 }
                 open = true;
                 ui.memory_mut(|mem| mem.data.insert_temp(global_open_select_id, select_id));
+
+                // Start every opening with an empty search
+                menu_state = SelectMenuState {
+                    focus_search: self.searchable,
+                    ..Default::default()
+                };
+                filtered = self.options.clone();
             }
             ui.memory_mut(|mem| mem.data.insert_temp(select_id, open));
         }
 
+        // Announce as a combo box named by its label, with the current value
+        let label = self
+            .label
+            .clone()
+            .unwrap_or_else(|| self.placeholder.clone());
+        let value = self.display_text();
+        let enabled = self.enabled;
+        response.widget_info(|| {
+            let mut info = WidgetInfo::labeled(WidgetType::ComboBox, enabled, &label);
+            info.current_text_value = Some(value.clone());
+            info
+        });
+        ui.ctx().accesskit_node_builder(response.id, |node| {
+            node.set_expanded(open);
+        });
+
         // Material Design colors
         let primary_color = get_global_color("primary");
         let surface = get_global_color("surface");
@@ -476,7 +792,7 @@ impl<'a> Widget for MaterialSelect<'a> {
         let error_color = get_global_color("error");
 
         // Determine if we should show floating label
-        let has_content = self.selected.is_some();
+        let has_content = !self.selection.is_empty();
         let should_float_label = has_content || open || response.hovered();
         
         // Hide label if field is empty and not focused (placeholder will be shown instead)
@@ -484,11 +800,19 @@ impl<'a> Widget for MaterialSelect<'a> {
         
         // Determine colors based on state
         let (bg_color, border_color, text_color) = if !self.enabled {
-            (
-                surface_variant.linear_multiply(0.38),
-                outline.linear_multiply(0.38),
-                on_surface.linear_multiply(0.38),
-            )
+            // M3 disabled text field: onSurface @ 4% container, 38% indicator, 12% outline
+            match self.variant {
+                SelectVariant::Filled => (
+                    on_surface.linear_multiply(0.04),
+                    on_surface.linear_multiply(0.38),
+                    on_surface.linear_multiply(0.38),
+                ),
+                SelectVariant::Outlined => (
+                    Color32::TRANSPARENT,
+                    on_surface.linear_multiply(0.12),
+                    on_surface.linear_multiply(0.38),
+                ),
+            }
         } else if self.error_text.is_some() {
             match self.variant {
                 SelectVariant::Filled => (surface_variant, error_color, on_surface),
@@ -575,22 +899,14 @@ impl<'a> Widget for MaterialSelect<'a> {
         }
 
         // Draw selected text or placeholder
-        let display_text = if let Some(selected_value) = *self.selected {
-            self.options
-                .iter()
-                .find(|option| option.value == selected_value)
-                .map(|option| option.text.as_str())
-                .unwrap_or(&self.placeholder)
-        } else {
-            &self.placeholder
-        };
+        let display_text = self.display_text();
 
         // Use consistent font styling for select field
         let select_font = FontId::new(16.0, FontFamily::Proportional);
         let text_y_offset = if should_show_label && should_float_label { 12.0 } else { 0.0 };
         let text_pos = Pos2::new(rect.min.x + 16.0, rect.center().y + text_y_offset);
         
-        let display_color = if self.selected.is_none() {
+        let display_color = if self.selection.is_empty() {
             on_surface_variant.linear_multiply(0.6)
         } else {
             text_color
@@ -663,7 +979,19 @@ impl<'a> Widget for MaterialSelect<'a> {
             let available_space_above = rect.min.y - viewport_rect.min.y - 4.0;
 
             let item_height = 48.0;
-            let dropdown_padding = 16.0;
+            // Room for the search field above the options
+            let search_height = if self.searchable { 48.0 } else { 0.0 };
+            // and for the "Select all" row of a multi-select
+            let multi = self.selection.is_multi();
+            let select_all_height = if multi { item_height } else { 0.0 };
+            let header_height = search_height + select_all_height;
+            let dropdown_padding = 16.0 + header_height;
+            // An empty filtered list shows an empty state two rows tall
+            let row_count = if filtered.is_empty() {
+                2
+            } else {
+                filtered.len()
+            };
 
             // Use menu_max_height if specified, otherwise use available space
             let effective_max_height = if let Some(max_h) = self.menu_max_height {
@@ -678,19 +1006,13 @@ impl<'a> Widget for MaterialSelect<'a> {
                 ((available_space_above.min(effective_max_height) - dropdown_padding) / item_height).floor() as usize;
 
             // Determine dropdown position and size
-            let (dropdown_y, visible_items, scroll_needed) = if max_items_below
-                >= self.options.len()
-            {
+            let (dropdown_y, visible_items, scroll_needed) = if max_items_below >= row_count {
                 // Fit below
-                (rect.max.y + 4.0, self.options.len(), false)
-            } else if max_items_above >= self.options.len() {
+                (rect.max.y + 4.0, row_count, false)
+            } else if max_items_above >= row_count {
                 // Fit above
-                let dropdown_height = self.options.len() as f32 * item_height + dropdown_padding;
-                (
-                    rect.min.y - 4.0 - dropdown_height,
-                    self.options.len(),
-                    false,
-                )
+                let dropdown_height = row_count as f32 * item_height + dropdown_padding;
+                (rect.min.y - 4.0 - dropdown_height, row_count, false)
             } else if max_items_below >= max_items_above {
                 // Partial fit below with scroll
                 (rect.max.y + 4.0, max_items_below.max(3), true)
@@ -715,9 +1037,18 @@ impl<'a> Widget for MaterialSelect<'a> {
 
             // Clone/copy data needed in the Area closure
             let ctx = ui.ctx().clone();
-            let options = self.options.clone();
-            let selected = self.selected;
-            let keep_open_on_select = self.keep_open_on_select;
+            let options = filtered;
+            let selected = &mut self.selection;
+            let keep_open_on_select = self.keep_open_on_select || multi;
+            // Room for the checkbox in front of multi-select options
+            let text_indent = if multi { 48.0 } else { 16.0 };
+            let searchable = self.searchable;
+            let highlighted = menu_state.highlighted;
+            let empty_state = self
+                .empty_state
+                .take()
+                .unwrap_or_else(|| MaterialEmptyState::new("No results").icon("search_off"))
+                .compact(true);
 
             // Use Area widget for proper z-layering (like menu component)
             egui::Area::new(select_id.with("dropdown"))
@@ -746,25 +1077,113 @@ impl<'a> Widget for MaterialSelect<'a> {
                         egui::epaint::StrokeKind::Outside,
                     );
 
+                    if searchable {
+                        let search_rect = Rect::from_min_size(
+                            Pos2::new(dropdown_rect.min.x + 8.0, dropdown_rect.min.y + 8.0),
+                            Vec2::new(menu_width - 16.0, search_height - 8.0),
+                        );
+                        let search_response = ui.put(
+                            search_rect,
+                            egui::TextEdit::singleline(&mut menu_state.query)
+                                .hint_text("Search")
+                                .desired_width(menu_width - 16.0),
+                        );
+                        if menu_state.focus_search {
+                            search_response.request_focus();
+                            menu_state.focus_search = false;
+                        }
+                        if search_response.changed() {
+                            // Highlight the best match so Enter picks it straight away
+                            menu_state.highlighted = (!menu_state.query.is_empty()).then_some(0);
+                            ui.ctx().request_repaint();
+                        }
+                    }
+
+                    if multi {
+                        // "Select all" row, anchored above the (possibly scrolling) options
+                        let select_all_rect = Rect::from_min_size(
+                            Pos2::new(
+                                dropdown_rect.min.x + 8.0,
+                                dropdown_rect.min.y + 8.0 + search_height,
+                            ),
+                            Vec2::new(menu_width - 16.0, select_all_height),
+                        );
+                        let select_all_response = ui.interact(
+                            select_all_rect,
+                            select_id.with("select_all"),
+                            Sense::click(),
+                        );
+                        let selected_count = options
+                            .iter()
+                            .filter(|option| selected.contains(option.value))
+                            .count();
+                        let all_selected = !options.is_empty() && selected_count == options.len();
+
+                        if select_all_response.hovered() {
+                            ui.painter().rect_filled(
+                                select_all_rect,
+                                4.0,
+                                Color32::from_rgba_premultiplied(
+                                    on_surface.r(),
+                                    on_surface.g(),
+                                    on_surface.b(),
+                                    20,
+                                ),
+                            );
+                        }
+                        paint_checkbox(
+                            ui.painter(),
+                            Pos2::new(select_all_rect.min.x + 25.0, select_all_rect.center().y),
+                            all_selected,
+                            selected_count > 0,
+                        );
+                        ui.painter().text(
+                            Pos2::new(
+                                select_all_rect.min.x + text_indent,
+                                select_all_rect.center().y,
+                            ),
+                            egui::Align2::LEFT_CENTER,
+                            if all_selected { "Clear" } else { "Select all" },
+                            select_font.clone(),
+                            on_surface,
+                        );
+                        ui.painter().hline(
+                            select_all_rect.x_range(),
+                            select_all_rect.max.y,
+                            Stroke::new(1.0, get_global_color("outlineVariant")),
+                        );
+
+                        if select_all_response.clicked() && !options.is_empty() {
+                            selected
+                                .set_all(options.iter().map(|option| option.value), !all_selected);
+                            response.mark_changed();
+                        }
+                    }
+
                     // Render options with scrolling support
                     if scroll_needed && visible_items < options.len() {
                         let scroll_area_rect = Rect::from_min_size(
-                            Pos2::new(dropdown_rect.min.x + 8.0, dropdown_rect.min.y + 8.0),
-                            Vec2::new(menu_width - 16.0, dropdown_height - 16.0),
+                            Pos2::new(
+                                dropdown_rect.min.x + 8.0,
+                                dropdown_rect.min.y + 8.0 + header_height,
+                            ),
+                            Vec2::new(menu_width - 16.0, dropdown_height - dropdown_padding),
                         );
 
                         ui.scope_builder(egui::UiBuilder::new().max_rect(scroll_area_rect), |ui| {
                             egui::ScrollArea::vertical()
-                                .max_height(dropdown_height - 16.0)
+                                .max_height(dropdown_height - dropdown_padding)
                                 .scroll_bar_visibility(
                                     egui::scroll_area::ScrollBarVisibility::VisibleWhenNeeded,
                                 )
                                 .auto_shrink([false; 2])
-                                .show(ui, |ui| {
-                                    for option in &options {
+                                .show_material(ui, |ui| {
+                                    for (position, option) in options.iter().enumerate() {
                                         // Calculate text layout first to determine actual height needed
-                                        let available_width = ui.available_width() - 32.0;
-                                        let is_selected = *selected == Some(option.value);
+                                        let available_width =
+                                            ui.available_width() - 16.0 - text_indent;
+                                        let is_selected = selected.contains(option.value);
+                                        let is_highlighted = highlighted == Some(position);
                                         let text_color = if is_selected {
                                             get_global_color("primary")
                                         } else {
@@ -811,7 +1230,7 @@ impl<'a> Widget for MaterialSelect<'a> {
                                                 on_surface.b(),
                                                 30,
                                             )
-                                        } else if option_response.hovered() {
+                                        } else if option_response.hovered() || is_highlighted {
                                             Color32::from_rgba_premultiplied(
                                                 on_surface.r(),
                                                 on_surface.g(),
@@ -822,15 +1241,37 @@ impl<'a> Widget for MaterialSelect<'a> {
                                             Color32::TRANSPARENT
                                         };
 
+                                        if is_highlighted && highlight_moved {
+                                            ui.scroll_to_rect(option_rect, None);
+                                        }
+
                                         if option_bg_color != Color32::TRANSPARENT {
                                             ui.painter().rect_filled(option_rect, 4.0, option_bg_color);
                                         }
 
-                                        let text_pos = Pos2::new(option_rect.min.x + 16.0, option_rect.center().y - text_height / 2.0);
+                                        if multi {
+                                            paint_checkbox(
+                                                ui.painter(),
+                                                Pos2::new(option_rect.min.x + 25.0, option_rect.center().y),
+                                                is_selected,
+                                                false,
+                                            );
+                                        }
+
+                                        let text_pos = Pos2::new(option_rect.min.x + text_indent, option_rect.center().y - text_height / 2.0);
                                         ui.painter().galley(text_pos, galley, text_color);
 
+                                        option_response.widget_info(|| {
+                                            WidgetInfo::selected(
+                                                WidgetType::SelectableLabel,
+                                                true,
+                                                is_selected,
+                                                &option.text,
+                                            )
+                                        });
+
                                         if option_response.clicked() {
-                                            *selected = Some(option.value);
+                                            selected.pick(option.value);
                                             if !keep_open_on_select {
                                                 open = false;
                                                 ui.memory_mut(|mem| {
@@ -845,19 +1286,28 @@ impl<'a> Widget for MaterialSelect<'a> {
                         });
                     } else {
                         // Draw options without scrolling
-                        let mut current_y = dropdown_rect.min.y + 8.0;
+                        let mut current_y = dropdown_rect.min.y + 8.0 + header_height;
                         let items_to_show = visible_items.min(options.len());
 
-                        for option in options.iter().take(items_to_show) {
+                        if options.is_empty() {
+                            let empty_rect = Rect::from_min_size(
+                                Pos2::new(dropdown_rect.min.x + 8.0, current_y),
+                                Vec2::new(menu_width - 16.0, visible_items as f32 * item_height),
+                            );
+                            ui.put(empty_rect, empty_state);
+                        }
+
+                        for (position, option) in options.iter().take(items_to_show).enumerate() {
                             // Calculate text layout first to determine actual height needed
-                            let is_selected = *selected == Some(option.value);
+                            let is_selected = selected.contains(option.value);
+                            let is_highlighted = highlighted == Some(position);
                             let text_color = if is_selected {
                                 get_global_color("primary")
                             } else {
                                 on_surface
                             };
 
-                            let available_width = menu_width - 16.0 - 32.0;
+                            let available_width = menu_width - 16.0 - 16.0 - text_indent;
                             let galley = ui.painter().layout_job(egui::text::LayoutJob {
                                 text: option.text.clone(),
                                 sections: vec![egui::text::LayoutSection {
@@ -904,7 +1354,7 @@ impl<'a> Widget for MaterialSelect<'a> {
                                     on_surface.b(),
                                     30,
                                 )
-                            } else if option_response.hovered() {
+                            } else if option_response.hovered() || is_highlighted {
                                 Color32::from_rgba_premultiplied(
                                     on_surface.r(),
                                     on_surface.g(),
@@ -919,8 +1369,17 @@ impl<'a> Widget for MaterialSelect<'a> {
                                 ui.painter().rect_filled(option_rect, 4.0, option_bg_color);
                             }
 
+                            option_response.widget_info(|| {
+                                WidgetInfo::selected(
+                                    WidgetType::SelectableLabel,
+                                    true,
+                                    is_selected,
+                                    &option.text,
+                                )
+                            });
+
                             if option_response.clicked() {
-                                *selected = Some(option.value);
+                                selected.pick(option.value);
                                 if !keep_open_on_select {
                                     open = false;
                                     ui.memory_mut(|mem| {
@@ -931,7 +1390,16 @@ impl<'a> Widget for MaterialSelect<'a> {
                                 response.mark_changed();
                             }
 
-                            let text_pos = Pos2::new(option_rect.min.x + 16.0, option_rect.center().y - text_height / 2.0);
+                            if multi {
+                                paint_checkbox(
+                                    ui.painter(),
+                                    Pos2::new(option_rect.min.x + 25.0, option_rect.center().y),
+                                    is_selected,
+                                    false,
+                                );
+                            }
+
+                            let text_pos = Pos2::new(option_rect.min.x + text_indent, option_rect.center().y - text_height / 2.0);
                             ui.painter().galley(text_pos, galley, text_color);
 
                             current_y += option_height;
@@ -939,6 +1407,7 @@ impl<'a> Widget for MaterialSelect<'a> {
                     }
                 });
         }
+        ui.memory_mut(|mem| mem.data.insert_temp(menu_state_id, menu_state));
         
         // Draw helper text or error text below the field
         if let Some(ref error) = self.error_text {
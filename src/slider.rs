@@ -10,6 +10,7 @@
 //! ## Inactive State
 //! - **surfaceContainerHighest**: Inactive track
 //! - **onSurfaceVariant**: Tick marks, value labels
+//! - **inverseSurface / inverseOnSurface**: Value indicator bubble
 //!
 //! ## Disabled State
 //! - **onSurface @ 12%**: Inactive track
@@ -19,9 +20,28 @@
 //! - **Track height**: 4dp
 //! - **Thumb size**: 20dp (default), 44dp touch target
 //! - **Tick mark**: 2dp diameter
+//!
+//! ## Value Indicator
+//! [`MaterialSlider::show_value_on_drag`] shows the current value in a pill
+//! above the thumb while it is pressed, and
+//! [`ValueIndicator::Always`] keeps it visible. The pill is drawn on a tooltip
+//! layer and kept inside the viewport, moving below the thumb when there is
+//! no room above. Use [`MaterialSlider::value_formatter`] to format the value.
+//!
+//! ## Discrete Sliders
+//! With [`MaterialSlider::step`] the value snaps to `start + k * step` while
+//! dragging, and tick marks are drawn at each stop. When the range isn't a
+//! whole number of steps, the end of the range is a stop too.
+//!
+//! ## Accessibility
+//! Sliders and range slider thumbs report their value, range and step to
+//! screen readers. The focused slider or thumb moves one step with the arrow
+//! keys or an assistive technology's increment and decrement actions, and ten
+//! steps with Page Up and Page Down.
 
 use crate::get_global_color;
-use egui::{self, Color32, FontId, Pos2, Rect, Response, Sense, Ui, Vec2, Widget};
+use crate::theme::{type_font_id, TypeRole};
+use egui::{self, accesskit, Color32, Pos2, Rect, Response, Sense, Ui, Vec2, Widget, WidgetInfo};
 use std::ops::RangeInclusive;
 
 /// Interaction modes for sliders
@@ -52,6 +72,18 @@ pub enum ThumbShape {
 }
 
 
+/// When a slider shows its value in a bubble above the thumb
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValueIndicator {
+    /// Never show the bubble (default)
+    #[default]
+    Never,
+    /// Show the bubble while the thumb is pressed or dragged
+    OnDrag,
+    /// Always show the bubble
+    Always,
+}
+
 /// Range values for RangeSlider
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RangeValues {
@@ -86,8 +118,10 @@ pub struct MaterialSlider<'a> {
     show_value: bool,
     /// Secondary track value (e.g., for buffering indicators)
     secondary_track_value: Option<f32>,
-    /// Whether to show value indicator while dragging
-    show_value_indicator: bool,
+    /// When to show the value bubble above the thumb
+    value_indicator: ValueIndicator,
+    /// Custom formatting for the displayed value
+    value_formatter: Option<Box<dyn Fn(f32) -> String + 'a>>,
     /// Interaction mode
     interaction_mode: SliderInteraction,
     /// Thumb shape
@@ -111,7 +145,8 @@ impl<'a> MaterialSlider<'a> {
             step: None,
             show_value: true,
             secondary_track_value: None,
-            show_value_indicator: false,
+            value_indicator: ValueIndicator::Never,
+            value_formatter: None,
             interaction_mode: SliderInteraction::default(),
             thumb_shape: ThumbShape::default(),
             overlay_color: None,
@@ -120,6 +155,27 @@ impl<'a> MaterialSlider<'a> {
         }
     }
 
+    /// Create a range slider with two thumbs editing `values` as `(start, end)`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut price = (100.0, 500.0);
+    /// let response = MaterialSlider::range(&mut price, 0.0..=1000.0)
+    ///     .step(10.0)
+    ///     .show(ui);
+    /// if response.changed() {
+    ///     println!("{:?} moved to {:?}", response.moved, price);
+    /// }
+    /// # });
+    /// ```
+    pub fn range(
+        values: &'a mut (f32, f32),
+        range: RangeInclusive<f32>,
+    ) -> MaterialRangeSlider<'a> {
+        MaterialRangeSlider::with_target(RangeTarget::Tuple(values), range)
+    }
+
     pub fn text(mut self, text: impl Into<String>) -> Self {
         self.text = Some(text.into());
         self
@@ -150,11 +206,53 @@ impl<'a> MaterialSlider<'a> {
         self
     }
 
-    pub fn show_value_indicator(mut self, show: bool) -> Self {
-        self.show_value_indicator = show;
+    /// Show the value in a bubble above the thumb while it is pressed or dragged.
+    pub fn show_value_on_drag(mut self, show: bool) -> Self {
+        self.value_indicator = if show {
+            ValueIndicator::OnDrag
+        } else {
+            ValueIndicator::Never
+        };
         self
     }
 
+    /// Same as [`Self::show_value_on_drag`].
+    pub fn show_value_indicator(self, show: bool) -> Self {
+        self.show_value_on_drag(show)
+    }
+
+    /// Choose when the value bubble is shown above the thumb.
+    pub fn value_indicator(mut self, indicator: ValueIndicator) -> Self {
+        self.value_indicator = indicator;
+        self
+    }
+
+    /// Format the value shown in the bubble and next to the slider.
+    ///
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut volume = 4200.0;
+    /// ui.add(egui_material3::slider(&mut volume, 0.0..=10_000.0)
+    ///     .show_value_on_drag(true)
+    ///     .value_formatter(|v| format!("{:.0} Hz", v)));
+    /// # });
+    /// ```
+    pub fn value_formatter(mut self, format: impl Fn(f32) -> String + 'a) -> Self {
+        self.value_formatter = Some(Box::new(format));
+        self
+    }
+
+    /// Format `value` with the custom formatter, or by the step size.
+    fn format_value(&self, value: f32) -> String {
+        if let Some(format) = &self.value_formatter {
+            return format(value);
+        }
+        match self.step {
+            Some(step) if step >= 1.0 => format!("{:.0}", value),
+            _ => format!("{:.2}", value),
+        }
+    }
+
     pub fn interaction_mode(mut self, mode: SliderInteraction) -> Self {
         self.interaction_mode = mode;
         self
@@ -192,7 +290,12 @@ impl<'a> Widget for MaterialSlider<'a> {
             Vec2::new(slider_width, height)
         };
 
-        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click_and_drag());
+        let sense = if self.enabled {
+            Sense::click_and_drag()
+        } else {
+            Sense::hover()
+        };
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, sense);
 
         // Material Design colors
         let primary_color = get_global_color("primary");
@@ -234,20 +337,35 @@ impl<'a> Widget for MaterialSlider<'a> {
             if let Some(mouse_pos) = response.interact_pointer_pos() {
                 let normalized =
                     ((mouse_pos.x - track_rect.min.x) / track_rect.width()).clamp(0.0, 1.0);
-                let mut new_value =
+                let new_value =
                     *self.range.start() + normalized * (self.range.end() - self.range.start());
 
-                // Apply step if specified
-                if let Some(step) = self.step {
-                    new_value = (new_value / step).round() * step;
+                // Snap while dragging, so the value on release is the one shown
+                *self.value = snap_to_step(new_value, &self.range, self.step);
+                if (*self.value - old_value).abs() > f32::EPSILON {
+                    response.mark_changed();
                 }
+            }
+        }
 
-                *self.value = new_value.clamp(*self.range.start(), *self.range.end());
+        // Keyboard and assistive technology: one step per key press or request
+        if self.enabled {
+            let steps = nudge_steps(ui, &response);
+            if steps != 0 {
+                *self.value = offset_by_steps(*self.value, steps, &self.range, self.step);
                 if (*self.value - old_value).abs() > f32::EPSILON {
                     response.mark_changed();
                 }
             }
         }
+        response.widget_info(|| {
+            WidgetInfo::slider(
+                self.enabled,
+                *self.value as f64,
+                self.text.as_deref().unwrap_or(""),
+            )
+        });
+        describe_range(ui, &response, &self.range, self.step);
 
         if !self.enabled {
             response = response.on_disabled_hover_text("Slider is disabled");
@@ -324,6 +442,36 @@ impl<'a> Widget for MaterialSlider<'a> {
                 .rect_filled(active_track_rect, 2.0, track_active_color);
         }
 
+        // Draw tick marks at each stop, unless they're too dense to tell apart
+        if let Some(step) = self.step.filter(|step| *step > 0.0) {
+            let span = self.range.end() - self.range.start();
+            if span > 0.0 && span / step * 4.0 <= track_rect.width() {
+                let (active_tick_color, inactive_tick_color) = if self.enabled {
+                    (
+                        get_global_color("onPrimary"),
+                        get_global_color("onSurfaceVariant"),
+                    )
+                } else {
+                    let disabled_color = get_global_color("surface");
+                    (disabled_color, disabled_color)
+                };
+                for tick in tick_values(&self.range, step) {
+                    let tick_x =
+                        track_rect.min.x + (tick - self.range.start()) / span * track_rect.width();
+                    let color = if tick_x <= thumb_x {
+                        active_tick_color
+                    } else {
+                        inactive_tick_color
+                    };
+                    ui.painter().circle_filled(
+                        Pos2::new(tick_x, track_rect.center().y),
+                        1.0,
+                        color,
+                    );
+                }
+            }
+        }
+
         // Draw thumb based on shape
         match self.thumb_shape {
             ThumbShape::Round => {
@@ -369,43 +517,20 @@ impl<'a> Widget for MaterialSlider<'a> {
                 .circle_filled(thumb_center, ripple_radius, ripple_color);
         }
 
-        // Draw value indicator if enabled and dragging
-        if self.show_value_indicator && response.dragged() && self.enabled {
-            let value_text = if let Some(step) = self.step {
-                if step >= 1.0 {
-                    format!("{:.0}", *self.value)
-                } else {
-                    format!("{:.2}", *self.value)
-                }
-            } else {
-                format!("{:.2}", *self.value)
+        // Draw value indicator above the thumb
+        let pressed = response.is_pointer_button_down_on() || response.dragged();
+        let show_indicator = self.enabled
+            && match self.value_indicator {
+                ValueIndicator::Never => false,
+                ValueIndicator::OnDrag => pressed,
+                ValueIndicator::Always => true,
             };
-
-            // Simple rectangle indicator
-            let indicator_font = FontId::proportional(12.0);
-            let galley = ui.painter().layout_no_wrap(value_text, indicator_font, on_surface);
-            let indicator_size = Vec2::new(galley.size().x + 16.0, galley.size().y + 8.0);
-            let indicator_pos = Pos2::new(
-                thumb_center.x - indicator_size.x / 2.0,
-                thumb_center.y - indicator_size.y - 16.0,
-            );
-            let indicator_rect = Rect::from_min_size(indicator_pos, indicator_size);
-
-            // Draw indicator background
-            ui.painter().rect_filled(
-                indicator_rect,
-                4.0,
-                primary_color,
-            );
-
-            // Draw indicator text
-            ui.painter().galley(
-                Pos2::new(
-                    indicator_rect.center().x - galley.size().x / 2.0,
-                    indicator_rect.center().y - galley.size().y / 2.0,
-                ),
-                galley,
-                Color32::WHITE,
+        if show_indicator {
+            paint_value_indicator(
+                ui,
+                response.id,
+                thumb_center,
+                self.format_value(*self.value),
             );
         }
 
@@ -429,15 +554,7 @@ impl<'a> Widget for MaterialSlider<'a> {
 
         // Draw value
         if self.show_value {
-            let value_text = if let Some(step) = self.step {
-                if step >= 1.0 {
-                    format!("{:.0}", *self.value)
-                } else {
-                    format!("{:.2}", *self.value)
-                }
-            } else {
-                format!("{:.2}", *self.value)
-            };
+            let value_text = self.format_value(*self.value);
 
             let value_pos = Pos2::new(
                 track_rect.max.x + 16.0,
@@ -463,9 +580,61 @@ impl<'a> Widget for MaterialSlider<'a> {
     }
 }
 
+/// Identifies one of the two thumbs of a range slider
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeThumb {
+    /// The thumb controlling the lower bound
+    Start,
+    /// The thumb controlling the upper bound
+    End,
+}
+
+/// Response from showing a [`MaterialRangeSlider`]
+pub struct RangeSliderResponse {
+    /// The combined response of the track and both thumbs
+    pub response: Response,
+    /// The thumb whose value changed this frame, if any
+    pub moved: Option<RangeThumb>,
+}
+
+impl RangeSliderResponse {
+    /// Whether either thumb changed value this frame
+    pub fn changed(&self) -> bool {
+        self.response.changed()
+    }
+}
+
+/// Storage for the two values edited by a range slider
+enum RangeTarget<'a> {
+    Values(&'a mut RangeValues),
+    Tuple(&'a mut (f32, f32)),
+}
+
+impl RangeTarget<'_> {
+    fn get(&self) -> (f32, f32) {
+        match self {
+            RangeTarget::Values(values) => (values.start, values.end),
+            RangeTarget::Tuple(values) => **values,
+        }
+    }
+
+    fn set(&mut self, start: f32, end: f32) {
+        match self {
+            RangeTarget::Values(values) => {
+                values.start = start;
+                values.end = end;
+            }
+            RangeTarget::Tuple(values) => **values = (start, end),
+        }
+    }
+}
+
 /// Range Slider component for selecting a range of values
+///
+/// Both thumbs are focusable: Tab moves between them and the arrow keys nudge
+/// the focused thumb by one step. The thumbs never cross each other.
 pub struct MaterialRangeSlider<'a> {
-    values: &'a mut RangeValues,
+    values: RangeTarget<'a>,
     range: RangeInclusive<f32>,
     text: Option<String>,
     enabled: bool,
@@ -479,6 +648,10 @@ pub struct MaterialRangeSlider<'a> {
 
 impl<'a> MaterialRangeSlider<'a> {
     pub fn new(values: &'a mut RangeValues, range: RangeInclusive<f32>) -> Self {
+        Self::with_target(RangeTarget::Values(values), range)
+    }
+
+    fn with_target(values: RangeTarget<'a>, range: RangeInclusive<f32>) -> Self {
         Self {
             values,
             range,
@@ -532,10 +705,39 @@ impl<'a> MaterialRangeSlider<'a> {
         self.min_separation = separation;
         self
     }
-}
 
-impl<'a> Widget for MaterialRangeSlider<'a> {
-    fn ui(self, ui: &mut Ui) -> Response {
+    /// Move `thumb` to `value`, keeping it on its side of the other thumb.
+    /// Returns `true` if the stored value changed.
+    fn set_thumb(&mut self, thumb: RangeThumb, value: f32) -> bool {
+        let (start, end) = self.values.get();
+        let value = snap_to_step(value, &self.range, self.step);
+        let (new_start, new_end) = match thumb {
+            RangeThumb::Start => (
+                value.clamp(
+                    *self.range.start(),
+                    (end - self.min_separation).max(*self.range.start()),
+                ),
+                end,
+            ),
+            RangeThumb::End => (
+                start,
+                value.clamp(
+                    (start + self.min_separation).min(*self.range.end()),
+                    *self.range.end(),
+                ),
+            ),
+        };
+
+        let changed =
+            (new_start - start).abs() > f32::EPSILON || (new_end - end).abs() > f32::EPSILON;
+        if changed {
+            self.values.set(new_start, new_end);
+        }
+        changed
+    }
+
+    /// Show the slider, reporting which thumb moved
+    pub fn show(mut self, ui: &mut Ui) -> RangeSliderResponse {
         let slider_width = self.width.unwrap_or(200.0);
         let height = 48.0;
 
@@ -545,7 +747,13 @@ impl<'a> Widget for MaterialRangeSlider<'a> {
             Vec2::new(slider_width, height)
         };
 
-        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click_and_drag());
+        // The thumbs are the Tab stops, so the track only takes pointer input
+        let track_sense = if self.enabled {
+            Sense::CLICK | Sense::DRAG
+        } else {
+            Sense::hover()
+        };
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, track_sense);
 
         // Material Design colors
         let primary_color = get_global_color("primary");
@@ -559,50 +767,142 @@ impl<'a> Widget for MaterialRangeSlider<'a> {
             Vec2::new(slider_width, 4.0),
         );
 
-        // Handle interaction
-        if response.dragged() && self.enabled {
-            if let Some(mouse_pos) = response.interact_pointer_pos() {
-                let normalized =
-                    ((mouse_pos.x - track_rect.min.x) / track_rect.width()).clamp(0.0, 1.0);
-                let mut new_value =
-                    *self.range.start() + normalized * (self.range.end() - self.range.start());
+        let range_start = *self.range.start();
+        let span = self.range.end() - range_start;
+        let value_to_x = |value: f32| {
+            let normalized = ((value - range_start) / span).clamp(0.0, 1.0);
+            track_rect.min.x + normalized * track_rect.width()
+        };
+        let x_to_value = |x: f32| {
+            let normalized = ((x - track_rect.min.x) / track_rect.width()).clamp(0.0, 1.0);
+            range_start + normalized * span
+        };
 
-                // Apply step if specified
-                if let Some(step) = self.step {
-                    new_value = (new_value / step).round() * step;
-                }
+        // Each thumb gets its own focusable 44dp touch target
+        let thumb_sense = if self.enabled {
+            Sense::click_and_drag()
+        } else {
+            Sense::hover()
+        };
+        let (start, end) = self.values.get();
+        let start_response = ui.interact(
+            Rect::from_center_size(
+                Pos2::new(value_to_x(start), track_rect.center().y),
+                Vec2::splat(44.0),
+            ),
+            response.id.with("start_thumb"),
+            thumb_sense,
+        );
+        let end_response = ui.interact(
+            Rect::from_center_size(
+                Pos2::new(value_to_x(end), track_rect.center().y),
+                Vec2::splat(44.0),
+            ),
+            response.id.with("end_thumb"),
+            thumb_sense,
+        );
 
-                // Determine which thumb is closer
-                let dist_to_start = (new_value - self.values.start).abs();
-                let dist_to_end = (new_value - self.values.end).abs();
+        let mut moved = None;
+
+        if self.enabled {
+            // Pick the thumb being dragged when the gesture starts, and keep
+            // it for the whole drag so the thumbs cannot swap mid-gesture.
+            let active_id = response.id.with("active_thumb");
+            let mut active = ui.data(|d| d.get_temp::<RangeThumb>(active_id));
+            let pointer_delta = ui.input(|i| i.pointer.delta().x);
+
+            let closest_thumb = |pointer_value: f32| {
+                let dist_to_start = (pointer_value - start).abs();
+                let dist_to_end = (pointer_value - end).abs();
+                if dist_to_start < dist_to_end
+                    || (dist_to_start == dist_to_end && pointer_value < start)
+                {
+                    RangeThumb::Start
+                } else {
+                    RangeThumb::End
+                }
+            };
 
-                if dist_to_start < dist_to_end {
-                    // Move start thumb
-                    self.values.start = new_value.clamp(
-                        *self.range.start(),
-                        (self.values.end - self.min_separation).min(*self.range.end()),
-                    );
+            if start_response.drag_started() || start_response.clicked() {
+                active = Some(RangeThumb::Start);
+                start_response.request_focus();
+            } else if end_response.drag_started() || end_response.clicked() {
+                // Overlapping thumbs: let the drag direction decide
+                active = if (end - start).abs() <= f32::EPSILON && pointer_delta < 0.0 {
+                    Some(RangeThumb::Start)
                 } else {
-                    // Move end thumb
-                    self.values.end = new_value.clamp(
-                        (self.values.start + self.min_separation).max(*self.range.start()),
-                        *self.range.end(),
-                    );
+                    Some(RangeThumb::End)
+                };
+                end_response.request_focus();
+            } else if response.drag_started() || response.clicked() {
+                active = response
+                    .interact_pointer_pos()
+                    .map(|pos| closest_thumb(x_to_value(pos.x)));
+            }
+
+            let pointer_active = [&response, &start_response, &end_response]
+                .iter()
+                .any(|r| r.dragged() || r.clicked());
+            if pointer_active {
+                let pointer_pos = ui.input(|i| i.pointer.interact_pos());
+                if let (Some(thumb), Some(pos)) = (active, pointer_pos) {
+                    if self.set_thumb(thumb, x_to_value(pos.x)) {
+                        moved = Some(thumb);
+                    }
+                }
+            }
+
+            if ui.input(|i| i.pointer.any_released()) {
+                active = None;
+            }
+            ui.data_mut(|d| match active {
+                Some(thumb) => d.insert_temp(active_id, thumb),
+                None => d.remove::<RangeThumb>(active_id),
+            });
+
+            // Keyboard and assistive technology: nudge a thumb by one step
+            for (thumb, thumb_response) in [
+                (RangeThumb::Start, &start_response),
+                (RangeThumb::End, &end_response),
+            ] {
+                let steps = nudge_steps(ui, thumb_response);
+                if steps != 0 {
+                    let (start, end) = self.values.get();
+                    let current = match thumb {
+                        RangeThumb::Start => start,
+                        RangeThumb::End => end,
+                    };
+                    let target = offset_by_steps(current, steps, &self.range, self.step);
+                    if self.set_thumb(thumb, target) {
+                        moved = Some(thumb);
+                    }
                 }
             }
         }
 
+        let (start, end) = self.values.get();
+        let label = self.text.as_deref().unwrap_or("");
+        for (thumb_response, value, name) in [
+            (&start_response, start, "start"),
+            (&end_response, end, "end"),
+        ] {
+            thumb_response.widget_info(|| {
+                WidgetInfo::slider(self.enabled, value as f64, format!("{label} {name}").trim())
+            });
+            describe_range(ui, thumb_response, &self.range, self.step);
+        }
+
+        let start_focused = start_response.has_focus();
+        let end_focused = end_response.has_focus();
+        response = response.union(start_response).union(end_response);
+        if moved.is_some() {
+            response.mark_changed();
+        }
+
         // Calculate thumb positions
-        let start_normalized =
-            (self.values.start - self.range.start()) / (self.range.end() - self.range.start());
-        let start_normalized = start_normalized.clamp(0.0, 1.0);
-        let start_x = track_rect.min.x + start_normalized * track_rect.width();
+        let start_x = value_to_x(start);
         let start_center = Pos2::new(start_x, track_rect.center().y);
-
-        let end_normalized =
-            (self.values.end - self.range.start()) / (self.range.end() - self.range.start());
-        let end_normalized = end_normalized.clamp(0.0, 1.0);
-        let end_x = track_rect.min.x + end_normalized * track_rect.width();
+        let end_x = value_to_x(end);
         let end_center = Pos2::new(end_x, track_rect.center().y);
 
         // Determine colors
@@ -691,6 +991,17 @@ impl<'a> Widget for MaterialRangeSlider<'a> {
                 .circle_filled(end_center, 28.0, ripple_color);
         }
 
+        // Focus indicator on the keyboard-focused thumb
+        for (focused, center) in [(start_focused, start_center), (end_focused, end_center)] {
+            if focused && self.enabled {
+                ui.painter().circle_stroke(
+                    center,
+                    thumb_radius + 4.0,
+                    egui::Stroke::new(2.0, primary_color),
+                );
+            }
+        }
+
         // Draw label
         if let Some(ref text) = self.text {
             let text_pos = Pos2::new(track_rect.max.x + 16.0, rect.center().y - 16.0);
@@ -723,11 +1034,7 @@ impl<'a> Widget for MaterialRangeSlider<'a> {
                 }
             };
 
-            let value_text = format!(
-                "{} - {}",
-                format_value(self.values.start),
-                format_value(self.values.end)
-            );
+            let value_text = format!("{} - {}", format_value(start), format_value(end));
 
             let value_pos = Pos2::new(
                 track_rect.max.x + 16.0,
@@ -749,7 +1056,152 @@ impl<'a> Widget for MaterialRangeSlider<'a> {
             );
         }
 
-        response
+        RangeSliderResponse { response, moved }
+    }
+}
+
+/// Paint the value bubble for a thumb at `thumb_center` on a tooltip layer,
+/// so scroll areas and panels don't clip it.
+fn paint_value_indicator(ui: &Ui, id: egui::Id, thumb_center: Pos2, text: String) {
+    let text_color = get_global_color("inverseOnSurface");
+    let galley = ui
+        .painter()
+        .layout_no_wrap(text, type_font_id(TypeRole::LabelMedium), text_color);
+    let size = Vec2::new((galley.size().x + 16.0).max(28.0), 28.0);
+    let rect = value_indicator_rect(thumb_center, size, ui.ctx().content_rect());
+
+    let painter = ui.ctx().layer_painter(egui::LayerId::new(
+        egui::Order::Tooltip,
+        id.with("value_indicator"),
+    ));
+    painter.rect_filled(rect, size.y / 2.0, get_global_color("inverseSurface"));
+    painter.galley(rect.center() - galley.size() / 2.0, galley, text_color);
+}
+
+/// Place a value bubble of `size` centered above the thumb, kept inside
+/// `bounds` horizontally and moved below the thumb when there is no room
+/// above. The placement only depends on the thumb position, so the bubble
+/// doesn't jump around while dragging along the edges.
+fn value_indicator_rect(thumb_center: Pos2, size: Vec2, bounds: Rect) -> Rect {
+    // Thumb radius plus spacing
+    let gap = 16.0;
+    let max_x = (bounds.max.x - size.x).max(bounds.min.x);
+    let x = (thumb_center.x - size.x / 2.0).clamp(bounds.min.x, max_x);
+    let above = thumb_center.y - gap - size.y;
+    let y = if above >= bounds.min.y {
+        above
+    } else {
+        thumb_center.y + gap
+    };
+    Rect::from_min_size(Pos2::new(x, y), size)
+}
+
+/// Snap `value` to the nearest stop of a discrete slider and clamp it to the
+/// range. The stops are `start + k * step`, plus the end of the range when
+/// the range isn't a whole number of steps.
+fn snap_to_step(value: f32, range: &RangeInclusive<f32>, step: Option<f32>) -> f32 {
+    let (min, max) = (*range.start(), *range.end());
+    let value = value.clamp(min, max);
+    match step {
+        Some(step) if step > 0.0 => {
+            let snapped = (min + ((value - min) / step).round() * step).min(max);
+            if max - value < (value - snapped).abs() {
+                max
+            } else {
+                snapped
+            }
+        }
+        _ => value,
+    }
+}
+
+/// Move `value` by `steps` stops (see [`snap_to_step`]), or by 1% of the
+/// range per step for a continuous slider.
+fn offset_by_steps(value: f32, steps: i32, range: &RangeInclusive<f32>, step: Option<f32>) -> f32 {
+    let (min, max) = (*range.start(), *range.end());
+    match step {
+        Some(step) if step > 0.0 => {
+            // Count from the neighbouring stop, so a value off the grid (like
+            // the end of an uneven range) moves to the stop next to it
+            let position = (value - min) / step;
+            let stop = if steps > 0 {
+                (position + 1e-3).floor()
+            } else {
+                (position - 1e-3).ceil()
+            };
+            (min + (stop + steps as f32) * step).clamp(min, max)
+        }
+        _ => (value + steps as f32 * (max - min) / 100.0).clamp(min, max),
+    }
+}
+
+/// Values of the tick marks of a discrete slider: every stop from the start
+/// of the range, and the end of the range.
+fn tick_values(range: &RangeInclusive<f32>, step: f32) -> Vec<f32> {
+    let (min, max) = (*range.start(), *range.end());
+    let count = ((max - min) / step + 1e-3).floor() as usize;
+    let mut ticks: Vec<f32> = (0..=count).map(|k| min + k as f32 * step).collect();
+    if ticks.last().is_some_and(|last| max - last > step * 1e-3) {
+        ticks.push(max);
+    }
+    ticks
+}
+
+/// Steps to move a slider this frame: +1/-1 per arrow key press and +10/-10
+/// per Page Up/Down press while it has focus, and +1/-1 per increment or
+/// decrement request from assistive technology.
+fn nudge_steps(ui: &Ui, response: &Response) -> i32 {
+    if response.has_focus() {
+        // Keep the arrow keys from moving focus to another widget
+        ui.memory_mut(|m| {
+            m.set_focus_lock_filter(
+                response.id,
+                egui::EventFilter {
+                    horizontal_arrows: true,
+                    vertical_arrows: true,
+                    ..Default::default()
+                },
+            )
+        });
+    }
+    ui.input(|i| {
+        let mut steps = 0;
+        if response.has_focus() {
+            if i.key_pressed(egui::Key::ArrowRight) || i.key_pressed(egui::Key::ArrowUp) {
+                steps += 1;
+            }
+            if i.key_pressed(egui::Key::ArrowLeft) || i.key_pressed(egui::Key::ArrowDown) {
+                steps -= 1;
+            }
+            if i.key_pressed(egui::Key::PageUp) {
+                steps += 10;
+            }
+            if i.key_pressed(egui::Key::PageDown) {
+                steps -= 10;
+            }
+        }
+        steps += i.num_accesskit_action_requests(response.id, accesskit::Action::Increment) as i32;
+        steps -= i.num_accesskit_action_requests(response.id, accesskit::Action::Decrement) as i32;
+        steps
+    })
+}
+
+/// Add the slider's bounds and step to its accessibility node.
+fn describe_range(ui: &Ui, response: &Response, range: &RangeInclusive<f32>, step: Option<f32>) {
+    ui.ctx().accesskit_node_builder(response.id, |node| {
+        node.set_min_numeric_value(*range.start() as f64);
+        node.set_max_numeric_value(*range.end() as f64);
+        if let Some(step) = step {
+            node.set_numeric_value_step(step as f64);
+        }
+        node.add_action(accesskit::Action::Increment);
+        node.add_action(accesskit::Action::Decrement);
+    });
+}
+
+impl<'a> Widget for MaterialRangeSlider<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
     }
 }
 
@@ -763,3 +1215,213 @@ pub fn range_slider<'a>(
 ) -> MaterialRangeSlider<'a> {
     MaterialRangeSlider::new(values, range)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_indicator_centered_above_thumb() {
+        let bounds = Rect::from_min_max(Pos2::ZERO, Pos2::new(800.0, 600.0));
+        let rect = value_indicator_rect(Pos2::new(400.0, 300.0), Vec2::new(40.0, 28.0), bounds);
+        assert_eq!(rect.center().x, 400.0);
+        assert!(rect.max.y < 300.0);
+    }
+
+    #[test]
+    fn test_value_indicator_stays_in_viewport() {
+        let bounds = Rect::from_min_max(Pos2::ZERO, Pos2::new(800.0, 600.0));
+        let size = Vec2::new(60.0, 28.0);
+
+        let left = value_indicator_rect(Pos2::new(5.0, 300.0), size, bounds);
+        assert_eq!(left.min.x, 0.0);
+        let right = value_indicator_rect(Pos2::new(798.0, 300.0), size, bounds);
+        assert_eq!(right.max.x, 800.0);
+
+        // No room above: the bubble moves below the thumb
+        let top = value_indicator_rect(Pos2::new(400.0, 10.0), size, bounds);
+        assert!(top.min.y > 10.0);
+        assert!(bounds.contains_rect(top));
+    }
+
+    #[test]
+    fn test_snap_to_step() {
+        let range = 0.0..=100.0;
+        assert_eq!(snap_to_step(42.0, &range, Some(5.0)), 40.0);
+        assert_eq!(snap_to_step(43.0, &range, Some(5.0)), 45.0);
+        assert_eq!(snap_to_step(120.0, &range, Some(5.0)), 100.0);
+        assert_eq!(snap_to_step(42.0, &range, None), 42.0);
+
+        // Stops count from the start of the range
+        assert_eq!(snap_to_step(4.2, &(1.0..=5.0), Some(2.0)), 5.0);
+        assert_eq!(snap_to_step(2.8, &(1.0..=5.0), Some(2.0)), 3.0);
+    }
+
+    #[test]
+    fn test_uneven_range_clamps_to_max() {
+        // 0..=10 in steps of 3: stops at 0, 3, 6, 9 and 10
+        let range = 0.0..=10.0;
+        assert_eq!(tick_values(&range, 3.0), vec![0.0, 3.0, 6.0, 9.0, 10.0]);
+        assert_eq!(snap_to_step(9.4, &range, Some(3.0)), 9.0);
+        assert_eq!(snap_to_step(9.6, &range, Some(3.0)), 10.0);
+
+        assert_eq!(offset_by_steps(9.0, 1, &range, Some(3.0)), 10.0);
+        assert_eq!(offset_by_steps(10.0, 1, &range, Some(3.0)), 10.0);
+        assert_eq!(offset_by_steps(10.0, -1, &range, Some(3.0)), 9.0);
+        assert_eq!(offset_by_steps(9.0, -1, &range, Some(3.0)), 6.0);
+    }
+
+    #[test]
+    fn test_page_steps() {
+        let range = 0.0..=100.0;
+        assert_eq!(offset_by_steps(40.0, 10, &range, Some(1.0)), 50.0);
+        assert_eq!(offset_by_steps(5.0, -10, &range, Some(1.0)), 0.0);
+        // Continuous sliders move 1% of the range per step
+        assert_eq!(offset_by_steps(40.0, 10, &range, None), 50.0);
+    }
+
+    #[test]
+    fn test_tick_values() {
+        assert_eq!(
+            tick_values(&(0.0..=5.0), 1.0),
+            vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]
+        );
+        assert_eq!(tick_values(&(10.0..=20.0), 5.0), vec![10.0, 15.0, 20.0]);
+    }
+
+    /// Drag the range slider thumb at value `from` to value `to`, on a 0..=100
+    /// slider with steps of 10, returning the final values and every thumb
+    /// reported as moved
+    fn drag_range_thumb(values: (f32, f32), from: f32, to: f32) -> ((f32, f32), Vec<RangeThumb>) {
+        drag_range_slider(values, from, to, true)
+    }
+
+    /// [`drag_range_thumb`] on an enabled or disabled slider
+    fn drag_range_slider(
+        mut values: (f32, f32),
+        from: f32,
+        to: f32,
+        enabled: bool,
+    ) -> ((f32, f32), Vec<RangeThumb>) {
+        let ctx = egui::Context::default();
+        let mut track = Rect::NOTHING;
+        let mut moved = Vec::new();
+        for frame in 0..5 {
+            let at = |value: f32| {
+                Pos2::new(
+                    track.min.x + value / 100.0 * track.width(),
+                    track.center().y,
+                )
+            };
+            let button = |pos, pressed| egui::Event::PointerButton {
+                pos,
+                button: egui::PointerButton::Primary,
+                pressed,
+                modifiers: Default::default(),
+            };
+            let events = match frame {
+                1 => vec![egui::Event::PointerMoved(at(from))],
+                2 => vec![button(at(from), true)],
+                3 => vec![egui::Event::PointerMoved(at(to))],
+                4 => vec![button(at(to), false)],
+                _ => vec![],
+            };
+            let input = egui::RawInput {
+                time: Some(frame as f64 * 0.05),
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let response = MaterialSlider::range(&mut values, 0.0..=100.0)
+                        .step(10.0)
+                        .width(200.0)
+                        .enabled(enabled)
+                        .show(ui);
+                    let rect = response.response.rect;
+                    track = Rect::from_min_size(rect.min, Vec2::new(200.0, rect.height()));
+                    moved.extend(response.moved);
+                });
+            });
+        }
+        (values, moved)
+    }
+
+    #[test]
+    fn test_range_slider_thumbs_cannot_cross() {
+        // The start thumb stops at the end thumb
+        let (values, moved) = drag_range_thumb((20.0, 60.0), 20.0, 100.0);
+        assert_eq!(values, (60.0, 60.0));
+        assert_eq!(moved, vec![RangeThumb::Start]);
+
+        // The end thumb stops at the start thumb
+        let (values, moved) = drag_range_thumb((20.0, 60.0), 60.0, 0.0);
+        assert_eq!(values, (20.0, 20.0));
+        assert_eq!(moved, vec![RangeThumb::End]);
+    }
+
+    #[test]
+    fn test_disabled_range_slider_ignores_drags() {
+        // On a thumb and on the empty track
+        for (from, to) in [(20.0, 40.0), (90.0, 30.0)] {
+            let (values, moved) = drag_range_slider((20.0, 60.0), from, to, false);
+            assert_eq!(values, (20.0, 60.0));
+            assert!(moved.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_range_slider_tab_stops_are_the_thumbs() {
+        let ctx = egui::Context::default();
+        let tab = egui::Event::Key {
+            key: egui::Key::Tab,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Default::default(),
+        };
+        let mut values = (20.0, 60.0);
+        let mut track_id = egui::Id::NULL;
+        for events in [vec![], vec![tab], vec![]] {
+            let input = egui::RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    track_id = MaterialSlider::range(&mut values, 0.0..=100.0)
+                        .show(ui)
+                        .response
+                        .id;
+                });
+            });
+        }
+        assert_eq!(
+            ctx.memory(|m| m.focused()),
+            Some(track_id.with("start_thumb"))
+        );
+    }
+
+    #[test]
+    fn test_range_slider_thumbs_snap_to_steps() {
+        let (values, moved) = drag_range_thumb((20.0, 60.0), 20.0, 43.0);
+        assert_eq!(values, (40.0, 60.0));
+        assert_eq!(moved, vec![RangeThumb::Start]);
+
+        let (values, moved) = drag_range_thumb((20.0, 60.0), 60.0, 87.0);
+        assert_eq!(values, (20.0, 90.0));
+        assert_eq!(moved, vec![RangeThumb::End]);
+    }
+
+    #[test]
+    fn test_value_formatter() {
+        let mut value = 1234.0;
+        let slider = MaterialSlider::new(&mut value, 0.0..=10_000.0)
+            .value_formatter(|v| format!("{:.0} Hz", v));
+        assert_eq!(slider.format_value(1234.0), "1234 Hz");
+
+        let mut value = 0.5;
+        let slider = MaterialSlider::new(&mut value, 0.0..=1.0);
+        assert_eq!(slider.format_value(0.5), "0.50");
+    }
+}
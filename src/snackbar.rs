@@ -10,16 +10,19 @@
 //! ## Dimensions
 //! - **Min width**: 344dp, **Max width**: 672dp (two-line snackbars)
 //! - **Height**: 48dp (single-line), 68dp+ (multi-line with action)
+//! - **Layout**: the action sits beside a message that fits on one line; a
+//!   longer message wraps over the full width and the action moves below it
 //! - **Corner radius**: 4dp
 //! - **Elevation**: 6dp shadow (raised above content)
 //! - **Margin**: 8dp from edges (floating), 0dp (fixed)
 
-use crate::theme::get_global_color;
+use crate::theme::{get_global_color, reduced_motion, type_font_id, TypeRole};
 use egui::{
     ecolor::Color32,
     epaint::{CornerRadius, Shadow, Stroke},
     Rect, Response, Sense, Ui, Vec2, Widget,
 };
+use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
 /// Defines where a SnackBar should appear and how its location should be adjusted.
@@ -33,6 +36,128 @@ pub enum SnackBarBehavior {
     Floating,
 }
 
+/// Fraction of the snackbar width a swipe must travel to dismiss it.
+const SWIPE_DISMISS_FRACTION: f32 = 1.0 / 3.0;
+/// Duration of the slide-out and spring-back animations, in seconds.
+const SWIPE_ANIMATION_S: f64 = 0.2;
+
+/// Response from showing a snackbar, telling apart the ways it can be dismissed.
+pub struct SnackbarResponse {
+    /// The response of the snackbar area
+    pub response: Response,
+    /// The action button was clicked this frame
+    pub action_clicked: bool,
+    /// The close icon was clicked this frame
+    pub closed: bool,
+    /// The snackbar was swiped away this frame
+    pub dismissed_by_swipe: bool,
+    /// The auto-dismiss duration elapsed
+    pub timed_out: bool,
+    /// The snackbar is being dragged or is animating after a drag
+    pub swiping: bool,
+}
+
+impl SnackbarResponse {
+    fn new(response: Response) -> Self {
+        Self {
+            response,
+            action_clicked: false,
+            closed: false,
+            dismissed_by_swipe: false,
+            timed_out: false,
+            swiping: false,
+        }
+    }
+
+    /// Whether the snackbar was dismissed this frame, for any reason.
+    pub fn dismissed(&self) -> bool {
+        self.action_clicked || self.closed || self.dismissed_by_swipe || self.timed_out
+    }
+}
+
+/// Drag state kept between frames while a snackbar is being swiped.
+#[derive(Clone, Copy, Default)]
+struct SwipeState {
+    offset: f32,
+    release_offset: f32,
+    release_time: Option<f64>,
+    exiting: bool,
+}
+
+struct SwipeOutcome {
+    offset: f32,
+    opacity: f32,
+    swiping: bool,
+    dismissed: bool,
+}
+
+/// Track a horizontal drag on the snackbar and animate it out or back after release.
+fn update_swipe(ui: &Ui, id: egui::Id, rect: Rect) -> SwipeOutcome {
+    let mut state = ui
+        .ctx()
+        .data(|data| data.get_temp::<SwipeState>(id))
+        .unwrap_or_default();
+    let response = ui.interact(
+        rect.translate(Vec2::new(state.offset, 0.0)),
+        id,
+        Sense::drag(),
+    );
+    let now = ui.input(|i| i.time);
+    let width = rect.width().max(1.0);
+
+    if response.drag_stopped() {
+        state.exiting = state.offset.abs() > width * SWIPE_DISMISS_FRACTION;
+        state.release_offset = state.offset;
+        state.release_time = Some(now);
+    } else if response.dragged() {
+        state.offset += response.drag_delta().x;
+        state.release_time = None;
+        state.exiting = false;
+    }
+
+    let mut dismissed = false;
+    if let Some(release_time) = state.release_time {
+        let target = if state.exiting {
+            width * state.release_offset.signum()
+        } else {
+            0.0
+        };
+        // Reduced motion skips the slide and snaps straight to the end state
+        let t = if reduced_motion(ui.ctx()) {
+            1.0
+        } else {
+            ((now - release_time) / SWIPE_ANIMATION_S).clamp(0.0, 1.0) as f32
+        };
+        let eased = 1.0 - (1.0 - t).powi(3);
+        state.offset = state.release_offset + (target - state.release_offset) * eased;
+
+        if t >= 1.0 {
+            dismissed = state.exiting;
+            state = SwipeState::default();
+        } else {
+            ui.ctx().request_repaint();
+        }
+    }
+
+    let swiping = state.offset != 0.0 || response.dragged();
+    let opacity = (1.0 - state.offset.abs() / width).clamp(0.0, 1.0);
+    let offset = state.offset;
+    ui.ctx().data_mut(|data| {
+        if swiping {
+            data.insert_temp(id, state);
+        } else {
+            data.remove::<SwipeState>(id);
+        }
+    });
+
+    SwipeOutcome {
+        offset,
+        opacity,
+        swiping,
+        dismissed,
+    }
+}
+
 /// Material Design snackbar component.
 ///
 /// Snackbars provide brief messages about app processes at the bottom of the screen.
@@ -67,6 +192,8 @@ pub struct MaterialSnackbar<'a> {
     leading_icon: Option<String>,
     action_overflow_threshold: f32,
     on_visible: Option<Box<dyn Fn() + Send + Sync + 'a>>,
+    swipe_to_dismiss: bool,
+    id_salt: Option<egui::Id>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -106,6 +233,8 @@ impl<'a> MaterialSnackbar<'a> {
             leading_icon: None,
             action_overflow_threshold: 0.25,
             on_visible: None,
+            swipe_to_dismiss: true,
+            id_salt: None,
         }
     }
 
@@ -286,6 +415,21 @@ impl<'a> MaterialSnackbar<'a> {
         self
     }
 
+    /// Allow dismissing the snackbar by dragging it sideways (default: true).
+    ///
+    /// Dragging past a third of its width slides and fades the snackbar out;
+    /// releasing it earlier springs it back into place.
+    pub fn swipe_to_dismiss(mut self, enabled: bool) -> Self {
+        self.swipe_to_dismiss = enabled;
+        self
+    }
+
+    /// Set a unique id salt for the swipe state, needed when several snackbars share a `Ui`.
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(egui::Id::new(id_salt));
+        self
+    }
+
     /// Show the snackbar only if the condition is true.
     ///
     /// This method manages the visibility state properly and is useful for
@@ -343,10 +487,16 @@ impl<'a> MaterialSnackbar<'a> {
     }
 }
 
-impl Widget for MaterialSnackbar<'_> {
-    fn ui(mut self, ui: &mut Ui) -> Response {
+impl MaterialSnackbar<'_> {
+    /// Show the snackbar, reporting how it was dismissed this frame.
+    pub fn show_with_response(self, ui: &mut Ui) -> SnackbarResponse {
+        self.show_at(ui, 0.0)
+    }
+
+    /// Lay out and paint the snackbar, moved `vertical_offset` away from its edge.
+    fn show_at(mut self, ui: &mut Ui, vertical_offset: f32) -> SnackbarResponse {
         if !self.visible {
-            return ui.allocate_response(Vec2::ZERO, Sense::hover());
+            return SnackbarResponse::new(ui.allocate_response(Vec2::ZERO, Sense::hover()));
         }
 
         // Initialize show time when first rendered
@@ -368,7 +518,10 @@ impl Widget for MaterialSnackbar<'_> {
 
         if should_auto_dismiss {
             // Return empty response if auto-dismissed
-            return ui.allocate_response(Vec2::ZERO, Sense::hover());
+            return SnackbarResponse {
+                timed_out: true,
+                ..SnackbarResponse::new(ui.allocate_response(Vec2::ZERO, Sense::hover()))
+            };
         }
 
         let (background_color, border_stroke) = self.get_snackbar_style();
@@ -387,16 +540,19 @@ impl Widget for MaterialSnackbar<'_> {
             width,
             margin,
             show_close_icon,
-            close_icon_color: _,
+            close_icon_color,
             leading_icon,
-            action_overflow_threshold: _,
+            action_overflow_threshold,
             on_visible: _,
+            swipe_to_dismiss,
+            id_salt,
         } = self;
 
         // Material 3 design tokens
         let label_text_color = get_global_color("onInverseSurface");
         let action_text_color = get_global_color("inversePrimary");
-        let _default_close_icon_color = get_global_color("onInverseSurface");
+        let close_icon_color = close_icon_color.unwrap_or(label_text_color);
+        let message_font = type_font_id(TypeRole::BodyMedium);
 
         // Calculate leading icon size if present
         let icon_galley = leading_icon.as_ref().map(|icon| {
@@ -412,50 +568,38 @@ impl Widget for MaterialSnackbar<'_> {
         let action_galley = action_text.as_ref().map(|text| {
             ui.painter().layout_no_wrap(
                 text.clone(),
-                egui::FontId::proportional(14.0),
+                type_font_id(TypeRole::LabelLarge),
                 action_text_color,
             )
         });
 
-        // Calculate close icon size
-        let close_icon_width = if show_close_icon { 48.0 } else { 0.0 }; // 24px icon + padding
-
-        // Calculate available width for message
-        let action_area_width = if let Some(galley) = &action_galley {
-            galley.size().x + 64.0
-        } else {
-            0.0
-        };
-
-        let max_message_width = 600.0 - action_area_width - icon_width - close_icon_width;
-
-        // Calculate message text with width constraint
-        let text_galley = ui.painter().layout(
-            message.clone(),
-            egui::FontId::proportional(14.0),
-            label_text_color,
-            max_message_width.max(200.0),
-        );
-
         // Material Design padding
         let is_floating = behavior == SnackBarBehavior::Floating;
         let horizontal_padding = if is_floating { 16.0 } else { 24.0 };
         let label_padding = Vec2::new(horizontal_padding, 14.0);
-        let action_padding = Vec2::new(8.0, 14.0);
+        let trailing_padding = 8.0;
         let action_spacing = if action_text.is_some() { 8.0 } else { 0.0 };
         let action_width = action_galley.as_ref().map_or(0.0, |g| g.size().x + 32.0);
+        let close_icon_width = if show_close_icon { 48.0 } else { 0.0 }; // 24px icon + padding
+
+        // Width of the message on a single line decides between the layouts
+        let single_line_width = ui
+            .painter()
+            .layout_no_wrap(message.clone(), message_font.clone(), label_text_color)
+            .size()
+            .x;
 
         // Calculate width following Material Design constraints
-        let content_width = icon_width
-            + text_galley.size().x
-            + action_width
+        let content_width = label_padding.x
+            + icon_width
+            + single_line_width
             + action_spacing
+            + action_width
             + close_icon_width
-            + label_padding.x
-            + action_padding.x;
+            + trailing_padding;
         let min_width = 344.0;
         let max_width = 672.0;
-        
+
         // Apply custom width if specified (floating only)
         let snackbar_width = if let Some(custom_width) = width {
             if is_floating {
@@ -472,344 +616,104 @@ impl Widget for MaterialSnackbar<'_> {
                 .max(min_width)
         };
 
-        // Calculate dynamic height
-        let min_height = 48.0;
-        let text_height = text_galley.size().y;
-        let icon_height = icon_galley.as_ref().map_or(0.0, |g| g.size().y);
-        let action_height = if action_text.is_some() { 36.0 } else { 0.0 };
-        let content_height = text_height.max(action_height).max(icon_height);
-        let snackbar_height = (content_height + label_padding.y * 2.0).max(min_height);
-
-        let snackbar_size = Vec2::new(snackbar_width, snackbar_height);
-
-        // Allocate space
-        let (_allocated_rect, mut response) = ui.allocate_exact_size(snackbar_size, Sense::click());
-
-        // Calculate position
-        let screen_rect = ui.ctx().content_rect();
-        
-        // Apply margin for floating behavior
-        let effective_margin = if is_floating {
-            margin.unwrap_or(Vec2::new(24.0, 16.0))
-        } else {
-            Vec2::ZERO
-        };
-        
-        let snackbar_x = if is_floating {
-            (screen_rect.width() - snackbar_size.x).max(0.0) / 2.0
-        } else {
-            0.0
-        };
-        
-        let snackbar_y = match position {
-            SnackbarPosition::Bottom => {
-                if is_floating {
-                    screen_rect.height() - snackbar_size.y - effective_margin.y - 32.0
-                } else {
-                    screen_rect.height() - snackbar_size.y
-                }
-            }
-            SnackbarPosition::Top => {
-                if is_floating {
-                    32.0 + effective_margin.y
-                } else {
-                    0.0
-                }
-            }
-        };
-
-        let snackbar_pos = egui::pos2(snackbar_x, snackbar_y);
-        let snackbar_rect = Rect::from_min_size(snackbar_pos, snackbar_size);
-
-        // Draw Material Design elevation 6dp shadow
-        let shadow_layers = [
-            (
-                Vec2::new(0.0, 6.0),
-                10.0,
-                Color32::from_rgba_unmultiplied(0, 0, 0, 20),
-            ),
-            (
-                Vec2::new(0.0, 1.0),
-                18.0,
-                Color32::from_rgba_unmultiplied(0, 0, 0, 14),
-            ),
-            (
-                Vec2::new(0.0, 3.0),
-                5.0,
-                Color32::from_rgba_unmultiplied(0, 0, 0, 12),
-            ),
-        ];
-
-        for (offset, blur_radius, color) in shadow_layers {
-            let shadow_rect = snackbar_rect.translate(offset).expand(blur_radius / 2.0);
-            ui.painter().rect_filled(shadow_rect, corner_radius, color);
-        }
-
-        // Draw snackbar background
-        ui.painter()
-            .rect_filled(snackbar_rect, corner_radius, background_color);
-
-        // Draw border if present
-        if let Some(stroke) = border_stroke {
-            ui.painter().rect_stroke(
-                snackbar_rect,
-                corner_radius,
-                stroke,
-                egui::epaint::StrokeKind::Outside,
-            );
-        }
-
-        // Draw message text with proper Material Design positioning
-        // For multi-line text, align to the top with proper padding
-        let text_pos = egui::pos2(
-            snackbar_rect.min.x + label_padding.x,
-            snackbar_rect.min.y + label_padding.y,
-        );
-        ui.painter().galley(text_pos, text_galley, label_text_color);
-
-        // Handle action button if present
-        let mut action_clicked = false;
-
-        if let (Some(_action_text), Some(action_galley)) =
-            (action_text.as_ref(), action_galley.as_ref())
-        {
-            // Material Design action button positioning (right-aligned with proper spacing)
-            // Position action button at top-right, aligned with text baseline
-            let action_rect = Rect::from_min_size(
-                egui::pos2(
-                    snackbar_rect.max.x - action_width - 8.0, // 8px right margin
-                    snackbar_rect.min.y + label_padding.y - 6.0, // Align with text, slight adjustment
-                ),
-                Vec2::new(action_width, 36.0),
-            );
-
-            let action_response = ui.interact(action_rect, ui.next_auto_id(), Sense::click());
-
-            // Material Design state layers for action button
-            if action_response.hovered() {
-                let hover_color = action_text_color.linear_multiply(0.04); // Material hover opacity
-                ui.painter()
-                    .rect_filled(action_rect, CornerRadius::from(4.0), hover_color);
-            }
-            if action_response.is_pointer_button_down_on() {
-                let pressed_color = action_text_color.linear_multiply(0.10); // Material pressed opacity
-                ui.painter()
-                    .rect_filled(action_rect, CornerRadius::from(4.0), pressed_color);
-            }
-
-            // Action text centered in button
-            let action_text_pos = egui::pos2(
-                action_rect.center().x - action_galley.size().x / 2.0,
-                action_rect.center().y - action_galley.size().y / 2.0,
-            );
-            ui.painter()
-                .galley(action_text_pos, action_galley.clone(), action_text_color);
-
-            if action_response.clicked() {
-                if let Some(callback) = action_callback {
-                    callback();
-                }
-                action_clicked = true;
-            }
-
-            response = response.union(action_response);
-        }
-
-        // Update response state
-        if action_clicked {
-            response = response.on_hover_text("Action clicked");
-        }
-
-        // Allow clicking outside action to dismiss (only for basic snackbars)
-        if response.clicked() && action_text.is_none() {
-            response = response.on_hover_text("Dismissed");
-        }
-
-        response
-    }
-}
-
-/// A wrapper for MaterialSnackbar that includes vertical offset for stacking
-#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
-pub struct MaterialSnackbarWithOffset<'a> {
-    snackbar: MaterialSnackbar<'a>,
-    vertical_offset: f32,
-}
-
-impl Widget for MaterialSnackbarWithOffset<'_> {
-    fn ui(mut self, ui: &mut Ui) -> Response {
-        if !self.snackbar.visible {
-            return ui.allocate_response(Vec2::ZERO, Sense::hover());
-        }
-
-        // Initialize show time when first rendered
-        if self.snackbar.show_time.is_none() {
-            self.snackbar.show_time = Some(Instant::now());
-        }
-
-        // Check auto-dismiss
-        let should_auto_dismiss = if let (Some(auto_dismiss), Some(show_time)) =
-            (self.snackbar.auto_dismiss, self.snackbar.show_time)
-        {
-            show_time.elapsed() >= auto_dismiss
-        } else {
-            false
-        };
-
-        if should_auto_dismiss {
-            // Return empty response if auto-dismissed
-            return ui.allocate_response(Vec2::ZERO, Sense::hover());
-        }
-
-        let (background_color, border_stroke) = self.snackbar.get_snackbar_style();
-
-        let MaterialSnackbar {
-            message,
-            action_text,
-            action_callback,
-            visible: _,
-            auto_dismiss: _,
-            show_time: _,
-            position,
-            corner_radius,
-            elevation: _,
-            behavior,
-            width,
-            margin,
-            show_close_icon,
-            close_icon_color,
-            leading_icon,
+        // Single line with the action beside the message, or the message
+        // wrapped over the full width with the action below it
+        let message_area_width =
+            snackbar_width - label_padding.x - icon_width - close_icon_width - trailing_padding;
+        let inline_width = message_area_width - action_spacing - action_width;
+        let action_below = action_below(
+            single_line_width,
+            inline_width,
+            action_width,
+            snackbar_width,
             action_overflow_threshold,
-            on_visible: _,
-        } = self.snackbar;
-
-        // Material 3 design tokens
-        let label_text_color = get_global_color("onInverseSurface");
-        let action_text_color = get_global_color("inversePrimary");
-        let default_close_icon_color = get_global_color("onInverseSurface");
-
-        // Calculate leading icon size if present
-        let icon_galley = leading_icon.as_ref().map(|icon| {
-            ui.painter().layout_no_wrap(
-                icon.clone(),
-                egui::FontId::proportional(20.0), // Larger for icons
-                label_text_color,
-            )
-        });
-        let icon_width = icon_galley.as_ref().map_or(0.0, |g| g.size().x + 16.0); // icon + spacing
-
-        // Calculate action button size
-        let action_galley = action_text.as_ref().map(|text| {
-            ui.painter().layout_no_wrap(
-                text.clone(),
-                egui::FontId::proportional(14.0),
-                action_text_color,
-            )
-        });
-
-        // Calculate close icon size
-        let close_icon_width = if show_close_icon { 48.0 } else { 0.0 }; // 24px icon + padding
-
-        // Calculate available width for message
-        let action_area_width = if let Some(galley) = &action_galley {
-            galley.size().x + 64.0
-        } else {
-            0.0
-        };
-
-        let max_message_width = 600.0 - action_area_width - icon_width - close_icon_width;
-
-        // Calculate message text with width constraint
-        let text_galley = ui.painter().layout(
-            message.clone(),
-            egui::FontId::proportional(14.0),
-            label_text_color,
-            max_message_width.max(200.0),
         );
-
-        // Material Design padding
-        let is_floating = behavior == SnackBarBehavior::Floating;
-        let horizontal_padding = if is_floating { 16.0 } else { 24.0 };
-        let label_padding = Vec2::new(horizontal_padding, 14.0);
-        let action_padding = Vec2::new(8.0, 14.0);
-        let action_spacing = if action_text.is_some() { 8.0 } else { 0.0 };
-        let action_width = action_galley.as_ref().map_or(0.0, |g| g.size().x + 32.0);
-
-        // Calculate width following Material Design constraints
-        let content_width = icon_width
-            + text_galley.size().x
-            + action_width
-            + action_spacing
-            + close_icon_width
-            + label_padding.x
-            + action_padding.x;
-        let min_width = 344.0;
-        let max_width = 672.0;
-        
-        // Apply custom width if specified (floating only)
-        let snackbar_width = if let Some(custom_width) = width {
-            if is_floating {
-                custom_width.clamp(min_width, max_width)
-            } else {
-                content_width.max(min_width).min(max_width)
-            }
+        let wrap_width = if action_below {
+            message_area_width
         } else {
-            let available_width = ui.available_width().max(min_width + 48.0) - 48.0;
-            content_width
-                .max(min_width)
-                .min(max_width)
-                .min(available_width)
-                .max(min_width)
+            inline_width
         };
+        let text_galley =
+            ui.painter()
+                .layout(message, message_font, label_text_color, wrap_width.max(1.0));
 
         // Calculate dynamic height
         let min_height = 48.0;
+        let action_height = 36.0;
         let text_height = text_galley.size().y;
         let icon_height = icon_galley.as_ref().map_or(0.0, |g| g.size().y);
-        let action_height = if action_text.is_some() { 36.0 } else { 0.0 };
-        let content_height = text_height.max(action_height).max(icon_height);
-        let snackbar_height = (content_height + label_padding.y * 2.0).max(min_height);
+        let row_height = text_height.max(icon_height);
+        let snackbar_height = if action_below {
+            label_padding.y + row_height + 4.0 + action_height + trailing_padding
+        } else {
+            (row_height + label_padding.y * 2.0).max(min_height)
+        };
 
         let snackbar_size = Vec2::new(snackbar_width, snackbar_height);
 
-        // Allocate space
-        let (_allocated_rect, mut response) = ui.allocate_exact_size(snackbar_size, Sense::click());
-
         // Calculate position with vertical offset for stacking
         let screen_rect = ui.ctx().content_rect();
-        
+
         // Apply margin for floating behavior
         let effective_margin = if is_floating {
             margin.unwrap_or(Vec2::new(24.0, 16.0))
         } else {
             Vec2::ZERO
         };
-        
+
         let snackbar_x = if is_floating {
             (screen_rect.width() - snackbar_size.x).max(0.0) / 2.0
         } else {
             0.0
         };
-        
+
         let snackbar_y = match position {
             SnackbarPosition::Bottom => {
                 if is_floating {
-                    screen_rect.height() - snackbar_size.y - effective_margin.y - 32.0 - self.vertical_offset
+                    screen_rect.height()
+                        - snackbar_size.y
+                        - effective_margin.y
+                        - 32.0
+                        - vertical_offset
                 } else {
-                    screen_rect.height() - snackbar_size.y - self.vertical_offset
+                    screen_rect.height() - snackbar_size.y - vertical_offset
                 }
             }
             SnackbarPosition::Top => {
                 if is_floating {
-                    32.0 + effective_margin.y + self.vertical_offset
+                    32.0 + effective_margin.y + vertical_offset
                 } else {
-                    self.vertical_offset
+                    vertical_offset
                 }
             }
         };
 
         let snackbar_pos = egui::pos2(snackbar_x, snackbar_y);
-        let snackbar_rect = Rect::from_min_size(snackbar_pos, snackbar_size);
+        let mut snackbar_rect = Rect::from_min_size(snackbar_pos, snackbar_size);
+
+        // The snackbar floats over the layout, so it senses clicks where it's painted
+        let mut response = ui.interact(
+            snackbar_rect,
+            id_salt.unwrap_or_else(|| ui.id()).with("material_snackbar"),
+            Sense::CLICK,
+        );
+
+        // Horizontal swipe-to-dismiss
+        let mut painter = ui.painter().clone();
+        let mut swiping = false;
+        if swipe_to_dismiss {
+            let swipe_id = id_salt.unwrap_or_else(|| ui.id().with("material_snackbar_swipe"));
+            let swipe = update_swipe(ui, swipe_id, snackbar_rect);
+            if swipe.dismissed {
+                return SnackbarResponse {
+                    dismissed_by_swipe: true,
+                    ..SnackbarResponse::new(response)
+                };
+            }
+            snackbar_rect = snackbar_rect.translate(Vec2::new(swipe.offset, 0.0));
+            painter.multiply_opacity(swipe.opacity);
+            swiping = swipe.swiping;
+        }
 
         // Draw Material Design elevation 6dp shadow
         let shadow_layers = [
@@ -832,16 +736,15 @@ impl Widget for MaterialSnackbarWithOffset<'_> {
 
         for (offset, blur_radius, color) in shadow_layers {
             let shadow_rect = snackbar_rect.translate(offset).expand(blur_radius / 2.0);
-            ui.painter().rect_filled(shadow_rect, corner_radius, color);
+            painter.rect_filled(shadow_rect, corner_radius, color);
         }
 
         // Draw snackbar background
-        ui.painter()
-            .rect_filled(snackbar_rect, corner_radius, background_color);
+        painter.rect_filled(snackbar_rect, corner_radius, background_color);
 
         // Draw border if present
         if let Some(stroke) = border_stroke {
-            ui.painter().rect_stroke(
+            painter.rect_stroke(
                 snackbar_rect,
                 corner_radius,
                 stroke,
@@ -849,76 +752,61 @@ impl Widget for MaterialSnackbarWithOffset<'_> {
             );
         }
 
-        // Track current x position for content layout
+        // The first row holds the icon, the message and, on a single line,
+        // the action and close icon
+        let row_center_y = snackbar_rect.min.y + label_padding.y + row_height / 2.0;
         let mut current_x = snackbar_rect.min.x + label_padding.x;
 
         // Draw leading icon if present
-        if let (Some(_icon_text), Some(icon_galley)) = (leading_icon.as_ref(), icon_galley.as_ref())
-        {
-            let icon_pos = egui::pos2(
-                current_x,
-                snackbar_rect.center().y - icon_galley.size().y / 2.0,
-            );
-            ui.painter().galley(icon_pos, icon_galley.clone(), label_text_color);
-            current_x += icon_galley.size().x + 16.0; // icon + spacing
+        if let Some(icon_galley) = &icon_galley {
+            let icon_pos = egui::pos2(current_x, row_center_y - icon_galley.size().y / 2.0);
+            painter.galley(icon_pos, icon_galley.clone(), label_text_color);
+            current_x += icon_width;
         }
 
         // Draw message text
-        let text_pos = egui::pos2(current_x, snackbar_rect.min.y + label_padding.y);
-        ui.painter().galley(text_pos, text_galley.clone(), label_text_color);
+        let text_pos = egui::pos2(current_x, row_center_y - text_height / 2.0);
+        painter.galley(text_pos, text_galley, label_text_color);
 
-        // Calculate action and close icon area width
-        let action_and_icon_width = action_width + close_icon_width;
-        let will_overflow_action = 
-            action_and_icon_width / snackbar_width > action_overflow_threshold;
+        let trailing_edge = snackbar_rect.max.x - trailing_padding;
 
         // Handle action button if present
         let mut action_clicked = false;
-
-        if let (Some(_action_text), Some(action_galley)) =
-            (action_text.as_ref(), action_galley.as_ref())
-        {
-            let action_rect = if will_overflow_action {
-                // Action overflows to new line
+        if let Some(action_galley) = &action_galley {
+            let action_rect = if action_below {
+                // Below the message, aligned to the trailing edge
                 Rect::from_min_size(
                     egui::pos2(
-                        snackbar_rect.max.x - action_width - close_icon_width - 8.0,
-                        snackbar_rect.min.y + label_padding.y + text_galley.size().y + 8.0,
+                        trailing_edge - action_width,
+                        snackbar_rect.min.y + label_padding.y + row_height + 4.0,
                     ),
-                    Vec2::new(action_width, 36.0),
+                    Vec2::new(action_width, action_height),
                 )
             } else {
-                // Action stays on same line
+                // Beside the message, before the close icon
                 Rect::from_min_size(
                     egui::pos2(
-                        snackbar_rect.max.x - action_width - close_icon_width - 8.0,
-                        snackbar_rect.min.y + label_padding.y - 6.0,
+                        trailing_edge - close_icon_width - action_width,
+                        row_center_y - action_height / 2.0,
                     ),
-                    Vec2::new(action_width, 36.0),
+                    Vec2::new(action_width, action_height),
                 )
             };
 
             let action_response = ui.interact(action_rect, ui.next_auto_id(), Sense::click());
 
             // Material Design state layers for action button
-            if action_response.hovered() {
-                let hover_color = action_text_color.linear_multiply(0.08);
-                ui.painter()
-                    .rect_filled(action_rect, CornerRadius::from(4.0), hover_color);
-            }
             if action_response.is_pointer_button_down_on() {
                 let pressed_color = action_text_color.linear_multiply(0.12);
-                ui.painter()
-                    .rect_filled(action_rect, CornerRadius::from(4.0), pressed_color);
+                painter.rect_filled(action_rect, CornerRadius::from(4.0), pressed_color);
+            } else if action_response.hovered() {
+                let hover_color = action_text_color.linear_multiply(0.08);
+                painter.rect_filled(action_rect, CornerRadius::from(4.0), hover_color);
             }
 
             // Action text centered in button
-            let action_text_pos = egui::pos2(
-                action_rect.center().x - action_galley.size().x / 2.0,
-                action_rect.center().y - action_galley.size().y / 2.0,
-            );
-            ui.painter()
-                .galley(action_text_pos, action_galley.clone(), action_text_color);
+            let action_text_pos = action_rect.center() - action_galley.size() / 2.0;
+            painter.galley(action_text_pos, action_galley.clone(), action_text_color);
 
             if action_response.clicked() {
                 if let Some(callback) = action_callback {
@@ -933,41 +821,33 @@ impl Widget for MaterialSnackbarWithOffset<'_> {
         // Handle close icon if present
         let mut close_clicked = false;
         if show_close_icon {
-            let close_icon_color = close_icon_color.unwrap_or(default_close_icon_color);
-            
-            let close_rect = Rect::from_min_size(
-                egui::pos2(
-                    snackbar_rect.max.x - 40.0,
-                    snackbar_rect.center().y - 20.0,
-                ),
+            let close_rect = Rect::from_center_size(
+                egui::pos2(trailing_edge - close_icon_width / 2.0, row_center_y),
                 Vec2::new(40.0, 40.0),
             );
 
             let close_response = ui.interact(close_rect, ui.next_auto_id(), Sense::click());
 
             // State layer for close button
-            if close_response.hovered() {
-                let hover_color = close_icon_color.linear_multiply(0.08);
-                ui.painter()
-                    .circle_filled(close_rect.center(), 20.0, hover_color);
-            }
             if close_response.is_pointer_button_down_on() {
                 let pressed_color = close_icon_color.linear_multiply(0.12);
-                ui.painter()
-                    .circle_filled(close_rect.center(), 20.0, pressed_color);
+                painter.circle_filled(close_rect.center(), 20.0, pressed_color);
+            } else if close_response.hovered() {
+                let hover_color = close_icon_color.linear_multiply(0.08);
+                painter.circle_filled(close_rect.center(), 20.0, hover_color);
             }
 
             // Draw X icon
             let icon_size = 16.0;
             let center = close_rect.center();
-            ui.painter().line_segment(
+            painter.line_segment(
                 [
                     egui::pos2(center.x - icon_size / 2.0, center.y - icon_size / 2.0),
                     egui::pos2(center.x + icon_size / 2.0, center.y + icon_size / 2.0),
                 ],
                 Stroke::new(2.0, close_icon_color),
             );
-            ui.painter().line_segment(
+            painter.line_segment(
                 [
                     egui::pos2(center.x + icon_size / 2.0, center.y - icon_size / 2.0),
                     egui::pos2(center.x - icon_size / 2.0, center.y + icon_size / 2.0),
@@ -975,19 +855,58 @@ impl Widget for MaterialSnackbarWithOffset<'_> {
                 Stroke::new(2.0, close_icon_color),
             );
 
-            if close_response.clicked() {
-                close_clicked = true;
-            }
-
+            close_clicked = close_response.clicked();
             response = response.union(close_response);
         }
 
-        // Update response state
-        if action_clicked || close_clicked {
-            response = response.on_hover_text("Snackbar dismissed");
+        SnackbarResponse {
+            response,
+            action_clicked,
+            closed: close_clicked,
+            dismissed_by_swipe: false,
+            timed_out: false,
+            swiping,
         }
+    }
+}
+
+/// Whether the action moves below the message: when the message doesn't fit
+/// on one line in `inline_width` beside the action, or the action takes up
+/// more than `threshold` of the snackbar width.
+fn action_below(
+    message_width: f32,
+    inline_width: f32,
+    action_width: f32,
+    snackbar_width: f32,
+    threshold: f32,
+) -> bool {
+    action_width > 0.0
+        && (message_width > inline_width || action_width / snackbar_width > threshold)
+}
 
-        response
+impl Widget for MaterialSnackbar<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show_with_response(ui).response
+    }
+}
+
+/// A wrapper for MaterialSnackbar that includes vertical offset for stacking
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct MaterialSnackbarWithOffset<'a> {
+    snackbar: MaterialSnackbar<'a>,
+    vertical_offset: f32,
+}
+
+impl MaterialSnackbarWithOffset<'_> {
+    /// Show the snackbar, reporting how it was dismissed this frame.
+    pub fn show_with_response(self, ui: &mut Ui) -> SnackbarResponse {
+        self.snackbar.show_at(ui, self.vertical_offset)
+    }
+}
+
+impl Widget for MaterialSnackbarWithOffset<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show_with_response(ui).response
     }
 }
 
@@ -996,14 +915,371 @@ pub fn snackbar(message: impl Into<String>) -> MaterialSnackbar<'static> {
     MaterialSnackbar::new(message)
 }
 
-/// Convenience function to create a snackbar with an action.
-pub fn snackbar_with_action<F>(
+/// Show a snackbar with an action button and a close icon.
+///
+/// Returns `true` when the action or the close icon was clicked this frame,
+/// at which point the caller should stop showing it. Use
+/// [`MaterialSnackbar::show_with_response`] to tell the two apart.
+///
+/// ```rust,no_run
+/// # use egui_material3::snackbar_with_action;
+/// # egui::__run_test_ui(|ui| {
+/// let mut show_undo = true;
+/// if show_undo && snackbar_with_action(ui, "Message archived", "Undo") {
+///     show_undo = false;
+/// }
+/// # });
+/// ```
+pub fn snackbar_with_action(
+    ui: &mut Ui,
     message: impl Into<String>,
-    action_text: impl Into<String>,
-    callback: F,
-) -> MaterialSnackbar<'static>
-where
-    F: Fn() + Send + Sync + 'static,
-{
-    MaterialSnackbar::new(message).action(action_text, callback)
+    action_label: impl Into<String>,
+) -> bool {
+    let response = MaterialSnackbar::new(message)
+        .action(action_label, || {})
+        .show_close_icon(true)
+        .auto_dismiss(None)
+        .show_with_response(ui);
+    response.action_clicked || response.closed
+}
+
+/// How long a queued snackbar stays on screen before the host advances.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SnackbarDuration {
+    /// Dismiss automatically once the duration has elapsed.
+    Timed(Duration),
+    /// Stay on screen until the action is clicked or the snackbar is closed.
+    Indefinite,
+}
+
+struct QueuedSnackbar<T> {
+    message: String,
+    action: Option<(String, T)>,
+    duration: SnackbarDuration,
+}
+
+/// Queue manager that shows one snackbar at a time.
+///
+/// Messages are displayed in the order they were pushed. Each one stays on
+/// screen for its duration (4 seconds by default, indefinitely when it carries
+/// an action), after which the next queued message is shown. `T` identifies
+/// the action so the app can tell which one was clicked.
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Action { Undo }
+///
+/// let mut host = SnackbarHost::new();
+/// host.show("Saved", None);
+/// host.show("Item deleted", Some(("Undo", Action::Undo)));
+///
+/// if host.render(ctx) == Some(Action::Undo) {
+///     // restore the item
+/// }
+/// # });
+/// ```
+pub struct SnackbarHost<T = ()> {
+    id: egui::Id,
+    queue: VecDeque<QueuedSnackbar<T>>,
+    current: Option<(QueuedSnackbar<T>, f64)>,
+    shown_count: u64,
+    position: SnackbarPosition,
+    behavior: SnackBarBehavior,
+    default_duration: Duration,
+    show_close_icon: bool,
+}
+
+impl<T> Default for SnackbarHost<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SnackbarHost<T> {
+    /// Create an empty snackbar host.
+    pub fn new() -> Self {
+        Self {
+            id: egui::Id::new("material_snackbar_host"),
+            queue: VecDeque::new(),
+            current: None,
+            shown_count: 0,
+            position: SnackbarPosition::Bottom,
+            behavior: SnackBarBehavior::Fixed,
+            default_duration: Duration::from_secs(4),
+            show_close_icon: false,
+        }
+    }
+
+    /// Set a unique id salt, needed when an app uses more than one host.
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id = egui::Id::new(id_salt);
+        self
+    }
+
+    /// Set where the snackbars appear.
+    pub fn position(mut self, position: SnackbarPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Set the behavior (Fixed or Floating) of the displayed snackbars.
+    pub fn behavior(mut self, behavior: SnackBarBehavior) -> Self {
+        self.behavior = behavior;
+        self
+    }
+
+    /// Set how long messages without an action stay on screen (default: 4 seconds).
+    pub fn default_duration(mut self, duration: Duration) -> Self {
+        self.default_duration = duration;
+        self
+    }
+
+    /// Show a close icon on every snackbar so indefinite ones can be dismissed.
+    pub fn show_close_icon(mut self, show: bool) -> Self {
+        self.show_close_icon = show;
+        self
+    }
+
+    /// Queue a message, optionally with an action label and its id.
+    ///
+    /// Messages with an action stay until dismissed; others use the default duration.
+    pub fn show(&mut self, message: impl Into<String>, action: Option<(&str, T)>) {
+        let duration = if action.is_some() {
+            SnackbarDuration::Indefinite
+        } else {
+            SnackbarDuration::Timed(self.default_duration)
+        };
+        self.show_with_duration(message, action, duration);
+    }
+
+    /// Queue a message with an explicit duration.
+    pub fn show_with_duration(
+        &mut self,
+        message: impl Into<String>,
+        action: Option<(&str, T)>,
+        duration: SnackbarDuration,
+    ) {
+        self.queue.push_back(QueuedSnackbar {
+            message: message.into(),
+            action: action.map(|(label, id)| (label.to_owned(), id)),
+            duration,
+        });
+    }
+
+    /// The message currently on screen, if any.
+    pub fn current_message(&self) -> Option<&str> {
+        self.current.as_ref().map(|(item, _)| item.message.as_str())
+    }
+
+    /// Number of messages waiting behind the current one.
+    pub fn queued_len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Dismiss the message currently on screen. The next one is shown on the following frame.
+    pub fn dismiss_current(&mut self) {
+        self.current = None;
+    }
+
+    /// Drop the current message and everything queued behind it.
+    pub fn clear(&mut self) {
+        self.current = None;
+        self.queue.clear();
+    }
+
+    /// Expire the current message if its time is up and surface the next one.
+    ///
+    /// Returns the seconds left before the current message times out.
+    fn advance(&mut self, now: f64) -> Option<f64> {
+        if let Some((item, shown_at)) = &self.current {
+            if let SnackbarDuration::Timed(duration) = item.duration {
+                if now - shown_at >= duration.as_secs_f64() {
+                    self.current = None;
+                }
+            }
+        }
+
+        if self.current.is_none() {
+            if let Some(next) = self.queue.pop_front() {
+                self.current = Some((next, now));
+                self.shown_count += 1;
+            }
+        }
+
+        match &self.current {
+            Some((item, shown_at)) => match item.duration {
+                SnackbarDuration::Timed(duration) => {
+                    Some((duration.as_secs_f64() - (now - shown_at)).max(0.0))
+                }
+                SnackbarDuration::Indefinite => None,
+            },
+            None => None,
+        }
+    }
+
+    /// Show the current snackbar and advance the queue.
+    ///
+    /// Returns the id of the action clicked this frame, if any.
+    pub fn render(&mut self, ctx: &egui::Context) -> Option<T> {
+        let now = ctx.input(|i| i.time);
+        if let Some(remaining) = self.advance(now) {
+            ctx.request_repaint_after(Duration::from_secs_f64(remaining));
+        }
+
+        let (item, _) = self.current.as_ref()?;
+        // A fresh id per message so swipe state never carries over to the next one
+        let snackbar_id = self.id.with(self.shown_count);
+
+        let response = egui::Area::new(self.id)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.set_clip_rect(ctx.content_rect());
+
+                let mut snackbar = MaterialSnackbar::new(item.message.clone())
+                    .id_salt(snackbar_id)
+                    .auto_dismiss(None)
+                    .position(self.position)
+                    .behavior(self.behavior)
+                    .show_close_icon(self.show_close_icon);
+                if let Some((label, _)) = &item.action {
+                    snackbar = snackbar.action(label.clone(), || {});
+                }
+
+                let mut visible = true;
+                snackbar
+                    .show_with_offset(&mut visible, 0.0)
+                    .show_with_response(ui)
+            })
+            .inner;
+
+        if response.swiping {
+            // Interacting with the snackbar cancels its timer until the drag is over
+            if let Some((_, shown_at)) = &mut self.current {
+                *shown_at = now;
+            }
+        }
+
+        let dismissed = response.dismissed() || response.response.clicked();
+        if !dismissed {
+            return None;
+        }
+
+        let (item, _) = self.current.take()?;
+        ctx.request_repaint();
+        if response.action_clicked {
+            item.action.map(|(_, id)| id)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Pos2;
+
+    #[test]
+    fn test_host_shows_messages_in_order() {
+        let mut host: SnackbarHost = SnackbarHost::new();
+        host.show("First", None);
+        host.show("Second", None);
+        host.show("Third", None);
+
+        assert_eq!(host.advance(0.0), Some(4.0));
+        assert_eq!(host.current_message(), Some("First"));
+        assert_eq!(host.queued_len(), 2);
+
+        host.advance(3.9);
+        assert_eq!(host.current_message(), Some("First"));
+
+        host.advance(4.0);
+        assert_eq!(host.current_message(), Some("Second"));
+
+        host.advance(8.0);
+        assert_eq!(host.current_message(), Some("Third"));
+        assert_eq!(host.queued_len(), 0);
+
+        assert_eq!(host.advance(12.0), None);
+        assert_eq!(host.current_message(), None);
+    }
+
+    #[test]
+    fn test_action_snackbar_is_indefinite() {
+        let mut host = SnackbarHost::new();
+        host.show("Deleted", Some(("Undo", 7)));
+        host.show("Saved", None);
+
+        assert_eq!(host.advance(0.0), None);
+        host.advance(60.0);
+        assert_eq!(host.current_message(), Some("Deleted"));
+
+        host.dismiss_current();
+        host.advance(61.0);
+        assert_eq!(host.current_message(), Some("Saved"));
+    }
+
+    /// Input for a frame of an 800×600 screen, with an optional primary button event at `pos`
+    fn frame_input(frame: usize, pos: Pos2, pressed: Option<bool>) -> egui::RawInput {
+        let mut input = egui::RawInput {
+            time: Some(frame as f64 * 0.05),
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(800.0, 600.0))),
+            ..Default::default()
+        };
+        input.events.push(egui::Event::PointerMoved(pos));
+        if let Some(pressed) = pressed {
+            input.events.push(egui::Event::PointerButton {
+                pos,
+                button: egui::PointerButton::Primary,
+                pressed,
+                modifiers: Default::default(),
+            });
+        }
+        input
+    }
+
+    #[test]
+    fn test_snackbar_senses_clicks_where_it_is_painted() {
+        let ctx = egui::Context::default();
+        let mut rect = Rect::NOTHING;
+        let _ = ctx.run(frame_input(0, Pos2::ZERO, None), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                rect = MaterialSnackbar::new("Saved")
+                    .show_with_response(ui)
+                    .response
+                    .rect;
+            });
+        });
+        // Painted at the bottom of the screen, not where the panel's layout starts
+        assert!(rect.min.y > 300.0);
+        assert!(rect.max.y <= 600.0);
+    }
+
+    #[test]
+    fn test_clicking_empty_space_keeps_host_message() {
+        let ctx = egui::Context::default();
+        let mut host: SnackbarHost = SnackbarHost::new();
+        host.show("Saved", None);
+
+        let corner = Pos2::new(10.0, 10.0);
+        for (frame, pressed) in [None, Some(true), Some(false)].into_iter().enumerate() {
+            let _ = ctx.run(frame_input(frame, corner, pressed), |ctx| {
+                host.render(ctx);
+            });
+        }
+        assert_eq!(host.current_message(), Some("Saved"));
+    }
+
+    #[test]
+    fn test_long_message_moves_action_below() {
+        // Short message fits on one line beside the action
+        assert!(!action_below(120.0, 250.0, 80.0, 400.0, 0.25));
+        // Long message wraps and the action moves below it
+        assert!(action_below(420.0, 250.0, 80.0, 400.0, 0.25));
+        // A long action label moves below even for a short message
+        assert!(action_below(120.0, 150.0, 180.0, 400.0, 0.25));
+        // Without an action the message simply wraps
+        assert!(!action_below(420.0, 330.0, 0.0, 400.0, 0.25));
+    }
 }
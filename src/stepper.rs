@@ -0,0 +1,667 @@
+//! Material Design 3 Stepper Components
+//!
+//! Steppers show progress through a numbered sequence of steps, such as an
+//! onboarding flow or a multi-step form.
+//!
+//! # M3 Color Role Usage
+//!
+//! - **primary**: Active and completed step circles, connectors after completed steps
+//! - **onPrimary**: Step number and check mark on active and completed circles
+//! - **surfaceContainerHighest**: Upcoming step circles
+//! - **onSurface**: Active step label
+//! - **onSurfaceVariant**: Other step labels, supporting text, upcoming step numbers
+//! - **outlineVariant**: Connectors between upcoming steps
+//! - **onSurface @ 12% / 38%**: Disabled step circle / label
+//! - **State layers**: onSurface @ 8% (hover) on clickable step headers
+//!
+//! ## Dimensions
+//! - **Step circle**: 24dp
+//! - **Connector**: 1dp, at least 16dp long in horizontal steppers
+//!
+//! ## Orientation
+//! Horizontal steppers draw the step headers in one row with the active
+//! step's content and the Back/Next buttons below it. Vertical steppers stack
+//! the headers and show the active step's content and buttons under its
+//! header, alongside the connector to the next step.
+
+use crate::get_global_color;
+use crate::material_symbol::ICON_CHECK;
+use crate::theme::{type_font_id, TypeRole};
+use crate::MaterialButton;
+use egui::{
+    self, Color32, Galley, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget, WidgetInfo,
+    WidgetType,
+};
+use std::sync::Arc;
+
+const CIRCLE_SIZE: f32 = 24.0;
+const LABEL_GAP: f32 = 8.0;
+const HEADER_PADDING: f32 = 12.0;
+const MIN_CONNECTOR_LENGTH: f32 = 16.0;
+
+/// Layout direction of a stepper
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StepperOrientation {
+    /// Step headers in a row, content below (default)
+    #[default]
+    Horizontal,
+    /// Step headers stacked, content under the active header
+    Vertical,
+}
+
+/// How a step is drawn this frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepState {
+    /// A step after the active one that hasn't been completed
+    Upcoming,
+    /// The current step
+    Active,
+    /// A completed step, shown with a check mark
+    Completed,
+    /// A step that can't be selected or navigated to
+    Disabled,
+}
+
+/// A single step of a [`MaterialStepper`].
+pub struct Step<'a> {
+    label: String,
+    supporting_text: Option<String>,
+    completed: Option<bool>,
+    enabled: bool,
+    #[allow(clippy::type_complexity)]
+    content: Option<Box<dyn FnOnce(&mut Ui) + 'a>>,
+}
+
+impl<'a> Step<'a> {
+    /// Create a step with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            supporting_text: None,
+            completed: None,
+            enabled: true,
+            content: None,
+        }
+    }
+
+    /// Set a line of supporting text shown under the label, e.g. "Optional".
+    pub fn supporting_text(mut self, text: impl Into<String>) -> Self {
+        self.supporting_text = Some(text.into());
+        self
+    }
+
+    /// Mark the step as completed or not.
+    ///
+    /// By default, the steps before the active one are completed.
+    pub fn completed(mut self, completed: bool) -> Self {
+        self.completed = Some(completed);
+        self
+    }
+
+    /// Enable or disable the step. Disabled steps are skipped by Next and Back.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Set the panel shown while this step is active.
+    pub fn content(mut self, content: impl FnOnce(&mut Ui) + 'a) -> Self {
+        self.content = Some(Box::new(content));
+        self
+    }
+
+    fn state(&self, index: usize, active: usize) -> StepState {
+        if !self.enabled {
+            StepState::Disabled
+        } else if index == active {
+            StepState::Active
+        } else if self.completed.unwrap_or(index < active) {
+            StepState::Completed
+        } else {
+            StepState::Upcoming
+        }
+    }
+}
+
+/// Response from showing a [`MaterialStepper`]
+pub struct StepperResponse {
+    /// The response of the whole stepper
+    pub response: Response,
+    /// Index of the active step after this frame
+    pub active: usize,
+    /// The Next (or Finish) button was clicked this frame
+    pub next_clicked: bool,
+    /// The Back button was clicked this frame
+    pub back_clicked: bool,
+    /// Index of the step header clicked this frame
+    pub step_clicked: Option<usize>,
+    /// Next was clicked on the last enabled step
+    pub finished: bool,
+}
+
+/// Material Design stepper component.
+///
+/// Steppers display progress through a sequence of numbered steps, each shown
+/// as a circle with its number, or a check mark once completed, connected by
+/// lines. The active step's content is shown with Back and Next buttons.
+///
+/// # Example
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// use egui_material3::{stepper, Step};
+///
+/// let mut active = 0;
+/// let response = stepper(&mut active)
+///     .step(Step::new("Account").content(|ui| {
+///         ui.label("Choose a user name");
+///     }))
+///     .step(Step::new("Profile").supporting_text("Optional"))
+///     .step(Step::new("Done"))
+///     .show(ui);
+/// if response.finished {
+///     // Submit the form
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct MaterialStepper<'a> {
+    active: &'a mut usize,
+    steps: Vec<Step<'a>>,
+    orientation: StepperOrientation,
+    show_controls: bool,
+    linear: bool,
+    back_text: String,
+    next_text: String,
+    finish_text: String,
+    id_salt: Option<egui::Id>,
+}
+
+impl<'a> MaterialStepper<'a> {
+    /// Create a stepper editing the index of the active step.
+    pub fn new(active: &'a mut usize) -> Self {
+        Self {
+            active,
+            steps: Vec::new(),
+            orientation: StepperOrientation::default(),
+            show_controls: true,
+            linear: false,
+            back_text: "Back".to_string(),
+            next_text: "Next".to_string(),
+            finish_text: "Finish".to_string(),
+            id_salt: None,
+        }
+    }
+
+    /// Add a step.
+    pub fn step(mut self, step: Step<'a>) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Set the layout direction.
+    pub fn orientation(mut self, orientation: StepperOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Show the Back and Next buttons under the active step (default: true).
+    pub fn show_controls(mut self, show: bool) -> Self {
+        self.show_controls = show;
+        self
+    }
+
+    /// In a linear stepper, clicking a step header only goes back to completed
+    /// steps; moving forward takes the Next button. Otherwise any enabled
+    /// step can be selected from its header (default: false).
+    pub fn linear(mut self, linear: bool) -> Self {
+        self.linear = linear;
+        self
+    }
+
+    /// Set the labels of the Back, Next and, on the last step, Finish buttons.
+    pub fn button_texts(
+        mut self,
+        back: impl Into<String>,
+        next: impl Into<String>,
+        finish: impl Into<String>,
+    ) -> Self {
+        self.back_text = back.into();
+        self.next_text = next.into();
+        self.finish_text = finish.into();
+        self
+    }
+
+    /// Set an id source, to tell apart several steppers in the same `Ui`.
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(egui::Id::new(id_salt));
+        self
+    }
+
+    /// Show the stepper, reporting the active step and the navigation this frame.
+    pub fn show(self, ui: &mut Ui) -> StepperResponse {
+        let MaterialStepper {
+            active,
+            mut steps,
+            orientation,
+            show_controls,
+            linear,
+            back_text,
+            next_text,
+            finish_text,
+            id_salt,
+        } = self;
+
+        if steps.is_empty() {
+            return StepperResponse {
+                response: ui.allocate_response(Vec2::ZERO, Sense::hover()),
+                active: *active,
+                next_clicked: false,
+                back_clicked: false,
+                step_clicked: None,
+                finished: false,
+            };
+        }
+
+        let id = id_salt.unwrap_or_else(|| ui.id().with("material_stepper"));
+        let current = (*active).min(steps.len() - 1);
+        let enabled: Vec<bool> = steps.iter().map(|step| step.enabled).collect();
+        let states: Vec<StepState> = steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| step.state(index, current))
+            .collect();
+        let clickable: Vec<bool> = states
+            .iter()
+            .enumerate()
+            .map(|(index, state)| match state {
+                StepState::Disabled | StepState::Active => false,
+                StepState::Completed => true,
+                StepState::Upcoming => !linear || index < current,
+            })
+            .collect();
+        let content = steps[current].content.take();
+        let can_go_back = adjacent_step(&enabled, current, false).is_some();
+        let is_last = adjacent_step(&enabled, current, true).is_none();
+
+        let mut step_clicked = None;
+        let mut back_clicked = false;
+        let mut next_clicked = false;
+
+        let show_panel = |ui: &mut Ui, back_clicked: &mut bool, next_clicked: &mut bool| {
+            if let Some(content) = content {
+                content(ui);
+            }
+            if show_controls {
+                ui.add_space(16.0);
+                ui.horizontal(|ui| {
+                    *back_clicked = ui
+                        .add(MaterialButton::text(back_text.as_str()).enabled(can_go_back))
+                        .clicked();
+                    let next_label = if is_last { &finish_text } else { &next_text };
+                    *next_clicked = ui
+                        .add(MaterialButton::filled(next_label.as_str()))
+                        .clicked();
+                });
+            }
+        };
+
+        let mut response = match orientation {
+            StepperOrientation::Horizontal => {
+                ui.vertical(|ui| {
+                    step_clicked = horizontal_headers(ui, id, &steps, &states, &clickable);
+                    ui.add_space(16.0);
+                    show_panel(ui, &mut back_clicked, &mut next_clicked);
+                })
+                .response
+            }
+            StepperOrientation::Vertical => {
+                ui.vertical(|ui| {
+                    let mut previous_circle: Option<(Pos2, StepState)> = None;
+                    let mut show_panel = Some(show_panel);
+                    for (index, (step, state)) in steps.iter().zip(&states).enumerate() {
+                        let header = step_header(ui, id, index, step, *state, clickable[index]);
+                        if header.clicked {
+                            step_clicked = Some(index);
+                        }
+
+                        // Connect to the previous circle, past its content panel
+                        if let Some((previous, previous_state)) = previous_circle {
+                            ui.painter().line_segment(
+                                [
+                                    previous + Vec2::new(0.0, CIRCLE_SIZE / 2.0 + 4.0),
+                                    header.circle_center - Vec2::new(0.0, CIRCLE_SIZE / 2.0 + 4.0),
+                                ],
+                                Stroke::new(1.0, connector_color(previous_state)),
+                            );
+                        }
+                        previous_circle = Some((header.circle_center, *state));
+
+                        if index == current {
+                            if let Some(show_panel) = show_panel.take() {
+                                ui.horizontal(|ui| {
+                                    ui.add_space(CIRCLE_SIZE + LABEL_GAP);
+                                    ui.vertical(|ui| {
+                                        show_panel(ui, &mut back_clicked, &mut next_clicked);
+                                        ui.add_space(8.0);
+                                    });
+                                });
+                            }
+                        }
+                    }
+                })
+                .response
+            }
+        };
+
+        let mut finished = false;
+        let mut new_active = current;
+        if back_clicked {
+            new_active = adjacent_step(&enabled, current, false).unwrap_or(current);
+        }
+        if next_clicked {
+            match adjacent_step(&enabled, current, true) {
+                Some(next) => new_active = next,
+                None => finished = true,
+            }
+        }
+        if let Some(index) = step_clicked {
+            new_active = index;
+        }
+        if new_active != *active {
+            *active = new_active;
+            response.mark_changed();
+        }
+
+        StepperResponse {
+            response,
+            active: *active,
+            next_clicked,
+            back_clicked,
+            step_clicked,
+            finished,
+        }
+    }
+}
+
+impl Widget for MaterialStepper<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+/// The nearest enabled step after (`forward`) or before `from`.
+fn adjacent_step(enabled: &[bool], from: usize, forward: bool) -> Option<usize> {
+    if forward {
+        (from + 1..enabled.len()).find(|&index| enabled[index])
+    } else {
+        (0..from.min(enabled.len()))
+            .rev()
+            .find(|&index| enabled[index])
+    }
+}
+
+fn connector_color(state: StepState) -> Color32 {
+    match state {
+        StepState::Completed => get_global_color("primary"),
+        _ => get_global_color("outlineVariant"),
+    }
+}
+
+/// Label and supporting text of a step header, laid out in the state's colors.
+fn header_galleys(
+    ui: &Ui,
+    step: &Step<'_>,
+    state: StepState,
+) -> (Arc<Galley>, Option<Arc<Galley>>) {
+    let (label_color, supporting_color) = match state {
+        StepState::Active => (
+            get_global_color("onSurface"),
+            get_global_color("onSurfaceVariant"),
+        ),
+        StepState::Disabled => {
+            let disabled = get_global_color("onSurface").linear_multiply(0.38);
+            (disabled, disabled)
+        }
+        StepState::Upcoming | StepState::Completed => {
+            let color = get_global_color("onSurfaceVariant");
+            (color, color)
+        }
+    };
+    let label = ui.painter().layout_no_wrap(
+        step.label.clone(),
+        type_font_id(TypeRole::TitleSmall),
+        label_color,
+    );
+    let supporting = step.supporting_text.as_ref().map(|text| {
+        ui.painter().layout_no_wrap(
+            text.clone(),
+            type_font_id(TypeRole::BodySmall),
+            supporting_color,
+        )
+    });
+    (label, supporting)
+}
+
+/// Width and height of a step header: circle, gap and the wider text line.
+fn header_size(label: &Galley, supporting: Option<&Arc<Galley>>) -> Vec2 {
+    let text_width = supporting.map_or(label.size().x, |s| label.size().x.max(s.size().x));
+    let text_height = label.size().y + supporting.map_or(0.0, |s| s.size().y);
+    Vec2::new(
+        CIRCLE_SIZE + LABEL_GAP + text_width,
+        CIRCLE_SIZE.max(text_height) + HEADER_PADDING * 2.0,
+    )
+}
+
+struct StepHeader {
+    circle_center: Pos2,
+    clicked: bool,
+}
+
+/// Allocate and paint a step header on its own row, for vertical steppers.
+fn step_header(
+    ui: &mut Ui,
+    id: egui::Id,
+    index: usize,
+    step: &Step<'_>,
+    state: StepState,
+    clickable: bool,
+) -> StepHeader {
+    let (label, supporting) = header_galleys(ui, step, state);
+    let size = header_size(&label, supporting.as_ref());
+    let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+    paint_step(
+        ui, id, index, step, state, clickable, rect, label, supporting,
+    )
+}
+
+/// Allocate and paint the row of step headers and connectors of a horizontal
+/// stepper, returning the index of a clicked header.
+fn horizontal_headers(
+    ui: &mut Ui,
+    id: egui::Id,
+    steps: &[Step<'_>],
+    states: &[StepState],
+    clickable: &[bool],
+) -> Option<usize> {
+    let galleys: Vec<_> = steps
+        .iter()
+        .zip(states)
+        .map(|(step, state)| header_galleys(ui, step, *state))
+        .collect();
+    let sizes: Vec<Vec2> = galleys
+        .iter()
+        .map(|(label, supporting)| header_size(label, supporting.as_ref()))
+        .collect();
+
+    let connectors = steps.len() - 1;
+    let headers_width: f32 = sizes.iter().map(|size| size.x).sum();
+    let connector_length = if connectors > 0 {
+        let free = ui.available_width() - headers_width - connectors as f32 * LABEL_GAP * 2.0;
+        (free / connectors as f32).max(MIN_CONNECTOR_LENGTH)
+    } else {
+        0.0
+    };
+    let height = sizes.iter().map(|size| size.y).fold(0.0, f32::max);
+    let width = headers_width + connectors as f32 * (connector_length + LABEL_GAP * 2.0);
+    let (rect, _) = ui.allocate_exact_size(Vec2::new(width, height), Sense::hover());
+
+    let mut clicked = None;
+    let mut x = rect.min.x;
+    for (index, ((step, state), (label, supporting))) in
+        steps.iter().zip(states).zip(galleys).enumerate()
+    {
+        let step_rect =
+            Rect::from_min_size(Pos2::new(x, rect.min.y), Vec2::new(sizes[index].x, height));
+        let header = paint_step(
+            ui,
+            id,
+            index,
+            step,
+            *state,
+            clickable[index],
+            step_rect,
+            label,
+            supporting,
+        );
+        if header.clicked {
+            clicked = Some(index);
+        }
+        x = step_rect.max.x;
+
+        if index < connectors {
+            let y = rect.center().y;
+            ui.painter().line_segment(
+                [
+                    Pos2::new(x + LABEL_GAP, y),
+                    Pos2::new(x + LABEL_GAP + connector_length, y),
+                ],
+                Stroke::new(1.0, connector_color(*state)),
+            );
+            x += connector_length + LABEL_GAP * 2.0;
+        }
+    }
+    clicked
+}
+
+/// Paint a step's circle and text in `rect`, handling clicks on the header.
+#[allow(clippy::too_many_arguments)]
+fn paint_step(
+    ui: &mut Ui,
+    id: egui::Id,
+    index: usize,
+    step: &Step<'_>,
+    state: StepState,
+    clickable: bool,
+    rect: Rect,
+    label: Arc<Galley>,
+    supporting: Option<Arc<Galley>>,
+) -> StepHeader {
+    let sense = if clickable {
+        Sense::click()
+    } else {
+        Sense::hover()
+    };
+    let response = ui.interact(rect, id.with(("step", index)), sense);
+    response.widget_info(|| {
+        WidgetInfo::selected(
+            WidgetType::SelectableLabel,
+            state != StepState::Disabled,
+            state == StepState::Active,
+            &step.label,
+        )
+    });
+
+    let on_surface = get_global_color("onSurface");
+    if clickable && response.hovered() {
+        ui.painter().rect_filled(
+            rect.expand2(Vec2::new(4.0, 0.0)),
+            4.0,
+            on_surface.linear_multiply(0.08),
+        );
+    }
+
+    // Circle with the step number, or a check mark once completed
+    let circle_center = Pos2::new(rect.min.x + CIRCLE_SIZE / 2.0, rect.center().y);
+    let (circle_color, mark_color) = match state {
+        StepState::Active | StepState::Completed => {
+            (get_global_color("primary"), get_global_color("onPrimary"))
+        }
+        StepState::Upcoming => (
+            get_global_color("surfaceContainerHighest"),
+            get_global_color("onSurfaceVariant"),
+        ),
+        StepState::Disabled => (
+            on_surface.linear_multiply(0.12),
+            on_surface.linear_multiply(0.38),
+        ),
+    };
+    ui.painter()
+        .circle_filled(circle_center, CIRCLE_SIZE / 2.0, circle_color);
+    if state == StepState::Completed {
+        ui.painter().text(
+            circle_center,
+            egui::Align2::CENTER_CENTER,
+            ICON_CHECK,
+            egui::FontId::proportional(18.0),
+            mark_color,
+        );
+    } else {
+        ui.painter().text(
+            circle_center,
+            egui::Align2::CENTER_CENTER,
+            (index + 1).to_string(),
+            type_font_id(TypeRole::LabelMedium),
+            mark_color,
+        );
+    }
+
+    // Label, with the supporting text under it
+    let text_height = label.size().y + supporting.as_ref().map_or(0.0, |s| s.size().y);
+    let text_x = rect.min.x + CIRCLE_SIZE + LABEL_GAP;
+    let mut text_y = rect.center().y - text_height / 2.0;
+    let label_height = label.size().y;
+    ui.painter()
+        .galley(Pos2::new(text_x, text_y), label, Color32::PLACEHOLDER);
+    text_y += label_height;
+    if let Some(supporting) = supporting {
+        ui.painter()
+            .galley(Pos2::new(text_x, text_y), supporting, Color32::PLACEHOLDER);
+    }
+
+    StepHeader {
+        circle_center,
+        clicked: response.clicked(),
+    }
+}
+
+/// Convenience function to create a stepper.
+pub fn stepper(active: &mut usize) -> MaterialStepper<'_> {
+    MaterialStepper::new(active)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjacent_step_skips_disabled() {
+        let enabled = [true, false, true, true];
+        assert_eq!(adjacent_step(&enabled, 0, true), Some(2));
+        assert_eq!(adjacent_step(&enabled, 2, false), Some(0));
+        assert_eq!(adjacent_step(&enabled, 3, true), None);
+        assert_eq!(adjacent_step(&enabled, 0, false), None);
+    }
+
+    #[test]
+    fn test_step_state() {
+        let step = Step::new("Details");
+        assert_eq!(step.state(0, 1), StepState::Completed);
+        assert_eq!(step.state(1, 1), StepState::Active);
+        assert_eq!(step.state(2, 1), StepState::Upcoming);
+
+        let step = Step::new("Review").completed(true);
+        assert_eq!(step.state(2, 1), StepState::Completed);
+        let step = Step::new("Review").enabled(false);
+        assert_eq!(step.state(1, 1), StepState::Disabled);
+    }
+}
@@ -27,9 +27,37 @@
 //! - **Thumb**: 16dp off (no icon), 24dp on or with icon, 28dp pressed
 //! - **Touch target**: 48x48dp minimum (40dp state layer)
 //! - **Icon**: 16dp on thumb
+//!
+//! ## Icons and Label
+//! `.icons(true)` draws the standard check mark on the selected thumb and an X on
+//! the unselected one; glyphs set with `.selected_icon()` / `.unselected_icon()`
+//! take precedence. `.with_label()` places a label after the track. The label and
+//! track form a single focusable target, so clicking either (or pressing Space/Enter
+//! while focused) toggles the switch and marks the response as changed.
 
 use crate::get_global_color;
-use egui::{self, Color32, FontId, Pos2, Rect, Response, Sense, Stroke, StrokeKind, Ui, Vec2, Widget};
+use egui::{
+    self, Color32, FontId, Pos2, Rect, Response, Sense, Stroke, StrokeKind, Ui, Vec2, Widget,
+    WidgetInfo, WidgetType,
+};
+
+/// Thumb diameter when off without an icon
+const THUMB_SIZE_SMALL: f32 = 16.0;
+/// Thumb diameter when on, or off with an icon
+const THUMB_SIZE_LARGE: f32 = 24.0;
+/// Thumb diameter while the pointer is held down
+const THUMB_SIZE_PRESSED: f32 = 28.0;
+
+/// M3 thumb diameter for the given state
+fn thumb_diameter(selected: bool, has_icon: bool, pressed: bool) -> f32 {
+    if pressed {
+        THUMB_SIZE_PRESSED
+    } else if selected || has_icon {
+        THUMB_SIZE_LARGE
+    } else {
+        THUMB_SIZE_SMALL
+    }
+}
 
 /// Material Design switch component following Material Design 3 specifications
 ///
@@ -49,6 +77,12 @@ use egui::{self, Color32, FontId, Pos2, Rect, Response, Sense, Stroke, StrokeKin
 /// ui.add(MaterialSwitch::new(&mut bluetooth_enabled)
 ///     .text("Enable Bluetooth"));
 ///
+/// // Switch with check/X icons and a clickable label
+/// let mut airplane_mode = false;
+/// ui.add(MaterialSwitch::new(&mut airplane_mode)
+///     .icons(true)
+///     .with_label("Airplane mode"));
+///
 /// // Disabled switch
 /// let mut disabled_option = false;
 /// ui.add(MaterialSwitch::new(&mut disabled_option)
@@ -77,6 +111,8 @@ pub struct MaterialSwitch<'a> {
     selected_icon: Option<char>,
     /// Optional icon displayed on thumb when unselected
     unselected_icon: Option<char>,
+    /// Whether to draw the default check/X glyphs on the thumb
+    icons: bool,
     /// Whether to show track outline (Material 3: true, Material 2: false)
     show_track_outline: bool,
 }
@@ -96,6 +132,7 @@ impl<'a> MaterialSwitch<'a> {
             enabled: true,
             selected_icon: None,
             unselected_icon: None,
+            icons: false,
             show_track_outline: true, // Material 3 default
         }
     }
@@ -111,6 +148,17 @@ impl<'a> MaterialSwitch<'a> {
         self
     }
 
+    /// Set the label displayed next to the track
+    ///
+    /// Same as [`text`](Self::text). The label is part of the switch's click target,
+    /// so clicking it toggles the switch.
+    ///
+    /// ## Parameters
+    /// - `label`: Label text to display next to the switch
+    pub fn with_label(self, label: impl Into<String>) -> Self {
+        self.text(label)
+    }
+
     /// Set whether the switch is enabled or disabled
     ///
     /// Disabled switches cannot be interacted with and are visually dimmed.
@@ -151,6 +199,18 @@ impl<'a> MaterialSwitch<'a> {
         self
     }
 
+    /// Show the default thumb icons: a check mark when on and an X when off
+    ///
+    /// Icons set with [`selected_icon`](Self::selected_icon) or
+    /// [`unselected_icon`](Self::unselected_icon) replace the defaults.
+    ///
+    /// ## Parameters
+    /// - `icons`: True to draw icons on the thumb in both states
+    pub fn icons(mut self, icons: bool) -> Self {
+        self.icons = icons;
+        self
+    }
+
     /// Set whether to show track outline (Material 3 style)
     ///
     /// ## Parameters
@@ -179,12 +239,26 @@ impl<'a> Widget for MaterialSwitch<'a> {
             Vec2::new(switch_width, switch_height)
         };
 
-        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+        // Label and track share one focusable target
+        let sense = if self.enabled {
+            Sense::click()
+        } else {
+            Sense::hover()
+        };
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, sense);
 
         if response.clicked() && self.enabled {
             *self.selected = !*self.selected;
             response.mark_changed();
         }
+        response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::Checkbox,
+                self.enabled,
+                *self.selected,
+                self.text.as_deref().unwrap_or(""),
+            )
+        });
 
         // Track interaction states
         let is_pressed = response.is_pointer_button_down_on();
@@ -211,32 +285,23 @@ impl<'a> Widget for MaterialSwitch<'a> {
             Rect::from_center_size(switch_rect.center(), Vec2::new(switch_width, track_height));
 
         // M3 thumb sizing: 16dp off (no icon), 24dp on or with icon, 28dp pressed
-        let has_icon = if *self.selected {
-            self.selected_icon.is_some()
-        } else {
-            self.unselected_icon.is_some()
-        };
-
-        let base_thumb_size_on = 24.0;
-        let base_thumb_size_off = if has_icon { 24.0 } else { 16.0 };
-        let pressed_thumb_size = 28.0;
-
-        let thumb_size = if is_pressed {
-            pressed_thumb_size
-        } else if *self.selected {
-            base_thumb_size_on
+        let current_icon = if *self.selected {
+            self.selected_icon
         } else {
-            base_thumb_size_off
+            self.unselected_icon
         };
+        let has_icon = current_icon.is_some() || self.icons;
+        let thumb_size = thumb_diameter(*self.selected, has_icon, is_pressed && self.enabled);
 
-        let thumb_travel = switch_width - base_thumb_size_on - 4.0;
+        // The thumb stays centered in the rounded end of the track, so it grows
+        // in place while pressed
         let thumb_x = if *self.selected {
-            switch_rect.min.x + 2.0 + thumb_travel
+            switch_rect.max.x - track_height / 2.0
         } else {
-            switch_rect.min.x + 2.0
+            switch_rect.min.x + track_height / 2.0
         };
 
-        let thumb_center = Pos2::new(thumb_x + thumb_size / 2.0, switch_rect.center().y);
+        let thumb_center = Pos2::new(thumb_x, switch_rect.center().y);
 
         // M3 color resolution based on state
         let (track_color, thumb_color, track_outline_color, icon_color) = if !self.enabled {
@@ -340,12 +405,6 @@ impl<'a> Widget for MaterialSwitch<'a> {
             .circle_filled(thumb_center, thumb_size / 2.0, thumb_color);
 
         // Draw icon on thumb if present
-        let current_icon = if *self.selected {
-            self.selected_icon
-        } else {
-            self.unselected_icon
-        };
-
         if let Some(icon) = current_icon {
             let icon_size = 16.0;
             let icon_font = FontId::proportional(icon_size);
@@ -357,6 +416,26 @@ impl<'a> Widget for MaterialSwitch<'a> {
                 icon_font,
                 icon_color,
             );
+        } else if self.icons {
+            // Default glyphs drawn as strokes within the 16dp icon box
+            let stroke = Stroke::new(2.0, icon_color);
+            let c = thumb_center;
+            if *self.selected {
+                ui.painter().add(egui::Shape::line(
+                    vec![
+                        c + Vec2::new(-5.0, 0.5),
+                        c + Vec2::new(-1.5, 4.0),
+                        c + Vec2::new(5.5, -3.5),
+                    ],
+                    stroke,
+                ));
+            } else {
+                let d = 4.0;
+                ui.painter()
+                    .line_segment([c + Vec2::new(-d, -d), c + Vec2::new(d, d)], stroke);
+                ui.painter()
+                    .line_segment([c + Vec2::new(-d, d), c + Vec2::new(d, -d)], stroke);
+            }
         }
 
         // Draw label text
@@ -386,3 +465,17 @@ impl<'a> Widget for MaterialSwitch<'a> {
 pub fn switch(selected: &mut bool) -> MaterialSwitch<'_> {
     MaterialSwitch::new(selected)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thumb_diameter_follows_spec() {
+        assert_eq!(thumb_diameter(false, false, false), 16.0);
+        assert_eq!(thumb_diameter(false, true, false), 24.0);
+        assert_eq!(thumb_diameter(true, false, false), 24.0);
+        assert_eq!(thumb_diameter(true, true, true), 28.0);
+        assert_eq!(thumb_diameter(false, false, true), 28.0);
+    }
+}
@@ -24,9 +24,43 @@
 //! - **Height**: 46dp (text only), 72dp (with icons)
 //! - **Indicator**: 3dp (primary), 2dp (secondary), 3dp top corner radius
 //! - **Min touch target**: 48x48dp
+//!
+//! # Tab Panels
+//!
+//! [`MaterialTabs::show_with_panels`] shows the tab bar followed by the content
+//! of the selected tab, so callers don't need to `match` on the index
+//! themselves. Only the selected panel is rendered each frame; inactive panels
+//! cost nothing, while each keeps its own id scope so widget state such as
+//! scroll offsets survives switching away and back. Changing tabs crossfades
+//! from the old panel to the new one unless [`MaterialTabs::crossfade`] is off
+//! or reduced motion is on.
+//!
+//! ```rust
+//! # egui::__run_test_ui(|ui| {
+//! use egui_material3::tabs_primary;
+//!
+//! let mut selected = 0;
+//! tabs_primary(&mut selected)
+//!     .tab("Songs")
+//!     .tab("Albums")
+//!     .show_with_panels(ui, |ui, index| match index {
+//!         0 => {
+//!             ui.label("All songs");
+//!         }
+//!         _ => {
+//!             ui.label("All albums");
+//!         }
+//!     });
+//! # });
+//! ```
 
+use crate::badge::MaterialBadge;
 use crate::get_global_color;
-use egui::{self, Color32, FontId, Pos2, Rect, Response, Sense, Ui, Vec2, Widget};
+use crate::theme::{motion_duration, type_font_id, TypeRole};
+use egui::{
+    self, accesskit, Color32, FontId, Pos2, Rect, Response, Sense, Ui, UiBuilder, Vec2, Widget,
+    WidgetInfo, WidgetType,
+};
 use egui::epaint::CornerRadius;
 
 /// Material Design tabs component.
@@ -59,6 +93,12 @@ pub struct MaterialTabs<'a> {
     id_salt: Option<String>,
     /// Optional custom height for the tab bar
     height: Option<f32>,
+    /// Whether tabs can be reordered by dragging
+    reorderable: bool,
+    /// Whether the tab row scrolls horizontally instead of squeezing tabs
+    scrollable: bool,
+    /// Whether managed panels crossfade when the selection changes
+    crossfade: bool,
 }
 
 /// Individual tab item data.
@@ -67,6 +107,64 @@ pub struct TabItem {
     label: String,
     /// Optional icon for the tab
     icon: Option<String>,
+    /// Whether the tab shows a close button
+    closable: bool,
+    /// Optional badge shown on the tab's icon or label
+    badge: Option<MaterialBadge>,
+}
+
+impl TabItem {
+    /// Create a tab with a text label.
+    ///
+    /// # Example
+    /// ```rust
+    /// let item = TabItem::new("main.rs").icon("description").closable(true);
+    /// ```
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            closable: false,
+            badge: None,
+        }
+    }
+
+    /// Set the icon shown above the label.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Show a close button at the end of the tab.
+    ///
+    /// Clicking it reports the tab in [`TabsResponse::closed`] without selecting it.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Show a badge on the top-right of the tab's icon, or of its label for text-only tabs.
+    ///
+    /// # Example
+    /// ```rust
+    /// let item = TabItem::new("Inbox").icon("inbox").badge(MaterialBadge::count(3));
+    /// ```
+    pub fn badge(mut self, badge: MaterialBadge) -> Self {
+        self.badge = Some(badge);
+        self
+    }
+}
+
+/// Response from showing [`MaterialTabs`]
+pub struct TabsResponse {
+    /// The response of the whole tab bar
+    pub response: Response,
+    /// Index of the selected tab after this frame
+    pub selected: usize,
+    /// Index of the tab whose close button was clicked this frame
+    pub closed: Option<usize>,
+    /// `(from, to)` when a dragged tab moved past its neighbor this frame
+    pub reordered: Option<(usize, usize)>,
 }
 
 /// Visual variants for tabs component.
@@ -100,6 +198,9 @@ impl<'a> MaterialTabs<'a> {
             variant,
             id_salt: None,
             height: None,
+            reorderable: false,
+            scrollable: false,
+            crossfade: true,
         }
     }
 
@@ -154,10 +255,7 @@ impl<'a> MaterialTabs<'a> {
     /// # });
     /// ```
     pub fn tab(mut self, label: impl Into<String>) -> Self {
-        self.tabs.push(TabItem {
-            label: label.into(),
-            icon: None,
-        });
+        self.tabs.push(TabItem::new(label));
         self
     }
 
@@ -177,10 +275,62 @@ impl<'a> MaterialTabs<'a> {
     /// # });
     /// ```
     pub fn tab_with_icon(mut self, label: impl Into<String>, icon: impl Into<String>) -> Self {
-        self.tabs.push(TabItem {
-            label: label.into(),
-            icon: Some(icon.into()),
-        });
+        self.tabs.push(TabItem::new(label).icon(icon));
+        self
+    }
+
+    /// Add a fully configured tab item.
+    ///
+    /// # Arguments
+    /// * `item` - The tab, e.g. a closable one
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut tab_index = 0;
+    /// let response = MaterialTabs::secondary(&mut tab_index)
+    ///     .item(TabItem::new("main.rs").closable(true))
+    ///     .item(TabItem::new("lib.rs").closable(true))
+    ///     .show(ui);
+    /// if let Some(index) = response.closed {
+    ///     println!("Close tab {index}");
+    /// }
+    /// # });
+    /// ```
+    pub fn item(mut self, item: TabItem) -> Self {
+        self.tabs.push(item);
+        self
+    }
+
+    /// Allow reordering tabs by dragging them.
+    ///
+    /// Dragging a tab past the center of its neighbor reports the move in
+    /// [`TabsResponse::reordered`]; the caller is expected to apply it to its
+    /// own tab list. The selected index follows the moved tab.
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Make the tab row horizontally scrollable (M3 scrollable tabs).
+    ///
+    /// Tabs size to their content instead of sharing the available width.
+    /// The selected tab is kept scrolled into view, and fades at the edges hint
+    /// at tabs hidden to the left or right.
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut tab_index = 0;
+    /// let mut tabs = MaterialTabs::primary(&mut tab_index).scrollable(true);
+    /// for i in 1..=20 {
+    ///     tabs = tabs.tab(format!("Tab {i}"));
+    /// }
+    /// ui.add(tabs);
+    /// # });
+    /// ```
+    pub fn scrollable(mut self, scrollable: bool) -> Self {
+        self.scrollable = scrollable;
         self
     }
 
@@ -235,6 +385,14 @@ impl<'a> MaterialTabs<'a> {
         self.height = Some(height);
         self
     }
+
+    /// Crossfade between panels shown by [`Self::show_with_panels`] (default: true).
+    ///
+    /// With crossfade off, the new panel replaces the old one immediately.
+    pub fn crossfade(mut self, crossfade: bool) -> Self {
+        self.crossfade = crossfade;
+        self
+    }
 }
 
 /// M3 tab height constants
@@ -246,20 +404,257 @@ const SECONDARY_INDICATOR_HEIGHT: f32 = 2.0;
 const INDICATOR_TOP_ROUNDING: f32 = 3.0;
 /// M3 divider
 const DIVIDER_HEIGHT: f32 = 1.0;
-/// M3 label font size
-const LABEL_FONT_SIZE: f32 = 14.0;
 const ICON_FONT_SIZE: f32 = 18.0;
 
-impl<'a> Widget for MaterialTabs<'a> {
-    fn ui(self, ui: &mut Ui) -> Response {
+/// Width reserved at the end of a closable tab for its close button
+const CLOSE_BUTTON_AREA: f32 = 32.0;
+/// Duration of the active indicator slide, in seconds
+const INDICATOR_ANIMATION_S: f32 = 0.25;
+/// M3 scrollable tabs: horizontal label padding and minimum tab width
+const SCROLLABLE_TAB_PADDING: f32 = 24.0;
+const SCROLLABLE_TAB_MIN_WIDTH: f32 = 90.0;
+/// Width of the fades drawn where scrollable tabs overflow
+const EDGE_FADE_WIDTH: f32 = 32.0;
+/// Duration of the crossfade between tab panels, in seconds
+const PANEL_CROSSFADE_S: f32 = 0.15;
+
+/// Which panel is shown, and which one is fading out since when
+#[derive(Clone, Copy)]
+struct PanelTransition {
+    shown: usize,
+    fading: Option<usize>,
+    started: f64,
+}
+
+/// Width a tab needs in a scrollable tab row.
+fn scrollable_tab_width(ui: &Ui, tab: &TabItem) -> f32 {
+    let label_width = ui
+        .painter()
+        .layout_no_wrap(
+            tab.label.clone(),
+            type_font_id(TypeRole::TitleSmall),
+            Color32::PLACEHOLDER,
+        )
+        .size()
+        .x;
+    let icon_width = tab.icon.as_ref().map_or(0.0, |icon| {
+        ui.painter()
+            .layout_no_wrap(
+                icon.clone(),
+                FontId::proportional(ICON_FONT_SIZE),
+                Color32::PLACEHOLDER,
+            )
+            .size()
+            .x
+    });
+    let close_width = if tab.closable { CLOSE_BUTTON_AREA } else { 0.0 };
+    (label_width.max(icon_width) + SCROLLABLE_TAB_PADDING * 2.0 + close_width)
+        .max(SCROLLABLE_TAB_MIN_WIDTH)
+}
+
+/// Paint a horizontal gradient from `color` at one edge of `rect` to transparent at the other.
+fn paint_edge_fade(painter: &egui::Painter, rect: Rect, color: Color32, opaque_left: bool) {
+    let (left, right) = if opaque_left {
+        (color, Color32::TRANSPARENT)
+    } else {
+        (Color32::TRANSPARENT, color)
+    };
+    let mut mesh = egui::Mesh::default();
+    mesh.colored_vertex(rect.left_top(), left);
+    mesh.colored_vertex(rect.right_top(), right);
+    mesh.colored_vertex(rect.right_bottom(), right);
+    mesh.colored_vertex(rect.left_bottom(), left);
+    mesh.add_triangle(0, 1, 2);
+    mesh.add_triangle(0, 2, 3);
+    painter.add(mesh);
+}
+
+impl MaterialTabs<'_> {
+    fn bar_id(&self, ui: &Ui) -> egui::Id {
+        if let Some(ref salt) = self.id_salt {
+            egui::Id::new((salt, "tabs"))
+        } else {
+            ui.id().with("material_tabs")
+        }
+    }
+
+    /// Show the tabs followed by the selected tab's panel.
+    ///
+    /// `add_panel` is called with the index of the selected tab and only renders
+    /// that panel; while a crossfade runs it is also called for the previous
+    /// tab. Each panel gets its own id scope so its widget state is kept.
+    ///
+    /// # Example
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut tab_index = 0;
+    /// MaterialTabs::secondary(&mut tab_index)
+    ///     .tab("Overview")
+    ///     .tab("Specifications")
+    ///     .show_with_panels(ui, |ui, index| {
+    ///         ui.label(format!("Panel {index}"));
+    ///     });
+    /// # });
+    /// ```
+    pub fn show_with_panels(
+        self,
+        ui: &mut Ui,
+        mut add_panel: impl FnMut(&mut Ui, usize),
+    ) -> TabsResponse {
+        let transition_id = self.bar_id(ui).with("panel_transition");
+        let duration = if self.crossfade {
+            motion_duration(ui.ctx(), PANEL_CROSSFADE_S)
+        } else {
+            0.0
+        };
+        let tabs = self.show(ui);
+        let selected = tabs.selected;
+
+        let now = ui.input(|i| i.time);
+        let mut transition = ui
+            .ctx()
+            .data(|d| d.get_temp::<PanelTransition>(transition_id))
+            .unwrap_or(PanelTransition {
+                shown: selected,
+                fading: None,
+                started: f64::NEG_INFINITY,
+            });
+        if transition.shown != selected {
+            transition = PanelTransition {
+                shown: selected,
+                fading: Some(transition.shown),
+                started: now,
+            };
+        }
+        let t = if duration > 0.0 {
+            ((now - transition.started) as f32 / duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        if t >= 1.0 {
+            transition.fading = None;
+        }
+        ui.ctx()
+            .data_mut(|d| d.insert_temp(transition_id, transition));
+
+        if let Some(previous) = transition.fading {
+            // The outgoing panel is painted underneath without taking up space
+            let mut outgoing = ui.new_child(
+                UiBuilder::new()
+                    .id_salt(("tab_panel", previous))
+                    .max_rect(ui.available_rect_before_wrap())
+                    .layout(*ui.layout()),
+            );
+            outgoing.multiply_opacity(1.0 - t);
+            add_panel(&mut outgoing, previous);
+            ui.ctx().request_repaint();
+        }
+        ui.scope_builder(UiBuilder::new().id_salt(("tab_panel", selected)), |ui| {
+            ui.multiply_opacity(t);
+            add_panel(ui, selected);
+        });
+
+        tabs
+    }
+
+    /// Show the tabs, reporting selection, close and reorder events.
+    pub fn show(mut self, ui: &mut Ui) -> TabsResponse {
         let has_icons = self.tabs.iter().any(|t| t.icon.is_some());
         let tab_height = self
             .height
             .unwrap_or(if has_icons { TAB_HEIGHT_WITH_ICON } else { TAB_HEIGHT_TEXT_ONLY });
-        let tab_width = ui.available_width() / self.tabs.len().max(1) as f32;
+        let available_width = ui.available_width();
+
+        let bar_id = self.bar_id(ui);
+
+        if !self.scrollable {
+            let tab_width = available_width / self.tabs.len().max(1) as f32;
+            let widths = vec![tab_width; self.tabs.len()];
+            return self.show_strip(ui, bar_id, &widths, Vec2::new(available_width, tab_height));
+        }
 
-        let desired_size = Vec2::new(ui.available_width(), tab_height);
+        let widths: Vec<f32> = self
+            .tabs
+            .iter()
+            .map(|tab| scrollable_tab_width(ui, tab))
+            .collect();
+        let content_width = widths.iter().sum::<f32>().max(available_width);
+        let mut scroll_area = egui::ScrollArea::horizontal()
+            .id_salt(bar_id.with("scroll"))
+            .scroll_bar_visibility(egui::scroll_area::ScrollBarVisibility::AlwaysHidden);
+
+        // Scroll just enough to show the selected tab whenever the selection changes
+        let scrolled_id = bar_id.with("scrolled_to");
+        let selected = *self.selected;
+        let scrolled_to = ui.ctx().data(|d| d.get_temp::<usize>(scrolled_id));
+        if selected < widths.len() && scrolled_to != Some(selected) {
+            let start: f32 = widths[..selected].iter().sum();
+            let end = start + widths[selected];
+            let scroll_id = ui.make_persistent_id(bar_id.with("scroll"));
+            let current = egui::scroll_area::State::load(ui.ctx(), scroll_id)
+                .map_or(0.0, |state| state.offset.x);
+            let target = if start < current {
+                start
+            } else if end > current + available_width {
+                end - available_width
+            } else {
+                current
+            };
+            scroll_area = scroll_area.horizontal_scroll_offset(target);
+            ui.ctx().data_mut(|d| d.insert_temp(scrolled_id, selected));
+        }
+
+        let output = scroll_area.show(ui, |ui| {
+            self.show_strip(ui, bar_id, &widths, Vec2::new(content_width, tab_height))
+        });
+
+        // Fade the edges where more tabs are hidden
+        let viewport = output.inner_rect;
+        let offset = output.state.offset.x;
+        let max_offset = (output.content_size.x - viewport.width()).max(0.0);
+        let fade_color = match self.variant {
+            TabVariant::Primary => get_global_color("surfaceContainer"),
+            TabVariant::Secondary => get_global_color("surface"),
+        };
+        let fade_height = viewport.height() - DIVIDER_HEIGHT;
+        if offset > 0.5 {
+            let fade_rect =
+                Rect::from_min_size(viewport.min, Vec2::new(EDGE_FADE_WIDTH, fade_height));
+            paint_edge_fade(ui.painter(), fade_rect, fade_color, true);
+        }
+        if offset < max_offset - 0.5 {
+            let fade_rect = Rect::from_min_size(
+                Pos2::new(viewport.max.x - EDGE_FADE_WIDTH, viewport.min.y),
+                Vec2::new(EDGE_FADE_WIDTH, fade_height),
+            );
+            paint_edge_fade(ui.painter(), fade_rect, fade_color, false);
+        }
+
+        output.inner
+    }
+
+    /// Lay out and paint the tab row with the given tab widths.
+    fn show_strip(
+        &mut self,
+        ui: &mut Ui,
+        bar_id: egui::Id,
+        widths: &[f32],
+        desired_size: Vec2,
+    ) -> TabsResponse {
+        let tab_height = desired_size.y;
         let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::hover());
+        ui.ctx().accesskit_node_builder(response.id, |node| {
+            node.set_role(accesskit::Role::TabList);
+        });
+        let tab_rects: Vec<Rect> = widths
+            .iter()
+            .scan(rect.min.x, |x, width| {
+                let tab_rect =
+                    Rect::from_min_size(Pos2::new(*x, rect.min.y), Vec2::new(*width, tab_height));
+                *x += width;
+                Some(tab_rect)
+            })
+            .collect();
 
         // M3 Color Roles - Tabs
         let primary = get_global_color("primary"); // Selected tab indicator
@@ -276,17 +671,19 @@ impl<'a> Widget for MaterialTabs<'a> {
         };
         ui.painter().rect_filled(rect, 0.0, bg_color);
 
+        let drag_id = bar_id.with("dragged_tab");
+        let mut dragged = ui.ctx().data(|d| d.get_temp::<usize>(drag_id));
+
         // Draw tabs
         let mut any_clicked = false;
-        let label_font = FontId::proportional(LABEL_FONT_SIZE);
-        let icon_font = FontId::proportional(ICON_FONT_SIZE);
+        let mut closed = None;
+        let mut reordered = None;
+        let mut indicator_target = None;
 
-        for (index, tab) in self.tabs.iter().enumerate() {
-            let tab_rect = Rect::from_min_size(
-                Pos2::new(rect.min.x + index as f32 * tab_width, rect.min.y),
-                Vec2::new(tab_width, tab_height),
-            );
+        let label_font = type_font_id(TypeRole::TitleSmall);
+        let icon_font = FontId::proportional(ICON_FONT_SIZE);
 
+        for (index, (tab, &tab_rect)) in self.tabs.iter().zip(&tab_rects).enumerate() {
             // Create unique ID for each tab using optional salt
             let tab_id = if let Some(ref salt) = self.id_salt {
                 egui::Id::new((salt, "tab", index))
@@ -294,10 +691,36 @@ impl<'a> Widget for MaterialTabs<'a> {
                 egui::Id::new(("tab", index))
             };
 
-            let tab_response = ui.interact(tab_rect, tab_id, Sense::click());
+            let sense = if self.reorderable {
+                Sense::click_and_drag()
+            } else {
+                Sense::click()
+            };
+            let tab_response = ui.interact(tab_rect, tab_id, sense);
+
+            // The close button sits on top of the tab so its clicks never select the tab
+            let content_rect = if tab.closable {
+                Rect::from_min_max(
+                    tab_rect.min,
+                    Pos2::new(tab_rect.max.x - CLOSE_BUTTON_AREA, tab_rect.max.y),
+                )
+            } else {
+                tab_rect
+            };
+            let close_response = tab.closable.then(|| {
+                let close_rect = Rect::from_center_size(
+                    Pos2::new(
+                        tab_rect.max.x - CLOSE_BUTTON_AREA / 2.0,
+                        tab_rect.center().y,
+                    ),
+                    Vec2::splat(24.0),
+                );
+                ui.interact(close_rect, tab_id.with("close"), Sense::click())
+            });
 
             let is_selected = *self.selected == index;
             let is_hovered = tab_response.hovered();
+            let is_dragged = dragged == Some(index);
 
             // M3 label colors per variant
             let text_color = match self.variant {
@@ -324,8 +747,8 @@ impl<'a> Widget for MaterialTabs<'a> {
                 text_color.linear_multiply(0.38)
             };
 
-            // M3 state layer (hover overlay)
-            if is_hovered && self.enabled {
+            // M3 state layer (hover overlay, stronger while dragged)
+            if (is_hovered || is_dragged) && self.enabled {
                 let state_layer_color = match self.variant {
                     TabVariant::Primary => primary,
                     TabVariant::Secondary => on_surface,
@@ -334,7 +757,7 @@ impl<'a> Widget for MaterialTabs<'a> {
                     state_layer_color.r(),
                     state_layer_color.g(),
                     state_layer_color.b(),
-                    20, // ~8% opacity
+                    if is_dragged { 31 } else { 20 }, // ~12% / ~8% opacity
                 );
                 ui.painter().rect_filled(tab_rect, 0.0, hover_color);
             }
@@ -345,15 +768,40 @@ impl<'a> Widget for MaterialTabs<'a> {
                 any_clicked = true;
             }
 
+            // Dragging a tab selects it, like in a browser
+            if self.reorderable && self.enabled && tab_response.drag_started() {
+                dragged = Some(index);
+                if *self.selected != index {
+                    *self.selected = index;
+                    any_clicked = true;
+                }
+            }
+
+            // Expose the tab to screen readers with its selection state
+            let now_selected = *self.selected == index;
+            tab_response.widget_info(|| {
+                WidgetInfo::selected(
+                    WidgetType::SelectableLabel,
+                    self.enabled,
+                    now_selected,
+                    &tab.label,
+                )
+            });
+            ui.ctx().accesskit_node_builder(tab_id, |node| {
+                node.set_role(accesskit::Role::Tab);
+                node.clear_toggled();
+                node.set_selected(now_selected);
+            });
+
             // Layout and draw tab content
             if let Some(icon) = &tab.icon {
                 // Icon + text layout: icon above label
-                let icon_y = tab_rect.center().y - 10.0;
-                let label_y = tab_rect.center().y + 12.0;
+                let icon_y = content_rect.center().y - 10.0;
+                let label_y = content_rect.center().y + 12.0;
 
                 // Draw icon as text (emoji/unicode)
                 ui.painter().text(
-                    Pos2::new(tab_rect.center().x, icon_y),
+                    Pos2::new(content_rect.center().x, icon_y),
                     egui::Align2::CENTER_CENTER,
                     icon,
                     icon_font.clone(),
@@ -362,7 +810,7 @@ impl<'a> Widget for MaterialTabs<'a> {
 
                 // Draw label text
                 ui.painter().text(
-                    Pos2::new(tab_rect.center().x, label_y),
+                    Pos2::new(content_rect.center().x, label_y),
                     egui::Align2::CENTER_CENTER,
                     &tab.label,
                     label_font.clone(),
@@ -371,7 +819,7 @@ impl<'a> Widget for MaterialTabs<'a> {
             } else {
                 // Text-only layout: centered
                 ui.painter().text(
-                    tab_rect.center(),
+                    content_rect.center(),
                     egui::Align2::CENTER_CENTER,
                     &tab.label,
                     label_font.clone(),
@@ -379,9 +827,63 @@ impl<'a> Widget for MaterialTabs<'a> {
                 );
             }
 
-            // Draw indicator for selected tab
+            // Badge on the top-right of the icon, or of the label for text-only tabs
+            if let Some(badge) = &tab.badge {
+                let anchor = if tab.icon.is_some() {
+                    Rect::from_center_size(
+                        Pos2::new(content_rect.center().x, content_rect.center().y - 10.0),
+                        Vec2::splat(ICON_FONT_SIZE),
+                    )
+                } else {
+                    let label_size = ui
+                        .painter()
+                        .layout_no_wrap(tab.label.clone(), label_font.clone(), text_color)
+                        .size();
+                    Rect::from_center_size(content_rect.center(), label_size)
+                };
+                badge.draw_on_rect(ui, anchor);
+            }
+
+            // Draw close button
+            if let Some(close_response) = close_response {
+                let center = close_response.rect.center();
+                if close_response.hovered() && self.enabled {
+                    ui.painter()
+                        .circle_filled(center, 12.0, on_surface.linear_multiply(0.08));
+                }
+                let half = 4.5;
+                let stroke = egui::Stroke::new(1.5, text_color);
+                ui.painter().line_segment(
+                    [
+                        center + Vec2::new(-half, -half),
+                        center + Vec2::new(half, half),
+                    ],
+                    stroke,
+                );
+                ui.painter().line_segment(
+                    [
+                        center + Vec2::new(half, -half),
+                        center + Vec2::new(-half, half),
+                    ],
+                    stroke,
+                );
+
+                close_response.widget_info(|| {
+                    WidgetInfo::labeled(
+                        WidgetType::Button,
+                        self.enabled,
+                        format!("Close {}", tab.label),
+                    )
+                });
+                if close_response.clicked() && self.enabled {
+                    closed = Some(index);
+                }
+                response = response.union(close_response);
+            }
+
+            // Remember where the indicator belongs for the selected tab
             if is_selected && self.enabled {
-                match self.variant {
+                indicator_target = Some(match self.variant {
                     TabVariant::Primary => {
                         // M3: indicator width matches label, top-rounded corners
                         let galley = ui.painter().layout_no_wrap(
@@ -390,33 +892,89 @@ impl<'a> Widget for MaterialTabs<'a> {
                             text_color,
                         );
                         let label_width = galley.size().x + 16.0; // add padding
-                        let indicator_x =
-                            tab_rect.center().x - label_width / 2.0;
-                        let indicator_rect = Rect::from_min_size(
+                        let indicator_x = content_rect.center().x - label_width / 2.0;
+                        Rect::from_min_size(
                             Pos2::new(indicator_x, tab_rect.max.y - PRIMARY_INDICATOR_HEIGHT),
                             Vec2::new(label_width, PRIMARY_INDICATOR_HEIGHT),
-                        );
-                        let rounding = CornerRadius {
-                            nw: INDICATOR_TOP_ROUNDING as u8,
-                            ne: INDICATOR_TOP_ROUNDING as u8,
-                            sw: 0,
-                            se: 0,
-                        };
-                        ui.painter()
-                            .rect_filled(indicator_rect, rounding, primary);
+                        )
                     }
                     TabVariant::Secondary => {
                         // M3: full tab width underline, primary color
-                        let indicator_rect = Rect::from_min_size(
+                        Rect::from_min_size(
                             Pos2::new(tab_rect.min.x, tab_rect.max.y - SECONDARY_INDICATOR_HEIGHT),
-                            Vec2::new(tab_width, SECONDARY_INDICATOR_HEIGHT),
-                        );
-                        ui.painter()
-                            .rect_filled(indicator_rect, 0.0, primary);
+                            Vec2::new(tab_rect.width(), SECONDARY_INDICATOR_HEIGHT),
+                        )
+                    }
+                });
+            }
+        }
+
+        // Move a dragged tab one slot once the pointer passes its neighbor's center
+        if let Some(from) = dragged {
+            let (pointer_down, pointer_pos) =
+                ui.input(|i| (i.pointer.primary_down(), i.pointer.interact_pos()));
+            if !pointer_down || !self.reorderable || from >= self.tabs.len() {
+                dragged = None;
+            } else if let Some(pos) = pointer_pos {
+                let center_of = |index: usize| tab_rects[index].center().x;
+                let to = if from + 1 < self.tabs.len() && pos.x > center_of(from + 1) {
+                    Some(from + 1)
+                } else if from > 0 && pos.x < center_of(from - 1) {
+                    Some(from - 1)
+                } else {
+                    None
+                };
+                if let Some(to) = to {
+                    reordered = Some((from, to));
+                    dragged = Some(to);
+                    if *self.selected == from {
+                        *self.selected = to;
+                    } else if *self.selected == to {
+                        *self.selected = from;
                     }
                 }
             }
         }
+        ui.ctx().data_mut(|d| match dragged {
+            Some(index) => d.insert_temp(drag_id, index),
+            None => d.remove::<usize>(drag_id),
+        });
+
+        // Ask the scroll area to bring the tab fully into view on the next frame,
+        // also when clicking an already selected but partially visible tab
+        if self.scrollable && (any_clicked || reordered.is_some()) {
+            ui.ctx()
+                .data_mut(|d| d.remove::<usize>(bar_id.with("scrolled_to")));
+            ui.ctx().request_repaint();
+        }
+
+        // Slide the active indicator towards the selected tab. Positions are
+        // animated relative to the row so scrolling never makes it lag behind.
+        if let Some(target) = indicator_target {
+            let ctx = ui.ctx();
+            let left = ctx.animate_value_with_time(
+                bar_id.with("indicator_left"),
+                target.min.x - rect.min.x,
+                motion_duration(ctx, INDICATOR_ANIMATION_S),
+            );
+            let right = ctx.animate_value_with_time(
+                bar_id.with("indicator_right"),
+                target.max.x - rect.min.x,
+                motion_duration(ctx, INDICATOR_ANIMATION_S),
+            );
+            let indicator_rect =
+                Rect::from_x_y_ranges((rect.min.x + left)..=(rect.min.x + right), target.y_range());
+            let rounding = match self.variant {
+                TabVariant::Primary => CornerRadius {
+                    nw: INDICATOR_TOP_ROUNDING as u8,
+                    ne: INDICATOR_TOP_ROUNDING as u8,
+                    sw: 0,
+                    se: 0,
+                },
+                TabVariant::Secondary => CornerRadius::ZERO,
+            };
+            ui.painter().rect_filled(indicator_rect, rounding, primary);
+        }
 
         // M3: Draw bottom divider for both variants
         let divider_rect = Rect::from_min_size(
@@ -428,7 +986,18 @@ impl<'a> Widget for MaterialTabs<'a> {
         if any_clicked {
             response.mark_changed();
         }
-        response
+        TabsResponse {
+            response,
+            selected: *self.selected,
+            closed,
+            reordered,
+        }
+    }
+}
+
+impl Widget for MaterialTabs<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
     }
 }
 
@@ -471,3 +1040,89 @@ pub fn tabs_primary<'a>(selected: &'a mut usize) -> MaterialTabs<'a> {
 pub fn tabs_secondary<'a>(selected: &'a mut usize) -> MaterialTabs<'a> {
     MaterialTabs::secondary(selected)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui_kittest::Harness;
+
+    #[test]
+    fn test_show_with_panels_renders_only_selected_panel() {
+        let ctx = egui::Context::default();
+        let mut selected = 1;
+        let mut rendered = Vec::new();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let response = tabs_primary(&mut selected)
+                    .tab("One")
+                    .tab("Two")
+                    .tab("Three")
+                    .show_with_panels(ui, |ui, index| {
+                        rendered.push(index);
+                        ui.label(format!("Panel {index}"));
+                    });
+                assert_eq!(response.selected, 1);
+            });
+        });
+        assert_eq!(rendered, vec![1]);
+    }
+
+    #[test]
+    fn test_close_button_reports_closed_tab_without_selecting_it() {
+        let mut harness = Harness::new_ui_state(
+            |ui, (selected, closed): &mut (usize, Vec<usize>)| {
+                let response = MaterialTabs::secondary(selected)
+                    .item(TabItem::new("main.rs").closable(true))
+                    .item(TabItem::new("lib.rs").closable(true))
+                    .show(ui);
+                closed.extend(response.closed);
+            },
+            (0, Vec::new()),
+        );
+
+        harness.get_by_label("Close lib.rs").click();
+        harness.run();
+
+        assert_eq!(harness.state(), &(0, vec![1]));
+    }
+
+    #[test]
+    fn test_dragging_tab_past_neighbor_reports_reorder() {
+        let mut harness = Harness::new_ui_state(
+            |ui, (selected, bar_rect, reordered): &mut (usize, Rect, Vec<(usize, usize)>)| {
+                let response = MaterialTabs::secondary(selected)
+                    .tab("One")
+                    .tab("Two")
+                    .tab("Three")
+                    .reorderable(true)
+                    .show(ui);
+                *bar_rect = response.response.rect;
+                reordered.extend(response.reordered);
+            },
+            (0, Rect::NOTHING, Vec::new()),
+        );
+
+        // Grab the first tab and drop it just past the center of the second
+        let bar_rect = harness.state().1;
+        let tab_width = bar_rect.width() / 3.0;
+        let grab = Pos2::new(bar_rect.min.x + tab_width * 0.5, bar_rect.center().y);
+        let drop = Pos2::new(bar_rect.min.x + tab_width * 1.5 + 10.0, grab.y);
+        for (pos, pressed) in [(grab, Some(true)), (drop, None), (drop, Some(false))] {
+            let events = &mut harness.input_mut().events;
+            events.push(egui::Event::PointerMoved(pos));
+            if let Some(pressed) = pressed {
+                events.push(egui::Event::PointerButton {
+                    pos,
+                    button: egui::PointerButton::Primary,
+                    pressed,
+                    modifiers: Default::default(),
+                });
+            }
+            harness.step();
+        }
+
+        // The dragged tab stays selected in its new slot
+        assert_eq!(harness.state().0, 1);
+        assert_eq!(harness.state().2, vec![(0, 1)]);
+    }
+}
@@ -0,0 +1,578 @@
+//! Material Design 3 Text Field
+//!
+//! Single-line text input wrapping [`egui::TextEdit`], in the filled and
+//! outlined variants. The label rests inside the empty field and floats
+//! above the input when the field is focused or has content.
+//!
+//! # M3 Color Role Usage
+//!
+//! - **surfaceContainerHighest**: Filled text field container
+//! - **onSurface**: Input text, hover state layer @ 8%, hover indicator/outline
+//! - **onSurfaceVariant**: Label, helper text, character counter, icons, filled active indicator
+//! - **outline**: Outlined text field border
+//! - **primary**: Label and indicator/outline while focused
+//! - **error**: Label, indicator/outline, helper text and trailing icon in the error state
+//!
+//! ## Dimensions
+//! - **Height**: 56dp; 4dp corner radius (top corners only for filled)
+//! - **Padding**: 16dp, or 12dp around 24dp leading/trailing icons
+//! - **Active indicator/outline**: 1dp, 2dp while focused
+//! - **Supporting text**: 12sp, 4dp below the container
+
+use crate::material_symbol::material_symbol_text_or_default;
+use crate::ripple::rounded_rect_points;
+use crate::theme::{get_global_color, reduced_motion, type_font_id, TypeRole};
+use egui::{
+    epaint::{CornerRadius, PathShape, Stroke},
+    pos2, Align, Align2, FontId, Id, Layout, Rect, Response, Sense, TextEdit, Ui, UiBuilder, Vec2,
+    Widget,
+};
+
+/// Height of the field container
+const FIELD_HEIGHT: f32 = 56.0;
+/// Space taken below the container by the supporting text line
+const SUPPORTING_HEIGHT: f32 = 20.0;
+/// Horizontal padding, and the space from the edge to the text next to an icon
+const FIELD_PADDING: f32 = 16.0;
+const ICON_TEXT_INSET: f32 = 52.0;
+/// Seconds for the label to float up or settle back
+const LABEL_ANIMATION_S: f32 = 0.15;
+
+/// Visual variant of the text field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextFieldVariant {
+    /// Tinted container with an active indicator along the bottom
+    #[default]
+    Filled,
+    /// Transparent container with an outline; the floated label sits in a gap in the outline
+    Outlined,
+}
+
+/// Result of showing a [`MaterialTextField`].
+pub struct TextFieldResponse {
+    /// The response of the inner [`TextEdit`]; `changed()` is set when the text was edited
+    pub response: Response,
+    /// Whether the trailing icon was clicked this frame, e.g. to clear the field
+    pub trailing_icon_clicked: bool,
+}
+
+/// Material Design text field component.
+///
+/// # Example
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// let mut email = String::new();
+///
+/// let response = ui.add(
+///     MaterialTextField::new(&mut email)
+///         .variant(TextFieldVariant::Outlined)
+///         .label("Email")
+///         .leading_icon("mail")
+///         .helper_text("We'll never share your email"),
+/// );
+/// if response.lost_focus() && !email.contains('@') {
+///     // show an error next frame with `.error_text(..)`
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct MaterialTextField<'a> {
+    text: &'a mut String,
+    variant: TextFieldVariant,
+    label: Option<String>,
+    placeholder: Option<String>,
+    leading_icon: Option<String>,
+    trailing_icon: Option<String>,
+    helper_text: Option<String>,
+    error_text: Option<String>,
+    max_length: Option<usize>,
+    password: bool,
+    enabled: bool,
+    width: Option<f32>,
+    id: Option<Id>,
+}
+
+impl<'a> MaterialTextField<'a> {
+    /// Create a filled text field editing `text`.
+    pub fn new(text: &'a mut String) -> Self {
+        Self {
+            text,
+            variant: TextFieldVariant::Filled,
+            label: None,
+            placeholder: None,
+            leading_icon: None,
+            trailing_icon: None,
+            helper_text: None,
+            error_text: None,
+            max_length: None,
+            password: false,
+            enabled: true,
+            width: None,
+            id: None,
+        }
+    }
+
+    /// Create an outlined text field editing `text`.
+    pub fn outlined(text: &'a mut String) -> Self {
+        Self::new(text).variant(TextFieldVariant::Outlined)
+    }
+
+    /// Set the visual variant.
+    pub fn variant(mut self, variant: TextFieldVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Set the label that rests in the empty field and floats up on focus or content.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set hint text shown in the empty field once the label has floated up.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Show a Material Symbols icon at the start of the field.
+    pub fn leading_icon(mut self, icon: impl Into<String>) -> Self {
+        self.leading_icon = Some(icon.into());
+        self
+    }
+
+    /// Show a clickable Material Symbols icon at the end of the field.
+    ///
+    /// See [`TextFieldResponse::trailing_icon_clicked`].
+    pub fn trailing_icon(mut self, icon: impl Into<String>) -> Self {
+        self.trailing_icon = Some(icon.into());
+        self
+    }
+
+    /// Set supporting text shown below the field.
+    pub fn helper_text(mut self, text: impl Into<String>) -> Self {
+        self.helper_text = Some(text.into());
+        self
+    }
+
+    /// Put the field in the error state, showing `text` in place of the helper text.
+    pub fn error_text(mut self, text: impl Into<String>) -> Self {
+        self.error_text = Some(text.into());
+        self
+    }
+
+    /// Limit the input to `max_length` characters and show a character counter.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Hide the input, e.g. for passwords.
+    pub fn password(mut self, password: bool) -> Self {
+        self.password = password;
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Set the field width (default: `ui.spacing().text_edit_width`).
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the id of the inner [`TextEdit`], e.g. to request focus for it.
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn show(mut self, ui: &mut Ui) -> TextFieldResponse {
+        let id = self
+            .id
+            .unwrap_or_else(|| ui.next_auto_id().with("text_field"));
+        let width = self
+            .width
+            .unwrap_or(ui.spacing().text_edit_width)
+            .min(ui.available_width());
+        let has_supporting =
+            self.helper_text.is_some() || self.error_text.is_some() || self.max_length.is_some();
+        let supporting_height = if has_supporting {
+            SUPPORTING_HEIGHT
+        } else {
+            0.0
+        };
+        let height = FIELD_HEIGHT + supporting_height;
+
+        // Not focusable: the TextEdit inside is the field's only Tab stop
+        let sense = if self.enabled {
+            Sense::CLICK
+        } else {
+            Sense::hover()
+        };
+        let (rect, container_response) = ui.allocate_exact_size(Vec2::new(width, height), sense);
+        let field_rect = Rect::from_min_size(rect.min, Vec2::new(width, FIELD_HEIGHT));
+
+        // Clicking anywhere in the container focuses the input
+        if container_response.clicked() {
+            ui.memory_mut(|m| m.request_focus(id));
+        }
+
+        let focused = ui.memory(|m| m.has_focus(id));
+        let hovered = self.enabled && ui.rect_contains_pointer(field_rect);
+        let error = self.error_text.is_some();
+
+        let primary = get_global_color("primary");
+        let on_surface = get_global_color("onSurface");
+        let on_surface_variant = get_global_color("onSurfaceVariant");
+        let error_color = get_global_color("error");
+        let disabled = on_surface.linear_multiply(0.38);
+
+        // Indicator/outline and label colors follow error > focus > hover
+        let (indicator_color, indicator_width) = if !self.enabled {
+            match self.variant {
+                TextFieldVariant::Filled => (disabled, 1.0),
+                TextFieldVariant::Outlined => (on_surface.linear_multiply(0.12), 1.0),
+            }
+        } else if error {
+            (error_color, if focused { 2.0 } else { 1.0 })
+        } else if focused {
+            (primary, 2.0)
+        } else if hovered {
+            (on_surface, 1.0)
+        } else {
+            match self.variant {
+                TextFieldVariant::Filled => (on_surface_variant, 1.0),
+                TextFieldVariant::Outlined => (get_global_color("outline"), 1.0),
+            }
+        };
+        let label_color = if !self.enabled {
+            disabled
+        } else if error {
+            error_color
+        } else if focused {
+            primary
+        } else {
+            on_surface_variant
+        };
+        let icon_color = if self.enabled {
+            on_surface_variant
+        } else {
+            disabled
+        };
+
+        // Label float progress
+        let floated = focused || !self.text.is_empty();
+        let label_t = if reduced_motion(ui.ctx()) {
+            if floated {
+                1.0
+            } else {
+                0.0
+            }
+        } else {
+            ui.ctx()
+                .animate_bool_with_time(id.with("label"), floated, LABEL_ANIMATION_S)
+        };
+
+        // Container
+        if self.variant == TextFieldVariant::Filled {
+            let container_radius = CornerRadius {
+                nw: 4,
+                ne: 4,
+                sw: 0,
+                se: 0,
+            };
+            let container = if self.enabled {
+                get_global_color("surfaceContainerHighest")
+            } else {
+                on_surface.linear_multiply(0.04)
+            };
+            ui.painter()
+                .rect_filled(field_rect, container_radius, container);
+            if hovered && !focused {
+                ui.painter().rect_filled(
+                    field_rect,
+                    container_radius,
+                    on_surface.linear_multiply(0.08),
+                );
+            }
+        }
+
+        // Leading and trailing icons
+        let text_left = if self.leading_icon.is_some() {
+            ICON_TEXT_INSET
+        } else {
+            FIELD_PADDING
+        };
+        let show_error_icon = error && self.trailing_icon.is_none();
+        let text_right = if self.trailing_icon.is_some() || show_error_icon {
+            ICON_TEXT_INSET
+        } else {
+            FIELD_PADDING
+        };
+
+        if let Some(icon) = &self.leading_icon {
+            ui.painter().text(
+                pos2(field_rect.min.x + 24.0, field_rect.center().y),
+                Align2::CENTER_CENTER,
+                material_symbol_text_or_default(icon, icon),
+                FontId::proportional(24.0),
+                icon_color,
+            );
+        }
+
+        let mut trailing_icon_clicked = false;
+        let trailing_icon = self
+            .trailing_icon
+            .as_deref()
+            .or(show_error_icon.then_some("error"));
+        if let Some(icon) = trailing_icon {
+            let icon_rect = Rect::from_center_size(
+                pos2(field_rect.max.x - 24.0, field_rect.center().y),
+                Vec2::splat(40.0),
+            );
+            if self.trailing_icon.is_some() && self.enabled {
+                let icon_response =
+                    ui.interact(icon_rect, id.with("trailing_icon"), Sense::click());
+                trailing_icon_clicked = icon_response.clicked();
+            }
+            ui.painter().text(
+                icon_rect.center(),
+                Align2::CENTER_CENTER,
+                material_symbol_text_or_default(icon, icon),
+                FontId::proportional(24.0),
+                if error && self.enabled {
+                    error_color
+                } else {
+                    icon_color
+                },
+            );
+        }
+
+        // Input: below the floated label in filled fields, centered otherwise
+        let input_top = if self.variant == TextFieldVariant::Filled && self.label.is_some() {
+            field_rect.min.y + 24.0
+        } else {
+            field_rect.min.y + 16.0
+        };
+        let input_rect = Rect::from_min_max(
+            pos2(field_rect.min.x + text_left, input_top),
+            pos2(field_rect.max.x - text_right, input_top + 24.0),
+        );
+
+        let show_hint = self.label.is_none() || label_t >= 1.0;
+        let mut text_edit = TextEdit::singleline(&mut *self.text)
+            .id(id)
+            .frame(false)
+            .margin(Vec2::ZERO)
+            .font(type_font_id(TypeRole::BodyLarge))
+            .text_color(if self.enabled { on_surface } else { disabled })
+            .desired_width(input_rect.width())
+            .password(self.password)
+            .interactive(self.enabled);
+        if let Some(placeholder) = self.placeholder.as_ref().filter(|_| show_hint) {
+            text_edit = text_edit.hint_text(placeholder.as_str());
+        }
+        if let Some(max_length) = self.max_length {
+            text_edit = text_edit.char_limit(max_length);
+        }
+        let mut input_ui = ui.new_child(
+            UiBuilder::new()
+                .max_rect(input_rect)
+                .layout(Layout::left_to_right(Align::Center)),
+        );
+        let response = input_ui.add(text_edit);
+
+        // Floating label
+        let mut label_gap = None;
+        if let Some(label) = &self.label {
+            let resting_x = field_rect.min.x + text_left;
+            let (floated_x, floated_y) = match self.variant {
+                TextFieldVariant::Filled => (resting_x, field_rect.min.y + 16.0),
+                TextFieldVariant::Outlined => (field_rect.min.x + FIELD_PADDING, field_rect.min.y),
+            };
+            let x = egui::lerp(resting_x..=floated_x, label_t);
+            let y = egui::lerp(field_rect.center().y..=floated_y, label_t);
+            let resting_font = type_font_id(TypeRole::BodyLarge);
+            let floated_size = type_font_id(TypeRole::BodySmall).size;
+            let font = FontId::new(
+                egui::lerp(resting_font.size..=floated_size, label_t),
+                resting_font.family,
+            );
+            let galley = ui
+                .painter()
+                .layout_no_wrap(label.clone(), font, label_color);
+            if self.variant == TextFieldVariant::Outlined && label_t > 0.0 {
+                let half_gap = (galley.size().x / 2.0 + 4.0) * label_t;
+                let center = x + galley.size().x / 2.0;
+                label_gap = Some((center - half_gap, center + half_gap));
+            }
+            ui.painter()
+                .galley(pos2(x, y - galley.size().y / 2.0), galley, label_color);
+        }
+
+        // Active indicator or outline
+        let stroke = Stroke::new(indicator_width, indicator_color);
+        match self.variant {
+            TextFieldVariant::Filled => {
+                let y = field_rect.max.y - indicator_width / 2.0;
+                ui.painter().hline(field_rect.x_range(), y, stroke);
+            }
+            TextFieldVariant::Outlined => {
+                paint_outline(ui, field_rect, label_gap, stroke);
+            }
+        }
+
+        // Supporting text and character counter
+        let supporting_y = field_rect.max.y + 4.0;
+        let supporting = self
+            .error_text
+            .as_ref()
+            .map(|text| (text, error_color))
+            .or(self
+                .helper_text
+                .as_ref()
+                .map(|text| (text, on_surface_variant)));
+        if let Some((text, color)) = supporting {
+            ui.painter().text(
+                pos2(field_rect.min.x + FIELD_PADDING, supporting_y),
+                Align2::LEFT_TOP,
+                text,
+                type_font_id(TypeRole::BodySmall),
+                if self.enabled { color } else { disabled },
+            );
+        }
+        if let Some(max_length) = self.max_length {
+            ui.painter().text(
+                pos2(field_rect.max.x - FIELD_PADDING, supporting_y),
+                Align2::RIGHT_TOP,
+                format!("{} / {}", self.text.chars().count(), max_length),
+                type_font_id(TypeRole::BodySmall),
+                if self.enabled {
+                    on_surface_variant
+                } else {
+                    disabled
+                },
+            );
+        }
+
+        TextFieldResponse {
+            response,
+            trailing_icon_clicked,
+        }
+    }
+}
+
+/// Draw the outline of an outlined field, leaving `gap` open in the top edge for the label.
+fn paint_outline(ui: &Ui, rect: Rect, gap: Option<(f32, f32)>, stroke: Stroke) {
+    let rect = rect.shrink(stroke.width / 2.0);
+    let points = rounded_rect_points(rect, CornerRadius::same(4));
+    // Each of the four corners contributes the same number of points and the
+    // outline starts at the top-left corner, so the top edge runs from
+    // `points[split - 1]` to `points[split]`
+    let split = points.len() / 4;
+    let (top_left, top_right) = (points[split - 1], points[split]);
+
+    let mut sides: Vec<_> = points[split..].to_vec();
+    sides.extend_from_slice(&points[..split]);
+    ui.painter().add(PathShape::line(sides, stroke));
+
+    match gap {
+        Some((start, end)) => {
+            let y = top_left.y;
+            if start > top_left.x {
+                ui.painter()
+                    .line_segment([top_left, pos2(start, y)], stroke);
+            }
+            if end < top_right.x {
+                ui.painter().line_segment([pos2(end, y), top_right], stroke);
+            }
+        }
+        None => {
+            ui.painter().line_segment([top_left, top_right], stroke);
+        }
+    }
+}
+
+impl Widget for MaterialTextField<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+/// Convenience function to create a filled text field.
+pub fn text_field(text: &mut String) -> MaterialTextField<'_> {
+    MaterialTextField::new(text)
+}
+
+/// Convenience function to create an outlined text field.
+pub fn outlined_text_field(text: &mut String) -> MaterialTextField<'_> {
+    MaterialTextField::outlined(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Show an outlined field with id `email` for one frame of `events`
+    fn run_field(
+        ctx: &egui::Context,
+        text: &mut String,
+        events: Vec<egui::Event>,
+    ) -> TextFieldResponse {
+        let mut response = None;
+        let input = egui::RawInput {
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                response = Some(
+                    MaterialTextField::outlined(text)
+                        .id(Id::new("email"))
+                        .label("Email")
+                        .helper_text("Work address")
+                        .max_length(40)
+                        .show(ui),
+                );
+            });
+        });
+        response.unwrap()
+    }
+
+    #[test]
+    fn test_returns_inner_text_edit_response() {
+        let ctx = egui::Context::default();
+        let mut text = String::from("someone@example.com");
+        let response = run_field(&ctx, &mut text, Vec::new());
+        assert_eq!(response.response.id, Id::new("email"));
+        assert!(!response.trailing_icon_clicked);
+
+        // Typing into the focused field is reported through the returned response
+        ctx.memory_mut(|m| m.request_focus(Id::new("email")));
+        let response = run_field(&ctx, &mut text, vec![egui::Event::Text(".org".into())]);
+        assert!(response.response.has_focus());
+        assert!(response.response.changed());
+        assert_eq!(text, "someone@example.com.org");
+    }
+
+    #[test]
+    fn test_tab_focuses_the_input_directly() {
+        let ctx = egui::Context::default();
+        let mut text = String::new();
+        let tab = egui::Event::Key {
+            key: egui::Key::Tab,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Default::default(),
+        };
+        run_field(&ctx, &mut text, Vec::new());
+        run_field(&ctx, &mut text, vec![tab]);
+        run_field(&ctx, &mut text, Vec::new());
+        assert!(ctx.memory(|m| m.has_focus(Id::new("email"))));
+    }
+}
@@ -36,6 +36,71 @@
 //! update_window_background(&egui_ctx);
 //! ```
 //!
+//! # Font Downloads
+//!
+//! With the `ondemand` feature, `setup_google_fonts` downloads fonts that aren't
+//! in `resources/` on a background thread and returns immediately. Downloads are
+//! kept in `egui_material3_fonts` under the OS cache directory and reused on
+//! later runs; a file in `resources/` always takes precedence. The default
+//! font is used until the download finishes; then the fonts are loaded into
+//! the context that last called `load_fonts` and a repaint is requested. A
+//! failed or timed-out download leaves the default font in place. Poll
+//! [`font_load_status`] to show progress.
+//!
+//! # Shape and Type Scales
+//!
+//! Components take their corner radii from the M3 shape scale ([`Shape`],
+//! [`shape_radius`]) and their text sizes from the M3 type scale
+//! ([`TypeRole`], [`type_font_id`]). Replace either scale with
+//! [`set_shape_scale`] or [`set_type_scale`] to restyle every component, and
+//! use [`MaterialUiExt::material_label`] for text of your own.
+//!
+//! # Color Providers
+//!
+//! Components look up their colors with [`get_global_color`], which asks the
+//! current [`ColorProvider`]: the innermost [`ThemeScope`], or else
+//! [`GlobalColors`], the process-wide theme. Tests and preview panes can draw
+//! components with a fixed [`MaterialThemeContext`] or color map through
+//! [`with_color_provider`] without touching the global theme.
+//!
+//! # Scrollbars
+//!
+//! [`apply_material_visuals`] gives every `ScrollArea` a thin floating
+//! scrollbar that widens on hover ([`apply_material_scrollbars`]), and the
+//! scroll areas inside components draw it in the outline colors
+//! ([`MaterialScrollAreaExt::show_material`]). Apps that style scrollbars
+//! themselves can opt out with [`set_material_scrollbars`].
+//!
+//! # Keyboard Shortcuts
+//!
+//! A [`Shortcut`] is a key plus modifiers that can be detected with
+//! [`Shortcut::matches`] and shown with [`Shortcut::format`], using ⌘-style
+//! glyphs on macOS and `Ctrl+` names elsewhere. Menu items
+//! ([`MenuItem::key_shortcut`](crate::MenuItem::key_shortcut)) and tooltips
+//! ([`MaterialTooltip::shortcut`](crate::MaterialTooltip::shortcut)) display
+//! it right-aligned in a muted color.
+//!
+//! # Focus and Dismissal
+//!
+//! [`trap_focus`] keeps Tab and Shift+Tab cycling through the widgets of a
+//! region, such as a dialog, so keyboard focus can't wander to the app behind
+//! it. [`dismiss_on_escape`] consumes an Escape press so only one overlay closes
+//! per press. Dialogs, modal drawers, menus and the command palette use both.
+//!
+//! # Web (wasm32)
+//!
+//! There is no filesystem and no blocking network access on the web, so
+//! `setup_local_fonts`, `setup_local_theme` with a path and `setup_google_fonts`
+//! fall back to the defaults there. Embed the files and pass their contents
+//! instead:
+//!
+//! ```rust,no_run
+//! use egui_material3::theme::{load_theme_from_json_str, setup_local_fonts_from_bytes};
+//!
+//! setup_local_fonts_from_bytes("MyFont", include_bytes!("../resources/my-font.ttf"));
+//! load_theme_from_json_str(include_str!("../resources/my-theme.json")).unwrap();
+//! ```
+//!
 //! # Build-time Theme Inclusion
 //!
 //! The build script automatically scans for theme JSON files in:
@@ -76,9 +141,10 @@
 use egui::{Color32, FontData, FontDefinitions, FontFamily};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 
-#[cfg(feature = "ondemand")]
+#[cfg(all(feature = "ondemand", not(target_family = "wasm")))]
 use std::io::Read;
 
 // Font runtime management system - replaced build-time font inclusion with runtime loading for better flexibility
@@ -91,10 +157,199 @@ pub struct PreparedFont {
     pub name: String,
     pub data: Arc<FontData>,
     pub families: Vec<FontFamily>,
+    pub priority: FontPriority,
+}
+
+/// Where a prepared font goes in the proportional fallback chain.
+///
+/// Glyphs are looked up in `Primary` fonts first, then `Fallback` fonts, then
+/// egui's built-in fonts, then `Icons`. Fonts with the same priority keep the
+/// order they were prepared in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FontPriority {
+    /// The main text font, usually covering Latin text
+    #[default]
+    Primary,
+    /// Extra coverage for scripts the primary font lacks, e.g. CJK
+    Fallback,
+    /// Icon fonts such as Material Symbols, tried after all text fonts
+    Icons,
+}
+
+impl FontPriority {
+    /// Priority for fonts prepared without one: Material Symbols fonts are icons, anything else is text
+    fn inferred(font_name: &str) -> Self {
+        if font_name.contains("MaterialSymbols") {
+            FontPriority::Icons
+        } else {
+            FontPriority::Primary
+        }
+    }
 }
 
 static PREPARED_FONTS: Mutex<Vec<PreparedFont>> = Mutex::new(Vec::new());
 
+/// Progress of a font requested with `setup_google_fonts`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FontLoadStatus {
+    /// Downloading in the background; the default font is used meanwhile
+    Loading,
+    /// Prepared and picked up by `load_fonts`
+    Loaded,
+    /// Not found or not downloadable; the default font stays in use
+    Failed,
+}
+
+static FONT_LOAD_STATUS: std::sync::LazyLock<Mutex<HashMap<String, FontLoadStatus>>> =
+    std::sync::LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Context that last loaded fonts; refreshed when a background download finishes
+static FONT_CONTEXT: Mutex<Option<egui::Context>> = Mutex::new(None);
+
+/// Time limit for each request of a Google Fonts download
+#[cfg(all(feature = "ondemand", not(target_family = "wasm")))]
+const FONT_DOWNLOAD_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+fn set_font_load_status(font_name: &str, status: FontLoadStatus) {
+    if let Ok(mut statuses) = FONT_LOAD_STATUS.lock() {
+        statuses.insert(font_name.to_owned(), status);
+    }
+}
+
+/// Error from setting up a theme or font
+#[derive(Debug)]
+pub enum ThemeError {
+    /// Reading a theme or font file failed
+    Io(std::io::Error),
+    /// Theme JSON is malformed
+    Parse(serde_json::Error),
+    /// Downloading a font failed
+    Network(String),
+    /// A theme or font file, or a font on Google Fonts, doesn't exist
+    NotFound(String),
+    /// Decoding a source image for a generated theme failed
+    Image(String),
+    /// Font data isn't a valid TrueType or OpenType font
+    InvalidFont(String),
+}
+
+impl std::fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeError::Io(e) => write!(f, "I/O error: {}", e),
+            ThemeError::Parse(e) => write!(f, "Failed to parse theme JSON: {}", e),
+            ThemeError::Network(e) => write!(f, "Network error: {}", e),
+            ThemeError::NotFound(what) => write!(f, "Not found: {}", what),
+            ThemeError::Image(e) => write!(f, "Failed to decode image: {}", e),
+            ThemeError::InvalidFont(name) => write!(f, "Invalid font data: {}", name),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ThemeError::Io(e) => Some(e),
+            ThemeError::Parse(e) => Some(e),
+            ThemeError::Network(_)
+            | ThemeError::NotFound(_)
+            | ThemeError::Image(_)
+            | ThemeError::InvalidFont(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ThemeError {
+    fn from(e: std::io::Error) -> Self {
+        ThemeError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for ThemeError {
+    fn from(e: serde_json::Error) -> Self {
+        ThemeError::Parse(e)
+    }
+}
+
+/// Read a theme or font file, reporting a missing file as `ThemeError::NotFound`
+#[cfg(not(target_arch = "wasm32"))]
+fn read_file(path: &str) -> Result<Vec<u8>, ThemeError> {
+    std::fs::read(path).map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => ThemeError::NotFound(path.to_owned()),
+        _ => ThemeError::Io(e),
+    })
+}
+
+/// Web builds have no filesystem; fonts and themes have to be passed in as bytes
+#[cfg(target_arch = "wasm32")]
+fn read_file(path: &str) -> Result<Vec<u8>, ThemeError> {
+    Err(ThemeError::NotFound(format!(
+        "{} (no filesystem on the web, pass the file contents instead)",
+        path
+    )))
+}
+
+/// egui's default fonts with the prepared fonts added in priority order
+fn font_definitions(prepared_fonts: &[PreparedFont]) -> FontDefinitions {
+    let mut fonts = FontDefinitions::default();
+
+    let mut ordered: Vec<&PreparedFont> = prepared_fonts.iter().collect();
+    ordered.sort_by_key(|prepared_font| prepared_font.priority);
+
+    // Text fonts go in front of egui's defaults, icon fonts behind them
+    let mut text_fonts = 0;
+    for prepared_font in ordered {
+        fonts
+            .font_data
+            .insert(prepared_font.name.clone(), prepared_font.data.clone());
+
+        for family in &prepared_font.families {
+            let chain = match family {
+                FontFamily::Proportional | FontFamily::Monospace => {
+                    fonts.families.entry(family.clone()).or_default()
+                }
+                _ => continue,
+            };
+            if *family == FontFamily::Proportional && prepared_font.priority != FontPriority::Icons
+            {
+                chain.insert(text_fonts, prepared_font.name.clone());
+                text_fonts += 1;
+            } else {
+                chain.push(prepared_font.name.clone());
+            }
+        }
+    }
+    fonts
+}
+
+/// File name stem used for a font on disk, e.g. `google-sans-code`
+#[cfg(not(target_arch = "wasm32"))]
+fn font_file_stem(font_name: &str) -> String {
+    font_name.replace(" ", "-").to_lowercase()
+}
+
+/// Where a downloaded font is cached, outside the working directory so shipped
+/// binaries and read-only installs work too
+#[cfg(not(target_arch = "wasm32"))]
+fn font_cache_path(font_name: &str) -> std::path::PathBuf {
+    crate::disk_cache::os_cache_dir()
+        .join("egui_material3_fonts")
+        .join(format!("{}.ttf", font_file_stem(font_name)))
+}
+
+/// Whether `data` is a TrueType or OpenType font egui can load
+fn is_valid_font(data: &[u8]) -> bool {
+    ttf_parser::Face::parse(data, 0).is_ok()
+}
+
+/// Load status of a font passed to `setup_google_fonts`, or `None` if it was never requested
+pub fn font_load_status(font_name: &str) -> Option<FontLoadStatus> {
+    FONT_LOAD_STATUS
+        .lock()
+        .ok()
+        .and_then(|statuses| statuses.get(font_name).copied())
+}
+
 /// A prepared Material Design theme ready for loading
 ///
 /// This struct represents a Material Design theme that has been loaded and parsed
@@ -109,7 +364,7 @@ static PREPARED_FONTS: Mutex<Vec<PreparedFont>> = Mutex::new(Vec::new());
 /// # Usage
 /// This struct is primarily used internally by the theme system. Themes are prepared
 /// by `setup_local_theme()` and stored in the static `PREPARED_THEMES` collection,
-/// then activated by `load_themes()`.
+/// then activated by `load_themes()` or, by name, `activate_theme()`.
 #[derive(Debug, Clone)]
 pub struct PreparedTheme {
     pub name: String,
@@ -212,19 +467,28 @@ pub struct MaterialScheme {
     pub surface_container_highest: String,
 }
 
+/// Material Theme Builder export file
+///
+/// `schemes` is keyed by the names the Theme Builder exports: `light`, `dark`,
+/// `light-medium-contrast`, `light-high-contrast`, `dark-medium-contrast` and
+/// `dark-high-contrast`. Only `light` and `dark` are required; missing contrast
+/// variants fall back to the base scheme of the same mode.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MaterialThemeFile {
+    #[serde(default)]
     pub description: String,
+    #[serde(default)]
     pub seed: String,
-    #[serde(rename = "coreColors")]
+    #[serde(rename = "coreColors", default)]
     pub core_colors: HashMap<String, String>,
-    #[serde(rename = "extendedColors")]
+    #[serde(rename = "extendedColors", default)]
     pub extended_colors: Vec<serde_json::Value>,
     pub schemes: HashMap<String, MaterialScheme>,
+    #[serde(default)]
     pub palettes: HashMap<String, HashMap<String, String>>,
 }
 
-#[derive(Clone, Debug, Copy, PartialEq)]
+#[derive(Clone, Debug, Copy, PartialEq, Deserialize, Serialize)]
 pub enum ContrastLevel {
     Normal,
     Medium,
@@ -254,7 +518,7 @@ impl std::str::FromStr for ContrastLevel {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
 #[derive(Default)]
 pub enum ThemeMode {
     Light,
@@ -288,12 +552,55 @@ impl std::str::FromStr for ThemeMode {
 
 
 /// Global theme context that can be shared across all Material components
-#[derive(Clone, Debug)]
+///
+/// Serializes with serde so apps can persist the user's theme; see
+/// [`save_theme_to_json`] and [`load_theme_from_json`]. Colors in
+/// `selected_colors` are written as `"#RRGGBB"` strings.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
 pub struct MaterialThemeContext {
     pub theme_mode: ThemeMode,
     pub contrast_level: ContrastLevel,
     pub material_theme: Option<MaterialThemeFile>,
+    #[serde(with = "hex_color_map")]
     pub selected_colors: HashMap<String, Color32>,
+    /// Last known system dark-mode preference, used to resolve `ThemeMode::Auto`.
+    /// Kept up to date by `update_window_background` and `apply_theme`.
+    #[serde(skip)]
+    system_dark_mode: bool,
+}
+
+/// Serde adapter writing a color map as `"#RRGGBB"` strings, sorted by name
+mod hex_color_map {
+    use super::MaterialThemeContext;
+    use egui::Color32;
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::{BTreeMap, HashMap};
+
+    pub fn serialize<S: Serializer>(
+        colors: &HashMap<String, Color32>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        colors
+            .iter()
+            .map(|(name, color)| (name, MaterialThemeContext::color32_to_hex(*color)))
+            .collect::<BTreeMap<_, _>>()
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<String, Color32>, D::Error> {
+        HashMap::<String, String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(name, hex)| {
+                let color = MaterialThemeContext::hex_to_color32(&hex).ok_or_else(|| {
+                    D::Error::custom(format!("invalid color {:?} for {:?}", hex, name))
+                })?;
+                Ok((name, color))
+            })
+            .collect()
+    }
 }
 
 impl Default for MaterialThemeContext {
@@ -303,6 +610,7 @@ impl Default for MaterialThemeContext {
             contrast_level: ContrastLevel::Normal,
             material_theme: Some(get_default_material_theme()),
             selected_colors: HashMap::new(),
+            system_dark_mode: false,
         }
     }
 }
@@ -651,59 +959,389 @@ fn get_default_material_theme() -> MaterialThemeFile {
     }
 }
 
+/// Seed used when an image has no colorful pixels (Google Blue, as on Android)
+const FALLBACK_SEED: Color32 = Color32::from_rgb(0x42, 0x85, 0xF4);
+
+/// Tones written to the `palettes` section of a generated theme
+const PALETTE_TONES: [u8; 18] = [
+    0, 5, 10, 15, 20, 25, 30, 35, 40, 50, 60, 70, 80, 90, 95, 98, 99, 100,
+];
+
+/// Key palette of a generated scheme
+#[derive(Clone, Copy)]
+enum Palette {
+    Primary,
+    Secondary,
+    Tertiary,
+    Error,
+    Neutral,
+    NeutralVariant,
+}
+
+/// Palette and light/dark tone of each token, following the Material 3
+/// tonal spot scheme
+#[rustfmt::skip]
+const SCHEME_TONES: [(&str, Palette, u8, u8); 49] = [
+    ("primary", Palette::Primary, 40, 80),
+    ("surfaceTint", Palette::Primary, 40, 80),
+    ("onPrimary", Palette::Primary, 100, 20),
+    ("primaryContainer", Palette::Primary, 90, 30),
+    ("onPrimaryContainer", Palette::Primary, 30, 90),
+    ("secondary", Palette::Secondary, 40, 80),
+    ("onSecondary", Palette::Secondary, 100, 20),
+    ("secondaryContainer", Palette::Secondary, 90, 30),
+    ("onSecondaryContainer", Palette::Secondary, 30, 90),
+    ("tertiary", Palette::Tertiary, 40, 80),
+    ("onTertiary", Palette::Tertiary, 100, 20),
+    ("tertiaryContainer", Palette::Tertiary, 90, 30),
+    ("onTertiaryContainer", Palette::Tertiary, 30, 90),
+    ("error", Palette::Error, 40, 80),
+    ("onError", Palette::Error, 100, 20),
+    ("errorContainer", Palette::Error, 90, 30),
+    ("onErrorContainer", Palette::Error, 30, 90),
+    ("background", Palette::Neutral, 98, 6),
+    ("onBackground", Palette::Neutral, 10, 90),
+    ("surface", Palette::Neutral, 98, 6),
+    ("onSurface", Palette::Neutral, 10, 90),
+    ("surfaceVariant", Palette::NeutralVariant, 90, 30),
+    ("onSurfaceVariant", Palette::NeutralVariant, 30, 80),
+    ("outline", Palette::NeutralVariant, 50, 60),
+    ("outlineVariant", Palette::NeutralVariant, 80, 30),
+    ("shadow", Palette::Neutral, 0, 0),
+    ("scrim", Palette::Neutral, 0, 0),
+    ("inverseSurface", Palette::Neutral, 20, 90),
+    ("inverseOnSurface", Palette::Neutral, 95, 20),
+    ("inversePrimary", Palette::Primary, 80, 40),
+    ("primaryFixed", Palette::Primary, 90, 90),
+    ("onPrimaryFixed", Palette::Primary, 10, 10),
+    ("primaryFixedDim", Palette::Primary, 80, 80),
+    ("onPrimaryFixedVariant", Palette::Primary, 30, 30),
+    ("secondaryFixed", Palette::Secondary, 90, 90),
+    ("onSecondaryFixed", Palette::Secondary, 10, 10),
+    ("secondaryFixedDim", Palette::Secondary, 80, 80),
+    ("onSecondaryFixedVariant", Palette::Secondary, 30, 30),
+    ("tertiaryFixed", Palette::Tertiary, 90, 90),
+    ("onTertiaryFixed", Palette::Tertiary, 10, 10),
+    ("tertiaryFixedDim", Palette::Tertiary, 80, 80),
+    ("onTertiaryFixedVariant", Palette::Tertiary, 30, 30),
+    ("surfaceDim", Palette::Neutral, 87, 6),
+    ("surfaceBright", Palette::Neutral, 98, 24),
+    ("surfaceContainerLowest", Palette::Neutral, 100, 4),
+    ("surfaceContainerLow", Palette::Neutral, 96, 10),
+    ("surfaceContainer", Palette::Neutral, 94, 12),
+    ("surfaceContainerHigh", Palette::Neutral, 92, 17),
+    ("surfaceContainerHighest", Palette::Neutral, 90, 22),
+];
+
+/// CIE D65 reference white
+const D65: [f32; 3] = [0.95047, 1.0, 1.08883];
+
+fn srgb_to_linear(c: u8) -> f32 {
+    let c = c as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> u8 {
+    let c = if c <= 0.0031308 {
+        12.92 * c
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// CIELAB `[L*, a*, b*]` of an sRGB color
+fn color_to_lab(color: Color32) -> [f32; 3] {
+    let [r, g, b] = [color.r(), color.g(), color.b()].map(srgb_to_linear);
+    let xyz = [
+        0.4124 * r + 0.3576 * g + 0.1805 * b,
+        0.2126 * r + 0.7152 * g + 0.0722 * b,
+        0.0193 * r + 0.1192 * g + 0.9505 * b,
+    ];
+    let [fx, fy, fz] = [0, 1, 2].map(|i| {
+        let t = xyz[i] / D65[i];
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    });
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// Linear sRGB of a CIELAB color; components outside 0..=1 are out of gamut
+fn lab_to_linear_rgb([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let fy = (l + 16.0) / 116.0;
+    let f = [fy + a / 500.0, fy, fy - b / 200.0];
+    let [x, y, z] = [0, 1, 2].map(|i| {
+        let t = f[i].powi(3);
+        let t = if t > 216.0 / 24389.0 {
+            t
+        } else {
+            (116.0 * f[i] - 16.0) / (24389.0 / 27.0)
+        };
+        t * D65[i]
+    });
+    [
+        3.2406 * x - 1.5372 * y - 0.4986 * z,
+        -0.9689 * x + 1.8758 * y + 0.0415 * z,
+        0.0557 * x - 0.2040 * y + 1.0570 * z,
+    ]
+}
+
+/// Hue (degrees) and chroma of an sRGB color in CIELCh
+fn color_to_hue_chroma(color: Color32) -> (f32, f32) {
+    let [_, a, b] = color_to_lab(color);
+    (b.atan2(a).to_degrees().rem_euclid(360.0), a.hypot(b))
+}
+
+/// Colors of one hue and chroma at every tone (L*) from 0 to 100
+///
+/// Material's HCT color space defines tone as L*, so tones match the Theme
+/// Builder; hue and chroma are measured in CIELCh rather than CAM16, so
+/// generated colors are close to, not identical with, Theme Builder output.
+#[derive(Clone, Copy)]
+pub(crate) struct TonalPalette {
+    pub(crate) hue: f32,
+    pub(crate) chroma: f32,
+}
+
+impl TonalPalette {
+    /// The color at `tone`, reducing chroma where the full chroma is out of gamut
+    pub(crate) fn tone(&self, tone: u8) -> Color32 {
+        if tone == 0 {
+            return Color32::BLACK;
+        }
+        let l = tone as f32;
+        let (sin, cos) = self.hue.to_radians().sin_cos();
+        let rgb_at = |chroma: f32| lab_to_linear_rgb([l, chroma * cos, chroma * sin]);
+        let in_gamut = |rgb: [f32; 3]| rgb.iter().all(|c| (-1e-4..=1.0001).contains(c));
+
+        let mut rgb = rgb_at(self.chroma);
+        if !in_gamut(rgb) {
+            let (mut low, mut high) = (0.0, self.chroma);
+            for _ in 0..16 {
+                let mid = (low + high) / 2.0;
+                if in_gamut(rgb_at(mid)) {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            rgb = rgb_at(low);
+        }
+        let [r, g, b] = rgb.map(linear_to_srgb);
+        Color32::from_rgb(r, g, b)
+    }
+}
+
+/// Most frequent colorful color of an image, if it has one
+///
+/// Pixels are counted in 4-bit-per-channel buckets; transparent pixels and
+/// near-gray buckets are skipped. Every pixel is visited once.
+fn dominant_color(image: &image::RgbaImage) -> Option<Color32> {
+    let mut buckets = vec![(0u64, [0u64; 3]); 4096];
+    for pixel in image.pixels() {
+        let [r, g, b, a] = pixel.0;
+        if a < 128 {
+            continue;
+        }
+        let index = ((r as usize >> 4) << 8) | ((g as usize >> 4) << 4) | (b as usize >> 4);
+        let bucket = &mut buckets[index];
+        bucket.0 += 1;
+        bucket.1[0] += r as u64;
+        bucket.1[1] += g as u64;
+        bucket.1[2] += b as u64;
+    }
+
+    buckets
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, [r, g, b])| {
+            let color = Color32::from_rgb((r / count) as u8, (g / count) as u8, (b / count) as u8);
+            (count, color)
+        })
+        .filter(|(_, color)| color_to_hue_chroma(*color).1 >= 15.0)
+        .max_by_key(|(count, _)| *count)
+        .map(|(_, color)| color)
+}
+
+/// WCAG AA minimum contrast for normal-size text
+pub const CONTRAST_AA: f32 = 4.5;
+
+/// Foreground/background token pairs checked by `validate_contrast`
+const CONTRAST_PAIRS: [(&str, &str); 15] = [
+    ("onPrimary", "primary"),
+    ("onPrimaryContainer", "primaryContainer"),
+    ("onSecondary", "secondary"),
+    ("onSecondaryContainer", "secondaryContainer"),
+    ("onTertiary", "tertiary"),
+    ("onTertiaryContainer", "tertiaryContainer"),
+    ("onError", "error"),
+    ("onErrorContainer", "errorContainer"),
+    ("onBackground", "background"),
+    ("onSurface", "surface"),
+    ("onSurfaceVariant", "surfaceVariant"),
+    ("inverseOnSurface", "inverseSurface"),
+    ("onPrimaryFixed", "primaryFixed"),
+    ("onSecondaryFixed", "secondaryFixed"),
+    ("onTertiaryFixed", "tertiaryFixed"),
+];
+
+/// WCAG 2.1 relative luminance of an sRGB color
+fn relative_luminance(color: Color32) -> f32 {
+    let [r, g, b] = [color.r(), color.g(), color.b()].map(srgb_to_linear);
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// WCAG 2.1 contrast ratio between two colors, from 1.0 to 21.0
+///
+/// The order of the colors doesn't matter. Alpha is ignored.
+pub fn contrast_ratio(fg: Color32, bg: Color32) -> f32 {
+    let (a, b) = (relative_luminance(fg), relative_luminance(bg));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// A token pair whose contrast is below the WCAG AA threshold
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContrastWarning {
+    /// The "on" token drawn on top, e.g. `onPrimary`
+    pub foreground: &'static str,
+    /// The token it is drawn on, e.g. `primary`
+    pub background: &'static str,
+    pub ratio: f32,
+    pub required: f32,
+}
+
+impl std::fmt::Display for ContrastWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} on {} has contrast {:.2}:1, needs {:.1}:1",
+            self.foreground, self.background, self.ratio, self.required
+        )
+    }
+}
+
 impl MaterialThemeContext {
     pub fn setup_fonts(font_name: Option<&str>) {
-        let font_name = font_name.unwrap_or("Google Sans Code");
+        if let Err(e) = Self::try_setup_fonts(font_name) {
+            eprintln!("Failed to set up font: {}", e);
+        }
+    }
 
-        // Check if font exists in resources directory first
-        let font_file_path = format!(
-            "resources/{}.ttf",
-            font_name.replace(" ", "-").to_lowercase()
-        );
+    /// Fallible version of [`Self::setup_fonts`]
+    ///
+    /// Returns `Ok` once a local font is prepared or a download has started;
+    /// download failures are reported through [`font_load_status`].
+    pub fn try_setup_fonts(font_name: Option<&str>) -> Result<(), ThemeError> {
+        Self::try_setup_fonts_with_priority(font_name, FontPriority::Primary)
+    }
 
-        let font_data = if std::path::Path::new(&font_file_path).exists() {
-            // Use local font file with include_bytes!
-            Self::load_local_font(&font_file_path)
-        } else {
-            // Download font from Google Fonts at runtime (only if ondemand feature is enabled)
-            #[cfg(feature = "ondemand")]
-            {
-                Self::download_google_font(font_name)
+    /// Like [`Self::try_setup_fonts`], placing the font at `priority` in the fallback chain
+    pub fn try_setup_fonts_with_priority(
+        font_name: Option<&str>,
+        priority: FontPriority,
+    ) -> Result<(), ThemeError> {
+        let font_name = font_name.unwrap_or("Google Sans Code");
+
+        // A font the user put in the resources directory wins, then an earlier download
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let font_file_path = format!("resources/{}.ttf", font_file_stem(font_name));
+            if std::path::Path::new(&font_file_path).exists() {
+                return Self::prepare_google_font(font_name, read_file(&font_file_path), priority);
             }
-            #[cfg(not(feature = "ondemand"))]
-            {
-                eprintln!(
-                    "Font '{}' not found locally and ondemand feature is not enabled",
-                    font_name
-                );
-                None
+            let cached_path = font_cache_path(font_name);
+            if let Ok(data) = std::fs::read(&cached_path) {
+                if is_valid_font(&data) {
+                    return Self::prepare_google_font(font_name, Ok(data), priority);
+                }
+                // A broken cache entry would fail every run, so download it again
+                let _ = std::fs::remove_file(&cached_path);
             }
-        };
-
-        if let Some(data) = font_data {
-            let font_family_name = font_name.replace(" ", "");
+        }
 
-            let prepared_font = PreparedFont {
-                name: font_family_name.clone(),
-                data: Arc::new(FontData::from_owned(data)),
-                families: vec![FontFamily::Proportional, FontFamily::Monospace],
+        // Download font from Google Fonts at runtime (only if ondemand feature is enabled).
+        // The download runs on its own thread so app startup never waits on the network.
+        #[cfg(all(feature = "ondemand", not(target_family = "wasm")))]
+        {
+            set_font_load_status(font_name, FontLoadStatus::Loading);
+            let font_name = font_name.to_owned();
+            std::thread::spawn(move || {
+                let font_data = Self::download_google_font(&font_name);
+                match Self::prepare_google_font(&font_name, font_data, priority) {
+                    Ok(()) => {
+                        let ctx = FONT_CONTEXT.lock().ok().and_then(|ctx| ctx.clone());
+                        if let Some(ctx) = ctx {
+                            Self::load_fonts(&ctx);
+                            ctx.request_repaint();
+                        }
+                    }
+                    Err(e) => eprintln!("Failed to download font '{}': {}", font_name, e),
+                }
+            });
+            Ok(())
+        }
+        #[cfg(any(not(feature = "ondemand"), target_family = "wasm"))]
+        {
+            set_font_load_status(font_name, FontLoadStatus::Failed);
+            let reason = if cfg!(target_arch = "wasm32") {
+                "web builds can't read or download fonts, use setup_local_fonts_from_bytes"
+            } else {
+                "not in resources/ and the ondemand feature is disabled"
             };
+            Err(ThemeError::NotFound(format!(
+                "font '{}' ({})",
+                font_name, reason
+            )))
+        }
+    }
 
-            if let Ok(mut fonts) = PREPARED_FONTS.lock() {
-                // Remove any existing font with the same name
-                fonts.retain(|f| f.name != font_family_name);
-                fonts.push(prepared_font);
+    /// Prepare a font loaded by `setup_fonts` and record its load status
+    fn prepare_google_font(
+        font_name: &str,
+        font_data: Result<Vec<u8>, ThemeError>,
+        priority: FontPriority,
+    ) -> Result<(), ThemeError> {
+        let data = match font_data {
+            Ok(data) => data,
+            Err(e) => {
+                set_font_load_status(font_name, FontLoadStatus::Failed);
+                return Err(e);
             }
+        };
+        // egui panics on fonts it can't parse, so reject them here instead
+        if !is_valid_font(&data) {
+            set_font_load_status(font_name, FontLoadStatus::Failed);
+            return Err(ThemeError::InvalidFont(font_name.to_owned()));
         }
-    }
 
-    fn load_local_font(font_path: &str) -> Option<Vec<u8>> {
-        std::fs::read(font_path).ok()
+        let font_family_name = font_name.replace(" ", "");
+
+        let prepared_font = PreparedFont {
+            name: font_family_name.clone(),
+            data: Arc::new(FontData::from_owned(data)),
+            families: vec![FontFamily::Proportional, FontFamily::Monospace],
+            priority,
+        };
+
+        if let Ok(mut fonts) = PREPARED_FONTS.lock() {
+            // Remove any existing font with the same name
+            fonts.retain(|f| f.name != font_family_name);
+            fonts.push(prepared_font);
+        }
+        set_font_load_status(font_name, FontLoadStatus::Loaded);
+        Ok(())
     }
 
     // On-demand font downloading feature - downloads Google Fonts at runtime when ondemand feature is enabled
-    #[cfg(feature = "ondemand")]
-    fn download_google_font(font_name: &str) -> Option<Vec<u8>> {
+    #[cfg(all(feature = "ondemand", not(target_family = "wasm")))]
+    fn download_google_font(font_name: &str) -> Result<Vec<u8>, ThemeError> {
         // Convert font name to Google Fonts URL format
         let font_url_name = font_name.replace(" ", "+");
 
@@ -713,59 +1351,44 @@ impl MaterialThemeContext {
             font_url_name
         );
 
-        match ureq::get(&css_url)
+        let agent = ureq::AgentBuilder::new()
+            .timeout(FONT_DOWNLOAD_TIMEOUT)
+            .build();
+
+        let css_content = agent
+            .get(&css_url)
             .set(
                 "User-Agent",
                 "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36",
             )
             .call()
-        {
-            Ok(response) => {
-                let css_content = response.into_string().ok()?;
-
-                // Parse CSS to find TTF URL
-                let font_url = Self::extract_font_url_from_css(&css_content)?;
-
-                // Download the actual font file
-                match ureq::get(&font_url).call() {
-                    Ok(font_response) => {
-                        let mut font_data = Vec::new();
-                        if font_response
-                            .into_reader()
-                            .read_to_end(&mut font_data)
-                            .is_ok()
-                        {
-                            // Save font to resources directory for future use
-                            let target_path = format!(
-                                "resources/{}.ttf",
-                                font_name.replace(" ", "-").to_lowercase()
-                            );
-                            if let Ok(()) = std::fs::write(&target_path, &font_data) {
-                                eprintln!(
-                                    "Font '{}' downloaded and saved to {}",
-                                    font_name, target_path
-                                );
-                            }
-                            Some(font_data)
-                        } else {
-                            eprintln!("Failed to read font data for '{}'", font_name);
-                            None
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("Failed to download font '{}': {}", font_name, e);
-                        None
-                    }
-                }
-            }
-            Err(e) => {
-                eprintln!("Failed to fetch CSS for font '{}': {}", font_name, e);
-                None
-            }
+            .map_err(|e| ThemeError::Network(format!("fetching CSS for '{}': {}", font_name, e)))?
+            .into_string()?;
+
+        // Parse CSS to find TTF URL
+        let font_url = Self::extract_font_url_from_css(&css_content).ok_or_else(|| {
+            ThemeError::NotFound(format!("TrueType font '{}' on Google Fonts", font_name))
+        })?;
+
+        // Download the actual font file
+        let mut font_data = Vec::new();
+        agent
+            .get(&font_url)
+            .call()
+            .map_err(|e| ThemeError::Network(format!("downloading '{}': {}", font_name, e)))?
+            .into_reader()
+            .read_to_end(&mut font_data)?;
+
+        if !is_valid_font(&font_data) {
+            return Err(ThemeError::InvalidFont(font_name.to_owned()));
         }
+
+        // Cache the font so later runs don't download it again
+        let _ = crate::disk_cache::write_atomically(&font_cache_path(font_name), &font_data);
+        Ok(font_data)
     }
 
-    #[cfg(feature = "ondemand")]
+    #[cfg(all(feature = "ondemand", not(target_family = "wasm")))]
     fn extract_font_url_from_css(css_content: &str) -> Option<String> {
         // Look for TTF URLs in the CSS content
         // Google Fonts CSS contains lines like: src: url(https://fonts.gstatic.com/...) format('truetype');
@@ -785,71 +1408,69 @@ impl MaterialThemeContext {
     }
 
     pub fn setup_local_fonts(font_path: Option<&str>) {
-        // let default_material_symbols_path =
-        //     "resources/MaterialSymbolsOutlined[FILL,GRAD,opsz,wght].ttf";
-
         // Load custom text font if provided
         if let Some(path) = font_path {
-            if std::path::Path::new(path).exists() {
-                if let Ok(data) = std::fs::read(path) {
-                    // Derive font name from filename (e.g. "noto-sans-kr.ttf" -> "NotoSansKr")
-                    let font_name = std::path::Path::new(path)
-                        .file_stem()
-                        .and_then(|s| s.to_str())
-                        .unwrap_or("CustomFont")
-                        .split(['-', '_'])
-                        .map(|part| {
-                            let mut chars = part.chars();
-                            match chars.next() {
-                                Some(first) => {
-                                    let upper: String = first.to_uppercase().collect();
-                                    format!("{}{}", upper, chars.as_str())
-                                }
-                                None => String::new(),
-                            }
-                        })
-                        .collect::<String>();
-
-                    let prepared_font = PreparedFont {
-                        name: font_name.clone(),
-                        data: Arc::new(FontData::from_owned(data)),
-                        families: vec![FontFamily::Proportional, FontFamily::Monospace],
-                    };
-
-                    if let Ok(mut fonts) = PREPARED_FONTS.lock() {
-                        fonts.retain(|f| f.name != font_name);
-                        fonts.push(prepared_font);
-                    }
-                }
+            if let Err(e) = Self::try_setup_local_fonts(path) {
+                eprintln!("Failed to set up font: {}", e);
             }
         }
+    }
 
-        // Always load Material Symbols icon font
-        // let icon_font_data = if std::path::Path::new(default_material_symbols_path).exists() {
-        //     std::fs::read(default_material_symbols_path).ok()
-        // } else {
-        //     Self::get_embedded_material_symbols()
-        // };
+    /// Fallible version of [`Self::setup_local_fonts`] for a single font file
+    pub fn try_setup_local_fonts(font_path: &str) -> Result<(), ThemeError> {
+        let data = read_file(font_path)?;
+
+        // Derive font name from filename (e.g. "noto-sans-kr.ttf" -> "NotoSansKr")
+        let font_name = std::path::Path::new(font_path)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("CustomFont")
+            .split(['-', '_'])
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => {
+                        let upper: String = first.to_uppercase().collect();
+                        format!("{}{}", upper, chars.as_str())
+                    }
+                    None => String::new(),
+                }
+            })
+            .collect::<String>();
 
-        // if let Some(data) = icon_font_data {
-        //     let prepared_font = PreparedFont {
-        //         name: "MaterialSymbolsOutlined".to_owned(),
-        //         data: Arc::new(FontData::from_owned(data)),
-        //         families: vec![FontFamily::Proportional, FontFamily::Monospace],
-        //     };
+        let prepared_font = PreparedFont {
+            priority: FontPriority::inferred(&font_name),
+            name: font_name.clone(),
+            data: Arc::new(FontData::from_owned(data)),
+            families: vec![FontFamily::Proportional, FontFamily::Monospace],
+        };
 
-        //     if let Ok(mut fonts) = PREPARED_FONTS.lock() {
-        //         fonts.retain(|f| f.name != "MaterialSymbolsOutlined");
-        //         fonts.push(prepared_font);
-        //     }
-        // }
+        if let Ok(mut fonts) = PREPARED_FONTS.lock() {
+            fonts.retain(|f| f.name != font_name);
+            fonts.push(prepared_font);
+        }
+        Ok(())
     }
 
     pub fn setup_local_fonts_from_bytes(font_name: &str, font_data: &[u8]) {
+        Self::setup_local_fonts_from_bytes_with_priority(
+            font_name,
+            font_data,
+            FontPriority::inferred(font_name),
+        );
+    }
+
+    /// Like [`Self::setup_local_fonts_from_bytes`], placing the font at `priority` in the fallback chain
+    pub fn setup_local_fonts_from_bytes_with_priority(
+        font_name: &str,
+        font_data: &[u8],
+        priority: FontPriority,
+    ) {
         let prepared_font = PreparedFont {
             name: font_name.to_owned(),
             data: Arc::new(FontData::from_owned(font_data.to_vec())),
             families: vec![FontFamily::Proportional, FontFamily::Monospace],
+            priority,
         };
 
         if let Ok(mut fonts) = PREPARED_FONTS.lock() {
@@ -874,45 +1495,58 @@ impl MaterialThemeContext {
     /// - Parses JSON and stores in static PREPARED_THEMES collection
     /// - Replaces any existing theme with the same name
     pub fn setup_local_theme(theme_path: Option<&str>) {
-        let theme_data = if let Some(path) = theme_path {
-            // Try to load custom theme from path
-            if std::path::Path::new(path).exists() {
-                std::fs::read_to_string(path).ok()
-            } else {
-                // Fall back to embedded theme files or default theme
-                Self::get_embedded_theme_data(path).or_else(|| {
-                    Some(serde_json::to_string(&get_default_material_theme()).unwrap_or_default())
-                })
+        match Self::try_setup_local_theme(theme_path) {
+            Ok(()) => {}
+            // A missing theme file falls back to the default theme
+            Err(ThemeError::NotFound(_)) => {
+                Self::prepare_theme(Self::theme_name(theme_path), get_default_material_theme())
+            }
+            Err(e) => eprintln!("Failed to set up theme: {}", e),
+        }
+    }
+
+    /// Fallible version of [`Self::setup_local_theme`]
+    ///
+    /// Unlike `setup_local_theme`, a missing `theme_path` is an error
+    /// instead of falling back to the default theme.
+    pub fn try_setup_local_theme(theme_path: Option<&str>) -> Result<(), ThemeError> {
+        let theme_file = match theme_path {
+            Some(path) => {
+                let data = read_file(path)?;
+                serde_json::from_slice::<MaterialThemeFile>(&data)?
             }
-        } else {
             // Use embedded theme data first, then fall back to default
-            Self::get_embedded_theme_data("resources/material-theme1.json").or_else(|| {
-                Some(serde_json::to_string(&get_default_material_theme()).unwrap_or_default())
-            })
+            None => match Self::get_embedded_theme_data("resources/material-theme1.json") {
+                Some(data) => serde_json::from_str::<MaterialThemeFile>(&data)?,
+                None => get_default_material_theme(),
+            },
         };
 
-        // Parse and prepare theme if available
-        if let Some(data) = theme_data {
-            if let Ok(theme_file) = serde_json::from_str::<MaterialThemeFile>(&data) {
-                let theme_name = theme_path
-                    .and_then(|p| {
-                        std::path::Path::new(p)
-                            .file_stem()
-                            .map(|s| s.to_string_lossy().to_string())
-                    })
-                    .unwrap_or_else(|| "default".to_string());
-
-                let prepared_theme = PreparedTheme {
-                    name: theme_name.clone(),
-                    theme_data: theme_file,
-                };
+        Self::prepare_theme(Self::theme_name(theme_path), theme_file);
+        Ok(())
+    }
 
-                if let Ok(mut themes) = PREPARED_THEMES.lock() {
-                    // Remove any existing theme with the same name
-                    themes.retain(|t| t.name != theme_name);
-                    themes.push(prepared_theme);
-                }
-            }
+    /// Name a prepared theme after its file, or "default"
+    fn theme_name(theme_path: Option<&str>) -> String {
+        theme_path
+            .and_then(|p| {
+                std::path::Path::new(p)
+                    .file_stem()
+                    .map(|s| s.to_string_lossy().to_string())
+            })
+            .unwrap_or_else(|| "default".to_string())
+    }
+
+    fn prepare_theme(theme_name: String, theme_file: MaterialThemeFile) {
+        let prepared_theme = PreparedTheme {
+            name: theme_name.clone(),
+            theme_data: theme_file,
+        };
+
+        if let Ok(mut themes) = PREPARED_THEMES.lock() {
+            // Remove any existing theme with the same name
+            themes.retain(|t| t.name != theme_name);
+            themes.push(prepared_theme);
         }
     }
 
@@ -920,7 +1554,9 @@ impl MaterialThemeContext {
     fn get_embedded_theme_data(theme_path: &str) -> Option<String> {
         // For published packages, theme files are not included so we fallback to runtime loading
         // Users should provide their own theme files or use the default programmatic theme
-        std::fs::read_to_string(theme_path).ok()
+        read_file(theme_path)
+            .ok()
+            .and_then(|data| String::from_utf8(data).ok())
     }
 
     /// Internal implementation for loading prepared themes to the global theme context
@@ -947,77 +1583,318 @@ impl MaterialThemeContext {
         }
     }
 
+    /// Make the prepared theme called `name` the active theme
+    ///
+    /// Unlike `load_themes`, the current mode and contrast level are kept;
+    /// only the color overrides of the previous theme are dropped.
+    pub fn activate_theme(name: &str) -> Result<(), ThemeError> {
+        GLOBAL_THEME
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .apply_prepared_theme(name)?;
+        invalidate_theme_cache();
+        Ok(())
+    }
+
+    /// Switch this context to the prepared theme called `name`
+    ///
+    /// Like [`Self::activate_theme`], but for a context other than the global
+    /// one, e.g. a preview pane drawn with [`with_color_provider`].
+    pub fn apply_prepared_theme(&mut self, name: &str) -> Result<(), ThemeError> {
+        let theme_data = PREPARED_THEMES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .find(|theme| theme.name == name)
+            .map(|theme| theme.theme_data.clone())
+            .ok_or_else(|| ThemeError::NotFound(format!("prepared theme '{}'", name)))?;
+
+        self.material_theme = Some(theme_data);
+        self.selected_colors.clear();
+        Ok(())
+    }
+
     /// Load all prepared fonts to the egui context
+    ///
+    /// The context is remembered so fonts that finish downloading later are
+    /// loaded into it as well.
     pub fn load_fonts(ctx: &egui::Context) {
-        let mut fonts = FontDefinitions::default();
-
-        if let Ok(prepared_fonts) = PREPARED_FONTS.lock() {
-            for prepared_font in prepared_fonts.iter() {
-                // Add font data
-                fonts
-                    .font_data
-                    .insert(prepared_font.name.clone(), prepared_font.data.clone());
-
-                // Add to font families
-                for family in &prepared_font.families {
-                    match family {
-                        FontFamily::Proportional => {
-                            // Google fonts go to the front, icon fonts go to the back
-                            if prepared_font.name.contains("MaterialSymbols") {
-                                fonts
-                                    .families
-                                    .entry(FontFamily::Proportional)
-                                    .or_default()
-                                    .push(prepared_font.name.clone());
-                            } else {
-                                fonts
-                                    .families
-                                    .entry(FontFamily::Proportional)
-                                    .or_default()
-                                    .insert(0, prepared_font.name.clone());
-                            }
-                        }
-                        FontFamily::Monospace => {
-                            fonts
-                                .families
-                                .entry(FontFamily::Monospace)
-                                .or_default()
-                                .push(prepared_font.name.clone());
-                        }
-                        _ => {}
-                    }
-                }
-            }
+        if let Ok(mut font_ctx) = FONT_CONTEXT.lock() {
+            *font_ctx = Some(ctx.clone());
         }
 
+        let fonts = match PREPARED_FONTS.lock() {
+            Ok(prepared_fonts) => font_definitions(&prepared_fonts),
+            Err(_) => FontDefinitions::default(),
+        };
+
         ctx.set_fonts(fonts);
     }
 
-    pub fn get_current_scheme(&self) -> Option<&MaterialScheme> {
-        if let Some(ref theme) = self.material_theme {
-            let scheme_key = match (self.theme_mode, self.contrast_level) {
-                (ThemeMode::Light, ContrastLevel::Normal) => "light",
-                (ThemeMode::Light, ContrastLevel::Medium) => "light-medium-contrast",
-                (ThemeMode::Light, ContrastLevel::High) => "light-high-contrast",
-                (ThemeMode::Dark, ContrastLevel::Normal) => "dark",
-                (ThemeMode::Dark, ContrastLevel::Medium) => "dark-medium-contrast",
-                (ThemeMode::Dark, ContrastLevel::High) => "dark-high-contrast",
-                (ThemeMode::Auto, contrast) => {
-                    // For auto mode, we'll default to light for now
-                    match contrast {
-                        ContrastLevel::Normal => "light",
-                        ContrastLevel::Medium => "light-medium-contrast",
-                        ContrastLevel::High => "light-high-contrast",
-                    }
-                }
-            };
-            theme.schemes.get(scheme_key)
-        } else {
-            None
+    /// Theme Builder scheme key for a mode and contrast level
+    ///
+    /// `ThemeMode::Auto` maps to the light keys here; callers that know the
+    /// system preference should resolve Auto before asking for a key.
+    pub fn scheme_key(mode: ThemeMode, contrast: ContrastLevel) -> &'static str {
+        match (mode, contrast) {
+            (ThemeMode::Dark, ContrastLevel::Normal) => "dark",
+            (ThemeMode::Dark, ContrastLevel::Medium) => "dark-medium-contrast",
+            (ThemeMode::Dark, ContrastLevel::High) => "dark-high-contrast",
+            (_, ContrastLevel::Normal) => "light",
+            (_, ContrastLevel::Medium) => "light-medium-contrast",
+            (_, ContrastLevel::High) => "light-high-contrast",
         }
     }
 
-    pub fn hex_to_color32(hex: &str) -> Option<Color32> {
+    /// Whether the system preferred dark mode when the global theme last saw it
+    ///
+    /// This is what `ThemeMode::Auto` resolves against; it is detected, never saved.
+    pub fn system_dark_mode(&self) -> bool {
+        self.system_dark_mode
+    }
+
+    /// Resolve `ThemeMode::Auto` to Light or Dark
+    ///
+    /// # Arguments
+    /// * `dark_mode` - Whether the system (or egui context) currently prefers dark mode
+    ///
+    /// Explicit Light/Dark modes are returned unchanged.
+    pub fn resolved_mode(&self, dark_mode: bool) -> ThemeMode {
+        match self.theme_mode {
+            ThemeMode::Auto if dark_mode => ThemeMode::Dark,
+            ThemeMode::Auto => ThemeMode::Light,
+            mode => mode,
+        }
+    }
+
+    pub fn get_current_scheme(&self) -> Option<&MaterialScheme> {
+        let theme = self.material_theme.as_ref()?;
+        let mode = self.resolved_mode(self.system_dark_mode);
+        let scheme_key = Self::scheme_key(mode, self.contrast_level);
+        // Theme files without contrast variants fall back to the base scheme
+        // of the same mode instead of dropping to the gray fallbacks.
+        let base_key = Self::scheme_key(mode, ContrastLevel::Normal);
+        theme
+            .schemes
+            .get(scheme_key)
+            .or_else(|| theme.schemes.get(base_key))
+    }
+
+    /// Theme Builder JSON of the current theme with `selected_colors` applied
+    ///
+    /// All schemes of the theme file are written, light and dark alike. The
+    /// overrides go into the scheme in use (resolved mode and contrast level)
+    /// and the rest are exported unchanged, so the result can be imported
+    /// into the Material Theme Builder or loaded with `setup_local_theme`.
+    pub fn export_material_theme_json(&self) -> String {
+        let mut theme = self
+            .material_theme
+            .clone()
+            .unwrap_or_else(get_default_material_theme);
+        let mode = self.resolved_mode(self.system_dark_mode);
+        let scheme_key = Self::scheme_key(mode, self.contrast_level);
+
+        // Overrides made while a missing contrast variant fell back to the base
+        // scheme are written to a new scheme for that variant
+        if !self.selected_colors.is_empty() && !theme.schemes.contains_key(scheme_key) {
+            let base_key = Self::scheme_key(mode, ContrastLevel::Normal);
+            if let Some(base) = theme.schemes.get(base_key).cloned() {
+                theme.schemes.insert(scheme_key.to_string(), base);
+            }
+        }
+        if let Some(scheme) = theme.schemes.get_mut(scheme_key) {
+            *scheme = Self::scheme_with_overrides(scheme, &self.selected_colors);
+        }
+
+        serde_json::to_string_pretty(&theme).unwrap_or_default()
+    }
+
+    /// Copy of `scheme` with the given color tokens replaced
+    ///
+    /// Names that aren't scheme tokens are skipped so the output stays a valid
+    /// Theme Builder scheme.
+    fn scheme_with_overrides(
+        scheme: &MaterialScheme,
+        overrides: &HashMap<String, Color32>,
+    ) -> MaterialScheme {
+        let Ok(serde_json::Value::Object(mut tokens)) = serde_json::to_value(scheme) else {
+            return scheme.clone();
+        };
+        for (name, color) in overrides {
+            if let Some(value) = tokens.get_mut(name) {
+                *value = serde_json::Value::String(Self::color32_to_hex(*color));
+            }
+        }
+        serde_json::from_value(serde_json::Value::Object(tokens)).unwrap_or_else(|_| scheme.clone())
+    }
+
+    /// Generate a light and dark theme from a single seed color
+    ///
+    /// Uses the Material 3 tonal spot palettes: primary keeps the seed hue,
+    /// secondary and the neutrals are muted versions of it and tertiary is
+    /// rotated by 60°. Only the `light` and `dark` schemes are generated; the
+    /// contrast levels fall back to them.
+    pub fn from_seed_color(seed: Color32) -> MaterialThemeFile {
+        let (hue, chroma) = color_to_hue_chroma(seed);
+        let (error_hue, error_chroma) = color_to_hue_chroma(Color32::from_rgb(0xB3, 0x26, 0x1E));
+        let palette = |key: Palette| match key {
+            Palette::Primary => TonalPalette {
+                hue,
+                chroma: chroma.max(48.0),
+            },
+            Palette::Secondary => TonalPalette { hue, chroma: 16.0 },
+            Palette::Tertiary => TonalPalette {
+                hue: (hue + 60.0) % 360.0,
+                chroma: 24.0,
+            },
+            Palette::Error => TonalPalette {
+                hue: error_hue,
+                chroma: error_chroma,
+            },
+            Palette::Neutral => TonalPalette { hue, chroma: 4.0 },
+            Palette::NeutralVariant => TonalPalette { hue, chroma: 8.0 },
+        };
+
+        let scheme = |dark: bool| -> MaterialScheme {
+            let tokens = SCHEME_TONES
+                .iter()
+                .map(|(name, key, light_tone, dark_tone)| {
+                    let tone = if dark { *dark_tone } else { *light_tone };
+                    let hex = Self::color32_to_hex(palette(*key).tone(tone));
+                    (name.to_string(), serde_json::Value::String(hex))
+                })
+                .collect();
+            serde_json::from_value(serde_json::Value::Object(tokens))
+                .expect("SCHEME_TONES covers every scheme token")
+        };
+
+        let mut schemes = HashMap::new();
+        schemes.insert("light".to_string(), scheme(false));
+        schemes.insert("dark".to_string(), scheme(true));
+
+        let palettes = [
+            ("primary", Palette::Primary),
+            ("secondary", Palette::Secondary),
+            ("tertiary", Palette::Tertiary),
+            ("neutral", Palette::Neutral),
+            ("neutral-variant", Palette::NeutralVariant),
+        ]
+        .into_iter()
+        .map(|(name, key)| {
+            let tones: HashMap<String, String> = PALETTE_TONES
+                .iter()
+                .map(|tone| {
+                    (
+                        tone.to_string(),
+                        Self::color32_to_hex(palette(key).tone(*tone)),
+                    )
+                })
+                .collect();
+            (name.to_string(), tones)
+        })
+        .collect();
+
+        let seed_hex = Self::color32_to_hex(seed);
+        MaterialThemeFile {
+            description: "Generated from seed color".to_string(),
+            seed: seed_hex.clone(),
+            core_colors: HashMap::from([("primary".to_string(), seed_hex)]),
+            extended_colors: Vec::new(),
+            schemes,
+            palettes,
+        }
+    }
+
+    /// Generate a theme from the dominant color of an image, like Material You
+    /// does with wallpapers
+    ///
+    /// Accepts PNG, JPEG and WebP data. The most common colorful color becomes
+    /// the seed for [`Self::from_seed_color`]; images without one (e.g. black
+    /// and white photos) get a blue theme. Every pixel is sampled, so pass a
+    /// thumbnail — downscale larger photos first, e.g. to 128×128 with
+    /// `image::imageops::thumbnail`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use egui_material3::theme::{update_global_theme, MaterialThemeContext};
+    ///
+    /// let bytes = std::fs::read("wallpaper_thumbnail.png").unwrap();
+    /// let mut theme = MaterialThemeContext::default();
+    /// theme.material_theme = Some(MaterialThemeContext::from_image_bytes(&bytes).unwrap());
+    /// update_global_theme(theme);
+    /// ```
+    pub fn from_image_bytes(bytes: &[u8]) -> Result<MaterialThemeFile, ThemeError> {
+        let image = image::load_from_memory(bytes)
+            .map_err(|e| ThemeError::Image(e.to_string()))?
+            .to_rgba8();
+        let seed = dominant_color(&image).unwrap_or(FALLBACK_SEED);
+        let mut theme = Self::from_seed_color(seed);
+        theme.description = "Generated from image".to_string();
+        Ok(theme)
+    }
+
+    /// Check the resolved colors of the current scheme against WCAG AA
+    ///
+    /// Covers each "on" token and the container it is drawn on, e.g.
+    /// `onPrimary`/`primary` and `onSurface`/`surface`, including overrides
+    /// from `selected_colors`. Returns one warning per failing pair.
+    pub fn validate_contrast(&self) -> Vec<ContrastWarning> {
+        CONTRAST_PAIRS
+            .iter()
+            .filter_map(|&(foreground, background)| {
+                let ratio = contrast_ratio(
+                    self.get_color_by_name(foreground),
+                    self.get_color_by_name(background),
+                );
+                (ratio < CONTRAST_AA).then_some(ContrastWarning {
+                    foreground,
+                    background,
+                    ratio,
+                    required: CONTRAST_AA,
+                })
+            })
+            .collect()
+    }
+
+    /// Adjust failing "on" tokens until every pair meets WCAG AA
+    ///
+    /// Each failing foreground keeps its hue and is lightened or darkened,
+    /// whichever direction the background leaves more room in, one tone
+    /// at a time. The fixed colors are stored in `selected_colors`. Returns
+    /// the names of the tokens that were changed.
+    pub fn auto_fix_on_colors(&mut self) -> Vec<&'static str> {
+        let warnings = self.validate_contrast();
+        for warning in &warnings {
+            let foreground = self.get_color_by_name(warning.foreground);
+            let background = self.get_color_by_name(warning.background);
+            let (hue, chroma) = color_to_hue_chroma(foreground);
+            let palette = TonalPalette { hue, chroma };
+            let tone = color_to_lab(foreground)[0].round().clamp(0.0, 100.0) as u8;
+
+            // Black or white always reach 4.5:1, so one of the two directions ends in a fix
+            let lighten = contrast_ratio(Color32::WHITE, background)
+                > contrast_ratio(Color32::BLACK, background);
+            let tones: Vec<u8> = if lighten {
+                (tone..=100).collect()
+            } else {
+                (0..=tone).rev().collect()
+            };
+            let fixed = tones
+                .into_iter()
+                .map(|tone| palette.tone(tone))
+                .find(|color| contrast_ratio(*color, background) >= CONTRAST_AA)
+                .unwrap_or(if lighten {
+                    Color32::WHITE
+                } else {
+                    Color32::BLACK
+                });
+            self.selected_colors
+                .insert(warning.foreground.to_string(), fixed);
+        }
+        warnings.iter().map(|warning| warning.foreground).collect()
+    }
+
+    pub fn hex_to_color32(hex: &str) -> Option<Color32> {
         if hex.starts_with('#') && hex.len() == 7 {
             if let Ok(r) = u8::from_str_radix(&hex[1..3], 16) {
                 if let Ok(g) = u8::from_str_radix(&hex[3..5], 16) {
@@ -1209,15 +2086,37 @@ pub fn update_global_theme(theme: MaterialThemeContext) {
     if let Ok(mut global_theme) = GLOBAL_THEME.lock() {
         *global_theme = theme;
     }
+    invalidate_theme_cache();
 }
 
 /// Helper function to prepare Material Design fonts for the application
 /// Default font is "Google Sans Code" if not specified
 /// Note: Fonts are only prepared, call load_fonts() to actually load them
+///
+/// Fonts missing from `resources/` are downloaded in the background with the
+/// `ondemand` feature; see [`font_load_status`].
 pub fn setup_google_fonts(font_name: Option<&str>) {
     MaterialThemeContext::setup_fonts(font_name);
 }
 
+/// Like [`setup_google_fonts`], placing the font at `priority` in the fallback chain
+///
+/// Use [`FontPriority::Fallback`] for fonts that only fill in missing glyphs,
+/// such as a CJK font next to a Latin primary font.
+pub fn setup_google_fonts_with_priority(font_name: Option<&str>, priority: FontPriority) {
+    if let Err(e) = MaterialThemeContext::try_setup_fonts_with_priority(font_name, priority) {
+        eprintln!("Failed to set up font: {}", e);
+    }
+}
+
+/// Like [`setup_google_fonts`], but returns an error instead of printing it
+///
+/// A font that has to be downloaded returns `Ok` right away; whether the
+/// download succeeds is reported by [`font_load_status`].
+pub fn try_setup_google_fonts(font_name: Option<&str>) -> Result<(), ThemeError> {
+    MaterialThemeContext::try_setup_fonts(font_name)
+}
+
 /// Helper function to prepare local fonts from the resources directory
 ///
 /// # Arguments
@@ -1228,6 +2127,11 @@ pub fn setup_local_fonts(font_path: Option<&str>) {
     MaterialThemeContext::setup_local_fonts(font_path);
 }
 
+/// Like [`setup_local_fonts`], but returns an error for a missing or unreadable font file
+pub fn try_setup_local_fonts(font_path: &str) -> Result<(), ThemeError> {
+    MaterialThemeContext::try_setup_local_fonts(font_path)
+}
+
 /// Prepare a local font from pre-loaded byte data
 ///
 /// # Arguments
@@ -1239,6 +2143,20 @@ pub fn setup_local_fonts_from_bytes(font_name: &str, font_data: &[u8]) {
     MaterialThemeContext::setup_local_fonts_from_bytes(font_name, font_data);
 }
 
+/// Like [`setup_local_fonts_from_bytes`], placing the font at `priority` in the fallback chain
+///
+/// Without a priority, fonts named like "MaterialSymbols" are icon fonts and
+/// all others are primary text fonts.
+pub fn setup_local_fonts_from_bytes_with_priority(
+    font_name: &str,
+    font_data: &[u8],
+    priority: FontPriority,
+) {
+    MaterialThemeContext::setup_local_fonts_from_bytes_with_priority(
+        font_name, font_data, priority,
+    );
+}
+
 /// Prepare local Material Design themes for the application from JSON files
 ///
 /// This function loads Material Design theme data from JSON files and prepares them for use.
@@ -1274,6 +2192,27 @@ pub fn setup_local_theme(theme_path: Option<&str>) {
     MaterialThemeContext::setup_local_theme(theme_path);
 }
 
+/// Like [`setup_local_theme`], but returns an error instead of falling back or printing it
+///
+/// # Errors
+/// - `ThemeError::NotFound` if `theme_path` doesn't exist
+/// - `ThemeError::Io` if it can't be read
+/// - `ThemeError::Parse` if it isn't a valid Theme Builder export
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::{try_setup_local_theme, ThemeError};
+///
+/// match try_setup_local_theme(Some("my-theme.json")) {
+///     Ok(()) => {}
+///     Err(ThemeError::Parse(e)) => eprintln!("Theme file is malformed: {}", e),
+///     Err(e) => eprintln!("Could not load theme: {}", e),
+/// }
+/// ```
+pub fn try_setup_local_theme(theme_path: Option<&str>) -> Result<(), ThemeError> {
+    MaterialThemeContext::try_setup_local_theme(theme_path)
+}
+
 /// Load all prepared themes to the global theme context
 ///
 /// This function takes themes that were prepared by `setup_local_theme()` and applies
@@ -1292,13 +2231,52 @@ pub fn setup_local_theme(theme_path: Option<&str>) {
 /// ```
 ///
 /// # Behavior
-/// - If multiple themes were prepared, only the first one becomes active
+/// - If multiple themes were prepared, only the first one becomes active; switch
+///   to another with [`activate_theme`]
 /// - If no themes were prepared, the default built-in theme is used
 /// - The active theme becomes available via `get_global_color()` and other theme functions
 pub fn load_themes() {
     MaterialThemeContext::load_themes();
 }
 
+/// Names of all themes prepared by `setup_local_theme()`, in preparation order
+///
+/// A theme is named after its file without the extension, e.g.
+/// `"material-theme6"`, or `"default"` for `setup_local_theme(None)`.
+/// Pass one to [`activate_theme`] to switch to it, e.g. from a theme picker.
+pub fn available_theme_names() -> Vec<String> {
+    PREPARED_THEMES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .map(|theme| theme.name.clone())
+        .collect()
+}
+
+/// Switch the global theme to the prepared theme called `name`
+///
+/// The theme mode and contrast level stay as they are, while color overrides
+/// made for the previous theme are cleared. With theme animation enabled,
+/// components fade over to the new colors.
+///
+/// # Errors
+/// - `ThemeError::NotFound` if no theme called `name` was prepared
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::{activate_theme, available_theme_names, setup_local_theme};
+///
+/// setup_local_theme(Some("resources/material-theme1.json"));
+/// setup_local_theme(Some("resources/material-theme2.json"));
+/// for name in available_theme_names() {
+///     println!("{}", name);
+/// }
+/// activate_theme("material-theme2").unwrap();
+/// ```
+pub fn activate_theme(name: &str) -> Result<(), ThemeError> {
+    MaterialThemeContext::activate_theme(name)
+}
+
 /// Load a Material Design theme directly from a JSON string
 ///
 /// This function parses a Material Design theme JSON string and applies it to the global theme context.
@@ -1324,17 +2302,51 @@ pub fn load_themes() {
 /// ```
 pub fn load_theme_from_json_str(json_data: &str) -> Result<(), String> {
     let theme_file = serde_json::from_str::<MaterialThemeFile>(json_data)
-        .map_err(|e| format!("Failed to parse theme JSON: {}", e))?;
+        .map_err(|e| ThemeError::Parse(e).to_string())?;
 
     if let Ok(mut global_theme) = get_global_theme().lock() {
         global_theme.material_theme = Some(theme_file);
         global_theme.selected_colors.clear();
+        drop(global_theme);
+        invalidate_theme_cache();
         Ok(())
     } else {
         Err("Failed to acquire theme lock".to_string())
     }
 }
 
+/// Serialize the active theme (mode, contrast, theme file and color overrides) to JSON
+///
+/// Store the result in the app's own config and pass it to
+/// [`load_theme_from_json`] on the next start. Unlike the Theme Builder files
+/// read by [`load_theme_from_json_str`], this captures the user's choices too.
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::{load_theme_from_json, save_theme_to_json};
+///
+/// let saved = save_theme_to_json().unwrap();
+/// // ... later, e.g. on the next launch
+/// load_theme_from_json(&saved).unwrap();
+/// ```
+pub fn save_theme_to_json() -> Result<String, ThemeError> {
+    let theme = GLOBAL_THEME.lock().unwrap_or_else(|e| e.into_inner());
+    Ok(serde_json::to_string_pretty(&*theme)?)
+}
+
+/// Restore a theme saved with [`save_theme_to_json`] as the global theme
+///
+/// The current system dark-mode preference is kept, since it isn't saved.
+pub fn load_theme_from_json(json_data: &str) -> Result<(), ThemeError> {
+    let mut theme = serde_json::from_str::<MaterialThemeContext>(json_data)?;
+    if let Ok(mut global_theme) = GLOBAL_THEME.lock() {
+        theme.system_dark_mode = global_theme.system_dark_mode;
+        *global_theme = theme;
+    }
+    invalidate_theme_cache();
+    Ok(())
+}
+
 /// Trait to provide a unified interface for accessing egui Context
 pub trait ContextRef {
     fn context_ref(&self) -> &egui::Context;
@@ -1380,7 +2392,8 @@ pub fn load_fonts<C: ContextRef>(ctx: C) {
 /// - Medium contrast: `surfaceContainerLow`
 /// - Normal contrast: `surface`
 ///
-/// **Auto Theme:** Uses `surface` as default
+/// **Auto Theme:** Resolved to Light or Dark from the system theme reported by
+/// the egui context (falling back to `visuals.dark_mode`)
 ///
 /// # Usage
 /// This function should be called:
@@ -1408,35 +2421,28 @@ pub fn load_fonts<C: ContextRef>(ctx: C) {
 /// - `extreme_bg_color` - Background color for extreme contrast areas
 pub fn update_window_background<C: ContextRef>(ctx: C) {
     let ctx = ctx.context_ref();
-    if let Ok(theme) = GLOBAL_THEME.lock() {
-        // Resolve Auto mode to actual mode based on context
-        let mut resolved_theme = theme.clone();
-        if resolved_theme.theme_mode == ThemeMode::Auto {
-            if ctx.style().visuals.dark_mode {
-                resolved_theme.theme_mode = ThemeMode::Dark;
-            } else {
-                resolved_theme.theme_mode = ThemeMode::Light;
-            }
+    let dark_mode = context_prefers_dark(ctx);
+    if let Ok(mut theme) = GLOBAL_THEME.lock() {
+        // Remember the system preference so Auto resolves the same way for every component
+        if theme.system_dark_mode != dark_mode {
+            theme.system_dark_mode = dark_mode;
+            invalidate_theme_cache();
         }
+        let mode = theme.resolved_mode(dark_mode);
 
         // Get the appropriate background color from the material theme
-        let background_color = match (resolved_theme.theme_mode, resolved_theme.contrast_level) {
-            (ThemeMode::Dark, ContrastLevel::High) => {
-                resolved_theme.get_color_by_name("surfaceContainerHighest")
-            }
-            (ThemeMode::Dark, ContrastLevel::Medium) => {
-                resolved_theme.get_color_by_name("surfaceContainerHigh")
-            }
-            (ThemeMode::Dark, _) => resolved_theme.get_color_by_name("surface"),
-            (ThemeMode::Light, ContrastLevel::High) => {
-                resolved_theme.get_color_by_name("surfaceContainerLowest")
-            }
-            (ThemeMode::Light, ContrastLevel::Medium) => {
-                resolved_theme.get_color_by_name("surfaceContainerLow")
-            }
-            (ThemeMode::Light, _) => resolved_theme.get_color_by_name("surface"),
-            (ThemeMode::Auto, _) => resolved_theme.get_color_by_name("surface"), // Should be unreachable
+        let background_token = match (mode, theme.contrast_level) {
+            (ThemeMode::Dark, ContrastLevel::High) => "surfaceContainerHighest",
+            (ThemeMode::Dark, ContrastLevel::Medium) => "surfaceContainerHigh",
+            (ThemeMode::Light, ContrastLevel::High) => "surfaceContainerLowest",
+            (ThemeMode::Light, ContrastLevel::Medium) => "surfaceContainerLow",
+            _ => "surface",
         };
+        drop(theme);
+
+        // Fade the background along with the components during a theme change
+        animate_theme(ctx);
+        let background_color = get_global_color(background_token);
 
         // Apply the background color to the context
         let mut visuals = ctx.style().visuals.clone();
@@ -1450,8 +2456,138 @@ pub fn update_window_background<C: ContextRef>(ctx: C) {
     }
 }
 
-/// Helper function to get a color by name from the global theme
+/// Re-apply the Material visuals and window background if the theme changed
+///
+/// Call once per frame, at the start of `update`. It does nothing while the
+/// theme is unchanged, and otherwise runs [`apply_material_visuals`] and
+/// [`update_window_background`], so changing the mode, contrast or theme file
+/// anywhere in the app is reflected without further calls. Returns whether
+/// the visuals were applied this frame.
+///
+/// Changes are tracked with [`theme_version`], which the setters in this
+/// module bump. After editing `get_global_theme().lock()` directly, call
+/// [`invalidate_theme_cache`]. `update_window_background` is still there for
+/// apps that want to apply the theme themselves.
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::{apply_if_changed, set_theme_mode, ThemeMode};
+///
+/// // In eframe::App::update
+/// apply_if_changed(ctx);
+///
+/// // Anywhere in the app; the new mode is applied on the next frame
+/// set_theme_mode(ThemeMode::Dark);
+/// ```
+pub fn apply_if_changed<C: ContextRef>(ctx: C) -> bool {
+    let ctx = ctx.context_ref();
+    let id = egui::Id::new("egui_material3_applied_theme");
+    let current = (theme_version(), context_prefers_dark(ctx));
+    let applied = ctx.data(|data| data.get_temp::<(u64, bool)>(id));
+    if applied == Some(current) && !THEME_ANIMATING.load(Ordering::Relaxed) {
+        animate_theme(ctx);
+        return false;
+    }
+
+    apply_material_visuals(ctx);
+    update_window_background(ctx);
+    // Applying can itself bump the version (a new system preference) or flip the visuals
+    let applied = (theme_version(), context_prefers_dark(ctx));
+    ctx.data_mut(|data| data.insert_temp(id, applied));
+    true
+}
+
+/// Whether the egui context prefers dark mode
+///
+/// Uses the system theme reported by the integration when available and falls
+/// back to the context's current visuals otherwise.
+fn context_prefers_dark(ctx: &egui::Context) -> bool {
+    match ctx.input(|i| i.raw.system_theme) {
+        Some(egui::Theme::Dark) => true,
+        Some(egui::Theme::Light) => false,
+        None => ctx.style().visuals.dark_mode,
+    }
+}
+
+/// Source of theme colors by token name, e.g. `"primary"` or `"onSurface"`
+///
+/// [`get_global_color`] asks the innermost [`ThemeScope`] first and falls
+/// back to [`GlobalColors`]. Implement this to resolve colors from your own
+/// source, and draw components with it through [`with_color_provider`].
+///
+/// # Example
+/// ```rust
+/// use egui::Color32;
+/// use egui_material3::theme::{with_color_provider, ColorProvider, MaterialThemeContext};
+/// use egui_material3::MaterialButton;
+///
+/// // Everything is magenta, whatever the global theme says
+/// struct Magenta;
+///
+/// impl ColorProvider for Magenta {
+///     fn color(&self, _name: &str) -> Option<Color32> {
+///         Some(Color32::from_rgb(0xFF, 0x00, 0xFF))
+///     }
+/// }
+///
+/// # egui::__run_test_ui(|ui| {
+/// with_color_provider(ui, &Magenta, |ui| {
+///     ui.add(MaterialButton::filled("Magenta"));
+/// });
+/// # });
+/// ```
+pub trait ColorProvider {
+    /// The color of token `name`, or `None` to leave it to outer providers
+    fn color(&self, name: &str) -> Option<Color32>;
+}
+
+impl ColorProvider for MaterialThemeContext {
+    fn color(&self, name: &str) -> Option<Color32> {
+        Some(self.get_color_by_name(name))
+    }
+}
+
+impl ColorProvider for HashMap<String, Color32> {
+    fn color(&self, name: &str) -> Option<Color32> {
+        self.get(name).copied()
+    }
+}
+
+impl<P: ColorProvider + ?Sized> ColorProvider for Arc<P> {
+    fn color(&self, name: &str) -> Option<Color32> {
+        (**self).color(name)
+    }
+}
+
+/// The process-wide theme as a [`ColorProvider`]
+///
+/// While a theme crossfade is running (see [`set_theme_animation_enabled`])
+/// this returns the interpolated color. Otherwise the colors resolved by
+/// [`begin_frame`] are used, so most lookups don't lock the global theme.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GlobalColors;
+
+impl ColorProvider for GlobalColors {
+    fn color(&self, name: &str) -> Option<Color32> {
+        let color = animated_color(name)
+            .or_else(|| cached_color(name))
+            .unwrap_or_else(|| global_theme_color(name));
+        Some(color)
+    }
+}
+
+/// Helper function to get a color by name from the current [`ColorProvider`]
+///
+/// Inside a [`ThemeScope`] the scope's colors take precedence; tokens it
+/// doesn't define come from [`GlobalColors`].
 pub fn get_global_color(name: &str) -> Color32 {
+    scoped_color(name)
+        .or_else(|| GlobalColors.color(name))
+        .unwrap_or_else(|| global_theme_color(name))
+}
+
+/// Color of `name` read straight from the locked global theme
+fn global_theme_color(name: &str) -> Color32 {
     if let Ok(theme) = GLOBAL_THEME.lock() {
         theme.get_color_by_name(name)
     } else {
@@ -1505,137 +2641,418 @@ pub fn detect_os_theme() -> ThemeMode {
     }
 }
 
-/// Apply the Material Design 3 theme to the egui context
-///
-/// This function applies the current global theme to the egui visual system,
-/// comprehensively mapping Material Design 3 color roles to egui visuals.
-///
-/// # Material Design 3 Color Mappings
-///
-/// ## Widget States
-/// - **Noninteractive** (disabled): surface, surfaceVariant, outlineVariant, onSurfaceVariant
-/// - **Inactive** (default): primary with low opacity, outline, onSurface
-/// - **Hovered**: primary with medium opacity, outline, onSurface
-/// - **Active** (pressed/selected): primary, onPrimary, primaryContainer
-/// - **Open** (menus/dropdowns): primaryContainer, onPrimaryContainer, outline
-///
-/// ## Backgrounds
-/// - **window_fill**: surface
-/// - **panel_fill**: surfaceContainer
-/// - **faint_bg_color**: surfaceContainerLow
-/// - **extreme_bg_color**: surfaceContainerLowest
-/// - **code_bg_color**: surfaceContainerHighest
+/// Opacity of the `surfaceTint` overlay at each M3 elevation level (0–5)
+const SURFACE_TINT_OPACITY: [f32; 6] = [0.0, 0.05, 0.08, 0.11, 0.12, 0.14];
+
+/// Opacity of the hover state layer
+pub(crate) const HOVER_STATE_LAYER_OPACITY: f32 = 0.08;
+
+/// Opacity of the pressed state layer
+pub(crate) const PRESSED_STATE_LAYER_OPACITY: f32 = 0.12;
+
+/// `base` with `overlay` drawn on top at `opacity`, as for M3 state layers
+fn overlay_color(base: Color32, overlay: Color32, opacity: f32) -> Color32 {
+    Color32::from_rgb(base.r(), base.g(), base.b()).lerp_to_gamma(
+        Color32::from_rgb(overlay.r(), overlay.g(), overlay.b()),
+        opacity,
+    )
+}
+
+/// A surface tinted with `surfaceTint` for the given M3 elevation level (0–5)
 ///
-/// ## Text & Interaction
-/// - **override_text_color**: onSurface
-/// - **text_cursor**: primary
-/// - **hyperlink_color**: primary
-/// - **selection**: primary
+/// Higher levels get a stronger tint, which is how Material 3 shows elevation
+/// in dark themes where shadows are barely visible.
+pub fn tinted_surface(surface: Color32, surface_tint: Color32, elevation_level: usize) -> Color32 {
+    let opacity = SURFACE_TINT_OPACITY[elevation_level.min(SURFACE_TINT_OPACITY.len() - 1)];
+    overlay_color(surface, surface_tint, opacity)
+}
+
+/// Ambient shadow of each M3 elevation level (0–5) as `(y offset, blur, spread)` in dp
 ///
-/// ## Feedback Colors
-/// - **error_fg_color**: error
-/// - **warn_fg_color**: tertiary
+/// Level 1 is 1dp, level 2 3dp, level 3 6dp, level 4 8dp and level 5 12dp.
+const ELEVATION_SHADOWS: [(i8, u8, u8); 6] = [
+    (0, 0, 0),
+    (1, 3, 1),
+    (2, 6, 2),
+    (4, 8, 3),
+    (6, 10, 4),
+    (8, 12, 6),
+];
+
+/// Opacity of the `shadow` token in elevation shadows
+const SHADOW_OPACITY: f32 = 0.3;
+
+/// The M3 elevation level (0–5) for an elevation in dp
 ///
-/// ## Strokes & Shadows
-/// - **window_stroke**: outlineVariant
-/// - **window_shadow**: shadow
-/// - **popup_shadow**: shadow
+/// Elevations between two levels round up, so 2dp is level 2 (3dp).
+pub fn elevation_level(elevation_dp: f32) -> u8 {
+    match elevation_dp {
+        dp if dp <= 0.0 => 0,
+        dp if dp <= 1.0 => 1,
+        dp if dp <= 3.0 => 2,
+        dp if dp <= 6.0 => 3,
+        dp if dp <= 8.0 => 4,
+        _ => 5,
+    }
+}
+
+/// The drop shadow for an M3 elevation level (0–5), in the theme's `shadow` color
 ///
-/// # Parameters
-/// - `ctx`: The egui context to apply the theme to
-/// - `os_theme_detector`: Optional function to detect OS theme mode when `ThemeMode::Auto` is set.
-///   If not provided, uses the default `detect_os_theme()` function.
+/// egui draws a single shadow per shape, so this follows the spec's ambient
+/// shadow (offset, blur and spread) at the darker key shadow's opacity.
+/// Levels above 5 are clamped to 5.
 ///
 /// # Example
 /// ```rust,no_run
-/// use egui_material3::theme::{apply_theme, ThemeMode};
+/// use egui_material3::theme::elevation_shadow;
 ///
-/// // Use default OS theme detection
-/// apply_theme(&egui_ctx, None);
-///
-/// // Provide custom theme detection (e.g., for Android)
-/// apply_theme(&egui_ctx, Some(|| {
-///     // Custom Android theme detection logic
-///     ThemeMode::Dark
-/// }));
+/// # egui::__run_test_ui(|ui| {
+/// let rect = ui.available_rect_before_wrap();
+/// ui.painter().add(elevation_shadow(3).as_shape(rect, 12.0));
+/// # });
 /// ```
-pub fn apply_theme<C, F>(ctx: C, os_theme_detector: Option<F>)
-where
-    C: ContextRef,
-    F: FnOnce() -> ThemeMode,
-{
-    let ctx = ctx.context_ref();
-
-    let mut theme = if let Ok(theme) = GLOBAL_THEME.lock() {
-        theme.clone()
+pub fn elevation_shadow(level: u8) -> egui::epaint::Shadow {
+    let (offset_y, blur, spread) =
+        ELEVATION_SHADOWS[(level as usize).min(ELEVATION_SHADOWS.len() - 1)];
+    let color = if level == 0 {
+        Color32::TRANSPARENT
     } else {
-        return;
-    };
-
-    let mut visuals = match theme.theme_mode {
-        ThemeMode::Light => egui::Visuals::light(),
-        ThemeMode::Dark => egui::Visuals::dark(),
-        ThemeMode::Auto => {
-            // Detect OS theme preference
-            let detected_mode = if let Some(detector) = os_theme_detector {
-                detector()
-            } else {
-                detect_os_theme()
-            };
-            theme.theme_mode = detected_mode; // Resolve Auto to detected OS theme
-            match detected_mode {
-                ThemeMode::Dark => egui::Visuals::dark(),
-                _ => egui::Visuals::light(),
-            }
-        }
+        let shadow = get_global_color("shadow");
+        Color32::from_rgba_unmultiplied(
+            shadow.r(),
+            shadow.g(),
+            shadow.b(),
+            (SHADOW_OPACITY * 255.0) as u8,
+        )
     };
+    egui::epaint::Shadow {
+        offset: [0, offset_y],
+        blur,
+        spread,
+        color,
+    }
+}
 
-    // Extract Material Design 3 color roles
-    let primary = theme.get_primary_color();
-    let on_primary = theme.get_on_primary_color();
-    let primary_container = theme.get_color_by_name("primaryContainer");
-    let on_primary_container = theme.get_color_by_name("onPrimaryContainer");
+/// The drop shadow at a fractional M3 elevation level (0.0–5.0)
+///
+/// Offset, blur and spread are interpolated between the two surrounding
+/// levels, and the shadow fades in between levels 0 and 1, so an animated
+/// level lifts a surface smoothly. Whole levels match [`elevation_shadow`].
+pub fn elevation_shadow_at(level: f32) -> egui::epaint::Shadow {
+    let level = level.clamp(0.0, 5.0);
+    let lower = level.floor() as u8;
+    let t = level - lower as f32;
+    let from = elevation_shadow(lower);
+    let to = elevation_shadow(lower + 1);
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+    egui::epaint::Shadow {
+        offset: [
+            0,
+            lerp(from.offset[1] as f32, to.offset[1] as f32).round() as i8,
+        ],
+        blur: lerp(from.blur as f32, to.blur as f32).round() as u8,
+        spread: lerp(from.spread as f32, to.spread as f32).round() as u8,
+        color: if lower == 0 {
+            to.color.gamma_multiply(t)
+        } else {
+            from.color
+        },
+    }
+}
 
-    let secondary = theme.get_color_by_name("secondary");
-    let on_secondary = theme.get_color_by_name("onSecondary");
-    let secondary_container = theme.get_color_by_name("secondaryContainer");
+/// The `surfaceTint` overlay for an M3 elevation level (0–5)
+///
+/// Paint it over a surface to get the tonal elevation of [`tinted_surface`];
+/// level 0 is fully transparent.
+pub fn surface_tint_overlay(level: u8) -> Color32 {
+    let opacity = SURFACE_TINT_OPACITY[(level as usize).min(SURFACE_TINT_OPACITY.len() - 1)];
+    let tint = get_global_color("surfaceTint");
+    Color32::from_rgba_unmultiplied(
+        tint.r(),
+        tint.g(),
+        tint.b(),
+        (opacity * 255.0).round() as u8,
+    )
+}
 
-    let tertiary = theme.get_color_by_name("tertiary");
+/// M3 shape scale tokens, from square to fully rounded corners
+///
+/// Components that take a `.shape(Shape)` resolve it with [`shape_radius`],
+/// and their default corners come from the same tokens, so
+/// [`set_shape_scale`] makes the whole app rounder or squarer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Shape {
+    /// Square corners
+    None,
+    /// 4dp by default, e.g. data tables and menus
+    ExtraSmall,
+    /// 8dp by default, e.g. chips
+    Small,
+    /// 12dp by default, e.g. cards
+    Medium,
+    /// 16dp by default, e.g. navigation drawers
+    Large,
+    /// 28dp by default, e.g. dialogs
+    ExtraLarge,
+    /// Semicircular ends, e.g. buttons
+    Full,
+}
 
-    let error = theme.get_color_by_name("error");
-    let on_error = theme.get_color_by_name("onError");
-    let error_container = theme.get_color_by_name("errorContainer");
+/// Corner radius in dp of each [`Shape`] token between `None` and `Full`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShapeScale {
+    pub extra_small: u8,
+    pub small: u8,
+    pub medium: u8,
+    pub large: u8,
+    pub extra_large: u8,
+}
 
-    let surface = theme.get_surface_color(visuals.dark_mode);
-    let on_surface = theme.get_color_by_name("onSurface");
-    let surface_variant = theme.get_color_by_name("surfaceVariant");
-    let on_surface_variant = theme.get_color_by_name("onSurfaceVariant");
+impl ShapeScale {
+    /// The Material Design 3 shape scale: 4, 8, 12, 16 and 28dp
+    pub const MATERIAL: Self = Self {
+        extra_small: 4,
+        small: 8,
+        medium: 12,
+        large: 16,
+        extra_large: 28,
+    };
 
-    let surface_container = theme.get_color_by_name("surfaceContainer");
-    let surface_container_high = theme.get_color_by_name("surfaceContainerHigh");
-    let surface_container_highest = theme.get_color_by_name("surfaceContainerHighest");
-    let surface_container_low = theme.get_color_by_name("surfaceContainerLow");
-    let surface_container_lowest = theme.get_color_by_name("surfaceContainerLowest");
+    /// The corner radius of `shape` on this scale
+    ///
+    /// `Full` is the largest possible radius, which egui clamps to half the
+    /// shorter side of the shape.
+    pub fn radius(&self, shape: Shape) -> egui::CornerRadius {
+        egui::CornerRadius::same(match shape {
+            Shape::None => 0,
+            Shape::ExtraSmall => self.extra_small,
+            Shape::Small => self.small,
+            Shape::Medium => self.medium,
+            Shape::Large => self.large,
+            Shape::ExtraLarge => self.extra_large,
+            Shape::Full => u8::MAX,
+        })
+    }
+}
 
-    let outline = theme.get_color_by_name("outline");
-    let outline_variant = theme.get_color_by_name("outlineVariant");
+impl Default for ShapeScale {
+    fn default() -> Self {
+        Self::MATERIAL
+    }
+}
 
-    let inverse_surface = theme.get_color_by_name("inverseSurface");
-    let inverse_on_surface = theme.get_color_by_name("inverseOnSurface");
-    let inverse_primary = theme.get_color_by_name("inversePrimary");
+static SHAPE_SCALE: Mutex<ShapeScale> = Mutex::new(ShapeScale::MATERIAL);
 
-    let shadow = theme.get_color_by_name("shadow");
+/// The shape scale used by [`shape_radius`]
+pub fn get_shape_scale() -> ShapeScale {
+    SHAPE_SCALE
+        .lock()
+        .map(|scale| *scale)
+        .unwrap_or(ShapeScale::MATERIAL)
+}
 
-    // === Selection colors ===
-    visuals.selection.bg_fill = primary;
-    visuals.selection.stroke.color = primary;
+/// Replace the shape scale for every component, e.g. halve it for a squarer look
+///
+/// Components pick up the new radii the next time they are built.
+pub fn set_shape_scale(scale: ShapeScale) {
+    if let Ok(mut current) = SHAPE_SCALE.lock() {
+        *current = scale;
+    }
+}
 
-    // === Hyperlink ===
-    visuals.hyperlink_color = primary;
+/// The corner radius of a shape token on the current shape scale
+///
+/// # Example
+/// ```rust
+/// use egui_material3::theme::{shape_radius, Shape};
+///
+/// assert_eq!(shape_radius(Shape::Medium), egui::CornerRadius::same(12));
+/// ```
+pub fn shape_radius(shape: Shape) -> egui::CornerRadius {
+    get_shape_scale().radius(shape)
+}
+
+/// M3 type scale roles, from the largest display text to the smallest label
+///
+/// Sizes are given in sp by the spec and used as egui points one to one: egui
+/// points are density-independent like dp and sp, so a 16sp body line is
+/// `FontId::proportional(16.0)`. Font weights aren't applied, since egui
+/// draws each font family at a single weight.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TypeRole {
+    /// 57sp
+    DisplayLarge,
+    /// 45sp
+    DisplayMedium,
+    /// 36sp
+    DisplaySmall,
+    /// 32sp
+    HeadlineLarge,
+    /// 28sp
+    HeadlineMedium,
+    /// 24sp, e.g. dialog titles
+    HeadlineSmall,
+    /// 22sp, e.g. top app bar and drawer titles
+    TitleLarge,
+    /// 16sp
+    TitleMedium,
+    /// 14sp, e.g. tabs and drawer section headers
+    TitleSmall,
+    /// 16sp
+    BodyLarge,
+    /// 14sp, e.g. supporting text
+    BodyMedium,
+    /// 12sp
+    BodySmall,
+    /// 14sp, e.g. buttons, chips and navigation drawer items
+    LabelLarge,
+    /// 12sp, e.g. navigation bar labels
+    LabelMedium,
+    /// 11sp
+    LabelSmall,
+}
+
+/// Font size of each [`TypeRole`], in points
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TypeScale {
+    sizes: [f32; 15],
+}
+
+impl TypeScale {
+    /// The Material Design 3 type scale
+    pub const MATERIAL: Self = Self {
+        sizes: [
+            57.0, 45.0, 36.0, // Display
+            32.0, 28.0, 24.0, // Headline
+            22.0, 16.0, 14.0, // Title
+            16.0, 14.0, 12.0, // Body
+            14.0, 12.0, 11.0, // Label
+        ],
+    };
+
+    /// The font size of `role`
+    pub fn size(&self, role: TypeRole) -> f32 {
+        self.sizes[role as usize]
+    }
+
+    /// Override the font size of a single role
+    pub fn with_size(mut self, role: TypeRole, size: f32) -> Self {
+        self.sizes[role as usize] = size;
+        self
+    }
+
+    /// Multiply every size by `factor`, e.g. 1.15 for larger text throughout
+    pub fn scaled(mut self, factor: f32) -> Self {
+        for size in &mut self.sizes {
+            *size *= factor;
+        }
+        self
+    }
+
+    /// The proportional font at the size of `role`
+    ///
+    /// Uses whichever fonts [`load_fonts`] put in the proportional family.
+    pub fn font_id(&self, role: TypeRole) -> egui::FontId {
+        egui::FontId::proportional(self.size(role))
+    }
+}
+
+impl Default for TypeScale {
+    fn default() -> Self {
+        Self::MATERIAL
+    }
+}
+
+static TYPE_SCALE: Mutex<TypeScale> = Mutex::new(TypeScale::MATERIAL);
+
+/// The type scale used by [`type_font_id`] and the components
+pub fn get_type_scale() -> TypeScale {
+    TYPE_SCALE
+        .lock()
+        .map(|scale| *scale)
+        .unwrap_or(TypeScale::MATERIAL)
+}
+
+/// Replace the type scale for every component
+pub fn set_type_scale(scale: TypeScale) {
+    if let Ok(mut current) = TYPE_SCALE.lock() {
+        *current = scale;
+    }
+}
+
+/// The font of a type role on the current type scale
+///
+/// # Example
+/// ```rust
+/// use egui_material3::theme::{type_font_id, TypeRole};
+///
+/// assert_eq!(type_font_id(TypeRole::BodyLarge), egui::FontId::proportional(16.0));
+/// ```
+pub fn type_font_id(role: TypeRole) -> egui::FontId {
+    get_type_scale().font_id(role)
+}
+
+/// Material typography helpers for [`egui::Ui`]
+pub trait MaterialUiExt {
+    /// Add a label in the font of an M3 type role
+    ///
+    /// ```rust
+    /// use egui_material3::theme::{MaterialUiExt, TypeRole};
+    ///
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.material_label("Settings", TypeRole::HeadlineSmall);
+    /// # });
+    /// ```
+    fn material_label(&mut self, text: impl Into<String>, role: TypeRole) -> egui::Response;
+}
+
+impl MaterialUiExt for egui::Ui {
+    fn material_label(&mut self, text: impl Into<String>, role: TypeRole) -> egui::Response {
+        self.label(egui::RichText::new(text).font(type_font_id(role)))
+    }
+}
+
+/// Map the colors of `theme` onto egui visuals for light or dark mode
+fn material_visuals(theme: &MaterialThemeContext, dark_mode: bool) -> egui::Visuals {
+    let mut visuals = if dark_mode {
+        egui::Visuals::dark()
+    } else {
+        egui::Visuals::light()
+    };
+    let color = |name: &str| theme.get_color_by_name(name);
+
+    let primary = color("primary");
+    let on_primary = color("onPrimary");
+    let primary_container = color("primaryContainer");
+    let on_primary_container = color("onPrimaryContainer");
+    let secondary_container = color("secondaryContainer");
+    let on_secondary_container = color("onSecondaryContainer");
+    let tertiary = color("tertiary");
+    let error = color("error");
+    let surface = color("surface");
+    let surface_tint = color("surfaceTint");
+    let on_surface = color("onSurface");
+    let surface_variant = color("surfaceVariant");
+    let on_surface_variant = color("onSurfaceVariant");
+    let surface_container = color("surfaceContainer");
+    let surface_container_low = color("surfaceContainerLow");
+    let surface_container_lowest = color("surfaceContainerLowest");
+    let surface_container_high = color("surfaceContainerHigh");
+    let surface_container_highest = color("surfaceContainerHighest");
+    let outline = color("outline");
+    let outline_variant = color("outlineVariant");
+    let shadow = color("shadow");
+
+    // === Selection colors ===
+    visuals.selection.bg_fill = primary_container;
+    visuals.selection.stroke.color = on_primary_container;
+
+    // === Hyperlink ===
+    visuals.hyperlink_color = primary;
 
     // === Widget colors (noninteractive, inactive, hovered, active, open) ===
 
-    // Noninteractive widgets (disabled state)
+    // Noninteractive widgets (labels, separators, disabled state)
     visuals.widgets.noninteractive.bg_fill = surface;
     visuals.widgets.noninteractive.weak_bg_fill = surface_variant;
     visuals.widgets.noninteractive.bg_stroke.color = outline_variant;
@@ -1643,40 +3060,40 @@ where
 
     // Inactive widgets (default state)
     visuals.widgets.inactive.weak_bg_fill = surface_container_highest;
-    visuals.widgets.inactive.bg_fill = egui::Color32::from_rgba_unmultiplied(
-        primary.r(),
-        primary.g(),
-        primary.b(),
-        20,
-    );
+    visuals.widgets.inactive.bg_fill = surface_container_highest;
     visuals.widgets.inactive.bg_stroke.color = outline;
     visuals.widgets.inactive.fg_stroke.color = on_surface;
 
-    // Hovered widgets
-    visuals.widgets.hovered.weak_bg_fill = surface_container_high;
-    visuals.widgets.hovered.bg_fill = egui::Color32::from_rgba_unmultiplied(
-        primary.r(),
-        primary.g(),
-        primary.b(),
-        40,
+    // Hovered widgets: onSurface hover state layer
+    visuals.widgets.hovered.weak_bg_fill = overlay_color(
+        surface_container_high,
+        on_surface,
+        HOVER_STATE_LAYER_OPACITY,
+    );
+    visuals.widgets.hovered.bg_fill = overlay_color(
+        surface_container_highest,
+        on_surface,
+        HOVER_STATE_LAYER_OPACITY,
     );
     visuals.widgets.hovered.bg_stroke.color = outline;
     visuals.widgets.hovered.fg_stroke.color = on_surface;
 
-    // Active widgets (pressed/selected state)
-    visuals.widgets.active.weak_bg_fill = primary_container;
+    // Active widgets (pressed/dragged): primary with a pressed state layer
+    visuals.widgets.active.weak_bg_fill =
+        overlay_color(primary, on_primary, PRESSED_STATE_LAYER_OPACITY);
     visuals.widgets.active.bg_fill = primary;
     visuals.widgets.active.bg_stroke.color = primary;
     visuals.widgets.active.fg_stroke.color = on_primary;
 
     // Open widgets (menus, dropdowns)
-    visuals.widgets.open.weak_bg_fill = surface_container_low;
-    visuals.widgets.open.bg_fill = primary_container;
+    visuals.widgets.open.weak_bg_fill = secondary_container;
+    visuals.widgets.open.bg_fill = secondary_container;
     visuals.widgets.open.bg_stroke.color = outline;
-    visuals.widgets.open.fg_stroke.color = on_primary_container;
+    visuals.widgets.open.fg_stroke.color = on_secondary_container;
 
     // === Background colors ===
-    visuals.window_fill = surface;
+    // Windows and popups sit at elevation level 3, like M3 dialogs and menus
+    visuals.window_fill = tinted_surface(surface, surface_tint, 3);
     visuals.panel_fill = surface_container;
     visuals.faint_bg_color = surface_container_low;
     visuals.extreme_bg_color = surface_container_lowest;
@@ -1698,7 +3115,487 @@ where
     visuals.window_shadow.color = shadow;
     visuals.popup_shadow.color = shadow;
 
-    ctx.set_visuals(visuals);
+    visuals
+}
+
+/// Apply the global Material Design 3 theme to egui's own widgets
+///
+/// Maps Material Design 3 color roles onto `egui::Visuals`, so plain egui
+/// widgets, windows and panels match the Material components. `ThemeMode::Auto`
+/// follows the system theme reported by the integration. Call once per frame,
+/// or whenever the theme changes.
+///
+/// # Material Design 3 Color Mappings
+///
+/// ## Widget States
+/// - **Noninteractive** (labels, disabled): surface, surfaceVariant, outlineVariant, onSurfaceVariant
+/// - **Inactive** (default): surfaceContainerHighest, outline, onSurface
+/// - **Hovered**: surfaceContainerHigh/Highest with an 8% onSurface state layer
+/// - **Active** (pressed): primary with a 12% onPrimary state layer, onPrimary
+/// - **Open** (menus/dropdowns): secondaryContainer, onSecondaryContainer, outline
+///
+/// ## Backgrounds
+/// - **window_fill**: surface tinted with surfaceTint at elevation level 3
+/// - **panel_fill**: surfaceContainer
+/// - **faint_bg_color**: surfaceContainerLow
+/// - **extreme_bg_color**: surfaceContainerLowest
+/// - **code_bg_color**: surfaceContainerHighest
+///
+/// ## Text & Interaction
+/// - **override_text_color**: onSurface
+/// - **text_cursor**: primary
+/// - **hyperlink_color**: primary
+/// - **selection**: primaryContainer, onPrimaryContainer
+///
+/// ## Feedback Colors
+/// - **error_fg_color**: error
+/// - **warn_fg_color**: tertiary
+///
+/// ## Strokes & Shadows
+/// - **window_stroke**: outlineVariant
+/// - **window_shadow**: shadow
+/// - **popup_shadow**: shadow
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::apply_material_visuals;
+///
+/// // In eframe::App::update
+/// apply_material_visuals(ctx);
+/// ```
+pub fn apply_material_visuals(ctx: &egui::Context) {
+    let dark_mode = context_prefers_dark(ctx);
+    let theme = {
+        let Ok(mut theme) = GLOBAL_THEME.lock() else {
+            return;
+        };
+        if theme.system_dark_mode != dark_mode {
+            theme.system_dark_mode = dark_mode;
+            invalidate_theme_cache();
+        }
+        theme.clone()
+    };
+
+    let mode = theme.resolved_mode(dark_mode);
+    ctx.set_visuals(material_visuals(&theme, mode == ThemeMode::Dark));
+    if get_material_scrollbars() {
+        apply_material_scrollbars(ctx);
+    }
+}
+
+/// Apply the Material Design 3 theme to the egui context, detecting the OS theme
+///
+/// Uses the same color mapping as [`apply_material_visuals`], but resolves
+/// `ThemeMode::Auto` by asking the OS instead of the egui integration.
+///
+/// # Parameters
+/// - `ctx`: The egui context to apply the theme to
+/// - `os_theme_detector`: Optional function to detect OS theme mode when `ThemeMode::Auto` is set.
+///   If not provided, uses the default `detect_os_theme()` function.
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::{apply_theme, ThemeMode};
+///
+/// // Use default OS theme detection
+/// apply_theme(&egui_ctx, None);
+///
+/// // Provide custom theme detection (e.g., for Android)
+/// apply_theme(&egui_ctx, Some(|| {
+///     // Custom Android theme detection logic
+///     ThemeMode::Dark
+/// }));
+/// ```
+pub fn apply_theme<C, F>(ctx: C, os_theme_detector: Option<F>)
+where
+    C: ContextRef,
+    F: FnOnce() -> ThemeMode,
+{
+    let ctx = ctx.context_ref();
+
+    let mut theme = if let Ok(theme) = GLOBAL_THEME.lock() {
+        theme.clone()
+    } else {
+        return;
+    };
+
+    if theme.theme_mode == ThemeMode::Auto {
+        // Detect OS theme preference
+        let detected_mode = if let Some(detector) = os_theme_detector {
+            detector()
+        } else {
+            detect_os_theme()
+        };
+        theme.theme_mode = detected_mode; // Resolve Auto to detected OS theme
+        if let Ok(mut global_theme) = GLOBAL_THEME.lock() {
+            global_theme.system_dark_mode = detected_mode == ThemeMode::Dark;
+        }
+        invalidate_theme_cache();
+    }
+
+    let dark_mode = theme.theme_mode == ThemeMode::Dark;
+    ctx.set_visuals(material_visuals(&theme, dark_mode));
+    if get_material_scrollbars() {
+        apply_material_scrollbars(ctx);
+    }
+}
+
+static MATERIAL_SCROLLBARS: AtomicBool = AtomicBool::new(true);
+
+/// Check whether scroll areas get Material scrollbars
+///
+/// # Returns
+/// `true` unless turned off with [`set_material_scrollbars`]
+pub fn get_material_scrollbars() -> bool {
+    MATERIAL_SCROLLBARS.load(Ordering::Relaxed)
+}
+
+/// Turn Material scrollbars on or off, for apps that style scrollbars themselves
+///
+/// When off, [`apply_material_visuals`] and [`apply_theme`] leave
+/// `Spacing::scroll` alone and the scroll areas inside components use the
+/// app's own scrollbar colors. Turn it off before applying the theme, since a
+/// scrollbar style that was already applied is kept.
+///
+/// # Parameters
+/// - `enabled`: `false` to keep the app's own scrollbar styling
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::set_material_scrollbars;
+///
+/// set_material_scrollbars(false);
+/// ```
+pub fn set_material_scrollbars(enabled: bool) {
+    MATERIAL_SCROLLBARS.store(enabled, Ordering::Relaxed);
+    invalidate_theme_cache();
+}
+
+/// Scrollbar sizes and opacities of Material scroll areas
+///
+/// A 4dp handle floats over the content and widens to 8dp, over a visible
+/// track, while the pointer is on the scrollbar.
+pub fn material_scroll_style() -> egui::style::ScrollStyle {
+    egui::style::ScrollStyle {
+        floating: true,
+        bar_width: 8.0,
+        floating_width: 4.0,
+        floating_allocated_width: 0.0,
+        handle_min_length: 24.0,
+        bar_inner_margin: 4.0,
+        bar_outer_margin: 2.0,
+        foreground_color: false,
+        dormant_background_opacity: 0.0,
+        active_background_opacity: 0.0,
+        interact_background_opacity: 1.0,
+        dormant_handle_opacity: 1.0,
+        active_handle_opacity: 1.0,
+        interact_handle_opacity: 1.0,
+        ..egui::style::ScrollStyle::floating()
+    }
+}
+
+/// Give every `ScrollArea` the thin, floating Material scrollbar
+///
+/// Sets `Spacing::scroll` to [`material_scroll_style`].
+/// [`apply_material_visuals`] calls this unless [`set_material_scrollbars`]
+/// turned it off. egui draws the scrollbar handle in the widget fill colors;
+/// scroll areas shown with [`MaterialScrollAreaExt::show_material`], as the
+/// ones inside components are, draw it in outlineVariant (outline when
+/// hovered or dragged) on a surfaceContainerHighest track instead.
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::apply_material_scrollbars;
+///
+/// // In eframe::App::update, after applying your own visuals
+/// apply_material_scrollbars(ctx);
+/// ```
+pub fn apply_material_scrollbars(ctx: &egui::Context) {
+    ctx.style_mut(|style| style.spacing.scroll = material_scroll_style());
+}
+
+/// Show scroll areas with Material scrollbar colors
+pub trait MaterialScrollAreaExt {
+    /// Like `ScrollArea::show`, with an outlineVariant handle that turns
+    /// outline when hovered or dragged, on a surfaceContainerHighest track.
+    /// The contents keep the style of `ui`.
+    ///
+    /// ```rust
+    /// use egui_material3::theme::MaterialScrollAreaExt;
+    ///
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::vertical().show_material(ui, |ui| {
+    ///     ui.label("Long content");
+    /// });
+    /// # });
+    /// ```
+    fn show_material<R>(
+        self,
+        ui: &mut egui::Ui,
+        add_contents: impl FnOnce(&mut egui::Ui) -> R,
+    ) -> egui::scroll_area::ScrollAreaOutput<R>;
+}
+
+impl MaterialScrollAreaExt for egui::ScrollArea {
+    fn show_material<R>(
+        self,
+        ui: &mut egui::Ui,
+        add_contents: impl FnOnce(&mut egui::Ui) -> R,
+    ) -> egui::scroll_area::ScrollAreaOutput<R> {
+        if !get_material_scrollbars() {
+            return self.show(ui, add_contents);
+        }
+
+        // egui draws the bars with the style of the ui the scroll area is
+        // shown in, so restyle that ui and give the contents the original back
+        let content_style = ui.style().clone();
+        ui.scope(|ui| {
+            ui.spacing_mut().scroll = material_scroll_style();
+            let visuals = ui.visuals_mut();
+            let handle = get_global_color("outlineVariant");
+            let hovered_handle = get_global_color("outline");
+            visuals.extreme_bg_color = get_global_color("surfaceContainerHighest");
+            for (widget, fill) in [
+                (&mut visuals.widgets.inactive, handle),
+                (&mut visuals.widgets.hovered, hovered_handle),
+                (&mut visuals.widgets.active, hovered_handle),
+            ] {
+                widget.bg_fill = fill;
+                widget.corner_radius = egui::CornerRadius::same(u8::MAX);
+            }
+            self.show(ui, |ui| {
+                ui.set_style(content_style);
+                add_contents(ui)
+            })
+        })
+        .inner
+    }
+}
+
+/// A keyboard shortcut: a key pressed together with modifiers
+///
+/// Use [`egui::Modifiers::COMMAND`] for shortcuts that should be ⌘ on macOS
+/// and Ctrl elsewhere.
+///
+/// ```rust
+/// use egui_material3::theme::Shortcut;
+///
+/// let save = Shortcut::command(egui::Key::S);
+/// # egui::__run_test_ui(|ui| {
+/// if ui.input(|i| save.matches(i)) {
+///     // save the document
+/// }
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    pub modifiers: egui::Modifiers,
+    pub key: egui::Key,
+}
+
+impl Shortcut {
+    pub const fn new(modifiers: egui::Modifiers, key: egui::Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// `key` with ⌘ on macOS and Ctrl elsewhere
+    pub const fn command(key: egui::Key) -> Self {
+        Self::new(egui::Modifiers::COMMAND, key)
+    }
+
+    /// Whether the shortcut was pressed this frame
+    ///
+    /// Extra Shift is ignored unless the shortcut asks for it, so `Ctrl+=`
+    /// still matches on layouts where `=` needs Shift. The input is left
+    /// untouched; see [`Shortcut::consume`] to keep others from seeing it.
+    pub fn matches(&self, input: &egui::InputState) -> bool {
+        input.key_pressed(self.key) && input.modifiers.matches_logically(self.modifiers)
+    }
+
+    /// Like [`Shortcut::matches`], and remove the key press from `input`
+    pub fn consume(&self, input: &mut egui::InputState) -> bool {
+        input.consume_shortcut(&egui::KeyboardShortcut::new(self.modifiers, self.key))
+    }
+
+    /// The shortcut as shown to users on the platform this was compiled for
+    pub fn format(&self) -> String {
+        self.format_for(cfg!(target_os = "macos"))
+    }
+
+    /// The shortcut as shown to users on macOS (`⇧⌘S`) or elsewhere (`Ctrl+Shift+S`)
+    ///
+    /// Prefer this with `ctx.os() == egui::os::OperatingSystem::Mac` on the
+    /// web, where the compile target doesn't say which platform runs the app.
+    pub fn format_for(&self, is_mac: bool) -> String {
+        let names = if is_mac {
+            egui::ModifierNames::SYMBOLS
+        } else {
+            egui::ModifierNames::NAMES
+        };
+        egui::KeyboardShortcut::new(self.modifiers, self.key).format(&names, is_mac)
+    }
+}
+
+impl From<egui::KeyboardShortcut> for Shortcut {
+    fn from(shortcut: egui::KeyboardShortcut) -> Self {
+        Self::new(shortcut.modifiers, shortcut.logical_key)
+    }
+}
+
+/// Keeps keyboard focus inside a region of a [`egui::Ui`].
+///
+/// Invisible focusable markers are placed before and after the region's
+/// widgets. Tabbing past the last widget lands on the trailing marker and is
+/// sent on to the first widget, and Shift+Tab past the first widget wraps to
+/// the last, a frame or two later. Focus moved outside the region in any other
+/// way, e.g. by a click, is returned to the widget inside that last had it.
+///
+/// [`trap_focus`] is the shorthand for an always-on trap.
+///
+/// ```rust
+/// use egui_material3::theme::FocusTrap;
+///
+/// # egui::__run_test_ui(|ui| {
+/// FocusTrap::new(egui::Id::new("sign_in")).show(ui, |ui| {
+///     ui.text_edit_singleline(&mut String::new());
+///     let _ = ui.button("Sign in");
+/// });
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use = "Call `.show(ui, add_contents)` to trap focus in the contents"]
+pub struct FocusTrap {
+    id: egui::Id,
+    enabled: bool,
+}
+
+/// What a [`FocusTrap`] remembers between passes
+#[derive(Clone, Copy, Debug, Default)]
+struct FocusTrapState {
+    /// The widget inside the region that last had focus
+    last_focus: Option<egui::Id>,
+    /// Pass in which focus was last sent on from one of the markers
+    wrapped_pass: Option<u64>,
+}
+
+impl FocusTrap {
+    pub fn new(id: impl Into<egui::Id>) -> Self {
+        Self {
+            id: id.into(),
+            enabled: true,
+        }
+    }
+
+    /// Turn the trap off, showing the contents as they are.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// The widget inside the trap `id` that last had keyboard focus
+    pub fn last_focus(ctx: &egui::Context, id: egui::Id) -> Option<egui::Id> {
+        ctx.data(|d| d.get_temp::<FocusTrapState>(id))
+            .and_then(|state| state.last_focus)
+    }
+
+    /// Forget what the trap `id` remembers, e.g. when its dialog closes.
+    pub fn remove(ctx: &egui::Context, id: egui::Id) {
+        ctx.data_mut(|d| d.remove::<FocusTrapState>(id));
+    }
+
+    /// Show `add_contents` with focus trapped inside it.
+    pub fn show<R>(self, ui: &mut egui::Ui, add_contents: impl FnOnce(&mut egui::Ui) -> R) -> R {
+        if !self.enabled {
+            return add_contents(ui);
+        }
+        let ctx = ui.ctx().clone();
+        let mut state: FocusTrapState = ctx.data(|d| d.get_temp(self.id)).unwrap_or_default();
+        let start = self.id.with("focus_trap_start");
+        let end = self.id.with("focus_trap_end");
+
+        // Landing on a marker means focus was tabbed past an end of the
+        // region: send it on to the nearest widget inside, wrapping around
+        // when it came from inside
+        let focused = ctx.memory(|m| m.focused());
+        if let Some(marker) = focused.filter(|id| *id == start || *id == end) {
+            let pass = ctx.cumulative_pass_nr();
+            let came_from_inside = state.last_focus.is_some();
+            let forward = (marker == end) == came_from_inside;
+            if state
+                .wrapped_pass
+                .is_some_and(|wrapped| wrapped + 1 >= pass)
+            {
+                // The markers sent focus to each other: nothing inside takes focus
+                ctx.memory_mut(|m| m.surrender_focus(marker));
+            } else {
+                let (from, direction) = if forward {
+                    (start, egui::FocusDirection::Next)
+                } else {
+                    (end, egui::FocusDirection::Previous)
+                };
+                ctx.memory_mut(|m| {
+                    m.request_focus(from);
+                    m.move_focus(direction);
+                });
+                state.wrapped_pass = Some(pass);
+                ctx.request_repaint();
+            }
+        }
+
+        let add_marker = |ui: &mut egui::Ui, id: egui::Id| {
+            let rect = egui::Rect::from_min_size(ui.cursor().min, egui::Vec2::ZERO);
+            ui.interact(rect, id, egui::Sense::focusable_noninteractive());
+        };
+        let scope = ui.scope(|ui| {
+            add_marker(ui, start);
+            let inner = add_contents(ui);
+            add_marker(ui, end);
+            inner
+        });
+
+        let region = scope.response.rect.expand(1.0);
+        let layer_id = ui.layer_id();
+        match ctx.memory(|m| m.focused()) {
+            // Mid-wrap, or nothing focused while egui hands focus on
+            None => {}
+            Some(id) if id == start || id == end => {}
+            Some(id) => {
+                let inside = ctx.read_response(id).is_some_and(|response| {
+                    response.layer_id == layer_id
+                        && region.contains(response.interact_rect.center())
+                });
+                if inside {
+                    state.last_focus = Some(id);
+                    state.wrapped_pass = None;
+                } else if let Some(last_focus) = state.last_focus {
+                    ctx.memory_mut(|m| m.request_focus(last_focus));
+                }
+            }
+        }
+        ctx.data_mut(|d| d.insert_temp(self.id, state));
+        scope.inner
+    }
+}
+
+/// Show `add_contents` with Tab and Shift+Tab cycling through its widgets.
+///
+/// Shorthand for [`FocusTrap::new(id).show(ui, add_contents)`](FocusTrap::show).
+pub fn trap_focus<R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> R {
+    FocusTrap::new(id).show(ui, add_contents)
+}
+
+/// Whether Escape was pressed, consuming the press so nothing else sees it.
+///
+/// Only the first caller in a pass gets `true`. When overlays are stacked,
+/// let the topmost one ask, e.g. by checking [`DialogStack::top`](crate::DialogStack::top)
+/// first.
+pub fn dismiss_on_escape(ctx: &egui::Context) -> bool {
+    ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Escape))
 }
 
 // ============================================================================
@@ -1740,6 +3637,7 @@ pub fn set_theme_mode(mode: ThemeMode) {
     if let Ok(mut theme) = get_global_theme().lock() {
         theme.theme_mode = mode;
     }
+    invalidate_theme_cache();
 }
 
 /// Get the current contrast level from the global theme
@@ -1777,4 +3675,1298 @@ pub fn set_contrast_level(level: ContrastLevel) {
     if let Ok(mut theme) = get_global_theme().lock() {
         theme.contrast_level = level;
     }
+    invalidate_theme_cache();
+}
+
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+/// Check whether components should skip decorative animations by default
+///
+/// # Returns
+/// `true` when reduced motion has been requested with [`set_reduced_motion`].
+/// Components read [`reduced_motion`] instead, which also honors a
+/// per-context override.
+pub fn get_reduced_motion() -> bool {
+    REDUCED_MOTION.load(Ordering::Relaxed)
+}
+
+fn reduced_motion_id() -> egui::Id {
+    egui::Id::new("material3_reduced_motion")
+}
+
+/// Check whether components drawn in `ctx` should skip decorative animations
+///
+/// # Returns
+/// The override set with [`set_context_reduced_motion`] for `ctx`, or the
+/// global [`get_reduced_motion`] setting when there is none
+pub fn reduced_motion(ctx: &egui::Context) -> bool {
+    ctx.data(|d| d.get_temp::<bool>(reduced_motion_id()))
+        .unwrap_or_else(get_reduced_motion)
+}
+
+/// Override reduced motion for a single context
+///
+/// Useful when an app runs several contexts, or in tests that must not change
+/// the global setting other tests rely on.
+///
+/// # Parameters
+/// - `reduced`: `Some` to override [`set_reduced_motion`] for `ctx`, `None` to
+///   follow the global setting again
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::set_context_reduced_motion;
+///
+/// let ctx = egui::Context::default();
+/// set_context_reduced_motion(&ctx, Some(true));
+/// ```
+pub fn set_context_reduced_motion(ctx: &egui::Context, reduced: Option<bool>) {
+    ctx.data_mut(|d| match reduced {
+        Some(reduced) => d.insert_temp(reduced_motion_id(), reduced),
+        None => d.remove::<bool>(reduced_motion_id()),
+    });
+}
+
+/// Ask components to skip decorative animations, such as the snackbar swipe slide
+///
+/// This is the single switch for every animated component: ripples, chips,
+/// icon button toggles, text field labels, list reordering and swiping, data
+/// table chevrons, image placeholders, snackbars, tab, navigation bar and
+/// navigation rail indicators, FAB labels and menus, carousel snapping,
+/// spinning icons and indeterminate progress indicators all jump straight to
+/// their end state and stop scheduling repaints for the animation. egui does
+/// not expose the platform's reduced motion preference, so it is off until
+/// the app turns it on. [`set_context_reduced_motion`] overrides it for one
+/// context.
+///
+/// # Parameters
+/// - `reduced`: `true` to jump straight to the end state of animations
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::set_reduced_motion;
+///
+/// set_reduced_motion(true);
+/// ```
+pub fn set_reduced_motion(reduced: bool) {
+    REDUCED_MOTION.store(reduced, Ordering::Relaxed);
+}
+
+/// Length of an animation in seconds, taking reduced motion into account
+///
+/// # Parameters
+/// - `ctx`: Context the animation runs in
+/// - `duration`: Length of the animation with full motion
+///
+/// # Returns
+/// `duration`, or zero while [`reduced_motion`] is set for `ctx` so animations
+/// such as `Context::animate_bool_with_time` jump straight to their target
+pub fn motion_duration(ctx: &egui::Context, duration: f32) -> f32 {
+    if reduced_motion(ctx) {
+        0.0
+    } else {
+        duration
+    }
+}
+
+static RTL: AtomicBool = AtomicBool::new(false);
+
+/// Check whether components are mirrored for right-to-left languages by default
+///
+/// # Returns
+/// `true` when right-to-left layout has been requested with [`set_rtl`].
+/// Components read [`rtl`] instead, which also honors a per-context override.
+pub fn get_rtl() -> bool {
+    RTL.load(Ordering::Relaxed)
+}
+
+fn rtl_id() -> egui::Id {
+    egui::Id::new("material3_rtl")
+}
+
+/// Check whether components drawn in `ctx` are mirrored for right-to-left languages
+///
+/// # Returns
+/// The override set with [`set_context_rtl`] for `ctx`, or the global
+/// [`get_rtl`] setting when there is none
+pub fn rtl(ctx: &egui::Context) -> bool {
+    ctx.data(|d| d.get_temp::<bool>(rtl_id()))
+        .unwrap_or_else(get_rtl)
+}
+
+/// Override right-to-left layout for a single context
+///
+/// # Parameters
+/// - `rtl`: `Some` to override [`set_rtl`] for `ctx`, `None` to follow the
+///   global setting again
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::set_context_rtl;
+///
+/// let ctx = egui::Context::default();
+/// set_context_rtl(&ctx, Some(true));
+/// ```
+pub fn set_context_rtl(ctx: &egui::Context, rtl: Option<bool>) {
+    ctx.data_mut(|d| match rtl {
+        Some(rtl) => d.insert_temp(rtl_id(), rtl),
+        None => d.remove::<bool>(rtl_id()),
+    });
+}
+
+/// Mirror component layouts for right-to-left languages such as Arabic and Hebrew
+///
+/// Drawers slide in from the right, leading and trailing elements of list
+/// items, chips and app bars swap sides, and text is aligned to the right.
+/// [`set_context_rtl`] overrides it for one context.
+///
+/// # Parameters
+/// - `rtl`: `true` to lay components out right-to-left
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::set_rtl;
+///
+/// set_rtl(true);
+/// ```
+pub fn set_rtl(rtl: bool) {
+    RTL.store(rtl, Ordering::Relaxed);
+}
+
+/// Check whether components in `ui` should be laid out right-to-left
+///
+/// # Returns
+/// `true` when [`rtl`] is on for the context or `ui` itself runs right-to-left,
+/// e.g. inside `ui.with_layout(Layout::right_to_left(..), ..)`
+pub fn is_rtl(ui: &egui::Ui) -> bool {
+    rtl(ui.ctx()) || ui.layout().main_dir() == egui::Direction::RightToLeft
+}
+
+/// Mirror `x` across the vertical center line of `within` when `rtl` is set
+pub(crate) fn mirror_x(rtl: bool, within: egui::Rect, x: f32) -> f32 {
+    if rtl {
+        within.min.x + within.max.x - x
+    } else {
+        x
+    }
+}
+
+/// Mirror `rect` horizontally inside `within` when `rtl` is set
+pub(crate) fn mirror_rect(rtl: bool, within: egui::Rect, rect: egui::Rect) -> egui::Rect {
+    if rtl {
+        egui::Rect::from_x_y_ranges(
+            mirror_x(rtl, within, rect.max.x)..=mirror_x(rtl, within, rect.min.x),
+            rect.y_range(),
+        )
+    } else {
+        rect
+    }
+}
+
+/// Swap left and right text anchoring when `rtl` is set
+pub(crate) fn mirror_align(rtl: bool, align: egui::Align2) -> egui::Align2 {
+    if rtl {
+        align.flip_x()
+    } else {
+        align
+    }
+}
+
+/// Color tokens of a Material scheme, as used with `get_global_color`
+const THEME_TOKENS: [&str; 49] = [
+    "primary",
+    "surfaceTint",
+    "onPrimary",
+    "primaryContainer",
+    "onPrimaryContainer",
+    "secondary",
+    "onSecondary",
+    "secondaryContainer",
+    "onSecondaryContainer",
+    "tertiary",
+    "onTertiary",
+    "tertiaryContainer",
+    "onTertiaryContainer",
+    "error",
+    "onError",
+    "errorContainer",
+    "onErrorContainer",
+    "background",
+    "onBackground",
+    "surface",
+    "onSurface",
+    "surfaceVariant",
+    "onSurfaceVariant",
+    "outline",
+    "outlineVariant",
+    "shadow",
+    "scrim",
+    "inverseSurface",
+    "inverseOnSurface",
+    "inversePrimary",
+    "primaryFixed",
+    "onPrimaryFixed",
+    "primaryFixedDim",
+    "onPrimaryFixedVariant",
+    "secondaryFixed",
+    "onSecondaryFixed",
+    "secondaryFixedDim",
+    "onSecondaryFixedVariant",
+    "tertiaryFixed",
+    "onTertiaryFixed",
+    "tertiaryFixedDim",
+    "onTertiaryFixedVariant",
+    "surfaceDim",
+    "surfaceBright",
+    "surfaceContainerLowest",
+    "surfaceContainerLow",
+    "surfaceContainer",
+    "surfaceContainerHigh",
+    "surfaceContainerHighest",
+];
+
+/// Position of a color token in `THEME_TOKENS`
+fn token_index(name: &str) -> Option<usize> {
+    Some(match name {
+        "primary" => 0,
+        "surfaceTint" => 1,
+        "onPrimary" => 2,
+        "primaryContainer" => 3,
+        "onPrimaryContainer" => 4,
+        "secondary" => 5,
+        "onSecondary" => 6,
+        "secondaryContainer" => 7,
+        "onSecondaryContainer" => 8,
+        "tertiary" => 9,
+        "onTertiary" => 10,
+        "tertiaryContainer" => 11,
+        "onTertiaryContainer" => 12,
+        "error" => 13,
+        "onError" => 14,
+        "errorContainer" => 15,
+        "onErrorContainer" => 16,
+        "background" => 17,
+        "onBackground" => 18,
+        "surface" => 19,
+        "onSurface" => 20,
+        "surfaceVariant" => 21,
+        "onSurfaceVariant" => 22,
+        "outline" => 23,
+        "outlineVariant" => 24,
+        "shadow" => 25,
+        "scrim" => 26,
+        "inverseSurface" => 27,
+        "inverseOnSurface" => 28,
+        "inversePrimary" => 29,
+        "primaryFixed" => 30,
+        "onPrimaryFixed" => 31,
+        "primaryFixedDim" => 32,
+        "onPrimaryFixedVariant" => 33,
+        "secondaryFixed" => 34,
+        "onSecondaryFixed" => 35,
+        "secondaryFixedDim" => 36,
+        "onSecondaryFixedVariant" => 37,
+        "tertiaryFixed" => 38,
+        "onTertiaryFixed" => 39,
+        "tertiaryFixedDim" => 40,
+        "onTertiaryFixedVariant" => 41,
+        "surfaceDim" => 42,
+        "surfaceBright" => 43,
+        "surfaceContainerLowest" => 44,
+        "surfaceContainerLow" => 45,
+        "surfaceContainer" => 46,
+        "surfaceContainerHigh" => 47,
+        "surfaceContainerHighest" => 48,
+        _ => return None,
+    })
+}
+
+/// Duration of the crossfade between two themes
+const THEME_ANIMATION_S: f64 = 0.2;
+
+/// Crossfades every color token when the resolved theme changes
+///
+/// The animator keeps the colors it is fading from and to. Each call to
+/// [`ThemeAnimator::update`] compares the new target with the current one;
+/// on a change it starts a new fade from the colors currently on screen, so
+/// switching again mid-fade continues smoothly instead of jumping.
+///
+/// The global instance is driven by [`animate_theme`]; `get_global_color`
+/// returns its interpolated colors while a fade is running.
+#[derive(Clone, Debug, Default)]
+pub struct ThemeAnimator {
+    from: HashMap<&'static str, Color32>,
+    to: HashMap<&'static str, Color32>,
+    start_time: f64,
+    progress: f32,
+}
+
+impl ThemeAnimator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advance the fade to `time` (seconds), retargeting it if `target` changed
+    ///
+    /// Returns whether a fade is still running.
+    pub fn update(&mut self, target: HashMap<&'static str, Color32>, time: f64) -> bool {
+        if self.to.is_empty() {
+            // Nothing was shown before, so there is nothing to fade from
+            self.jump_to(target);
+            return false;
+        }
+
+        if target != self.to {
+            self.from = self.current_colors();
+            self.to = target;
+            self.start_time = time;
+        }
+
+        self.progress = ((time - self.start_time) / THEME_ANIMATION_S).clamp(0.0, 1.0) as f32;
+        self.is_animating()
+    }
+
+    /// Show `target` right away, ending any running fade
+    pub fn jump_to(&mut self, target: HashMap<&'static str, Color32>) {
+        self.to = target;
+        self.progress = 1.0;
+    }
+
+    /// Whether a fade is running
+    pub fn is_animating(&self) -> bool {
+        self.progress < 1.0
+    }
+
+    /// Interpolated color of a token, or `None` when no fade is running
+    pub fn color(&self, name: &str) -> Option<Color32> {
+        if !self.is_animating() {
+            return None;
+        }
+        let from = self.from.get(name)?;
+        let to = self.to.get(name)?;
+        Some(from.lerp_to_gamma(*to, egui::emath::easing::cubic_in_out(self.progress)))
+    }
+
+    fn current_colors(&self) -> HashMap<&'static str, Color32> {
+        self.to
+            .iter()
+            .map(|(name, to)| (*name, self.color(name).unwrap_or(*to)))
+            .collect()
+    }
+}
+
+static THEME_ANIMATOR: std::sync::LazyLock<Mutex<ThemeAnimator>> =
+    std::sync::LazyLock::new(|| Mutex::new(ThemeAnimator::new()));
+static THEME_ANIMATION_ENABLED: AtomicBool = AtomicBool::new(false);
+/// Set while the global animator is fading, so idle color lookups skip its lock
+static THEME_ANIMATING: AtomicBool = AtomicBool::new(false);
+
+/// Turn theme crossfades on or off (off by default)
+///
+/// When enabled, changing the theme mode, contrast level, theme file or
+/// `selected_colors` fades every color token over ~200ms instead of
+/// snapping. Fades are also skipped while [`reduced_motion`] is set.
+/// Requires [`animate_theme`] to be called every frame.
+pub fn set_theme_animation_enabled(enabled: bool) {
+    THEME_ANIMATION_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether theme crossfades are enabled
+pub fn theme_animation_enabled() -> bool {
+    THEME_ANIMATION_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Advance the theme crossfade; call once per frame
+///
+/// Also called by [`update_window_background`]. Refreshes the color cache
+/// like [`begin_frame`] and requests a repaint while a fade is running.
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::{animate_theme, set_theme_animation_enabled};
+///
+/// set_theme_animation_enabled(true);
+/// // In eframe::App::update
+/// animate_theme(ctx);
+/// ```
+pub fn animate_theme<C: ContextRef>(ctx: C) {
+    let ctx = ctx.context_ref();
+    let Some(colors) = refresh_color_cache() else {
+        return;
+    };
+    let target: HashMap<&'static str, Color32> = THEME_TOKENS.into_iter().zip(colors).collect();
+
+    let Ok(mut animator) = THEME_ANIMATOR.lock() else {
+        return;
+    };
+    let animating = if theme_animation_enabled() && !reduced_motion(ctx) {
+        animator.update(target, ctx.input(|i| i.time))
+    } else {
+        animator.jump_to(target);
+        false
+    };
+    THEME_ANIMATING.store(animating, Ordering::Relaxed);
+
+    if animating {
+        ctx.request_repaint();
+    }
+}
+
+/// Resolved colors of the active scheme, indexed like `THEME_TOKENS`
+struct ColorCache {
+    version: u64,
+    colors: [Color32; 49],
+}
+
+impl ColorCache {
+    /// Resolve every token of `theme`, tagged with the theme `version` it was read at
+    fn resolve(theme: &MaterialThemeContext, version: u64) -> Self {
+        Self {
+            version,
+            colors: THEME_TOKENS.map(|name| theme.get_color_by_name(name)),
+        }
+    }
+
+    /// Color of a token, if the cache was resolved at the current `version`
+    fn color(&self, name: &str, version: u64) -> Option<Color32> {
+        let index = token_index(name)?;
+        (self.version == version).then_some(self.colors[index])
+    }
+}
+
+/// Bumped whenever the global theme changes, marking the color cache stale
+static THEME_VERSION: AtomicU64 = AtomicU64::new(0);
+static COLOR_CACHE: RwLock<Option<ColorCache>> = RwLock::new(None);
+
+/// Mark the resolved colors stale after changing the global theme directly
+///
+/// The setters in this module (`set_theme_mode`, `update_global_theme`, ...)
+/// call this themselves. Changes made through `get_global_theme().lock()`
+/// are picked up by the next [`begin_frame`]; call this to see them earlier.
+pub fn invalidate_theme_cache() {
+    THEME_VERSION.fetch_add(1, Ordering::AcqRel);
+}
+
+/// Counter bumped on every change to the global theme
+///
+/// Compare it with the value seen last frame to react to theme changes, as
+/// [`apply_if_changed`] does.
+pub fn theme_version() -> u64 {
+    THEME_VERSION.load(Ordering::Acquire)
+}
+
+/// Resolve the active scheme's colors once for the frame
+///
+/// Call at the start of each frame, before drawing components. Afterwards
+/// `get_global_color` reads the resolved colors instead of locking the
+/// global theme and parsing hex strings for every lookup, until the theme
+/// changes. [`update_window_background`] and [`animate_theme`] call this
+/// for you. Without it, every lookup takes the slower path.
+pub fn begin_frame() {
+    refresh_color_cache();
+}
+
+fn refresh_color_cache() -> Option<[Color32; 49]> {
+    // Read the version before the theme, so a change made meanwhile leaves the cache stale
+    let version = THEME_VERSION.load(Ordering::Acquire);
+    let resolved = ColorCache::resolve(&*GLOBAL_THEME.lock().ok()?, version);
+    let colors = resolved.colors;
+    if let Ok(mut cache) = COLOR_CACHE.write() {
+        *cache = Some(resolved);
+    }
+    Some(colors)
+}
+
+/// Color of a token from the frame's color cache, if it is up to date
+fn cached_color(name: &str) -> Option<Color32> {
+    let cache = COLOR_CACHE.read().ok()?;
+    cache
+        .as_ref()?
+        .color(name, THEME_VERSION.load(Ordering::Acquire))
+}
+
+/// Color of a token mid-crossfade, if a fade is running
+fn animated_color(name: &str) -> Option<Color32> {
+    if !THEME_ANIMATING.load(Ordering::Relaxed) {
+        return None;
+    }
+    THEME_ANIMATOR.lock().ok()?.color(name)
+}
+
+thread_local! {
+    /// Color overrides of the active theme scopes, innermost last
+    static THEME_SCOPES: std::cell::RefCell<Vec<HashMap<String, Color32>>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Guard that overrides theme colors for components drawn while it is alive
+///
+/// Scopes nest: the innermost scope that defines a token wins, and tokens it
+/// doesn't define fall through to outer scopes and then the global theme.
+/// Dropping the guard (also during a panic) removes its overrides and any
+/// scopes opened inside it. Scopes are per thread, so the guard can't be
+/// sent to another thread.
+///
+/// Only components drawn before the guard is dropped are affected; egui
+/// popups and tooltips that are shown in a later pass use the global theme.
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::ThemeScope;
+/// use egui_material3::{get_global_color, MaterialButton};
+/// use std::collections::HashMap;
+///
+/// # fn ui(ui: &mut egui::Ui) {
+/// // A "danger zone" whose primary buttons use the error colors
+/// let _scope = ThemeScope::with_colors(HashMap::from([
+///     ("primary".to_string(), get_global_color("error")),
+///     ("onPrimary".to_string(), get_global_color("onError")),
+/// ]));
+/// ui.add(MaterialButton::filled("Delete account"));
+/// # }
+/// ```
+pub struct ThemeScope {
+    depth: usize,
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+impl ThemeScope {
+    /// Use every color of `scheme`, e.g. `theme_file.schemes["dark"]` for a dark preview pane
+    pub fn new(scheme: &MaterialScheme) -> Self {
+        let colors = match serde_json::to_value(scheme) {
+            Ok(serde_json::Value::Object(tokens)) => tokens
+                .into_iter()
+                .filter_map(|(name, hex)| {
+                    let color = MaterialThemeContext::hex_to_color32(hex.as_str()?)?;
+                    Some((name, color))
+                })
+                .collect(),
+            _ => HashMap::new(),
+        };
+        Self::with_colors(colors)
+    }
+
+    /// Use the colors `provider` gives for the M3 color tokens
+    ///
+    /// The colors are read once, when the scope is created. Tokens the
+    /// provider returns `None` for fall through to outer scopes.
+    pub fn with_provider(provider: &dyn ColorProvider) -> Self {
+        let colors = THEME_TOKENS
+            .iter()
+            .filter_map(|name| Some((name.to_string(), provider.color(name)?)))
+            .collect();
+        Self::with_colors(colors)
+    }
+
+    /// Override only the given tokens
+    pub fn with_colors(colors: HashMap<String, Color32>) -> Self {
+        let depth = THEME_SCOPES.with(|scopes| {
+            let mut scopes = scopes.borrow_mut();
+            scopes.push(colors);
+            scopes.len() - 1
+        });
+        Self {
+            depth,
+            _not_send: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Drop for ThemeScope {
+    fn drop(&mut self) {
+        // Truncating rather than popping also cleans up inner scopes that were leaked
+        THEME_SCOPES.with(|scopes| scopes.borrow_mut().truncate(self.depth));
+    }
+}
+
+/// Draw `add_contents` with the colors of `scheme`
+///
+/// Shorthand for keeping a [`ThemeScope`] alive around the closure.
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_material3::theme::{with_scope, MaterialThemeContext};
+/// use egui_material3::MaterialButton;
+///
+/// # fn ui(ui: &mut egui::Ui, theme: &MaterialThemeContext) {
+/// if let Some(dark) = theme.material_theme.as_ref().and_then(|t| t.schemes.get("dark")) {
+///     with_scope(ui, dark, |ui| {
+///         ui.add(MaterialButton::filled("Dark preview"));
+///     });
+/// }
+/// # }
+/// ```
+pub fn with_scope<R>(
+    ui: &mut egui::Ui,
+    scheme: &MaterialScheme,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> R {
+    let _scope = ThemeScope::new(scheme);
+    add_contents(ui)
+}
+
+/// Draw `add_contents` with the colors of `provider`
+///
+/// Shorthand for keeping a [`ThemeScope::with_provider`] alive around the
+/// closure. Nothing global changes, so tests can render components with a
+/// fixed scheme while other tests run.
+///
+/// # Example
+/// ```rust
+/// use egui_material3::theme::{with_color_provider, MaterialThemeContext, ThemeMode};
+/// use egui_material3::MaterialButton;
+///
+/// let mut dark = MaterialThemeContext::default();
+/// dark.theme_mode = ThemeMode::Dark;
+/// # egui::__run_test_ui(|ui| {
+/// with_color_provider(ui, &dark, |ui| {
+///     ui.add(MaterialButton::filled("Dark preview"));
+/// });
+/// # });
+/// ```
+pub fn with_color_provider<R>(
+    ui: &mut egui::Ui,
+    provider: &dyn ColorProvider,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> R {
+    let _scope = ThemeScope::with_provider(provider);
+    add_contents(ui)
+}
+
+/// Color of a token from the innermost theme scope that defines it
+fn scoped_color(name: &str) -> Option<Color32> {
+    THEME_SCOPES.with(|scopes| {
+        scopes
+            .borrow()
+            .iter()
+            .rev()
+            .find_map(|colors| colors.get(name).copied())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_material_visuals_use_theme_tokens() {
+        let theme = MaterialThemeContext {
+            theme_mode: ThemeMode::Dark,
+            ..Default::default()
+        };
+        let visuals = material_visuals(&theme, true);
+        assert!(visuals.dark_mode);
+        let color = |name| theme.get_color_by_name(name);
+        assert_eq!(visuals.panel_fill, color("surfaceContainer"));
+        assert_eq!(visuals.widgets.active.bg_fill, color("primary"));
+        assert_eq!(
+            visuals.widgets.open.fg_stroke.color,
+            color("onSecondaryContainer")
+        );
+        // Windows are tinted toward surfaceTint
+        let surface = color("surface");
+        assert_ne!(visuals.window_fill, surface);
+        assert_eq!(tinted_surface(surface, Color32::RED, 0), surface);
+    }
+
+    #[test]
+    fn test_color_cache_follows_theme_changes() {
+        let mut theme = MaterialThemeContext {
+            theme_mode: ThemeMode::Light,
+            ..Default::default()
+        };
+        let cache = ColorCache::resolve(&theme, 1);
+        let light_surface = theme.get_color_by_name("surface");
+        assert_eq!(cache.color("surface", 1), Some(light_surface));
+        assert_eq!(cache.color("notAToken", 1), None);
+
+        // A newer theme version leaves the cache stale until it is resolved again
+        theme.theme_mode = ThemeMode::Dark;
+        assert_eq!(cache.color("surface", 2), None);
+        let dark_surface = theme.get_color_by_name("surface");
+        assert_ne!(dark_surface, light_surface);
+
+        let cache = ColorCache::resolve(&theme, 2);
+        assert_eq!(cache.color("surface", 2), Some(dark_surface));
+    }
+
+    #[test]
+    fn test_token_index_matches_theme_tokens() {
+        for (index, token) in THEME_TOKENS.iter().enumerate() {
+            assert_eq!(token_index(token), Some(index));
+        }
+        assert_eq!(token_index("notAToken"), None);
+    }
+
+    #[test]
+    fn test_theme_scope_overrides_buttons_inside_only() {
+        let scoped_primary = Color32::from_rgb(0xFF, 0x00, 0xFF);
+        let mut scheme = get_default_material_theme().schemes["light"].clone();
+        scheme.primary = MaterialThemeContext::color32_to_hex(scoped_primary);
+
+        let ctx = egui::Context::default();
+        let output = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                with_scope(ui, &scheme, |ui| {
+                    ui.add(crate::MaterialButton::filled("Inside"));
+                    assert_eq!(get_global_color("primary"), scoped_primary);
+                });
+                ui.add(crate::MaterialButton::filled("Outside"));
+            });
+        });
+
+        assert_ne!(get_global_color("primary"), scoped_primary);
+        let scoped_fills = output
+            .shapes
+            .iter()
+            .filter(|clipped| {
+                matches!(&clipped.shape, egui::Shape::Rect(rect) if rect.fill == scoped_primary)
+            })
+            .count();
+        assert_eq!(scoped_fills, 1);
+    }
+
+    #[test]
+    fn test_theme_scopes_nest_and_unwind_on_panic() {
+        let outer = ThemeScope::with_colors(HashMap::from([
+            ("primary".to_string(), Color32::RED),
+            ("secondary".to_string(), Color32::GREEN),
+        ]));
+        {
+            let _inner =
+                ThemeScope::with_colors(HashMap::from([("primary".to_string(), Color32::BLUE)]));
+            assert_eq!(get_global_color("primary"), Color32::BLUE);
+            assert_eq!(get_global_color("secondary"), Color32::GREEN);
+        }
+        assert_eq!(get_global_color("primary"), Color32::RED);
+
+        let result = std::panic::catch_unwind(|| {
+            let _scope =
+                ThemeScope::with_colors(HashMap::from([("primary".to_string(), Color32::BLUE)]));
+            panic!("drawing failed");
+        });
+        assert!(result.is_err());
+        assert_eq!(get_global_color("primary"), Color32::RED);
+
+        drop(outer);
+        assert_eq!(scoped_color("primary"), None);
+    }
+
+    #[test]
+    fn test_color_provider_scope_ignores_global_theme() {
+        let mut fixed = MaterialThemeContext {
+            theme_mode: ThemeMode::Dark,
+            ..Default::default()
+        };
+        let fixed_primary = Color32::from_rgb(0x12, 0x34, 0x56);
+        fixed.selected_colors.insert("primary".to_string(), fixed_primary);
+        let overrides = HashMap::from([("primary".to_string(), Color32::RED)]);
+
+        let fixed_color = |name| fixed.get_color_by_name(name);
+
+        let _fixed = ThemeScope::with_provider(&fixed);
+        assert_eq!(get_global_color("primary"), fixed_primary);
+        for name in ["surface", "onSurface"] {
+            assert_eq!(get_global_color(name), fixed_color(name));
+        }
+        {
+            // Providers nest like any other scope
+            let _inner = ThemeScope::with_provider(&overrides);
+            assert_eq!(get_global_color("primary"), Color32::RED);
+            assert_eq!(get_global_color("surface"), fixed_color("surface"));
+        }
+        assert_eq!(get_global_color("primary"), fixed_primary);
+    }
+
+    #[test]
+    fn test_contrast_ratio_known_pairs() {
+        assert!((contrast_ratio(Color32::BLACK, Color32::WHITE) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(Color32::WHITE, Color32::BLACK) - 21.0).abs() < 0.01);
+        assert_eq!(contrast_ratio(Color32::RED, Color32::RED), 1.0);
+        // #767676 is the lightest gray that passes AA on white
+        let gray = Color32::from_gray(0x76);
+        assert!((contrast_ratio(gray, Color32::WHITE) - 4.54).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_auto_fix_repairs_unreadable_overrides() {
+        let mut theme = MaterialThemeContext {
+            theme_mode: ThemeMode::Light,
+            ..Default::default()
+        };
+        assert!(theme.validate_contrast().is_empty());
+
+        // Light text on a light surface
+        theme
+            .selected_colors
+            .insert("onSurface".to_string(), Color32::from_rgb(0xE0, 0xE8, 0xD8));
+        let warnings = theme.validate_contrast();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].foreground, "onSurface");
+        assert!(warnings[0].ratio < CONTRAST_AA);
+
+        assert_eq!(theme.auto_fix_on_colors(), vec!["onSurface"]);
+        assert!(theme.validate_contrast().is_empty());
+    }
+
+    #[test]
+    fn test_seed_scheme_uses_material_tones() {
+        let seed = Color32::from_rgb(0x67, 0x50, 0xA4);
+        let theme = MaterialThemeContext::from_seed_color(seed);
+        let lab = |scheme: &str, hex: fn(&MaterialScheme) -> &String| {
+            color_to_lab(MaterialThemeContext::hex_to_color32(hex(&theme.schemes[scheme])).unwrap())
+        };
+
+        assert!((lab("light", |s| &s.primary)[0] - 40.0).abs() < 1.0);
+        assert!((lab("dark", |s| &s.primary)[0] - 80.0).abs() < 1.0);
+        assert!((lab("light", |s| &s.surface)[0] - 98.0).abs() < 1.0);
+        assert_eq!(theme.schemes["light"].on_primary, "#FFFFFF");
+
+        let primary =
+            MaterialThemeContext::hex_to_color32(&theme.schemes["light"].primary).unwrap();
+        let hue_difference = color_to_hue_chroma(primary).0 - color_to_hue_chroma(seed).0;
+        assert!(hue_difference.abs() < 5.0);
+    }
+
+    #[test]
+    fn test_image_theme_seeds_from_dominant_color() {
+        // Mostly red with a gray stripe and a few blue pixels
+        let image = image::RgbaImage::from_fn(16, 16, |x, y| match (x, y) {
+            (0..=3, _) => image::Rgba([128, 128, 128, 255]),
+            (4, 0..=3) => image::Rgba([30, 60, 220, 255]),
+            _ => image::Rgba([200, 40, 40, 255]),
+        });
+        let mut png = std::io::Cursor::new(Vec::new());
+        image::DynamicImage::ImageRgba8(image)
+            .write_to(&mut png, image::ImageFormat::Png)
+            .unwrap();
+
+        let theme = MaterialThemeContext::from_image_bytes(png.get_ref()).unwrap();
+        assert_eq!(theme.seed, "#C82828");
+        assert!(matches!(
+            MaterialThemeContext::from_image_bytes(b"not an image"),
+            Err(ThemeError::Image(_))
+        ));
+    }
+
+    fn single_token(color: Color32) -> HashMap<&'static str, Color32> {
+        HashMap::from([("primary", color)])
+    }
+
+    #[test]
+    fn test_theme_animator_reanchors_on_rapid_switch() {
+        let light = Color32::from_gray(240);
+        let dark = Color32::from_gray(20);
+        let mut animator = ThemeAnimator::new();
+
+        // The first target is shown without a fade
+        assert!(!animator.update(single_token(light), 0.0));
+        assert_eq!(animator.color("primary"), None);
+
+        assert!(animator.update(single_token(dark), 1.0));
+        assert_eq!(animator.color("primary"), Some(light));
+
+        assert!(animator.update(single_token(dark), 1.1));
+        let midway = animator.color("primary").unwrap();
+        assert_ne!(midway, light);
+        assert_ne!(midway, dark);
+
+        // Switching back mid-fade starts from the color on screen
+        assert!(animator.update(single_token(light), 1.1));
+        assert_eq!(animator.color("primary"), Some(midway));
+
+        assert!(!animator.update(single_token(light), 1.1 + THEME_ANIMATION_S));
+        assert_eq!(animator.color("primary"), None);
+    }
+
+    #[test]
+    fn test_theme_context_serde_round_trip() {
+        let mut theme = context_from_export(THEME_BUILDER_EXPORT);
+        theme.theme_mode = ThemeMode::Dark;
+        theme.contrast_level = ContrastLevel::Medium;
+        let primary = Color32::from_rgb(0x12, 0x34, 0x56);
+        theme.selected_colors.insert("primary".to_string(), primary);
+
+        let json = serde_json::to_string(&theme).unwrap();
+        assert!(json.contains("\"#123456\""));
+
+        let restored: MaterialThemeContext = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.theme_mode, ThemeMode::Dark);
+        assert_eq!(restored.contrast_level, ContrastLevel::Medium);
+        assert_eq!(restored.get_color_by_name("primary"), primary);
+        assert_eq!(
+            restored.get_color_by_name("surface"),
+            theme.get_color_by_name("surface")
+        );
+    }
+
+    #[test]
+    fn test_export_applies_overrides_to_active_scheme() {
+        let mut theme = context_from_export(THEME_BUILDER_EXPORT);
+        theme.theme_mode = ThemeMode::Light;
+        theme
+            .selected_colors
+            .insert("primary".to_string(), Color32::from_rgb(0xAB, 0xCD, 0xEF));
+        theme
+            .selected_colors
+            .insert("notAToken".to_string(), Color32::RED);
+
+        let json = theme.export_material_theme_json();
+        let exported: MaterialThemeFile = serde_json::from_str(&json).unwrap();
+        let original = theme.material_theme.as_ref().unwrap();
+
+        assert_eq!(exported.schemes.len(), original.schemes.len());
+        assert_eq!(exported.schemes["light"].primary, "#ABCDEF");
+        assert_eq!(
+            exported.schemes["dark"].primary,
+            original.schemes["dark"].primary
+        );
+        assert!(!json.contains("notAToken"));
+    }
+
+    #[test]
+    fn test_invalid_theme_json_is_parse_error() {
+        let path = std::env::temp_dir().join("egui_material3_invalid_theme.json");
+        std::fs::write(&path, "{ \"schemes\": ").unwrap();
+        let result = try_setup_local_theme(path.to_str());
+        let _ = std::fs::remove_file(&path);
+        assert!(matches!(result, Err(ThemeError::Parse(_))));
+
+        assert!(matches!(
+            try_setup_local_theme(Some("no/such/theme.json")),
+            Err(ThemeError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_missing_font_reports_failed_status() {
+        assert_eq!(font_load_status("No Such Font Family"), None);
+        let missing = Err(ThemeError::NotFound("No Such Font Family".to_string()));
+        assert!(MaterialThemeContext::prepare_google_font(
+            "No Such Font Family",
+            missing,
+            FontPriority::Primary
+        )
+        .is_err());
+        assert_eq!(
+            font_load_status("No Such Font Family"),
+            Some(FontLoadStatus::Failed)
+        );
+    }
+
+    #[test]
+    fn test_truncated_font_is_rejected() {
+        let font = include_bytes!("../resources/MaterialSymbolsOutlined[FILL,GRAD,opsz,wght].ttf");
+        assert!(is_valid_font(font));
+
+        let truncated = Ok(font[..1024].to_vec());
+        assert!(matches!(
+            MaterialThemeContext::prepare_google_font(
+                "Truncated Font Family",
+                truncated,
+                FontPriority::Primary
+            ),
+            Err(ThemeError::InvalidFont(_))
+        ));
+        assert_eq!(
+            font_load_status("Truncated Font Family"),
+            Some(FontLoadStatus::Failed)
+        );
+    }
+
+    #[test]
+    fn test_apply_if_changed_reapplies_after_theme_change() {
+        let ctx = egui::Context::default();
+        assert!(apply_if_changed(&ctx));
+
+        let before = theme_version();
+        invalidate_theme_cache();
+        assert!(theme_version() > before);
+        assert!(apply_if_changed(&ctx));
+    }
+
+    #[test]
+    fn test_shape_scale_maps_tokens_to_radii() {
+        let scale = ShapeScale::default();
+        assert_eq!(scale.radius(Shape::None), egui::CornerRadius::ZERO);
+        assert_eq!(scale.radius(Shape::ExtraSmall), egui::CornerRadius::same(4));
+        assert_eq!(
+            scale.radius(Shape::ExtraLarge),
+            egui::CornerRadius::same(28)
+        );
+        assert_eq!(scale.radius(Shape::Full), egui::CornerRadius::same(u8::MAX));
+
+        let square = ShapeScale {
+            medium: 2,
+            ..ShapeScale::MATERIAL
+        };
+        assert_eq!(square.radius(Shape::Medium), egui::CornerRadius::same(2));
+    }
+
+    #[test]
+    fn test_type_scale_follows_m3_sizes() {
+        let scale = TypeScale::default();
+        assert_eq!(scale.size(TypeRole::DisplayLarge), 57.0);
+        assert_eq!(scale.size(TypeRole::HeadlineSmall), 24.0);
+        assert_eq!(scale.size(TypeRole::TitleMedium), 16.0);
+        assert_eq!(scale.size(TypeRole::BodySmall), 12.0);
+        assert_eq!(scale.size(TypeRole::LabelSmall), 11.0);
+        assert_eq!(
+            scale.font_id(TypeRole::LabelLarge),
+            egui::FontId::proportional(14.0)
+        );
+
+        let custom = scale.with_size(TypeRole::BodyLarge, 18.0).scaled(2.0);
+        assert_eq!(custom.size(TypeRole::BodyLarge), 36.0);
+        assert_eq!(custom.size(TypeRole::BodyMedium), 28.0);
+    }
+
+    #[test]
+    fn test_font_fallback_chain_follows_priority() {
+        let font = |name: &str, priority| PreparedFont {
+            name: name.to_owned(),
+            data: Arc::new(FontData::from_static(&[])),
+            families: vec![FontFamily::Proportional, FontFamily::Monospace],
+            priority,
+        };
+        // Prepared in an order that used to let the CJK font shadow the Latin one
+        let prepared = [
+            font("MaterialSymbolsOutlined", FontPriority::Icons),
+            font("GoogleSansCode", FontPriority::Primary),
+            font("NanumGothic", FontPriority::Fallback),
+        ];
+        let defaults = FontDefinitions::default();
+        let fonts = font_definitions(&prepared);
+
+        let proportional = &fonts.families[&FontFamily::Proportional];
+        let default_count = defaults.families[&FontFamily::Proportional].len();
+        assert_eq!(proportional.len(), default_count + 3);
+        assert_eq!(proportional[0], "GoogleSansCode");
+        assert_eq!(proportional[1], "NanumGothic");
+        assert_eq!(
+            proportional[2..2 + default_count],
+            defaults.families[&FontFamily::Proportional][..]
+        );
+        assert_eq!(proportional.last().unwrap(), "MaterialSymbolsOutlined");
+
+        let monospace = &fonts.families[&FontFamily::Monospace];
+        let tail: Vec<&str> = monospace[monospace.len() - 3..]
+            .iter()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(
+            tail,
+            ["GoogleSansCode", "NanumGothic", "MaterialSymbolsOutlined"]
+        );
+    }
+
+    #[test]
+    fn test_downloaded_fonts_are_cached_outside_resources() {
+        let path = font_cache_path("Google Sans Code");
+        assert!(path.is_absolute());
+        assert!(!path.starts_with("resources"));
+        assert!(path.ends_with("egui_material3_fonts/google-sans-code.ttf"));
+    }
+
+    const THEME_BUILDER_EXPORT: &str = include_str!("../resources/material-theme1.json");
+
+    fn context_from_export(json: &str) -> MaterialThemeContext {
+        MaterialThemeContext {
+            material_theme: Some(serde_json::from_str(json).expect("Failed to parse theme")),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_contrast_schemes_loaded() {
+        let mut theme = context_from_export(THEME_BUILDER_EXPORT);
+        let schemes = &theme.material_theme.as_ref().unwrap().schemes;
+        assert_eq!(schemes.len(), 6);
+
+        theme.theme_mode = ThemeMode::Light;
+        theme.contrast_level = ContrastLevel::Normal;
+        let normal = theme.get_color_by_name("primary");
+        theme.contrast_level = ContrastLevel::High;
+        let high = theme.get_color_by_name("primary");
+        assert_ne!(normal, high);
+        assert_eq!(high, MaterialThemeContext::hex_to_color32("#032B5B").unwrap());
+    }
+
+    #[test]
+    fn test_missing_contrast_scheme_falls_back_to_base() {
+        let mut file: MaterialThemeFile = serde_json::from_str(THEME_BUILDER_EXPORT).unwrap();
+        file.schemes.retain(|key, _| key == "light" || key == "dark");
+
+        let theme = MaterialThemeContext {
+            theme_mode: ThemeMode::Dark,
+            contrast_level: ContrastLevel::High,
+            material_theme: Some(file),
+            ..Default::default()
+        };
+        let scheme = theme.get_current_scheme().expect("Expected dark fallback");
+        assert_eq!(scheme.primary, "#AAC7FF");
+    }
+
+    #[test]
+    fn test_auto_mode_resolves_to_dark() {
+        let mut theme = context_from_export(THEME_BUILDER_EXPORT);
+        theme.theme_mode = ThemeMode::Auto;
+        assert_eq!(theme.resolved_mode(true), ThemeMode::Dark);
+        assert_eq!(theme.resolved_mode(false), ThemeMode::Light);
+
+        theme.system_dark_mode = true;
+        let scheme = theme.get_current_scheme().expect("Expected dark scheme");
+        assert_eq!(scheme.primary, "#AAC7FF");
+
+        theme.theme_mode = ThemeMode::Light;
+        assert_eq!(theme.resolved_mode(true), ThemeMode::Light);
+    }
+
+    #[test]
+    fn test_elevation_shadows_grow_with_level() {
+        assert_eq!(elevation_shadow(0).color, Color32::TRANSPARENT);
+        for level in 1..=5 {
+            let lower = elevation_shadow(level - 1);
+            let higher = elevation_shadow(level);
+            assert!(higher.offset[1] > lower.offset[1]);
+            assert!(higher.blur > lower.blur);
+        }
+        assert_eq!(elevation_shadow(9), elevation_shadow(5));
+
+        assert_eq!(elevation_shadow_at(2.0), elevation_shadow(2));
+        assert_eq!(elevation_shadow_at(0.0).color.a(), 0);
+        let halfway = elevation_shadow_at(3.5);
+        assert!(halfway.blur > elevation_shadow(3).blur && halfway.blur < elevation_shadow(4).blur);
+
+        assert_eq!(elevation_level(1.0), 1);
+        assert_eq!(elevation_level(2.0), 2);
+        assert_eq!(elevation_level(6.0), 3);
+        assert_eq!(elevation_level(12.0), 5);
+    }
+
+    #[test]
+    fn test_context_reduced_motion_overrides_global_setting() {
+        let reduced = egui::Context::default();
+        let full = egui::Context::default();
+        set_context_reduced_motion(&reduced, Some(true));
+        set_context_reduced_motion(&full, Some(false));
+        assert!(reduced_motion(&reduced));
+        assert_eq!(motion_duration(&reduced, 0.25), 0.0);
+        assert!(!reduced_motion(&full));
+        assert_eq!(motion_duration(&full, 0.25), 0.25);
+
+        set_context_reduced_motion(&reduced, None);
+        assert_eq!(reduced_motion(&reduced), get_reduced_motion());
+    }
+
+    #[test]
+    fn test_shortcut_format_per_platform() {
+        let save = Shortcut::command(egui::Key::S);
+        assert_eq!(save.format_for(true), "⌘S");
+        assert_eq!(save.format_for(false), "Ctrl+S");
+
+        let save_as = Shortcut::new(
+            egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+            egui::Key::S,
+        );
+        assert_eq!(save_as.format_for(true), "⇧⌘S");
+        assert_eq!(save_as.format_for(false), "Ctrl+Shift+S");
+    }
+
+    #[test]
+    fn test_activate_theme_switches_between_prepared_themes() {
+        const OCEAN: &str = "test_activate_theme_switches_ocean";
+        const FOREST: &str = "test_activate_theme_switches_forest";
+
+        // Prepared themes are global, so remove ours again even if an assertion fails
+        struct RemoveOnDrop;
+        impl Drop for RemoveOnDrop {
+            fn drop(&mut self) {
+                PREPARED_THEMES
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .retain(|theme| theme.name != OCEAN && theme.name != FOREST);
+            }
+        }
+        let cleanup = RemoveOnDrop;
+
+        let prepare = |name: &str, primary: &str| {
+            let mut theme = get_default_material_theme();
+            for scheme in theme.schemes.values_mut() {
+                scheme.primary = primary.to_string();
+            }
+            MaterialThemeContext::prepare_theme(name.to_string(), theme);
+        };
+        prepare(OCEAN, "#0061A4");
+        prepare(FOREST, "#386A20");
+        let names = available_theme_names();
+        assert!(names.contains(&OCEAN.to_string()));
+        assert!(names.contains(&FOREST.to_string()));
+
+        // A local context, so other tests reading the global theme are unaffected
+        let mut theme = MaterialThemeContext::default();
+        let active_primary = |theme: &MaterialThemeContext| {
+            theme.material_theme.as_ref().unwrap().schemes["light"]
+                .primary
+                .clone()
+        };
+        theme.apply_prepared_theme(OCEAN).unwrap();
+        assert_eq!(active_primary(&theme), "#0061A4");
+        theme.apply_prepared_theme(FOREST).unwrap();
+        assert_eq!(active_primary(&theme), "#386A20");
+        assert!(matches!(
+            theme.apply_prepared_theme("test-missing"),
+            Err(ThemeError::NotFound(_))
+        ));
+        assert_eq!(active_primary(&theme), "#386A20");
+
+        drop(cleanup);
+        let names = available_theme_names();
+        assert!(!names.contains(&OCEAN.to_string()));
+        assert!(!names.contains(&FOREST.to_string()));
+    }
+
+    #[test]
+    fn test_tab_from_last_widget_wraps_to_first_in_trap() {
+        let ctx = egui::Context::default();
+        let trap_id = egui::Id::new("test_focus_trap");
+        let mut ids = Vec::new();
+        let mut run = |events: Vec<egui::Event>| {
+            ids.clear();
+            let input = egui::RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let _ = ui.button("Before");
+                    trap_focus(ui, trap_id, |ui| {
+                        for label in ["First", "Middle", "Last"] {
+                            ids.push(ui.button(label).id);
+                        }
+                    });
+                    let _ = ui.button("After");
+                });
+            });
+            ids.clone()
+        };
+
+        let inside = run(Vec::new());
+        ctx.memory_mut(|m| m.request_focus(inside[2]));
+        run(Vec::new());
+        assert_eq!(ctx.memory(|m| m.focused()), Some(inside[2]));
+
+        run(vec![egui::Event::Key {
+            key: egui::Key::Tab,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::NONE,
+        }]);
+        // Focus passes the trailing marker on its way back to the start
+        for _ in 0..4 {
+            run(Vec::new());
+        }
+        assert_eq!(ctx.memory(|m| m.focused()), Some(inside[0]));
+    }
 }
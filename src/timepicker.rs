@@ -0,0 +1,574 @@
+//! Material Design 3 Time Picker
+//!
+//! Lets users pick a time of day in a modal [`MaterialDialog`], either on a
+//! clock dial or by typing into two text fields. An icon button at the bottom
+//! of the dialog switches between the two.
+//!
+//! - **Dial**: tap or drag around the dial to pick the hour, then the minute.
+//!   Tapping the hour or minute box above the dial switches between them. In
+//!   24-hour mode, hours 00 and 13–23 sit on an inner ring. The focused dial
+//!   also follows the arrow keys.
+//! - **Input**: hour and minute [`MaterialTextField`]s for keyboard entry.
+//!
+//! In 12-hour mode an AM/PM [`MaterialSegmentedButton`] sits below the time.
+//! Times are `(hour, minute)` tuples with the hour always in 0–23.
+//!
+//! # M3 Color Role Usage
+//!
+//! - **primaryContainer / onPrimaryContainer**: Selected hour or minute box
+//! - **surfaceContainerHighest**: Unselected time box, dial background
+//! - **onSurface**: Time digits, dial numbers
+//! - **primary**: Dial hand, selector and center dot
+//! - **onPrimary**: Number under the selector
+//!
+//! ## Dimensions
+//! - **Time boxes**: 96x80dp, 8dp corner radius, 57sp digits
+//! - **Dial**: 256dp, with a 48dp selector and a 2dp hand
+
+use crate::dialog::MaterialDialog;
+use crate::iconbutton::MaterialIconButton;
+use crate::ripple::Ripple;
+use crate::segmentedbutton::{ButtonSegment, MaterialSegmentedButton};
+use crate::textfield::MaterialTextField;
+use crate::theme::get_global_color;
+use egui::{
+    vec2, Align2, Context, FontId, Id, Key, Pos2, Sense, Stroke, Ui, Vec2, WidgetInfo, WidgetType,
+};
+use std::f32::consts::TAU;
+
+/// Size of the hour and minute boxes
+const TIME_BOX: Vec2 = vec2(96.0, 80.0);
+/// Diameter of the clock dial
+const DIAL_SIZE: f32 = 256.0;
+/// Diameter of the selector at the end of the hand
+const SELECTOR_SIZE: f32 = 48.0;
+/// Distance from the dial center to the outer and inner (24-hour) rings of numbers
+const OUTER_RING: f32 = 104.0;
+const INNER_RING: f32 = 68.0;
+/// Width of the modal dialog
+const PICKER_WIDTH: f32 = 328.0;
+
+/// Which value the dial is picking
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DialMode {
+    Hour,
+    Minute,
+}
+
+/// Picker state, kept in temp memory while the dialog is open
+#[derive(Clone, Debug)]
+struct PickerState {
+    hour: u32,
+    minute: u32,
+    mode: DialMode,
+    /// Whether the text fields replace the dial
+    input: bool,
+    hour_text: String,
+    minute_text: String,
+}
+
+impl PickerState {
+    fn new((hour, minute): (u32, u32), input: bool, hour_24: bool) -> Self {
+        let mut state = Self {
+            hour,
+            minute,
+            mode: DialMode::Hour,
+            input,
+            hour_text: String::new(),
+            minute_text: String::new(),
+        };
+        state.sync_text(hour_24);
+        state
+    }
+
+    /// Refill the text fields from the picked time
+    fn sync_text(&mut self, hour_24: bool) {
+        self.hour_text = format!("{:02}", display_hour(self.hour, hour_24));
+        self.minute_text = format!("{:02}", self.minute);
+    }
+}
+
+/// Material Design time picker.
+///
+/// # Example
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// let mut alarm: Option<(u32, u32)> = Some((7, 30));
+/// let mut picker_open = false;
+///
+/// if ui.button("Set alarm").clicked() {
+///     picker_open = true;
+/// }
+/// if let Some((hour, minute)) =
+///     MaterialTimePicker::new("alarm", &mut alarm).show(ui.ctx(), &mut picker_open)
+/// {
+///     println!("Alarm set for {hour:02}:{minute:02}");
+/// }
+/// # });
+/// ```
+pub struct MaterialTimePicker<'a> {
+    id: Id,
+    selected: &'a mut Option<(u32, u32)>,
+    title: String,
+    hour_24: bool,
+    input: bool,
+}
+
+impl<'a> MaterialTimePicker<'a> {
+    /// Create a time picker editing `selected`.
+    pub fn new(id: impl Into<Id>, selected: &'a mut Option<(u32, u32)>) -> Self {
+        Self {
+            id: id.into(),
+            selected,
+            title: "Select time".to_string(),
+            hour_24: false,
+            input: false,
+        }
+    }
+
+    /// Set the dialog title (default: "Select time").
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Show hours 0–23 instead of 1–12 with AM/PM (default: false).
+    pub fn hour_24(mut self, hour_24: bool) -> Self {
+        self.hour_24 = hour_24;
+        self
+    }
+
+    /// Open with the text fields instead of the dial (default: false).
+    pub fn input_mode(mut self, input: bool) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// Show the picker while `open` is true.
+    ///
+    /// The picked time is only written to `selected` when OK is clicked.
+    ///
+    /// ## Returns
+    /// The time confirmed this frame, if any
+    pub fn show(self, ctx: &Context, open: &mut bool) -> Option<(u32, u32)> {
+        let state_id = self.id.with("time_picker_state");
+        if !*open {
+            ctx.data_mut(|d| d.remove::<PickerState>(state_id));
+            return None;
+        }
+
+        let hour_24 = self.hour_24;
+        let mut state = ctx
+            .data(|d| d.get_temp::<PickerState>(state_id))
+            .unwrap_or_else(|| {
+                PickerState::new(self.selected.unwrap_or((0, 0)), self.input, hour_24)
+            });
+
+        let id = self.id;
+        let result = MaterialDialog::new(self.id, self.title.clone(), &mut *open)
+            .min_width(PICKER_WIDTH)
+            .max_width(PICKER_WIDTH)
+            .content(|ui| {
+                if state.input {
+                    input_ui(ui, id, &mut state, hour_24);
+                } else {
+                    time_boxes_ui(ui, id, &mut state, hour_24);
+                    ui.add_space(24.0);
+                    ui.vertical_centered(|ui| dial_ui(ui, &mut state, hour_24));
+                }
+                ui.add_space(12.0);
+                let icon = if state.input { "schedule" } else { "keyboard" };
+                if ui.add(MaterialIconButton::standard(icon)).clicked() {
+                    state.input = !state.input;
+                    state.sync_text(hour_24);
+                }
+            })
+            .text_action("Cancel", || {})
+            .confirm_action("OK", || {})
+            .show(ctx);
+
+        let confirmed = result.confirmed.then_some((state.hour, state.minute));
+        if confirmed.is_some() {
+            *self.selected = confirmed;
+        }
+        if *open {
+            ctx.data_mut(|d| d.insert_temp(state_id, state));
+        } else {
+            ctx.data_mut(|d| d.remove::<PickerState>(state_id));
+        }
+        confirmed
+    }
+}
+
+/// Hour and minute boxes above the dial; clicking one switches the dial to it
+fn time_boxes_ui(ui: &mut Ui, id: Id, state: &mut PickerState, hour_24: bool) {
+    ui.horizontal(|ui| {
+        for mode in [DialMode::Hour, DialMode::Minute] {
+            if mode == DialMode::Minute {
+                let (rect, _) = ui.allocate_exact_size(vec2(24.0, TIME_BOX.y), Sense::hover());
+                ui.painter().text(
+                    rect.center(),
+                    Align2::CENTER_CENTER,
+                    ":",
+                    FontId::proportional(57.0),
+                    get_global_color("onSurface"),
+                );
+            }
+
+            let (value, label) = match mode {
+                DialMode::Hour => (display_hour(state.hour, hour_24), "Hour"),
+                DialMode::Minute => (state.minute, "Minute"),
+            };
+            let selected = state.mode == mode;
+            let (rect, response) = ui.allocate_exact_size(TIME_BOX, Sense::click());
+            response.widget_info(|| {
+                WidgetInfo::selected(
+                    WidgetType::Button,
+                    true,
+                    selected,
+                    format!("{label} {value}"),
+                )
+            });
+            let (fill, text_color) = if selected {
+                (
+                    get_global_color("primaryContainer"),
+                    get_global_color("onPrimaryContainer"),
+                )
+            } else {
+                (
+                    get_global_color("surfaceContainerHighest"),
+                    get_global_color("onSurface"),
+                )
+            };
+            ui.painter().rect_filled(rect, 8.0, fill);
+            Ripple::new(text_color)
+                .corner_radius(8.0)
+                .show(ui, rect, &response);
+            ui.painter().text(
+                rect.center(),
+                Align2::CENTER_CENTER,
+                format!("{value:02}"),
+                FontId::proportional(57.0),
+                text_color,
+            );
+            if response.clicked() {
+                state.mode = mode;
+            }
+        }
+    });
+
+    if !hour_24 {
+        ui.add_space(12.0);
+        period_ui(ui, id, state);
+    }
+}
+
+/// AM/PM toggle; switching keeps the hour on the clock face
+fn period_ui(ui: &mut Ui, id: Id, state: &mut PickerState) {
+    let mut period = usize::from(state.hour >= 12);
+    ui.add(
+        MaterialSegmentedButton::single(&mut period)
+            .segment(ButtonSegment::new("AM"))
+            .segment(ButtonSegment::new("PM"))
+            .show_checkmark(false)
+            .id(id.with("period")),
+    );
+    state.hour = state.hour % 12 + 12 * period as u32;
+}
+
+/// Hour and minute text fields
+fn input_ui(ui: &mut Ui, id: Id, state: &mut PickerState, hour_24: bool) {
+    ui.horizontal(|ui| {
+        let hour_valid = parse_hour(&state.hour_text, hour_24, state.hour >= 12).is_some();
+        let mut hour_field = MaterialTextField::outlined(&mut state.hour_text)
+            .id(id.with("hour_input"))
+            .width(TIME_BOX.x);
+        hour_field = if hour_valid {
+            hour_field.helper_text("Hour")
+        } else {
+            hour_field.error_text("Hour")
+        };
+        if hour_field.show(ui).response.changed() {
+            if let Some(hour) = parse_hour(&state.hour_text, hour_24, state.hour >= 12) {
+                state.hour = hour;
+            }
+        }
+
+        ui.label(egui::RichText::new(":").size(57.0));
+
+        let minute_valid = parse_minute(&state.minute_text).is_some();
+        let mut minute_field = MaterialTextField::outlined(&mut state.minute_text)
+            .id(id.with("minute_input"))
+            .width(TIME_BOX.x);
+        minute_field = if minute_valid {
+            minute_field.helper_text("Minute")
+        } else {
+            minute_field.error_text("Minute")
+        };
+        if minute_field.show(ui).response.changed() {
+            if let Some(minute) = parse_minute(&state.minute_text) {
+                state.minute = minute;
+            }
+        }
+    });
+
+    if !hour_24 {
+        ui.add_space(12.0);
+        period_ui(ui, id, state);
+    }
+}
+
+/// The clock dial: numbers, hand and selector, driven by pointer and arrow keys
+fn dial_ui(ui: &mut Ui, state: &mut PickerState, hour_24: bool) {
+    let (rect, response) = ui.allocate_exact_size(Vec2::splat(DIAL_SIZE), Sense::click_and_drag());
+    let center = rect.center();
+    let (value, steps) = match state.mode {
+        DialMode::Hour => (state.hour, 24),
+        DialMode::Minute => (state.minute, 60),
+    };
+    response.widget_info(|| {
+        WidgetInfo::slider(
+            true,
+            match state.mode {
+                DialMode::Hour => display_hour(state.hour, hour_24),
+                DialMode::Minute => state.minute,
+            } as f64,
+            match state.mode {
+                DialMode::Hour => "Hour",
+                DialMode::Minute => "Minute",
+            },
+        )
+    });
+
+    // Pointer: pick the value under the pointer; releasing on an hour moves on to minutes
+    if response.is_pointer_button_down_on() {
+        if let Some(pos) = response.interact_pointer_pos() {
+            let offset = pos - center;
+            match state.mode {
+                DialMode::Hour => {
+                    let inner = hour_24 && offset.length() < (OUTER_RING + INNER_RING) / 2.0;
+                    state.hour = hour_from_step(dial_step(offset, 12), inner, hour_24, state.hour);
+                }
+                DialMode::Minute => state.minute = dial_step(offset, 60),
+            }
+        }
+    }
+    if (response.clicked() || response.drag_stopped()) && state.mode == DialMode::Hour {
+        state.mode = DialMode::Minute;
+    }
+
+    // Keyboard: arrows step through the values around the dial
+    if response.has_focus() {
+        let delta = ui.input(|i| {
+            if i.key_pressed(Key::ArrowUp) || i.key_pressed(Key::ArrowRight) {
+                1
+            } else if i.key_pressed(Key::ArrowDown) || i.key_pressed(Key::ArrowLeft) {
+                steps - 1
+            } else {
+                0
+            }
+        });
+        match state.mode {
+            DialMode::Hour if !hour_24 => {
+                // Stay within AM or PM
+                state.hour = state.hour / 12 * 12 + (state.hour % 12 + delta) % 12;
+            }
+            DialMode::Hour => state.hour = (state.hour + delta) % 24,
+            DialMode::Minute => state.minute = (state.minute + delta) % 60,
+        }
+    }
+
+    let painter = ui.painter();
+    let on_surface = get_global_color("onSurface");
+    let on_primary = get_global_color("onPrimary");
+    let primary = get_global_color("primary");
+    painter.circle_filled(
+        center,
+        DIAL_SIZE / 2.0,
+        get_global_color("surfaceContainerHighest"),
+    );
+
+    // Hand and selector
+    let (selector, on_label) = match state.mode {
+        DialMode::Hour => {
+            let ring = if hour_24 && is_inner_hour(value) {
+                INNER_RING
+            } else {
+                OUTER_RING
+            };
+            (dial_point(center, ring, value % 12, 12), true)
+        }
+        DialMode::Minute => (dial_point(center, OUTER_RING, value, 60), value % 5 == 0),
+    };
+    painter.line_segment([center, selector], Stroke::new(2.0, primary));
+    painter.circle_filled(center, 4.0, primary);
+    painter.circle_filled(selector, SELECTOR_SIZE / 2.0, primary);
+    if !on_label {
+        // A minute between two labels
+        painter.circle_filled(selector, 2.0, on_primary);
+    }
+    if response.has_focus() {
+        painter.circle_stroke(
+            center,
+            DIAL_SIZE / 2.0 - 1.0,
+            Stroke::new(2.0, get_global_color("secondary")),
+        );
+    }
+
+    // Numbers
+    let mut labels: Vec<(Pos2, u32, String)> = Vec::new();
+    match state.mode {
+        DialMode::Hour if hour_24 => {
+            for step in 0..12 {
+                let outer = if step == 0 { 12 } else { step };
+                let inner = if step == 0 { 0 } else { step + 12 };
+                labels.push((
+                    dial_point(center, OUTER_RING, step, 12),
+                    outer,
+                    outer.to_string(),
+                ));
+                labels.push((
+                    dial_point(center, INNER_RING, step, 12),
+                    inner,
+                    format!("{inner:02}"),
+                ));
+            }
+        }
+        DialMode::Hour => {
+            for step in 0..12 {
+                let hour = if step == 0 { 12 } else { step };
+                let value = hour % 12 + if state.hour >= 12 { 12 } else { 0 };
+                labels.push((
+                    dial_point(center, OUTER_RING, step, 12),
+                    value,
+                    hour.to_string(),
+                ));
+            }
+        }
+        DialMode::Minute => {
+            for minute in (0..60).step_by(5) {
+                labels.push((
+                    dial_point(center, OUTER_RING, minute, 60),
+                    minute,
+                    format!("{minute:02}"),
+                ));
+            }
+        }
+    }
+    for (pos, label_value, text) in labels {
+        let color = if label_value == value {
+            on_primary
+        } else {
+            on_surface
+        };
+        painter.text(
+            pos,
+            Align2::CENTER_CENTER,
+            text,
+            FontId::proportional(16.0),
+            color,
+        );
+    }
+}
+
+/// Number of the dial position nearest to `offset` from the center, with
+/// `steps` positions clockwise from the top
+fn dial_step(offset: Vec2, steps: u32) -> u32 {
+    let turn = offset.x.atan2(-offset.y).rem_euclid(TAU) / TAU;
+    (turn * steps as f32).round() as u32 % steps
+}
+
+/// Center of dial position `step` of `steps` at `radius` from `center`
+fn dial_point(center: Pos2, radius: f32, step: u32, steps: u32) -> Pos2 {
+    let angle = step as f32 / steps as f32 * TAU;
+    center + radius * vec2(angle.sin(), -angle.cos())
+}
+
+/// Hour picked at dial position `step` (0 = top)
+///
+/// 12-hour dials keep the AM/PM half of `current`; 24-hour dials show 12 and
+/// 1–11 on the outer ring and 00 and 13–23 on the inner one.
+fn hour_from_step(step: u32, inner: bool, hour_24: bool, current: u32) -> u32 {
+    if hour_24 {
+        match (step, inner) {
+            (0, false) => 12,
+            (0, true) => 0,
+            (step, false) => step,
+            (step, true) => step + 12,
+        }
+    } else {
+        step + if current >= 12 { 12 } else { 0 }
+    }
+}
+
+fn is_inner_hour(hour: u32) -> bool {
+    hour == 0 || hour > 12
+}
+
+/// Hour as shown on the clock: 0–23, or 1–12 in 12-hour mode
+fn display_hour(hour: u32, hour_24: bool) -> u32 {
+    if hour_24 {
+        hour
+    } else if hour % 12 == 0 {
+        12
+    } else {
+        hour % 12
+    }
+}
+
+/// Parse a typed hour into 0–23; 12-hour input takes 1–12 and keeps AM/PM
+fn parse_hour(text: &str, hour_24: bool, pm: bool) -> Option<u32> {
+    let hour: u32 = text.trim().parse().ok()?;
+    if hour_24 {
+        (hour < 24).then_some(hour)
+    } else {
+        (1..=12)
+            .contains(&hour)
+            .then_some(hour % 12 + if pm { 12 } else { 0 })
+    }
+}
+
+fn parse_minute(text: &str) -> Option<u32> {
+    let minute: u32 = text.trim().parse().ok()?;
+    (minute < 60).then_some(minute)
+}
+
+/// Convenience function to create a time picker.
+pub fn time_picker<'a>(
+    id: impl Into<Id>,
+    selected: &'a mut Option<(u32, u32)>,
+) -> MaterialTimePicker<'a> {
+    MaterialTimePicker::new(id, selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dial_positions_map_to_times() {
+        // Clockwise from the top
+        assert_eq!(dial_step(vec2(0.0, -1.0), 12), 0);
+        assert_eq!(dial_step(vec2(1.0, 0.0), 12), 3);
+        assert_eq!(dial_step(vec2(0.0, 1.0), 60), 30);
+        assert_eq!(dial_step(vec2(-1.0, -0.01), 60), 45);
+        assert_eq!(dial_step(vec2(-0.01, -1.0), 60), 0);
+
+        // 12-hour dials keep AM/PM
+        assert_eq!(hour_from_step(0, false, false, 9), 0);
+        assert_eq!(hour_from_step(0, false, false, 15), 12);
+        assert_eq!(hour_from_step(3, false, false, 15), 15);
+        // 24-hour dials: 12 and 1–11 outside, 00 and 13–23 inside
+        assert_eq!(hour_from_step(0, false, true, 0), 12);
+        assert_eq!(hour_from_step(0, true, true, 0), 0);
+        assert_eq!(hour_from_step(5, true, true, 0), 17);
+
+        assert_eq!(display_hour(0, false), 12);
+        assert_eq!(display_hour(13, false), 1);
+        assert_eq!(parse_hour("12", false, false), Some(0));
+        assert_eq!(parse_hour("7", false, true), Some(19));
+        assert_eq!(parse_hour("0", false, false), None);
+        assert_eq!(parse_hour("23", true, false), Some(23));
+        assert_eq!(parse_minute("60"), None);
+    }
+}
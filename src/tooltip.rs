@@ -2,19 +2,52 @@
 //!
 //! # M3 Color Role Usage
 //!
+//! ## Plain Tooltip
 //! - **inverseSurface**: Tooltip background (inverse for high contrast)
 //! - **inverseOnSurface**: Tooltip text on inverse background
+//!
+//! ## Rich Tooltip
+//! - **surfaceContainer**: Tooltip background
+//! - **onSurfaceVariant**: Title and body text
+//! - **primary**: Action label
 //! - **Shadow**: 2dp elevation shadow
 //!
 //! ## Dimensions
 //! - **Max width**: 200dp (plain), 320dp (rich)
-//! - **Padding**: 4dp vertical, 8dp horizontal (plain)
-//! - **Corner radius**: 4dp
+//! - **Padding**: 4dp vertical, 8dp horizontal (plain); 12dp top, 16dp sides, 8dp bottom (rich)
+//! - **Corner radius**: 4dp (plain), 12dp (rich)
+//!
+//! ## Showing Tooltips
+//! [`material_tooltip`] and [`rich_tooltip`] are the shared entry points used by the
+//! components' `.tooltip()` builders. They wait for egui's hover delay
+//! (`style.interaction.tooltip_delay`) before appearing and dismiss as soon as the
+//! pointer leaves the target. A rich tooltip also stays open while the pointer is over
+//! the tooltip itself, so its action can be clicked.
 
-use crate::theme::get_global_color;
+use crate::{
+    theme::{get_global_color, Shortcut},
+    MaterialButton,
+};
 use egui::{
-    pos2, Area, FontId, Id, Order, Rect, Response, Sense, Stroke, Ui, Vec2,
+    pos2, Align2, Area, Context, CornerRadius, FontId, Frame, Id, LayerId, Margin, Order, Rect,
+    Response, RichText, Sense, Shadow, Stroke, Ui, Vec2,
 };
+use std::time::Duration;
+
+/// Gap between a tooltip and its target
+const TOOLTIP_SPACING: f32 = 8.0;
+
+/// Gap between a plain tooltip's text and its keyboard shortcut
+const SHORTCUT_SPACING: f32 = 12.0;
+
+/// Hover tracking for a tooltip, stored in temp memory under the target's id
+#[derive(Clone, Copy)]
+struct TooltipHover {
+    /// Input time at which the pointer entered the target
+    since: f64,
+    /// Where the tooltip was drawn last frame, if it can be hovered itself
+    tooltip_rect: Option<Rect>,
+}
 
 /// Tooltip position relative to target element
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -51,6 +84,7 @@ pub struct MaterialTooltip {
     max_width: f32,
     padding: Vec2,
     font_size: f32,
+    shortcut: Option<Shortcut>,
 }
 
 impl MaterialTooltip {
@@ -62,9 +96,29 @@ impl MaterialTooltip {
             max_width: 200.0,
             padding: Vec2::new(8.0, 6.0),
             font_size: 12.0,
+            shortcut: None,
         }
     }
 
+    /// Show a keyboard shortcut after the text, right-aligned in a muted color
+    ///
+    /// ```rust
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_material3::{theme::Shortcut, MaterialButton, MaterialTooltip};
+    ///
+    /// let response = ui.add(MaterialButton::icon("undo"));
+    /// if response.hovered() {
+    ///     MaterialTooltip::new("Undo")
+    ///         .shortcut(Shortcut::command(egui::Key::Z))
+    ///         .show(ui, response.rect);
+    /// }
+    /// # });
+    /// ```
+    pub fn shortcut(mut self, shortcut: Shortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
     /// Set the tooltip position
     pub fn position(mut self, position: TooltipPosition) -> Self {
         self.position = position;
@@ -91,35 +145,52 @@ impl MaterialTooltip {
 
     /// Show the tooltip at a specific position
     pub fn show(&self, ui: &mut Ui, target_rect: Rect) {
+        self.show_in(ui.ctx(), target_rect);
+    }
+
+    /// Show the tooltip on the tooltip layer next to `target_rect`
+    fn show_in(&self, ctx: &Context, target_rect: Rect) {
         let inverse_surface = get_global_color("inverseSurface");
         let inverse_on_surface = get_global_color("inverseOnSurface");
 
-        // Calculate text size
-        let text_galley = ui.painter().layout(
+        // Create unique ID for this tooltip
+        let tooltip_id = Id::new("tooltip").with(&self.text);
+
+        // Calculate text size, leaving room for the shortcut on the right
+        let painter = ctx.layer_painter(LayerId::new(Order::Tooltip, tooltip_id));
+        let shortcut_color = inverse_on_surface.gamma_multiply(0.7);
+        let shortcut_galley = self.shortcut.map(|shortcut| {
+            painter.layout_no_wrap(
+                shortcut.format_for(ctx.os() == egui::os::OperatingSystem::Mac),
+                FontId::proportional(self.font_size),
+                shortcut_color,
+            )
+        });
+        let shortcut_width = shortcut_galley
+            .as_ref()
+            .map_or(0.0, |galley| galley.size().x + SHORTCUT_SPACING);
+        let text_galley = painter.layout(
             self.text.clone(),
             FontId::proportional(self.font_size),
             inverse_on_surface,
-            self.max_width - self.padding.x * 2.0,
+            (self.max_width - self.padding.x * 2.0 - shortcut_width).max(0.0),
         );
 
         let tooltip_size = Vec2::new(
-            text_galley.size().x + self.padding.x * 2.0,
+            text_galley.size().x + shortcut_width + self.padding.x * 2.0,
             text_galley.size().y + self.padding.y * 2.0,
         );
 
         // Calculate tooltip position based on preferred position
-        let screen_rect = ui.ctx().content_rect();
+        let screen_rect = ctx.content_rect();
         let tooltip_pos = self.calculate_position(target_rect, tooltip_size, screen_rect);
 
-        // Create unique ID for this tooltip
-        let tooltip_id = Id::new("tooltip").with(&self.text);
-
         // Show tooltip as an overlay
         Area::new(tooltip_id)
             .fixed_pos(tooltip_pos)
             .order(Order::Tooltip)
             .interactable(false)
-            .show(ui.ctx(), |ui| {
+            .show(ctx, |ui| {
                 let (rect, _) = ui.allocate_exact_size(tooltip_size, Sense::hover());
 
                 // Draw background
@@ -139,6 +210,13 @@ impl MaterialTooltip {
                     rect.min.y + self.padding.y,
                 );
                 ui.painter().galley(text_pos, text_galley, inverse_on_surface);
+
+                // Same font as the text, so it lines up with the first row
+                if let Some(galley) = shortcut_galley {
+                    let shortcut_pos =
+                        pos2(rect.max.x - self.padding.x - galley.size().x, text_pos.y);
+                    ui.painter().galley(shortcut_pos, galley, shortcut_color);
+                }
             });
     }
 
@@ -149,7 +227,7 @@ impl MaterialTooltip {
         tooltip_size: Vec2,
         screen_rect: Rect,
     ) -> egui::Pos2 {
-        let spacing = 8.0; // Gap between target and tooltip
+        let spacing = TOOLTIP_SPACING;
 
         let position = match self.position {
             TooltipPosition::Auto => {
@@ -190,7 +268,7 @@ impl MaterialTooltip {
         tooltip_size: Vec2,
         screen_rect: Rect,
     ) -> TooltipPosition {
-        let spacing = 8.0;
+        let spacing = TOOLTIP_SPACING;
 
         // Check available space in each direction
         let space_above = target_rect.min.y - screen_rect.min.y;
@@ -214,6 +292,191 @@ impl MaterialTooltip {
     }
 }
 
+/// Material Design rich tooltip with an optional title and action
+///
+/// Rich tooltips give more context than a plain tooltip. Show one with [`rich_tooltip`].
+///
+/// # Example
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// use egui_material3::{rich_tooltip, MaterialButton, RichTooltip};
+///
+/// let response = ui.add(MaterialButton::outlined("Sync"));
+/// let tooltip = RichTooltip::new("Changes are uploaded whenever you're online.")
+///     .title("Automatic sync")
+///     .action("Learn more");
+/// if rich_tooltip(&response, tooltip) {
+///     // open the help page
+/// }
+/// # });
+/// ```
+pub struct RichTooltip {
+    title: Option<String>,
+    body: String,
+    action: Option<String>,
+    max_width: f32,
+}
+
+impl RichTooltip {
+    /// Create a new rich tooltip with body text
+    pub fn new(body: impl Into<String>) -> Self {
+        Self {
+            title: None,
+            body: body.into(),
+            action: None,
+            max_width: 320.0,
+        }
+    }
+
+    /// Set the title shown above the body
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Add a text action below the body
+    pub fn action(mut self, label: impl Into<String>) -> Self {
+        self.action = Some(label.into());
+        self
+    }
+
+    /// Set the maximum width
+    pub fn max_width(mut self, width: f32) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Show the tooltip below `target_rect`, returning its rect and whether the action was clicked
+    fn show_in(&self, ctx: &Context, id: Id, target_rect: Rect) -> (Rect, bool) {
+        let surface_container = get_global_color("surfaceContainer");
+        let on_surface_variant = get_global_color("onSurfaceVariant");
+        let primary = get_global_color("primary");
+
+        let area = Area::new(id)
+            .pivot(Align2::CENTER_TOP)
+            .fixed_pos(target_rect.center_bottom() + Vec2::new(0.0, TOOLTIP_SPACING))
+            .order(Order::Tooltip)
+            .show(ctx, |ui| {
+                Frame::new()
+                    .fill(surface_container)
+                    .corner_radius(CornerRadius::same(12))
+                    .inner_margin(Margin {
+                        left: 16,
+                        right: 16,
+                        top: 12,
+                        bottom: 8,
+                    })
+                    .shadow(Shadow {
+                        offset: [0, 2],
+                        blur: 6,
+                        spread: 0,
+                        color: egui::Color32::from_black_alpha(40),
+                    })
+                    .show(ui, |ui| {
+                        ui.set_max_width(self.max_width - 32.0);
+                        if let Some(title) = &self.title {
+                            ui.label(
+                                RichText::new(title)
+                                    .size(14.0)
+                                    .strong()
+                                    .color(on_surface_variant),
+                            );
+                        }
+                        ui.label(
+                            RichText::new(&self.body)
+                                .size(14.0)
+                                .color(on_surface_variant),
+                        );
+                        self.action.as_ref().is_some_and(|label| {
+                            ui.add_space(4.0);
+                            ui.add(MaterialButton::text(label).text_color(primary))
+                                .clicked()
+                        })
+                    })
+                    .inner
+            });
+        (area.response.rect, area.inner)
+    }
+}
+
+/// Whether the tooltip for `response` should be shown this frame.
+///
+/// Tracks how long the target has been hovered, waits for the hover delay and forgets
+/// the hover once the pointer leaves both the target and `hoverable` tooltips.
+fn tooltip_visible(response: &Response, hoverable: bool) -> bool {
+    let ctx = &response.ctx;
+    let id = response.id.with("material_tooltip");
+    let state = ctx.data(|d| d.get_temp::<TooltipHover>(id));
+
+    // Keep a hoverable tooltip open while the pointer moves onto it; the union
+    // also covers the gap between target and tooltip.
+    let over_tooltip = hoverable
+        && state
+            .and_then(|state| state.tooltip_rect)
+            .zip(ctx.pointer_hover_pos())
+            .is_some_and(|(rect, pos)| rect.union(response.rect).contains(pos));
+
+    if !response.hovered() && !over_tooltip {
+        ctx.data_mut(|d| d.remove::<TooltipHover>(id));
+        return false;
+    }
+
+    let now = ctx.input(|i| i.time);
+    let state = state.unwrap_or(TooltipHover {
+        since: now,
+        tooltip_rect: None,
+    });
+    ctx.data_mut(|d| d.insert_temp(id, state));
+
+    let remaining = ctx.style().interaction.tooltip_delay as f64 - (now - state.since);
+    if remaining > 0.0 {
+        ctx.request_repaint_after(Duration::from_secs_f64(remaining));
+        return false;
+    }
+    true
+}
+
+/// Show an M3 plain tooltip for `response` after the hover delay.
+///
+/// This is what the components' `.tooltip()` builders use.
+///
+/// # Example
+/// ```rust
+/// # egui::__run_test_ui(|ui| {
+/// use egui_material3::{material_tooltip, MaterialButton};
+///
+/// let response = ui.add(MaterialButton::icon("delete"));
+/// material_tooltip(&response, "Delete");
+/// # });
+/// ```
+pub fn material_tooltip(response: &Response, text: impl Into<String>) {
+    if tooltip_visible(response, false) {
+        MaterialTooltip::new(text).show_in(&response.ctx, response.rect);
+    }
+}
+
+/// Show an M3 rich tooltip for `response` after the hover delay.
+///
+/// Returns `true` when the tooltip's action was clicked, which also closes it.
+pub fn rich_tooltip(response: &Response, tooltip: RichTooltip) -> bool {
+    if !tooltip_visible(response, true) {
+        return false;
+    }
+
+    let ctx = &response.ctx;
+    let id = response.id.with("material_tooltip");
+    let (rect, clicked) = tooltip.show_in(ctx, id.with("area"), response.rect);
+    ctx.data_mut(|d| {
+        if clicked {
+            d.remove::<TooltipHover>(id);
+        } else if let Some(mut state) = d.get_temp::<TooltipHover>(id) {
+            state.tooltip_rect = Some(rect);
+            d.insert_temp(id, state);
+        }
+    });
+    clicked
+}
+
 /// Convenience function to show a tooltip on hover
 ///
 /// This function shows a tooltip when the user hovers over the target element.
@@ -279,3 +542,38 @@ pub fn with_tooltip<R>(
 pub fn tooltip(text: impl Into<String>) -> MaterialTooltip {
     MaterialTooltip::new(text)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tooltip_waits_for_hover_delay_and_dismisses() {
+        let ctx = Context::default();
+        let delay = ctx.style().interaction.tooltip_delay as f64;
+        let frame = |time: f64, pos: egui::Pos2| {
+            let mut visible = false;
+            let input = egui::RawInput {
+                time: Some(time),
+                events: vec![egui::Event::PointerMoved(pos)],
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let response = ui.allocate_response(Vec2::splat(40.0), Sense::hover());
+                    visible = tooltip_visible(&response, false);
+                });
+            });
+            visible
+        };
+
+        let inside = pos2(20.0, 20.0);
+        let outside = pos2(300.0, 300.0);
+        assert!(!frame(0.0, inside));
+        assert!(!frame(0.1, inside));
+        assert!(frame(0.2 + delay, inside));
+        assert!(!frame(0.3 + delay, outside));
+        // Hovering again restarts the delay
+        assert!(!frame(0.4 + delay, inside));
+    }
+}
@@ -24,12 +24,15 @@
 //! - **Padding**: 16dp horizontal, 8dp vertical for icons
 
 use crate::material_symbol::material_symbol_text;
-use crate::theme::get_global_color;
+use crate::menu::{Corner, MaterialMenu, MenuItem};
+use crate::theme::{get_global_color, is_rtl, mirror_align, mirror_rect, mirror_x};
+use crate::tooltip::material_tooltip;
 use egui::{
     ecolor::Color32,
     epaint::{CornerRadius, Shadow},
     Rect, Response, Sense, Ui, Vec2, Widget,
 };
+use std::cell::Cell;
 
 /// Material Design top app bar variants.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -40,6 +43,50 @@ pub enum TopAppBarVariant {
     CenterAligned,
 }
 
+/// An action shown in the top app bar, or in its overflow menu when space runs out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AppBarAction {
+    id: String,
+    icon: String,
+    label: String,
+}
+
+impl AppBarAction {
+    /// Create a new action.
+    ///
+    /// # Arguments
+    /// * `id` - Identifier reported in [`TopAppBarResponse::clicked_action`]
+    /// * `icon` - Material symbol name or character shown on the icon button
+    /// * `label` - Text used for the overflow menu item and the tooltip
+    pub fn new(id: impl Into<String>, icon: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            icon: icon.into(),
+            label: label.into(),
+        }
+    }
+}
+
+/// Response from showing a [`MaterialTopAppBar`]
+pub struct TopAppBarResponse {
+    /// The combined response of the bar and its icon buttons
+    pub response: Response,
+    /// Id of the [`AppBarAction`] clicked this frame, from the bar or the overflow menu
+    pub clicked_action: Option<String>,
+}
+
+/// Resolve an icon given either as a material symbol name or as the symbol character itself.
+fn icon_text(icon: &str) -> String {
+    let mut chars = icon.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        if ('\u{e000}'..='\u{f8ff}').contains(&ch) || ('\u{ea00}'..='\u{eb8d}').contains(&ch) {
+            // It's already a Material Symbol character, use it directly
+            return icon.to_string();
+        }
+    }
+    material_symbol_text(icon)
+}
+
 /// Material Design top app bar component.
 ///
 /// Top app bars display information and actions related to the current screen.
@@ -93,6 +140,21 @@ pub struct MaterialTopAppBar<'a> {
     leading_width: f32,
     scrolled_under_elevation: f32,
     surface_tint_color: Option<Color32>,
+    scroll_offset: Option<f32>,
+    actions: Vec<AppBarAction>,
+}
+
+/// Height of the collapsed (small) app bar row.
+const COLLAPSED_HEIGHT: f32 = 64.0;
+
+/// How far the medium/large app bar has collapsed for a given scroll offset,
+/// from 0.0 (fully expanded) to 1.0 (collapsed to a small app bar).
+fn collapse_fraction(scroll_offset: f32, expanded_height: f32) -> f32 {
+    let range = expanded_height - COLLAPSED_HEIGHT;
+    if range <= 0.0 {
+        return 1.0;
+    }
+    (scroll_offset / range).clamp(0.0, 1.0)
 }
 
 impl<'a> MaterialTopAppBar<'a> {
@@ -139,6 +201,8 @@ impl<'a> MaterialTopAppBar<'a> {
             leading_width: 56.0,
             scrolled_under_elevation: 3.0,
             surface_tint_color: None,
+            scroll_offset: None,
+            actions: Vec::new(),
         }
     }
 
@@ -178,6 +242,32 @@ impl<'a> MaterialTopAppBar<'a> {
         self
     }
 
+    /// Set the actions shown at the end of the bar.
+    ///
+    /// As many actions as fit are shown as icon buttons; the rest move into an
+    /// overflow menu behind a trailing "more_vert" button. The split is recomputed
+    /// every frame, so resizing the window moves actions in and out of the menu.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = MaterialTopAppBar::regular("Photos")
+    ///     .actions(vec![
+    ///         AppBarAction::new("search", "search", "Search"),
+    ///         AppBarAction::new("share", "share", "Share"),
+    ///         AppBarAction::new("delete", "delete", "Delete"),
+    ///     ])
+    ///     .show(ui);
+    ///
+    /// if let Some(id) = response.clicked_action {
+    ///     println!("{id} clicked");
+    /// }
+    /// # });
+    /// ```
+    pub fn actions(mut self, actions: Vec<AppBarAction>) -> Self {
+        self.actions = actions;
+        self
+    }
+
     /// Set custom height.
     pub fn height(mut self, height: f32) -> Self {
         self.height = height;
@@ -202,6 +292,34 @@ impl<'a> MaterialTopAppBar<'a> {
         self
     }
 
+    /// Collapse a medium or large app bar based on the vertical scroll offset of the content.
+    ///
+    /// The bar shrinks from its expanded height to the 64dp small app bar as the
+    /// content scrolls up, moving the title from its large bottom-aligned position
+    /// into the top row. A positive offset also sets the scrolled state.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut offset = 0.0;
+    /// ui.add(MaterialTopAppBar::large("Inbox").with_scroll_offset(offset));
+    /// offset = egui::ScrollArea::vertical()
+    ///     .show(ui, |ui| ui.label("Content"))
+    ///     .state
+    ///     .offset
+    ///     .y;
+    /// # });
+    /// ```
+    pub fn with_scroll_offset(mut self, offset: f32) -> Self {
+        self.scroll_offset = Some(offset.max(0.0));
+        self.scrolled = offset > 0.0;
+        self
+    }
+
+    /// Collapse a medium or large app bar from the output of the `ScrollArea` below it.
+    pub fn with_scroll_output<R>(self, output: &egui::scroll_area::ScrollAreaOutput<R>) -> Self {
+        self.with_scroll_offset(output.state.offset.y)
+    }
+
     /// Set unique ID salt to prevent ID clashes.
     pub fn id_salt(mut self, salt: impl Into<String>) -> Self {
         self.id_salt = Some(salt.into());
@@ -270,8 +388,9 @@ impl<'a> MaterialTopAppBar<'a> {
     }
 }
 
-impl Widget for MaterialTopAppBar<'_> {
-    fn ui(self, ui: &mut Ui) -> Response {
+impl MaterialTopAppBar<'_> {
+    /// Show the app bar, reporting which [`AppBarAction`] was clicked.
+    pub fn show(self, ui: &mut Ui) -> TopAppBarResponse {
         let background_color = self.get_background_color();
         let text_color = self.get_foreground_color();
         let leading_icon_color = self.get_leading_icon_color();
@@ -293,10 +412,25 @@ impl Widget for MaterialTopAppBar<'_> {
             leading_width,
             scrolled_under_elevation,
             surface_tint_color: _,
+            scroll_offset,
+            actions,
         } = self;
 
+        let collapse = match (variant, scroll_offset) {
+            (TopAppBarVariant::Medium | TopAppBarVariant::Large, Some(offset)) => {
+                collapse_fraction(offset, height)
+            }
+            _ => 0.0,
+        };
+        let expanded_height = height;
+        let height = egui::lerp(
+            expanded_height..=COLLAPSED_HEIGHT.min(expanded_height),
+            collapse,
+        );
+
         let desired_size = Vec2::new(ui.available_width(), height);
         let mut response = ui.allocate_response(desired_size, Sense::hover());
+        let mut clicked_action = None;
         let rect = response.rect;
 
         if ui.is_rect_visible(rect) {
@@ -320,14 +454,18 @@ impl Widget for MaterialTopAppBar<'_> {
             let icon_padding = 12.0;
             let icon_total_size = icon_size + icon_padding * 2.0;
 
+            // Positions are worked out left-to-right and mirrored in RTL, which
+            // puts the navigation icon on the right and the actions on the left
+            let rtl = is_rtl(ui);
             let mut left_x = rect.min.x + 4.0;
-            let toolbar_height = 64.0_f32;
+            let toolbar_height = COLLAPSED_HEIGHT;
             let icon_y = rect.min.y + (toolbar_height - icon_total_size) / 2.0;
 
             // Draw navigation icon
             if let Some((nav_icon, nav_callback)) = navigation_icon {
                 let nav_rect =
                     Rect::from_min_size(egui::pos2(left_x, icon_y), Vec2::splat(icon_total_size));
+                let nav_rect = mirror_rect(rtl, rect, nav_rect);
 
                 let nav_id = if let Some(ref salt) = id_salt {
                     egui::Id::new((salt, "nav_icon"))
@@ -350,20 +488,7 @@ impl Widget for MaterialTopAppBar<'_> {
 
                 // Render navigation icon using material symbol font
                 // Support both icon names (like "menu") and direct character constants
-                let nav_icon_text = if nav_icon.chars().count() == 1 {
-                    // If it's a single character, check if it's in Material Symbols range
-                    let ch = nav_icon.chars().next().unwrap();
-                    if ('\u{e000}'..='\u{f8ff}').contains(&ch) || ('\u{ea00}'..='\u{eb8d}').contains(&ch) {
-                        // It's already a Material Symbol character, use it directly
-                        nav_icon.clone()
-                    } else {
-                        // Try to look it up as a name
-                        material_symbol_text(&nav_icon)
-                    }
-                } else {
-                    // Multiple characters, treat as icon name
-                    material_symbol_text(&nav_icon)
-                };
+                let nav_icon_text = icon_text(&nav_icon);
                 ui.painter().text(
                     nav_rect.center(),
                     egui::Align2::CENTER_CENTER,
@@ -384,11 +509,13 @@ impl Widget for MaterialTopAppBar<'_> {
             // M3: Regular/CenterAligned use titleLarge (22px)
             // Medium expanded uses headlineSmall (24px)
             // Large expanded uses headlineMedium (28px)
-            let title_font_size = match variant {
+            // Collapsing medium/large bars shrink towards titleLarge
+            let expanded_font_size = match variant {
                 TopAppBarVariant::Regular | TopAppBarVariant::CenterAligned => 22.0,
                 TopAppBarVariant::Medium => 24.0,
                 TopAppBarVariant::Large => 28.0,
             };
+            let title_font_size = egui::lerp(expanded_font_size..=22.0, collapse);
 
             // M3 title padding from bottom:
             // Medium: 20px, Large: 28px (from expandedTitlePadding)
@@ -396,8 +523,16 @@ impl Widget for MaterialTopAppBar<'_> {
                 TopAppBarVariant::Regular | TopAppBarVariant::CenterAligned => {
                     rect.min.y + (toolbar_height - title_font_size) / 2.0
                 }
-                TopAppBarVariant::Medium => rect.min.y + height - 20.0 - title_font_size,
-                TopAppBarVariant::Large => rect.min.y + height - 28.0 - title_font_size,
+                TopAppBarVariant::Medium | TopAppBarVariant::Large => {
+                    let bottom_padding = if variant == TopAppBarVariant::Medium {
+                        20.0
+                    } else {
+                        28.0
+                    };
+                    let expanded_y = rect.min.y + height - bottom_padding - title_font_size;
+                    let collapsed_y = rect.min.y + (toolbar_height - title_font_size) / 2.0;
+                    egui::lerp(expanded_y..=collapsed_y, collapse)
+                }
             };
 
             // M3 expanded title left padding is 16px
@@ -411,24 +546,149 @@ impl Widget for MaterialTopAppBar<'_> {
                     );
                     rect.center().x - title_galley.size().x / 2.0
                 }
-                TopAppBarVariant::Medium | TopAppBarVariant::Large => {
-                    rect.min.x + title_spacing
-                }
+                TopAppBarVariant::Medium | TopAppBarVariant::Large => egui::lerp(
+                    (rect.min.x + title_spacing)..=(left_x + title_spacing),
+                    collapse,
+                ),
                 _ => left_x + title_spacing,
             };
 
             // Draw title
-            ui.painter().text(
-                egui::pos2(title_x, title_y),
-                egui::Align2::LEFT_TOP,
+            let title_rect = ui.painter().text(
+                egui::pos2(mirror_x(rtl, rect, title_x), title_y),
+                mirror_align(rtl, egui::Align2::LEFT_TOP),
                 &title,
                 egui::FontId::proportional(title_font_size),
                 text_color,
             );
+            let title_rect = mirror_rect(rtl, rect, title_rect);
 
             // Draw action icons
             let mut right_x = rect.max.x - 4.0;
 
+            // Fit as many actions as the free space in the top row allows,
+            // keeping room for the icons added with `action_icon`
+            let title_in_top_row = match variant {
+                TopAppBarVariant::Regular | TopAppBarVariant::CenterAligned => true,
+                TopAppBarVariant::Medium | TopAppBarVariant::Large => collapse > 0.5,
+            };
+            let actions_min_x = if title_in_top_row {
+                title_rect.max.x + 8.0
+            } else {
+                left_x
+            };
+            let available_width =
+                right_x - action_icons.len() as f32 * icon_total_size - actions_min_x;
+            let slots = (available_width.max(0.0) / icon_total_size).floor() as usize;
+            let (visible_count, has_overflow) = if actions.len() <= slots {
+                (actions.len(), false)
+            } else {
+                (slots.saturating_sub(1), true)
+            };
+
+            let actions_id = if let Some(ref salt) = id_salt {
+                egui::Id::new((salt, "app_bar_actions"))
+            } else {
+                egui::Id::new(("top_app_bar_actions", &title))
+            };
+            let menu_id = actions_id.with("overflow_menu");
+            let mut overflow_open = ui
+                .ctx()
+                .data(|d| d.get_temp::<bool>(menu_id))
+                .unwrap_or(false);
+            let mut overflow_anchor = None;
+
+            if has_overflow {
+                right_x -= icon_total_size;
+                let more_rect =
+                    Rect::from_min_size(egui::pos2(right_x, icon_y), Vec2::splat(icon_total_size));
+                let more_rect = mirror_rect(rtl, rect, more_rect);
+                let more_response = ui.interact(more_rect, actions_id.with("more"), Sense::click());
+                material_tooltip(&more_response, "More options");
+
+                if more_response.hovered() {
+                    ui.painter().rect_filled(
+                        more_rect,
+                        CornerRadius::from(20.0),
+                        action_icon_color.linear_multiply(0.08),
+                    );
+                }
+                ui.painter().text(
+                    more_rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    icon_text("more_vert"),
+                    egui::FontId::proportional(icon_size),
+                    action_icon_color,
+                );
+
+                if more_response.clicked() {
+                    overflow_open = !overflow_open;
+                }
+                overflow_anchor = Some(more_rect);
+                response = response.union(more_response);
+            } else {
+                // Everything fits again, e.g. after the window was widened
+                overflow_open = false;
+            }
+
+            for (index, action) in actions[..visible_count].iter().enumerate().rev() {
+                right_x -= icon_total_size;
+
+                let action_rect =
+                    Rect::from_min_size(egui::pos2(right_x, icon_y), Vec2::splat(icon_total_size));
+                let action_rect = mirror_rect(rtl, rect, action_rect);
+                let action_response =
+                    ui.interact(action_rect, actions_id.with(index), Sense::click());
+                material_tooltip(&action_response, &action.label);
+
+                if action_response.hovered() {
+                    ui.painter().rect_filled(
+                        action_rect,
+                        CornerRadius::from(20.0),
+                        action_icon_color.linear_multiply(0.08),
+                    );
+                }
+                ui.painter().text(
+                    action_rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    icon_text(&action.icon),
+                    egui::FontId::proportional(icon_size),
+                    action_icon_color,
+                );
+
+                if action_response.clicked() {
+                    clicked_action = Some(action.id.clone());
+                }
+                response = response.union(action_response);
+            }
+
+            if let Some(anchor) = overflow_anchor {
+                let picked = Cell::new(None);
+                let (anchor_corner, menu_corner) = if rtl {
+                    (Corner::BottomLeft, Corner::TopLeft)
+                } else {
+                    (Corner::BottomRight, Corner::TopRight)
+                };
+                let mut menu = MaterialMenu::new(menu_id, &mut overflow_open)
+                    .anchor_rect(anchor)
+                    .anchor_corner(anchor_corner)
+                    .menu_corner(menu_corner);
+                for (index, action) in actions.iter().enumerate().skip(visible_count) {
+                    let picked = &picked;
+                    menu = menu.item(
+                        MenuItem::new(action.label.clone())
+                            .leading_icon(action.icon.clone())
+                            .on_click(move || picked.set(Some(index))),
+                    );
+                }
+                menu.show(ui.ctx());
+
+                if let Some(index) = picked.get() {
+                    clicked_action = Some(actions[index].id.clone());
+                }
+            }
+            ui.ctx().data_mut(|d| d.insert_temp(menu_id, overflow_open));
+
             for (action_index, (action_icon, action_callback)) in
                 action_icons.iter().enumerate().rev()
             {
@@ -436,6 +696,7 @@ impl Widget for MaterialTopAppBar<'_> {
 
                 let action_rect =
                     Rect::from_min_size(egui::pos2(right_x, icon_y), Vec2::splat(icon_total_size));
+                let action_rect = mirror_rect(rtl, rect, action_rect);
 
                 let action_id = if let Some(ref salt) = id_salt {
                     egui::Id::new((salt, "action_icon", action_index))
@@ -458,20 +719,7 @@ impl Widget for MaterialTopAppBar<'_> {
 
                 // Render action icon using material symbol font
                 // Support both icon names (like "search") and direct character constants
-                let action_icon_text = if action_icon.chars().count() == 1 {
-                    // If it's a single character, check if it's in Material Symbols range
-                    let ch = action_icon.chars().next().unwrap();
-                    if ('\u{e000}'..='\u{f8ff}').contains(&ch) || ('\u{ea00}'..='\u{eb8d}').contains(&ch) {
-                        // It's already a Material Symbol character, use it directly
-                        action_icon.clone()
-                    } else {
-                        // Try to look it up as a name
-                        material_symbol_text(action_icon.as_str())
-                    }
-                } else {
-                    // Multiple characters, treat as icon name
-                    material_symbol_text(action_icon.as_str())
-                };
+                let action_icon_text = icon_text(action_icon);
                 ui.painter().text(
                     action_rect.center(),
                     egui::Align2::CENTER_CENTER,
@@ -488,7 +736,16 @@ impl Widget for MaterialTopAppBar<'_> {
             }
         }
 
-        response
+        TopAppBarResponse {
+            response,
+            clicked_action,
+        }
+    }
+}
+
+impl Widget for MaterialTopAppBar<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
     }
 }
 
@@ -511,3 +768,48 @@ pub fn medium_top_app_bar(title: impl Into<String>) -> MaterialTopAppBar<'static
 pub fn large_top_app_bar(title: impl Into<String>) -> MaterialTopAppBar<'static> {
     MaterialTopAppBar::large(title)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::set_context_rtl;
+
+    /// Horizontal centers of the navigation icon, title and action icon
+    fn paint_app_bar(rtl: bool) -> (f32, f32, f32) {
+        let ctx = egui::Context::default();
+        set_context_rtl(&ctx, Some(rtl));
+        let output = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                top_app_bar("Title")
+                    .navigation_icon("menu", || {})
+                    .action_icon("search", || {})
+                    .show(ui);
+            });
+        });
+        let center_of = |text: &str| {
+            output
+                .shapes
+                .iter()
+                .find_map(|clipped| match &clipped.shape {
+                    egui::Shape::Text(shape) if shape.galley.text() == text => {
+                        Some(shape.visual_bounding_rect().center().x)
+                    }
+                    _ => None,
+                })
+                .unwrap()
+        };
+        (
+            center_of(&icon_text("menu")),
+            center_of("Title"),
+            center_of(&icon_text("search")),
+        )
+    }
+
+    #[test]
+    fn test_rtl_swaps_navigation_icon_and_actions() {
+        let (navigation, title, action) = paint_app_bar(false);
+        assert!(navigation < title && title < action);
+        let (navigation, title, action) = paint_app_bar(true);
+        assert!(navigation > title && title > action);
+    }
+}
@@ -0,0 +1,161 @@
+//! Snapshot tests rendering components into a headless egui_kittest harness
+//!
+//! Each test renders a few components with a fixed theme and compares the
+//! result with `tests/snapshots/<name>.png`. After an intended visual change,
+//! regenerate the images and commit them along with the change:
+//!
+//! ```bash
+//! UPDATE_SNAPSHOTS=1 cargo test --test snapshots --features snapshot_tests
+//! ```
+//!
+//! A failing test leaves `<name>.new.png` and `<name>.diff.png` next to the
+//! committed image to show what changed.
+//!
+//! Rendering goes through wgpu and needs a GPU adapter, so these tests only
+//! build with the `snapshot_tests` feature and a plain `cargo test` skips them.
+//! Without a GPU, install Mesa's software Vulkan driver (`libvulkan1
+//! mesa-vulkan-drivers` on Debian and Ubuntu), as the `snapshots` CI job does.
+
+use egui::Vec2;
+use egui_kittest::Harness;
+use egui_material3::theme::{
+    load_fonts, set_context_reduced_motion, setup_local_fonts_from_bytes, with_color_provider,
+    ContrastLevel, MaterialThemeContext, ThemeMode,
+};
+use egui_material3::{
+    assist_chip, filter_chip, input_chip, suggestion_chip, MaterialButton, MaterialCheckbox,
+    MaterialDataTable, MaterialSlider, MaterialSwitch,
+};
+use std::sync::Once;
+
+/// Prepare the same fonts for every snapshot, whatever the machine
+///
+/// The icon font is embedded and text uses egui's built-in fonts.
+fn setup_fonts() {
+    static SETUP: Once = Once::new();
+    SETUP.call_once(|| {
+        setup_local_fonts_from_bytes(
+            "MaterialSymbolsOutlined",
+            include_bytes!("../resources/MaterialSymbolsOutlined[FILL,GRAD,opsz,wght].ttf"),
+        );
+    });
+}
+
+/// The light scheme of an embedded theme, independent of the global theme
+fn snapshot_theme() -> MaterialThemeContext {
+    let mut theme = MaterialThemeContext::default();
+    theme.theme_mode = ThemeMode::Light;
+    theme.contrast_level = ContrastLevel::Normal;
+    theme.material_theme =
+        Some(serde_json::from_str(include_str!("../resources/material-theme1.json")).unwrap());
+    theme
+}
+
+/// A harness of `size` showing `add_contents` on the snapshot theme's surface
+fn harness<'a>(size: Vec2, mut add_contents: impl FnMut(&mut egui::Ui) + 'a) -> Harness<'a> {
+    setup_fonts();
+    let theme = snapshot_theme();
+    let mut harness = Harness::builder().with_size(size).build_ui(move |ui| {
+        let surface = theme.get_color_by_name("surface");
+        ui.painter().rect_filled(ui.max_rect(), 0.0, surface);
+        with_color_provider(ui, &theme, &mut add_contents);
+    });
+    load_fonts(&harness.ctx);
+    // No snapshot is taken halfway through an animation
+    set_context_reduced_motion(&harness.ctx, Some(true));
+    harness.run();
+    harness
+}
+
+#[test]
+fn button_variants() {
+    let mut harness = harness(Vec2::new(360.0, 280.0), |ui| {
+        ui.add(MaterialButton::filled("Filled"));
+        ui.add(MaterialButton::outlined("Outlined"));
+        ui.add(MaterialButton::text("Text"));
+        ui.add(MaterialButton::elevated("Elevated"));
+        ui.add(MaterialButton::filled_tonal("Tonal"));
+        ui.add(MaterialButton::filled("Icon").leading_icon("add"));
+        ui.add(MaterialButton::filled("Disabled").enabled(false));
+    });
+    harness.snapshot("button_variants");
+}
+
+#[test]
+fn chips() {
+    let mut harness = harness(Vec2::new(360.0, 240.0), |ui| {
+        let mut selected = true;
+        let mut unselected = false;
+        ui.add(assist_chip("Assist").leading_icon("event"));
+        ui.add(filter_chip("Selected", &mut selected));
+        ui.add(filter_chip("Unselected", &mut unselected));
+        ui.add(input_chip("Input"));
+        ui.add(suggestion_chip("Suggestion"));
+        ui.add(assist_chip("Disabled").enabled(false));
+    });
+    harness.snapshot("chips");
+}
+
+#[test]
+fn checkbox_and_switch_states() {
+    let mut harness = harness(Vec2::new(320.0, 320.0), |ui| {
+        for (checked, indeterminate, enabled, label) in [
+            (false, false, true, "Unchecked"),
+            (true, false, true, "Checked"),
+            (false, true, true, "Indeterminate"),
+            (true, false, false, "Disabled"),
+        ] {
+            let mut checked = checked;
+            ui.add(
+                MaterialCheckbox::new(&mut checked, label)
+                    .indeterminate(indeterminate)
+                    .enabled(enabled),
+            );
+        }
+        for (selected, enabled, label) in [
+            (false, true, "Off"),
+            (true, true, "On"),
+            (true, false, "Disabled"),
+        ] {
+            let mut selected = selected;
+            ui.add(
+                MaterialSwitch::new(&mut selected)
+                    .text(label)
+                    .enabled(enabled),
+            );
+        }
+    });
+    harness.snapshot("checkbox_and_switch_states");
+}
+
+#[test]
+fn slider() {
+    let mut harness = harness(Vec2::new(360.0, 160.0), |ui| {
+        let mut volume = 40.0;
+        let mut disabled = 70.0;
+        ui.add(MaterialSlider::new(&mut volume, 0.0..=100.0).text("Volume"));
+        ui.add(
+            MaterialSlider::new(&mut disabled, 0.0..=100.0)
+                .text("Disabled")
+                .enabled(false),
+        );
+    });
+    harness.snapshot("slider");
+}
+
+#[test]
+fn data_table() {
+    let mut harness = harness(Vec2::new(480.0, 260.0), |ui| {
+        ui.add(
+            MaterialDataTable::new()
+                .id("snapshot_table")
+                .column("Dessert", 200.0, false)
+                .column("Calories", 100.0, true)
+                .column("Fat (g)", 100.0, true)
+                .row(|row| row.cell("Frozen yogurt").cell("159").cell("6.0"))
+                .row(|row| row.cell("Ice cream sandwich").cell("237").cell("9.0"))
+                .row(|row| row.cell("Eclair").cell("262").cell("16.0")),
+        );
+    });
+    harness.snapshot("data_table");
+}